@@ -161,6 +161,13 @@ impl CompliancePanel {
             .and_then(|item| serde_json::to_string(item).ok())
     }
 
+    /// Violations on a page, with page-local positions and any bounding
+    /// boxes, for drawing highlight overlays
+    #[wasm_bindgen(js_name = getViolationsForPageJson)]
+    pub fn get_violations_for_page_json(&self, page: u32) -> String {
+        serde_json::to_string(&self.violations_for_page(page)).unwrap_or_default()
+    }
+
     #[wasm_bindgen(js_name = clearViolations)]
     pub fn clear_violations(&mut self) {
         self.violations.clear();
@@ -189,6 +196,8 @@ mod tests {
             page: Some(1),
             text_snippet: Some("waives right to notice".to_string()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         }];
         panel.set_violations(violations);
         assert_eq!(panel.violations().len(), 1);
@@ -205,6 +214,8 @@ mod tests {
                 page: Some(1),
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             },
             Violation {
                 statute: "83.49".to_string(),
@@ -213,6 +224,8 @@ mod tests {
                 page: Some(2),
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             },
         ];
         panel.set_violations(violations);
@@ -235,6 +248,8 @@ mod tests {
                 page: Some(1),
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             },
             Violation {
                 statute: "83.49".to_string(),
@@ -243,6 +258,8 @@ mod tests {
                 page: Some(2),
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             },
         ];
         panel.set_violations(violations);
@@ -262,6 +279,8 @@ mod tests {
             page: Some(1),
             text_snippet: Some("snippet".to_string()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         }];
         panel.set_violations(violations);
 
@@ -321,6 +340,8 @@ mod tests {
             page: Some(1),
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         }];
         panel.set_violations(violations);
 
@@ -106,6 +106,25 @@ pub async fn extract_text_with_strategy(data: &[u8], strategy: &str) -> Result<S
     Ok(text)
 }
 
+/// Extract a PDF as a `LeaseDocument` JSON for the compliance checks
+///
+/// Unlike the text-only entry points this keeps one `text_content` entry
+/// per page and the positioned text of each page, so reported violations
+/// carry their page and, where the backend provides positions, the boxes to
+/// highlight.
+#[wasm_bindgen]
+pub async fn extract_lease_document(data: &[u8], filename: &str) -> Result<String, JsValue> {
+    let router = ExtractionRouter::new(ExtractionConfig::default());
+    let result = router
+        .extract(data)
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Extraction failed: {}", e)))?;
+
+    let document = result.to_lease_document("current-doc", filename, js_sys::Date::now() as u64);
+    serde_json::to_string(&document)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
 /// Get extraction metadata (backend used, timing, etc.)
 #[wasm_bindgen]
 pub async fn extract_with_metadata(data: &[u8], strategy: &str) -> Result<JsValue, JsValue> {
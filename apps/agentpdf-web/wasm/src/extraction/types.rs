@@ -1,6 +1,7 @@
 //! Common types for PDF extraction
 
 use serde::{Deserialize, Serialize};
+use shared_types::{BoundingBox, LeaseDocument, PageLayout, PositionedRun};
 use std::fmt;
 
 /// A single text item with spatial information
@@ -25,6 +26,36 @@ pub struct PageContent {
     pub height: f64,
 }
 
+impl PageContent {
+    /// Positioned runs for highlighting, with offsets into `raw_text`.
+    ///
+    /// Items are found in `raw_text` in order, so the separators added
+    /// between them do not shift later offsets. Items that cannot be found
+    /// are skipped.
+    pub fn layout(&self) -> PageLayout {
+        let mut runs = Vec::with_capacity(self.text_items.len());
+        let mut cursor = 0;
+        for item in &self.text_items {
+            let Some(found) = self.raw_text[cursor..].find(item.text.as_str()) else {
+                continue;
+            };
+            let start = cursor + found;
+            cursor = start + item.text.len();
+            runs.push(PositionedRun {
+                start_offset: start,
+                end_offset: cursor,
+                bbox: BoundingBox {
+                    x: item.x,
+                    y: item.y,
+                    width: item.width,
+                    height: item.height,
+                },
+            });
+        }
+        PageLayout { runs }
+    }
+}
+
 /// Complete extraction result
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ExtractionResult {
@@ -67,6 +98,19 @@ impl ExtractionResult {
     pub fn add_warning(&mut self, warning: String) {
         self.warnings.push(warning);
     }
+
+    /// The pages as a document for the compliance checks, with one text and
+    /// one layout per page so violations are reported on their page
+    pub fn to_lease_document(&self, id: &str, filename: &str, created_at: u64) -> LeaseDocument {
+        LeaseDocument {
+            id: id.to_string(),
+            filename: filename.to_string(),
+            pages: self.pages.len() as u32,
+            text_content: self.pages.iter().map(|p| p.raw_text.clone()).collect(),
+            created_at,
+            layout: self.pages.iter().map(PageContent::layout).collect(),
+        }
+    }
 }
 
 /// Extraction errors
@@ -274,4 +318,36 @@ mod tests {
         assert!(!result.is_valid);
         assert_eq!(result.garbage_ratio, 1.0);
     }
+
+    #[test]
+    fn test_page_layout_offsets() {
+        let item = |text: &str, x: f64| TextItem {
+            text: text.to_string(),
+            x,
+            y: 700.0,
+            width: 30.0,
+            height: 12.0,
+            font_name: None,
+            font_size: Some(12.0),
+        };
+        let page = PageContent {
+            page_number: 1,
+            text_items: vec![item("Tenant", 72.0), item("waives", 110.0)],
+            raw_text: "Tenant waives".to_string(),
+            width: 612.0,
+            height: 792.0,
+        };
+
+        let layout = page.layout();
+        assert_eq!(layout.runs.len(), 2);
+        assert_eq!(layout.runs[1].start_offset, 7);
+        assert_eq!(layout.runs[1].end_offset, 13);
+        assert_eq!(layout.runs[1].bbox.x, 110.0);
+
+        let result = ExtractionResult::new("browser").with_pages(vec![page]);
+        let document = result.to_lease_document("doc", "lease.pdf", 0);
+        assert_eq!(document.pages, 1);
+        assert_eq!(document.text_content, vec!["Tenant waives"]);
+        assert_eq!(document.layout, vec![layout]);
+    }
 }
@@ -1,5 +1,6 @@
-use compliance_engine::{ComplianceEngine, DocumentType, Jurisdiction, State};
+use compliance_engine::{DocumentType, Jurisdiction, NaiveDate, RulePack, State};
 use shared_types::LeaseDocument;
+use typst_engine::verifier::rules::compliance_engine;
 use wasm_bindgen::prelude::*;
 
 // Export modules
@@ -44,7 +45,16 @@ pub fn check_compliance_for_state_wasm(
     state_code: &str,
     year_built: Option<u32>,
 ) -> Result<String, JsValue> {
-    check_compliance_with_zip_wasm(document_json, state_code, year_built, None)
+    check_compliance_with_zip_wasm(document_json, state_code, year_built, None, None)
+}
+
+/// Parse an optional "YYYY-MM-DD" check date, defaulting to today
+fn parse_as_of(as_of: Option<String>) -> Result<NaiveDate, JsValue> {
+    match as_of {
+        Some(ref date) if !date.is_empty() => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|e| JsValue::from_str(&format!("Invalid as-of date '{}': {}", date, e))),
+        _ => Ok(chrono::Utc::now().date_naive()),
+    }
 }
 
 /// WASM entry point for compliance checking with state and ZIP code
@@ -53,12 +63,16 @@ pub fn check_compliance_for_state_wasm(
 /// - Chicago RLTO for 606xx ZIPs
 /// - NYC rent stabilization for 100xx/110xx ZIPs
 /// - SF/LA rent control for California ZIPs
+///
+/// `as_of` ("YYYY-MM-DD", default today) skips rules not yet in force on that
+/// date, e.g. the date an older lease was signed.
 #[wasm_bindgen]
 pub fn check_compliance_with_zip_wasm(
     document_json: &str,
     state_code: &str,
     year_built: Option<u32>,
     zip_code: Option<String>,
+    as_of: Option<String>,
 ) -> Result<String, JsValue> {
     let document: LeaseDocument = serde_json::from_str(document_json)
         .map_err(|e| JsValue::from_str(&format!("Failed to parse document: {}", e)))?;
@@ -72,8 +86,9 @@ pub fn check_compliance_with_zip_wasm(
         _ => Jurisdiction::new(state),
     };
 
-    let engine = ComplianceEngine::new();
-    let report = engine.check_compliance(&jurisdiction, &document, year_built);
+    let as_of = parse_as_of(as_of)?;
+    let engine = compliance_engine();
+    let report = engine.check_compliance(&jurisdiction, &document, year_built, as_of);
 
     serde_json::to_string(&report)
         .map_err(|e| JsValue::from_str(&format!("Failed to serialize report: {}", e)))
@@ -82,7 +97,7 @@ pub fn check_compliance_with_zip_wasm(
 /// Get list of supported states with statute citations
 #[wasm_bindgen]
 pub fn get_supported_states() -> Result<String, JsValue> {
-    let engine = ComplianceEngine::new();
+    let engine = compliance_engine();
     let states: Vec<_> = engine
         .supported_states()
         .iter()
@@ -91,6 +106,7 @@ pub fn get_supported_states() -> Result<String, JsValue> {
                 "code": format!("{:?}", s),
                 "name": s.name(),
                 "implemented": s.is_implemented(),
+                "coverage": engine.coverage(*s, DocumentType::Lease),
                 "statutes": s.statute_citation()
             })
         })
@@ -109,8 +125,9 @@ pub fn get_supported_states() -> Result<String, JsValue> {
 /// # Arguments
 /// * `document_json` - JSON string of LeaseDocument (reused for any document type)
 /// * `state_code` - Two-letter state code (e.g., "FL")
-/// * `doc_type` - Document type: "purchase", "listing", "escalation", or "auto"
+/// * `doc_type` - Document type: "purchase", "listing", "escalation", "commercial", or "auto"
 /// * `year_built` - Optional year the property was built (for lead paint)
+/// * `as_of` - Optional "YYYY-MM-DD" date to check against (default today)
 ///
 /// # Returns
 /// JSON string of ComplianceReport
@@ -120,6 +137,7 @@ pub fn check_realestate_compliance_wasm(
     state_code: &str,
     doc_type: &str,
     year_built: Option<u32>,
+    as_of: Option<String>,
 ) -> Result<String, JsValue> {
     let document: LeaseDocument = serde_json::from_str(document_json)
         .map_err(|e| JsValue::from_str(&format!("Failed to parse document: {}", e)))?;
@@ -128,7 +146,8 @@ pub fn check_realestate_compliance_wasm(
         .ok_or_else(|| JsValue::from_str(&format!("Unsupported state: {}", state_code)))?;
 
     let jurisdiction = Jurisdiction::new(state);
-    let engine = ComplianceEngine::new();
+    let as_of = parse_as_of(as_of)?;
+    let engine = compliance_engine();
 
     // Parse document type
     let document_type = match doc_type.to_lowercase().as_str() {
@@ -136,6 +155,7 @@ pub fn check_realestate_compliance_wasm(
         "listing" | "listing_agreement" => DocumentType::ListingAgreement,
         "escalation" | "escalation_addendum" => DocumentType::EscalationAddendum,
         "lease" => DocumentType::Lease,
+        "commercial" | "commercial_lease" => DocumentType::CommercialLease,
         _ => {
             // Auto-detect document type (includes "auto" and any unrecognized type)
             let full_text = document.text_content.join("\n");
@@ -144,10 +164,14 @@ pub fn check_realestate_compliance_wasm(
     };
 
     let report = match document_type {
-        DocumentType::Lease => engine.check_compliance(&jurisdiction, &document, year_built),
-        _ => {
-            engine.check_realestate_compliance(&jurisdiction, &document, document_type, year_built)
-        }
+        DocumentType::Lease => engine.check_compliance(&jurisdiction, &document, year_built, as_of),
+        _ => engine.check_realestate_compliance(
+            &jurisdiction,
+            &document,
+            document_type,
+            year_built,
+            as_of,
+        ),
     };
 
     serde_json::to_string(&report)
@@ -157,22 +181,23 @@ pub fn check_realestate_compliance_wasm(
 /// Auto-detect document type and check appropriate compliance
 ///
 /// This function automatically determines if the document is a lease,
-/// purchase contract, listing agreement, or escalation addendum.
+/// commercial lease, purchase contract, listing agreement, or escalation
+/// addendum.
 #[wasm_bindgen]
 pub fn check_compliance_auto_detect_wasm(
     document_json: &str,
     state_code: &str,
     year_built: Option<u32>,
 ) -> Result<String, JsValue> {
-    check_realestate_compliance_wasm(document_json, state_code, "auto", year_built)
+    check_realestate_compliance_wasm(document_json, state_code, "auto", year_built, None)
 }
 
 /// Detect the type of real estate document
 ///
-/// Returns one of: "lease", "purchase", "listing", "escalation"
+/// Returns one of: "lease", "purchase", "listing", "escalation", "commercial"
 #[wasm_bindgen]
 pub fn detect_document_type_wasm(text: &str) -> String {
-    let engine = ComplianceEngine::new();
+    let engine = compliance_engine();
     let doc_type = engine.detect_document_type(text);
 
     match doc_type {
@@ -180,6 +205,7 @@ pub fn detect_document_type_wasm(text: &str) -> String {
         DocumentType::RealEstatePurchase => "purchase".to_string(),
         DocumentType::ListingAgreement => "listing".to_string(),
         DocumentType::EscalationAddendum => "escalation".to_string(),
+        DocumentType::CommercialLease => "commercial".to_string(),
     }
 }
 
@@ -202,19 +228,58 @@ pub fn get_realestate_statutes(state_code: &str) -> Result<String, JsValue> {
 // Template Rendering (Local-First - runs entirely in browser)
 // ============================================================================
 
+use std::cell::RefCell;
+
 use typst_engine::compiler::output::OutputFormat;
-use typst_engine::{compile_document_sync, RenderRequest};
+use typst_engine::compiler::ServerError;
+use typst_engine::{compile_document_sync, CompilerSession, RenderRequest, RenderResponse};
+
+thread_local! {
+    /// Compiler kept warm between template renders, so re-rendering a form
+    /// after each edit only recompiles what the edit changed
+    static SESSION: RefCell<CompilerSession> = RefCell::new(CompilerSession::new());
+}
+
+/// Render a request in the shared compiler session
+fn render_in_session(request: &RenderRequest) -> Result<RenderResponse, ServerError> {
+    SESSION.with(|session| session.borrow_mut().render(request))
+}
 
 /// Render a template to PDF bytes (local-first, no server required)
 ///
 /// # Arguments
-/// * `template_name` - Name of embedded template ("invoice", "letter", "florida_lease")
+/// * `template_name` - Name of embedded template ("invoice", "letter", "florida_lease"),
+///   or of a registered template package, optionally with a version ("letterhead@1.2.0")
 /// * `inputs_json` - JSON object with template variables
 ///
 /// # Returns
 /// Base64-encoded PDF data on success
 #[wasm_bindgen]
 pub fn render_template(template_name: &str, inputs_json: &str) -> Result<String, JsValue> {
+    render_template_pdf(template_name, inputs_json, false)
+}
+
+/// Render a template to a tagged PDF for screen readers: structure tree,
+/// reading order, alt text, language and title. The PDF is not PDF/UA
+/// conformant
+///
+/// Takes the same arguments as `render_template`.
+///
+/// # Returns
+/// Base64-encoded PDF data on success
+#[wasm_bindgen]
+pub fn render_template_accessible(
+    template_name: &str,
+    inputs_json: &str,
+) -> Result<String, JsValue> {
+    render_template_pdf(template_name, inputs_json, true)
+}
+
+fn render_template_pdf(
+    template_name: &str,
+    inputs_json: &str,
+    tagged: bool,
+) -> Result<String, JsValue> {
     console_error_panic_hook::set_once();
 
     let inputs: std::collections::HashMap<String, serde_json::Value> =
@@ -227,16 +292,22 @@ pub fn render_template(template_name: &str, inputs_json: &str) -> Result<String,
         assets: std::collections::HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        tagged,
+        ..Default::default()
     };
 
-    let response = compile_document_sync(request)
+    let response = render_in_session(&request)
         .map_err(|e| JsValue::from_str(&format!("Compilation failed: {:?}", e)))?;
 
     match response.artifact {
         Some(artifact) => Ok(artifact.data_base64),
         None => {
-            let error_msgs: Vec<String> =
-                response.errors.iter().map(|e| e.message.clone()).collect();
+            let error_msgs: Vec<String> = response
+                .input_errors
+                .iter()
+                .map(|e| e.to_string())
+                .chain(response.errors.iter().map(|e| e.message.clone()))
+                .collect();
             Err(JsValue::from_str(&format!(
                 "Template errors: {}",
                 error_msgs.join("; ")
@@ -245,6 +316,138 @@ pub fn render_template(template_name: &str, inputs_json: &str) -> Result<String,
     }
 }
 
+/// Render PNG thumbnails of a template's pages, for a page picker
+///
+/// # Arguments
+/// * `template_name` - Name of the template, as for `render_template`
+/// * `inputs_json` - JSON object with template variables
+/// * `max_width` - Maximum thumbnail width in pixels
+/// * `pages` - Optional one-indexed page range ("1-3,5"); all pages when empty
+///
+/// # Returns
+/// JSON array of base64-encoded PNGs, one per page
+#[wasm_bindgen]
+pub fn render_template_thumbnails(
+    template_name: &str,
+    inputs_json: &str,
+    max_width: u32,
+    pages: Option<String>,
+) -> Result<String, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let inputs: std::collections::HashMap<String, serde_json::Value> =
+        serde_json::from_str(inputs_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse inputs: {}", e)))?;
+    let pages = pages
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| p.parse())
+        .transpose()
+        .map_err(|e: String| JsValue::from_str(&e))?;
+
+    let request = RenderRequest {
+        source: format!("typst://templates/{}", template_name),
+        inputs,
+        format: OutputFormat::Png,
+        pages,
+        thumbnail_width: Some(max_width),
+        ..Default::default()
+    };
+
+    let response = compile_document_sync(request)
+        .map_err(|e| JsValue::from_str(&format!("Compilation failed: {:?}", e)))?;
+    if response.pages.is_empty() {
+        let error_msgs: Vec<String> = response
+            .input_errors
+            .iter()
+            .map(|e| e.to_string())
+            .chain(response.errors.iter().map(|e| e.message.clone()))
+            .collect();
+        return Err(JsValue::from_str(&format!(
+            "Template errors: {}",
+            error_msgs.join("; ")
+        )));
+    }
+
+    let thumbnails: Vec<String> = response.pages.into_iter().map(|p| p.data_base64).collect();
+    serde_json::to_string(&thumbnails)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize thumbnails: {}", e)))
+}
+
+/// Re-render PNG previews of a template after its inputs changed
+///
+/// Renders in a compiler session kept warm between calls, so only what the
+/// edit touched is recompiled and identical inputs are served from cache.
+/// Only the pages that differ from the previous preview are returned.
+///
+/// # Arguments
+/// * `template_name` - Name of the template, as for `render_template`
+/// * `inputs_json` - JSON object with template variables
+/// * `max_width` - Maximum preview width in pixels
+/// * `pages` - Optional one-indexed page range ("1-3,5"); all pages when empty
+///
+/// # Returns
+/// JSON object with `page_count`, `changed_pages` (one-indexed) and `pages`,
+/// the base64-encoded PNG of each changed page keyed by page number
+#[wasm_bindgen]
+pub fn preview_template(
+    template_name: &str,
+    inputs_json: &str,
+    max_width: u32,
+    pages: Option<String>,
+) -> Result<String, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let inputs: std::collections::HashMap<String, serde_json::Value> =
+        serde_json::from_str(inputs_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse inputs: {}", e)))?;
+    let pages = pages
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| p.parse())
+        .transpose()
+        .map_err(|e: String| JsValue::from_str(&e))?;
+
+    let request = RenderRequest {
+        source: format!("typst://templates/{}", template_name),
+        inputs,
+        format: OutputFormat::Png,
+        pages,
+        thumbnail_width: Some(max_width),
+        ..Default::default()
+    };
+
+    let response = render_in_session(&request)
+        .map_err(|e| JsValue::from_str(&format!("Compilation failed: {:?}", e)))?;
+    if response.pages.is_empty() {
+        let error_msgs: Vec<String> = response
+            .input_errors
+            .iter()
+            .map(|e| e.to_string())
+            .chain(response.errors.iter().map(|e| e.message.clone()))
+            .collect();
+        return Err(JsValue::from_str(&format!(
+            "Template errors: {}",
+            error_msgs.join("; ")
+        )));
+    }
+
+    let changed_pages = response.changed_pages.unwrap_or_default();
+    let page_count = response.pages[0].page_count;
+    let previews: serde_json::Map<String, serde_json::Value> = response
+        .pages
+        .into_iter()
+        .filter_map(|p| Some((p.page?, p.data_base64)))
+        .filter(|(page, _)| changed_pages.contains(page))
+        .map(|(page, data)| (page.to_string(), serde_json::Value::String(data)))
+        .collect();
+
+    serde_json::to_string(&serde_json::json!({
+        "page_count": page_count,
+        "changed_pages": changed_pages,
+        "pages": previews,
+    }))
+    .map_err(|e| JsValue::from_str(&format!("Failed to serialize previews: {}", e)))
+}
+
 /// Render raw Typst source to PDF bytes
 ///
 /// # Arguments
@@ -267,6 +470,7 @@ pub fn render_typst(source: &str, inputs_json: &str) -> Result<String, JsValue>
         assets: std::collections::HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     let response = compile_document_sync(request)
@@ -285,6 +489,22 @@ pub fn render_typst(source: &str, inputs_json: &str) -> Result<String, JsValue>
     }
 }
 
+/// Check a PDF for accessibility problems: untagged content, missing alt
+/// text, skipped heading levels, missing language or title
+///
+/// # Returns
+/// JSON accessibility report with an `issues` array (empty if none found)
+#[wasm_bindgen]
+pub fn check_pdf_accessibility(pdf_bytes: &[u8]) -> Result<String, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let report = typst_engine::check_accessibility(pdf_bytes)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    serde_json::to_string(&report)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize report: {}", e)))
+}
+
 /// List available embedded templates
 ///
 /// # Returns
@@ -297,6 +517,45 @@ pub fn list_templates() -> Result<String, JsValue> {
         .map_err(|e| JsValue::from_str(&format!("Failed to serialize templates: {}", e)))
 }
 
+/// Load a template package (zip with a template.toml manifest) for this session
+///
+/// # Returns
+/// JSON template info of the registered package, including its versioned URI
+#[wasm_bindgen]
+pub fn register_template_package(zip_data: &[u8]) -> Result<String, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let package = typst_engine::templates::TemplatePackage::from_zip(zip_data)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let info = typst_engine::templates::register_package(package)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    serde_json::to_string(&info)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize template: {}", e)))
+}
+
+/// Load a compliance rule pack for this session
+///
+/// The pack replaces the built-in rules of its state in every later
+/// compliance check, and replaces any pack loaded earlier for that state.
+///
+/// # Arguments
+/// * `source` - Rule pack text
+/// * `format` - "toml", "yaml" or "yml"
+///
+/// # Returns
+/// JSON pack info (`id`, `state`, `version`, `title`)
+#[wasm_bindgen]
+pub fn load_rule_pack(source: &str, format: &str) -> Result<String, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let pack = RulePack::parse(source, format).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let info = typst_engine::verifier::rules::register_rule_pack(pack);
+
+    serde_json::to_string(&info)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize rule pack: {}", e)))
+}
+
 /// Validate Typst syntax without rendering
 ///
 /// # Returns
@@ -319,6 +578,82 @@ pub fn validate_typst_syntax(source: &str) -> Result<String, JsValue> {
         .map_err(|e| JsValue::from_str(&format!("Failed to serialize errors: {}", e)))
 }
 
+// ============================================================================
+// Compliance Report Export
+// ============================================================================
+
+/// Export a compliance report
+///
+/// # Arguments
+/// * `report_json` - ComplianceReport JSON from one of the check functions
+/// * `document_json` - The checked LeaseDocument JSON, used to quote page excerpts
+/// * `format` - "pdf", "html" or "sarif"
+/// * `baseline_json` - Earlier SARIF output; matching findings are marked
+///   `unchanged` and suppressed (sarif only)
+///
+/// # Returns
+/// Base64-encoded PDF data for "pdf", otherwise the HTML or SARIF text
+#[wasm_bindgen]
+pub fn export_compliance_report(
+    report_json: &str,
+    document_json: Option<String>,
+    format: &str,
+    baseline_json: Option<String>,
+) -> Result<String, JsValue> {
+    use compliance_engine::report::{self, sarif, ReportOptions, ReportView};
+
+    console_error_panic_hook::set_once();
+
+    let report: shared_types::ComplianceReport = serde_json::from_str(report_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse report: {}", e)))?;
+    let document: Option<LeaseDocument> = document_json
+        .filter(|json| !json.trim().is_empty())
+        .map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse document: {}", e)))?;
+    let view = ReportView::new(&report, document.as_ref(), &ReportOptions::default());
+
+    match format {
+        "html" => Ok(report::html::render(&view)),
+        "sarif" => {
+            let baseline = baseline_json
+                .map(|json| sarif::Baseline::from_sarif(&json))
+                .transpose()
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse baseline: {}", e)))?;
+            serde_json::to_string_pretty(&sarif::render(&view, baseline.as_ref()))
+                .map_err(|e| JsValue::from_str(&format!("Failed to serialize SARIF: {}", e)))
+        }
+        "pdf" => {
+            let request = RenderRequest {
+                source: "typst://templates/compliance_report".to_string(),
+                inputs: report::typst_inputs(&view),
+                assets: std::collections::HashMap::new(),
+                format: OutputFormat::Pdf,
+                ppi: None,
+                ..Default::default()
+            };
+            let response = compile_document_sync(request)
+                .map_err(|e| JsValue::from_str(&format!("Compilation failed: {:?}", e)))?;
+
+            match response.artifact {
+                Some(artifact) => Ok(artifact.data_base64),
+                None => {
+                    let error_msgs: Vec<String> =
+                        response.errors.iter().map(|e| e.message.clone()).collect();
+                    Err(JsValue::from_str(&format!(
+                        "Template errors: {}",
+                        error_msgs.join("; ")
+                    )))
+                }
+            }
+        }
+        _ => Err(JsValue::from_str(&format!(
+            "Unsupported report format: {}",
+            format
+        ))),
+    }
+}
+
 // ============================================================================
 // Tests for Template Rendering
 // ============================================================================
@@ -345,6 +680,7 @@ mod template_tests {
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -370,8 +706,9 @@ mod template_tests {
     fn test_render_template_invoice() {
         let inputs = serde_json::json!({
             "invoice_number": "INV-001",
+            "company_name": "Widgets Inc",
             "client_name": "Acme Corp",
-            "total": "1000.00"
+            "items": [{ "description": "Consulting", "qty": 4, "price": "$250.00" }]
         });
 
         let request = RenderRequest {
@@ -380,6 +717,7 @@ mod template_tests {
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -393,6 +731,28 @@ mod template_tests {
         assert!(response.artifact.is_some(), "Should produce PDF artifact");
     }
 
+    #[test]
+    fn test_render_template_compliance_report() {
+        let report = serde_json::json!({
+            "document_id": "lease-1",
+            "violations": [{
+                "statute": "F.S. § 83.47(1)(a)",
+                "severity": "Critical",
+                "message": "Waiver of notice",
+                "page": 1,
+                "text_snippet": "...tenant waives all rights...",
+                "text_position": null
+            }],
+            "checked_at": 1750000000
+        });
+        let pdf = super::export_compliance_report(&report.to_string(), None, "pdf", None);
+        assert!(pdf.is_ok(), "Compliance report should compile");
+
+        let sarif =
+            super::export_compliance_report(&report.to_string(), None, "sarif", None).unwrap();
+        assert!(sarif.contains("\"ruleId\": \"fs-83.47-1-a\""));
+    }
+
     #[test]
     fn test_render_template_florida_lease() {
         let inputs = serde_json::json!({
@@ -410,6 +770,7 @@ mod template_tests {
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -438,12 +799,126 @@ mod template_tests {
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
         assert!(result.is_err(), "Nonexistent template should fail");
     }
 
+    #[test]
+    fn test_render_template_invoice_missing_items() {
+        let inputs = serde_json::json!({
+            "company_name": "Widgets Inc",
+            "client_name": "Acme Corp",
+            "due_date": "next week"
+        });
+
+        let request = RenderRequest {
+            source: "typst://templates/invoice".to_string(),
+            inputs: serde_json::from_value(inputs).unwrap(),
+            assets: std::collections::HashMap::new(),
+            format: OutputFormat::Pdf,
+            ppi: None,
+            ..Default::default()
+        };
+
+        let response = compile_document_sync(request).unwrap();
+        assert!(
+            response.artifact.is_none(),
+            "Invalid inputs should not render"
+        );
+        let fields: Vec<&str> = response
+            .input_errors
+            .iter()
+            .map(|e| e.field.as_str())
+            .collect();
+        assert_eq!(fields, ["items", "due_date"]);
+    }
+
+    #[test]
+    fn test_render_template_thumbnails() {
+        let inputs = r#"{"sender_name": "Test", "recipient_name": "User", "body": "Hello"}"#;
+        let thumbnails = super::render_template_thumbnails("letter", inputs, 120, None).unwrap();
+        let thumbnails: Vec<String> = serde_json::from_str(&thumbnails).unwrap();
+        assert!(!thumbnails.is_empty());
+    }
+
+    #[test]
+    fn test_render_template_accessible() {
+        use base64::Engine;
+
+        let inputs = r#"{"landlord_name": "Sunshine Properties LLC", "tenant_name": "Jordan Rivera",
+            "property_address": "100 Main Street", "monthly_rent": "1500",
+            "security_deposit": "1500", "lease_start": "January 1, 2026",
+            "lease_end": "December 31, 2026"}"#;
+        let pdf = super::render_template_accessible("florida_lease", inputs).unwrap();
+        let pdf = base64::engine::general_purpose::STANDARD
+            .decode(pdf)
+            .unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&super::check_pdf_accessibility(&pdf).unwrap()).unwrap();
+        assert_eq!(report["tagged"], true);
+        assert_eq!(report["issues"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_preview_template_returns_changed_pages() {
+        let inputs = |tenant: &str| {
+            serde_json::json!({
+                "landlord_name": "Sunshine Properties LLC", "tenant_name": tenant,
+                "property_address": "100 Main Street", "monthly_rent": "1500",
+                "security_deposit": "1500", "lease_start": "January 1, 2026",
+                "lease_end": "December 31, 2026"
+            })
+            .to_string()
+        };
+        let preview = |tenant: &str| -> serde_json::Value {
+            let json = super::preview_template("florida_lease", &inputs(tenant), 200, None);
+            serde_json::from_str(&json.unwrap()).unwrap()
+        };
+
+        let first = preview("Jordan Rivera");
+        let page_count = first["page_count"].as_u64().unwrap() as usize;
+        assert_eq!(first["changed_pages"].as_array().unwrap().len(), page_count);
+        assert_eq!(first["pages"].as_object().unwrap().len(), page_count);
+
+        let edited = preview("Jordan Rivers");
+        let changed = edited["changed_pages"].as_array().unwrap();
+        assert!(!changed.is_empty() && changed.len() < page_count);
+        assert_eq!(edited["pages"].as_object().unwrap().len(), changed.len());
+
+        let unchanged = preview("Jordan Rivers");
+        assert_eq!(unchanged["changed_pages"], serde_json::json!([]));
+        assert_eq!(unchanged["pages"], serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_downloads_do_not_reset_the_preview() {
+        let inputs = |tenant: &str| {
+            serde_json::json!({
+                "landlord_name": "Gulf Coast Rentals LLC", "tenant_name": tenant,
+                "property_address": "200 Bay Drive", "monthly_rent": "1800",
+                "security_deposit": "1800", "lease_start": "March 1, 2026",
+                "lease_end": "February 28, 2027"
+            })
+            .to_string()
+        };
+        let preview = || -> serde_json::Value {
+            let json = super::preview_template("florida_lease", &inputs("Avery Chen"), 200, None);
+            serde_json::from_str(&json.unwrap()).unwrap()
+        };
+
+        preview();
+        // Downloading a PDF of other inputs goes through the same session
+        super::render_template("florida_lease", &inputs("Morgan Diaz")).unwrap();
+
+        let again = preview();
+        assert_eq!(again["changed_pages"], serde_json::json!([]));
+        assert_eq!(again["pages"], serde_json::json!({}));
+    }
+
     // ===== render_typst tests =====
 
     #[test]
@@ -454,6 +929,7 @@ mod template_tests {
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -480,6 +956,7 @@ Hello, #name!"#
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -498,6 +975,7 @@ Hello, #name!"#
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -581,6 +1059,7 @@ Some *bold* and _italic_ text.
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request).unwrap();
@@ -616,6 +1095,7 @@ Some *bold* and _italic_ text.
             assets: std::collections::HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let response = compile_document_sync(request).unwrap();
@@ -652,6 +1132,7 @@ mod template_proptests {
                 assets: std::collections::HashMap::new(),
                 format: OutputFormat::Pdf,
                 ppi: None,
+                ..Default::default()
             };
 
             // Should not panic, even if it returns an error
@@ -667,6 +1148,7 @@ mod template_proptests {
                 assets: std::collections::HashMap::new(),
                 format: OutputFormat::Pdf,
                 ppi: None,
+                ..Default::default()
             };
 
             let result = compile_document_sync(request);
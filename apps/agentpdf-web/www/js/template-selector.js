@@ -22,9 +22,18 @@ const TemplateSelector = {
         modalId: 'template-modal',
 
         // Whether WASM rendering is available
-        wasmAvailable: false
+        wasmAvailable: false,
+
+        // Width of the live preview pages, in pixels
+        previewWidth: 300,
+
+        // Pause after the last edit before the preview is re-rendered
+        previewDelayMs: 250
     },
 
+    // Preview page images (data URLs) of each template, by page index
+    _previews: {},
+
     /**
      * Check if WASM template rendering is available
      * @returns {boolean}
@@ -214,7 +223,7 @@ const TemplateSelector = {
             reqSection.innerHTML = '<h4>Required Fields</h4>';
 
             reqFields.forEach(field => {
-                reqSection.appendChild(this._createFormField(field, true));
+                reqSection.appendChild(this._createFormField(field, true, this._schemaField(template, field)));
             });
             form.appendChild(reqSection);
         }
@@ -226,7 +235,7 @@ const TemplateSelector = {
             optSection.innerHTML = `<summary>Optional Fields (${optFields.length})</summary>`;
 
             optFields.forEach(field => {
-                optSection.appendChild(this._createFormField(field, false));
+                optSection.appendChild(this._createFormField(field, false, this._schemaField(template, field)));
             });
             form.appendChild(optSection);
         }
@@ -238,14 +247,23 @@ const TemplateSelector = {
         submitBtn.textContent = 'Generate Document';
         form.appendChild(submitBtn);
 
+        // Live preview, re-rendered a moment after each edit
+        const preview = modal.querySelector('.template-preview');
+        let previewTimer = null;
+        form.oninput = form.onchange = () => {
+            clearTimeout(previewTimer);
+            previewTimer = setTimeout(
+                () => this._updatePreview(template, form, preview),
+                this.config.previewDelayMs
+            );
+        };
+        this._updatePreview(template, form, preview);
+
         // Handle form submission
         form.onsubmit = async (e) => {
             e.preventDefault();
-            const formData = new FormData(form);
-            const inputs = {};
-            formData.forEach((value, key) => {
-                if (value) inputs[key] = value;
-            });
+            const inputs = this._collectInputs(form);
+            this._clearFieldErrors(form);
 
             submitBtn.disabled = true;
             submitBtn.textContent = 'Generating...';
@@ -255,6 +273,8 @@ const TemplateSelector = {
                 if (result.success && result.data) {
                     this.hideFormModal();
                     onSubmit(result.data, template.name);
+                } else if (result.input_errors && result.input_errors.length > 0) {
+                    this._showFieldErrors(form, result.input_errors);
                 } else {
                     alert('Failed to generate: ' + (result.error || 'Unknown error'));
                 }
@@ -313,8 +333,12 @@ const TemplateSelector = {
                     <h3 class="modal-title">Fill Template</h3>
                     <button class="modal-close" onclick="TemplateSelector.hideFormModal()">&times;</button>
                 </div>
-                <div class="modal-body">
+                <div class="modal-body template-form-body">
                     <form class="template-form"></form>
+                    <aside class="template-preview" aria-label="Preview">
+                        <div class="preview-status" role="status"></div>
+                        <div class="preview-pages"></div>
+                    </aside>
                 </div>
             </div>
         `;
@@ -342,9 +366,18 @@ const TemplateSelector = {
         return card;
     },
 
-    _createFormField(fieldName, required) {
+    /**
+     * Look up a field in the template's input schema, if it has one
+     */
+    _schemaField(template, fieldName) {
+        const fields = (template.schema && template.schema.fields) || [];
+        return fields.find(f => f.name === fieldName) || null;
+    },
+
+    _createFormField(fieldName, required, schema = null) {
         const div = document.createElement('div');
         div.className = 'form-field';
+        div.dataset.field = fieldName;
 
         const label = document.createElement('label');
         const friendlyLabel = this._getFriendlyLabel(fieldName);
@@ -357,32 +390,242 @@ const TemplateSelector = {
         if (this._isTristateFloodField(fieldName)) {
             div.appendChild(label);
             div.appendChild(this._createTristateRadio(fieldName));
-            return div;
+            return this._withHelp(div, schema);
         }
 
         // Check if this is a simple boolean field (lead paint, etc)
-        if (this._isBooleanField(fieldName)) {
+        if (schema ? schema.type === 'boolean' : this._isBooleanField(fieldName)) {
             div.appendChild(label);
             div.appendChild(this._createYesNoRadio(fieldName));
-            return div;
+            return this._withHelp(div, schema);
         }
 
         // NOTE: email_consent is NOT shown in template form
         // Per HB 615, tenant must actively consent during SIGNATURE CEREMONY
         // The template generates unchecked boxes that tenant fills during signing
 
-        const input = document.createElement('input');
-        input.type = this._getInputType(fieldName);
+        if (schema && schema.type === 'array' && schema.items && schema.items.type === 'object') {
+            div.appendChild(label);
+            div.appendChild(this._createLineItems(fieldName, schema.items));
+            return this._withHelp(div, schema);
+        }
+
+        const input = schema && schema.enum
+            ? this._createSelect(fieldName, schema.enum, required)
+            : this._createInput(fieldName, schema);
         input.name = fieldName;
         input.id = fieldName;
         input.required = required;
-        input.placeholder = this._getPlaceholder(fieldName);
+        if (schema && schema.default !== undefined && schema.default !== null) {
+            input.value = String(schema.default);
+        }
 
         div.appendChild(label);
         div.appendChild(input);
+        return this._withHelp(div, schema);
+    },
+
+    _createInput(fieldName, schema) {
+        const input = document.createElement('input');
+        input.type = schema ? this._getSchemaInputType(schema) : this._getInputType(fieldName);
+        if (schema && schema.type === 'integer') input.step = '1';
+        if (schema && schema.format === 'currency') {
+            input.step = '0.01';
+            input.min = '0';
+        }
+        input.placeholder = this._getPlaceholder(fieldName);
+        return input;
+    },
+
+    _createSelect(fieldName, options, required) {
+        const select = document.createElement('select');
+        if (!required) {
+            select.appendChild(new Option('', ''));
+        }
+        options.forEach(value => {
+            select.appendChild(new Option(this._formatFieldName(value), value));
+        });
+        return select;
+    },
+
+    /**
+     * Input type for a schema field, from its format first and then its type
+     */
+    _getSchemaInputType(schema) {
+        switch (schema.format) {
+            case 'date': return 'date';
+            case 'email': return 'email';
+            case 'phone': return 'tel';
+        }
+        return schema.type === 'number' || schema.type === 'integer' ? 'number' : 'text';
+    },
+
+    _withHelp(div, schema) {
+        if (schema && schema.help) {
+            const help = document.createElement('small');
+            help.className = 'field-help';
+            help.textContent = schema.help;
+            div.appendChild(help);
+        }
         return div;
     },
 
+    /**
+     * Create an editable list of rows for an array of objects (invoice items)
+     */
+    _createLineItems(fieldName, itemSchema) {
+        const list = document.createElement('div');
+        list.className = 'line-items';
+        list.dataset.field = fieldName;
+
+        const rows = document.createElement('div');
+        rows.className = 'line-item-rows';
+        list.appendChild(rows);
+
+        const addRow = () => {
+            const index = rows.children.length;
+            const row = document.createElement('div');
+            row.className = 'line-item';
+            (itemSchema.properties || []).forEach(property => {
+                const input = this._createInput(property.name, property);
+                input.name = `${fieldName}.${index}.${property.name}`;
+                input.dataset.property = property.name;
+                input.placeholder = this._formatFieldName(property.name);
+                input.required = !!property.required;
+                if (property.default !== undefined && property.default !== null) {
+                    input.value = String(property.default);
+                }
+                row.appendChild(input);
+            });
+
+            const remove = document.createElement('button');
+            remove.type = 'button';
+            remove.className = 'btn btn-small';
+            remove.textContent = '\u00d7';
+            remove.onclick = () => {
+                row.remove();
+                // Removing a row changes the inputs without an input event
+                rows.dispatchEvent(new Event('input', { bubbles: true }));
+            };
+            row.appendChild(remove);
+            rows.appendChild(row);
+        };
+
+        const add = document.createElement('button');
+        add.type = 'button';
+        add.className = 'btn btn-secondary btn-small';
+        add.textContent = '+ Add item';
+        add.onclick = addRow;
+        list.appendChild(add);
+
+        addRow();
+        return list;
+    },
+
+    /**
+     * Collect the form's values as template inputs
+     */
+    _collectInputs(form) {
+        const inputs = {};
+        new FormData(form).forEach((value, key) => {
+            // Line item inputs are collected per row below
+            if (value && !key.includes('.')) inputs[key] = value;
+        });
+        form.querySelectorAll('.line-items').forEach(list => {
+            const rows = this._collectLineItems(list);
+            if (rows.length > 0) inputs[list.dataset.field] = rows;
+        });
+        return inputs;
+    },
+
+    /**
+     * Re-render the live preview for the form's current inputs
+     *
+     * preview_template renders in a compiler session kept warm in WASM and
+     * returns only the pages that changed since this template's previous
+     * preview, so only those images are replaced.
+     */
+    _updatePreview(template, form, preview) {
+        const wasm = window.wasmBindings || window.wasm;
+        if (!wasm || typeof wasm.preview_template !== 'function') {
+            preview.hidden = true;
+            return;
+        }
+        preview.hidden = false;
+
+        const name = template.id || template.name;
+        const pages = this._previews[name] || (this._previews[name] = []);
+        const status = preview.querySelector('.preview-status');
+        try {
+            const result = JSON.parse(wasm.preview_template(
+                name,
+                JSON.stringify(this._collectInputs(form)),
+                this.config.previewWidth
+            ));
+            pages.length = result.page_count;
+            Object.entries(result.pages).forEach(([page, data]) => {
+                pages[Number(page) - 1] = `data:image/png;base64,${data}`;
+            });
+            status.textContent = '';
+        } catch (err) {
+            // Keep showing the last preview until the inputs are valid again
+            status.textContent = String(err && err.message || err)
+                .replace(/^Template errors: /, 'Preview paused: ');
+        }
+
+        const container = preview.querySelector('.preview-pages');
+        while (container.children.length > pages.length) {
+            container.lastChild.remove();
+        }
+        pages.forEach((src, index) => {
+            let img = container.children[index];
+            if (!img) {
+                img = document.createElement('img');
+                img.alt = `Page ${index + 1} preview`;
+                container.appendChild(img);
+            }
+            img.hidden = !src;
+            if (src && img.src !== src) img.src = src;
+        });
+    },
+
+    _collectLineItems(list) {
+        const items = [];
+        list.querySelectorAll('.line-item').forEach(row => {
+            const item = {};
+            row.querySelectorAll('input').forEach(input => {
+                if (input.value) item[input.dataset.property] = input.value;
+            });
+            if (Object.keys(item).length > 0) items.push(item);
+        });
+        return items;
+    },
+
+    /**
+     * Show schema validation errors next to their fields
+     * @param {Array<{field: string, message: string}>} errors
+     */
+    _showFieldErrors(form, errors) {
+        errors.forEach(error => {
+            // Nested paths like items[1].price belong to the items field
+            const name = error.field.split(/[.[]/)[0];
+            const field = form.querySelector(`.form-field[data-field="${name}"]`);
+            if (!field) return;
+            const message = document.createElement('small');
+            message.className = 'field-error';
+            message.textContent = `${this._getFriendlyLabel(name)} ${error.message}` +
+                (error.field !== name ? ` (${error.field})` : '');
+            field.appendChild(message);
+            // Make sure errors on collapsed optional fields are visible
+            const details = field.closest('details');
+            if (details) details.open = true;
+        });
+    },
+
+    _clearFieldErrors(form) {
+        form.querySelectorAll('.field-error').forEach(el => el.remove());
+    },
+
     /**
      * Check if field is a tristate flood disclosure field
      * Per scrivener adherence, these MUST offer 3 neutral options
@@ -704,18 +947,29 @@ const StateSelector = {
 
     _createSelectorHTML() {
         const implementedStates = this.states.filter(s => s.implemented);
-        const comingSoon = this.states.filter(s => !s.implemented);
+        // Checked against core statutory limits only (coverage "core")
+        const coreStates = this.states.filter(s => !s.implemented && s.coverage?.status === 'core');
+        const comingSoon = this.states.filter(s => !s.implemented && s.coverage?.status !== 'core');
+        const option = s => `
+            <option value="${s.code}" ${s.code === this.currentState ? 'selected' : ''}>
+                ${s.name} (${s.statutes})
+            </option>
+        `;
 
         let optgroupsHTML = `
             <optgroup label="Available (${implementedStates.length} states)">
-                ${implementedStates.map(s => `
-                    <option value="${s.code}" ${s.code === this.currentState ? 'selected' : ''}>
-                        ${s.name} (${s.statutes})
-                    </option>
-                `).join('')}
+                ${implementedStates.map(option).join('')}
             </optgroup>
         `;
 
+        if (coreStates.length > 0) {
+            optgroupsHTML += `
+                <optgroup label="Core limits only (${coreStates.length} states)">
+                    ${coreStates.map(option).join('')}
+                </optgroup>
+            `;
+        }
+
         // Only show "Coming Soon" if there are states pending
         if (comingSoon.length > 0) {
             optgroupsHTML += `
@@ -863,7 +1117,7 @@ const StateSelector = {
             box-shadow: 0 4px 20px rgba(0, 0, 0, 0.2);
         }
         .modal-large {
-            max-width: 800px;
+            max-width: 1040px;
         }
         .modal-header {
             display: flex;
@@ -929,6 +1183,44 @@ const StateSelector = {
             flex-direction: column;
             gap: 16px;
         }
+        .template-form-body {
+            display: flex;
+            align-items: flex-start;
+            gap: 20px;
+        }
+        .template-form-body .template-form {
+            flex: 1;
+            min-width: 0;
+        }
+        .template-preview {
+            position: sticky;
+            top: 0;
+            width: 240px;
+            flex-shrink: 0;
+        }
+        .template-preview[hidden] {
+            display: none;
+        }
+        .preview-status {
+            font-size: 0.85rem;
+            color: #666;
+            margin-bottom: 8px;
+        }
+        .preview-pages {
+            display: flex;
+            flex-direction: column;
+            gap: 8px;
+        }
+        .preview-pages img {
+            width: 100%;
+            border: 1px solid #ddd;
+            box-shadow: 0 1px 4px rgba(0, 0, 0, 0.1);
+        }
+        @media (max-width: 720px) {
+            .template-preview {
+                display: none;
+            }
+        }
         .form-section {
             border: 1px solid #eee;
             border-radius: 8px;
@@ -1051,6 +1343,12 @@ const StateSelector = {
             border-color: #007bff;
             background: #333;
         }
+        .dark-mode .preview-status {
+            color: #aaa;
+        }
+        .dark-mode .preview-pages img {
+            border-color: #444;
+        }
         .dark-mode .checkbox-field {
             background: #1a3a5c;
             border-color: #2563eb;
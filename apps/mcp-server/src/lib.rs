@@ -7,6 +7,7 @@ pub mod mcp;
 pub mod transport;
 
 // Re-export from typst-engine
+pub use typst_engine::accessibility;
 pub use typst_engine::compiler;
 pub use typst_engine::templates;
 pub use typst_engine::verifier;
@@ -23,6 +23,16 @@ struct Args {
     /// Compilation timeout in milliseconds
     #[arg(long, default_value = "5000")]
     timeout_ms: u64,
+
+    /// Directory of template packages (subdirectories with a template.toml,
+    /// or .zip archives) to serve alongside the built-in templates
+    #[arg(long)]
+    templates_dir: Option<std::path::PathBuf>,
+
+    /// Directory of compliance rule packs (.toml, .yaml or .yml) that
+    /// replace the built-in rules of their state in verify_lease
+    #[arg(long)]
+    rule_packs: Option<std::path::PathBuf>,
 }
 
 #[tokio::main]
@@ -48,6 +58,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Starting Typst MCP Server v{}", env!("CARGO_PKG_VERSION"));
     tracing::info!("Transport: {}", args.transport);
 
+    if let Some(dir) = &args.templates_dir {
+        let loaded = mcp_server::templates::load_packages_from_dir(dir)?;
+        tracing::info!(
+            "Loaded {} template packages from {}",
+            loaded.len(),
+            dir.display()
+        );
+    }
+
+    if let Some(dir) = &args.rule_packs {
+        let loaded = mcp_server::verifier::rules::load_rule_packs_from_dir(dir)?;
+        for pack in &loaded {
+            tracing::info!(
+                "Rule pack {} {} for {:?}",
+                pack.id,
+                pack.version,
+                pack.state
+            );
+        }
+        tracing::info!("Loaded {} rule packs from {}", loaded.len(), dir.display());
+    }
+
     match args.transport.as_str() {
         "stdio" => {
             mcp_server::transport::stdio::run_stdio_server(args.timeout_ms).await?;
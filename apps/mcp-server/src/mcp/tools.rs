@@ -3,19 +3,23 @@
 use serde_json::json;
 
 use super::protocol::{Tool, ToolResultContent};
+use crate::accessibility::check_accessibility;
 use crate::compiler::errors::ServerError;
 use crate::compiler::{compile_document, validate_syntax, RenderRequest};
 use crate::templates;
 use crate::verifier::verify_lease;
 use crate::world::fonts::global_font_cache;
 
+/// Prefix of the per-template render tools ("render_template_invoice")
+const TEMPLATE_TOOL_PREFIX: &str = "render_template_";
+
 /// Get all tool definitions
 pub fn get_tool_definitions() -> Vec<Tool> {
-    vec![
+    let mut tools = vec![
         Tool {
             name: "render_document".to_string(),
             description: Some(
-                "Compiles a Typst template with dynamic data into PDF, SVG, or PNG format"
+                "Compiles a Typst template with dynamic data into PDF, SVG, or PNG format. SVG and PNG return one artifact per page in `pages`"
                     .to_string(),
             ),
             input_schema: json!({
@@ -23,7 +27,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 "properties": {
                     "source": {
                         "type": "string",
-                        "description": "Raw Typst source code OR a template URI (e.g., typst://templates/invoice)"
+                        "description": "Raw Typst source code OR a template URI (e.g., typst://templates/invoice, or typst://templates/letterhead@1.2.0 for a template package version)"
                     },
                     "inputs": {
                         "type": "object",
@@ -45,6 +49,24 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                         "type": "integer",
                         "description": "Pixels per inch for PNG output (default: 144)",
                         "default": 144
+                    },
+                    "pages": {
+                        "type": "string",
+                        "description": "One-indexed pages to export, e.g. \"1-3,5\" or \"2-\" (default: all)"
+                    },
+                    "bundle": {
+                        "type": "boolean",
+                        "description": "For SVG/PNG, return all pages zipped into one artifact instead of one artifact per page",
+                        "default": false
+                    },
+                    "thumbnail_width": {
+                        "type": "integer",
+                        "description": "For PNG, render pages at most this many pixels wide (cheaper than full ppi)"
+                    },
+                    "tagged": {
+                        "type": "boolean",
+                        "description": "For PDF, write a tagged PDF (structure tree, reading order, alt text, language) for screen readers. Not PDF/UA conformant",
+                        "default": false
                     }
                 },
                 "required": ["source"]
@@ -88,7 +110,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
         Tool {
             name: "verify_lease".to_string(),
             description: Some(
-                "Verify a lease PDF for Florida compliance and detect anomalies".to_string(),
+                "Verify a lease PDF for state compliance and detect anomalies".to_string(),
             ),
             input_schema: json!({
                 "type": "object",
@@ -99,9 +121,8 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                     },
                     "state": {
                         "type": "string",
-                        "enum": ["florida"],
                         "default": "florida",
-                        "description": "State-specific rules to apply"
+                        "description": "State code or name whose rules to apply (e.g. \"FL\", \"texas\")"
                     },
                     "detect_anomalies": {
                         "type": "boolean",
@@ -112,7 +133,71 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 "required": ["pdf_base64"]
             }),
         },
-    ]
+        Tool {
+            name: "check_accessibility".to_string(),
+            description: Some(
+                "Check a PDF for common Section 508 / PDF/UA problems: untagged content or links, missing alt text, skipped heading levels, misplaced table rows or cells, missing language or title. Passing is not PDF/UA validation"
+                    .to_string(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "pdf_base64": {
+                        "type": "string",
+                        "description": "Base64-encoded PDF file"
+                    }
+                },
+                "required": ["pdf_base64"]
+            }),
+        },
+    ];
+    // Template packages are listed newest version first; the tool renders
+    // the newest
+    let mut seen = std::collections::HashSet::new();
+    tools.extend(
+        templates::list_templates()
+            .into_iter()
+            .filter(|t| seen.insert(t.name.clone()))
+            .map(template_tool),
+    );
+    tools
+}
+
+/// Render tool for one template, taking inputs that match its schema
+fn template_tool(template: templates::TemplateInfo) -> Tool {
+    Tool {
+        name: format!("{}{}", TEMPLATE_TOOL_PREFIX, template.name),
+        description: Some(format!(
+            "Renders the {} template: {}",
+            template.name, template.description
+        )),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "inputs": template.schema.to_json_schema(),
+                "format": {
+                    "type": "string",
+                    "enum": ["pdf", "svg", "png"],
+                    "default": "pdf",
+                    "description": "Output format"
+                },
+                "pages": {
+                    "type": "string",
+                    "description": "One-indexed pages to export, e.g. \"1-3,5\" (default: all)"
+                },
+                "thumbnail_width": {
+                    "type": "integer",
+                    "description": "For PNG, render pages at most this many pixels wide"
+                },
+                "tagged": {
+                    "type": "boolean",
+                    "description": "For PDF, write a tagged PDF for screen readers. Not PDF/UA conformant",
+                    "default": false
+                }
+            },
+            "required": ["inputs"]
+        }),
+    }
 }
 
 /// Handle a tool call
@@ -127,7 +212,13 @@ pub async fn handle_tool_call(
         "list_fonts" => handle_list_fonts(),
         "list_templates" => handle_list_templates(),
         "verify_lease" => handle_verify_lease(arguments),
-        _ => Err(ServerError::UnknownTool(name.to_string())),
+        "check_accessibility" => handle_check_accessibility(arguments),
+        _ => match name.strip_prefix(TEMPLATE_TOOL_PREFIX) {
+            Some(template) if templates::template_schema(template).is_some() => {
+                handle_render_template(template, arguments, timeout_ms).await
+            }
+            _ => Err(ServerError::UnknownTool(name.to_string())),
+        },
     }
 }
 
@@ -142,6 +233,20 @@ async fn handle_render_document(
     Ok(vec![ToolResultContent::Text { text: result_json }])
 }
 
+async fn handle_render_template(
+    template: &str,
+    mut args: serde_json::Value,
+    timeout_ms: u64,
+) -> Result<Vec<ToolResultContent>, ServerError> {
+    if let Some(args) = args.as_object_mut() {
+        args.insert(
+            "source".to_string(),
+            json!(format!("typst://templates/{}", template)),
+        );
+    }
+    handle_render_document(args, timeout_ms).await
+}
+
 fn handle_validate_syntax(args: serde_json::Value) -> Result<Vec<ToolResultContent>, ServerError> {
     let source = args
         .get("source")
@@ -249,3 +354,30 @@ fn handle_verify_lease(args: serde_json::Value) -> Result<Vec<ToolResultContent>
         text: serde_json::to_string_pretty(&result)?,
     }])
 }
+
+fn handle_check_accessibility(
+    args: serde_json::Value,
+) -> Result<Vec<ToolResultContent>, ServerError> {
+    let pdf_base64 = args
+        .get("pdf_base64")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| ServerError::InvalidArgument("pdf_base64 is required".to_string()))?;
+
+    let pdf_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, pdf_base64)
+        .map_err(|e| ServerError::InvalidArgument(format!("Invalid base64: {}", e)))?;
+
+    let report = check_accessibility(&pdf_bytes)
+        .map_err(|e| ServerError::InvalidArgument(format!("Accessibility check failed: {}", e)))?;
+
+    let result = json!({
+        "passes": report.passes(),
+        "tagged": report.tagged,
+        "language": report.language,
+        "title": report.title,
+        "issues": report.issues
+    });
+
+    Ok(vec![ToolResultContent::Text {
+        text: serde_json::to_string_pretty(&result)?,
+    }])
+}
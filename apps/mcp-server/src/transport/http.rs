@@ -18,7 +18,7 @@ use tokio::sync::broadcast;
 use tower_http::cors::{Any, CorsLayer};
 
 use crate::compiler::errors::ServerError;
-use crate::compiler::{compile_document, RenderRequest};
+use crate::compiler::{compile_document, InputError, PageSelection, RenderRequest};
 use crate::mcp::protocol::*;
 use crate::mcp::{prompts, resources, tools, TypstMcpServer};
 use crate::templates;
@@ -37,6 +37,16 @@ pub struct RenderApiRequest {
     /// Output format: pdf, svg, png
     #[serde(default = "default_format")]
     pub format: String,
+    /// One-indexed pages to export ("1-3,5"); all pages when absent
+    #[serde(default)]
+    pub pages: Option<PageSelection>,
+    /// For PNG, render pages at most this many pixels wide
+    #[serde(default)]
+    pub thumbnail_width: Option<u32>,
+    /// For PDF, write a tagged PDF for screen readers (not PDF/UA
+    /// conformant)
+    #[serde(default)]
+    pub tagged: bool,
 }
 
 fn default_format() -> String {
@@ -49,10 +59,17 @@ pub struct RenderApiResponse {
     pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
+    /// For SVG and PNG, each exported page's data; `data` is the first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<String>>,
+    /// Inputs rejected by the template's schema, for showing next to the
+    /// form fields
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_errors: Vec<InputError>,
 }
 
 /// Shared state for the HTTP server
@@ -199,6 +216,10 @@ async fn handle_api_render(
             _ => crate::compiler::OutputFormat::Pdf,
         },
         ppi: Some(144),
+        pages: request.pages,
+        thumbnail_width: request.thumbnail_width,
+        tagged: request.tagged,
+        ..Default::default()
     };
 
     // Compile document
@@ -214,29 +235,39 @@ async fn handle_api_render(
                 Json(RenderApiResponse {
                     success: true,
                     data: Some(artifact.data_base64),
+                    pages: response.pages.into_iter().map(|p| p.data_base64).collect(),
                     error: None,
                     warnings: if warnings.is_empty() {
                         None
                     } else {
                         Some(warnings)
                     },
+                    input_errors: vec![],
                 })
             } else {
-                let errors: Vec<String> =
-                    response.errors.iter().map(|e| e.message.clone()).collect();
+                let errors: Vec<String> = response
+                    .input_errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .chain(response.errors.iter().map(|e| e.message.clone()))
+                    .collect();
                 Json(RenderApiResponse {
                     success: false,
                     data: None,
+                    pages: vec![],
                     error: Some(errors.join("; ")),
                     warnings: None,
+                    input_errors: response.input_errors,
                 })
             }
         }
         Err(e) => Json(RenderApiResponse {
             success: false,
             data: None,
+            pages: vec![],
             error: Some(e.to_string()),
             warnings: None,
+            input_errors: vec![],
         }),
     }
 }
@@ -454,6 +485,50 @@ mod tests {
         assert!(tool_names.contains(&"validate_syntax"));
         assert!(tool_names.contains(&"list_fonts"));
         assert!(tool_names.contains(&"list_templates"));
+        assert!(tool_names.contains(&"check_accessibility"));
+
+        // One render tool per template, with the template's input schema
+        let lease = tools
+            .iter()
+            .find(|t| t["name"] == "render_template_florida_lease")
+            .unwrap();
+        let inputs = &lease["inputSchema"]["properties"]["inputs"];
+        assert_eq!(inputs["properties"]["monthly_rent"]["format"], "currency");
+        assert!(inputs["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("tenant_name")));
+    }
+
+    #[tokio::test]
+    async fn test_process_tools_call_template_reports_input_errors() {
+        let state = create_test_state();
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(3)),
+            method: "tools/call".to_string(),
+            params: json!({
+                "name": "render_template_letter",
+                "arguments": {
+                    "inputs": { "sender_name": "Alice", "date": "someday" }
+                }
+            }),
+        };
+
+        let response = process_request(&state, request).await;
+        let result = response.result.unwrap();
+        assert_eq!(result["isError"], false);
+
+        let text = result["content"][0]["text"].as_str().unwrap();
+        let render: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(render["status"], "error");
+        let fields: Vec<&str> = render["input_errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|e| e["field"].as_str())
+            .collect();
+        assert_eq!(fields, ["recipient_name", "body", "date"]);
     }
 
     #[tokio::test]
@@ -562,6 +637,55 @@ mod tests {
         assert!(!contents.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_process_template_package_versions() {
+        for version in ["1.0.0", "1.1.0"] {
+            let manifest = format!(
+                "[template]\nname = \"http_test_memo\"\nversion = \"{}\"\n",
+                version
+            );
+            let package = templates::TemplatePackage::from_files(vec![
+                ("template.toml".to_string(), manifest.into_bytes()),
+                (
+                    "main.typ".to_string(),
+                    format!("Memo {}", version).into_bytes(),
+                ),
+            ])
+            .unwrap();
+            templates::register_package(package).unwrap();
+        }
+        let state = create_test_state();
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(7)),
+            method: "resources/read".to_string(),
+            params: json!({
+                "uri": "typst://templates/http_test_memo@1.0.0"
+            }),
+        };
+        let response = process_request(&state, request).await;
+        let result = response.result.unwrap();
+        assert_eq!(result["contents"][0]["text"], "Memo 1.0.0");
+
+        // One render tool per package, not per version
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(8)),
+            method: "tools/list".to_string(),
+            params: json!({}),
+        };
+        let response = process_request(&state, request).await;
+        let result = response.result.unwrap();
+        let count = result["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|t| t["name"] == "render_template_http_test_memo")
+            .count();
+        assert_eq!(count, 1);
+    }
+
     #[tokio::test]
     async fn test_process_resources_read_fonts() {
         let state = create_test_state();
@@ -719,6 +843,59 @@ mod tests {
         assert!(result.get("content").is_some() || result.get("isError").is_some());
     }
 
+    #[tokio::test]
+    async fn test_process_tagged_render_passes_accessibility_check() {
+        let state = create_test_state();
+        let call = |id: i64, name: &str, arguments: serde_json::Value| JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(id)),
+            method: "tools/call".to_string(),
+            params: json!({ "name": name, "arguments": arguments }),
+        };
+        let text = |response: JsonRpcResponse| -> serde_json::Value {
+            let result = response.result.unwrap();
+            assert_eq!(result["isError"], false, "{}", result);
+            serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap()
+        };
+
+        let source = "#set document(title: \"Notice\")\n= Notice\n\nThe rent is due.";
+        let mut pdfs = Vec::new();
+        for tagged in [false, true] {
+            let render = text(
+                process_request(
+                    &state,
+                    call(
+                        15,
+                        "render_document",
+                        json!({ "source": source, "tagged": tagged }),
+                    ),
+                )
+                .await,
+            );
+            pdfs.push(render["artifact"]["data_base64"].clone());
+        }
+
+        let untagged = text(
+            process_request(
+                &state,
+                call(16, "check_accessibility", json!({ "pdf_base64": pdfs[0] })),
+            )
+            .await,
+        );
+        assert_eq!(untagged["passes"], false);
+        assert_eq!(untagged["issues"][0]["kind"], "untagged_content");
+
+        let tagged = text(
+            process_request(
+                &state,
+                call(17, "check_accessibility", json!({ "pdf_base64": pdfs[1] })),
+            )
+            .await,
+        );
+        assert_eq!(tagged["passes"], true, "{}", tagged);
+        assert_eq!(tagged["title"], "Notice");
+    }
+
     // ===========================================
     // REST API Tests for /api/templates and /api/render
     // ===========================================
@@ -810,6 +987,9 @@ mod tests {
                 map
             },
             format: "pdf".to_string(),
+            pages: None,
+            thumbnail_width: None,
+            tagged: false,
         };
 
         let response = handle_api_render(State(state), Json(request))
@@ -849,6 +1029,9 @@ mod tests {
             is_template: true,
             inputs: std::collections::HashMap::new(),
             format: "pdf".to_string(),
+            pages: None,
+            thumbnail_width: None,
+            tagged: false,
         };
 
         let response = handle_api_render(State(state), Json(request))
@@ -877,6 +1060,9 @@ mod tests {
             is_template: false,
             inputs: std::collections::HashMap::new(),
             format: "pdf".to_string(),
+            pages: None,
+            thumbnail_width: None,
+            tagged: false,
         };
 
         let response = handle_api_render(State(state), Json(request))
@@ -892,6 +1078,37 @@ mod tests {
         assert!(json["data"].is_string());
     }
 
+    #[tokio::test]
+    async fn test_api_render_png_page_thumbnails() {
+        use axum::extract::State;
+        use axum::response::IntoResponse;
+        use axum::Json;
+
+        let state = create_test_state();
+        let request = RenderApiRequest {
+            template: "One #pagebreak() Two #pagebreak() Three".to_string(),
+            is_template: false,
+            inputs: std::collections::HashMap::new(),
+            format: "png".to_string(),
+            pages: Some("2-".parse().unwrap()),
+            thumbnail_width: Some(120),
+            tagged: false,
+        };
+
+        let response = handle_api_render(State(state), Json(request))
+            .await
+            .into_response();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["success"], true, "Render failed: {:?}", json["error"]);
+        let pages = json["pages"].as_array().unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(json["data"], pages[0]);
+    }
+
     // ===========================================
     // Property Tests for REST API
     // ===========================================
@@ -918,6 +1135,9 @@ mod tests {
                     is_template: false,
                     inputs: std::collections::HashMap::new(),
                     format: format.clone(),
+                    pages: None,
+                    thumbnail_width: None,
+                    tagged: false,
                 };
 
                 let response = handle_api_render(State(state), Json(request))
@@ -961,6 +1181,9 @@ mod tests {
                     is_template: true,
                     inputs,
                     format: "pdf".to_string(),
+                    pages: None,
+                    thumbnail_width: None,
+                    tagged: false,
                 };
 
                 let response = handle_api_render(State(state), Json(request))
@@ -1026,6 +1249,9 @@ mod tests {
                     is_template: true,
                     inputs,
                     format: "pdf".to_string(),
+                    pages: None,
+                    thumbnail_width: None,
+                    tagged: false,
                 };
 
                 let response = handle_api_render(State(state), Json(request))
@@ -1068,6 +1294,9 @@ mod tests {
                     is_template: true,
                     inputs,
                     format: "pdf".to_string(),
+                    pages: None,
+                    thumbnail_width: None,
+                    tagged: false,
                 };
 
                 let response = handle_api_render(State(state), Json(request))
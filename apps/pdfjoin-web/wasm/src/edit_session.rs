@@ -27,11 +27,11 @@ impl EditSession {
     #[wasm_bindgen(constructor)]
     pub fn new(name: &str, bytes: &[u8]) -> Result<EditSession, JsValue> {
         // Check for signatures first
-        let is_signed =
-            has_signatures(bytes).map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+        let is_signed = has_signatures(bytes, false)
+            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
 
         // Get page count
-        let page_count = pdfjoin_core::get_page_count(bytes)
+        let page_count = pdfjoin_core::get_page_count(bytes, false)
             .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
 
         Ok(EditSession {
@@ -492,7 +492,7 @@ impl EditSession {
             ));
         }
 
-        let result = apply_operations(&self.document_bytes, &self.operations)
+        let result = apply_operations(&self.document_bytes, &self.operations, false)
             .map_err(|e| JsValue::from_str(&format!("Export error: {}", e)))?;
 
         let array = js_sys::Uint8Array::new_with_length(result.len() as u32);
@@ -510,7 +510,7 @@ impl EditSession {
             ));
         }
 
-        let result = apply_operations_flattened(&self.document_bytes, &self.operations)
+        let result = apply_operations_flattened(&self.document_bytes, &self.operations, false)
             .map_err(|e| JsValue::from_str(&format!("Export error: {}", e)))?;
 
         let array = js_sys::Uint8Array::new_with_length(result.len() as u32);
@@ -545,7 +545,7 @@ impl EditSession {
         ));
 
         // Call actual flattened export
-        match apply_operations_flattened(&self.document_bytes, &self.operations) {
+        match apply_operations_flattened(&self.document_bytes, &self.operations, false) {
             Ok(result) => {
                 let result_str = String::from_utf8_lossy(&result);
                 let has_white_rect = result_str.contains("1 1 1 rg");
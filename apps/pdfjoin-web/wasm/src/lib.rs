@@ -40,6 +40,7 @@ pub mod page_info;
 pub mod session;
 pub mod validation;
 
+use pdfjoin_core::DocumentMetadata;
 use wasm_bindgen::prelude::*;
 
 // Re-export main types for JavaScript
@@ -85,6 +86,56 @@ pub fn get_page_count(bytes: &[u8]) -> Result<u32, JsValue> {
     Ok(info.page_count)
 }
 
+/// Read document metadata (title, author, dates, custom properties)
+/// Returns a `DocumentMetadata` object with ISO 8601 dates
+#[wasm_bindgen(js_name = getMetadata)]
+pub fn get_metadata(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let metadata = pdfjoin_core::metadata::read_metadata(bytes, false)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    serde_wasm_bindgen::to_value(&metadata)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Replace document metadata, keeping `/Info` and XMP in sync
+#[wasm_bindgen(js_name = setMetadata)]
+pub fn set_metadata(bytes: &[u8], metadata: JsValue) -> Result<js_sys::Uint8Array, JsValue> {
+    let metadata: DocumentMetadata = serde_wasm_bindgen::from_value(metadata)
+        .map_err(|e| JsValue::from_str(&format!("Invalid metadata: {}", e)))?;
+    let result = pdfjoin_core::metadata::write_metadata(bytes, &metadata, false)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(js_sys::Uint8Array::from(result.as_slice()))
+}
+
+/// Remove author, producer, creator tool, dates and edit history
+#[wasm_bindgen(js_name = scrubMetadata)]
+pub fn scrub_metadata(bytes: &[u8]) -> Result<js_sys::Uint8Array, JsValue> {
+    let result = pdfjoin_core::metadata::scrub_metadata(bytes, false)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(js_sys::Uint8Array::from(result.as_slice()))
+}
+
+/// Repair a damaged PDF (broken xref, missing trailer, truncated streams)
+/// Returns `{ bytes: Uint8Array, fixes: RepairFix[] }`; `fixes` is empty for intact files
+#[wasm_bindgen(js_name = repairPdf)]
+pub fn repair_pdf(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    let (repaired, report) =
+        pdfjoin_core::repair_pdf(bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let result = js_sys::Object::new();
+    js_sys::Reflect::set(
+        &result,
+        &"bytes".into(),
+        &js_sys::Uint8Array::from(repaired.as_slice()),
+    )?;
+    let fixes = serde_wasm_bindgen::to_value(&report.fixes)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
+    js_sys::Reflect::set(&result, &"fixes".into(), &fixes)?;
+    Ok(result.into())
+}
+
 /// Format bytes as human-readable string
 #[wasm_bindgen]
 pub fn format_bytes(bytes: usize) -> String {
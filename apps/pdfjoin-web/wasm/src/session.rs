@@ -6,6 +6,7 @@
 use crate::page_info::PageInfo;
 use crate::validation::{validate_pdf, PdfInfo};
 use lopdf::Document;
+use pdfjoin_core::DocumentMetadata;
 use wasm_bindgen::prelude::*;
 
 /// Document entry with metadata
@@ -33,6 +34,9 @@ pub struct PdfJoinSession {
     documents: Vec<DocumentEntry>,
     selected_pages: Vec<u32>,
     progress_callback: Option<js_sys::Function>,
+    output_metadata: Option<DocumentMetadata>,
+    scrub_metadata: bool,
+    lenient: bool,
 }
 
 #[wasm_bindgen]
@@ -45,6 +49,9 @@ impl PdfJoinSession {
             documents: Vec::new(),
             selected_pages: Vec::new(),
             progress_callback: None,
+            output_metadata: None,
+            scrub_metadata: false,
+            lenient: false,
         }
     }
 
@@ -61,6 +68,29 @@ impl PdfJoinSession {
         self.progress_callback = Some(callback);
     }
 
+    /// Set metadata for the output document
+    /// Without this, a merged file keeps the first document's metadata
+    #[wasm_bindgen(js_name = setOutputMetadata)]
+    pub fn set_output_metadata(&mut self, metadata: JsValue) -> Result<(), JsValue> {
+        let metadata = serde_wasm_bindgen::from_value(metadata)
+            .map_err(|e| JsValue::from_str(&format!("Invalid metadata: {}", e)))?;
+        self.output_metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Strip personal metadata (author, producer, history) from the output
+    #[wasm_bindgen(js_name = setScrubMetadata)]
+    pub fn set_scrub_metadata(&mut self, scrub: bool) {
+        self.scrub_metadata = scrub;
+    }
+
+    /// Recover damaged PDFs (broken xref, missing trailer, truncated streams)
+    /// instead of rejecting them. Applies to documents added afterwards.
+    #[wasm_bindgen(js_name = setLenient)]
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
     /// Internal method to add a document (testable without JsValue)
     fn add_document_internal(&mut self, name: &str, bytes: &[u8]) -> Result<PdfInfo, String> {
         // In split mode, only allow one document
@@ -70,6 +100,17 @@ impl PdfJoinSession {
             );
         }
 
+        // Repair first so the session only ever holds parseable bytes
+        let repaired;
+        let bytes = if self.lenient {
+            repaired = pdfjoin_core::repair_pdf(bytes)
+                .map_err(|e| format!("Failed to repair PDF: {}", e))?
+                .0;
+            repaired.as_slice()
+        } else {
+            bytes
+        };
+
         // Validate and parse the PDF
         let info = validate_pdf(bytes)?;
 
@@ -288,6 +329,7 @@ impl PdfJoinSession {
             SessionMode::Split => self.execute_split()?,
             SessionMode::Merge => self.execute_merge()?,
         };
+        let result = self.apply_output_metadata(result)?;
 
         self.report_progress(100, 100, "Complete")?;
 
@@ -300,9 +342,12 @@ impl PdfJoinSession {
     fn execute_split(&self) -> Result<Vec<u8>, JsValue> {
         self.report_progress(10, 100, "Extracting pages...")?;
 
-        let result =
-            pdfjoin_core::split_document(&self.documents[0].bytes, self.selected_pages.clone())
-                .map_err(|e| JsValue::from_str(&format!("Split failed: {}", e)))?;
+        let result = pdfjoin_core::split_document(
+            &self.documents[0].bytes,
+            self.selected_pages.clone(),
+            false,
+        )
+        .map_err(|e| JsValue::from_str(&format!("Split failed: {}", e)))?;
 
         self.report_progress(90, 100, "Finalizing...")?;
 
@@ -331,7 +376,7 @@ impl PdfJoinSession {
 
         self.report_progress(85, 100, "Merging...")?;
 
-        let result = pdfjoin_core::merge_documents(docs)
+        let result = pdfjoin_core::merge_documents(docs, false)
             .map_err(|e| JsValue::from_str(&format!("Merge failed: {}", e)))?;
 
         self.report_progress(95, 100, "Finalizing...")?;
@@ -339,6 +384,20 @@ impl PdfJoinSession {
         Ok(result)
     }
 
+    /// Apply `setOutputMetadata` / `setScrubMetadata` to the result
+    fn apply_output_metadata(&self, bytes: Vec<u8>) -> Result<Vec<u8>, JsValue> {
+        let mut bytes = bytes;
+        if let Some(ref metadata) = self.output_metadata {
+            bytes = pdfjoin_core::metadata::write_metadata(&bytes, metadata, false)
+                .map_err(|e| JsValue::from_str(&format!("Metadata update failed: {}", e)))?;
+        }
+        if self.scrub_metadata {
+            bytes = pdfjoin_core::metadata::scrub_metadata(&bytes, false)
+                .map_err(|e| JsValue::from_str(&format!("Metadata scrub failed: {}", e)))?;
+        }
+        Ok(bytes)
+    }
+
     /// Report progress to JavaScript callback
     fn report_progress(&self, current: u32, total: u32, message: &str) -> Result<(), JsValue> {
         if let Some(ref callback) = self.progress_callback {
@@ -608,4 +667,74 @@ mod tests {
         let output_doc = Document::load_mem(&result).unwrap();
         assert_eq!(output_doc.get_pages().len(), 1);
     }
+
+    #[test]
+    fn test_merge_output_metadata_replaces_first_document_info() {
+        let mut session = PdfJoinSession::new(SessionMode::Merge);
+        let first = pdfjoin_core::metadata::write_metadata(
+            &create_test_pdf(1),
+            &DocumentMetadata {
+                title: Some("First".to_string()),
+                author: Some("Original Author".to_string()),
+                ..Default::default()
+            },
+            false,
+        )
+        .unwrap();
+        session.add_document_internal("first.pdf", &first).unwrap();
+        session
+            .add_document_internal("second.pdf", &create_test_pdf(1))
+            .unwrap();
+        session.output_metadata = Some(DocumentMetadata {
+            title: Some("Combined".to_string()),
+            ..Default::default()
+        });
+
+        let merged = session.execute_merge().unwrap();
+        let result = session.apply_output_metadata(merged).unwrap();
+        let metadata = pdfjoin_core::metadata::read_metadata(&result, false).unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("Combined"));
+        assert!(metadata.author.is_none());
+    }
+
+    #[test]
+    fn test_scrub_metadata_on_execute() {
+        let mut session = PdfJoinSession::new(SessionMode::Split);
+        let pdf = pdfjoin_core::metadata::write_metadata(
+            &create_test_pdf(2),
+            &DocumentMetadata {
+                author: Some("Original Author".to_string()),
+                producer: Some("Some Producer".to_string()),
+                ..Default::default()
+            },
+            false,
+        )
+        .unwrap();
+        session.add_document_internal("test.pdf", &pdf).unwrap();
+        session.set_scrub_metadata(true);
+
+        let split = session.execute_split().unwrap();
+        let result = session.apply_output_metadata(split).unwrap();
+        let metadata = pdfjoin_core::metadata::read_metadata(&result, false).unwrap();
+        assert!(metadata.author.is_none());
+        assert!(metadata.producer.is_none());
+    }
+
+    #[test]
+    fn test_lenient_session_accepts_damaged_pdf() {
+        let mut pdf = create_test_pdf(3);
+        let xref = pdf.windows(4).rposition(|w| w == b"xref").unwrap();
+        pdf.truncate(xref);
+
+        let mut strict = PdfJoinSession::new(SessionMode::Split);
+        assert!(strict.add_document_internal("damaged.pdf", &pdf).is_err());
+
+        let mut session = PdfJoinSession::new(SessionMode::Split);
+        session.set_lenient(true);
+        let info = session.add_document_internal("damaged.pdf", &pdf).unwrap();
+        assert_eq!(info.page_count, 3);
+
+        let result = session.execute_split().unwrap();
+        assert_eq!(Document::load_mem(&result).unwrap().get_pages().len(), 3);
+    }
 }
@@ -129,11 +129,11 @@ impl BenchResult {
 
 fn bench_split(pdf: &[u8], pages: Vec<u32>, name: &str) -> BenchResult {
     // Warm up
-    let _ = pdfjoin_core::split_document(pdf, pages.clone());
+    let _ = pdfjoin_core::split_document(pdf, pages.clone(), false);
 
     // Benchmark lopdf
     let start = Instant::now();
-    let _ = pdfjoin_core::split_document(pdf, pages.clone());
+    let _ = pdfjoin_core::split_document(pdf, pages.clone(), false);
     let lopdf_time = start.elapsed();
 
     // Benchmark streaming
@@ -166,11 +166,11 @@ fn bench_split(pdf: &[u8], pages: Vec<u32>, name: &str) -> BenchResult {
 
 fn bench_merge(pdfs: Vec<Vec<u8>>, name: &str) -> BenchResult {
     // Warm up
-    let _ = pdfjoin_core::merge_documents(pdfs.clone());
+    let _ = pdfjoin_core::merge_documents(pdfs.clone(), false);
 
     // Benchmark lopdf
     let start = Instant::now();
-    let _ = pdfjoin_core::merge_documents(pdfs.clone());
+    let _ = pdfjoin_core::merge_documents(pdfs.clone(), false);
     let lopdf_time = start.elapsed();
 
     // Benchmark streaming
@@ -292,7 +292,7 @@ fn benchmark_real_pdfs() {
         eprintln!("Testing {}: {} bytes", name, bytes.len());
 
         // Get page count
-        let page_count = pdfjoin_core::get_page_count(bytes).unwrap_or(1);
+        let page_count = pdfjoin_core::get_page_count(bytes, false).unwrap_or(1);
         eprintln!("  Pages: {}", page_count);
 
         // Split: extract first page
@@ -0,0 +1,228 @@
+//! PDFJoin memory benchmark: in-memory merge vs chunked MergeWriter
+//!
+//! Merges large files from disk and records peak heap usage with a counting
+//! allocator. The chunked merge should stay near the size of the largest
+//! object while the in-memory merge grows with the total input, whether the
+//! sources use classic xref tables or cross-reference streams.
+//!
+//! Run with: cargo test -p benchmark-harness --test pdfjoin_memory -- --nocapture
+
+use lopdf::xref::XrefType;
+use lopdf::{Dictionary, Document, Object, Stream};
+use pdfjoin_core::MergeWriter;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// System allocator that tracks current and peak heap usage
+struct CountingAllocator;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// The counters are global, so benchmarks must not run concurrently
+static SERIAL: Mutex<()> = Mutex::new(());
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(current, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const SOURCES: usize = 8;
+const PAGES_PER_SOURCE: u32 = 4;
+/// Size of each page's content stream, the largest object in every source
+const STREAM_SIZE: usize = 1024 * 1024;
+
+/// Run `f` and return its result with the heap growth above the starting point
+fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, usize, Duration) {
+    let baseline = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(baseline, Ordering::SeqCst);
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let peak = PEAK.load(Ordering::SeqCst).saturating_sub(baseline);
+    (result, peak, elapsed)
+}
+
+/// Create a PDF whose pages each carry a large uncompressed content stream
+fn write_large_pdf(path: &Path, label: usize, xref_type: XrefType) {
+    let mut doc = Document::with_version("1.7");
+    let pages_id = doc.new_object_id();
+    let mut page_ids = Vec::new();
+
+    for page in 0..PAGES_PER_SOURCE {
+        let mut content =
+            format!("BT 72 700 Td (Source {} page {}) Tj ET\n", label, page + 1).into_bytes();
+        // Pad with comment lines so the stream stays valid content
+        while content.len() < STREAM_SIZE {
+            content.extend_from_slice(b"% filler filler filler filler filler filler filler\n");
+        }
+        let content_id = doc.add_object(Stream::new(Dictionary::new(), content));
+        let page_id = doc.add_object(Dictionary::from_iter(vec![
+            ("Type", Object::Name(b"Page".to_vec())),
+            ("Parent", Object::Reference(pages_id)),
+            ("Contents", Object::Reference(content_id)),
+        ]));
+        page_ids.push(page_id);
+    }
+
+    let pages = Dictionary::from_iter(vec![
+        ("Type", Object::Name(b"Pages".to_vec())),
+        ("Count", Object::Integer(PAGES_PER_SOURCE as i64)),
+        (
+            "Kids",
+            Object::Array(page_ids.iter().map(|id| Object::Reference(*id)).collect()),
+        ),
+        (
+            "MediaBox",
+            Object::Array(vec![0.into(), 0.into(), 612.into(), 792.into()]),
+        ),
+    ]);
+    doc.objects.insert(pages_id, Object::Dictionary(pages));
+    let catalog_id = doc.add_object(Dictionary::from_iter(vec![
+        ("Type", Object::Name(b"Catalog".to_vec())),
+        ("Pages", Object::Reference(pages_id)),
+    ]));
+    doc.trailer.set("Root", Object::Reference(catalog_id));
+    doc.reference_table.cross_reference_type = xref_type;
+    doc.save(path).unwrap();
+}
+
+fn create_sources(xref_type: XrefType) -> (PathBuf, Vec<PathBuf>) {
+    let dir = std::env::temp_dir().join(format!(
+        "pdfjoin_memory_{}_{:?}",
+        std::process::id(),
+        xref_type
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let paths = (0..SOURCES)
+        .map(|i| {
+            let path = dir.join(format!("source_{}.pdf", i));
+            write_large_pdf(&path, i, xref_type);
+            path
+        })
+        .collect();
+    (dir, paths)
+}
+
+fn mb(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+/// Merge file to file with `MergeWriter`, returning the page count, peak
+/// heap growth and time
+fn chunked_merge(paths: &[PathBuf], output: &Path) -> (u32, usize, Duration) {
+    measure_peak(|| {
+        let out = BufWriter::new(File::create(output).unwrap());
+        let mut writer = MergeWriter::new(out).unwrap();
+        for path in paths {
+            let source = BufReader::new(File::open(path).unwrap());
+            writer.add_source(source, false).unwrap();
+        }
+        let pages = writer.page_count();
+        writer.finish().unwrap();
+        pages
+    })
+}
+
+#[test]
+fn bench_merge_peak_memory() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (dir, paths) = create_sources(XrefType::CrossReferenceTable);
+    let total_input: u64 = paths
+        .iter()
+        .map(|p| std::fs::metadata(p).unwrap().len())
+        .sum();
+
+    // In-memory merge: every input and the output are held at once
+    let (merged, in_memory_peak, in_memory_time) = measure_peak(|| {
+        let inputs: Vec<Vec<u8>> = paths.iter().map(|p| std::fs::read(p).unwrap()).collect();
+        pdfjoin_core::merge_documents(inputs, false).unwrap().len()
+    });
+    assert!(merged > 0);
+
+    // Chunked merge: file to file, one object at a time
+    let output = dir.join("merged.pdf");
+    let (pages, chunked_peak, chunked_time) = chunked_merge(&paths, &output);
+
+    eprintln!("\n=== Merge Peak Memory ===\n");
+    eprintln!(
+        "Input: {} files, {:.1} MB total, largest object {:.1} MB",
+        SOURCES,
+        mb(total_input as usize),
+        mb(STREAM_SIZE)
+    );
+    eprintln!(
+        "{:<20} peak heap: {:>8.2} MB  time: {:>10.2?}",
+        "merge_documents",
+        mb(in_memory_peak),
+        in_memory_time
+    );
+    eprintln!(
+        "{:<20} peak heap: {:>8.2} MB  time: {:>10.2?}",
+        "MergeWriter",
+        mb(chunked_peak),
+        chunked_time
+    );
+
+    assert_eq!(pages, SOURCES as u32 * PAGES_PER_SOURCE);
+    let doc = Document::load(&output).unwrap();
+    assert_eq!(doc.get_pages().len(), pages as usize);
+
+    // Bounded by the largest object, not by the input size
+    assert!(
+        chunked_peak < 2 * STREAM_SIZE,
+        "chunked merge peaked at {:.2} MB",
+        mb(chunked_peak)
+    );
+    assert!(in_memory_peak as u64 > total_input);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn bench_xref_stream_merge_peak_memory() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let (dir, paths) = create_sources(XrefType::CrossReferenceStream);
+
+    let output = dir.join("merged.pdf");
+    let (pages, chunked_peak, chunked_time) = chunked_merge(&paths, &output);
+
+    eprintln!("\n=== Merge Peak Memory (cross-reference streams) ===\n");
+    eprintln!(
+        "{:<20} peak heap: {:>8.2} MB  time: {:>10.2?}",
+        "MergeWriter",
+        mb(chunked_peak),
+        chunked_time
+    );
+
+    assert_eq!(pages, SOURCES as u32 * PAGES_PER_SOURCE);
+    let doc = Document::load(&output).unwrap();
+    assert_eq!(doc.get_pages().len(), pages as usize);
+
+    // Read in place like classic tables, not loaded whole
+    assert!(
+        chunked_peak < 2 * STREAM_SIZE,
+        "chunked merge peaked at {:.2} MB",
+        mb(chunked_peak)
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -132,7 +132,7 @@ fn test_merge_real_demo_pdfs() {
     let docs: Vec<Vec<u8>> = demo_pdfs.iter().map(|(_, b)| b.clone()).collect();
 
     let start = Instant::now();
-    let merged = pdfjoin_core::merge_documents(docs).expect("Should merge demo PDFs");
+    let merged = pdfjoin_core::merge_documents(docs, false).expect("Should merge demo PDFs");
     let elapsed = start.elapsed();
 
     eprintln!("Merged in {:?}", elapsed);
@@ -169,7 +169,7 @@ fn test_merge_demo_pdfs_multiple_times() {
     eprintln!("Merging {} documents...", docs.len());
 
     let start = Instant::now();
-    let merged = pdfjoin_core::merge_documents(docs).expect("Should merge repeated PDFs");
+    let merged = pdfjoin_core::merge_documents(docs, false).expect("Should merge repeated PDFs");
     let elapsed = start.elapsed();
 
     eprintln!("Merged in {:?}", elapsed);
@@ -190,7 +190,7 @@ fn test_merge_10_documents() {
         .collect();
 
     let start = Instant::now();
-    let merged = pdfjoin_core::merge_documents(docs).expect("Should merge 10 documents");
+    let merged = pdfjoin_core::merge_documents(docs, false).expect("Should merge 10 documents");
     let elapsed = start.elapsed();
 
     eprintln!("Merged 10 documents (50 pages) in {:?}", elapsed);
@@ -206,7 +206,7 @@ fn test_merge_50_documents() {
         .collect();
 
     let start = Instant::now();
-    let merged = pdfjoin_core::merge_documents(docs).expect("Should merge 50 documents");
+    let merged = pdfjoin_core::merge_documents(docs, false).expect("Should merge 50 documents");
     let elapsed = start.elapsed();
 
     eprintln!("Merged 50 documents (100 pages) in {:?}", elapsed);
@@ -229,7 +229,7 @@ fn test_merge_100_single_page_documents() {
         .collect();
 
     let start = Instant::now();
-    let merged = pdfjoin_core::merge_documents(docs).expect("Should merge 100 documents");
+    let merged = pdfjoin_core::merge_documents(docs, false).expect("Should merge 100 documents");
     let elapsed = start.elapsed();
 
     eprintln!("Merged 100 single-page documents in {:?}", elapsed);
@@ -245,7 +245,7 @@ fn test_large_document_100_pages() {
 
     // Test splitting various ranges
     let start = Instant::now();
-    let split = pdfjoin_core::split_document(&doc, vec![1, 50, 100]).expect("Should split");
+    let split = pdfjoin_core::split_document(&doc, vec![1, 50, 100], false).expect("Should split");
     let elapsed = start.elapsed();
 
     eprintln!("Split 3 pages from 100-page doc in {:?}", elapsed);
@@ -262,7 +262,8 @@ fn test_large_document_500_pages() {
     // Test splitting all pages
     let pages: Vec<u32> = (1..=500).collect();
     let start = Instant::now();
-    let split = pdfjoin_core::split_document(&doc, pages).expect("Should split all 500 pages");
+    let split =
+        pdfjoin_core::split_document(&doc, pages, false).expect("Should split all 500 pages");
     let elapsed = start.elapsed();
 
     eprintln!("Split all 500 pages in {:?}", elapsed);
@@ -281,7 +282,8 @@ fn test_split_every_other_page() {
     let odd_pages: Vec<u32> = (1..=100).filter(|p| p % 2 == 1).collect();
 
     let start = Instant::now();
-    let split = pdfjoin_core::split_document(&doc, odd_pages).expect("Should split odd pages");
+    let split =
+        pdfjoin_core::split_document(&doc, odd_pages, false).expect("Should split odd pages");
     let elapsed = start.elapsed();
 
     eprintln!("Split 50 odd pages from 100-page doc in {:?}", elapsed);
@@ -298,14 +300,16 @@ fn test_merge_then_split_roundtrip() {
     let doc3 = create_synthetic_pdf(10, "Third");
 
     // Merge them
-    let merged = pdfjoin_core::merge_documents(vec![doc1, doc2, doc3]).expect("Should merge");
+    let merged =
+        pdfjoin_core::merge_documents(vec![doc1, doc2, doc3], false).expect("Should merge");
 
     let merged_doc = Document::load_mem(&merged).unwrap();
     assert_eq!(merged_doc.get_pages().len(), 30);
 
     // Split out the middle document (pages 11-20)
     let middle_pages: Vec<u32> = (11..=20).collect();
-    let split = pdfjoin_core::split_document(&merged, middle_pages).expect("Should split middle");
+    let split =
+        pdfjoin_core::split_document(&merged, middle_pages, false).expect("Should split middle");
 
     let split_doc = Document::load_mem(&split).unwrap();
     assert_eq!(split_doc.get_pages().len(), 10);
@@ -321,7 +325,8 @@ fn test_split_single_page_from_large_doc() {
     eprintln!("Created 1000-page document: {} bytes", doc.len());
 
     let start = Instant::now();
-    let split = pdfjoin_core::split_document(&doc, vec![500]).expect("Should split page 500");
+    let split =
+        pdfjoin_core::split_document(&doc, vec![500], false).expect("Should split page 500");
     let elapsed = start.elapsed();
 
     eprintln!("Extracted single page from 1000-page doc in {:?}", elapsed);
@@ -337,7 +342,7 @@ fn test_merge_identical_documents() {
     // Merge the same document 10 times
     let docs: Vec<Vec<u8>> = (0..10).map(|_| doc.clone()).collect();
 
-    let merged = pdfjoin_core::merge_documents(docs).expect("Should merge identical docs");
+    let merged = pdfjoin_core::merge_documents(docs, false).expect("Should merge identical docs");
 
     let merged_doc = Document::load_mem(&merged).unwrap();
     assert_eq!(merged_doc.get_pages().len(), 50);
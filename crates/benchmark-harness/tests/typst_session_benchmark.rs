@@ -0,0 +1,140 @@
+//! Typst render benchmark: cold compiles vs a warm compiler session
+//!
+//! Measures what the agentpdf form preview pays per keystroke when:
+//! - every render builds a new world (`compile_document_sync`)
+//! - a `CompilerSession` swaps only the changed inputs
+//! - the session serves an identical request from its artifact cache
+//!
+//! comemo's memo cache is process-wide, so cold compiles start from an
+//! empty one, and it is emptied again before the session is primed.
+//! Otherwise the cold compiles reuse each other's layouts, and the
+//! session pays for dropping their results once they age out, which
+//! showed up as p95 spikes of several times the warm p50.
+//!
+//! Run with: cargo test -p benchmark-harness --test typst_session_benchmark --release -- --nocapture
+
+use benchmark_harness::stats::{remove_warmup, PercentileSummary};
+use std::collections::HashMap;
+use std::time::Instant;
+use typst_engine::compiler::{OutputFormat, RenderStatus};
+use typst_engine::{compile_document_sync, CompilerSession, RenderRequest, RenderResponse};
+
+const ITERATIONS: usize = 12;
+const WARMUP: usize = 2;
+
+/// Florida lease request as the form would send it after `edit` keystrokes
+fn lease_request(edit: usize, format: OutputFormat) -> RenderRequest {
+    let inputs = serde_json::json!({
+        "landlord_name": "Sunshine Properties LLC",
+        "tenant_name": format!("Jordan Rivera {}", edit),
+        "property_address": "100 Main Street",
+        "property_city": "Miami",
+        "property_zip": "33101",
+        "monthly_rent": "1500",
+        "security_deposit": "1500",
+        "lease_start": "January 1, 2026",
+        "lease_end": "December 31, 2026"
+    });
+    RenderRequest {
+        source: "typst://templates/florida_lease".to_string(),
+        inputs: serde_json::from_value::<HashMap<_, _>>(inputs).unwrap(),
+        format,
+        thumbnail_width: (format == OutputFormat::Png).then_some(300),
+        ..Default::default()
+    }
+}
+
+/// Time `render` over the benchmark iterations, in milliseconds
+fn bench(name: &str, mut render: impl FnMut(usize) -> RenderResponse) -> PercentileSummary {
+    let samples: Vec<f64> = (0..ITERATIONS)
+        .map(|i| {
+            let start = Instant::now();
+            let response = render(i);
+            let elapsed = start.elapsed().as_secs_f64() * 1000.0;
+            assert_eq!(
+                response.status,
+                RenderStatus::Success,
+                "{}: {:?}",
+                name,
+                response.errors
+            );
+            elapsed
+        })
+        .collect();
+
+    let summary = PercentileSummary::from_samples(remove_warmup(&samples, WARMUP)).unwrap();
+    eprintln!(
+        "{:<40} p50: {:>9.2}ms  p95: {:>9.2}ms  mean: {:>9.2}ms",
+        name, summary.p50, summary.p95, summary.mean
+    );
+    summary
+}
+
+fn print_speedup(name: &str, cold: &PercentileSummary, warm: &PercentileSummary) {
+    eprintln!(
+        "{:<40} speedup (p50): {:>6.1}x",
+        name,
+        cold.p50 / warm.p50.max(0.000001)
+    );
+}
+
+#[test]
+fn benchmark_session_vs_cold_compile() {
+    for format in [OutputFormat::Pdf, OutputFormat::Png] {
+        eprintln!(
+            "\n========== FLORIDA LEASE RE-RENDER ({}) ==========\n",
+            format.extension().to_uppercase()
+        );
+
+        let cold = bench("cold compile_document_sync", |i| {
+            comemo::evict(0);
+            compile_document_sync(lease_request(i, format)).unwrap()
+        });
+        comemo::evict(0);
+
+        // Prime the session the way the preview does on first load
+        let mut session = CompilerSession::new();
+        session.render(&lease_request(0, format)).unwrap();
+        let mut changed = 0;
+        let mut total = 0;
+        let warm = bench("warm session, changed input", |i| {
+            let response = session.render(&lease_request(i + 1, format)).unwrap();
+            changed += response.changed_pages.as_ref().map_or(0, Vec::len);
+            total += response.artifact.as_ref().map_or(0, |a| a.page_count);
+            response
+        });
+
+        let cached = bench("warm session, identical request", |_| {
+            session.render(&lease_request(0, format)).unwrap()
+        });
+
+        eprintln!();
+        print_speedup("warm session vs cold", &cold, &warm);
+        print_speedup("cache hit vs cold", &cold, &cached);
+        eprintln!(
+            "{:<40} {} of {} pages changed",
+            "pages re-rendered per edit", changed, total
+        );
+
+        assert!(
+            warm.p50 < cold.p50,
+            "re-rendering in a warm session should be faster than cold compiles"
+        );
+        assert!(
+            warm.p95 < cold.p95,
+            "warm session renders should not spike past cold compiles"
+        );
+        assert!(
+            cached.p50 < warm.p50,
+            "cache hits should be faster than re-rendering"
+        );
+        assert!(
+            cached.p50 < cold.p50,
+            "cache hits should be faster than cold compiles"
+        );
+        let stats = session.stats();
+        assert_eq!(stats.world_builds, 1);
+        assert_eq!(stats.cache_hits, ITERATIONS);
+    }
+    eprintln!();
+}
@@ -0,0 +1,268 @@
+//! Notice deadlines with the reasoning behind them
+
+use super::{BusinessCalendar, DayCount, StateCalendar};
+use crate::jurisdiction::State;
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::Serialize;
+use std::fmt;
+
+/// A day inside a notice period that was not counted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedDay {
+    pub date: NaiveDate,
+    /// "Saturday", "Sunday" or the holiday's name
+    pub reason: String,
+}
+
+/// The last day of a notice period and how it was derived
+///
+/// The day of service is not counted; counting starts the next day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NoticeDeadline {
+    /// Jurisdiction whose calendar was used
+    pub calendar: String,
+    pub served: NaiveDate,
+    pub days: u32,
+    pub count: DayCount,
+    pub deadline: NaiveDate,
+    /// Statute that sets the period, when known
+    pub statute: Option<String>,
+    /// Days not counted, in date order
+    pub skipped: Vec<SkippedDay>,
+    /// For [`DayCount::CalendarRollForward`], the day the period would have
+    /// ended before it was moved off a weekend or holiday
+    pub rolled_from: Option<NaiveDate>,
+}
+
+impl NoticeDeadline {
+    /// Count `days` from `served` on a calendar
+    pub fn compute(
+        calendar: &dyn BusinessCalendar,
+        served: NaiveDate,
+        days: u32,
+        count: DayCount,
+    ) -> Self {
+        let mut skipped = Vec::new();
+        let mut rolled_from = None;
+        let mut current = served;
+
+        match count {
+            DayCount::Calendar => {
+                current = advance(current, days);
+            }
+            DayCount::CalendarRollForward => {
+                current = advance(current, days);
+                if !calendar.is_business_day(current) {
+                    rolled_from = Some(current);
+                    while let Some(reason) = closed(calendar, current, false) {
+                        skipped.push(SkippedDay {
+                            date: current,
+                            reason,
+                        });
+                        current = advance(current, 1);
+                    }
+                }
+            }
+            DayCount::Business | DayCount::Court => {
+                let court = count == DayCount::Court;
+                let mut remaining = days;
+                while remaining > 0 {
+                    current = advance(current, 1);
+                    match closed(calendar, current, court) {
+                        Some(reason) => skipped.push(SkippedDay {
+                            date: current,
+                            reason,
+                        }),
+                        None => remaining -= 1,
+                    }
+                }
+            }
+        }
+
+        Self {
+            calendar: calendar.name().to_string(),
+            served,
+            days,
+            count,
+            deadline: current,
+            statute: None,
+            skipped,
+            rolled_from,
+        }
+    }
+
+    /// Deadline of a state's notice to pay rent or quit, where the data
+    /// records how that notice is counted; see
+    /// [`StateCalendar::nonpayment_coverage`] for why it is not
+    pub fn nonpayment(state: State, served: NaiveDate) -> Option<Self> {
+        let calendar = StateCalendar::for_state(state);
+        let notice = calendar.nonpayment_notice()?;
+        let mut deadline = Self::compute(calendar, served, notice.days, notice.count);
+        deadline.statute = Some(notice.statute.clone());
+        Some(deadline)
+    }
+
+    /// Step-by-step account of how the deadline was reached
+    pub fn explanation(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "Served {}; {} {} counted from the next day under {} law{}.",
+            long_date(self.served),
+            self.days,
+            self.count.describe(),
+            self.calendar,
+            self.statute
+                .as_ref()
+                .map(|s| format!(" ({})", s))
+                .unwrap_or_default()
+        )];
+
+        if let Some(original) = self.rolled_from {
+            lines.push(format!(
+                "The period ends on {}, which is not a business day.",
+                long_date(original)
+            ));
+            for day in &self.skipped {
+                lines.push(format!(
+                    "Moved past {}: {}.",
+                    long_date(day.date),
+                    day.reason
+                ));
+            }
+        } else {
+            for day in &self.skipped {
+                lines.push(format!(
+                    "Not counted: {} ({}).",
+                    long_date(day.date),
+                    day.reason
+                ));
+            }
+        }
+
+        lines.push(format!("Deadline: {}.", long_date(self.deadline)));
+        lines
+    }
+}
+
+impl fmt::Display for NoticeDeadline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.explanation().join("\n"))
+    }
+}
+
+fn advance(date: NaiveDate, days: u32) -> NaiveDate {
+    date.checked_add_days(chrono::Days::new(days as u64))
+        .expect("Date overflow")
+}
+
+/// Why a date does not count, or `None` if it does
+fn closed(calendar: &dyn BusinessCalendar, date: NaiveDate, court: bool) -> Option<String> {
+    match date.weekday() {
+        Weekday::Sat => return Some("Saturday".to_string()),
+        Weekday::Sun => return Some("Sunday".to_string()),
+        _ => {}
+    }
+    let holiday = if court {
+        calendar.court_holiday(date)
+    } else {
+        calendar.holiday(date)
+    };
+    holiday.map(str::to_string)
+}
+
+fn long_date(date: NaiveDate) -> String {
+    date.format("%A, %B %-d, %Y").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_florida_three_business_days() {
+        // Served Friday before Memorial Day 2024
+        let deadline = NoticeDeadline::nonpayment(State::FL, date(2024, 5, 24)).unwrap();
+        assert_eq!(deadline.deadline, date(2024, 5, 30));
+        assert_eq!(deadline.count, DayCount::Business);
+        let reasons: Vec<&str> = deadline.skipped.iter().map(|d| d.reason.as_str()).collect();
+        assert_eq!(reasons, vec!["Saturday", "Sunday", "Memorial Day"]);
+
+        let explanation = deadline.explanation();
+        assert!(explanation[0].contains("F.S. § 83.56(3)"));
+        assert!(
+            explanation.contains(&"Not counted: Monday, May 27, 2024 (Memorial Day).".to_string())
+        );
+        assert_eq!(
+            explanation.last().unwrap(),
+            "Deadline: Thursday, May 30, 2024."
+        );
+    }
+
+    #[test]
+    fn test_texas_three_days_roll_forward() {
+        // Served Thursday July 1 2027: day 3 is Sunday July 4, and Monday
+        // July 5 is the observed Independence Day
+        let deadline = NoticeDeadline::nonpayment(State::TX, date(2027, 7, 1)).unwrap();
+        assert_eq!(deadline.rolled_from, Some(date(2027, 7, 4)));
+        assert_eq!(deadline.deadline, date(2027, 7, 6));
+        assert_eq!(deadline.skipped.len(), 2);
+        assert!(deadline.to_string().contains("which is not a business day"));
+
+        // Ending on a weekday, nothing moves
+        let deadline = NoticeDeadline::nonpayment(State::TX, date(2025, 6, 2)).unwrap();
+        assert_eq!(deadline.deadline, date(2025, 6, 5));
+        assert_eq!(deadline.rolled_from, None);
+    }
+
+    #[test]
+    fn test_texas_emancipation_day_extends_notice() {
+        // Served Monday June 16 2025: day 3 is Thursday June 19
+        let deadline = NoticeDeadline::nonpayment(State::TX, date(2025, 6, 16)).unwrap();
+        assert_eq!(deadline.rolled_from, Some(date(2025, 6, 19)));
+        assert_eq!(deadline.deadline, date(2025, 6, 20));
+        assert_eq!(deadline.skipped[0].reason, "Emancipation Day");
+    }
+
+    #[test]
+    fn test_nevada_judicial_days() {
+        // Served Friday November 21 2025; Thanksgiving and the day after
+        // are not judicial days
+        let deadline = NoticeDeadline::nonpayment(State::NV, date(2025, 11, 21)).unwrap();
+        assert_eq!(deadline.count, DayCount::Court);
+        assert_eq!(deadline.deadline, date(2025, 12, 4));
+    }
+
+    #[test]
+    fn test_new_york_fourteen_day_demand() {
+        // Served December 12 2025: day 14 is Friday December 26
+        let deadline = NoticeDeadline::nonpayment(State::NY, date(2025, 12, 12)).unwrap();
+        assert_eq!(deadline.days, 14);
+        assert_eq!(deadline.deadline, date(2025, 12, 26));
+
+        // Served December 11 2025: day 14 is Christmas, runs to the 26th
+        let deadline = NoticeDeadline::nonpayment(State::NY, date(2025, 12, 11)).unwrap();
+        assert_eq!(deadline.rolled_from, Some(date(2025, 12, 25)));
+        assert_eq!(deadline.deadline, date(2025, 12, 26));
+    }
+
+    #[test]
+    fn test_california_court_days() {
+        let deadline = NoticeDeadline::nonpayment(State::CA, date(2025, 3, 27)).unwrap();
+        assert_eq!(deadline.count, DayCount::Court);
+        assert_eq!(deadline.deadline, date(2025, 4, 2));
+        assert_eq!(deadline.skipped.last().unwrap().reason, "Cesar Chavez Day");
+    }
+
+    #[test]
+    fn test_calendar_days_ignore_holidays() {
+        let calendar = StateCalendar::for_state(State::WA);
+        let deadline =
+            NoticeDeadline::compute(calendar, date(2025, 12, 11), 14, DayCount::Calendar);
+        assert_eq!(deadline.deadline, date(2025, 12, 25));
+        assert!(deadline.skipped.is_empty());
+        assert!(NoticeDeadline::nonpayment(State::GA, date(2025, 1, 1)).is_none());
+    }
+}
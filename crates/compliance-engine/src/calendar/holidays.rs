@@ -0,0 +1,399 @@
+//! Holiday rules and per-state calendars from `data/holidays.toml`
+
+use super::{BusinessCalendar, DayCount};
+use crate::jurisdiction::State;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use shared_types::Coverage;
+use std::collections::HashMap;
+
+const HOLIDAYS_TOML: &str = include_str!("../../data/holidays.toml");
+
+/// When a holiday falls in a year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HolidayRule {
+    /// Same date every year
+    Fixed { month: u32, day: u32 },
+    /// The nth weekday of a month, e.g. the third Monday in January
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        n: u32,
+    },
+    /// The last weekday of a month, e.g. the last Monday in May
+    LastWeekday { month: u32, weekday: Weekday },
+    /// Days from Easter Sunday, e.g. -2 for Good Friday
+    Easter { offset: i64 },
+}
+
+impl HolidayRule {
+    /// The date the rule falls on in `year`
+    pub fn date(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            HolidayRule::LastWeekday { month, weekday } => {
+                let next_month = if month == 12 {
+                    NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+                } else {
+                    NaiveDate::from_ymd_opt(year, month + 1, 1)?
+                };
+                let last_day = next_month.pred_opt()?;
+                let back = (7 + last_day.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                Some(last_day - Duration::days(back as i64))
+            }
+            HolidayRule::Easter { offset } => Some(easter(year)? + Duration::days(offset)),
+        }
+    }
+}
+
+/// How a holiday falling on a weekend is observed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Observance {
+    /// Saturday holidays are observed Friday, Sunday holidays Monday
+    #[default]
+    NearestWeekday,
+    /// Sunday holidays are observed Monday; Saturday holidays are not moved
+    SundayToMonday,
+    /// Weekend holidays are observed the following Monday
+    NextWeekday,
+    /// Holidays are not moved
+    None,
+}
+
+impl Observance {
+    /// The day a holiday falling on `date` is observed
+    pub fn observed(&self, date: NaiveDate) -> NaiveDate {
+        match (self, date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Sat) => date - Duration::days(1),
+            (Observance::NearestWeekday | Observance::SundayToMonday, Weekday::Sun) => {
+                date + Duration::days(1)
+            }
+            (Observance::NextWeekday, Weekday::Sat) => date + Duration::days(2),
+            (Observance::NextWeekday, Weekday::Sun) => date + Duration::days(1),
+            _ => date,
+        }
+    }
+}
+
+/// Notice to pay rent or quit, and how its days are counted
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NoticePeriod {
+    pub days: u32,
+    pub count: DayCount,
+    pub statute: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Holiday {
+    name: String,
+    rule: HolidayRule,
+    /// Days after the rule's date, e.g. 1 for the day after Thanksgiving
+    #[serde(default)]
+    offset_days: i64,
+    /// First year the holiday was observed
+    since: Option<i32>,
+}
+
+impl Holiday {
+    fn date(&self, year: i32) -> Option<NaiveDate> {
+        if self.since.is_some_and(|since| year < since) {
+            return None;
+        }
+        Some(self.rule.date(year)? + Duration::days(self.offset_days))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct HolidayFile {
+    version: String,
+    holidays: HashMap<String, Holiday>,
+    states: HashMap<String, StateEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StateEntry {
+    holidays: Vec<String>,
+    court_holidays: Option<Vec<String>>,
+    #[serde(default)]
+    observance: Observance,
+    nonpayment_notice: Option<NoticePeriod>,
+    /// Why `nonpayment_notice` is not recorded
+    nonpayment_uncovered: Option<String>,
+}
+
+/// Legal and court holidays of one state
+#[derive(Debug, Clone)]
+pub struct StateCalendar {
+    state: State,
+    holidays: Vec<Holiday>,
+    court_holidays: Vec<Holiday>,
+    observance: Observance,
+    nonpayment_notice: Option<NoticePeriod>,
+    nonpayment_uncovered: Option<String>,
+}
+
+lazy_static! {
+    static ref DATA: (String, HashMap<State, StateCalendar>) = parse(HOLIDAYS_TOML);
+}
+
+impl StateCalendar {
+    /// Calendar for a state; every [`State`] has one
+    pub fn for_state(state: State) -> &'static StateCalendar {
+        DATA.1
+            .get(&state)
+            .unwrap_or_else(|| panic!("holidays.toml: no calendar for {:?}", state))
+    }
+
+    /// Dataset version from `data/holidays.toml`
+    pub fn version() -> &'static str {
+        &DATA.0
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn observance(&self) -> Observance {
+        self.observance
+    }
+
+    /// Notice to pay rent or quit, where its counting rule is recorded
+    pub fn nonpayment_notice(&self) -> Option<&NoticePeriod> {
+        self.nonpayment_notice.as_ref()
+    }
+
+    /// Whether the notice to pay rent or quit can be counted, and if not why
+    pub fn nonpayment_coverage(&self) -> Coverage {
+        match &self.nonpayment_uncovered {
+            Some(reason) => Coverage::Unavailable {
+                reason: reason.clone(),
+            },
+            None => Coverage::Full,
+        }
+    }
+
+    /// Observed legal holidays in `year`, in date order
+    pub fn holidays_in(&self, year: i32) -> Vec<(NaiveDate, &str)> {
+        let mut days: Vec<(NaiveDate, &str)> = [year, year + 1]
+            .iter()
+            .flat_map(|&y| {
+                self.holidays
+                    .iter()
+                    .filter_map(move |h| Some((self.observance.observed(h.date(y)?), &*h.name)))
+            })
+            .filter(|(date, _)| date.year() == year)
+            .collect();
+        days.sort();
+        days
+    }
+
+    fn find<'a>(&self, holidays: &'a [Holiday], date: NaiveDate) -> Option<&'a str> {
+        // A January 1 holiday can be observed on December 31
+        [date.year(), date.year() + 1].iter().find_map(|&year| {
+            holidays.iter().find_map(|h| {
+                let actual = h.date(year)?;
+                (actual == date || self.observance.observed(actual) == date)
+                    .then_some(h.name.as_str())
+            })
+        })
+    }
+}
+
+impl BusinessCalendar for StateCalendar {
+    fn name(&self) -> &str {
+        self.state.name()
+    }
+
+    fn holiday(&self, date: NaiveDate) -> Option<&str> {
+        self.find(&self.holidays, date)
+    }
+
+    fn court_holiday(&self, date: NaiveDate) -> Option<&str> {
+        self.find(&self.court_holidays, date)
+    }
+}
+
+/// Easter Sunday (anonymous Gregorian algorithm)
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+fn parse(toml: &str) -> (String, HashMap<State, StateCalendar>) {
+    let file: HolidayFile = toml::from_str(toml).expect("holidays.toml: invalid");
+    let resolve = |ids: &[String]| -> Vec<Holiday> {
+        ids.iter()
+            .map(|id| {
+                file.holidays
+                    .get(id)
+                    .unwrap_or_else(|| panic!("holidays.toml: unknown holiday {:?}", id))
+                    .clone()
+            })
+            .collect()
+    };
+
+    let calendars = file
+        .states
+        .iter()
+        .map(|(code, entry)| {
+            let state = State::parse_code(code)
+                .unwrap_or_else(|| panic!("holidays.toml: bad state {:?}", code));
+            assert!(
+                entry.nonpayment_notice.is_some() != entry.nonpayment_uncovered.is_some(),
+                "holidays.toml: {} needs one of nonpayment_notice and nonpayment_uncovered",
+                code
+            );
+            let holidays = resolve(&entry.holidays);
+            let court_holidays = match &entry.court_holidays {
+                Some(ids) => resolve(ids),
+                None => holidays.clone(),
+            };
+            let calendar = StateCalendar {
+                state,
+                holidays,
+                court_holidays,
+                observance: entry.observance,
+                nonpayment_notice: entry.nonpayment_notice.clone(),
+                nonpayment_uncovered: entry.nonpayment_uncovered.clone(),
+            };
+            (state, calendar)
+        })
+        .collect();
+    (file.version, calendars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_every_state_has_a_calendar() {
+        assert_eq!(StateCalendar::version(), "2025.11");
+        for state in State::implemented_states() {
+            let calendar = StateCalendar::for_state(state);
+            let holidays = calendar.holidays_in(2025);
+            assert!(holidays.len() >= 8, "{:?}: {:?}", state, holidays);
+            assert!(
+                holidays.iter().any(|(_, name)| *name == "Christmas Day"),
+                "{:?}",
+                state
+            );
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        let mlk = HolidayRule::NthWeekday {
+            month: 1,
+            weekday: Weekday::Mon,
+            n: 3,
+        };
+        assert_eq!(mlk.date(2025), Some(date(2025, 1, 20)));
+        let memorial = HolidayRule::LastWeekday {
+            month: 5,
+            weekday: Weekday::Mon,
+        };
+        assert_eq!(memorial.date(2024), Some(date(2024, 5, 27)));
+        assert_eq!(memorial.date(2025), Some(date(2025, 5, 26)));
+        let good_friday = HolidayRule::Easter { offset: -2 };
+        assert_eq!(good_friday.date(2024), Some(date(2024, 3, 29)));
+        assert_eq!(good_friday.date(2025), Some(date(2025, 4, 18)));
+        let fifth_monday = HolidayRule::NthWeekday {
+            month: 2,
+            weekday: Weekday::Mon,
+            n: 5,
+        };
+        assert_eq!(fifth_monday.date(2025), None);
+    }
+
+    #[test]
+    fn test_observance() {
+        let saturday = date(2026, 7, 4);
+        let sunday = date(2027, 7, 4);
+        assert_eq!(
+            Observance::NearestWeekday.observed(saturday),
+            date(2026, 7, 3)
+        );
+        assert_eq!(
+            Observance::NearestWeekday.observed(sunday),
+            date(2027, 7, 5)
+        );
+        assert_eq!(Observance::SundayToMonday.observed(saturday), saturday);
+        assert_eq!(Observance::NextWeekday.observed(saturday), date(2026, 7, 6));
+        assert_eq!(Observance::None.observed(sunday), sunday);
+
+        // New Year's Day 2022 fell on Saturday and was observed December 31
+        let florida = StateCalendar::for_state(State::FL);
+        assert_eq!(florida.holiday(date(2021, 12, 31)), Some("New Year's Day"));
+        // New York moves only Sunday holidays
+        let new_york = StateCalendar::for_state(State::NY);
+        assert_eq!(new_york.holiday(date(2026, 7, 3)), None);
+        assert_eq!(new_york.holiday(date(2027, 7, 5)), Some("Independence Day"));
+    }
+
+    #[test]
+    fn test_state_specific_holidays() {
+        let texas = StateCalendar::for_state(State::TX);
+        let massachusetts = StateCalendar::for_state(State::MA);
+        let louisiana = StateCalendar::for_state(State::LA);
+        // Patriots' Day 2025 is April 21
+        assert_eq!(
+            massachusetts.holiday(date(2025, 4, 21)),
+            Some("Patriots' Day")
+        );
+        assert_eq!(texas.holiday(date(2025, 4, 21)), None);
+        // Mardi Gras 2025 is March 4
+        assert_eq!(louisiana.holiday(date(2025, 3, 4)), Some("Mardi Gras"));
+        // Juneteenth was added in 2021
+        let oregon = StateCalendar::for_state(State::OR);
+        assert_eq!(oregon.holiday(date(2025, 6, 19)), Some("Juneteenth"));
+        assert_eq!(oregon.holiday(date(2020, 6, 19)), None);
+        // Texas has kept June 19 since long before
+        assert_eq!(texas.holiday(date(2020, 6, 19)), Some("Emancipation Day"));
+    }
+
+    #[test]
+    fn test_nonpayment_notice_recorded_or_uncovered() {
+        for state in State::implemented_states() {
+            let calendar = StateCalendar::for_state(state);
+            match calendar.nonpayment_coverage() {
+                Coverage::Full => assert!(calendar.nonpayment_notice().is_some(), "{:?}", state),
+                Coverage::Unavailable { reason } => {
+                    assert!(calendar.nonpayment_notice().is_none(), "{:?}", state);
+                    assert!(!reason.is_empty(), "{:?}", state);
+                }
+                Coverage::Core => unreachable!(),
+            }
+        }
+        let georgia = StateCalendar::for_state(State::GA);
+        assert!(matches!(
+            georgia.nonpayment_coverage(),
+            Coverage::Unavailable { reason } if reason.contains("44-7-50")
+        ));
+    }
+}
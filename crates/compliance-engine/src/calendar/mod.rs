@@ -0,0 +1,215 @@
+//! Business-day calendars for notice periods
+//!
+//! Each state's legal holidays are data in `data/holidays.toml`: fixed dates,
+//! nth and last weekdays of a month and Easter offsets, with a rule for how a
+//! holiday falling on a weekend is observed. [`StateCalendar`] loads them for
+//! any [`State`]; [`NoticeDeadline`] counts a notice period on a calendar and
+//! records why each skipped day was skipped.
+
+mod deadline;
+mod holidays;
+
+pub use deadline::{NoticeDeadline, SkippedDay};
+pub use holidays::{HolidayRule, NoticePeriod, Observance, StateCalendar};
+
+use crate::jurisdiction::State;
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// How the days of a notice period are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayCount {
+    /// Every day counts
+    Calendar,
+    /// Every day counts, but a period ending on a weekend or legal holiday
+    /// runs to the next business day
+    CalendarRollForward,
+    /// Weekends and legal holidays are not counted
+    Business,
+    /// Weekends and court holidays are not counted
+    Court,
+}
+
+impl DayCount {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            DayCount::Calendar => "calendar days",
+            DayCount::CalendarRollForward => "calendar days, extended past a weekend or holiday",
+            DayCount::Business => "business days (excluding weekends and legal holidays)",
+            DayCount::Court => "court days (excluding weekends and court holidays)",
+        }
+    }
+}
+
+/// Weekends and holidays of a jurisdiction
+pub trait BusinessCalendar {
+    /// Name of the jurisdiction, e.g. "Texas"
+    fn name(&self) -> &str;
+
+    /// Name of the legal holiday observed on `date`, if any
+    fn holiday(&self, date: NaiveDate) -> Option<&str>;
+
+    /// Name of the court holiday observed on `date`, if any
+    fn court_holiday(&self, date: NaiveDate) -> Option<&str> {
+        self.holiday(date)
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    /// Check if a date is a business day (not weekend or holiday)
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && self.holiday(date).is_none()
+    }
+
+    /// Check if a date is a court day (not weekend or court holiday)
+    fn is_court_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && self.court_holiday(date).is_none()
+    }
+
+    /// Add business days to a date; the start date itself is not counted
+    fn add_business_days(&self, start: NaiveDate, days: u32) -> NaiveDate {
+        add_counted_days(start, days, |date| self.is_business_day(date))
+    }
+
+    /// Add court days to a date; the start date itself is not counted
+    fn add_court_days(&self, start: NaiveDate, days: u32) -> NaiveDate {
+        add_counted_days(start, days, |date| self.is_court_day(date))
+    }
+
+    /// The date itself if it is a business day, otherwise the next one
+    fn next_business_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut current = date;
+        while !self.is_business_day(current) {
+            current = current.succ_opt().expect("Date overflow");
+        }
+        current
+    }
+}
+
+fn add_counted_days(start: NaiveDate, days: u32, counts: impl Fn(NaiveDate) -> bool) -> NaiveDate {
+    let mut current_date = start;
+    let mut remaining_days = days;
+
+    while remaining_days > 0 {
+        current_date = current_date.succ_opt().expect("Date overflow");
+
+        if counts(current_date) {
+            remaining_days -= 1;
+        }
+    }
+
+    current_date
+}
+
+/// Florida Business Calendar for calculating notice periods per § 83.56 and § 83.57
+///
+/// Shorthand for [`StateCalendar::for_state`]`(State::FL)`.
+pub struct FloridaBusinessCalendar;
+
+impl FloridaBusinessCalendar {
+    /// Add business days to a date, excluding weekends and Florida state holidays
+    pub fn add_business_days(start_date: NaiveDate, days: u32) -> NaiveDate {
+        Self::calendar().add_business_days(start_date, days)
+    }
+
+    /// Check if a date is a business day (not weekend or holiday)
+    pub fn is_business_day(date: NaiveDate) -> bool {
+        Self::calendar().is_business_day(date)
+    }
+
+    /// Check if a date is a Florida state holiday
+    pub fn is_holiday(date: NaiveDate) -> bool {
+        Self::calendar().holiday(date).is_some()
+    }
+
+    fn calendar() -> &'static StateCalendar {
+        StateCalendar::for_state(State::FL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_three_day_notice_excludes_weekends() {
+        // Friday notice should have deadline on Wednesday
+        let notice_date = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(); // Friday
+        let deadline = FloridaBusinessCalendar::add_business_days(notice_date, 3);
+        assert_eq!(deadline, NaiveDate::from_ymd_opt(2024, 1, 10).unwrap()); // Wednesday
+    }
+
+    #[test]
+    fn test_excludes_florida_holidays() {
+        // Memorial Day 2024 is May 27
+        let notice_date = NaiveDate::from_ymd_opt(2024, 5, 24).unwrap(); // Friday before
+        let deadline = FloridaBusinessCalendar::add_business_days(notice_date, 3);
+        // Skip Sat, Sun, Memorial Day Monday = Tuesday May 28, Wed 29, Thu 30
+        assert_eq!(deadline, NaiveDate::from_ymd_opt(2024, 5, 30).unwrap());
+    }
+
+    #[test]
+    fn test_new_years_day() {
+        let notice_date = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(); // Friday
+        let deadline = FloridaBusinessCalendar::add_business_days(notice_date, 3);
+        // Skip Sat 30, Sun 31, Mon Jan 1 (holiday) = Tue 2, Wed 3, Thu 4
+        assert_eq!(deadline, NaiveDate::from_ymd_opt(2024, 1, 4).unwrap());
+    }
+
+    #[test]
+    fn test_is_florida_holiday() {
+        // New Year's Day 2024
+        assert!(FloridaBusinessCalendar::is_holiday(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        ));
+        // Regular day
+        assert!(!FloridaBusinessCalendar::is_holiday(
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_seven_day_notice() {
+        let notice_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(); // Friday
+        let deadline = FloridaBusinessCalendar::add_business_days(notice_date, 7);
+        // 7 business days from Friday March 1
+        assert_eq!(deadline, NaiveDate::from_ymd_opt(2024, 3, 12).unwrap());
+    }
+
+    #[test]
+    fn test_court_days_skip_judicial_holidays() {
+        let california = StateCalendar::for_state(State::CA);
+        // Cesar Chavez Day 2025 is Monday March 31
+        assert_eq!(
+            california.holiday(date(2025, 3, 31)),
+            Some("Cesar Chavez Day")
+        );
+        assert_eq!(
+            california.add_court_days(date(2025, 3, 27), 3),
+            date(2025, 4, 2)
+        );
+        // Columbus Day is a state holiday but courts are open
+        assert!(!california.is_business_day(date(2025, 10, 13)));
+        assert!(california.is_court_day(date(2025, 10, 13)));
+    }
+
+    #[test]
+    fn test_next_business_day() {
+        let texas = StateCalendar::for_state(State::TX);
+        // Christmas Eve, Christmas and the day after, then the weekend
+        assert_eq!(
+            texas.next_business_day(date(2025, 12, 24)),
+            date(2025, 12, 29)
+        );
+        assert_eq!(texas.next_business_day(date(2025, 7, 5)), date(2025, 7, 7));
+        assert_eq!(texas.next_business_day(date(2025, 7, 7)), date(2025, 7, 7));
+    }
+}
@@ -0,0 +1,546 @@
+//! Clause segmentation and match context
+//!
+//! Keyword checks fire on words, not on what a clause says: "Landlord shall
+//! NOT require Tenant to waive any rights" contains "waive", and a quoted
+//! statute contains whatever it prohibits. This module splits a lease into
+//! clauses (sentences and numbered paragraphs) and classifies each stretch of
+//! text as one of:
+//!
+//! - negated - inside the scope of "not", "never", "in no event", ...
+//! - quoted - inside a quotation of four or more words
+//! - citation - a clause reciting the law ("§ 83.47 provides that ...",
+//!   "any such waiver is void")
+//! - carved out - a clause limited by "except as provided by law" and the like
+//!
+//! A sentence can join several clauses ("Smoking is prohibited and Tenant
+//! waives ..."), so citations and carve-outs cover only the clause they sit
+//! in. A carve-out never covers a waiver: the statutes void those outright,
+//! and deferring to the law does not make one lawful.
+//!
+//! Spanish leases are classified the same way. Only unambiguous Spanish
+//! negations count ("nunca", "en ningún caso", "no renunciará"): a bare "no"
+//! also opens prohibitions such as "no se admiten niños", which must stay
+//! operative.
+//!
+//! [`operative_text`] blanks everything that is not operative while keeping
+//! byte offsets, so checks can run on it unchanged and still report positions
+//! in the original text.
+
+use crate::patterns::floor_char_boundary;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::ops::Range;
+
+/// Words a negation reaches at most, when nothing ends its scope earlier
+const MAX_SCOPE_WORDS: usize = 25;
+
+/// Quotations shorter than this are defined terms or labels ("AS IS",
+/// the "Premises"), not quoted provisions
+const MIN_QUOTED_WORDS: usize = 4;
+
+/// Quotations are not followed further than this without a closing mark
+const MAX_QUOTE_BYTES: usize = 1000;
+
+/// Words ending in a period that do not end a sentence
+const ABBREVIATIONS: &[&str] = &[
+    "no", "nos", "sec", "secs", "ss", "art", "para", "ch", "stat", "stats", "fla", "tex", "cal",
+    "civ", "prop", "gen", "oblig", "ann", "rev", "admin", "ga", "ill", "comp", "mass", "mich",
+    "penn", "pa", "tenn", "va", "wash", "mr", "mrs", "ms", "dr", "inc", "llc", "ltd", "co", "corp",
+    "st", "ave", "blvd", "apt", "ste", "vs", "approx", "jr", "sr",
+];
+
+lazy_static! {
+    /// Number opening a clause: "5.", "12.3", "(a)", "iv)", "Section 4"
+    static ref CLAUSE_NUMBER: Regex = Regex::new(
+        r"(?i)^\s*((?:section|article|paragraph)\s+\d+(?:\.\d+)*\.?|\d+(?:\.\d+)*[.)]|\([a-z0-9]{1,4}\)|[a-z0-9]{1,4}\))\s"
+    )
+    .unwrap();
+
+    static ref NEGATOR: Regex = Regex::new(
+        r"(?i)\b(?:not|never|neither|nor|cannot|in\s+no\s+event|under\s+no\s+circumstances?|nothing\s+(?:in|herein|contained)|no\s+(?:provision|term|clause|part|portion)\s+of)\b|n['’]t\b|\b(?:nunca|jam[aá]s|en\s+ning[uú]n\s+caso|bajo\s+ninguna\s+circunstancia|nada\s+(?:en|de\s+lo\s+contenido\s+en)\s+este|ninguna\s+(?:disposici[oó]n|cl[aá]usula|parte)\s+de|no\s+(?:se\s+)?(?:renuncia\w*|exigir[aá]|requerir[aá]|obligar[aá]|podr[aá]n?|deber[aá]n?|autoriza(?:r[aá]|n)?|permitir[aá]))\b"
+    )
+    .unwrap();
+
+    /// Punctuation and words that end a negation's scope
+    static ref SCOPE_END: Regex = Regex::new(
+        r"(?i)[;:!?]|\b(?:but|however|except|unless|provided|although|whereas|instead|pero|sin\s+embargo|excepto|salvo|a\s+menos\s+que|siempre\s+que)\b|\b(?:and|or)\s+(?:the\s+)?(?:tenants?|landlords?|lessees?|lessors?|owners?|residents?|hereby|further|waives?|agrees?|releases?|authorizes?)\b|\b(?:y|o)\s+(?:el\s+|la\s+)?(?:inquilinos?|arrendador(?:es)?|arrendatarios?|propietarios?|renuncia\w*)\b"
+    )
+    .unwrap();
+
+    /// Opens a conditional clause ("If rent is not paid, ..."), whose
+    /// negations end at the comma closing it
+    static ref CONDITIONAL: Regex = Regex::new(
+        r"(?i)\b(?:if|when|whenever|in\s+the\s+event|in\s+case|should|si|cuando|en\s+caso\s+de\s+que)\b[^,;]*$"
+    )
+    .unwrap();
+
+    /// A line holding nothing but a clause number, up to its period
+    static ref NUMBER_ONLY: Regex =
+        Regex::new(r"(?i)^\s*(?:(?:section|article|paragraph)\s+)?\d+(?:\.\d+)*$").unwrap();
+
+    static ref WORD: Regex = Regex::new(r"\S+").unwrap();
+
+    /// Recites the law; everything after it in the sentence is the recital
+    static ref RECITAL: Regex = Regex::new(
+        r"(?i)(?:§|\bsection\s+\d|\bstatutes?\b|\bcode\b|\blaw\b|\bact\b)[^;]{0,120}?\b(?:provides|states|reads|declares)(?:\s+that\b|\s*:)|(?:§|\bsecci[oó]n\s+\d|\bley\b|\bc[oó]digo\b|\bestatutos?\b)[^;]{0,120}?\b(?:dispone|establece|indica)\s+que\b"
+    )
+    .unwrap();
+
+    /// Declares something void, stating the law for the clause it ends
+    static ref DECLARATION: Regex = Regex::new(
+        r"(?i)\b(?:is|are|shall\s+be|will\s+be)\s+(?:null\s+and\s+)?(?:void|unenforceable|prohibited|of\s+no\s+(?:force|effect))\b|\b(?:es|son|ser[aá]n?)\s+(?:nul[oa]s?|inv[aá]lid[oa]s?|prohibid[oa]s?|inaplicables?)\b"
+    )
+    .unwrap();
+
+    /// Where a sentence joins two clauses: a semicolon, or a conjunction
+    /// opening a new subject ("... and Tenant waives ...")
+    static ref MEMBER_BREAK: Regex = Regex::new(
+        r"(?i);|\b(?:and|or|but)\s+(?:the\s+)?(?:tenants?|landlords?|lessees?|lessors?|owners?|residents?)\s|\b(?:and|or|but)\s+(?:hereby|further)\b|\b(?:y|o|pero)\s+(?:el\s+|la\s+)?(?:inquilinos?|arrendador(?:es)?|arrendatarios?|propietarios?)\s"
+    )
+    .unwrap();
+
+    /// A waiver, which is void however the clause defers to the law
+    static ref WAIVER: Regex = Regex::new(
+        r"(?i)\b(?:waives?|waiving|waivers?|relinquish(?:es)?|renuncia\w*|renuncie\w*)\b"
+    )
+    .unwrap();
+
+    /// A clause that yields to the law where the law says otherwise
+    static ref CARVE_OUT: Regex = Regex::new(
+        r"(?i)\bexcept\s+(?:as|where|to\s+the\s+extent)\s+(?:otherwise\s+)?(?:provided|required|permitted|allowed|prohibited)\s+by\s+(?:applicable\s+)?(?:law|statute)|\bunless\s+(?:otherwise\s+)?(?:prohibited|required)\s+by\s+(?:applicable\s+)?law|\bto\s+the\s+(?:fullest\s+)?extent\s+(?:permitted|allowed)\s+by\s+(?:applicable\s+)?law|\bsubject\s+to\s+(?:applicable\s+law|chapter\s+83)|\b(?:salvo|excepto)\s+(?:lo\s+)?(?:dispuesto|previsto|permitido|requerido|exigido)\s+(?:por|en)\s+la\s+ley|\ben\s+la\s+medida\s+(?:en\s+que\s+lo\s+)?(?:permitid[oa]|permita)\s+(?:por\s+)?la\s+ley|\bsujeto\s+a\s+(?:la\s+ley\s+aplicable|el\s+cap[ií]tulo\s+83)"
+    )
+    .unwrap();
+}
+
+/// A sentence or numbered paragraph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause<'a> {
+    /// Byte offset of the clause in the segmented text
+    pub start: usize,
+    /// Clause text, including its closing punctuation
+    pub text: &'a str,
+    /// Leading number such as "5.", "(a)" or "Section 4", if any
+    pub number: Option<&'a str>,
+}
+
+impl Clause<'_> {
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+}
+
+/// How a match should be read, from where it sits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchContext {
+    /// A term of the lease
+    Operative,
+    /// Inside the scope of a negation
+    Negated,
+    /// Inside a quotation
+    Quoted,
+    /// In a clause reciting the law
+    Citation,
+    /// In a clause that defers to the law
+    CarvedOut,
+}
+
+impl MatchContext {
+    pub fn is_operative(self) -> bool {
+        self == MatchContext::Operative
+    }
+}
+
+/// Split text into sentences and numbered paragraphs
+///
+/// A clause ends at `.`, `!` or `?` followed by whitespace (not after an
+/// abbreviation such as "F.S." or "Stat."), at a blank line, or before a line
+/// that opens with a clause number.
+pub fn segment(text: &str) -> Vec<Clause<'_>> {
+    let mut clauses = Vec::new();
+    let mut start = 0;
+    for end in boundaries(text).into_iter().chain([text.len()]) {
+        if end <= start {
+            continue;
+        }
+        let piece = &text[start..end];
+        let trimmed = piece.trim_start();
+        if !trimmed.trim_end().is_empty() {
+            let offset = start + piece.len() - trimmed.len();
+            let clause = &text[offset..end];
+            clauses.push(Clause {
+                start: offset,
+                text: clause,
+                number: CLAUSE_NUMBER
+                    .captures(clause)
+                    .and_then(|caps| caps.get(1))
+                    .map(|m| m.as_str()),
+            });
+        }
+        start = end;
+    }
+    clauses
+}
+
+fn boundaries(text: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let bytes = text.as_bytes();
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '\n' => {
+                let rest = &text[i + 1..];
+                let next_line = rest.split('\n').next().unwrap_or("");
+                if next_line.trim().is_empty() || CLAUSE_NUMBER.is_match(rest) {
+                    ends.push(i + 1);
+                }
+            }
+            '.' | '!' | '?' => {
+                // Closing quotes and brackets stay with their sentence
+                let mut end = i + 1;
+                while let Some(next) = text[end..].chars().next() {
+                    if matches!(next, '"' | '”' | '’' | '\'' | ')' | ']') {
+                        end += next.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+                let followed_by_space = end >= bytes.len() || bytes[end].is_ascii_whitespace();
+                let line = &text[text[..i].rfind('\n').map_or(0, |n| n + 1)..i];
+                let continues =
+                    c == '.' && (is_abbreviation(&text[..i]) || NUMBER_ONLY.is_match(line));
+                if followed_by_space && !continues {
+                    ends.push(end);
+                }
+            }
+            _ => {}
+        }
+    }
+    ends
+}
+
+/// Whether the word just before a period is an abbreviation
+fn is_abbreviation(before: &str) -> bool {
+    let word = before
+        .rsplit(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .unwrap_or("");
+    let letters = word.chars().filter(|c| c.is_alphabetic()).count();
+    // Initials ("A."), dotted forms ("F.S", "U.S.C", "e.g") and known short forms
+    (letters == 1 && word.chars().count() == 1)
+        || (word.contains('.') && letters > 0)
+        || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Byte ranges of text that is not operative, with the reason, in text order
+pub fn non_operative_spans(text: &str) -> Vec<(Range<usize>, MatchContext)> {
+    let mut spans: Vec<(Range<usize>, MatchContext)> = quoted_spans(text)
+        .into_iter()
+        .map(|range| (range, MatchContext::Quoted))
+        .collect();
+    let unquoted = blank(text, spans.iter().map(|(range, _)| range));
+
+    for clause in segment(&unquoted) {
+        // A recital runs to the end of the sentence, whatever it joins
+        let recital = RECITAL.find(clause.text).map(|m| m.start());
+        for member in members(clause.text) {
+            let text = &clause.text[member.clone()];
+            let span = clause.start + member.start..clause.start + member.end;
+            if recital.is_some_and(|at| at < member.end) {
+                spans.push((
+                    span.start..clause.start + clause.text.len(),
+                    MatchContext::Citation,
+                ));
+                break;
+            }
+            if DECLARATION.is_match(text) {
+                spans.push((span, MatchContext::Citation));
+                continue;
+            }
+            // "To the extent permitted by law" cannot save a waiver the
+            // law voids outright
+            if CARVE_OUT.is_match(text) && !WAIVER.is_match(text) {
+                spans.push((span, MatchContext::CarvedOut));
+                continue;
+            }
+            for negator in NEGATOR.find_iter(text) {
+                let scope = negation_scope(clause.text, member.start + negator.start());
+                spans.push((
+                    clause.start + scope.start..clause.start + scope.end,
+                    MatchContext::Negated,
+                ));
+            }
+        }
+    }
+
+    spans.sort_by_key(|(range, _)| range.start);
+    spans
+}
+
+/// How the text at `offset` should be read
+pub fn context_at(text: &str, offset: usize) -> MatchContext {
+    non_operative_spans(text)
+        .into_iter()
+        .find(|(range, _)| range.contains(&offset))
+        .map_or(MatchContext::Operative, |(_, context)| context)
+}
+
+/// The text with negated, quoted, recited and carved-out stretches replaced
+/// by spaces
+///
+/// Byte offsets are unchanged, so a match in the result is at the same
+/// position in the original text.
+pub fn operative_text(text: &str) -> String {
+    let spans = non_operative_spans(text);
+    blank(text, spans.iter().map(|(range, _)| range))
+}
+
+/// Ranges of the clauses a sentence joins, each starting at its conjunction
+fn members(clause: &str) -> Vec<Range<usize>> {
+    let mut members = Vec::new();
+    let mut start = 0;
+    for join in MEMBER_BREAK.find_iter(clause) {
+        if join.start() > start {
+            members.push(start..join.start());
+            start = join.start();
+        }
+    }
+    members.push(start..clause.len());
+    members
+}
+
+fn negation_scope(clause: &str, from: usize) -> Range<usize> {
+    let rest = &clause[from..];
+    // The negator itself never ends its own scope
+    let skip = NEGATOR.find(rest).map_or(0, |m| m.end());
+    let mut end = SCOPE_END
+        .find_at(rest, skip)
+        .map_or(rest.len(), |m| m.start());
+    if CONDITIONAL.is_match(&clause[..from]) {
+        if let Some(comma) = rest.find(',') {
+            end = end.min(comma);
+        }
+    }
+    if let Some(word) = WORD.find_iter(rest).nth(MAX_SCOPE_WORDS) {
+        end = end.min(word.start());
+    }
+    from..from + end
+}
+
+/// Inner ranges of quotations of at least [`MIN_QUOTED_WORDS`] words
+fn quoted_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut from = 0;
+
+    while let Some((open, mark)) = text[from..]
+        .char_indices()
+        .find(|(_, c)| matches!(c, '"' | '“'))
+        .map(|(i, c)| (from + i, c))
+    {
+        let inner = open + mark.len_utf8();
+        let close = if mark == '“' { '”' } else { '"' };
+        let limit = (inner + MAX_QUOTE_BYTES).min(text.len());
+        let window = &text[inner..floor_char_boundary(text, limit)];
+        match window.find(close) {
+            Some(len) => {
+                if WORD.find_iter(&window[..len]).count() >= MIN_QUOTED_WORDS {
+                    spans.push(inner..inner + len);
+                }
+                from = inner + len + close.len_utf8();
+            }
+            None => from = inner,
+        }
+    }
+    spans
+}
+
+/// Replace every non-whitespace character in the ranges with spaces
+fn blank<'r>(text: &str, ranges: impl Iterator<Item = &'r Range<usize>>) -> String {
+    let mut masked = vec![false; text.len()];
+    for range in ranges {
+        masked[range.clone()].iter_mut().for_each(|m| *m = true);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.char_indices() {
+        if masked[i] && !c.is_whitespace() {
+            out.extend(std::iter::repeat_n(' ', c.len_utf8()));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_sentences_and_numbers() {
+        let text = "1. Rent is due on the 1st. Late fees apply per F.S. § 83.808.\n\
+                    2. Tenant shall keep the Premises clean.\n(a) No pets.";
+        let clauses = segment(text);
+        let texts: Vec<&str> = clauses.iter().map(|c| c.text.trim_end()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "1. Rent is due on the 1st.",
+                "Late fees apply per F.S. § 83.808.",
+                "2. Tenant shall keep the Premises clean.",
+                "(a) No pets.",
+            ]
+        );
+        assert_eq!(clauses[0].number, Some("1."));
+        assert_eq!(clauses[1].number, None);
+        assert_eq!(clauses[3].number, Some("(a)"));
+        assert_eq!(&text[clauses[2].span()], clauses[2].text);
+    }
+
+    #[test]
+    fn test_abbreviations_do_not_split() {
+        let text = "See Fla. Stat. § 83.49 and Tex. Prop. Code § 92.103. Next sentence.";
+        assert_eq!(segment(text).len(), 2);
+    }
+
+    #[test]
+    fn test_negation_scope() {
+        let text = "Landlord shall NOT require Tenant to waive any rights.";
+        let waive = text.find("waive").unwrap();
+        assert_eq!(context_at(text, waive), MatchContext::Negated);
+        assert!(!operative_text(text).contains("waive"));
+        assert!(operative_text(text).starts_with("Landlord shall"));
+
+        // The scope ends where a new subject takes over
+        let text = "Landlord is not liable for noise, and Tenant waives all notice.";
+        let waive = text.find("waives").unwrap();
+        assert_eq!(context_at(text, waive), MatchContext::Operative);
+        // A negation later in the sentence does not reach back
+        let text = "Tenant waives all rights and Landlord shall not be liable.";
+        assert_eq!(context_at(text, 7), MatchContext::Operative);
+        // A negation in a conditional ends with the conditional
+        let text = "If rent is not received by the 3rd, a late fee of 13% will be charged.";
+        let fee = text.find("late fee").unwrap();
+        assert_eq!(context_at(text, fee), MatchContext::Operative);
+        let text = "Landlord shall not, if Tenant asks, require Tenant to waive notice.";
+        let waive = text.find("waive").unwrap();
+        assert_eq!(context_at(text, waive), MatchContext::Negated);
+    }
+
+    #[test]
+    fn test_quotations_and_citations() {
+        let text = "Section 83.47 provides that a provision waiving notice is void. \
+                    Tenant accepts the unit \"AS IS\".";
+        assert_eq!(
+            context_at(text, text.find("waiving").unwrap()),
+            MatchContext::Citation
+        );
+        // A two-word quotation is a label, not a quotation
+        assert_eq!(
+            context_at(text, text.find("AS IS").unwrap()),
+            MatchContext::Operative
+        );
+
+        let text = "Tenant received the notice “a rental agreement shall not waive the \
+                    tenant's right to notice.” Tenant pays rent monthly.";
+        assert_eq!(
+            context_at(text, text.find("rental").unwrap()),
+            MatchContext::Quoted
+        );
+        assert_eq!(
+            context_at(text, text.find("monthly").unwrap()),
+            MatchContext::Operative
+        );
+    }
+
+    #[test]
+    fn test_carve_out() {
+        let text = "Except as provided by law, Landlord may enter on 12 hours notice.";
+        assert_eq!(
+            context_at(text, text.find("enter").unwrap()),
+            MatchContext::CarvedOut
+        );
+        // Deferring to the law does not save a waiver the law voids
+        let text = "To the fullest extent permitted by law, Tenant waives all rights to \
+                    notice before eviction.";
+        assert_eq!(
+            context_at(text, text.find("waives").unwrap()),
+            MatchContext::Operative
+        );
+        let text = "Except as required by law, Landlord may enter at any time and Tenant \
+                    waives notice.";
+        assert_eq!(
+            context_at(text, text.find("enter").unwrap()),
+            MatchContext::CarvedOut
+        );
+        assert_eq!(
+            context_at(text, text.find("waives").unwrap()),
+            MatchContext::Operative
+        );
+    }
+
+    #[test]
+    fn test_citation_covers_only_its_clause() {
+        let text = "Smoking is prohibited and Tenant waives all rights to notice before eviction.";
+        assert_eq!(
+            context_at(text, text.find("Smoking").unwrap()),
+            MatchContext::Citation
+        );
+        assert_eq!(
+            context_at(text, text.find("waives").unwrap()),
+            MatchContext::Operative
+        );
+        // A recital covers the rest of the sentence, conjunctions and all
+        let text = "F.S. § 83.47 provides that a lease is void if it waives notice and \
+                    Tenant waives it anyway.";
+        assert_eq!(
+            context_at(text, text.rfind("waives").unwrap()),
+            MatchContext::Citation
+        );
+        // "null and void" and a possessive do not split a clause
+        let text = "Any waiver of notice and the tenant's right to cure is null and void.";
+        assert_eq!(
+            context_at(text, text.find("waiver").unwrap()),
+            MatchContext::Citation
+        );
+    }
+
+    #[test]
+    fn test_spanish_context() {
+        let text = "El Arrendador no exigirá que el Inquilino renuncie a sus derechos.";
+        assert_eq!(
+            context_at(text, text.find("renuncie").unwrap()),
+            MatchContext::Negated
+        );
+        // A bare "no" opens a prohibition, which stays operative
+        let text = "No se admiten niños en la propiedad.";
+        assert_eq!(
+            context_at(text, text.find("niños").unwrap()),
+            MatchContext::Operative
+        );
+
+        let text = "La sección 83.47 establece que tal renuncia es nula.";
+        assert_eq!(
+            context_at(text, text.find("renuncia").unwrap()),
+            MatchContext::Citation
+        );
+        let text = "Salvo lo dispuesto por la ley, el Arrendador podrá entrar con aviso.";
+        assert_eq!(
+            context_at(text, text.find("entrar").unwrap()),
+            MatchContext::CarvedOut
+        );
+        let text = "Salvo lo dispuesto por la ley, el Inquilino renuncia al aviso.";
+        assert_eq!(
+            context_at(text, text.find("renuncia").unwrap()),
+            MatchContext::Operative
+        );
+    }
+
+    #[test]
+    fn test_operative_text_keeps_offsets() {
+        let text = "Landlord’s “tenant shall never waive notice” — señor. Rent is due.";
+        let operative = operative_text(text);
+        assert_eq!(operative.len(), text.len());
+        assert!(operative.starts_with("Landlord’s “"));
+        assert!(operative.ends_with("” — señor. Rent is due."));
+    }
+}
@@ -0,0 +1,222 @@
+//! Effective dates for statutory rules
+//!
+//! Every rule is in force over an [`EffectiveRange`]. A hand-written rule
+//! that took effect (or was repealed) recently is declared as a
+//! [`DatedRule`] constant next to its check, and the check reports its
+//! violations under that constant's citation. Undated rules have been in
+//! force for as long as leases we check could have been signed. Rule packs
+//! carry their own dates per rule.
+//!
+//! Checking "as of" a date drops violations for rules not yet in force (or
+//! already repealed) on that date, so a 2023 lease is not flagged under a 2025
+//! statute.
+
+use crate::{layers, states};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use shared_types::Violation;
+
+/// Dates a rule is in force, both ends inclusive; `None` means unbounded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveRange {
+    pub effective_from: Option<NaiveDate>,
+    pub effective_to: Option<NaiveDate>,
+}
+
+impl EffectiveRange {
+    /// In force on every date
+    pub const ALWAYS: Self = Self {
+        effective_from: None,
+        effective_to: None,
+    };
+
+    pub fn new(effective_from: Option<NaiveDate>, effective_to: Option<NaiveDate>) -> Self {
+        Self {
+            effective_from,
+            effective_to,
+        }
+    }
+
+    /// Whether the rule is in force on `date`
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.effective_from.is_none_or(|from| date >= from)
+            && self.effective_to.is_none_or(|to| date <= to)
+    }
+}
+
+/// A covered citation with the dates it applies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoveredStatute {
+    pub citation: String,
+    #[serde(flatten)]
+    pub range: EffectiveRange,
+}
+
+/// A hand-written rule's citation and the dates it is in force
+///
+/// The citation is the exact `Violation::statute` the rule reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatedRule {
+    pub citation: &'static str,
+    pub range: EffectiveRange,
+}
+
+impl DatedRule {
+    /// In force from `from` on
+    pub const fn from(citation: &'static str, from: NaiveDate) -> Self {
+        Self::between_opt(citation, Some(from), None)
+    }
+
+    /// In force until `to`, when it was repealed or preempted
+    pub const fn until(citation: &'static str, to: NaiveDate) -> Self {
+        Self::between_opt(citation, None, Some(to))
+    }
+
+    /// In force from `from` through `to`
+    pub const fn between(citation: &'static str, from: NaiveDate, to: NaiveDate) -> Self {
+        Self::between_opt(citation, Some(from), Some(to))
+    }
+
+    const fn between_opt(
+        citation: &'static str,
+        effective_from: Option<NaiveDate>,
+        effective_to: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            citation,
+            range: EffectiveRange {
+                effective_from,
+                effective_to,
+            },
+        }
+    }
+}
+
+/// A calendar date for use in constants; panics at compile time if invalid
+pub const fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => date,
+        None => panic!("invalid date"),
+    }
+}
+
+/// Dated rules of every hand-written module
+fn dated_rules() -> impl Iterator<Item = &'static DatedRule> {
+    [
+        states::florida::DATED_RULES,
+        states::florida_commercial::DATED_RULES,
+        states::florida_realestate::DATED_RULES,
+        states::texas::DATED_RULES,
+        states::california::DATED_RULES,
+        states::new_york::DATED_RULES,
+        states::georgia::DATED_RULES,
+        states::illinois::DATED_RULES,
+        states::massachusetts::DATED_RULES,
+        states::michigan::DATED_RULES,
+        states::virginia::DATED_RULES,
+        states::washington::DATED_RULES,
+        layers::local::DATED_RULES,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Whether `statute` is `citation` or one of its subsections
+///
+/// "F.S. § 83.49" covers "F.S. § 83.49(3)(a)" but not "F.S. § 83.491".
+pub fn cites(statute: &str, citation: &str) -> bool {
+    statute.starts_with(citation)
+        && !statute[citation.len()..].starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Effective range of the rule reporting a statute citation
+pub fn effective_range(statute: &str) -> EffectiveRange {
+    dated_rules()
+        .find(|rule| rule.citation == statute)
+        .map(|rule| rule.range)
+        .unwrap_or(EffectiveRange::ALWAYS)
+}
+
+/// Keep only violations of rules in force on `as_of`
+pub fn in_force(violations: Vec<Violation>, as_of: NaiveDate) -> Vec<Violation> {
+    violations
+        .into_iter()
+        .filter(|v| effective_range(&v.statute).contains(as_of))
+        .collect()
+}
+
+/// Date a covered-statute entry ("citation - description") for display
+pub fn covered(entry: &str) -> CoveredStatute {
+    let citation = entry.split(" - ").next().unwrap_or(entry);
+    CoveredStatute {
+        citation: entry.to_string(),
+        range: effective_range(citation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_contains_inclusive_bounds() {
+        let range = EffectiveRange::new(Some(date(2024, 7, 1)), Some(date(2025, 6, 30)));
+        assert!(!range.contains(date(2024, 6, 30)));
+        assert!(range.contains(date(2024, 7, 1)));
+        assert!(range.contains(date(2025, 6, 30)));
+        assert!(!range.contains(date(2025, 7, 1)));
+        assert!(EffectiveRange::ALWAYS.contains(date(1990, 1, 1)));
+    }
+
+    #[test]
+    fn test_ranges_come_from_rule_definitions() {
+        assert_eq!(
+            effective_range("F.S. § 83.512 (SB 948)"),
+            states::florida::FLOOD_DISCLOSURE.range
+        );
+        assert_eq!(
+            effective_range("F.S. § 83.512 (SB 948)").effective_from,
+            Some(date(2025, 10, 1))
+        );
+        // Undated rules, including other subsections of a dated statute
+        assert_eq!(
+            effective_range("F.S. § 83.51(2)(a)"),
+            EffectiveRange::ALWAYS
+        );
+        assert_eq!(effective_range("F.S. § 83.56(3)"), EffectiveRange::ALWAYS);
+        assert_eq!(
+            effective_range("F.S. § 83.56 (HB 615)").effective_from,
+            Some(date(2025, 7, 1))
+        );
+    }
+
+    #[test]
+    fn test_dated_citations_are_unique() {
+        let mut citations: Vec<&str> = dated_rules().map(|rule| rule.citation).collect();
+        citations.sort();
+        let count = citations.len();
+        citations.dedup();
+        assert_eq!(citations.len(), count);
+    }
+
+    #[test]
+    fn test_citation_boundaries() {
+        assert!(cites("F.S. § 83.49(3)(a)", "F.S. § 83.49"));
+        assert!(!cites("F.S. § 83.491", "F.S. § 83.49"));
+        assert!(!cites(
+            "Tex. Prop. Code § 92.0561",
+            "Tex. Prop. Code § 92.056"
+        ));
+    }
+
+    #[test]
+    fn test_covered_entry_uses_citation_part() {
+        let entry = covered("Tex. Prop. Code § 92.3515 - Screening criteria notice");
+        assert_eq!(entry.range, states::texas::SCREENING_CRITERIA.range);
+        assert_eq!(
+            entry.citation,
+            "Tex. Prop. Code § 92.3515 - Screening criteria notice"
+        );
+        assert_eq!(entry.range.effective_from, Some(date(2025, 1, 1)));
+    }
+}
@@ -1,2 +1,3 @@
 // Extractors for parsing lease document text
 pub mod numeric;
+pub mod terms;
@@ -4,25 +4,45 @@ use regex::Regex;
 /// Extracts a number that appears near keywords related to deposit returns
 /// Returns the number of days if found
 pub fn extract_days_near_deposit_return(text: &str) -> Option<u32> {
-    let text_lower = text.to_lowercase();
+    find_days_near_deposit_return(text).map(|(days, _, _)| days)
+}
+
+/// Like [`extract_days_near_deposit_return`], also returning the byte span of
+/// the "within X days" match
+pub(crate) fn find_days_near_deposit_return(text: &str) -> Option<(u32, usize, usize)> {
+    // ASCII lowercasing keeps byte offsets aligned with the original text
+    let text_lower = text.to_ascii_lowercase();
 
     // Look for patterns like "within X days" or "X days" near "return" and "deposit"
-    let re = Regex::new(r"(?:within\s+)?(\d+)\s+days?").unwrap();
+    // ("dentro de X días" near "devolver" and "depósito" in Spanish leases)
+    let re =
+        Regex::new(r"(?:within\s+|dentro\s+de\s+(?:los\s+)?)?(\d+)\s+(?:days?|d[ií]as?)").unwrap();
 
     // Find all day references
     for cap in re.captures_iter(&text_lower) {
         if let Some(num_match) = cap.get(1) {
             if let Ok(days) = num_match.as_str().parse::<u32>() {
                 // Check if this appears in context of deposit return
-                let start = cap.get(0).unwrap().start();
-                let context_start = start.saturating_sub(50);
-                let context_end = (start + 50).min(text_lower.len());
+                let whole = cap.get(0).unwrap();
+                let start = whole.start();
+                let mut context_start = start.saturating_sub(50);
+                while !text_lower.is_char_boundary(context_start) {
+                    context_start -= 1;
+                }
+                let mut context_end = (start + 50).min(text_lower.len());
+                while !text_lower.is_char_boundary(context_end) {
+                    context_end += 1;
+                }
                 let context = &text_lower[context_start..context_end];
 
-                if (context.contains("return") || context.contains("refund"))
-                    && context.contains("deposit")
+                let mentions_return = context.contains("return")
+                    || context.contains("refund")
+                    || context.contains("devol")
+                    || context.contains("devuel")
+                    || context.contains("reembols");
+                if mentions_return && (context.contains("deposit") || context.contains("depósito"))
                 {
-                    return Some(days);
+                    return Some((days, start, whole.end()));
                 }
             }
         }
@@ -38,6 +58,10 @@ pub fn has_claim_context(text: &str) -> bool {
         || text_lower.contains("impose")
         || text_lower.contains("deduction")
         || text_lower.contains("withhold")
+        || text_lower.contains("reclam")
+        || text_lower.contains("deducci")
+        || text_lower.contains("retener")
+        || text_lower.contains("imponer")
 }
 
 /// Checks if text mentions bank location information
@@ -47,7 +71,10 @@ pub fn has_bank_location(text: &str) -> bool {
     // Check for bank mention
     let has_bank = text_lower.contains("bank")
         || text_lower.contains("credit union")
-        || text_lower.contains("financial institution");
+        || text_lower.contains("financial institution")
+        || text_lower.contains("banco")
+        || text_lower.contains("cooperativa de crédito")
+        || text_lower.contains("institución financiera");
 
     if !has_bank {
         return false;
@@ -85,6 +112,12 @@ mod tests {
             extract_days_near_deposit_return("Notice within 30 days"),
             None // No "return" or "deposit" in close context
         );
+        assert_eq!(
+            extract_days_near_deposit_return(
+                "El arrendador devolverá el depósito dentro de los 45 días"
+            ),
+            Some(45)
+        );
     }
 
     #[test]
@@ -92,6 +125,12 @@ mod tests {
         assert!(has_claim_context("If landlord intends to impose a claim"));
         assert!(has_claim_context("making deductions from deposit"));
         assert!(!has_claim_context("return deposit within 15 days"));
+        assert!(has_claim_context(
+            "si el arrendador desea imponer un reclamo"
+        ));
+        assert!(!has_claim_context(
+            "devolverá el depósito dentro de 15 días"
+        ));
     }
 
     #[test]
@@ -99,5 +138,8 @@ mod tests {
         assert!(has_bank_location("First National Bank, Miami, Florida"));
         assert!(has_bank_location("held at SunTrust Bank in Tampa"));
         assert!(!has_bank_location("deposit held at bank"));
+        assert!(has_bank_location(
+            "depositado en Banco Popular, Miami, Florida"
+        ));
     }
 }
@@ -0,0 +1,586 @@
+//! Structured lease term extraction
+//!
+//! [`LeaseTerms::extract`] parses a lease once into typed values (amounts,
+//! day counts, dates, names), each with the span it was read from and a
+//! confidence. State, local and rule-pack checks evaluate these values instead
+//! of re-parsing the text, so a limit such as "deposit ≤ 2× rent" reads the
+//! same deposit and rent in every state.
+//!
+//! Confidence is a rough 0.0-1.0 score: an explicitly labelled value with a
+//! currency sign or unit scores high, a bare number near a keyword scores low.
+
+use crate::extractors::numeric::find_days_near_deposit_return;
+use crate::patterns::floor_char_boundary;
+use chrono::NaiveDate;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use shared_types::TextPosition;
+
+/// A value read from the lease, with where it was found
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Extracted<T> {
+    pub value: T,
+    pub span: TextPosition,
+    pub confidence: f32,
+}
+
+/// A late fee as written in the lease
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum LateFee {
+    /// Dollar amount
+    Flat(f64),
+    /// Percent of monthly rent
+    Percent(f64),
+}
+
+/// Terms extracted from a lease
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LeaseTerms {
+    /// Monthly rent in dollars
+    pub rent: Option<Extracted<f64>>,
+    pub security_deposit: Option<Extracted<f64>>,
+    pub pet_deposit: Option<Extracted<f64>>,
+    /// Every late fee stated, flat and percentage, in document order
+    pub late_fees: Vec<Extracted<LateFee>>,
+    /// Days after the due date before a late fee applies
+    pub grace_period_days: Option<Extracted<u32>>,
+    pub term_start: Option<Extracted<NaiveDate>>,
+    pub term_end: Option<Extracted<NaiveDate>>,
+    /// Days the landlord gives itself to return the deposit
+    pub deposit_return_days: Option<Extracted<u32>>,
+    /// Notice periods for nonpayment of rent, each occurrence
+    pub nonpayment_notice_days: Vec<Extracted<u32>>,
+    /// Notice periods to end a month-to-month tenancy, each occurrence
+    pub termination_notice_days: Vec<Extracted<u32>>,
+    /// Notice before landlord entry in hours, each occurrence
+    pub entry_notice_hours: Vec<Extracted<u32>>,
+    /// Notice periods for a rent increase, each occurrence
+    pub rent_increase_notice_days: Vec<Extracted<u32>>,
+    pub landlord: Option<Extracted<String>>,
+    pub tenants: Vec<Extracted<String>>,
+    /// Street address of the premises
+    pub address: Option<Extracted<String>>,
+}
+
+lazy_static! {
+    static ref RENT_PATTERN: Regex =
+        Regex::new(r"(?i)(?:(?:monthly\s+)?rent|(?:renta|alquiler)(?:\s+mensual)?)[:\s]+(?:of\s+|is\s+|de\s+|es\s+(?:de\s+)?)?(\$\s*)?([\d,]+(?:\.\d{2})?)").unwrap();
+
+    static ref DEPOSIT_PATTERN: Regex =
+        Regex::new(r"(?i)(?:(?:security\s+)?deposit|dep[oó]sito(?:\s+de\s+(?:seguridad|garant[ií]a))?)[:\s]+(?:of\s+|de\s+)?(\$\s*)?([\d,]+(?:\.\d{2})?)").unwrap();
+
+    static ref PET_DEPOSIT_PATTERN: Regex =
+        Regex::new(r"(?i)(?:pet\s+deposit|dep[oó]sito\s+(?:por|de|para)\s+mascotas?)[:\s]+(?:of\s+|de\s+)?(\$\s*)?([\d,]+(?:\.\d{2})?)").unwrap();
+
+    static ref LATE_FEE_FLAT_PATTERN: Regex =
+        Regex::new(r"(?i)(?:late\s*(?:fee|charge)|(?:cargo|recargo)\s+por\s+(?:pago\s+)?(?:atrasado|atraso|mora|retraso|tard[ií]o))(?:[:\s]+(\$\s*)?|[^.%]*?(\$\s*))([\d,]+(?:\.\d{2})?)").unwrap();
+
+    static ref LATE_FEE_PERCENT_PATTERN: Regex =
+        Regex::new(r"(?i)(?:late\s*(?:fee|charge)|(?:cargo|recargo)\s+por\s+(?:pago\s+)?(?:atrasado|atraso|mora|retraso|tard[ií]o))[^.]*?(\d+(?:\.\d+)?)\s*%").unwrap();
+
+    static ref GRACE_PERIOD_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)grace\s+period\s+of\s+(\d+)\s*(?:calendar\s+)?days?").unwrap(),
+        Regex::new(r"(?i)(\d+)[\s-]*(?:calendar\s+)?days?['’]?\s+grace").unwrap(),
+        Regex::new(r"(?i)late\s*(?:fee|charge)[^.]*?(?:more\s+than|after)\s+(\d+)\s+days?").unwrap(),
+        Regex::new(r"(?i)per[ií]odo\s+de\s+gracia\s+de\s+(\d+)\s*d[ií]as?").unwrap(),
+    ];
+
+    static ref NONPAYMENT_NOTICE_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)(\d+)[\s-]*(?:calendar\s+|business\s+)?days?['’]?\s*(?:written\s+)?notice[^.]*?(?:nonpayment|non-payment|unpaid\s+rent|rent)").unwrap(),
+        Regex::new(r"(?i)(?:nonpayment|non-payment|unpaid\s+rent)[^.]*?(\d+)[\s-]*(?:calendar\s+|business\s+)?days?['’]?\s*(?:written\s+)?notice").unwrap(),
+        Regex::new(r"(?i)(\d+)\s*d[ií]as?\s+(?:h[aá]biles\s+|naturales\s+)?de\s+(?:aviso|notificaci[oó]n|preaviso)[^.]*?(?:falta\s+de\s+pago|renta|alquiler)").unwrap(),
+        Regex::new(r"(?i)(?:falta\s+de\s+pago|renta\s+(?:impaga|no\s+pagada))[^.]*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*d[ií]as?").unwrap(),
+    ];
+
+    static ref TERMINATION_NOTICE_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)(\d+)[\s-]*days?['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice[^.]*?(?:terminat|end|cancel)[^.]*?(?:month-to-month|monthly)").unwrap(),
+        Regex::new(r"(?i)(?:month-to-month|monthly)[^.]*?(?:terminat|end|cancel)[^.]*?(\d+)[\s-]*days?['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice").unwrap(),
+        Regex::new(r"(?i)(?:terminat|end|cancel)[^.]*?(?:month-to-month|monthly)[^.]*?(\d+)[\s-]*days?['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice").unwrap(),
+        Regex::new(r"(?i)(\d+)\s*d[ií]as?\s+de\s+(?:aviso|notificaci[oó]n|preaviso)[^.]*?(?:terminar|terminaci[oó]n|rescindir|cancelar)[^.]*?mes\s+a\s+mes").unwrap(),
+        Regex::new(r"(?i)(?:terminar|terminaci[oó]n|rescindir|cancelar)[^.]*?mes\s+a\s+mes[^.]*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*d[ií]as?").unwrap(),
+    ];
+
+    static ref ENTRY_NOTICE_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)(\d+)[\s-]*(hours?|days?)['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice[^.]*?(?:enter|entry|access)").unwrap(),
+        Regex::new(r"(?i)(?:enter|entry|access)[^.]*?(\d+)[\s-]*(hours?|days?)['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice").unwrap(),
+        Regex::new(r"(?i)(?:entrar|ingresar|entrada|acceso)[^.]*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+previo)?(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*(horas?|d[ií]as?)").unwrap(),
+        Regex::new(r"(?i)(\d+)\s*(horas?|d[ií]as?)\s+de\s+(?:aviso|notificaci[oó]n|preaviso)[^.]*?(?:entrar|ingresar|entrada|acceso)").unwrap(),
+    ];
+
+    static ref RENT_INCREASE_NOTICE_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)(\d+)[\s-]*days?['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice[^.]*?(?:rent\s+increase|increas\w*\s+(?:the\s+)?rent)").unwrap(),
+        Regex::new(r"(?i)(?:rent\s+increase|increas\w*\s+(?:the\s+)?rent)[^.]*?(\d+)[\s-]*days?['’]?\s*(?:advance\s+|prior\s+)?(?:written\s+)?notice").unwrap(),
+        Regex::new(r"(?i)(?:aumento\s+de\s+(?:la\s+)?(?:renta|alquiler)|aumentar\s+(?:la\s+|el\s+)?(?:renta|alquiler))[^.]*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*d[ií]as?").unwrap(),
+        Regex::new(r"(?i)(\d+)\s*d[ií]as?\s+de\s+(?:aviso|notificaci[oó]n|preaviso)[^.]*?(?:aumento\s+de\s+(?:la\s+)?(?:renta|alquiler)|aumentar\s+(?:la\s+|el\s+)?(?:renta|alquiler))").unwrap(),
+    ];
+
+    static ref DATE_PATTERN: Regex = Regex::new(
+        r"(?i)\b(?:(january|february|march|april|may|june|july|august|september|october|november|december)\s+(\d{1,2})(?:st|nd|rd|th)?,?\s+(\d{4})|(\d{1,2})/(\d{1,2})/(\d{4})|(\d{4})-(\d{2})-(\d{2}))\b"
+    )
+    .unwrap();
+
+    static ref TERM_START_PATTERN: Regex =
+        Regex::new(r"(?i)(?:commenc\w*|begin\w*|start\w*)(?:\s+date)?(?:\s+on)?[:\s]").unwrap();
+
+    static ref TERM_END_PATTERN: Regex =
+        Regex::new(r"(?i)(?:end\w*|expir\w*|terminat\w*|through|until)(?:\s+date)?(?:\s+on)?[:\s]").unwrap();
+
+    static ref PARTIES_PATTERN: Regex = Regex::new(
+        r#"(?i)between\s+(.+?)\s*\(\s*["“]?(?:landlord|lessor|owner)["”]?\s*\)\s*,?\s*and\s+(.+?)\s*\(\s*["“]?(?:tenants?|lessees?|residents?)["”]?\s*\)"#
+    )
+    .unwrap();
+
+    static ref LANDLORD_LINE_PATTERN: Regex =
+        Regex::new(r"(?im)^\s*(?:landlord|lessor|owner)(?:\s+name)?\s*:\s*([^\n]+?)\s*$").unwrap();
+
+    static ref TENANT_LINE_PATTERN: Regex =
+        Regex::new(r"(?im)^\s*(?:tenants?|lessees?|residents?)(?:\(s\))?(?:\s+names?)?\s*:\s*([^\n]+?)\s*$").unwrap();
+
+    static ref NAME_SEPARATOR: Regex = Regex::new(r"(?i)\s*(?:,\s*(?:and\s+)?|;\s*|\s+and\s+)").unwrap();
+
+    static ref ADDRESS_PATTERN: Regex = Regex::new(
+        r"(?i)(?:located\s+at|known\s+as|premises\s+address|property\s+address|address\s+of\s+the\s+premises|address)\s*:?\s*(\d+\s[^\n]{3,120}?\b\d{5}(?:-\d{4})?)\b"
+    )
+    .unwrap();
+}
+
+impl LeaseTerms {
+    /// Extract every term the lease states
+    pub fn extract(text: &str) -> Self {
+        Self {
+            rent: amount(&RENT_PATTERN, text, &[]),
+            security_deposit: amount(&DEPOSIT_PATTERN, text, &["pet"]),
+            pet_deposit: amount(&PET_DEPOSIT_PATTERN, text, &[]),
+            late_fees: late_fees(text),
+            grace_period_days: first_days(&GRACE_PERIOD_PATTERNS, text),
+            term_start: date_after(&TERM_START_PATTERN, text),
+            term_end: date_after(&TERM_END_PATTERN, text),
+            deposit_return_days: find_days_near_deposit_return(text).map(|(days, start, end)| {
+                Extracted {
+                    value: days,
+                    span: span(start, end),
+                    confidence: 0.7,
+                }
+            }),
+            nonpayment_notice_days: all_days(&NONPAYMENT_NOTICE_PATTERNS, text),
+            termination_notice_days: all_days(&TERMINATION_NOTICE_PATTERNS, text),
+            entry_notice_hours: entry_hours(text),
+            rent_increase_notice_days: all_days(&RENT_INCREASE_NOTICE_PATTERNS, text),
+            landlord: landlord(text),
+            tenants: tenants(text),
+            address: ADDRESS_PATTERN.captures(text).and_then(|caps| {
+                let m = caps.get(1)?;
+                Some(Extracted {
+                    value: m.as_str().trim().to_string(),
+                    span: span(m.start(), m.end()),
+                    confidence: 0.7,
+                })
+            }),
+        }
+    }
+
+    /// Security deposit and monthly rent, when both are stated
+    pub fn deposit_and_rent(&self) -> Option<(f64, f64)> {
+        Some((
+            self.security_deposit.as_ref()?.value,
+            self.rent.as_ref()?.value,
+        ))
+    }
+
+    /// Deposit and the cap it exceeds, if it is over `months` of rent
+    pub fn deposit_over(&self, months: f64) -> Option<(f64, f64)> {
+        let (deposit, rent) = self.deposit_and_rent()?;
+        let limit = rent * months;
+        (deposit > limit + 0.005).then_some((deposit, limit))
+    }
+
+    /// First flat late fee in dollars
+    pub fn late_fee_flat(&self) -> Option<f64> {
+        self.late_fees.iter().find_map(|fee| match fee.value {
+            LateFee::Flat(amount) => Some(amount),
+            LateFee::Percent(_) => None,
+        })
+    }
+
+    /// First percentage late fee
+    pub fn late_fee_percent(&self) -> Option<f64> {
+        self.late_fees.iter().find_map(|fee| match fee.value {
+            LateFee::Percent(percent) => Some(percent),
+            LateFee::Flat(_) => None,
+        })
+    }
+}
+
+fn span(start: usize, end: usize) -> TextPosition {
+    TextPosition::new(start, end)
+}
+
+fn parse_amount(s: &str) -> Option<f64> {
+    s.replace(',', "").parse::<f64>().ok()
+}
+
+/// First dollar amount the pattern captures (group 1 is the `$`, group 2
+/// the amount), skipping matches preceded by any of `not_after`
+fn amount(pattern: &Regex, text: &str, not_after: &[&str]) -> Option<Extracted<f64>> {
+    pattern.captures_iter(text).find_map(|caps| {
+        let whole = caps.get(0)?;
+        let before = text[..whole.start()].trim_end().to_lowercase();
+        if not_after.iter().any(|word| before.ends_with(word)) {
+            return None;
+        }
+        let m = caps.get(2)?;
+        Some(Extracted {
+            value: parse_amount(m.as_str())?,
+            span: span(whole.start(), m.end()),
+            confidence: if caps.get(1).is_some() { 0.9 } else { 0.6 },
+        })
+    })
+}
+
+fn late_fees(text: &str) -> Vec<Extracted<LateFee>> {
+    let mut fees = Vec::new();
+
+    if let Some(caps) = LATE_FEE_FLAT_PATTERN
+        .captures_iter(text)
+        .find(|caps| !followed_by_percent(text, caps))
+    {
+        let whole = caps.get(0).unwrap();
+        let m = caps.get(3).unwrap();
+        if let Some(value) = parse_amount(m.as_str()) {
+            let has_dollar = caps.get(1).is_some() || caps.get(2).is_some();
+            fees.push(Extracted {
+                value: LateFee::Flat(value),
+                span: span(whole.start(), whole.end()),
+                confidence: if has_dollar { 0.9 } else { 0.5 },
+            });
+        }
+    }
+
+    if let Some(caps) = LATE_FEE_PERCENT_PATTERN.captures(text) {
+        let whole = caps.get(0).unwrap();
+        if let Some(value) = caps.get(1).and_then(|m| m.as_str().parse().ok()) {
+            fees.push(Extracted {
+                value: LateFee::Percent(value),
+                span: span(whole.start(), whole.end()),
+                confidence: 0.8,
+            });
+        }
+    }
+
+    fees.sort_by_key(|fee| fee.span.start_offset);
+    fees
+}
+
+/// Whether a captured amount is really a percentage ("late fee: 10%")
+fn followed_by_percent(text: &str, caps: &Captures) -> bool {
+    caps.get(0)
+        .is_some_and(|m| text[m.end()..].trim_start().starts_with('%'))
+}
+
+fn first_days(patterns: &[Regex], text: &str) -> Option<Extracted<u32>> {
+    all_days(patterns, text)
+        .into_iter()
+        .min_by_key(|d| d.span.start_offset)
+}
+
+/// Every day count the patterns capture; where two patterns match the same
+/// sentence only the first is kept
+fn all_days(patterns: &[Regex], text: &str) -> Vec<Extracted<u32>> {
+    let mut found: Vec<Extracted<u32>> = Vec::new();
+    for pattern in patterns {
+        for caps in pattern.captures_iter(text) {
+            let m = caps.get(0).unwrap();
+            let Some(days) = caps.get(1).and_then(|d| d.as_str().parse().ok()) else {
+                continue;
+            };
+            if !overlaps(&found, m.start(), m.end()) {
+                found.push(Extracted {
+                    value: days,
+                    span: span(m.start(), m.end()),
+                    confidence: 0.8,
+                });
+            }
+        }
+    }
+    found
+}
+
+fn entry_hours(text: &str) -> Vec<Extracted<u32>> {
+    let mut found: Vec<Extracted<u32>> = Vec::new();
+    for pattern in ENTRY_NOTICE_PATTERNS.iter() {
+        for caps in pattern.captures_iter(text) {
+            let (Some(value), Some(unit)) = (caps.get(1), caps.get(2)) else {
+                continue;
+            };
+            let Ok(value) = value.as_str().parse::<u32>() else {
+                continue;
+            };
+            // "days" or "días"; everything else is hours ("hours", "horas")
+            let hours = if unit.as_str().to_lowercase().starts_with('d') {
+                value.saturating_mul(24)
+            } else {
+                value
+            };
+            let m = caps.get(0).unwrap();
+            if !overlaps(&found, m.start(), m.end()) {
+                found.push(Extracted {
+                    value: hours,
+                    span: span(m.start(), m.end()),
+                    confidence: 0.8,
+                });
+            }
+        }
+    }
+    found
+}
+
+fn overlaps<T>(found: &[Extracted<T>], start: usize, end: usize) -> bool {
+    found
+        .iter()
+        .any(|e| e.span.start_offset < end && start < e.span.end_offset)
+}
+
+/// First date within 40 bytes after a keyword match
+fn date_after(keyword: &Regex, text: &str) -> Option<Extracted<NaiveDate>> {
+    keyword.find_iter(text).find_map(|k| {
+        let window_end = floor_char_boundary(text, k.end() + 40);
+        let window = text.get(k.end()..window_end)?;
+        let caps = DATE_PATTERN.captures(window)?;
+        let m = caps.get(0)?;
+        Some(Extracted {
+            value: parse_date(&caps)?,
+            span: span(k.end() + m.start(), k.end() + m.end()),
+            confidence: 0.8,
+        })
+    })
+}
+
+fn parse_date(caps: &Captures) -> Option<NaiveDate> {
+    let num = |i: usize| caps.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+    if let Some(month) = caps.get(1) {
+        let month = match &month.as_str().to_lowercase()[..3] {
+            "jan" => 1,
+            "feb" => 2,
+            "mar" => 3,
+            "apr" => 4,
+            "may" => 5,
+            "jun" => 6,
+            "jul" => 7,
+            "aug" => 8,
+            "sep" => 9,
+            "oct" => 10,
+            "nov" => 11,
+            _ => 12,
+        };
+        NaiveDate::from_ymd_opt(num(3)? as i32, month, num(2)?)
+    } else if caps.get(4).is_some() {
+        // US order: MM/DD/YYYY
+        NaiveDate::from_ymd_opt(num(6)? as i32, num(4)?, num(5)?)
+    } else {
+        NaiveDate::from_ymd_opt(num(7)? as i32, num(8)?, num(9)?)
+    }
+}
+
+fn party(m: regex::Match) -> Option<Extracted<String>> {
+    let raw = m.as_str();
+    let name = raw.trim().trim_matches(|c| c == ',' || c == '"');
+    if name.is_empty() {
+        return None;
+    }
+    let start = m.start() + raw.find(name).unwrap_or(0);
+    Some(Extracted {
+        value: name.to_string(),
+        span: span(start, start + name.len()),
+        confidence: 0.7,
+    })
+}
+
+fn landlord(text: &str) -> Option<Extracted<String>> {
+    PARTIES_PATTERN
+        .captures(text)
+        .and_then(|caps| party(caps.get(1)?))
+        .or_else(|| {
+            LANDLORD_LINE_PATTERN
+                .captures(text)
+                .and_then(|caps| party(caps.get(1)?))
+        })
+}
+
+fn tenants(text: &str) -> Vec<Extracted<String>> {
+    let Some(m) = PARTIES_PATTERN
+        .captures(text)
+        .and_then(|caps| caps.get(2))
+        .or_else(|| {
+            TENANT_LINE_PATTERN
+                .captures(text)
+                .and_then(|caps| caps.get(1))
+        })
+    else {
+        return Vec::new();
+    };
+
+    // "A, B and C" names three tenants
+    let list = m.as_str();
+    let mut names = Vec::new();
+    let mut last = 0;
+    let separators = NAME_SEPARATOR
+        .find_iter(list)
+        .map(|sep| (sep.start(), sep.end()))
+        .chain(std::iter::once((list.len(), list.len())));
+    for (sep_start, sep_end) in separators {
+        let piece = &list[last..sep_start];
+        let trimmed = piece.trim();
+        if !trimmed.is_empty() {
+            let start = m.start() + last + (piece.len() - piece.trim_start().len());
+            names.push(Extracted {
+                value: trimmed.to_string(),
+                span: span(start, start + trimmed.len()),
+                confidence: 0.6,
+            });
+        }
+        last = sep_end;
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEASE: &str = "RESIDENTIAL LEASE AGREEMENT\n\
+        This Lease is made between Sunshine Properties LLC (\"Landlord\") and \
+        Jane Doe and John Roe (\"Tenant\").\n\
+        The premises located at 123 Main Street, Apt 4, Tampa, FL 33602.\n\
+        The term commences on January 1, 2025 and ends on 12/31/2025.\n\
+        Monthly rent: $1,500.00, due on the 1st.\n\
+        Security deposit: $3,000. Pet deposit: $250.\n\
+        A late fee of $75 applies if rent is received more than 5 days after the due date.\n\
+        Landlord shall return the deposit within 15 days after the tenancy ends.\n\
+        Landlord will give 24 hours notice before entry.\n\
+        Tenant shall receive 3 days notice for nonpayment of rent.";
+
+    #[test]
+    fn test_extracts_amounts_with_spans() {
+        let terms = LeaseTerms::extract(LEASE);
+
+        let rent = terms.rent.as_ref().unwrap();
+        assert_eq!(rent.value, 1500.0);
+        assert!(LEASE[rent.span.start_offset..rent.span.end_offset].contains("$1,500.00"));
+        assert!(rent.confidence > 0.8);
+
+        assert_eq!(terms.security_deposit.as_ref().unwrap().value, 3000.0);
+        assert_eq!(terms.pet_deposit.as_ref().unwrap().value, 250.0);
+        assert_eq!(terms.late_fee_flat(), Some(75.0));
+        assert_eq!(terms.late_fee_percent(), None);
+        assert_eq!(terms.grace_period_days.as_ref().unwrap().value, 5);
+        assert_eq!(terms.deposit_return_days.as_ref().unwrap().value, 15);
+        assert_eq!(terms.entry_notice_hours[0].value, 24);
+        assert_eq!(terms.nonpayment_notice_days[0].value, 3);
+        assert_eq!(terms.deposit_over(1.0), Some((3000.0, 1500.0)));
+        assert_eq!(terms.deposit_over(2.0), None);
+    }
+
+    #[test]
+    fn test_extracts_dates_parties_and_address() {
+        let terms = LeaseTerms::extract(LEASE);
+
+        assert_eq!(
+            terms.term_start.as_ref().unwrap().value,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+        );
+        assert_eq!(
+            terms.term_end.as_ref().unwrap().value,
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+        );
+
+        let landlord = terms.landlord.as_ref().unwrap();
+        assert_eq!(landlord.value, "Sunshine Properties LLC");
+        assert_eq!(
+            &LEASE[landlord.span.start_offset..landlord.span.end_offset],
+            "Sunshine Properties LLC"
+        );
+        let tenants: Vec<_> = terms.tenants.iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(tenants, vec!["Jane Doe", "John Roe"]);
+        for tenant in &terms.tenants {
+            assert_eq!(
+                &LEASE[tenant.span.start_offset..tenant.span.end_offset],
+                tenant.value
+            );
+        }
+
+        assert_eq!(
+            terms.address.as_ref().unwrap().value,
+            "123 Main Street, Apt 4, Tampa, FL 33602"
+        );
+    }
+
+    #[test]
+    fn test_pet_deposit_is_not_security_deposit() {
+        let terms = LeaseTerms::extract("Pet deposit: $300. Security deposit: $1,000.");
+        assert_eq!(terms.security_deposit.as_ref().unwrap().value, 1000.0);
+        assert_eq!(terms.pet_deposit.as_ref().unwrap().value, 300.0);
+    }
+
+    #[test]
+    fn test_late_fee_percent_is_not_flat() {
+        let terms = LeaseTerms::extract("Late fee: 10% of monthly rent.");
+        assert_eq!(terms.late_fee_flat(), None);
+        assert_eq!(terms.late_fee_percent(), Some(10.0));
+    }
+
+    #[test]
+    fn test_labelled_lines() {
+        let text = "Landlord: Acme Rentals\nTenant(s): Ana Lopez, Ben Ode\n\
+                    Lease start date: 2025-03-01";
+        let terms = LeaseTerms::extract(text);
+        assert_eq!(terms.landlord.unwrap().value, "Acme Rentals");
+        let tenants: Vec<_> = terms.tenants.into_iter().map(|t| t.value).collect();
+        assert_eq!(tenants, vec!["Ana Lopez", "Ben Ode"]);
+        assert_eq!(
+            terms.term_start.unwrap().value,
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_extracts_spanish_terms() {
+        let text = "Renta mensual: $1,200.00. Depósito de seguridad: $2,400.00. \
+                    Depósito por mascota: $200. Cargo por pago atrasado: $60. \
+                    El Arrendador devolverá el depósito dentro de 15 días. Período de gracia \
+                    de 3 días. El Arrendador dará 24 horas de aviso antes de entrar. \
+                    Por falta de pago, el Inquilino recibirá un aviso por escrito de 3 días.";
+        let terms = LeaseTerms::extract(text);
+
+        assert_eq!(terms.rent.as_ref().unwrap().value, 1200.0);
+        assert_eq!(terms.security_deposit.as_ref().unwrap().value, 2400.0);
+        assert_eq!(terms.pet_deposit.as_ref().unwrap().value, 200.0);
+        assert_eq!(terms.late_fee_flat(), Some(60.0));
+        assert_eq!(terms.grace_period_days.as_ref().unwrap().value, 3);
+        assert_eq!(terms.deposit_return_days.as_ref().unwrap().value, 15);
+        assert_eq!(terms.entry_notice_hours[0].value, 24);
+        assert_eq!(terms.nonpayment_notice_days[0].value, 3);
+    }
+
+    #[test]
+    fn test_window_ending_inside_a_character() {
+        // The 40-byte window after "Start date:" ends inside the dash
+        let terms = LeaseTerms::extract("Start date: January 1, 2025, ending ééééééé—x");
+        assert_eq!(
+            terms.term_start.unwrap().value,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_huge_entry_notice_does_not_overflow() {
+        let terms = LeaseTerms::extract("Landlord gives 4294967295 days notice before entry.");
+        assert_eq!(terms.entry_notice_hours[0].value, u32::MAX);
+    }
+
+    #[test]
+    fn test_empty_text() {
+        assert_eq!(LeaseTerms::extract(""), LeaseTerms::default());
+    }
+}
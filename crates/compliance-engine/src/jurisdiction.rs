@@ -5,7 +5,9 @@
 //! - State: Statutory requirements per state
 //! - Local: Municipal ordinances (Chicago RLTO, NYC rent control, etc.)
 
+use crate::locality_zips;
 use serde::{Deserialize, Serialize};
+use shared_types::LocalCoverage;
 
 /// US State codes for lease compliance
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -128,7 +130,6 @@ impl State {
     }
 
     /// Get the primary statute citation for landlord-tenant law
-    /// Returns the main statutory reference for implemented states
     pub fn statute_citation(&self) -> Option<&'static str> {
         match self {
             // Tier 0 + Tier 1: Big Five
@@ -149,8 +150,43 @@ impl State {
             State::AZ => Some("A.R.S. Title 33 Ch. 10"),
             State::NC => Some("N.C.G.S. Chapter 42"),
             State::TN => Some("T.C.A. Title 66 Ch. 28"),
-            // Not yet implemented
-            _ => None,
+            // Tier 3: URLTA Block
+            State::AK => Some("AS 34.03"),
+            State::KS => Some("K.S.A. 58-2540 et seq."),
+            State::KY => Some("KRS Chapter 383"),
+            State::NE => Some("Neb. Rev. Stat. § 76-1401 et seq."),
+            State::NM => Some("NMSA Chapter 47 Article 8"),
+            State::OR => Some("ORS Chapter 90"),
+            State::RI => Some("R.I. Gen. Laws Chapter 34-18"),
+            // Long tail: statutory profiles
+            State::AL => Some("Ala. Code § 35-9A"),
+            State::AR => Some("Ark. Code Ann. Title 18 Ch. 17"),
+            State::CO => Some("C.R.S. Title 38 Art. 12"),
+            State::CT => Some("C.G.S. Chapter 830"),
+            State::DE => Some("25 Del. C. Part III"),
+            State::HI => Some("HRS Chapter 521"),
+            State::IA => Some("Iowa Code Chapter 562A"),
+            State::ID => Some("Idaho Code Title 6 Ch. 3"),
+            State::IN => Some("IC 32-31"),
+            State::LA => Some("La. Civ. Code art. 2668 et seq."),
+            State::MD => Some("Md. Code, Real Prop. Title 8"),
+            State::ME => Some("14 M.R.S. Chapter 710"),
+            State::MN => Some("Minn. Stat. Chapter 504B"),
+            State::MO => Some("RSMo Chapters 441 and 535"),
+            State::MS => Some("Miss. Code Title 89 Ch. 8"),
+            State::MT => Some("MCA Title 70 Ch. 24"),
+            State::ND => Some("N.D.C.C. Chapter 47-16"),
+            State::NH => Some("RSA Chapters 540 and 540-A"),
+            State::NV => Some("NRS Chapter 118A"),
+            State::OK => Some("41 O.S. § 101 et seq."),
+            State::SC => Some("S.C. Code Title 27 Ch. 40"),
+            State::SD => Some("SDCL Chapter 43-32"),
+            State::UT => Some("Utah Code Title 57 Ch. 22"),
+            State::VT => Some("9 V.S.A. Chapter 137"),
+            State::WI => Some("Wis. Stat. Chapter 704"),
+            State::WV => Some("W. Va. Code Chapter 37 Art. 6"),
+            State::WY => Some("Wyo. Stat. § 1-21-1201 et seq."),
+            State::DC => Some("D.C. Code Title 42 Ch. 35"),
         }
     }
 
@@ -201,29 +237,12 @@ impl State {
         }
     }
 
-    /// Check if state has implementation
+    /// Check if a dedicated module implements the state's lease law
+    ///
+    /// Other states are still checked against a statutory profile, at
+    /// [`shared_types::Coverage::Core`]; see [`crate::states::coverage`].
     pub fn is_implemented(&self) -> bool {
-        matches!(
-            self,
-            // Tier 1: Big Five
-            State::FL
-                | State::TX
-                | State::CA
-                | State::NY
-                | State::GA
-                | State::IL
-                // Tier 2: Growth Hubs
-                | State::PA
-                | State::NJ
-                | State::VA
-                | State::MA
-                | State::OH
-                | State::MI
-                | State::WA
-                | State::AZ
-                | State::NC
-                | State::TN
-        )
+        crate::states::coverage(*self) == shared_types::Coverage::Full
     }
 
     /// Parse from state code or name (case-insensitive)
@@ -252,6 +271,33 @@ impl State {
             "NM" | "NEW MEXICO" => Some(State::NM),
             "OR" | "OREGON" => Some(State::OR),
             "RI" | "RHODE ISLAND" => Some(State::RI),
+            "AL" | "ALABAMA" => Some(State::AL),
+            "AR" | "ARKANSAS" => Some(State::AR),
+            "CO" | "COLORADO" => Some(State::CO),
+            "CT" | "CONNECTICUT" => Some(State::CT),
+            "DE" | "DELAWARE" => Some(State::DE),
+            "HI" | "HAWAII" => Some(State::HI),
+            "IA" | "IOWA" => Some(State::IA),
+            "ID" | "IDAHO" => Some(State::ID),
+            "IN" | "INDIANA" => Some(State::IN),
+            "LA" | "LOUISIANA" => Some(State::LA),
+            "MD" | "MARYLAND" => Some(State::MD),
+            "ME" | "MAINE" => Some(State::ME),
+            "MN" | "MINNESOTA" => Some(State::MN),
+            "MO" | "MISSOURI" => Some(State::MO),
+            "MS" | "MISSISSIPPI" => Some(State::MS),
+            "MT" | "MONTANA" => Some(State::MT),
+            "ND" | "NORTH DAKOTA" => Some(State::ND),
+            "NH" | "NEW HAMPSHIRE" => Some(State::NH),
+            "NV" | "NEVADA" => Some(State::NV),
+            "OK" | "OKLAHOMA" => Some(State::OK),
+            "SC" | "SOUTH CAROLINA" => Some(State::SC),
+            "SD" | "SOUTH DAKOTA" => Some(State::SD),
+            "UT" | "UTAH" => Some(State::UT),
+            "VT" | "VERMONT" => Some(State::VT),
+            "WI" | "WISCONSIN" => Some(State::WI),
+            "WV" | "WEST VIRGINIA" => Some(State::WV),
+            "WY" | "WYOMING" => Some(State::WY),
             "DC" | "DISTRICT OF COLUMBIA" => Some(State::DC),
             _ => None,
         }
@@ -278,6 +324,42 @@ impl State {
             State::AZ,
             State::NC,
             State::TN,
+            // Tier 3 and the long tail: statutory profiles
+            State::AK,
+            State::KS,
+            State::KY,
+            State::NE,
+            State::NM,
+            State::OR,
+            State::RI,
+            State::AL,
+            State::AR,
+            State::CO,
+            State::CT,
+            State::DE,
+            State::HI,
+            State::IA,
+            State::ID,
+            State::IN,
+            State::LA,
+            State::MD,
+            State::ME,
+            State::MN,
+            State::MO,
+            State::MS,
+            State::MT,
+            State::ND,
+            State::NH,
+            State::NV,
+            State::OK,
+            State::SC,
+            State::SD,
+            State::UT,
+            State::VT,
+            State::WI,
+            State::WV,
+            State::WY,
+            State::DC,
         ]
     }
 }
@@ -303,10 +385,12 @@ pub enum Tier {
     Four,
 }
 
-/// Full jurisdiction including state and optional locality
+/// Full jurisdiction including state, optional county and optional locality
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Jurisdiction {
     pub state: State,
+    #[serde(default)]
+    pub county: Option<County>,
     pub locality: Option<Locality>,
 }
 
@@ -314,6 +398,7 @@ impl Jurisdiction {
     pub fn new(state: State) -> Self {
         Self {
             state,
+            county: None,
             locality: None,
         }
     }
@@ -321,23 +406,102 @@ impl Jurisdiction {
     pub fn with_locality(state: State, locality: Locality) -> Self {
         Self {
             state,
+            county: None,
             locality: Some(locality),
         }
     }
 
-    /// Create jurisdiction from zip code (for local ordinance detection)
+    pub fn with_county(state: State, county: County) -> Self {
+        Self {
+            state,
+            county: Some(county),
+            locality: None,
+        }
+    }
+
+    /// Create jurisdiction from zip code (for county and local ordinance detection)
+    ///
+    /// A ZIP outside the locality table leaves both county and locality
+    /// unset; see [`Self::local_coverage`].
     pub fn from_zip(state: State, zip: &str) -> Self {
-        let locality = Locality::from_zip(state, zip);
-        Self { state, locality }
+        Self {
+            state,
+            county: County::from_zip(state, zip),
+            locality: Locality::from_zip(state, zip),
+        }
+    }
+
+    /// Whether the local layer checks ordinances here, finds none to check,
+    /// or doesn't know where the property is
+    ///
+    /// Every ZIP in the locality table resolves to a county, so a
+    /// jurisdiction with neither a county nor a locality is unknown rather
+    /// than ordinance-free.
+    pub fn local_coverage(&self) -> LocalCoverage {
+        let locality_ordinances = self
+            .locality
+            .as_ref()
+            .is_some_and(|l| !matches!(l, Locality::Custom(_)));
+        let county_ordinances = self.county.as_ref().is_some_and(County::has_ordinances);
+        if locality_ordinances || county_ordinances {
+            LocalCoverage::Checked
+        } else if self.locality.is_some() || self.county.is_some() {
+            LocalCoverage::NoOrdinance
+        } else {
+            LocalCoverage::Unknown
+        }
     }
 
     /// Get the jurisdiction ID string (e.g., "US-IL-CHICAGO")
+    ///
+    /// Uses the locality when there is one, otherwise a county with its own
+    /// ordinances.
     pub fn id(&self) -> String {
-        match &self.locality {
-            Some(loc) => format!("US-{}-{}", self.state, loc.code()),
-            None => format!("US-{}", self.state),
+        match (&self.locality, &self.county) {
+            (Some(loc), _) => format!("US-{}-{}", self.state, loc.code()),
+            (None, Some(county)) if county.has_ordinances() => {
+                format!("US-{}-{}", self.state, county.code())
+            }
+            _ => format!("US-{}", self.state),
+        }
+    }
+}
+
+/// Counties, with variants for those whose ordinances the local layer checks
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum County {
+    /// Miami-Dade County, FL: Tenant's Bill of Rights
+    MiamiDade,
+    /// Montgomery County, MD: Chapter 29 and rent stabilization
+    MontgomeryMD,
+    /// Any other county, by name without "County"
+    Custom(String),
+}
+
+impl County {
+    pub fn code(&self) -> &str {
+        match self {
+            County::MiamiDade => "MIAMI_DADE",
+            County::MontgomeryMD => "MONTGOMERY",
+            County::Custom(s) => s,
         }
     }
+
+    /// Whether the local layer has county ordinances for this county
+    pub fn has_ordinances(&self) -> bool {
+        !matches!(self, County::Custom(_))
+    }
+
+    /// Detect county from zip code using the bundled locality table;
+    /// `None` when the ZIP isn't in it
+    pub fn from_zip(state: State, zip: &str) -> Option<Self> {
+        let record = locality_zips::lookup(zip).filter(|r| r.state == state)?;
+        Some(match (state, record.county) {
+            (State::FL, "Miami-Dade") => County::MiamiDade,
+            (State::MD, "Montgomery") => County::MontgomeryMD,
+            (_, name) => County::Custom(name.to_string()),
+        })
+    }
 }
 
 /// Known localities with special ordinances
@@ -356,6 +520,11 @@ pub enum Locality {
     NewYorkCity,
     // Other cities with rent control or special rules
     WashingtonDC,
+    Seattle,
+    Portland,
+    Minneapolis,
+    Boston,
+    Denver,
     Custom(String),
 }
 
@@ -371,51 +540,36 @@ impl Locality {
             Locality::Berkeley => "BERKELEY",
             Locality::NewYorkCity => "NYC",
             Locality::WashingtonDC => "DC",
+            Locality::Seattle => "SEATTLE",
+            Locality::Portland => "PORTLAND",
+            Locality::Minneapolis => "MINNEAPOLIS",
+            Locality::Boston => "BOSTON",
+            Locality::Denver => "DENVER",
             Locality::Custom(s) => s,
         }
     }
 
-    /// Detect locality from zip code
+    /// Detect locality from zip code using the bundled locality table
+    ///
+    /// Only cities with ordinances the local layer checks are returned; a ZIP
+    /// in any other city (or not in the table) has no locality.
     pub fn from_zip(state: State, zip: &str) -> Option<Self> {
-        match state {
-            State::IL => {
-                // Chicago zip codes: 60601-60661, 60701-60707
-                if let Ok(z) = zip.parse::<u32>() {
-                    if (60601..=60661).contains(&z) || (60701..=60707).contains(&z) {
-                        return Some(Locality::Chicago);
-                    }
-                }
-                None
-            }
-            State::NY => {
-                // NYC zip codes: 10001-10292, 10301-10314 (Staten Island), 11xxx (Brooklyn/Queens)
-                if let Ok(z) = zip.parse::<u32>() {
-                    if (10001..=10292).contains(&z)
-                        || (10301..=10314).contains(&z)
-                        || (11001..=11697).contains(&z)
-                    {
-                        return Some(Locality::NewYorkCity);
-                    }
-                }
-                None
-            }
-            State::CA => {
-                // San Francisco: 94102-94188
-                // Los Angeles: 90001-90189, 90201-90899
-                // Santa Monica: 90401-90411
-                if let Ok(z) = zip.parse::<u32>() {
-                    if (94102..=94188).contains(&z) {
-                        return Some(Locality::SanFrancisco);
-                    }
-                    if (90401..=90411).contains(&z) {
-                        return Some(Locality::SantaMonica);
-                    }
-                    if (90001..=90189).contains(&z) || (90201..=90899).contains(&z) {
-                        return Some(Locality::LosAngeles);
-                    }
-                }
-                None
-            }
+        let record = locality_zips::lookup(zip).filter(|r| r.state == state)?;
+        match (state, record.city) {
+            (State::IL, "Chicago") => Some(Locality::Chicago),
+            (State::CA, "San Francisco") => Some(Locality::SanFrancisco),
+            (State::CA, "Los Angeles") => Some(Locality::LosAngeles),
+            (State::CA, "Santa Monica") => Some(Locality::SantaMonica),
+            (State::CA, "West Hollywood") => Some(Locality::WestHollywood),
+            (State::CA, "Oakland") => Some(Locality::Oakland),
+            (State::CA, "Berkeley") => Some(Locality::Berkeley),
+            (State::NY, "New York") => Some(Locality::NewYorkCity),
+            (State::DC, "Washington") => Some(Locality::WashingtonDC),
+            (State::WA, "Seattle") => Some(Locality::Seattle),
+            (State::OR, "Portland") => Some(Locality::Portland),
+            (State::MN, "Minneapolis") => Some(Locality::Minneapolis),
+            (State::MA, "Boston") => Some(Locality::Boston),
+            (State::CO, "Denver") => Some(Locality::Denver),
             _ => None,
         }
     }
@@ -451,6 +605,14 @@ mod tests {
         assert_eq!(State::parse_code("florida"), Some(State::FL));
         assert_eq!(State::parse_code("TX"), Some(State::TX));
         assert_eq!(State::parse_code("texas"), Some(State::TX));
+        assert_eq!(State::parse_code("wy"), Some(State::WY));
+        assert_eq!(State::parse_code("New Hampshire"), Some(State::NH));
+
+        // Every state round-trips through its code and its name
+        for state in State::implemented_states() {
+            assert_eq!(State::parse_code(&state.to_string()), Some(state));
+            assert_eq!(State::parse_code(state.name()), Some(state));
+        }
     }
 
     #[test]
@@ -462,6 +624,15 @@ mod tests {
         assert_eq!(State::AK.tier(), Tier::Three);
     }
 
+    #[test]
+    fn test_implemented_means_dedicated_module() {
+        assert!(State::FL.is_implemented());
+        assert!(State::TN.is_implemented());
+        // Checked against a statutory profile only
+        assert!(!State::CO.is_implemented());
+        assert!(crate::states::has_implementation(State::CO));
+    }
+
     #[test]
     fn test_urlta_states() {
         assert!(State::FL.is_urlta_state());
@@ -486,6 +657,110 @@ mod tests {
         assert_eq!(Locality::from_zip(State::TX, "75001"), None);
     }
 
+    #[test]
+    fn test_boundary_zips() {
+        // Floral Park (Nassau) borders Glen Oaks (Queens)
+        assert_eq!(Locality::from_zip(State::NY, "11001"), None);
+        assert_eq!(
+            Locality::from_zip(State::NY, "11004"),
+            Some(Locality::NewYorkCity)
+        );
+        assert_eq!(
+            Locality::from_zip(State::NY, "10451"),
+            Some(Locality::NewYorkCity)
+        );
+        assert_eq!(Locality::from_zip(State::NY, "10701"), None);
+
+        // Harwood Heights is an enclave inside Chicago
+        assert_eq!(Locality::from_zip(State::IL, "60706"), None);
+        assert_eq!(
+            County::from_zip(State::IL, "60706"),
+            Some(County::Custom("Cook".to_string()))
+        );
+
+        // West Hollywood and Beverly Hills ZIPs are numbered among Los Angeles'
+        assert_eq!(
+            Locality::from_zip(State::CA, "90069"),
+            Some(Locality::WestHollywood)
+        );
+        assert_eq!(Locality::from_zip(State::CA, "90210"), None);
+        assert_eq!(Locality::from_zip(State::CA, "94128"), None);
+
+        // Tukwila and Burien ZIPs are numbered among Seattle's
+        assert_eq!(
+            Locality::from_zip(State::WA, "98178"),
+            Some(Locality::Seattle)
+        );
+        assert_eq!(Locality::from_zip(State::WA, "98168"), None);
+
+        // Milwaukie (Clackamas) borders Portland
+        assert_eq!(
+            Locality::from_zip(State::OR, "97202"),
+            Some(Locality::Portland)
+        );
+        assert_eq!(Locality::from_zip(State::OR, "97222"), None);
+
+        // Columbia Heights is in Anoka County
+        assert_eq!(
+            Locality::from_zip(State::MN, "55418"),
+            Some(Locality::Minneapolis)
+        );
+        assert_eq!(
+            County::from_zip(State::MN, "55421"),
+            Some(County::Custom("Anoka".to_string()))
+        );
+
+        // Cambridge and Brookline border Boston
+        assert_eq!(
+            Locality::from_zip(State::MA, "02134"),
+            Some(Locality::Boston)
+        );
+        assert_eq!(Locality::from_zip(State::MA, "02139"), None);
+        assert_eq!(Locality::from_zip(State::MA, "02446"), None);
+
+        // Lakewood (Jefferson) borders Denver
+        assert_eq!(
+            Locality::from_zip(State::CO, "80204"),
+            Some(Locality::Denver)
+        );
+        assert_eq!(Locality::from_zip(State::CO, "80214"), None);
+
+        // A ZIP is only matched in its own state
+        assert_eq!(Locality::from_zip(State::ME, "97202"), None);
+    }
+
+    #[test]
+    fn test_county_from_zip() {
+        // Hallandale Beach (Broward) borders Hialeah (Miami-Dade)
+        assert_eq!(
+            County::from_zip(State::FL, "33010"),
+            Some(County::MiamiDade)
+        );
+        assert_eq!(
+            County::from_zip(State::FL, "33009"),
+            Some(County::Custom("Broward".to_string()))
+        );
+        assert_eq!(
+            County::from_zip(State::FL, "33037"),
+            Some(County::Custom("Monroe".to_string()))
+        );
+
+        // Silver Spring (Montgomery) borders Hyattsville (Prince George's)
+        assert_eq!(
+            County::from_zip(State::MD, "20903"),
+            Some(County::MontgomeryMD)
+        );
+        assert_eq!(
+            County::from_zip(State::MD, "20783"),
+            Some(County::Custom("Prince George's".to_string()))
+        );
+
+        let miami_beach = Jurisdiction::from_zip(State::FL, "33139");
+        assert_eq!(miami_beach.county, Some(County::MiamiDade));
+        assert_eq!(miami_beach.locality, None);
+        assert_eq!(miami_beach.id(), "US-FL-MIAMI_DADE");
+    }
+
     #[test]
     fn test_jurisdiction_id() {
         let fl = Jurisdiction::new(State::FL);
@@ -518,9 +793,6 @@ mod tests {
                 state
             );
         }
-
-        // Unimplemented states should have None
-        assert_eq!(State::AL.statute_citation(), None);
-        assert_eq!(State::WY.statute_citation(), None);
+        assert_eq!(State::implemented_states().len(), 51);
     }
 }
@@ -0,0 +1,303 @@
+//! Document language and localized violation messages
+//!
+//! The federal layer and the FL, TX, CA and NY rules match English and
+//! Spanish wording alike. [`Language::detect`] tells which one a lease is
+//! written in, and [`localize`] rewrites violation messages into it from the
+//! catalogue in `data/messages_es.toml`. Messages the catalogue does not have
+//! (local ordinances, rule packs, other states) stay in English.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use shared_types::Violation;
+use std::collections::HashMap;
+
+const MESSAGES_ES_TOML: &str = include_str!("../data/messages_es.toml");
+
+/// Words frequent in English leases and rare in Spanish ones
+const ENGLISH_WORDS: &[&str] = &[
+    "the", "and", "of", "to", "shall", "is", "be", "for", "with", "any", "this", "tenant",
+    "landlord", "lease",
+];
+
+/// Words frequent in Spanish leases and rare in English ones
+const SPANISH_WORDS: &[&str] = &[
+    "el",
+    "la",
+    "los",
+    "las",
+    "del",
+    "de",
+    "que",
+    "y",
+    "por",
+    "para",
+    "con",
+    "una",
+    "su",
+    "será",
+    "deberá",
+    "inquilino",
+    "arrendador",
+    "arrendatario",
+    "contrato",
+];
+
+/// Spanish words needed before a text is read as Spanish, so a one-line
+/// snippet with a Spanish name in it stays English
+const MIN_SPANISH_WORDS: usize = 5;
+
+/// Language a lease is written in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    /// The language most of the text is written in; English when unsure
+    pub fn detect(text: &str) -> Self {
+        let (mut english, mut spanish) = (0, 0);
+        for word in text.split(|c: char| !c.is_alphabetic()) {
+            if word.is_empty() {
+                continue;
+            }
+            let word = word.to_lowercase();
+            if ENGLISH_WORDS.contains(&word.as_str()) {
+                english += 1;
+            } else if SPANISH_WORDS.contains(&word.as_str()) {
+                spanish += 1;
+            }
+        }
+
+        if spanish >= MIN_SPANISH_WORDS && spanish > english {
+            Language::Spanish
+        } else {
+            Language::English
+        }
+    }
+
+    /// ISO 639-1 code
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CatalogueFile {
+    message: Vec<MessageEntry>,
+    #[serde(default)]
+    terms: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct MessageEntry {
+    en: String,
+    es: String,
+}
+
+/// A message template, matched against the English text a rule produced
+struct Translation {
+    pattern: Regex,
+    pieces: Vec<String>,
+}
+
+struct Catalogue {
+    messages: Vec<Translation>,
+    terms: HashMap<String, String>,
+}
+
+lazy_static! {
+    static ref SPANISH: Catalogue = parse(MESSAGES_ES_TOML);
+}
+
+fn parse(toml: &str) -> Catalogue {
+    let file: CatalogueFile = toml::from_str(toml).expect("messages_es.toml: invalid");
+    let messages = file
+        .message
+        .into_iter()
+        .map(|entry| {
+            let holes = entry
+                .en
+                .split("{}")
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join("(.+?)");
+            let pieces: Vec<String> = entry.es.split("{}").map(str::to_string).collect();
+            assert_eq!(
+                pieces.len(),
+                entry.en.split("{}").count(),
+                "messages_es.toml: placeholders differ in {:?}",
+                entry.en
+            );
+            Translation {
+                pattern: Regex::new(&format!("^{}$", holes))
+                    .expect("messages_es.toml: bad template"),
+                pieces,
+            }
+        })
+        .collect();
+
+    Catalogue {
+        messages,
+        terms: file.terms,
+    }
+}
+
+impl Catalogue {
+    fn translate(&self, message: &str) -> Option<String> {
+        self.messages.iter().find_map(|translation| {
+            let caps = translation.pattern.captures(message)?;
+            let mut out = translation.pieces[0].clone();
+            for (i, piece) in translation.pieces[1..].iter().enumerate() {
+                out.push_str(&self.term(&caps[i + 1]));
+                out.push_str(piece);
+            }
+            Some(out)
+        })
+    }
+
+    /// A filled-in value in Spanish; lists ("a, b") are translated item by item
+    fn term(&self, value: &str) -> String {
+        if let Some(term) = self.terms.get(value) {
+            return term.clone();
+        }
+        value
+            .split(", ")
+            .map(|item| self.terms.get(item).map_or(item, String::as_str))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// A violation message in `language`, if the catalogue has it
+///
+/// English messages are returned unchanged.
+pub fn translate(message: &str, language: Language) -> Option<String> {
+    match language {
+        Language::English => Some(message.to_string()),
+        Language::Spanish => SPANISH.translate(message),
+    }
+}
+
+/// Rewrite violation messages into `language` where a translation exists
+pub fn localize(violations: &mut [Violation], language: Language) {
+    if language == Language::English {
+        return;
+    }
+    for violation in violations {
+        if let Some(message) = translate(&violation.message, language) {
+            violation.message = message;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::terms::LeaseTerms;
+    use crate::layers::check_federal_compliance;
+    use crate::states::{california, florida, new_york, texas};
+
+    #[test]
+    fn test_detects_language() {
+        let english = "The Tenant shall pay rent to the Landlord on the first day of each month.";
+        assert_eq!(Language::detect(english), Language::English);
+
+        let spanish = "El Inquilino pagará la renta al Arrendador el primer día de cada mes, \
+                       por medio de cheque o transferencia a la cuenta del Arrendador.";
+        assert_eq!(Language::detect(spanish), Language::Spanish);
+
+        // A Spanish name in an English lease does not flip it
+        let mixed = "This lease is between José de la Cruz (Landlord) and the Tenant.";
+        assert_eq!(Language::detect(mixed), Language::English);
+        assert_eq!(Language::detect(""), Language::English);
+    }
+
+    #[test]
+    fn test_translates_filled_in_values() {
+        let message =
+            "Void clause: Waiver of jury trial rights is unenforceable under California law.";
+        assert_eq!(
+            translate(message, Language::Spanish).unwrap(),
+            "Cláusula nula: la renuncia a los derechos a un juicio con jurado no es exigible \
+             según la ley de California."
+        );
+
+        let message = "Security deposit (${:.2}) exceeds 1 month's rent (${:.2}). \
+                       NY law caps deposits at 1 month's rent.";
+        let message = message.replace("{:.2}", "3000.00");
+        assert!(translate(&message, Language::Spanish)
+            .unwrap()
+            .starts_with("El depósito de seguridad ($3000.00) supera"));
+
+        assert_eq!(translate("Unknown message", Language::Spanish), None);
+        assert_eq!(
+            translate("Unknown message", Language::English).as_deref(),
+            Some("Unknown message")
+        );
+    }
+
+    #[test]
+    fn test_catalogue_covers_federal_and_state_rules() {
+        // English leases tripping every federal, FL, TX, CA and NY rule
+        let fl = "Tenant waives all rights to notice before termination. Landlord may \
+                  dispose of property left by tenant. Tenant accepts the unit AS-IS including \
+                  plumbing. Tenant waives all rights under Florida law. Security deposit \
+                  returned within 45 days. Tenant shall pay landlord attorney fees. \
+                  2 days notice for nonpayment of rent. 3 days notice for violation. \
+                  7 days notice to terminate month-to-month. Notices by email.";
+        let fl_30 = "Security deposit will be returned within 30 days.";
+        let tx = "Landlord may change locks. Application fee for background check. \
+                  Vehicles will be towed. Deposit returned within 45 days. Tenant handles \
+                  repairs. Tenant waives repair and jury rights. Late fee of 15%.";
+        let ca = "Monthly rent: $2,000. Security deposit: $4,000. Tenant waives jury trial \
+                  rights. Tenant waives notice. Tenant waives habitability. Tenant waives \
+                  repair duties. Tenant waives rights under statute. Administrative fee \
+                  applies. Landlord may terminate. Rent increase at any time.";
+        let ny = "Monthly rent: $2,000. Security deposit: $4,000. Late fee: $100. \
+                  Late fee of 10%. New York City, NY 10001. Built in 1960. Renewal at \
+                  landlord's option. Tenant waives liability for negligence. Confession of \
+                  judgment.";
+
+        let mut violations = check_federal_compliance("Built in 1960.", None);
+        violations.extend(check_federal_compliance(
+            "Built in 1960. Lead-based paint disclosure attached.",
+            None,
+        ));
+        violations.extend(check_federal_compliance(
+            "No children. Christian only. Whites only. No wheelchairs. Female only.",
+            None,
+        ));
+        for text in [fl, fl_30] {
+            violations.extend(florida::check_florida_compliance(
+                text,
+                &LeaseTerms::extract(text),
+            ));
+        }
+        violations.extend(texas::check_texas_compliance(tx));
+        violations.extend(california::check_california_compliance(
+            ca,
+            &LeaseTerms::extract(ca),
+        ));
+        violations.extend(new_york::check_new_york_compliance(
+            ny,
+            &LeaseTerms::extract(ny),
+        ));
+
+        let mut statutes: Vec<_> = violations.iter().map(|v| v.statute.as_str()).collect();
+        statutes.sort();
+        statutes.dedup();
+        assert!(statutes.len() >= 35, "only {} rules fired", statutes.len());
+
+        for violation in &violations {
+            let spanish = translate(&violation.message, Language::Spanish)
+                .unwrap_or_else(|| panic!("no translation for {:?}", violation.message));
+            assert!(!spanish.contains("{}"), "{spanish}");
+        }
+    }
+}
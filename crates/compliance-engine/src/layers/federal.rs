@@ -4,6 +4,8 @@
 //! - Lead-Based Paint Disclosure (pre-1978 properties)
 //! - Fair Housing Act compliance (no discrimination)
 
+use crate::clauses;
+use crate::patterns::{ceil_char_boundary, floor_char_boundary};
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, TextPosition, Violation};
@@ -11,35 +13,35 @@ use shared_types::{Severity, TextPosition, Violation};
 lazy_static! {
     /// Lead paint disclosure patterns
     static ref LEAD_PAINT_DISCLOSURE_PATTERN: Regex =
-        Regex::new(r"(?i)(lead.based\s+paint|lead\s+paint|lead.hazard|EPA\s+pamphlet|protect\s+your\s+family)").unwrap();
+        Regex::new(r"(?i)(lead.based\s+paint|lead\s+paint|lead.hazard|EPA\s+pamphlet|protect\s+your\s+family|pintura\s+(?:a\s+base\s+de|con)\s+plomo|peligros?\s+del?\s+plomo|folleto\s+de\s+la\s+EPA|proteja\s+a\s+su\s+familia)").unwrap();
 
     /// Year built extraction pattern
     static ref YEAR_BUILT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:built|constructed|year\s+built)(?:\s+in)?[:\s]+(\d{4})").unwrap();
+        Regex::new(r"(?i)(?:built|constructed|year\s+built|construid[oa]|a[ñn]o\s+de\s+construcci[oó]n)(?:\s+(?:in|en))?[:\s]+(\d{4})").unwrap();
 
     /// Fair Housing Act protected classes
     static ref FAIR_HOUSING_PATTERNS: Vec<(Regex, &'static str)> = vec![
         (
-            Regex::new(r"(?i)\b(no\s+children|no\s+kids|adults\s+only|child.?free)\b").unwrap(),
+            Regex::new(r"(?i)\b(no\s+children|no\s+kids|adults\s+only|child.?free|no\s+se\s+(?:admiten|aceptan|permiten)\s+niños|sin\s+niños|s[oó]lo\s+(?:para\s+)?adultos)\b").unwrap(),
             "familial status",
         ),
         (
-            Regex::new(r"(?i)\b(christian\s+only|no\s+muslims|no\s+jews|religious\s+preference)\b")
+            Regex::new(r"(?i)\b(christian\s+only|no\s+muslims|no\s+jews|religious\s+preference|s[oó]lo\s+cristianos|no\s+(?:se\s+aceptan\s+)?(?:musulmanes|jud[ií]os)|preferencia\s+religiosa)\b")
                 .unwrap(),
             "religion",
         ),
         (
-            Regex::new(r"(?i)\b(whites?\s+only|no\s+blacks?|caucasian\s+only|no\s+hispanics?)\b")
+            Regex::new(r"(?i)\b(whites?\s+only|no\s+blacks?|caucasian\s+only|no\s+hispanics?|s[oó]lo\s+blancos|no\s+(?:se\s+aceptan\s+)?(?:negros|hispanos|latinos))\b")
                 .unwrap(),
             "race/national origin",
         ),
         (
-            Regex::new(r"(?i)\b(no\s+disabled|no\s+wheelchairs?|must\s+be\s+able.bodied)\b")
+            Regex::new(r"(?i)\b(no\s+disabled|no\s+wheelchairs?|must\s+be\s+able.bodied|no\s+(?:se\s+aceptan\s+)?(?:discapacitados|sillas\s+de\s+ruedas))\b")
                 .unwrap(),
             "disability",
         ),
         (
-            Regex::new(r"(?i)\b(female\s+only|male\s+only|no\s+single\s+(?:men|women))\b").unwrap(),
+            Regex::new(r"(?i)\b(female\s+only|male\s+only|no\s+single\s+(?:men|women)|s[oó]lo\s+(?:mujeres|hombres))\b").unwrap(),
             "sex",
         ),
     ];
@@ -79,14 +81,20 @@ pub fn check_lead_paint_disclosure(text: &str, year_built: Option<u32>) -> Vec<V
         None => {
             // Can't determine year - check if any lead paint language exists
             // If mentioned but no disclosure, that's a problem
-            text.to_lowercase().contains("1978") || text.to_lowercase().contains("lead")
+            let text_lower = text.to_lowercase();
+            text_lower.contains("1978")
+                || text_lower.contains("lead")
+                || text_lower.contains("plomo")
         }
     };
 
     if is_pre_1978 {
         let has_disclosure = LEAD_PAINT_DISCLOSURE_PATTERN.is_match(text);
-        let has_pamphlet_reference = text.to_lowercase().contains("pamphlet")
-            || text.to_lowercase().contains("protect your family");
+        let text_lower = text.to_lowercase();
+        let has_pamphlet_reference = text_lower.contains("pamphlet")
+            || text_lower.contains("protect your family")
+            || text_lower.contains("folleto")
+            || text_lower.contains("proteja a su familia");
 
         if !has_disclosure {
             violations.push(Violation {
@@ -98,6 +106,8 @@ pub fn check_lead_paint_disclosure(text: &str, year_built: Option<u32>) -> Vec<V
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         } else if !has_pamphlet_reference {
             violations.push(Violation {
@@ -109,6 +119,8 @@ pub fn check_lead_paint_disclosure(text: &str, year_built: Option<u32>) -> Vec<V
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -122,9 +134,11 @@ pub fn check_lead_paint_disclosure(text: &str, year_built: Option<u32>) -> Vec<V
 /// Protected classes: race, color, religion, sex, familial status, national origin, disability
 pub fn check_fair_housing(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
+    // "does not have a no children policy" is not a preference
+    let operative = clauses::operative_text(text);
 
     for (pattern, protected_class) in FAIR_HOUSING_PATTERNS.iter() {
-        if let Some(m) = pattern.find(text) {
+        if let Some(m) = pattern.find(&operative) {
             let snippet = extract_context(text, m.start(), m.end());
             violations.push(Violation {
                 statute: "42 U.S.C. § 3604".to_string(),
@@ -136,10 +150,9 @@ pub fn check_fair_housing(text: &str) -> Vec<Violation> {
                 ),
                 page: None,
                 text_snippet: Some(snippet),
-                text_position: Some(TextPosition {
-                    start_offset: m.start(),
-                    end_offset: m.end(),
-                }),
+                text_position: Some(TextPosition::new(m.start(), m.end())),
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -150,8 +163,8 @@ pub fn check_fair_housing(text: &str) -> Vec<Violation> {
 /// Extract context around a match for display
 fn extract_context(text: &str, start: usize, end: usize) -> String {
     let context_size = 50;
-    let ctx_start = start.saturating_sub(context_size);
-    let ctx_end = (end + context_size).min(text.len());
+    let ctx_start = floor_char_boundary(text, start.saturating_sub(context_size));
+    let ctx_end = ceil_char_boundary(text, end + context_size);
 
     let mut result = String::new();
     if ctx_start > 0 {
@@ -231,6 +244,48 @@ mod tests {
         assert!(violations.is_empty());
     }
 
+    #[test]
+    fn test_lead_paint_spanish() {
+        let text = "La propiedad fue construida en 1965. La renta mensual es $1500.";
+        let violations = check_lead_paint_disclosure(text, None);
+        assert!(violations.iter().any(|v| v.statute.contains("4852d")));
+
+        let text = "Construida en 1965. Divulgación de pintura a base de plomo: el Arrendador \
+                   no tiene conocimiento de peligros del plomo.";
+        let violations = check_lead_paint_disclosure(text, None);
+        assert!(violations.iter().any(|v| v.statute == "24 CFR 35.92"));
+
+        let text = "Construida en 1965. Divulgación de pintura a base de plomo. El Inquilino \
+                   recibió el folleto \"Proteja a su familia contra el plomo en su casa\".";
+        assert!(check_lead_paint_disclosure(text, None).is_empty());
+    }
+
+    #[test]
+    fn test_fair_housing_spanish() {
+        let cases = [
+            ("No se admiten niños en la propiedad.", "familial"),
+            ("Comunidad solo para adultos.", "familial"),
+            ("Se prefiere hogar sólo cristianos.", "religion"),
+            ("Solo blancos pueden solicitar.", "race"),
+            (
+                "No se aceptan sillas de ruedas en el edificio.",
+                "disability",
+            ),
+            ("Solo mujeres pueden alquilar esta habitación.", "sex"),
+        ];
+        for (text, class) in cases {
+            let violations = check_fair_housing(text);
+            assert!(
+                violations.iter().any(|v| v.message.contains(class)),
+                "{text}"
+            );
+        }
+
+        let text = "Todos los solicitantes serán considerados sin distinción de raza, \
+                   religión, sexo, situación familiar o discapacidad.";
+        assert!(check_fair_housing(text).is_empty());
+    }
+
     #[test]
     fn test_federal_combined() {
         let text = "Built 1960. No children. Adults only community.";
@@ -1,45 +1,90 @@
 //! Local Layer - Municipal/County Ordinance Compliance
 //!
 //! Layer 3 of the Layer Cake: Local overrides that supersede state law.
-//! These are triggered by locality (from ZIP code or explicit selection).
+//! These are triggered by county and locality (from ZIP code or explicit
+//! selection). County ordinances are checked first, then city ordinances.
+//!
+//! Key counties:
+//! - Miami-Dade (FL): Tenant's Bill of Rights (preempted July 2023)
+//! - Montgomery County (MD): Chapter 29 lease terms, rent stabilization
 //!
 //! Key localities:
 //! - Chicago (IL): RLTO Summary, deposit interest, bed bug disclosure
 //! - NYC (NY): Rent stabilization, good cause eviction, 1-month deposit cap
 //! - San Francisco (CA): Rent ordinance, just cause eviction
 //! - Los Angeles (CA): RSO, relocation assistance
-//! - Seattle (WA): Just cause eviction, first-in-time
-
-use crate::jurisdiction::{Jurisdiction, Locality};
+//! - Seattle (WA): Move-in cost cap, renter information handout
+//! - Portland (OR): 90-day rent increase notice, relocation assistance
+//! - Minneapolis (MN), Boston (MA), Denver (CO): Rental licensing/registration
+
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
+use crate::jurisdiction::{County, Jurisdiction, Locality};
+use crate::states;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[MIAMI_DADE_TENANT_RIGHTS, MONTGOMERY_RENT_STABILIZATION];
+
+/// Tenant's Bill of Rights, preempted by F.S. § 83.425 (HB 1417)
+pub const MIAMI_DADE_TENANT_RIGHTS: DatedRule = DatedRule::between(
+    "Miami-Dade Ord. No. 22-56",
+    date(2022, 5, 13),
+    date(2023, 6, 30),
+);
+
+/// Montgomery County rent stabilization
+pub const MONTGOMERY_RENT_STABILIZATION: DatedRule = DatedRule::from(
+    "Mont. Co. Bill 15-23 (Rent Stabilization)",
+    date(2023, 7, 23),
+);
+
 lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+    /// Annual rent increase stated as a percentage
+    static ref RENT_INCREASE_PERCENT_PATTERN: Regex =
+        Regex::new(r"(?i)(?:rent[^.%]*?increase|increase[^.%]*?rent)[^.%]*?(\d+(?:\.\d+)?)\s*%").unwrap();
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+    /// Denver's residential rental license, not a driver's or business license
+    static ref DENVER_LICENSE_PATTERN: Regex =
+        Regex::new(r"(?i)\brental\s+(?:property\s+)?licen[cs]e").unwrap();
 }
 
 /// Check local/municipal compliance requirements
 ///
 /// This is Layer 3 of the Layer Cake - local ordinances that override state defaults.
 /// Only runs if jurisdiction has a locality set.
-pub fn check_local_compliance(jurisdiction: &Jurisdiction, text: &str) -> Vec<Violation> {
+pub fn check_local_compliance(
+    jurisdiction: &Jurisdiction,
+    text: &str,
+    terms: &LeaseTerms,
+) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    // Only check if locality is specified
+    // County ordinances apply beneath any city ordinance
+    if let Some(ref county) = jurisdiction.county {
+        match county {
+            County::MiamiDade => {
+                violations.extend(check_miami_dade_requirements(text, terms));
+            }
+            County::MontgomeryMD => {
+                violations.extend(check_montgomery_county_requirements(text));
+            }
+            County::Custom(_) => {
+                // Counties without ordinances of their own
+            }
+        }
+    }
+
+    // City ordinances, if a locality is specified
     if let Some(ref locality) = jurisdiction.locality {
         match locality {
             Locality::Chicago => {
                 violations.extend(check_chicago_rlto(text));
             }
             Locality::NewYorkCity => {
-                violations.extend(check_nyc_requirements(text));
+                violations.extend(check_nyc_requirements(text, terms));
             }
             Locality::SanFrancisco => {
                 violations.extend(check_sf_requirements(text));
@@ -61,6 +106,21 @@ pub fn check_local_compliance(jurisdiction: &Jurisdiction, text: &str) -> Vec<Vi
             Locality::WashingtonDC => {
                 violations.extend(check_dc_requirements(text));
             }
+            Locality::Seattle => {
+                violations.extend(check_seattle_requirements(text, terms));
+            }
+            Locality::Portland => {
+                violations.extend(check_portland_requirements(text, terms));
+            }
+            Locality::Minneapolis => {
+                violations.extend(check_minneapolis_requirements(text));
+            }
+            Locality::Boston => {
+                violations.extend(check_boston_requirements(text));
+            }
+            Locality::Denver => {
+                violations.extend(check_denver_requirements(text));
+            }
             Locality::Custom(_) => {
                 // Custom localities - no specific rules
             }
@@ -70,6 +130,139 @@ pub fn check_local_compliance(jurisdiction: &Jurisdiction, text: &str) -> Vec<Vi
     violations
 }
 
+// ============================================================================
+// Miami-Dade County Tenant's Bill of Rights
+// ============================================================================
+
+/// Check Miami-Dade County Tenant's Bill of Rights requirements
+///
+/// Per Miami-Dade Ord. No. 22-56, preempted by F.S. § 83.425 (HB 1417) from
+/// July 1, 2023; the effective dates in [`crate::effective`] drop these for
+/// later leases.
+/// - Notice of the Tenant's Bill of Rights
+/// - 60 days' notice of a rent increase over 5%
+fn check_miami_dade_requirements(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let text_lower = text.to_lowercase();
+
+    let has_bill_of_rights = text_lower.contains("tenant's bill of rights")
+        || text_lower.contains("tenants bill of rights")
+        || text_lower.contains("tenant bill of rights");
+
+    if !has_bill_of_rights {
+        violations.push(Violation {
+            statute: MIAMI_DADE_TENANT_RIGHTS.citation.to_string(),
+            severity: Severity::Warning,
+            message: "Miami-Dade County requires landlords to give tenants notice of the \
+                     Tenant's Bill of Rights."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    for days in terms.rent_increase_notice_days.iter().map(|d| d.value) {
+        if days < 60 {
+            violations.push(Violation {
+                statute: MIAMI_DADE_TENANT_RIGHTS.citation.to_string(),
+                severity: Severity::Critical,
+                message: format!(
+                    "Miami-Dade County requires 60 days' written notice of a rent increase \
+                     over 5%. Found: {} days",
+                    days
+                ),
+                page: None,
+                text_snippet: None,
+                text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
+            });
+        }
+    }
+
+    violations
+}
+
+// ============================================================================
+// Montgomery County, MD Requirements
+// ============================================================================
+
+/// Check Montgomery County, MD requirements
+///
+/// Per Montgomery County Code Chapter 29:
+/// - Landlord must offer an initial two-year lease term (§ 29-27)
+/// - Landlord-Tenant Handbook must be provided (§ 29-27)
+/// - Rent stabilization caps annual increases at 6% (Bill 15-23)
+fn check_montgomery_county_requirements(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let text_lower = text.to_lowercase();
+
+    let mentions_two_year_offer = text_lower.contains("two-year")
+        || text_lower.contains("two year")
+        || text_lower.contains("2-year")
+        || text_lower.contains("24 months")
+        || text_lower.contains("24-month");
+
+    if !mentions_two_year_offer {
+        violations.push(Violation {
+            statute: "Mont. Co. Code § 29-27".to_string(),
+            severity: Severity::Warning,
+            message: "Montgomery County requires landlords to offer an initial lease term of \
+                     two years unless there is reasonable cause not to. Record the offer."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    if !text_lower.contains("handbook") {
+        violations.push(Violation {
+            statute: "Mont. Co. Code § 29-27".to_string(),
+            severity: Severity::Warning,
+            message: "Montgomery County requires landlords to give tenants the County's \
+                     Landlord-Tenant Handbook."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    let increase = RENT_INCREASE_PERCENT_PATTERN
+        .captures(text)
+        .and_then(|caps| caps.get(1))
+        .and_then(|m| m.as_str().parse::<f64>().ok());
+
+    if let Some(percent) = increase {
+        if percent > 6.0 {
+            violations.push(Violation {
+                statute: MONTGOMERY_RENT_STABILIZATION.citation.to_string(),
+                severity: Severity::Critical,
+                message: format!(
+                    "Montgomery County caps annual rent increases at the lesser of CPI plus 3% \
+                     or 6%. Found: {}%",
+                    percent
+                ),
+                page: None,
+                text_snippet: None,
+                text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
+            });
+        }
+    }
+
+    violations
+}
+
 // ============================================================================
 // Chicago RLTO (Residential Landlord and Tenant Ordinance)
 // ============================================================================
@@ -101,6 +294,8 @@ fn check_chicago_rlto(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -121,6 +316,8 @@ fn check_chicago_rlto(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -140,6 +337,8 @@ fn check_chicago_rlto(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -156,25 +355,15 @@ fn check_chicago_rlto(text: &str) -> Vec<Violation> {
 /// - Security deposit cap (1 month max under HSTPA)
 /// - Good Cause Eviction rider (2024+)
 /// - Rent Stabilization (pre-1974 buildings)
-fn check_nyc_requirements(text: &str) -> Vec<Violation> {
+fn check_nyc_requirements(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
     let text_lower = text.to_lowercase();
 
     // Check security deposit cap (NY RPL 7-108: 1 month max statewide, but NYC enforces strictly)
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
-
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
-
-    if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
+    if let Some((deposit_amt, rent_amt)) = terms.deposit_and_rent() {
         if deposit_amt > rent_amt {
             violations.push(Violation {
-                statute: "NY RPL § 7-108".to_string(),
+                statute: states::new_york::DEPOSIT_CAP.citation.to_string(),
                 severity: Severity::Critical,
                 message: format!(
                     "Security deposit (${:.2}) exceeds NYC limit of 1 month's rent (${:.2}). \
@@ -184,6 +373,8 @@ fn check_nyc_requirements(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -195,7 +386,7 @@ fn check_nyc_requirements(text: &str) -> Vec<Violation> {
 
     if !has_good_cause_notice {
         violations.push(Violation {
-            statute: "NY RPL § 226-c".to_string(),
+            statute: states::new_york::GOOD_CAUSE.citation.to_string(),
             severity: Severity::Info,
             message: "Consider including Good Cause Eviction notice for NYC properties. \
                      Required for buildings with 10+ units unless exempt."
@@ -203,6 +394,8 @@ fn check_nyc_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -221,6 +414,8 @@ fn check_nyc_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -251,6 +446,8 @@ fn check_sf_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -282,6 +479,8 @@ fn check_la_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -310,6 +509,8 @@ fn check_santa_monica_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -339,6 +540,8 @@ fn check_bay_area_rent_control(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -370,6 +573,195 @@ fn check_dc_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+// ============================================================================
+// Seattle Requirements
+// ============================================================================
+
+/// Check Seattle requirements
+///
+/// Per Seattle Municipal Code Chapter 7.24:
+/// - Security deposit and move-in fees together capped at 1 month's rent (7.24.035)
+/// - City renter information handout must be provided (7.24.030)
+fn check_seattle_requirements(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let text_lower = text.to_lowercase();
+
+    if let Some((deposit_amt, rent_amt)) = terms.deposit_and_rent() {
+        if deposit_amt > rent_amt {
+            violations.push(Violation {
+                statute: "Seattle Mun. Code § 7.24.035".to_string(),
+                severity: Severity::Critical,
+                message: format!(
+                    "Security deposit (${:.2}) exceeds Seattle's limit of 1 month's rent (${:.2}) \
+                     for the deposit and move-in fees combined.",
+                    deposit_amt, rent_amt
+                ),
+                page: None,
+                text_snippet: None,
+                text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
+            });
+        }
+    }
+
+    let has_handout = text_lower.contains("renter handbook")
+        || text_lower.contains("renter's handbook")
+        || text_lower.contains("renters handbook")
+        || text_lower.contains("information packet")
+        || text_lower.contains("7.24.030");
+
+    if !has_handout {
+        violations.push(Violation {
+            statute: "Seattle Mun. Code § 7.24.030".to_string(),
+            severity: Severity::Warning,
+            message: "Seattle requires landlords to give tenants the City's renter information \
+                     handout with the lease."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+// ============================================================================
+// Portland Requirements
+// ============================================================================
+
+/// Check Portland requirements
+///
+/// Per Portland City Code 30.01.085:
+/// - 90 days' written notice of a rent increase
+/// - Relocation assistance on no-cause termination or a 10%+ increase
+fn check_portland_requirements(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let text_lower = text.to_lowercase();
+
+    for days in terms.rent_increase_notice_days.iter().map(|d| d.value) {
+        if days < 90 {
+            violations.push(Violation {
+                statute: "Portland City Code § 30.01.085".to_string(),
+                severity: Severity::Critical,
+                message: format!(
+                    "Portland requires 90 days' written notice of a rent increase. Found: {} days",
+                    days
+                ),
+                page: None,
+                text_snippet: None,
+                text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
+            });
+        }
+    }
+
+    if !text_lower.contains("relocation") {
+        violations.push(Violation {
+            statute: "Portland City Code § 30.01.085".to_string(),
+            severity: Severity::Info,
+            message: "Portland requires relocation assistance when a landlord ends a tenancy \
+                     without cause or raises rent 10% or more. Consider disclosing it."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+// ============================================================================
+// Minneapolis Requirements
+// ============================================================================
+
+/// Check Minneapolis requirements
+fn check_minneapolis_requirements(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if !text.to_lowercase().contains("rental license") {
+        violations.push(Violation {
+            statute: "Minneapolis Code § 244.1810".to_string(),
+            severity: Severity::Info,
+            message: "Minneapolis requires a rental license for every rental dwelling. \
+                     Verify the property is licensed."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+// ============================================================================
+// Boston Requirements
+// ============================================================================
+
+/// Check Boston requirements
+fn check_boston_requirements(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let text_lower = text.to_lowercase();
+
+    let has_registration =
+        text_lower.contains("rental registry") || text_lower.contains("rental registration");
+
+    if !has_registration {
+        violations.push(Violation {
+            statute: "Boston City Code § 9-1.3".to_string(),
+            severity: Severity::Info,
+            message: "Boston requires rental units to be registered with the Rental Registry. \
+                     Verify the unit is registered."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+// ============================================================================
+// Denver Requirements
+// ============================================================================
+
+/// Check Denver requirements
+fn check_denver_requirements(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if !DENVER_LICENSE_PATTERN.is_match(text) {
+        violations.push(Violation {
+            statute: "D.R.M.C. § 27-201".to_string(),
+            severity: Severity::Info,
+            message: "Denver requires a residential rental property license. \
+                     Verify the property is licensed."
+                .to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -381,6 +773,10 @@ mod tests {
     use super::*;
     use crate::jurisdiction::State;
 
+    fn check(jurisdiction: &Jurisdiction, text: &str) -> Vec<Violation> {
+        check_local_compliance(jurisdiction, text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Chicago RLTO Tests
     // ========================================================================
@@ -390,7 +786,7 @@ mod tests {
         let jurisdiction = Jurisdiction::with_locality(State::IL, Locality::Chicago);
         let text = "Monthly rent: $2,000. Security deposit: $2,000.";
 
-        let violations = check_local_compliance(&jurisdiction, text);
+        let violations = check(&jurisdiction, text);
 
         assert!(
             violations
@@ -406,7 +802,7 @@ mod tests {
         let text = "Monthly rent: $2,000. RLTO Summary attached. \
                     Bed bug disclosure provided. Deposit earns interest.";
 
-        let violations = check_local_compliance(&jurisdiction, text);
+        let violations = check(&jurisdiction, text);
 
         assert!(
             !violations
@@ -421,7 +817,7 @@ mod tests {
         let jurisdiction = Jurisdiction::new(State::IL);
         let text = "Monthly rent: $2,000. Security deposit: $2,000.";
 
-        let violations = check_local_compliance(&jurisdiction, text);
+        let violations = check(&jurisdiction, text);
 
         assert!(
             violations.is_empty(),
@@ -438,7 +834,7 @@ mod tests {
         let jurisdiction = Jurisdiction::with_locality(State::NY, Locality::NewYorkCity);
         let text = "Monthly rent: $3,000. Security deposit: $3,500.";
 
-        let violations = check_local_compliance(&jurisdiction, text);
+        let violations = check(&jurisdiction, text);
 
         assert!(
             violations
@@ -454,7 +850,7 @@ mod tests {
         let text = "Monthly rent: $3,000. Security deposit: $3,000. \
                     Good Cause eviction notice attached. DHCR rider included.";
 
-        let violations = check_local_compliance(&jurisdiction, text);
+        let violations = check(&jurisdiction, text);
 
         assert!(
             !violations
@@ -473,7 +869,7 @@ mod tests {
         let jurisdiction = Jurisdiction::with_locality(State::CA, Locality::SanFrancisco);
         let text = "Monthly rent: $4,000.";
 
-        let violations = check_local_compliance(&jurisdiction, text);
+        let violations = check(&jurisdiction, text);
 
         assert!(
             violations
@@ -482,4 +878,97 @@ mod tests {
             "Should warn about SF Rent Ordinance"
         );
     }
+
+    // ========================================================================
+    // County Layer Tests
+    // ========================================================================
+
+    #[test]
+    fn test_miami_dade_rent_increase_notice() {
+        let jurisdiction = Jurisdiction::with_county(State::FL, County::MiamiDade);
+        let text = "Landlord will give 30 days written notice of any rent increase. \
+                    Tenant's Bill of Rights attached.";
+
+        let violations = check(&jurisdiction, text);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("60 days"));
+        assert_eq!(violations[0].severity, Severity::Critical);
+    }
+
+    #[test]
+    fn test_montgomery_county_via_zip() {
+        let jurisdiction = Jurisdiction::from_zip(State::MD, "20910");
+        let text = "Landlord offered a two-year term. Landlord-Tenant Handbook provided. \
+                    Rent will increase by 8% each year.";
+
+        let violations = check(&jurisdiction, text);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].statute.contains("Bill 15-23"));
+
+        // Prince George's County across the line has no county ordinance here
+        let jurisdiction = Jurisdiction::from_zip(State::MD, "20783");
+        assert!(check(&jurisdiction, text).is_empty());
+    }
+
+    #[test]
+    fn test_county_and_city_layers_stack() {
+        let jurisdiction = Jurisdiction {
+            state: State::FL,
+            county: Some(County::MiamiDade),
+            locality: Some(Locality::Custom("MIAMI_BEACH".to_string())),
+        };
+        let violations = check(&jurisdiction, "Monthly rent: $2,000.");
+        assert!(violations
+            .iter()
+            .any(|v| v.statute == "Miami-Dade Ord. No. 22-56"));
+    }
+
+    // ========================================================================
+    // New Locality Tests
+    // ========================================================================
+
+    #[test]
+    fn test_seattle_move_in_cost_cap() {
+        let jurisdiction = Jurisdiction::from_zip(State::WA, "98103");
+        let text = "Monthly rent: $2,000. Security deposit: $2,500. \
+                    Renter handbook provided.";
+
+        let violations = check(&jurisdiction, text);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].statute, "Seattle Mun. Code § 7.24.035");
+
+        // Tukwila, one ZIP over, is not Seattle
+        let jurisdiction = Jurisdiction::from_zip(State::WA, "98168");
+        assert!(check(&jurisdiction, text).is_empty());
+    }
+
+    #[test]
+    fn test_portland_rent_increase_notice() {
+        let jurisdiction = Jurisdiction::with_locality(State::OR, Locality::Portland);
+        let text = "Rent increases require 60 days written notice. \
+                    Relocation assistance is paid as required by city code.";
+
+        let violations = check(&jurisdiction, text);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Found: 60 days"));
+
+        let text = "Rent increases require 90 days written notice. \
+                    Relocation assistance is paid as required by city code.";
+        assert!(check(&jurisdiction, text).is_empty());
+    }
+
+    #[test]
+    fn test_denver_needs_rental_license_not_any_license() {
+        let jurisdiction = Jurisdiction::from_zip(State::CO, "80205");
+        let text = "Tenant shall provide a copy of a valid driver's license.";
+        let violations = check(&jurisdiction, text);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].statute, "D.R.M.C. § 27-201");
+
+        let text = "Tenant shall provide a copy of a valid driver's license. \
+                    Denver Residential Rental License No. 2025-RL-0042.";
+        assert!(check(&jurisdiction, text).is_empty());
+    }
 }
@@ -5,10 +5,13 @@
 //! - State Layer: State-specific statutory requirements
 //! - Local Layer: Municipal ordinances (Chicago RLTO, NYC rent control, etc.)
 //!
+//! Commercial leases are detected and checked on their own track (see
+//! [`states::commercial`]); residential layers never run on them.
+//!
 //! # Example
 //!
 //! ```rust
-//! use compliance_engine::{ComplianceEngine, Jurisdiction, State};
+//! use compliance_engine::{ComplianceEngine, Jurisdiction, NaiveDate, State};
 //! use shared_types::LeaseDocument;
 //!
 //! let engine = ComplianceEngine::new();
@@ -20,26 +23,58 @@
 //!     pages: 1,
 //!     text_content: vec!["Tenant waives all rights.".to_string()],
 //!     created_at: 0,
+//!     layout: Vec::new(),
 //! };
 //!
-//! let report = engine.check_compliance(&jurisdiction, &document, None);
+//! let as_of = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+//! let report = engine.check_compliance(&jurisdiction, &document, None, as_of);
 //! assert!(!report.violations.is_empty());
 //! ```
+//!
+//! Rules carry effective dates (see [`effective`]); checking "as of" the date
+//! a lease was signed skips rules that were not yet in force.
+//! Violations carry a suggested fix where a model clause exists; see
+//! [`remediation`]. Spanish leases are checked with the same rules and get
+//! their messages in Spanish; see [`language`]. A lease and its addenda and
+//! disclosure forms can be checked as one packet; see [`packet`].
 
 pub mod calendar;
+pub mod clauses;
+pub mod effective;
 pub mod extractors;
 pub mod jurisdiction;
+pub mod language;
 pub mod layers;
+pub mod locality_zips;
+pub mod locate;
+pub mod packet;
 pub mod patterns;
+pub mod remediation;
+pub mod report;
+pub mod rule_pack;
 pub mod rules;
 pub mod states;
 
-pub use jurisdiction::{Jurisdiction, Locality, State, Tier};
+pub use calendar::{BusinessCalendar, DayCount, NoticeDeadline, StateCalendar};
+pub use chrono::NaiveDate;
+pub use effective::{CoveredStatute, EffectiveRange};
+pub use extractors::terms::{Extracted, LateFee, LeaseTerms};
+pub use jurisdiction::{County, Jurisdiction, Locality, State, Tier};
+pub use language::Language;
+pub use locate::PageMap;
+pub use packet::{DocumentPacket, PacketDocumentType, PacketReport};
+pub use remediation::{
+    apply_page_remediations, apply_remediations, patch_typst_template, RemediationError, Suggestion,
+};
+pub use rule_pack::{RulePack, RulePackError};
+pub use states::commercial::CommercialDocumentType;
+pub use states::florida_commercial::covered_commercial_statutes;
 pub use states::florida_realestate::{
     check_florida_realestate_compliance, covered_realestate_statutes, RealEstateDocumentType,
 };
 
-use shared_types::{ComplianceReport, LeaseDocument, Violation};
+use shared_types::{ComplianceReport, Coverage, LeaseDocument, LocalCoverage, Track, Violation};
+use std::collections::HashMap;
 
 /// Document type for compliance checking
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -53,17 +88,102 @@ pub enum DocumentType {
     EscalationAddendum,
     /// Listing agreement
     ListingAgreement,
+    /// Commercial (non-residential) lease or its guaranty
+    CommercialLease,
+}
+
+impl DocumentType {
+    /// Lease or commercial lease, as detected in a lease's text
+    fn of_lease(commercial: CommercialDocumentType) -> Self {
+        match commercial {
+            CommercialDocumentType::Unknown => DocumentType::Lease,
+            _ => DocumentType::CommercialLease,
+        }
+    }
+
+    /// Whether checks of this type in `jurisdiction` cover its ordinances;
+    /// only residential leases go through the local layer
+    fn local_coverage(self, jurisdiction: &Jurisdiction) -> LocalCoverage {
+        match self {
+            DocumentType::Lease => jurisdiction.local_coverage(),
+            _ => LocalCoverage::NotApplicable,
+        }
+    }
+
+    /// Body of law documents of this type are checked under
+    pub fn track(self) -> Track {
+        match self {
+            DocumentType::Lease => Track::Residential,
+            DocumentType::CommercialLease => Track::Commercial,
+            DocumentType::RealEstatePurchase
+            | DocumentType::EscalationAddendum
+            | DocumentType::ListingAgreement => Track::RealEstate,
+        }
+    }
+}
+
+/// Source of "today" for checks that are not given a date
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// The system's current UTC date
+    #[default]
+    System,
+    /// A fixed date, for reproducible checks and tests
+    Fixed(NaiveDate),
+}
+
+impl Clock {
+    pub fn today(&self) -> NaiveDate {
+        match self {
+            Clock::System => chrono::Utc::now().date_naive(),
+            Clock::Fixed(date) => *date,
+        }
+    }
 }
 
 /// Multi-jurisdiction compliance engine
 ///
 /// Checks documents against federal, state, and local compliance rules
 /// following the Layer Cake architecture.
-pub struct ComplianceEngine;
+#[derive(Debug, Clone)]
+pub struct ComplianceEngine {
+    /// Declarative rules that replace the built-in state layer
+    rule_packs: HashMap<State, RulePack>,
+    /// Date used by [`Self::check_text_with_jurisdiction`]
+    clock: Clock,
+}
 
 impl ComplianceEngine {
     pub fn new() -> Self {
-        Self
+        Self {
+            rule_packs: HashMap::new(),
+            clock: Clock::System,
+        }
+    }
+
+    /// Take "today" from `clock` instead of the system date
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Use a rule pack for its state instead of the built-in Rust rules.
+    /// A later pack for the same state replaces an earlier one.
+    pub fn with_rule_pack(mut self, pack: RulePack) -> Self {
+        self.rule_packs.insert(pack.state(), pack);
+        self
+    }
+
+    /// Use the rule packs bundled with the crate (see [`rule_pack::builtin_packs`])
+    pub fn with_builtin_rule_packs(self) -> Self {
+        rule_pack::builtin_packs()
+            .into_iter()
+            .fold(self, |engine, pack| engine.with_rule_pack(pack))
+    }
+
+    /// Rule pack in effect for a state, if any
+    pub fn rule_pack(&self, state: State) -> Option<&RulePack> {
+        self.rule_packs.get(&state)
     }
 
     /// Check compliance for a specific jurisdiction
@@ -72,42 +192,131 @@ impl ComplianceEngine {
     /// * `jurisdiction` - The state and optional locality
     /// * `document` - The lease document to check
     /// * `year_built` - Optional year the property was built (for lead paint)
+    /// * `as_of` - Date to check against; rules not in force on it are skipped
     ///
     /// # Returns
-    /// ComplianceReport with all violations found
+    /// ComplianceReport with all violations found. Violation positions are
+    /// page-local (see [`locate::PageMap::localize`]), and so are remediation
+    /// spans; apply them with [`apply_page_remediations`]. A commercial lease
+    /// is checked on the commercial track and its coverage reported as such.
     pub fn check_compliance(
         &self,
         jurisdiction: &Jurisdiction,
         document: &LeaseDocument,
         year_built: Option<u32>,
+        as_of: NaiveDate,
     ) -> ComplianceReport {
-        let full_text = document.text_content.join("\n");
-        let violations = self.check_text_with_jurisdiction(jurisdiction, &full_text, year_built);
+        let pages = PageMap::new(&document.text_content);
+        let commercial = CommercialDocumentType::detect(pages.text());
+        let mut violations =
+            self.check_lease_text(jurisdiction, pages.text(), commercial, year_built, as_of);
+        pages.localize(&mut violations, &document.layout);
+        let doc_type = DocumentType::of_lease(commercial);
 
         ComplianceReport {
             document_id: document.id.clone(),
             violations,
             checked_at: chrono::Utc::now().timestamp() as u64,
+            coverage: self.coverage(jurisdiction.state, doc_type),
+            track: doc_type.track(),
+            local_coverage: doc_type.local_coverage(jurisdiction),
         }
     }
 
-    /// Check compliance on raw text for a specific jurisdiction
+    /// Check a lease together with its addenda and disclosures
+    ///
+    /// The packet is checked as one document, so a disclosure the lease
+    /// leaves to a separate form is not reported missing. The report gives
+    /// each document's type and violations and, for every requirement the
+    /// lease alone would miss, the documents that satisfy it. See [`packet`].
+    pub fn check_packet(
+        &self,
+        jurisdiction: &Jurisdiction,
+        packet: &DocumentPacket,
+        year_built: Option<u32>,
+        as_of: NaiveDate,
+    ) -> PacketReport {
+        packet::check_packet(self, jurisdiction, packet, year_built, as_of)
+    }
+
+    /// Check compliance on raw text for a specific jurisdiction, as of the
+    /// engine's [`Clock`]; use [`Self::check_text_as_of`] to pass a date
     pub fn check_text_with_jurisdiction(
         &self,
         jurisdiction: &Jurisdiction,
         text: &str,
         year_built: Option<u32>,
     ) -> Vec<Violation> {
+        let today = self.clock.today();
+        self.check_text_as_of(jurisdiction, text, year_built, today)
+    }
+
+    /// Check compliance on raw text against the rules in force on `as_of`
+    ///
+    /// A commercial lease or guaranty is checked on the commercial track;
+    /// [`Self::detect_document_type`] and [`DocumentType::track`] tell which
+    /// track the text takes.
+    pub fn check_text_as_of(
+        &self,
+        jurisdiction: &Jurisdiction,
+        text: &str,
+        year_built: Option<u32>,
+        as_of: NaiveDate,
+    ) -> Vec<Violation> {
+        let commercial = CommercialDocumentType::detect(text);
+        self.check_lease_text(jurisdiction, text, commercial, year_built, as_of)
+    }
+
+    /// Check lease text on the track `commercial` selects, whatever the text
+    /// itself would be detected as
+    pub(crate) fn check_lease_text(
+        &self,
+        jurisdiction: &Jurisdiction,
+        text: &str,
+        commercial: CommercialDocumentType,
+        year_built: Option<u32>,
+        as_of: NaiveDate,
+    ) -> Vec<Violation> {
+        // Residential deposit, notice and habitability rules do not apply
+        if commercial != CommercialDocumentType::Unknown {
+            return self.check_commercial_as_of(jurisdiction, text, commercial, as_of);
+        }
+
         let mut violations = Vec::new();
 
+        // Terms are extracted once and shared by every layer
+        let terms = LeaseTerms::extract(text);
+
         // Layer 1: Federal (applies to all)
         violations.extend(layers::check_federal_compliance(text, year_built));
 
-        // Layer 2: State-specific
-        violations.extend(states::check_state_compliance(jurisdiction.state, text));
+        // Layer 2: State-specific (rule pack if loaded, otherwise built-in rules)
+        match self.rule_packs.get(&jurisdiction.state) {
+            Some(pack) => {
+                violations = effective::in_force(violations, as_of);
+                violations.extend(pack.check_terms_as_of(text, &terms, as_of));
+            }
+            None => {
+                violations.extend(states::check_state_compliance(
+                    jurisdiction.state,
+                    text,
+                    &terms,
+                ));
+                violations = effective::in_force(violations, as_of);
+            }
+        }
 
         // Layer 3: Local overrides (municipality-specific ordinances)
-        violations.extend(layers::check_local_compliance(jurisdiction, text));
+        violations.extend(effective::in_force(
+            layers::check_local_compliance(jurisdiction, text, &terms),
+            as_of,
+        ));
+
+        // Suggested fixes, where a model clause exists
+        remediation::attach(&mut violations, text);
+
+        // Messages in the lease's own language
+        language::localize(&mut violations, Language::detect(text));
 
         violations
     }
@@ -124,19 +333,112 @@ impl ComplianceEngine {
         violations
     }
 
-    /// Get list of supported states
+    /// Get list of supported states, including states covered by rule packs
     pub fn supported_states(&self) -> Vec<State> {
-        State::implemented_states()
+        let mut states = State::implemented_states();
+        for state in self.rule_packs.keys() {
+            if !states.contains(state) {
+                states.push(*state);
+            }
+        }
+        states
     }
 
     /// Check if a state is fully supported
     pub fn is_state_supported(&self, state: State) -> bool {
-        states::has_implementation(state)
+        states::has_implementation(state) || self.rule_packs.contains_key(&state)
+    }
+
+    /// Get covered statutes for a state, each with the dates it is in force
+    pub fn covered_statutes(&self, state: State) -> Vec<CoveredStatute> {
+        if let Some(pack) = self.rule_packs.get(&state) {
+            return pack.covered_statutes();
+        }
+        match states::profiles::profile(state) {
+            Some(profile) => states::profile::covered_statutes(profile)
+                .iter()
+                .map(|entry| effective::covered(entry))
+                .collect(),
+            None => states::covered_statutes(state)
+                .into_iter()
+                .map(effective::covered)
+                .collect(),
+        }
+    }
+
+    /// How much of a state's law is checked for a document type
+    ///
+    /// Callers must not read an empty violation list as "compliant" when this
+    /// is [`Coverage::Unavailable`].
+    pub fn coverage(&self, state: State, doc_type: DocumentType) -> Coverage {
+        match doc_type {
+            DocumentType::Lease if self.rule_packs.contains_key(&state) => Coverage::Full,
+            DocumentType::Lease => states::coverage(state),
+            DocumentType::CommercialLease if states::commercial::has_commercial_rules(state) => {
+                Coverage::Full
+            }
+            DocumentType::CommercialLease => Coverage::Unavailable {
+                reason: format!(
+                    "No commercial lease statutes for {}; only general commercial terms were checked",
+                    state.name()
+                ),
+            },
+            _ if state == State::FL => Coverage::Full,
+            _ => Coverage::Unavailable {
+                reason: format!(
+                    "No real estate transaction rules for {}; only federal rules were checked",
+                    state.name()
+                ),
+            },
+        }
+    }
+
+    // ========================================================================
+    // Commercial Lease Compliance
+    // ========================================================================
+
+    /// Check a commercial lease or guaranty against the rules in force on `as_of`
+    ///
+    /// No federal or residential layer runs: lead paint disclosure, fair
+    /// housing and residential deposit and notice rules cover dwellings only.
+    pub fn check_commercial_compliance(
+        &self,
+        jurisdiction: &Jurisdiction,
+        document: &LeaseDocument,
+        as_of: NaiveDate,
+    ) -> ComplianceReport {
+        let pages = PageMap::new(&document.text_content);
+        let commercial = match CommercialDocumentType::detect(pages.text()) {
+            CommercialDocumentType::Unknown => CommercialDocumentType::Lease,
+            detected => detected,
+        };
+        let mut violations =
+            self.check_commercial_as_of(jurisdiction, pages.text(), commercial, as_of);
+        pages.localize(&mut violations, &document.layout);
+
+        ComplianceReport {
+            document_id: document.id.clone(),
+            violations,
+            checked_at: chrono::Utc::now().timestamp() as u64,
+            coverage: self.coverage(jurisdiction.state, DocumentType::CommercialLease),
+            track: Track::Commercial,
+            local_coverage: LocalCoverage::NotApplicable,
+        }
     }
 
-    /// Get covered statutes for a state
-    pub fn covered_statutes(&self, state: State) -> Vec<&'static str> {
-        states::covered_statutes(state)
+    fn check_commercial_as_of(
+        &self,
+        jurisdiction: &Jurisdiction,
+        text: &str,
+        doc_type: CommercialDocumentType,
+        as_of: NaiveDate,
+    ) -> Vec<Violation> {
+        let mut violations = effective::in_force(
+            states::commercial::check_commercial_compliance(jurisdiction.state, text, doc_type),
+            as_of,
+        );
+        remediation::attach(&mut violations, text);
+        violations
     }
 
     // ========================================================================
@@ -150,6 +452,7 @@ impl ComplianceEngine {
     /// * `document` - The document to check
     /// * `doc_type` - Type of real estate document
     /// * `year_built` - Optional year the property was built (for lead paint)
+    /// * `as_of` - Date to check against; rules not in force on it are skipped
     ///
     /// # Returns
     /// ComplianceReport with all violations found
@@ -159,19 +462,31 @@ impl ComplianceEngine {
         document: &LeaseDocument,
         doc_type: DocumentType,
         year_built: Option<u32>,
+        as_of: NaiveDate,
     ) -> ComplianceReport {
-        let full_text = document.text_content.join("\n");
-        let violations = self.check_realestate_text_with_jurisdiction(
-            jurisdiction,
-            &full_text,
-            doc_type,
-            year_built,
+        if doc_type == DocumentType::CommercialLease {
+            return self.check_commercial_compliance(jurisdiction, document, as_of);
+        }
+
+        let pages = PageMap::new(&document.text_content);
+        let mut violations = effective::in_force(
+            self.check_realestate_text_with_jurisdiction(
+                jurisdiction,
+                pages.text(),
+                doc_type,
+                year_built,
+            ),
+            as_of,
         );
+        pages.localize(&mut violations, &document.layout);
 
         ComplianceReport {
             document_id: document.id.clone(),
             violations,
             checked_at: chrono::Utc::now().timestamp() as u64,
+            coverage: self.coverage(jurisdiction.state, doc_type),
+            track: doc_type.track(),
+            local_coverage: LocalCoverage::NotApplicable,
         }
     }
 
@@ -183,6 +498,18 @@ impl ComplianceEngine {
         doc_type: DocumentType,
         year_built: Option<u32>,
     ) -> Vec<Violation> {
+        if doc_type == DocumentType::CommercialLease {
+            let commercial = match CommercialDocumentType::detect(text) {
+                CommercialDocumentType::Unknown => CommercialDocumentType::Lease,
+                detected => detected,
+            };
+            return states::commercial::check_commercial_compliance(
+                jurisdiction.state,
+                text,
+                commercial,
+            );
+        }
+
         let mut violations = Vec::new();
 
         // Layer 1: Federal (lead paint for pre-1978)
@@ -194,14 +521,16 @@ impl ComplianceEngine {
                 DocumentType::RealEstatePurchase => RealEstateDocumentType::PurchaseContract,
                 DocumentType::EscalationAddendum => RealEstateDocumentType::EscalationAddendum,
                 DocumentType::ListingAgreement => RealEstateDocumentType::ListingAgreement,
-                DocumentType::Lease => RealEstateDocumentType::Unknown,
+                DocumentType::Lease | DocumentType::CommercialLease => {
+                    RealEstateDocumentType::Unknown
+                }
             };
             violations.extend(states::florida_realestate::check_document_type(
                 text,
                 re_doc_type,
             ));
         }
-        // Other states can be added here as they get real estate implementations
+        // Other states report Coverage::Unavailable until they get real estate rules
 
         violations
     }
@@ -212,13 +541,23 @@ impl ComplianceEngine {
         jurisdiction: &Jurisdiction,
         document: &LeaseDocument,
         year_built: Option<u32>,
+        as_of: NaiveDate,
     ) -> ComplianceReport {
         let full_text = document.text_content.join("\n");
         let doc_type = self.detect_document_type(&full_text);
 
         match doc_type {
-            DocumentType::Lease => self.check_compliance(jurisdiction, document, year_built),
-            _ => self.check_realestate_compliance(jurisdiction, document, doc_type, year_built),
+            DocumentType::Lease => self.check_compliance(jurisdiction, document, year_built, as_of),
+            DocumentType::CommercialLease => {
+                self.check_commercial_compliance(jurisdiction, document, as_of)
+            }
+            _ => self.check_realestate_compliance(
+                jurisdiction,
+                document,
+                doc_type,
+                year_built,
+                as_of,
+            ),
         }
     }
 
@@ -242,6 +581,11 @@ impl ComplianceEngine {
             return DocumentType::ListingAgreement;
         }
 
+        // Check for commercial lease or guaranty
+        if CommercialDocumentType::detect(text) != CommercialDocumentType::Unknown {
+            return DocumentType::CommercialLease;
+        }
+
         // Check for purchase contract
         if (text_lower.contains("purchase") && text_lower.contains("contract"))
             || (text_lower.contains("sale") && text_lower.contains("agreement"))
@@ -329,7 +673,8 @@ mod tests {
                     Security deposit held at First National Bank, Miami, Florida. \
                     Landlord returns deposit within 15 days if no claim. \
                     The prevailing party shall be entitled to reasonable attorney fees. \
-                    Tenant shall receive 3 business days notice for nonpayment of rent.";
+                    Tenant shall receive 3 business days notice for nonpayment of rent. \
+                    RADON GAS: Radon is a naturally occurring radioactive gas.";
 
         let violations = engine.check_text_with_jurisdiction(&jurisdiction, text, Some(1995));
 
@@ -383,7 +728,203 @@ mod tests {
         assert!(states.contains(&State::AZ));
         assert!(states.contains(&State::NC));
         assert!(states.contains(&State::TN));
-        assert_eq!(states.len(), 16);
+        // Tier 3 and the long tail, through statutory profiles
+        assert!(states.contains(&State::AK));
+        assert!(states.contains(&State::WY));
+        assert!(states.contains(&State::DC));
+        assert_eq!(states.len(), 51);
+    }
+
+    #[test]
+    fn test_profile_state_reports_core_coverage() {
+        let engine = ComplianceEngine::new();
+        let document = LeaseDocument {
+            id: "nv".to_string(),
+            filename: "lease.pdf".to_string(),
+            pages: 1,
+            text_content: vec!["Monthly rent: $1,000. Security deposit: $4,000. \
+                 Landlord may enter the premises at any time."
+                .to_string()],
+            created_at: 0,
+            layout: Vec::new(),
+        };
+        let as_of = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let report =
+            engine.check_compliance(&Jurisdiction::new(State::NV), &document, Some(2000), as_of);
+
+        assert_eq!(report.coverage, Coverage::Core);
+        assert!(report
+            .violations
+            .iter()
+            .any(|v| v.statute == "NRS 118A.242(1)"));
+        assert!(report
+            .violations
+            .iter()
+            .any(|v| v.statute == "NRS 118A.330(1)"));
+        assert!(!engine.covered_statutes(State::NV).is_empty());
+    }
+
+    #[test]
+    fn test_commercial_lease_skips_residential_rules() {
+        let engine = ComplianceEngine::new();
+        let text = "COMMERCIAL LEASE AGREEMENT governed by Chapter 83, Part I (Non-Residential \
+                    Tenancies). Security deposit: $40,000. Landlord may change the locks \
+                    without resort to legal process. Tenant shall pay Florida sales tax on rent.";
+        let document = LeaseDocument {
+            id: "commercial".to_string(),
+            filename: "lease.pdf".to_string(),
+            pages: 1,
+            text_content: vec![text.to_string()],
+            created_at: 0,
+            layout: Vec::new(),
+        };
+        let as_of = NaiveDate::from_ymd_opt(2025, 11, 1).unwrap();
+
+        assert_eq!(
+            engine.detect_document_type(text),
+            DocumentType::CommercialLease
+        );
+        let report =
+            engine.check_auto_detect(&Jurisdiction::new(State::FL), &document, None, as_of);
+        let statutes: Vec<&str> = report
+            .violations
+            .iter()
+            .map(|v| v.statute.as_str())
+            .collect();
+        assert!(statutes.contains(&"F.S. § 83.05(2)"));
+        assert!(statutes.contains(&"F.S. § 212.031 (repeal)"));
+        assert!(!statutes.iter().any(|s| s.starts_with("F.S. § 83.49")));
+        assert!(!statutes.iter().any(|s| s.contains("4852d")));
+        assert_eq!(report.coverage, Coverage::Full);
+
+        // The residential entry point takes the commercial track too
+        let report =
+            engine.check_compliance(&Jurisdiction::new(State::TX), &document, Some(1960), as_of);
+        assert!(!report
+            .violations
+            .iter()
+            .any(|v| v.statute.contains("4852d")));
+        assert!(matches!(report.coverage, Coverage::Unavailable { .. }));
+        assert_eq!(report.track, Track::Commercial);
+    }
+
+    #[test]
+    fn test_residential_lease_forbidding_commercial_use_stays_residential() {
+        let engine = ComplianceEngine::new();
+        let text = "RESIDENTIAL LEASE. Tenant waives all rights to notice. \
+                    Security deposit: $5,000. Monthly rent: $1,000. \
+                    The Premises shall not be used for any non-residential purpose.";
+        let document = LeaseDocument {
+            id: "probe".to_string(),
+            filename: "lease.pdf".to_string(),
+            pages: 1,
+            text_content: vec![text.to_string()],
+            created_at: 0,
+            layout: Vec::new(),
+        };
+        let as_of = NaiveDate::from_ymd_opt(2025, 11, 1).unwrap();
+
+        assert_eq!(engine.detect_document_type(text), DocumentType::Lease);
+        let report =
+            engine.check_compliance(&Jurisdiction::new(State::FL), &document, Some(1960), as_of);
+        assert_eq!(report.track, Track::Residential);
+        let count = |statute: &str| {
+            report
+                .violations
+                .iter()
+                .filter(|v| v.statute == statute)
+                .count()
+        };
+        assert_eq!(count("42 U.S.C. § 4852d"), 1);
+        assert_eq!(count("F.S. § 83.47(1)(a)"), 2);
+        assert_eq!(count("F.S. § 83.49(2)"), 1);
+    }
+
+    #[test]
+    fn test_violations_located_on_their_page() {
+        use shared_types::{BoundingBox, PageLayout, PositionedRun};
+
+        let engine = ComplianceEngine::new();
+        let second = "The tenant waives all rights to notice before eviction.";
+        let waive = second.find("waive").unwrap();
+        let document = LeaseDocument {
+            id: "fl".to_string(),
+            filename: "lease.pdf".to_string(),
+            pages: 2,
+            text_content: vec![
+                "RESIDENTIAL LEASE between Landlord and Tenant.".to_string(),
+                second.to_string(),
+            ],
+            created_at: 0,
+            layout: vec![
+                PageLayout::default(),
+                PageLayout {
+                    runs: vec![PositionedRun {
+                        start_offset: 0,
+                        end_offset: second.len(),
+                        bbox: BoundingBox {
+                            x: 72.0,
+                            y: 700.0,
+                            width: second.len() as f64 * 5.0,
+                            height: 12.0,
+                        },
+                    }],
+                },
+            ],
+        };
+        let as_of = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let report = engine.check_compliance(&Jurisdiction::new(State::FL), &document, None, as_of);
+
+        let waiver = report
+            .violations
+            .iter()
+            .find(|v| v.statute == "F.S. § 83.47(1)(a)")
+            .unwrap();
+        assert_eq!(waiver.page, Some(2));
+        let position = waiver.text_position.as_ref().unwrap();
+        assert!(position.start_offset <= waive && waive < position.end_offset);
+        assert!(position.end_offset <= second.len());
+        assert_eq!(waiver.bounding_boxes.len(), 1);
+        assert_eq!(
+            waiver.bounding_boxes[0].x,
+            72.0 + position.start_offset as f64 * 5.0
+        );
+
+        // The remediation span is on the same page, in the same coordinates
+        let remediation = waiver.remediation.as_ref().unwrap();
+        assert_eq!(
+            remediation.replace,
+            Some(shared_types::TextPosition::new(0, second.len()))
+        );
+        let fixed = apply_page_remediations(
+            &document.text_content,
+            std::slice::from_ref(waiver),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(fixed[0], document.text_content[0]);
+        assert_eq!(fixed[1], remediation.clause);
+    }
+
+    #[test]
+    fn test_coverage_by_state_and_document_type() {
+        let engine = ComplianceEngine::new();
+        assert_eq!(
+            engine.coverage(State::FL, DocumentType::Lease),
+            Coverage::Full
+        );
+        assert_eq!(
+            engine.coverage(State::AK, DocumentType::Lease),
+            Coverage::Core
+        );
+        assert_eq!(
+            engine.coverage(State::FL, DocumentType::RealEstatePurchase),
+            Coverage::Full
+        );
+        assert!(matches!(
+            engine.coverage(State::TX, DocumentType::ListingAgreement),
+            Coverage::Unavailable { .. }
+        ));
     }
 
     #[test]
@@ -392,11 +933,116 @@ mod tests {
 
         let fl_statutes = engine.covered_statutes(State::FL);
         assert!(!fl_statutes.is_empty());
-        assert!(fl_statutes.iter().any(|s| s.contains("83.47")));
+        assert!(fl_statutes.iter().any(|s| s.citation.contains("83.47")));
 
         let tx_statutes = engine.covered_statutes(State::TX);
         assert!(!tx_statutes.is_empty());
-        assert!(tx_statutes.iter().any(|s| s.contains("92.")));
+        assert!(tx_statutes.iter().any(|s| s.citation.contains("92.")));
+
+        let screening = tx_statutes
+            .iter()
+            .find(|s| s.citation.contains("92.3515"))
+            .unwrap();
+        assert_eq!(
+            screening.range.effective_from,
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+        );
+
+        // Rule packs report the dates declared on their rules
+        let packs = ComplianceEngine::new().with_builtin_rule_packs();
+        let flood = packs
+            .covered_statutes(State::FL)
+            .into_iter()
+            .find(|s| s.citation.contains("83.512"))
+            .unwrap();
+        assert_eq!(
+            flood.range.effective_from,
+            NaiveDate::from_ymd_opt(2025, 10, 1)
+        );
+    }
+
+    #[test]
+    fn test_as_of_skips_rules_not_yet_in_force() {
+        let text = "Application fee of $50 is due with the rental application.";
+        let jurisdiction = Jurisdiction::new(State::TX);
+        let before = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+        let after = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+        for engine in [
+            ComplianceEngine::new(),
+            ComplianceEngine::new().with_builtin_rule_packs(),
+        ] {
+            let old = engine.check_text_as_of(&jurisdiction, text, None, before);
+            assert!(!old.iter().any(|v| v.statute.contains("92.3515")));
+
+            let new = engine.check_text_as_of(&jurisdiction, text, None, after);
+            assert!(new.iter().any(|v| v.statute.contains("92.3515")));
+        }
+    }
+
+    #[test]
+    fn test_clock_sets_default_check_date() {
+        let text = "Application fee of $50 is due with the rental application.";
+        let jurisdiction = Jurisdiction::new(State::TX);
+        let on = |y, m, d| {
+            ComplianceEngine::new()
+                .with_clock(Clock::Fixed(NaiveDate::from_ymd_opt(y, m, d).unwrap()))
+                .check_text_with_jurisdiction(&jurisdiction, text, None)
+        };
+
+        assert!(!on(2024, 12, 31)
+            .iter()
+            .any(|v| v.statute.contains("92.3515")));
+        assert!(on(2025, 1, 1).iter().any(|v| v.statute.contains("92.3515")));
+    }
+
+    #[test]
+    fn test_violations_carry_remediations() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::new(State::FL);
+        let text = "Rent is due on the 1st. Tenant waives all rights under Florida law.";
+
+        let violations = engine.check_text_with_jurisdiction(&jurisdiction, text, None);
+        let waiver = violations
+            .iter()
+            .find(|v| v.statute == "F.S. § 83.47(1)(a)")
+            .unwrap();
+        let remediation = waiver.remediation.as_ref().unwrap();
+        assert!(remediation.clause.starts_with("Tenant retains all rights"));
+        assert_eq!(
+            remediation.replace,
+            Some(shared_types::TextPosition::new(24, text.len()))
+        );
+
+        let fixed =
+            apply_remediations(text, std::slice::from_ref(waiver), &Default::default()).unwrap();
+        assert_eq!(
+            fixed,
+            format!("Rent is due on the 1st. {}", remediation.clause)
+        );
+    }
+
+    #[test]
+    fn test_florida_flood_disclosure_dated() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::new(State::FL);
+        let text = "Residential lease. Monthly rent $1,500.";
+
+        let in_2024 = engine.check_text_as_of(
+            &jurisdiction,
+            text,
+            None,
+            NaiveDate::from_ymd_opt(2024, 12, 1).unwrap(),
+        );
+        assert!(!in_2024.iter().any(|v| v.statute.contains("83.512")));
+
+        let in_2026 = engine.check_text_as_of(
+            &jurisdiction,
+            text,
+            None,
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        );
+        assert!(in_2026.iter().any(|v| v.statute.contains("83.512")));
     }
 
     #[test]
@@ -409,6 +1055,55 @@ mod tests {
         assert!(violations.iter().any(|v| v.statute.contains("83.47")));
     }
 
+    #[test]
+    fn test_rule_pack_replaces_state_layer() {
+        let pack = RulePack::from_toml(
+            r#"
+[pack]
+id = "test-ok"
+state = "OK"
+version = "1"
+
+[[rules]]
+id = "pet-waiver"
+statute = "Okla. Stat. tit. 41 § 113"
+severity = "Critical"
+message = "Tenant cannot waive statutory remedies"
+when = { all = ["waive", "remedies"] }
+"#,
+        )
+        .unwrap();
+        let engine = ComplianceEngine::new().with_rule_pack(pack);
+        assert!(engine.is_state_supported(State::OK));
+        assert!(engine.supported_states().contains(&State::OK));
+
+        let jurisdiction = Jurisdiction::new(State::OK);
+        let violations = engine.check_text_with_jurisdiction(
+            &jurisdiction,
+            "Tenant waives all remedies.",
+            Some(2000),
+        );
+        assert!(violations.iter().any(|v| v.statute.contains("41 § 113")));
+    }
+
+    #[test]
+    fn test_builtin_rule_packs_match_default_engine() {
+        let rust = ComplianceEngine::new();
+        let packs = ComplianceEngine::new().with_builtin_rule_packs();
+        assert!(packs.rule_pack(State::FL).is_some());
+
+        let text = "Tenant waives notice. Deposit returned in 45 days. Vehicles will be towed.";
+        for state in [State::FL, State::TX] {
+            let jurisdiction = Jurisdiction::new(state);
+            let expected = rust.check_text_with_jurisdiction(&jurisdiction, text, None);
+            let actual = packs.check_text_with_jurisdiction(&jurisdiction, text, None);
+            assert_eq!(
+                serde_json::to_value(&actual).unwrap(),
+                serde_json::to_value(&expected).unwrap()
+            );
+        }
+    }
+
     #[test]
     fn test_jurisdiction_from_zip() {
         // Chicago zip
@@ -424,12 +1119,68 @@ mod tests {
         assert_eq!(tx.locality, None);
     }
 
+    #[test]
+    fn test_unknown_locality_is_not_no_ordinance() {
+        let coverage = |state, zip| Jurisdiction::from_zip(state, zip).local_coverage();
+        assert_eq!(coverage(State::IL, "60601"), LocalCoverage::Checked);
+        assert_eq!(coverage(State::FL, "33130"), LocalCoverage::Checked);
+        // Floral Park is in the table, next to NYC, with no ordinance of its own
+        assert_eq!(coverage(State::NY, "11001"), LocalCoverage::NoOrdinance);
+        // Outside the table: where the property is isn't known
+        assert_eq!(coverage(State::TX, "75001"), LocalCoverage::Unknown);
+        assert_eq!(
+            Jurisdiction::new(State::TX).local_coverage(),
+            LocalCoverage::Unknown
+        );
+
+        let document = |text: &str| LeaseDocument {
+            id: "lease-1".to_string(),
+            filename: "lease.pdf".to_string(),
+            pages: 1,
+            text_content: vec![text.to_string()],
+            created_at: 0,
+            layout: Vec::new(),
+        };
+        let engine = ComplianceEngine::new();
+        let as_of = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let dallas = Jurisdiction::from_zip(State::TX, "75001");
+        let report = engine.check_compliance(&dallas, &document("Residential lease."), None, as_of);
+        assert_eq!(report.local_coverage, LocalCoverage::Unknown);
+
+        let commercial =
+            document("Commercial lease of retail premises between Landlord and Tenant.");
+        let report = engine.check_commercial_compliance(&dallas, &commercial, as_of);
+        assert_eq!(report.local_coverage, LocalCoverage::NotApplicable);
+    }
+
     // ========================================================================
     // Locality-based compliance tests
     // These tests verify that locality detection from ZIP codes affects
     // compliance checking, even when the lease text doesn't mention the city.
     // ========================================================================
 
+    #[test]
+    fn test_miami_dade_bill_of_rights_preempted() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::from_zip(State::FL, "33130");
+        assert_eq!(jurisdiction.county, Some(County::MiamiDade));
+
+        let text = "Landlord will give 30 days notice of any rent increase.";
+        let county_rule = |as_of: NaiveDate| {
+            engine
+                .check_text_as_of(&jurisdiction, text, Some(2000), as_of)
+                .into_iter()
+                .filter(|v| v.statute.starts_with("Miami-Dade"))
+                .count()
+        };
+
+        assert_eq!(
+            county_rule(NaiveDate::from_ymd_opt(2023, 1, 15).unwrap()),
+            2
+        );
+        assert_eq!(county_rule(NaiveDate::from_ymd_opt(2023, 7, 1).unwrap()), 0);
+    }
+
     #[test]
     fn test_chicago_rlto_applies_via_zip_not_text() {
         // BUG TEST: When user provides Chicago ZIP but lease text doesn't mention Chicago,
@@ -481,4 +1232,35 @@ mod tests {
             violations.iter().map(|v| &v.statute).collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_spanish_lease_gets_spanish_messages() {
+        let engine = ComplianceEngine::new();
+        let as_of = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let text = "Contrato de arrendamiento. El Inquilino renuncia a todos sus derechos según \
+                    el capítulo 83. La renta mensual es de $1,500 y el depósito de seguridad es \
+                    de $1,500.";
+
+        let violations =
+            engine.check_text_as_of(&Jurisdiction::new(State::FL), text, Some(2000), as_of);
+        let waiver = violations
+            .iter()
+            .find(|v| v.statute == "F.S. § 83.47(1)(a)")
+            .expect("waiver of rights not detected");
+        assert!(
+            waiver
+                .message
+                .starts_with("El contrato contiene una renuncia prohibida"),
+            "{}",
+            waiver.message
+        );
+
+        // English leases keep English messages
+        let text = "Tenant waives all rights under Florida landlord tenant law.";
+        let violations =
+            engine.check_text_as_of(&Jurisdiction::new(State::FL), text, Some(2000), as_of);
+        assert!(violations
+            .iter()
+            .any(|v| v.message.starts_with("Lease contains prohibited waiver")));
+    }
 }
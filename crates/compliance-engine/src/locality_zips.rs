@@ -0,0 +1,136 @@
+//! Bundled ZIP → city → county table for localities with ordinances
+//!
+//! Locality and county detection read `data/locality_zips.csv`, compiled into
+//! the crate. This is not a national ZIP database: it lists the ZIPs of the
+//! cities and counties whose ordinances the local layer checks, plus the
+//! neighbouring ZIPs most often confused with them. Any other ZIP, such as
+//! 75001, is not found, and its jurisdiction's local coverage is
+//! [`LocalCoverage::Unknown`](shared_types::LocalCoverage::Unknown) rather
+//! than "no local ordinance". A county or city added to the local layer needs
+//! its ZIPs added here.
+//!
+//! The file is versioned (see [`version`]) so reports can record which
+//! boundaries they were resolved against. Each row is one ZIP code, so a
+//! neighbouring city's ZIP is never labeled with the city next door (11001 is
+//! Floral Park in Nassau County, not New York City).
+
+use crate::jurisdiction::State;
+use lazy_static::lazy_static;
+
+const LOCALITY_ZIPS_CSV: &str = include_str!("../data/locality_zips.csv");
+
+/// One row of the dataset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipRecord {
+    pub zip: u32,
+    pub state: State,
+    pub city: &'static str,
+    /// County name without "County"
+    pub county: &'static str,
+}
+
+lazy_static! {
+    /// Records sorted by ZIP
+    static ref RECORDS: Vec<ZipRecord> = parse(LOCALITY_ZIPS_CSV);
+}
+
+/// Dataset version from the `# version:` header line
+pub fn version() -> &'static str {
+    LOCALITY_ZIPS_CSV
+        .lines()
+        .find_map(|line| line.strip_prefix("# version:"))
+        .map(str::trim)
+        .unwrap_or("unversioned")
+}
+
+/// Look up a ZIP or ZIP+4 code; `None` when it isn't in the table
+pub fn lookup(zip: &str) -> Option<&'static ZipRecord> {
+    let zip = zip.trim();
+    let five = zip.get(..5)?;
+    if !five.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let rest = &zip[5..];
+    if !rest.is_empty() && !rest.starts_with('-') {
+        return None;
+    }
+    let z: u32 = five.parse().ok()?;
+
+    RECORDS
+        .binary_search_by_key(&z, |r| r.zip)
+        .ok()
+        .map(|i| &RECORDS[i])
+}
+
+fn parse(csv: &'static str) -> Vec<ZipRecord> {
+    let mut records: Vec<ZipRecord> = csv
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .skip(1) // header
+        .map(|line| {
+            let fields: Vec<&'static str> = line.split(',').collect();
+            let [zip, state, city, county] = fields[..] else {
+                panic!("locality_zips.csv: expected 4 fields in {:?}", line);
+            };
+            ZipRecord {
+                zip: zip.parse().expect("locality_zips.csv: bad zip"),
+                state: State::parse_code(state).expect("locality_zips.csv: bad state"),
+                city,
+                county,
+            }
+        })
+        .collect();
+    records.sort_by_key(|r| r.zip);
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dataset_parses() {
+        assert!(!RECORDS.is_empty());
+        assert_eq!(version(), "2025.11");
+        for pair in RECORDS.windows(2) {
+            assert!(pair[0].zip < pair[1].zip, "duplicate ZIP {:?}", pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_zips_between_listed_ones_are_unknown() {
+        // 94128 (SFO) is numbered among San Francisco's ZIPs but in San Mateo County
+        let sfo = lookup("94128").unwrap();
+        assert_eq!(sfo.county, "San Mateo");
+        assert_eq!(lookup("94110").unwrap().city, "San Francisco");
+
+        // Post office box and unassigned ZIPs inside a city's numbering
+        assert!(lookup("10015").is_none());
+        assert!(lookup("60627").is_none());
+        // Thousand Oaks (Ventura County) sits between Los Angeles ZIPs
+        assert!(lookup("91360").is_none());
+        assert_eq!(lookup("10707").unwrap().city, "Tuckahoe");
+    }
+
+    #[test]
+    fn test_floral_park_is_not_nyc() {
+        let floral_park = lookup("11001").unwrap();
+        assert_eq!(floral_park.city, "Floral Park");
+        assert_eq!(floral_park.county, "Nassau");
+
+        let glen_oaks = lookup("11004").unwrap();
+        assert_eq!(glen_oaks.city, "New York");
+        assert_eq!(glen_oaks.county, "Queens");
+    }
+
+    #[test]
+    fn test_zip_formats() {
+        assert_eq!(lookup("33139-1234").unwrap().city, "Miami Beach");
+        assert_eq!(lookup(" 02134 ").unwrap().city, "Boston");
+        assert!(lookup("0213").is_none());
+        assert!(lookup("021345").is_none());
+        assert!(lookup("abcde").is_none());
+        assert!(lookup("75001").is_none());
+    }
+}
@@ -0,0 +1,322 @@
+//! Page-accurate violation locations
+//!
+//! Documents are checked as one string with their pages joined by `\n`, so
+//! rules report offsets into that joined text. [`PageMap`] maps them back to
+//! a page number and page-local offsets, for the violation and its
+//! remediation alike, and, when the document carries a [`PageLayout`] for
+//! the page, to the boxes the span covers, so a viewer can draw a highlight
+//! over the clause.
+
+use shared_types::{BoundingBox, PageLayout, TextPosition, Violation};
+
+/// Where each page starts in the joined document text
+#[derive(Debug, Clone)]
+pub struct PageMap {
+    text: String,
+    /// Byte offset of each page in `text`
+    starts: Vec<usize>,
+    /// Byte length of each page
+    lengths: Vec<usize>,
+}
+
+impl PageMap {
+    pub fn new(pages: &[String]) -> Self {
+        let mut starts = Vec::with_capacity(pages.len());
+        let mut offset = 0;
+        for page in pages {
+            starts.push(offset);
+            offset += page.len() + 1;
+        }
+        Self {
+            text: pages.join("\n"),
+            starts,
+            lengths: pages.iter().map(String::len).collect(),
+        }
+    }
+
+    /// The joined text the engine checks
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 1-based page number and page-local offset of a joined-text offset.
+    /// The separator after a page counts as that page's end.
+    pub fn locate(&self, offset: usize) -> Option<(u32, usize)> {
+        if offset > self.text.len() {
+            return None;
+        }
+        let index = self.starts.partition_point(|&start| start <= offset);
+        let index = index.checked_sub(1)?;
+        let local = (offset - self.starts[index]).min(self.lengths[index]);
+        Some((index as u32 + 1, local))
+    }
+
+    /// Move each violation's position and its remediation's span onto its
+    /// page and set `page`, so both are offsets into that page's text.
+    ///
+    /// Violations without a position but with a snippet cut around a match
+    /// (`...text...`, see [`crate::patterns::extract_snippet`]) are located
+    /// by finding the snippet, and ones with neither by their remediation
+    /// span. A span crossing a page break goes to the page holding most of
+    /// it and is clipped to that page.
+    pub fn localize(&self, violations: &mut [Violation], layout: &[PageLayout]) {
+        for violation in violations {
+            let position = violation.text_position.or_else(|| {
+                violation
+                    .text_snippet
+                    .as_deref()
+                    .and_then(|snippet| self.find_snippet(snippet))
+            });
+            let replace = violation.remediation.as_ref().and_then(|r| r.replace);
+            let Some(page) = position.or(replace).and_then(|span| self.page_of(span)) else {
+                continue;
+            };
+
+            violation.page = Some(page);
+            if let Some(position) = position {
+                let local = self.clip(position, page);
+                if let Some(page_layout) = layout.get(page as usize - 1) {
+                    violation.bounding_boxes =
+                        bounding_boxes(page_layout, local.start_offset, local.end_offset);
+                }
+                violation.text_position = Some(local);
+            }
+            if let Some(remediation) = &mut violation.remediation {
+                remediation.replace = remediation.replace.map(|span| self.clip(span, page));
+            }
+        }
+    }
+
+    /// Page holding most of a joined-text span
+    fn page_of(&self, position: TextPosition) -> Option<u32> {
+        let (first, _) = self.locate(position.start_offset)?;
+        let (last, _) = self.locate(position.end_offset.min(self.text.len()))?;
+        (first..=last).max_by_key(|&page| {
+            let clipped = self.clip(position, page);
+            (
+                clipped.end_offset - clipped.start_offset,
+                std::cmp::Reverse(page),
+            )
+        })
+    }
+
+    /// A joined-text span clipped to a page, in page-local offsets
+    fn clip(&self, position: TextPosition, page: u32) -> TextPosition {
+        let index = page as usize - 1;
+        let page_start = self.starts[index];
+        let page_end = page_start + self.lengths[index];
+        let start = position.start_offset.clamp(page_start, page_end);
+        let end = position.end_offset.clamp(start, page_end);
+        TextPosition::new(start - page_start, end - page_start)
+    }
+
+    fn find_snippet(&self, snippet: &str) -> Option<TextPosition> {
+        let core = snippet
+            .trim()
+            .strip_prefix("...")?
+            .strip_suffix("...")?
+            .trim();
+        if core.is_empty() {
+            return None;
+        }
+        let start = self.text.find(core)?;
+        Some(TextPosition::new(start, start + core.len()))
+    }
+}
+
+/// Boxes covering the page-local span `start..end`, one per line.
+///
+/// Runs partly inside the span are cut in proportion to the bytes covered,
+/// which is exact for monospaced text and close enough to highlight others.
+pub fn bounding_boxes(layout: &PageLayout, start: usize, end: usize) -> Vec<BoundingBox> {
+    let mut boxes: Vec<BoundingBox> = Vec::new();
+    for run in &layout.runs {
+        if run.end_offset <= start || run.start_offset >= end || run.end_offset <= run.start_offset
+        {
+            continue;
+        }
+        let len = (run.end_offset - run.start_offset) as f64;
+        let from = (start.max(run.start_offset) - run.start_offset) as f64 / len;
+        let to = (end.min(run.end_offset) - run.start_offset) as f64 / len;
+        let piece = BoundingBox {
+            x: run.bbox.x + run.bbox.width * from,
+            y: run.bbox.y,
+            width: run.bbox.width * (to - from),
+            height: run.bbox.height,
+        };
+
+        match boxes.last_mut() {
+            Some(last) if same_line(last, &piece) => *last = union(last, &piece),
+            _ => boxes.push(piece),
+        }
+    }
+    boxes
+}
+
+fn same_line(a: &BoundingBox, b: &BoundingBox) -> bool {
+    (a.y - b.y).abs() < a.height.min(b.height) / 2.0
+}
+
+fn union(a: &BoundingBox, b: &BoundingBox) -> BoundingBox {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+    BoundingBox {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared_types::{PositionedRun, Remediation, Severity};
+
+    fn pages() -> Vec<String> {
+        vec![
+            "Page one text.".to_string(),
+            "Tenant waives notice.".to_string(),
+        ]
+    }
+
+    fn violation(position: Option<TextPosition>, snippet: Option<&str>) -> Violation {
+        Violation {
+            statute: "S".to_string(),
+            severity: Severity::Critical,
+            message: "m".to_string(),
+            page: None,
+            text_snippet: snippet.map(str::to_string),
+            text_position: position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        }
+    }
+
+    fn run(start: usize, end: usize, x: f64, y: f64, width: f64) -> PositionedRun {
+        PositionedRun {
+            start_offset: start,
+            end_offset: end,
+            bbox: BoundingBox {
+                x,
+                y,
+                width,
+                height: 10.0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_locate() {
+        let map = PageMap::new(&pages());
+        assert_eq!(map.text(), "Page one text.\nTenant waives notice.");
+        assert_eq!(map.locate(0), Some((1, 0)));
+        assert_eq!(map.locate(14), Some((1, 14)));
+        assert_eq!(map.locate(15), Some((2, 0)));
+        assert_eq!(map.locate(22), Some((2, 7)));
+        assert_eq!(map.locate(100), None);
+    }
+
+    #[test]
+    fn test_localize_moves_span_onto_page() {
+        let map = PageMap::new(&pages());
+        let start = map.text().find("waives").unwrap();
+        let mut violations = vec![violation(Some(TextPosition::new(start, start + 6)), None)];
+
+        map.localize(&mut violations, &[]);
+
+        assert_eq!(violations[0].page, Some(2));
+        let position = violations[0].text_position.as_ref().unwrap();
+        assert_eq!(
+            &pages()[1][position.start_offset..position.end_offset],
+            "waives"
+        );
+        assert!(violations[0].bounding_boxes.is_empty());
+    }
+
+    #[test]
+    fn test_span_across_page_break_goes_to_larger_part() {
+        let map = PageMap::new(&pages());
+        // "text.\nTenant waives" is mostly on page 2
+        let start = map.text().find("text.").unwrap();
+        let end = map.text().find(" notice").unwrap();
+        let mut violations = vec![violation(Some(TextPosition::new(start, end)), None)];
+
+        map.localize(&mut violations, &[]);
+
+        assert_eq!(violations[0].page, Some(2));
+        assert_eq!(
+            violations[0].text_position,
+            Some(TextPosition::new(0, "Tenant waives".len()))
+        );
+    }
+
+    #[test]
+    fn test_localize_from_snippet() {
+        let map = PageMap::new(&pages());
+        let mut violations = vec![
+            violation(None, Some("...Tenant waives...")),
+            // Document-prefix snippets are not matches
+            violation(None, Some("Page one text.")),
+        ];
+
+        map.localize(&mut violations, &[]);
+
+        assert_eq!(violations[0].page, Some(2));
+        assert_eq!(
+            violations[0].text_position,
+            Some(TextPosition::new(0, "Tenant waives".len()))
+        );
+        assert_eq!(violations[1].page, None);
+        assert_eq!(violations[1].text_position, None);
+    }
+
+    #[test]
+    fn test_remediation_span_shares_the_page() {
+        let map = PageMap::new(&pages());
+        let start = map.text().find("Tenant").unwrap();
+        let mut violations = vec![violation(None, None)];
+        violations[0].remediation = Some(Remediation {
+            clause: "Tenant receives notice.".to_string(),
+            variables: Vec::new(),
+            replace: Some(TextPosition::new(start, map.text().len())),
+            explanation: String::new(),
+        });
+
+        map.localize(&mut violations, &[]);
+
+        assert_eq!(violations[0].page, Some(2));
+        assert_eq!(violations[0].text_position, None);
+        assert_eq!(
+            violations[0].remediation.as_ref().unwrap().replace,
+            Some(TextPosition::new(0, pages()[1].len()))
+        );
+    }
+
+    #[test]
+    fn test_bounding_boxes_merge_per_line() {
+        // "Tenant waives" on one line, "notice." on the next
+        let layout = PageLayout {
+            runs: vec![
+                run(0, 6, 72.0, 700.0, 36.0),
+                run(7, 13, 112.0, 700.0, 36.0),
+                run(14, 21, 72.0, 686.0, 42.0),
+            ],
+        };
+
+        let boxes = bounding_boxes(&layout, 3, 17);
+        assert_eq!(boxes.len(), 2);
+        assert_eq!(boxes[0].x, 90.0);
+        assert_eq!(boxes[0].width, 148.0 - 90.0);
+        assert_eq!(boxes[1].x, 72.0);
+        assert_eq!(boxes[1].width, 18.0);
+
+        let map = PageMap::new(&pages());
+        let start = map.text().find("waives").unwrap();
+        let mut violations = vec![violation(Some(TextPosition::new(start, start + 6)), None)];
+        map.localize(&mut violations, &[PageLayout::default(), layout]);
+        let boxes = &violations[0].bounding_boxes;
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes[0].x, 112.0);
+    }
+}
@@ -0,0 +1,536 @@
+//! Cross-document compliance
+//!
+//! A lease is rarely signed alone: the lead paint disclosure, the Chicago
+//! RLTO summary, the flood disclosure and the pet or parking addendum often
+//! arrive as separate PDFs. Checked one by one, the lease is flagged for every
+//! disclosure it leaves to another document. A [`DocumentPacket`] is checked
+//! as a whole instead: each document is classified, the rules run over the
+//! packet's combined text, and the report says which document satisfied each
+//! requirement the lease alone would have missed.
+
+use crate::locate::PageMap;
+use crate::{CommercialDocumentType, ComplianceEngine, DocumentType, Jurisdiction, NaiveDate};
+use serde::Serialize;
+use shared_types::{ComplianceReport, Coverage, LeaseDocument, Track, Violation};
+use std::collections::BTreeSet;
+
+/// Kind of document in a packet, read from its title
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacketDocumentType {
+    /// The lease agreement itself
+    Lease,
+    /// Lead-based paint disclosure form (42 U.S.C. § 4852d)
+    LeadPaintDisclosure,
+    /// Florida flood disclosure (F.S. § 83.512)
+    FloodDisclosure,
+    /// Chicago Residential Landlord and Tenant Ordinance summary
+    RltoSummary,
+    /// Electronic notice consent (F.S. § 83.56, HB 615)
+    ElectronicNoticeConsent,
+    /// Pet addendum
+    PetAddendum,
+    /// Parking or towing addendum
+    ParkingAddendum,
+    /// Any other addendum, rider or exhibit
+    Addendum,
+    /// Not recognized
+    Unknown,
+}
+
+/// Title phrases of each specific form, checked before the generic kinds
+const FORM_TITLES: &[(PacketDocumentType, &[&str])] = &[
+    (
+        PacketDocumentType::LeadPaintDisclosure,
+        &[
+            "lead-based paint",
+            "lead based paint",
+            "lead paint",
+            "pintura a base de plomo",
+        ],
+    ),
+    (
+        PacketDocumentType::FloodDisclosure,
+        &["flood disclosure", "83.512", "divulgación de inundaciones"],
+    ),
+    (
+        PacketDocumentType::RltoSummary,
+        &["rlto", "landlord and tenant ordinance"],
+    ),
+    (
+        PacketDocumentType::ElectronicNoticeConsent,
+        &["electronic notice consent", "consent to electronic notice"],
+    ),
+    (
+        PacketDocumentType::PetAddendum,
+        &[
+            "pet addendum",
+            "pet agreement",
+            "animal addendum",
+            "anexo de mascotas",
+        ],
+    ),
+    (
+        PacketDocumentType::ParkingAddendum,
+        &[
+            "parking addendum",
+            "parking rules addendum",
+            "vehicle addendum",
+            "towing addendum",
+        ],
+    ),
+];
+
+const ADDENDUM_TITLES: &[&str] = &["addendum", "rider", "exhibit", "anexo", "adenda"];
+
+const LEASE_TITLES: &[&str] = &[
+    "lease",
+    "rental agreement",
+    "arrendamiento",
+    "contrato de alquiler",
+];
+
+/// Characters of the first line read as the title
+const TITLE_CHARS: usize = 200;
+
+impl PacketDocumentType {
+    /// Classify a document from its title (the first non-empty line), or
+    /// from lease terms in the body when the title says nothing
+    pub fn detect(text: &str) -> Self {
+        let title: String = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or("")
+            .chars()
+            .take(TITLE_CHARS)
+            .collect::<String>()
+            .to_lowercase();
+
+        for (kind, phrases) in FORM_TITLES {
+            if phrases.iter().any(|p| title.contains(p)) {
+                return *kind;
+            }
+        }
+        if ADDENDUM_TITLES.iter().any(|t| title.contains(t)) {
+            return Self::Addendum;
+        }
+        if LEASE_TITLES.iter().any(|t| title.contains(t)) {
+            return Self::Lease;
+        }
+
+        let text_lower = text.to_lowercase();
+        if text_lower.contains("landlord")
+            && text_lower.contains("tenant")
+            && text_lower.contains("rent")
+        {
+            Self::Lease
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// A lease and the documents signed with it
+#[derive(Debug, Clone, Default)]
+pub struct DocumentPacket {
+    documents: Vec<LeaseDocument>,
+}
+
+impl DocumentPacket {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a document; order is kept in the report
+    pub fn with_document(mut self, document: LeaseDocument) -> Self {
+        self.documents.push(document);
+        self
+    }
+
+    pub fn documents(&self) -> &[LeaseDocument] {
+        &self.documents
+    }
+
+    /// Each document with its detected type
+    pub fn classify(&self) -> Vec<PacketDocument> {
+        self.documents
+            .iter()
+            .map(|document| PacketDocument {
+                document_id: document.id.clone(),
+                filename: document.filename.clone(),
+                document_type: PacketDocumentType::detect(&document.text_content.join("\n")),
+            })
+            .collect()
+    }
+
+    /// Index of the lease: the first document classified as one, otherwise
+    /// the first document
+    fn lease_index(classified: &[PacketDocument]) -> usize {
+        classified
+            .iter()
+            .position(|d| d.document_type == PacketDocumentType::Lease)
+            .unwrap_or(0)
+    }
+}
+
+/// A document of the packet and what it was classified as
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PacketDocument {
+    pub document_id: String,
+    pub filename: String,
+    pub document_type: PacketDocumentType,
+}
+
+/// A requirement the lease alone misses but the packet meets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SatisfiedRequirement {
+    pub statute: String,
+    /// Documents that satisfy it; when no single document does, every
+    /// document besides the lease
+    pub satisfied_by: Vec<String>,
+}
+
+/// Result of checking a packet
+#[derive(Debug, Clone, Serialize)]
+pub struct PacketReport {
+    /// The documents, classified, in packet order
+    pub documents: Vec<PacketDocument>,
+    /// One report per document, in packet order. Violations are reported on
+    /// the document and page they were found on; a missing requirement has no
+    /// location and is reported on the lease.
+    pub reports: Vec<ComplianceReport>,
+    pub satisfied: Vec<SatisfiedRequirement>,
+    pub coverage: Coverage,
+    /// Body of law the packet was checked under, decided by the lease
+    pub track: Track,
+}
+
+impl PacketReport {
+    /// Every violation in the packet
+    pub fn violations(&self) -> impl Iterator<Item = &Violation> {
+        self.reports.iter().flat_map(|r| r.violations.iter())
+    }
+
+    /// The report of one document
+    pub fn report(&self, document_id: &str) -> Option<&ComplianceReport> {
+        self.reports.iter().find(|r| r.document_id == document_id)
+    }
+}
+
+/// Check a packet; see [`ComplianceEngine::check_packet`]
+pub(crate) fn check_packet(
+    engine: &ComplianceEngine,
+    jurisdiction: &Jurisdiction,
+    packet: &DocumentPacket,
+    year_built: Option<u32>,
+    as_of: NaiveDate,
+) -> PacketReport {
+    let documents = packet.classify();
+    let checked_at = chrono::Utc::now().timestamp() as u64;
+    if packet.documents.is_empty() {
+        return PacketReport {
+            track: DocumentType::Lease.track(),
+            documents,
+            reports: Vec::new(),
+            satisfied: Vec::new(),
+            coverage: engine.coverage(jurisdiction.state, DocumentType::Lease),
+        };
+    }
+
+    // The lease goes first so the combined text reads like one agreement
+    let lease = DocumentPacket::lease_index(&documents);
+    let mut order = vec![lease];
+    order.extend((0..packet.documents.len()).filter(|&i| i != lease));
+
+    // The lease alone decides the track: an addendum's commercial wording
+    // must not move a residential lease off residential law
+    let commercial =
+        CommercialDocumentType::detect(&packet.documents[lease].text_content.join("\n"));
+    let doc_type = DocumentType::of_lease(commercial);
+
+    let check = |indices: &[usize]| -> Vec<Violation> {
+        let text = indices
+            .iter()
+            .flat_map(|&i| packet.documents[i].text_content.iter().cloned())
+            .collect::<Vec<_>>()
+            .join("\n");
+        engine.check_lease_text(jurisdiction, &text, commercial, year_built, as_of)
+    };
+
+    // Requirements the lease misses on its own but not with the others
+    let missed_alone = statutes(&check(&[lease]));
+    let mut pages = Vec::new();
+    let mut layout = Vec::new();
+    // Packet page number each document starts after
+    let mut first_page = vec![0; packet.documents.len()];
+    for &i in &order {
+        let document = &packet.documents[i];
+        first_page[i] = pages.len() as u32;
+        pages.extend(document.text_content.iter().cloned());
+        layout.extend(
+            (0..document.text_content.len())
+                .map(|page| document.layout.get(page).cloned().unwrap_or_default()),
+        );
+    }
+    let map = PageMap::new(&pages);
+    let mut violations =
+        engine.check_lease_text(jurisdiction, map.text(), commercial, year_built, as_of);
+    let missed_together = statutes(&violations);
+
+    let satisfied = missed_alone
+        .difference(&missed_together)
+        .map(|statute| {
+            let mut satisfied_by: Vec<String> = order[1..]
+                .iter()
+                .filter(|&&i| !statutes(&check(&[lease, i])).contains(statute))
+                .map(|&i| packet.documents[i].id.clone())
+                .collect();
+            if satisfied_by.is_empty() {
+                satisfied_by = order[1..]
+                    .iter()
+                    .map(|&i| packet.documents[i].id.clone())
+                    .collect();
+            }
+            SatisfiedRequirement {
+                statute: statute.clone(),
+                satisfied_by,
+            }
+        })
+        .collect();
+
+    // Split violations by the document holding their packet page
+    map.localize(&mut violations, &layout);
+    let mut reports: Vec<ComplianceReport> = packet
+        .documents
+        .iter()
+        .map(|document| ComplianceReport {
+            document_id: document.id.clone(),
+            violations: Vec::new(),
+            checked_at,
+            coverage: Coverage::default(),
+            track: doc_type.track(),
+            local_coverage: doc_type.local_coverage(jurisdiction),
+        })
+        .collect();
+    for mut violation in violations {
+        let owner = match violation.page {
+            Some(page) => order
+                .iter()
+                .copied()
+                .rfind(|&i| first_page[i] < page)
+                .unwrap_or(lease),
+            None => lease,
+        };
+        violation.page = violation.page.map(|page| page - first_page[owner]);
+        reports[owner].violations.push(violation);
+    }
+
+    let coverage = engine.coverage(jurisdiction.state, doc_type);
+    for report in &mut reports {
+        report.coverage = coverage.clone();
+    }
+
+    PacketReport {
+        documents,
+        reports,
+        satisfied,
+        coverage,
+        track: doc_type.track(),
+    }
+}
+
+fn statutes(violations: &[Violation]) -> BTreeSet<String> {
+    violations.iter().map(|v| v.statute.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Locality, State};
+
+    fn document(id: &str, pages: &[&str]) -> LeaseDocument {
+        LeaseDocument {
+            id: id.to_string(),
+            filename: format!("{}.pdf", id),
+            pages: pages.len() as u32,
+            text_content: pages.iter().map(|p| p.to_string()).collect(),
+            created_at: 0,
+            layout: Vec::new(),
+        }
+    }
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 11, 1).unwrap()
+    }
+
+    #[test]
+    fn test_classifies_documents() {
+        let cases = [
+            (
+                "RESIDENTIAL LEASE AGREEMENT\nTenant shall pay rent.",
+                PacketDocumentType::Lease,
+            ),
+            (
+                "Disclosure of Information on Lead-Based Paint and/or Lead-Based Paint Hazards",
+                PacketDocumentType::LeadPaintDisclosure,
+            ),
+            (
+                "FLOOD DISCLOSURE (F.S. § 83.512)",
+                PacketDocumentType::FloodDisclosure,
+            ),
+            (
+                "City of Chicago Residential Landlord and Tenant Ordinance Summary",
+                PacketDocumentType::RltoSummary,
+            ),
+            (
+                "ELECTRONIC NOTICE CONSENT",
+                PacketDocumentType::ElectronicNoticeConsent,
+            ),
+            ("Pet Addendum to Lease", PacketDocumentType::PetAddendum),
+            (
+                "Parking Rules Addendum",
+                PacketDocumentType::ParkingAddendum,
+            ),
+            ("Lease Addendum: Smoking", PacketDocumentType::Addendum),
+            (
+                "\n  This agreement is between Landlord and Tenant. Rent is $900.",
+                PacketDocumentType::Lease,
+            ),
+            ("Invoice #1234", PacketDocumentType::Unknown),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(PacketDocumentType::detect(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_disclosure_in_another_document_satisfies_lease() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::new(State::FL);
+        let packet = DocumentPacket::new()
+            .with_document(document(
+                "lead",
+                &["LEAD-BASED PAINT DISCLOSURE\n\
+                 Landlord has no knowledge of lead-based paint hazards. \
+                 Tenant received the EPA pamphlet Protect Your Family From Lead in Your Home."],
+            ))
+            .with_document(document(
+                "lease",
+                &["RESIDENTIAL LEASE AGREEMENT\n\
+                 Property built in 1965. Monthly rent: $1,500."],
+            ))
+            .with_document(document(
+                "flood",
+                &["FLOOD DISCLOSURE (F.S. § 83.512)\n\
+                 Landlord has no knowledge of prior flooding. No flood insurance claims \
+                 have been filed. No federal flood assistance (FEMA) has been received."],
+            ));
+
+        let report = engine.check_packet(&jurisdiction, &packet, None, as_of());
+
+        assert_eq!(report.documents[1].document_type, PacketDocumentType::Lease);
+        assert!(!report
+            .violations()
+            .any(|v| v.statute == "42 U.S.C. § 4852d" || v.statute == "F.S. § 83.512 (SB 948)"));
+        assert!(report.satisfied.contains(&SatisfiedRequirement {
+            statute: "42 U.S.C. § 4852d".to_string(),
+            satisfied_by: vec!["lead".to_string()],
+        }));
+        assert!(report.satisfied.contains(&SatisfiedRequirement {
+            statute: "F.S. § 83.512 (SB 948)".to_string(),
+            satisfied_by: vec!["flood".to_string()],
+        }));
+    }
+
+    #[test]
+    fn test_chicago_rlto_summary_as_separate_document() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::with_locality(State::IL, Locality::Chicago);
+        let lease = document("lease", &["LEASE\nMonthly rent: $2,000."]);
+        let summary = document(
+            "summary",
+            &["CITY OF CHICAGO RESIDENTIAL LANDLORD AND TENANT ORDINANCE SUMMARY"],
+        );
+
+        let alone = engine.check_packet(
+            &jurisdiction,
+            &DocumentPacket::new().with_document(lease.clone()),
+            None,
+            as_of(),
+        );
+        assert!(alone.violations().any(|v| v.statute.contains("5-12-170")));
+
+        let packet = DocumentPacket::new()
+            .with_document(lease)
+            .with_document(summary);
+        let report = engine.check_packet(&jurisdiction, &packet, None, as_of());
+        assert!(!report.violations().any(|v| v.statute.contains("5-12-170")));
+        let rlto = report
+            .satisfied
+            .iter()
+            .find(|s| s.statute.contains("5-12-170"))
+            .unwrap();
+        assert_eq!(rlto.satisfied_by, vec!["summary"]);
+    }
+
+    #[test]
+    fn test_violation_reported_on_its_document_and_page() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::new(State::FL);
+        let packet = DocumentPacket::new()
+            .with_document(document(
+                "lease",
+                &["RESIDENTIAL LEASE AGREEMENT", "Monthly rent: $1,500."],
+            ))
+            .with_document(document(
+                "pets",
+                &[
+                    "PET ADDENDUM",
+                    "One cat allowed. Tenant waives all rights under Florida law.",
+                ],
+            ));
+
+        let report = engine.check_packet(&jurisdiction, &packet, None, as_of());
+
+        let waiver = report
+            .report("pets")
+            .unwrap()
+            .violations
+            .iter()
+            .find(|v| v.statute == "F.S. § 83.47(1)(a)")
+            .expect("waiver reported on the addendum");
+        assert_eq!(waiver.page, Some(2));
+        let position = waiver.text_position.as_ref().unwrap();
+        assert!(position.end_offset <= packet.documents()[1].text_content[1].len());
+        assert!(!report
+            .report("lease")
+            .unwrap()
+            .violations
+            .iter()
+            .any(|v| v.statute == "F.S. § 83.47(1)(a)"));
+    }
+
+    #[test]
+    fn test_addendum_does_not_move_lease_off_residential_track() {
+        let engine = ComplianceEngine::new();
+        let jurisdiction = Jurisdiction::new(State::FL);
+        let packet = DocumentPacket::new()
+            .with_document(document(
+                "lease",
+                &["LEASE AGREEMENT\nMonthly rent: $1,500. Tenant waives all rights to notice."],
+            ))
+            .with_document(document(
+                "parking",
+                &["PARKING ADDENDUM\nSpace 12 may be used solely for commercial vehicle storage."],
+            ));
+
+        let report = engine.check_packet(&jurisdiction, &packet, None, as_of());
+
+        assert_eq!(report.track, Track::Residential);
+        assert!(report.reports.iter().all(|r| r.track == Track::Residential));
+        assert!(report
+            .violations()
+            .any(|v| v.statute == "F.S. § 83.47(1)(a)"));
+    }
+}
@@ -1,5 +1,7 @@
 //! Regex patterns and detection logic for prohibited provisions
 
+use crate::clauses;
+
 /// Waiver keywords that indicate a tenant is relinquishing rights
 pub const WAIVER_KEYWORDS: &[&str] = &[
     "waive",
@@ -11,14 +13,41 @@ pub const WAIVER_KEYWORDS: &[&str] = &[
     "forgoes",
     "surrender",
     "surrenders",
+    // Spanish
+    "renuncia",
+    "renunciar",
+    "renunciará",
+    "renuncie",
 ];
 
 /// Notice-related keywords
-pub const NOTICE_KEYWORDS: &[&str] = &["notice", "notification", "notify", "advance notice"];
+pub const NOTICE_KEYWORDS: &[&str] = &[
+    "notice",
+    "notification",
+    "notify",
+    "advance notice",
+    // Spanish
+    "aviso",
+    "notificación",
+    "notificacion",
+    "notificar",
+];
 
 /// Termination/eviction keywords
-pub const TERMINATION_KEYWORDS: &[&str] =
-    &["termination", "eviction", "evict", "terminate", "removal"];
+pub const TERMINATION_KEYWORDS: &[&str] = &[
+    "termination",
+    "eviction",
+    "evict",
+    "terminate",
+    "removal",
+    // Spanish
+    "terminación",
+    "terminacion",
+    "desalojo",
+    "desahucio",
+    "rescisión",
+    "rescision",
+];
 
 /// Property disposal keywords
 pub const DISPOSAL_KEYWORDS: &[&str] = &[
@@ -28,6 +57,11 @@ pub const DISPOSAL_KEYWORDS: &[&str] = &[
     "throw away",
     "remove",
     "destroy",
+    // Spanish
+    "desechar",
+    "deshacerse",
+    "destruir",
+    "disponer de",
 ];
 
 /// Property reference keywords
@@ -37,10 +71,22 @@ pub const PROPERTY_KEYWORDS: &[&str] = &[
     "possessions",
     "items",
     "personal property",
+    // Spanish
+    "pertenencias",
+    "bienes",
+    "propiedad",
+    "artículos",
 ];
 
 /// Tenant reference keywords
-pub const TENANT_KEYWORDS: &[&str] = &["tenant", "lessee", "renter"];
+pub const TENANT_KEYWORDS: &[&str] = &[
+    "tenant",
+    "lessee",
+    "renter",
+    // Spanish
+    "inquilino",
+    "arrendatario",
+];
 
 /// Structural/maintenance keywords that landlord is responsible for
 pub const STRUCTURAL_KEYWORDS: &[&str] = &[
@@ -57,13 +103,45 @@ pub const STRUCTURAL_KEYWORDS: &[&str] = &[
     "air conditioning",
     "electrical",
     "wiring",
+    // Spanish
+    "techo",
+    "plomería",
+    "plomeria",
+    "tuberías",
+    "tuberias",
+    "termitas",
+    "estructura",
+    "cimientos",
+    "calefacción",
+    "aire acondicionado",
+    "cableado",
+    "eléctric",
 ];
 
 /// AS-IS keywords
-pub const AS_IS_KEYWORDS: &[&str] = &["as-is", "as is", "asis"];
+pub const AS_IS_KEYWORDS: &[&str] = &[
+    "as-is",
+    "as is",
+    "asis",
+    // Spanish
+    "tal como está",
+    "tal como esta",
+    "tal cual",
+    "en su estado actual",
+];
 
 /// Rights reference keywords
-pub const RIGHTS_KEYWORDS: &[&str] = &["rights", "right", "protections", "remedies"];
+pub const RIGHTS_KEYWORDS: &[&str] = &[
+    "rights",
+    "right",
+    "protections",
+    "remedies",
+    // Spanish
+    "derechos",
+    "derecho",
+    "protecciones",
+    "recursos",
+];
 
 /// Florida landlord-tenant law references
 pub const FL_LAW_KEYWORDS: &[&str] = &[
@@ -72,19 +150,31 @@ pub const FL_LAW_KEYWORDS: &[&str] = &[
     "landlord tenant law",
     "landlord-tenant law",
     "statute",
+    // Spanish
+    "capítulo 83",
+    "capitulo 83",
+    "ley de propietarios e inquilinos",
+    "estatuto",
 ];
 
+/// Words for "all" that make a waiver of rights a general one
+pub const ALL_KEYWORDS: &[&str] = &["all", "todos", "todas", "cualquier"];
+
 /// Check if text contains semantic clustering of keywords
-/// Returns true if text contains words from multiple keyword groups within proximity
+/// Returns true if a single clause (see [`crate::clauses::segment`]) contains
+/// words from multiple keyword groups
 pub fn contains_semantic_cluster(text: &str, keyword_groups: &[&[&str]]) -> bool {
-    let text_lower = text.to_lowercase();
-    let mut found_groups = 0;
+    clauses::segment(text)
+        .iter()
+        .any(|clause| clause_has_cluster(clause.text, keyword_groups))
+}
 
-    for group in keyword_groups {
-        if group.iter().any(|keyword| text_lower.contains(keyword)) {
-            found_groups += 1;
-        }
-    }
+fn clause_has_cluster(clause: &str, keyword_groups: &[&[&str]]) -> bool {
+    let clause_lower = clause.to_lowercase();
+    let found_groups = keyword_groups
+        .iter()
+        .filter(|group| group.iter().any(|keyword| clause_lower.contains(keyword)))
+        .count();
 
     // Require at least 2 groups to be present for semantic clustering
     found_groups >= 2
@@ -96,8 +186,8 @@ pub fn extract_snippet(text: &str, keyword: &str) -> String {
     let keyword_lower = keyword.to_lowercase();
 
     if let Some(pos) = text_lower.find(&keyword_lower) {
-        let start = pos.saturating_sub(50);
-        let end = (pos + keyword.len() + 50).min(text.len());
+        let start = floor_char_boundary(text, pos.saturating_sub(50));
+        let end = ceil_char_boundary(text, pos + keyword.len() + 50);
         let snippet = &text[start..end];
         format!("...{}...", snippet.trim())
     } else {
@@ -105,6 +195,36 @@ pub fn extract_snippet(text: &str, keyword: &str) -> String {
     }
 }
 
+/// Snippet around a match, cut on character boundaries
+pub fn snippet_around(text: &str, start: usize, end: usize) -> String {
+    let from = floor_char_boundary(text, start.saturating_sub(50));
+    let to = ceil_char_boundary(text, end + 50);
+    format!("...{}...", text[from..to].trim())
+}
+
+/// Largest char boundary at or before `index`
+pub fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Smallest char boundary at or after `index`, capped at the text length
+pub fn ceil_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+/// First keyword of a group found in already-lowercased text
+pub fn first_keyword<'k>(text_lower: &str, keywords: &[&'k str]) -> Option<&'k str> {
+    keywords.iter().copied().find(|kw| text_lower.contains(kw))
+}
+
 /// Find the position of a keyword match for text highlighting
 /// Returns (start_offset, end_offset) if found
 pub fn find_text_position(text: &str, keyword: &str) -> Option<(usize, usize)> {
@@ -113,8 +233,8 @@ pub fn find_text_position(text: &str, keyword: &str) -> Option<(usize, usize)> {
 
     if let Some(start) = text_lower.find(&keyword_lower) {
         // Extend to capture the full phrase (up to 100 chars around the keyword)
-        let context_start = start.saturating_sub(20);
-        let context_end = (start + keyword.len() + 80).min(text.len());
+        let context_start = floor_char_boundary(text, start.saturating_sub(20));
+        let context_end = ceil_char_boundary(text, start + keyword.len() + 80);
         Some((context_start, context_end))
     } else {
         None
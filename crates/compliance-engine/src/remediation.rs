@@ -0,0 +1,1217 @@
+//! Suggested fixes for violations
+//!
+//! Each violation the engine can fix gets a [`Remediation`]: a model clause
+//! for its jurisdiction, the span of the checked text it replaces (or none,
+//! when the clause is missing and must be added) and an explanation.
+//! Model clauses are keyed by citation in [`MODEL_CLAUSES`], which also
+//! cover the subsections of that citation (see [`crate::effective::cites`]).
+//! A violation no model clause covers gets [`Suggestion::NoModelClause`]:
+//! the fix has to be drafted by hand.
+//!
+//! Accepted remediations are applied with [`apply_remediations`], which
+//! rewrites the checked text, or [`patch_typst_template`], which appends them
+//! as an addendum to the source of a document generated from a `typst-engine`
+//! template.
+
+use crate::effective::cites;
+use shared_types::{Remediation, TextPosition, Violation};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors applying remediations
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RemediationError {
+    #[error("{statute}: no value for clause variable '{variable}'")]
+    MissingVariable { statute: String, variable: String },
+
+    #[error("{statute}: replacement span {start}..{end} is outside the text")]
+    InvalidSpan {
+        statute: String,
+        start: usize,
+        end: usize,
+    },
+
+    #[error("{first} and {second} replace overlapping text")]
+    Overlap { first: String, second: String },
+}
+
+/// Model clause for a citation
+#[derive(Debug, Clone, Copy)]
+pub struct ModelClause {
+    pub citation: &'static str,
+    /// Words locating the offending sentence; empty when the clause is
+    /// missing from the lease and is added rather than substituted
+    pub anchors: &'static [&'static str],
+    /// Clause text; `{{name}}` marks a variable to fill in
+    pub clause: &'static str,
+    pub explanation: &'static str,
+}
+
+/// Model clauses by citation; the longest matching citation wins
+pub const MODEL_CLAUSES: &[ModelClause] = &[
+    // ------------------------------------------------------------------------
+    // Federal
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "42 U.S.C. § 4852d",
+        anchors: &[],
+        clause: "LEAD WARNING STATEMENT. Housing built before 1978 may contain lead-based paint. \
+                 Lead from paint, paint chips, and dust can pose health hazards if not managed \
+                 properly. Lead exposure is especially harmful to young children and pregnant \
+                 women. Before renting pre-1978 housing, lessors must disclose the presence of \
+                 known lead-based paint and/or lead-based paint hazards in the dwelling. Lessees \
+                 must also receive a federally approved pamphlet on lead poisoning prevention. \
+                 Lessor has {{lead_knowledge}} knowledge of lead-based paint and/or lead-based \
+                 paint hazards in the housing.",
+        explanation: "Leases of pre-1978 housing must include the Lead Warning Statement from \
+                      24 CFR 35.92 and the lessor's disclosure of known hazards.",
+    },
+    ModelClause {
+        citation: "24 CFR 35.92",
+        anchors: &[],
+        clause: "Lessee acknowledges receipt of the pamphlet \"Protect Your Family From Lead in \
+                 Your Home\".",
+        explanation: "The lessee must receive the EPA lead hazard pamphlet and the lease should \
+                      record it.",
+    },
+    ModelClause {
+        citation: "42 U.S.C. § 3604",
+        anchors: &[
+            "no children",
+            "adults only",
+            "no kids",
+            "christian",
+            "no section 8",
+            "english",
+            "married",
+            "single",
+            "wheelchair",
+            "disab",
+        ],
+        clause: "Landlord does not discriminate on the basis of race, color, religion, sex, \
+                 disability, familial status, or national origin in renting the Premises.",
+        explanation: "Statements indicating a preference or limitation based on a protected \
+                      class are unlawful under the Fair Housing Act and must be removed.",
+    },
+    // ------------------------------------------------------------------------
+    // Florida
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "F.S. § 83.47(1)(a)",
+        anchors: &["waive", "waiver", "relinquish", "forgo", "surrender"],
+        clause: "Tenant retains all rights, remedies, and notices provided by Part II of \
+                 Chapter 83, Florida Statutes, and no provision of this Lease limits them.",
+        explanation: "A provision waiving the tenant's statutory rights or notice is void and \
+                      unenforceable under § 83.47(1)(a); replace it with a savings clause.",
+    },
+    ModelClause {
+        citation: "F.S. § 83.47(1)(b)",
+        anchors: &["dispose", "disposal", "discard", "destroy"],
+        clause: "Personal property left on the Premises after Tenant surrenders or abandons \
+                 them shall be handled as provided in § 83.67 and Part II of Chapter 715, \
+                 Florida Statutes.",
+        explanation: "Landlord may not be authorized to dispose of tenant property outside the \
+                      statutory abandoned-property procedure (§ 83.47(1)(b)).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.51(2)(a)",
+        anchors: &["as-is", "as is"],
+        clause: "Notwithstanding any other provision of this Lease, Landlord shall maintain the \
+                 roof, windows, screens, doors, floors, steps, porches, exterior walls, \
+                 foundations, and other structural components, and the plumbing, in reasonable \
+                 working condition as required by § 83.51, Florida Statutes.",
+        explanation: "Structural and plumbing maintenance is the landlord's duty under § 83.51 \
+                      and cannot be shifted by an as-is clause.",
+    },
+    ModelClause {
+        citation: "F.S. § 83.49(2)",
+        anchors: &[],
+        clause: "The security deposit is held in a {{account_type}} account at {{bank_name}}, \
+                 {{bank_address}}, Florida, as required by § 83.49(2), Florida Statutes.",
+        explanation: "Within 30 days of receiving the deposit the landlord must disclose the \
+                      Florida institution where it is held (§ 83.49(2)).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.49(3)",
+        anchors: &["deposit", "security"],
+        clause: "If Landlord does not intend to impose a claim on the security deposit, Landlord \
+                 shall return it within 15 days after Tenant vacates the Premises. If Landlord \
+                 intends to impose a claim, Landlord shall give Tenant written notice of the \
+                 claim by certified mail within 30 days after Tenant vacates, as provided in \
+                 § 83.49(3), Florida Statutes.",
+        explanation: "Florida requires return within 15 days when no claim is made, or notice \
+                      of a claim within 30 days (§ 83.49(3)).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.48",
+        anchors: &["attorney"],
+        clause: "In any action to enforce this Lease or Part II of Chapter 83, Florida Statutes, \
+                 the prevailing party is entitled to recover reasonable attorney fees and court \
+                 costs from the non-prevailing party.",
+        explanation: "Attorney fee provisions are reciprocal by statute (§ 83.48); a prevailing \
+                      party clause states that correctly.",
+    },
+    ModelClause {
+        citation: "F.S. § 83.56(3)",
+        anchors: &["nonpayment", "non-payment", "rent"],
+        clause: "If rent is not paid when due, Landlord may terminate this Lease after delivering \
+                 a written notice demanding payment of the rent or possession of the Premises \
+                 within 3 days, excluding Saturday, Sunday, and legal holidays, as provided in \
+                 § 83.56(3), Florida Statutes.",
+        explanation: "The nonpayment notice period is at least 3 business days (§ 83.56(3)).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.56(2)",
+        anchors: &[
+            "violation",
+            "breach",
+            "default",
+            "noncompliance",
+            "non-compliance",
+        ],
+        clause: "If Tenant materially fails to comply with this Lease, Landlord shall deliver a \
+                 written notice specifying the noncompliance and giving Tenant 7 days to cure \
+                 it, as provided in § 83.56(2), Florida Statutes.",
+        explanation: "Curable lease violations require at least 7 days' written notice \
+                      (§ 83.56(2)).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.56 (HB 615)",
+        anchors: &[],
+        clause: "Tenant expressly consents to receive notices required by Part II of Chapter 83, \
+                 Florida Statutes, by email at {{tenant_email}}. Tenant may withdraw this \
+                 consent by written notice to Landlord.",
+        explanation: "Notices may be sent electronically only with the tenant's express written \
+                      consent (HB 615).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.57",
+        anchors: &["month-to-month", "monthly"],
+        clause: "Either party may terminate a month-to-month tenancy by giving written notice no \
+                 less than 30 days before the end of any monthly period, as provided in \
+                 § 83.57(3), Florida Statutes.",
+        explanation: "Month-to-month tenancies require written notice before the end of a \
+                      monthly period (§ 83.57).",
+    },
+    ModelClause {
+        citation: "F.S. § 83.512",
+        anchors: &[],
+        clause: "FLOOD DISCLOSURE (§ 83.512, Florida Statutes). Landlord {{flood_knowledge}} \
+                 knowledge of any flooding that has damaged the Premises during Landlord's \
+                 ownership or possession. Landlord {{flood_claims}} filed a claim with an \
+                 insurance provider for flood damage to the Premises. Landlord \
+                 {{flood_assistance}} received federal assistance, including from the Federal \
+                 Emergency Management Agency (FEMA), for flood damage to the Premises.",
+        explanation: "Leases of one year or longer must disclose known flooding, flood insurance \
+                      claims and federal flood assistance before execution (§ 83.512).",
+    },
+    // ------------------------------------------------------------------------
+    // Texas
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.3515",
+        anchors: &[],
+        clause: "Landlord's tenant selection criteria are attached to this Lease as the Notice of \
+                 Selection Criteria. Applicant acknowledges receiving the criteria before paying \
+                 any application fee, as required by § 92.3515, Texas Property Code.",
+        explanation: "Applicants must receive the selection criteria before an application fee \
+                      is accepted (§ 92.3515).",
+    },
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.0131",
+        anchors: &[],
+        clause: "Vehicles may be towed only as provided in the Parking Rules Addendum attached \
+                 to and made part of this Lease.",
+        explanation: "Towing must be authorized in a separate parking rules addendum \
+                      (§ 92.0131).",
+    },
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.104",
+        anchors: &["deposit", "security"],
+        clause: "Landlord shall refund the security deposit, less lawful deductions described in \
+                 a written itemized list, within 30 days after Tenant surrenders the Premises, \
+                 as provided in §§ 92.103 and 92.104, Texas Property Code.",
+        explanation: "The deposit must be refunded with an itemized list of deductions within 30 \
+                      days of surrender (§§ 92.103-92.104).",
+    },
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.107",
+        anchors: &[],
+        clause: "Tenant shall give Landlord a written statement of Tenant's forwarding address \
+                 when Tenant surrenders the Premises.",
+        explanation: "Landlord is not obligated to return the deposit until the tenant provides \
+                      a forwarding address (§ 92.107).",
+    },
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.006",
+        anchors: &["waive"],
+        clause: "Landlord shall make a diligent effort to repair conditions that materially \
+                 affect the physical health or safety of an ordinary tenant, as required by \
+                 Subchapter B, Chapter 92, Texas Property Code.",
+        explanation: "The duty to repair may not be waived except as § 92.006 specifically \
+                      permits.",
+    },
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.0062",
+        anchors: &["jury"],
+        clause: "Each party retains the right to a trial by jury in any action arising under \
+                 this Lease.",
+        explanation: "A lease provision waiving the right to a jury trial is void \
+                      (§ 92.0062).",
+    },
+    ModelClause {
+        citation: "Tex. Prop. Code § 92.019",
+        anchors: &["late fee", "late charge"],
+        clause: "If any part of the rent remains unpaid two full days after the date it is \
+                 originally due, Tenant shall pay a late fee of {{late_fee}}, which the parties \
+                 agree is a reasonable estimate of Landlord's damages and which does not exceed \
+                 12 percent of the monthly rent.",
+        explanation: "Late fees are limited to a reasonable amount; 12% of monthly rent is a \
+                      safe harbor for structures with four or fewer units (§ 92.019).",
+    },
+    // ------------------------------------------------------------------------
+    // California
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "CA Civil Code § 1950.5 (AB 12)",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed one month's rent, as provided in § 1950.5, California Civil Code.",
+        explanation: "Security deposits are capped at one month's rent for leases from July 1, \
+                      2024, with a two-month allowance for small landlords (AB 12).",
+    },
+    ModelClause {
+        citation: "CA Civil Code § 1953",
+        anchors: &["waive", "waiver", "relinquish", "forgo"],
+        clause: "Nothing in this Lease waives or modifies Tenant's rights or remedies under \
+                 California law, including the right to notice, to a hearing, and to \
+                 habitable premises.",
+        explanation: "Lease provisions waiving the tenant's statutory rights or procedural \
+                      protections are void as contrary to public policy (§ 1953).",
+    },
+    ModelClause {
+        citation: "CA Civil Code § 1946.2 (SB 611)",
+        anchors: &["administrative", "processing", "fee"],
+        clause: "The mandatory fees payable under this Lease, in addition to rent, are: \
+                 {{itemized_fees}}. No other fee is required as a condition of tenancy.",
+        explanation: "Every mandatory fee must be disclosed and itemized individually \
+                      (SB 611).",
+    },
+    ModelClause {
+        citation: "CA Civil Code § 1946.2 (AB 1482)",
+        anchors: &[],
+        clause: "California law limits the amount your rent can be increased. See Section \
+                 1947.12 of the Civil Code for more information. California law also \
+                 provides that after all of the tenants have continuously and lawfully \
+                 occupied the property for 12 months or more or at least one of the tenants \
+                 has continuously and lawfully occupied the property for 24 months or more, a \
+                 landlord must provide a statement of cause in any notice to terminate a \
+                 tenancy. See Section 1946.2 of the Civil Code for more information.",
+        explanation: "Covered tenancies must include the just cause and rent cap notice in \
+                      the form set by § 1946.2(f), or a statement of exemption.",
+    },
+    ModelClause {
+        citation: "CA Civil Code § 827",
+        anchors: &[],
+        clause: "Landlord shall give Tenant written notice of any rent increase at least 30 \
+                 days before it takes effect, or at least 90 days before it takes effect if \
+                 the increase, together with all increases in the preceding 12 months, \
+                 exceeds 10 percent of the lowest rent charged during that period, as \
+                 provided in § 827, California Civil Code.",
+        explanation: "Rent increases require 30 days' notice, or 90 days' notice above 10% \
+                      (§ 827).",
+    },
+    // ------------------------------------------------------------------------
+    // New York
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "NY RPL § 238-a",
+        anchors: &["late fee", "late charge"],
+        clause: "If any rent payment is received more than five days after its due date, \
+                 Tenant shall pay a late fee of {{late_fee}}, which shall not exceed the \
+                 lesser of fifty dollars or five percent of the monthly rent.",
+        explanation: "Late fees are limited to the lesser of $50 or 5% of the monthly rent and \
+                      may be charged only after a five-day grace period (RPL § 238-a).",
+    },
+    ModelClause {
+        citation: "NY RPL § 7-108",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed one month's rent, to be held and returned as provided in § 7-108, \
+                 New York General Obligations Law.",
+        explanation: "Deposits and advances may not exceed one month's rent (HSTPA).",
+    },
+    ModelClause {
+        citation: "NY GOL § 5-321",
+        anchors: &["liab", "negligen", "hold harmless", "indemn"],
+        clause: "Nothing in this Lease exempts Landlord from liability for damages for \
+                 injuries to persons or property caused by or resulting from the negligence \
+                 of Landlord, its agents, servants, or employees.",
+        explanation: "An agreement exempting a landlord from liability for its own negligence \
+                      is void (GOL § 5-321).",
+    },
+    ModelClause {
+        citation: "NY CPLR § 3218",
+        anchors: &["confess", "cognovit"],
+        clause: "Neither party authorizes the entry of judgment against it without notice \
+                 and an opportunity to be heard.",
+        explanation: "Confession of judgment provisions in residential leases are void; the \
+                      landlord must sue and obtain judgment in the ordinary course.",
+    },
+    // ------------------------------------------------------------------------
+    // Georgia
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "GA Code § 44-7-13 (HB 404)",
+        anchors: &["as-is", "as is", "habitab", "waive"],
+        clause: "Landlord shall keep the Premises fit for human habitation and in repair, as \
+                 required by § 44-7-13, Official Code of Georgia, and no provision of this \
+                 Lease waives that duty.",
+        explanation: "The duty to keep the premises habitable and in repair cannot be waived \
+                      or disclaimed by an as-is clause (Safe at Home Act, HB 404).",
+    },
+    ModelClause {
+        citation: "GA Code § 44-7-30",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed two months' rent.",
+        explanation: "Security deposits may not exceed two months' rent (HB 404).",
+    },
+    ModelClause {
+        citation: "GA Code § 44-7-33",
+        anchors: &[],
+        clause: "Before accepting the security deposit, Landlord shall give Tenant a \
+                 comprehensive list of any existing damage to the Premises. Tenant may \
+                 inspect the Premises and note any disagreement with the list within 3 \
+                 business days, as provided in § 44-7-33, Official Code of Georgia.",
+        explanation: "Landlords must provide a move-in damage list that the tenant may \
+                      review and dispute (§ 44-7-33).",
+    },
+    // ------------------------------------------------------------------------
+    // Illinois
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "765 ILCS 705/2.5",
+        anchors: &["electronic", "online", "portal"],
+        clause: "Tenant may pay rent by check, money order, or another non-electronic method \
+                 in addition to any electronic payment method Landlord offers.",
+        explanation: "A landlord may not require rent to be paid only by electronic funds \
+                      transfer (765 ILCS 705/2.5).",
+    },
+    ModelClause {
+        citation: "765 ILCS 705/1",
+        anchors: &["liab", "negligen", "hold harmless", "indemn"],
+        clause: "Nothing in this Lease exempts Landlord from liability for damages for \
+                 injuries to persons or property caused by the negligence of Landlord, its \
+                 agents, servants, or employees.",
+        explanation: "Exculpatory clauses relieving a landlord of liability for its own \
+                      negligence are void (765 ILCS 705/1).",
+    },
+    ModelClause {
+        citation: "765 ILCS 705/1.5",
+        anchors: &["jury"],
+        clause: "Each party retains the right to a trial by jury in any action arising under \
+                 this Lease.",
+        explanation: "A lease provision waiving the right to a jury trial is void \
+                      (765 ILCS 705/1.5).",
+    },
+    ModelClause {
+        citation: "735 ILCS 5/2-1301",
+        anchors: &["confess", "cognovit"],
+        clause: "Neither party authorizes the entry of judgment against it without notice \
+                 and an opportunity to be heard.",
+        explanation: "Confession of judgment provisions in residential leases are void \
+                      (735 ILCS 5/2-1301(c)).",
+    },
+    ModelClause {
+        citation: "Chicago Mun. Code § 5-12-170",
+        anchors: &[],
+        clause: "A copy of the City of Chicago Residential Landlord and Tenant Ordinance \
+                 Summary is attached to and made part of this Lease.",
+        explanation: "Chicago leases must attach the RLTO summary; without it the tenant may \
+                      terminate the lease (§ 5-12-170).",
+    },
+    // ------------------------------------------------------------------------
+    // Pennsylvania
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "68 P.S. § 250.511a",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed two months' rent during the first year of the tenancy or one month's \
+                 rent thereafter, as provided in § 250.511a of the Landlord and Tenant Act.",
+        explanation: "Deposits are capped at two months' rent in the first lease year and one \
+                      month's rent after that (68 P.S. § 250.511a).",
+    },
+    ModelClause {
+        citation: "68 P.S. § 250.512",
+        anchors: &[],
+        clause: "Within 30 days after termination of this Lease or surrender of the \
+                 Premises, Landlord shall return the security deposit to Tenant, less any \
+                 deductions for damages listed in a written itemized statement.",
+        explanation: "The deposit, with a written list of any damage deductions, must be \
+                      returned within 30 days (68 P.S. § 250.512).",
+    },
+    ModelClause {
+        citation: "68 P.S. § 250.513",
+        anchors: &["confess", "cognovit"],
+        clause: "Neither party authorizes the entry of judgment against it without notice \
+                 and an opportunity to be heard.",
+        explanation: "Confession of judgment clauses are prohibited in residential leases.",
+    },
+    // ------------------------------------------------------------------------
+    // New Jersey
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "N.J.S.A. 46:8-21.2",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed one and one-half months' rent.",
+        explanation: "Security deposits may not exceed one and a half months' rent \
+                      (N.J.S.A. 46:8-21.2).",
+    },
+    ModelClause {
+        citation: "N.J.S.A. 46:8-45",
+        anchors: &[],
+        clause: "Tenant acknowledges receiving the Truth in Renting statement published by \
+                 the New Jersey Department of Community Affairs.",
+        explanation: "Landlords must distribute the DCA Truth in Renting statement to tenants \
+                      (N.J.S.A. 46:8-45).",
+    },
+    ModelClause {
+        citation: "N.J.S.A. 2A:16-9.3",
+        anchors: &["confess", "cognovit"],
+        clause: "Neither party authorizes the entry of judgment against it without notice \
+                 and an opportunity to be heard.",
+        explanation: "Confession of judgment clauses are prohibited in residential leases.",
+    },
+    // ------------------------------------------------------------------------
+    // Virginia, Massachusetts, Ohio
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "VA Code § 55.1-1228",
+        anchors: &["as-is", "as is", "maintain", "repair"],
+        clause: "Landlord shall maintain the Premises in a fit and habitable condition as \
+                 required by § 55.1-1220, Code of Virginia, and no provision of this Lease \
+                 waives that duty.",
+        explanation: "The landlord's maintenance obligations under the VRLTA cannot be waived \
+                      by agreement.",
+    },
+    ModelClause {
+        citation: "VA Code § 55.1-1214",
+        anchors: &[],
+        clause: "Within 5 days after occupancy, Landlord shall give Tenant a written report \
+                 of the condition of the Premises, which is deemed correct unless Tenant \
+                 objects in writing within 5 days after receiving it.",
+        explanation: "Landlords must provide a move-in inspection report within 5 days of \
+                      occupancy (§ 55.1-1214).",
+    },
+    ModelClause {
+        citation: "M.G.L. c. 186 (2025 Reform)",
+        anchors: &["broker"],
+        clause: "Any broker engaged by Landlord shall be paid by Landlord. Tenant shall not \
+                 pay any fee to a broker Tenant did not engage.",
+        explanation: "From August 1, 2025, the party that hires a broker pays the broker's \
+                      fee.",
+    },
+    ModelClause {
+        citation: "M.G.L. c. 186 § 14",
+        anchors: &["quiet enjoyment", "waive"],
+        clause: "Tenant shall have quiet enjoyment of the Premises, and no provision of this \
+                 Lease waives Tenant's rights under § 14, Chapter 186, Massachusetts General \
+                 Laws.",
+        explanation: "The covenant of quiet enjoyment and the remedies for its breach cannot \
+                      be waived (c. 186 § 14).",
+    },
+    ModelClause {
+        citation: "M.G.L. c. 231 § 13A",
+        anchors: &["confess", "cognovit"],
+        clause: "Neither party authorizes the entry of judgment against it without notice \
+                 and an opportunity to be heard.",
+        explanation: "Confession of judgment clauses are prohibited in residential leases.",
+    },
+    ModelClause {
+        citation: "O.R.C. § 5321.04",
+        anchors: &["as-is", "as is"],
+        clause: "Landlord shall make all repairs and do whatever is reasonably necessary to \
+                 keep the Premises in a fit and habitable condition, as required by \
+                 § 5321.04, Ohio Revised Code.",
+        explanation: "An as-is clause does not relieve the landlord of its statutory duty to \
+                      maintain the premises (§ 5321.04).",
+    },
+    ModelClause {
+        citation: "O.R.C. § 2323.13",
+        anchors: &["confess", "cognovit"],
+        clause: "Neither party authorizes the entry of judgment against it without notice \
+                 and an opportunity to be heard.",
+        explanation: "Warrants of attorney to confess judgment are invalid in residential \
+                      leases (§ 2323.13).",
+    },
+    // ------------------------------------------------------------------------
+    // Michigan, Washington, North Carolina
+    // ------------------------------------------------------------------------
+    ModelClause {
+        citation: "M.C.L. 37.2502a (SB 205-207)",
+        anchors: &["section 8", "voucher", "source of income", "assistance"],
+        clause: "Landlord does not discriminate against any applicant or tenant because of \
+                 the lawful source of income used to pay rent, including housing assistance \
+                 vouchers.",
+        explanation: "Refusing tenants because of a lawful source of income, including \
+                      vouchers, is prohibited (SB 205-207).",
+    },
+    ModelClause {
+        citation: "M.C.L. 554.602",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed one and one-half months' rent.",
+        explanation: "Security deposits may not exceed one and a half months' rent \
+                      (M.C.L. 554.602).",
+    },
+    ModelClause {
+        citation: "M.C.L. 554.609",
+        anchors: &[],
+        clause: "Within 30 days after termination of occupancy, Landlord shall mail Tenant an \
+                 itemized list of damages claimed against the security deposit, with the \
+                 balance of the deposit, to the forwarding address Tenant provides.",
+        explanation: "Deductions must be itemized and mailed within 30 days of move-out \
+                      (M.C.L. 554.609).",
+    },
+    ModelClause {
+        citation: "M.C.L. 554.139",
+        anchors: &["liab", "negligen", "hold harmless", "indemn"],
+        clause: "Landlord shall keep the Premises fit for their intended use and in \
+                 reasonable repair, and nothing in this Lease exempts Landlord from \
+                 liability for its own negligence.",
+        explanation: "The covenants of fitness and repair cannot be waived, and a landlord \
+                      may not disclaim liability for its negligence (M.C.L. 554.139, \
+                      554.633).",
+    },
+    ModelClause {
+        citation: "RCW 59.18.140",
+        anchors: &["increase"],
+        clause: "Landlord shall give Tenant written notice of any rent increase at least 90 \
+                 days before it takes effect, as provided in RCW 59.18.140.",
+        explanation: "Rent increases require at least 90 days' written notice \
+                      (RCW 59.18.140(3)).",
+    },
+    ModelClause {
+        citation: "RCW 59.18.280",
+        anchors: &[],
+        clause: "Within 21 days after termination of this Lease and vacation of the \
+                 Premises, Landlord shall give Tenant a full and specific statement of the \
+                 basis for retaining any of the deposit, together with the balance of the \
+                 deposit.",
+        explanation: "The deposit, or a statement of deductions, must be delivered within 21 \
+                      days of move-out (RCW 59.18.280).",
+    },
+    ModelClause {
+        citation: "N.C.G.S. § 42-51",
+        anchors: &["deposit", "security"],
+        clause: "Tenant shall pay a security deposit of {{security_deposit}}, which does not \
+                 exceed the maximum permitted by § 42-51, North Carolina General Statutes, \
+                 for the term of this tenancy.",
+        explanation: "Deposits are capped at two weeks' rent for week-to-week tenancies, one \
+                      and a half months' rent for month-to-month tenancies, and two months' \
+                      rent for longer terms (§ 42-51).",
+    },
+    ModelClause {
+        citation: "N.C.G.S. § 42-52",
+        anchors: &[],
+        clause: "Within 30 days after termination of the tenancy and delivery of possession, \
+                 Landlord shall deliver to Tenant the balance of the security deposit with \
+                 an itemized list of any damages charged against it.",
+        explanation: "The deposit, with an itemized list of damages, must be refunded within \
+                      30 days of the end of the tenancy (§ 42-52).",
+    },
+];
+
+/// Outcome of looking for a fix to a violation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Suggestion {
+    /// A model clause covers the violated statute
+    ModelClause(Remediation),
+    /// No model clause covers the violated statute
+    NoModelClause,
+}
+
+impl Suggestion {
+    pub fn into_remediation(self) -> Option<Remediation> {
+        match self {
+            Suggestion::ModelClause(remediation) => Some(remediation),
+            Suggestion::NoModelClause => None,
+        }
+    }
+}
+
+/// Model clause for a statute, if one exists
+pub fn model_clause(statute: &str) -> Option<&'static ModelClause> {
+    MODEL_CLAUSES
+        .iter()
+        .filter(|clause| cites(statute, clause.citation))
+        .max_by_key(|clause| clause.citation.len())
+}
+
+/// Suggest a remediation for a violation found in `text`
+pub fn suggest(violation: &Violation, text: &str) -> Suggestion {
+    let Some(model) = model_clause(&violation.statute) else {
+        return Suggestion::NoModelClause;
+    };
+    let replace = if model.anchors.is_empty() {
+        None
+    } else {
+        offending_sentence(text, violation.text_position, model.anchors)
+    };
+    Suggestion::ModelClause(Remediation {
+        clause: model.clause.to_string(),
+        variables: variables(model.clause),
+        replace,
+        explanation: model.explanation.to_string(),
+    })
+}
+
+/// Attach remediations to violations that have none
+pub fn attach(violations: &mut [Violation], text: &str) {
+    for violation in violations.iter_mut() {
+        if violation.remediation.is_none() {
+            violation.remediation = suggest(violation, text).into_remediation();
+        }
+    }
+}
+
+/// Apply accepted remediations to the text they were suggested for
+///
+/// Clauses with a span replace it; the rest are appended as new paragraphs.
+/// `values` fills the clauses' `{{variable}}` placeholders. Violations
+/// without a remediation are ignored, and identical edits are applied once.
+pub fn apply_remediations(
+    text: &str,
+    accepted: &[Violation],
+    values: &HashMap<String, String>,
+) -> Result<String, RemediationError> {
+    apply(text, accepted, values)
+}
+
+/// Apply accepted remediations from a [`ComplianceEngine::check_compliance`]
+/// report to the document's pages
+///
+/// Report spans are page-local, so each replacement is applied to the page
+/// its violation is on; clauses without a span are appended to the last
+/// page. A replacement whose violation has no page is an invalid span.
+///
+/// [`ComplianceEngine::check_compliance`]: crate::ComplianceEngine::check_compliance
+pub fn apply_page_remediations(
+    pages: &[String],
+    accepted: &[Violation],
+    values: &HashMap<String, String>,
+) -> Result<Vec<String>, RemediationError> {
+    let replaces = |violation: &Violation| {
+        violation
+            .remediation
+            .as_ref()
+            .is_some_and(|remediation| remediation.replace.is_some())
+    };
+    let out_of_range = accepted.iter().filter(|v| replaces(v)).find(|v| {
+        v.page
+            .is_none_or(|page| page == 0 || page as usize > pages.len())
+    });
+    if let Some(violation) = out_of_range {
+        let span = violation.remediation.as_ref().and_then(|r| r.replace);
+        let span = span.unwrap_or_default();
+        return Err(RemediationError::InvalidSpan {
+            statute: violation.statute.clone(),
+            start: span.start_offset,
+            end: span.end_offset,
+        });
+    }
+
+    let mut fixed = Vec::with_capacity(pages.len());
+    for (index, page) in pages.iter().enumerate() {
+        let number = Some(index as u32 + 1);
+        let on_page = accepted.iter().filter(|v| replaces(v) && v.page == number);
+        fixed.push(apply(page, on_page, values)?);
+    }
+    if let Some(last) = fixed.last_mut() {
+        let additions = accepted
+            .iter()
+            .filter(|v| v.remediation.is_some() && !replaces(v));
+        *last = apply(last, additions, values)?;
+    }
+    Ok(fixed)
+}
+
+fn apply<'a>(
+    text: &str,
+    accepted: impl IntoIterator<Item = &'a Violation>,
+    values: &HashMap<String, String>,
+) -> Result<String, RemediationError> {
+    let mut replacements: Vec<(TextPosition, String, &str)> = Vec::new();
+    let mut additions: Vec<String> = Vec::new();
+
+    for violation in accepted {
+        let Some(remediation) = &violation.remediation else {
+            continue;
+        };
+        let clause = fill(remediation, &violation.statute, values)?;
+        match remediation.replace {
+            Some(span) => {
+                let valid = span.start_offset <= span.end_offset
+                    && text.is_char_boundary(span.start_offset)
+                    && text.is_char_boundary(span.end_offset)
+                    && span.end_offset <= text.len();
+                if !valid {
+                    return Err(RemediationError::InvalidSpan {
+                        statute: violation.statute.clone(),
+                        start: span.start_offset,
+                        end: span.end_offset,
+                    });
+                }
+                if !replacements
+                    .iter()
+                    .any(|(s, c, _)| *s == span && *c == clause)
+                {
+                    replacements.push((span, clause, &violation.statute));
+                }
+            }
+            None => {
+                if !additions.contains(&clause) {
+                    additions.push(clause);
+                }
+            }
+        }
+    }
+
+    replacements.sort_by_key(|(span, _, _)| span.start_offset);
+    for pair in replacements.windows(2) {
+        if pair[1].0.start_offset < pair[0].0.end_offset {
+            return Err(RemediationError::Overlap {
+                first: pair[0].2.to_string(),
+                second: pair[1].2.to_string(),
+            });
+        }
+    }
+
+    let mut result = text.to_string();
+    for (span, clause, _) in replacements.iter().rev() {
+        result.replace_range(span.start_offset..span.end_offset, clause);
+    }
+    for clause in additions {
+        if !result.is_empty() {
+            result.push_str(if result.ends_with('\n') { "\n" } else { "\n\n" });
+        }
+        result.push_str(&clause);
+    }
+    Ok(result)
+}
+
+/// Patch the Typst source of a generated document with accepted remediations
+///
+/// Spans refer to the rendered text, not the template, so every clause is
+/// added in a "Compliance Addendum" on a new page that controls over
+/// conflicting provisions.
+pub fn patch_typst_template(
+    source: &str,
+    accepted: &[Violation],
+    values: &HashMap<String, String>,
+) -> Result<String, RemediationError> {
+    let mut items: Vec<String> = Vec::new();
+    for violation in accepted {
+        let Some(remediation) = &violation.remediation else {
+            continue;
+        };
+        let clause = fill(remediation, &violation.statute, values)?;
+        let item = format!(
+            "+ *{}.* {}",
+            escape_typst(&violation.statute),
+            escape_typst(&clause)
+        );
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+    if items.is_empty() {
+        return Ok(source.to_string());
+    }
+
+    let mut patched = source.trim_end().to_string();
+    patched.push_str("\n\n#pagebreak()\n\n= Compliance Addendum\n\n");
+    patched.push_str(
+        "The following provisions are part of this agreement and control over any \
+         conflicting provision.\n\n",
+    );
+    patched.push_str(&items.join("\n"));
+    patched.push('\n');
+    Ok(patched)
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// `{{name}}` placeholders in a clause, in order of first use
+fn variables(clause: &str) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    let mut rest = clause;
+    while let Some(open) = rest.find("{{") {
+        let Some(close) = rest[open..].find("}}") else {
+            break;
+        };
+        let name = rest[open + 2..open + close].trim().to_string();
+        if !found.contains(&name) {
+            found.push(name);
+        }
+        rest = &rest[open + close + 2..];
+    }
+    found
+}
+
+fn fill(
+    remediation: &Remediation,
+    statute: &str,
+    values: &HashMap<String, String>,
+) -> Result<String, RemediationError> {
+    let mut clause = remediation.clause.clone();
+    for variable in &remediation.variables {
+        let value = values
+            .get(variable)
+            .ok_or_else(|| RemediationError::MissingVariable {
+                statute: statute.to_string(),
+                variable: variable.clone(),
+            })?;
+        clause = clause.replace(&format!("{{{{{}}}}}", variable), value);
+    }
+    Ok(clause)
+}
+
+/// Span of the sentence holding the first anchor word, searched within the
+/// violation's position when it has one
+fn offending_sentence(
+    text: &str,
+    position: Option<TextPosition>,
+    anchors: &[&str],
+) -> Option<TextPosition> {
+    let lower = text.to_lowercase();
+    if lower.len() != text.len() {
+        // Lowercasing changed byte offsets; anchors cannot be mapped back
+        return position.map(|p| sentence_at(text, p.start_offset));
+    }
+    let (from, to) = match position {
+        Some(p) if p.start_offset <= p.end_offset && p.end_offset <= text.len() => {
+            (p.start_offset, p.end_offset)
+        }
+        _ => (0, text.len()),
+    };
+    let window = lower.get(from..to)?;
+    anchors
+        .iter()
+        .filter_map(|anchor| window.find(anchor))
+        .min()
+        .map(|at| sentence_at(text, from + at))
+        .or_else(|| position.map(|p| sentence_at(text, p.start_offset)))
+}
+
+/// Span of the sentence containing byte offset `at`
+fn sentence_at(text: &str, at: usize) -> TextPosition {
+    let bytes = text.as_bytes();
+    let at = at.min(text.len());
+    let ends_sentence = |i: usize| match bytes[i] {
+        b'\n' => true,
+        b'.' | b'!' | b'?' => bytes.get(i + 1).is_none_or(|b| b.is_ascii_whitespace()),
+        _ => false,
+    };
+
+    let mut start = (0..at)
+        .rev()
+        .find(|&i| ends_sentence(i))
+        .map_or(0, |i| i + 1);
+    while start < at && bytes[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    let end = (at..bytes.len())
+        .find(|&i| ends_sentence(i))
+        .map_or(text.len(), |i| if bytes[i] == b'\n' { i } else { i + 1 });
+
+    TextPosition::new(start, end)
+}
+
+fn escape_typst(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '#' | '*' | '_' | '`' | '$' | '<' | '>' | '@' | '[' | ']' | '~' | '/'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::terms::LeaseTerms;
+    use crate::states::{florida, texas};
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_replaces_offending_sentence() {
+        let text = "Rent is $1,500.00 per month. Tenant waives all rights under Florida law. \
+                    Pets are not allowed.";
+        let mut violations = florida::check_florida_compliance(text, &LeaseTerms::extract(text));
+        attach(&mut violations, text);
+
+        let waiver = violations
+            .iter()
+            .find(|v| v.statute == "F.S. § 83.47(1)(a)")
+            .unwrap();
+        let remediation = waiver.remediation.as_ref().unwrap();
+        let span = remediation.replace.unwrap();
+        assert_eq!(
+            &text[span.start_offset..span.end_offset],
+            "Tenant waives all rights under Florida law."
+        );
+
+        let fixed =
+            apply_remediations(text, std::slice::from_ref(waiver), &HashMap::new()).unwrap();
+        assert!(fixed.starts_with("Rent is $1,500.00 per month. Tenant retains all rights"));
+        assert!(fixed.ends_with("Pets are not allowed."));
+        assert!(
+            florida::check_florida_compliance(&fixed, &LeaseTerms::extract(&fixed))
+                .iter()
+                .all(|v| v.statute != "F.S. § 83.47(1)(a)")
+        );
+    }
+
+    #[test]
+    fn test_missing_clause_is_appended_with_variables() {
+        let text = "Application fee of $50 required for background check.";
+        let mut violations = texas::check_texas_compliance(text);
+        attach(&mut violations, text);
+
+        let screening: Vec<_> = violations
+            .into_iter()
+            .filter(|v| v.statute.contains("92.3515"))
+            .collect();
+        assert_eq!(screening[0].remediation.as_ref().unwrap().replace, None);
+
+        let fixed = apply_remediations(text, &screening, &HashMap::new()).unwrap();
+        assert!(fixed.starts_with(text));
+        assert!(fixed.contains("Notice of Selection Criteria"));
+        assert!(texas::check_texas_compliance(&fixed)
+            .iter()
+            .all(|v| !v.statute.contains("92.3515")));
+    }
+
+    #[test]
+    fn test_variables_must_be_filled() {
+        let mut violations = vec![Violation {
+            statute: "F.S. § 83.49(2)".to_string(),
+            severity: shared_types::Severity::Warning,
+            message: "m".to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        }];
+        attach(&mut violations, "Security deposit: $1,000.");
+        let remediation = violations[0].remediation.as_ref().unwrap();
+        assert_eq!(
+            remediation.variables,
+            vec!["account_type", "bank_name", "bank_address"]
+        );
+
+        let err = apply_remediations("", &violations, &HashMap::new()).unwrap_err();
+        assert!(
+            matches!(err, RemediationError::MissingVariable { ref variable, .. }
+            if variable == "account_type")
+        );
+
+        let filled = apply_remediations(
+            "",
+            &violations,
+            &values(&[
+                ("account_type", "non-interest-bearing"),
+                ("bank_name", "First National Bank"),
+                ("bank_address", "100 Main St, Miami"),
+            ]),
+        )
+        .unwrap();
+        assert!(filled.starts_with("The security deposit is held in a non-interest-bearing"));
+        assert!(!filled.contains("{{"));
+    }
+
+    #[test]
+    fn test_overlapping_replacements_rejected() {
+        let text = "Tenant waives jury trial and repair rights.";
+        let remediation = |clause: &str, start| Remediation {
+            clause: clause.to_string(),
+            variables: Vec::new(),
+            replace: Some(TextPosition::new(start, text.len())),
+            explanation: String::new(),
+        };
+        let violation = |statute: &str, r| Violation {
+            statute: statute.to_string(),
+            severity: shared_types::Severity::Critical,
+            message: String::new(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: Some(r),
+            bounding_boxes: Vec::new(),
+        };
+        let accepted = vec![
+            violation("A", remediation("a", 0)),
+            violation("B", remediation("b", 7)),
+        ];
+        assert_eq!(
+            apply_remediations(text, &accepted, &HashMap::new()),
+            Err(RemediationError::Overlap {
+                first: "A".to_string(),
+                second: "B".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_page_remediations_apply_per_page() {
+        let pages = vec![
+            "Rent is due monthly.".to_string(),
+            "Tenant waives notice.".to_string(),
+        ];
+        let violation = |page, replace: Option<TextPosition>| Violation {
+            statute: "S".to_string(),
+            severity: shared_types::Severity::Critical,
+            message: String::new(),
+            page,
+            text_snippet: None,
+            text_position: None,
+            remediation: Some(Remediation {
+                clause: if replace.is_some() {
+                    "Fixed."
+                } else {
+                    "Added."
+                }
+                .to_string(),
+                variables: Vec::new(),
+                replace,
+                explanation: String::new(),
+            }),
+            bounding_boxes: Vec::new(),
+        };
+        let accepted = vec![
+            violation(Some(2), Some(TextPosition::new(0, pages[1].len()))),
+            violation(None, None),
+        ];
+
+        let fixed = apply_page_remediations(&pages, &accepted, &HashMap::new()).unwrap();
+        assert_eq!(fixed, vec!["Rent is due monthly.", "Fixed.\n\nAdded."]);
+
+        // Page-local spans cannot be applied without their page
+        let unplaced = vec![violation(Some(3), Some(TextPosition::new(0, 4)))];
+        assert!(matches!(
+            apply_page_remediations(&pages, &unplaced, &HashMap::new()),
+            Err(RemediationError::InvalidSpan { .. })
+        ));
+    }
+
+    #[test]
+    fn test_typst_patch_appends_escaped_addendum() {
+        let text = "Application fee of $50 required for background check.";
+        let mut violations = texas::check_texas_compliance(text);
+        attach(&mut violations, text);
+
+        let source = "#let data = sys.inputs\n= Residential Lease\n";
+        let patched = patch_typst_template(source, &violations, &HashMap::new()).unwrap();
+        assert!(patched.starts_with(source.trim_end()));
+        assert!(patched.contains("= Compliance Addendum"));
+        assert!(patched.contains("+ *Tex. Prop. Code § 92.3515.*"));
+
+        assert_eq!(escape_typst("$50 #1 @x"), "\\$50 \\#1 \\@x");
+        assert_eq!(
+            patch_typst_template(source, &[], &HashMap::new()).unwrap(),
+            source
+        );
+    }
+
+    #[test]
+    fn test_other_states_have_model_clauses() {
+        use crate::jurisdiction::State;
+        use crate::states::check_state_compliance;
+
+        let cases = [
+            (
+                State::NY,
+                "Tenant agrees to confess judgment for unpaid rent.",
+                "NY CPLR § 3218",
+            ),
+            (
+                State::IL,
+                "Tenant waives the right to a jury trial.",
+                "765 ILCS 705/1.5",
+            ),
+            (
+                State::CA,
+                "Monthly rent: $2,000. Security deposit: $5,000.",
+                "CA Civil Code § 1950.5 (AB 12)",
+            ),
+            (
+                State::MI,
+                "Monthly rent: $1,000. Security deposit: $2,500.",
+                "M.C.L. 554.602",
+            ),
+        ];
+        for (state, text, statute) in cases {
+            let mut violations = check_state_compliance(state, text, &LeaseTerms::extract(text));
+            attach(&mut violations, text);
+            let violation = violations
+                .iter()
+                .find(|v| v.statute == statute)
+                .unwrap_or_else(|| panic!("{:?}: no {} in {:?}", state, statute, violations));
+            assert_eq!(
+                violation.remediation.as_ref().map(|r| r.clause.as_str()),
+                Some(model_clause(statute).unwrap().clause),
+                "{}",
+                statute
+            );
+        }
+    }
+
+    #[test]
+    fn test_uncovered_statute_has_no_model_clause() {
+        let violation = Violation {
+            statute: "T.C.A. § 66-28-505".to_string(),
+            severity: shared_types::Severity::Warning,
+            message: "m".to_string(),
+            page: None,
+            text_snippet: None,
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        };
+        assert_eq!(suggest(&violation, ""), Suggestion::NoModelClause);
+
+        let mut violations = vec![violation];
+        attach(&mut violations, "");
+        assert_eq!(violations[0].remediation, None);
+    }
+
+    #[test]
+    fn test_sentence_boundaries() {
+        let text = "First. Deposit of $1,000.50 is due.\nSecond line";
+        let span = sentence_at(text, 10);
+        assert_eq!(
+            &text[span.start_offset..span.end_offset],
+            "Deposit of $1,000.50 is due."
+        );
+        let span = sentence_at(text, text.len() - 2);
+        assert_eq!(&text[span.start_offset..span.end_offset], "Second line");
+    }
+}
@@ -0,0 +1,166 @@
+//! Static HTML report
+//!
+//! One self-contained page with inline styles, suitable for emailing or
+//! printing. Everything taken from the document is escaped.
+
+use super::{brand_color, ReportEntry, ReportView};
+use shared_types::Severity;
+use std::fmt::Write;
+
+/// Render a report as a standalone HTML page
+pub fn render(view: &ReportView) -> String {
+    let mut html = String::new();
+    let color = brand_color(&view.brand_color);
+
+    let _ = write!(
+        html,
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; color: #1f2937; max-width: 860px; margin: 2rem auto; padding: 0 1rem; }}
+header {{ border-bottom: 4px solid {color}; padding-bottom: 0.75rem; margin-bottom: 1.5rem; }}
+header .brand {{ color: {color}; font-weight: 700; letter-spacing: 0.04em; text-transform: uppercase; font-size: 0.8rem; }}
+h1 {{ margin: 0.25rem 0; }}
+.meta {{ color: #6b7280; font-size: 0.9rem; }}
+table.summary {{ border-collapse: collapse; margin: 1rem 0 2rem; }}
+table.summary th, table.summary td {{ border: 1px solid #d1d5db; padding: 0.4rem 1rem; text-align: left; }}
+section.violation {{ border: 1px solid #e5e7eb; border-left: 6px solid #9ca3af; border-radius: 4px; padding: 0.75rem 1rem; margin-bottom: 1rem; page-break-inside: avoid; }}
+section.critical {{ border-left-color: #dc2626; }}
+section.warning {{ border-left-color: #d97706; }}
+section.info {{ border-left-color: #2563eb; }}
+.badge {{ font-size: 0.75rem; font-weight: 700; text-transform: uppercase; }}
+.rule {{ font-family: monospace; color: #6b7280; font-size: 0.8rem; }}
+blockquote {{ background: #f9fafb; border-left: 3px solid #d1d5db; margin: 0.5rem 0; padding: 0.5rem 0.75rem; white-space: pre-wrap; }}
+</style>
+</head>
+<body>
+<header>
+<div class="brand">{brand}</div>
+<h1>{title}</h1>
+<div class="meta">{document} &middot; checked {checked_at} &middot; {coverage} &middot; {local_coverage}</div>
+</header>
+"#,
+        title = escape(&view.title),
+        brand = escape(&view.brand_name),
+        document = escape(view.document_name.as_deref().unwrap_or(&view.document_id)),
+        checked_at = escape(&view.checked_at),
+        coverage = escape(&view.coverage),
+        local_coverage = escape(&view.local_coverage),
+    );
+
+    let _ = write!(
+        html,
+        r#"<table class="summary">
+<tr><th>Critical</th><th>Warning</th><th>Info</th><th>Total</th></tr>
+<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>
+</table>
+"#,
+        view.summary.critical, view.summary.warning, view.summary.info, view.summary.total
+    );
+
+    if view.entries.is_empty() {
+        html.push_str("<p>No violations found.</p>\n");
+    }
+    for entry in &view.entries {
+        render_entry(&mut html, entry);
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn render_entry(html: &mut String, entry: &ReportEntry) {
+    let class = severity_class(entry.severity);
+    let statute = match &entry.statute_url {
+        Some(url) => format!(
+            r#"<a href="{}">{}</a>"#,
+            escape(url),
+            escape(&entry.statute)
+        ),
+        None => escape(&entry.statute),
+    };
+    let page = entry
+        .page
+        .map(|p| format!(" &middot; page {}", p))
+        .unwrap_or_default();
+
+    let _ = writeln!(
+        html,
+        r#"<section class="violation {class}" id="{rule}-{short}">
+<div><span class="badge">{class}</span> &middot; {statute}{page}</div>
+<p>{message}</p>"#,
+        rule = escape(&entry.rule_id),
+        short = &entry.fingerprint[..entry.fingerprint.len().min(8)],
+        message = escape(&entry.message),
+    );
+    if let Some(excerpt) = &entry.excerpt {
+        let _ = writeln!(html, "<blockquote>{}</blockquote>", escape(excerpt));
+    }
+    if let Some(clause) = &entry.suggested_clause {
+        let _ = writeln!(
+            html,
+            "<p><strong>Suggested clause:</strong></p>\n<blockquote>{}</blockquote>",
+            escape(clause)
+        );
+    } else if entry.no_model_clause {
+        let _ = writeln!(
+            html,
+            "<p><em>No model clause covers this statute; the fix must be drafted by hand.</em></p>"
+        );
+    }
+    let _ = writeln!(
+        html,
+        r#"<div class="rule">{} &middot; {}</div>
+</section>"#,
+        escape(&entry.rule_id),
+        escape(&entry.fingerprint)
+    );
+}
+
+fn severity_class(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::sample;
+    use crate::report::ReportOptions;
+
+    #[test]
+    fn test_render_html() {
+        let (report, document) = sample();
+        let view = ReportView::new(&report, Some(&document), &ReportOptions::default());
+        let html = render(&view);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td>1</td><td>1</td><td>0</td><td>2</td>"));
+        assert!(html.contains("Sections/0083.47.html\">F.S. § 83.47(1)(a)</a> &middot; page 2"));
+        assert!(html.contains("<blockquote>waives all rights to notice.</blockquote>"));
+        // Message text is escaped
+        assert!(html.contains("Missing lead paint disclosure &lt;pre-1978&gt;"));
+        assert!(!html.contains("<pre-1978>"));
+    }
+}
@@ -0,0 +1,534 @@
+//! Compliance report export
+//!
+//! A [`ComplianceReport`] is turned into a [`ReportView`] once: violations
+//! get a stable rule ID, a fingerprint, a statute link where one is known and
+//! the excerpt of the page they were found on. The view is then rendered:
+//!
+//! - [`html::render`] - static, printable HTML
+//! - [`sarif::render`] - SARIF-style JSON for CI, with baseline suppression
+//! - [`typst_inputs`] - inputs for typst-engine's `compliance_report`
+//!   template, which renders the branded PDF
+
+pub mod html;
+pub mod sarif;
+
+use crate::remediation;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared_types::{ComplianceReport, Coverage, LeaseDocument, LocalCoverage, Severity, Violation};
+use std::collections::HashMap;
+
+/// Longest excerpt quoted per violation, in characters
+const MAX_EXCERPT_CHARS: usize = 300;
+
+/// Brand color used when none, or an invalid one, is given
+const DEFAULT_BRAND_COLOR: &str = "#1e40af";
+
+/// Branding and title for rendered reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportOptions {
+    pub title: String,
+    pub brand_name: String,
+    /// Hex color, e.g. `#1e40af`; anything else renders in the default
+    pub brand_color: String,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            title: "Lease Compliance Report".to_string(),
+            brand_name: "agentPDF".to_string(),
+            brand_color: DEFAULT_BRAND_COLOR.to_string(),
+        }
+    }
+}
+
+/// A report prepared for rendering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportView {
+    pub title: String,
+    pub brand_name: String,
+    pub brand_color: String,
+    pub document_id: String,
+    /// File name, when the document was supplied
+    pub document_name: Option<String>,
+    /// Check time as an RFC 3339 timestamp
+    pub checked_at: String,
+    pub coverage: String,
+    /// Whether city and county ordinances were checked
+    #[serde(default)]
+    pub local_coverage: String,
+    pub summary: Summary,
+    pub entries: Vec<ReportEntry>,
+}
+
+/// Violation counts by severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Summary {
+    pub critical: usize,
+    pub warning: usize,
+    pub info: usize,
+    pub total: usize,
+}
+
+/// One violation prepared for rendering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportEntry {
+    /// Stable ID derived from the statute, see [`rule_id`]
+    pub rule_id: String,
+    /// Stable across runs while the offending text is unchanged, see [`fingerprint`]
+    pub fingerprint: String,
+    pub statute: String,
+    pub statute_url: Option<String>,
+    pub severity: Severity,
+    pub message: String,
+    /// 1-based page number
+    pub page: Option<u32>,
+    /// Page-local byte offsets of the offending text
+    pub start_offset: Option<usize>,
+    pub end_offset: Option<usize>,
+    pub excerpt: Option<String>,
+    /// Model clause suggested as a fix
+    pub suggested_clause: Option<String>,
+    /// No model clause covers the statute, so a fix must be drafted by hand
+    #[serde(default)]
+    pub no_model_clause: bool,
+}
+
+impl ReportView {
+    /// Prepare a report; with the checked document, entries quote the text
+    /// they were found in
+    pub fn new(
+        report: &ComplianceReport,
+        document: Option<&LeaseDocument>,
+        options: &ReportOptions,
+    ) -> Self {
+        let mut entries: Vec<ReportEntry> = report
+            .violations
+            .iter()
+            .map(|violation| entry(violation, document))
+            .collect();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for entry in &mut entries {
+            let occurrence = seen.entry(entry.fingerprint.clone()).or_default();
+            if *occurrence > 0 {
+                entry.fingerprint = repeated(&entry.fingerprint, *occurrence);
+            }
+            *occurrence += 1;
+        }
+        let count = |severity: Severity| entries.iter().filter(|e| e.severity == severity).count();
+
+        Self {
+            title: options.title.clone(),
+            brand_name: options.brand_name.clone(),
+            brand_color: brand_color(&options.brand_color).to_string(),
+            document_id: report.document_id.clone(),
+            document_name: document.map(|d| d.filename.clone()),
+            checked_at: chrono::DateTime::from_timestamp(report.checked_at as i64, 0)
+                .unwrap_or_default()
+                .to_rfc3339(),
+            coverage: coverage_label(&report.coverage),
+            local_coverage: local_coverage_label(report.local_coverage).to_string(),
+            summary: Summary {
+                critical: count(Severity::Critical),
+                warning: count(Severity::Warning),
+                info: count(Severity::Info),
+                total: entries.len(),
+            },
+            entries,
+        }
+    }
+}
+
+/// Stable rule ID for a statute: lowercase, punctuation collapsed to `-`,
+/// decimal points kept. "F.S. § 83.47(1)(a)" is `fs-83.47-1-a`.
+pub fn rule_id(statute: &str) -> String {
+    let chars: Vec<char> = statute.chars().collect();
+    let mut id = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c.to_ascii_lowercase());
+        } else if c == '.'
+            && i > 0
+            && chars[i - 1].is_ascii_digit()
+            && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit())
+        {
+            id.push('.');
+        } else if c != '.' && !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+    id.trim_end_matches('-').to_string()
+}
+
+/// Fingerprint of a violation: SHA-256 over the rule ID and the offending
+/// text (or the message, when there is no text), lowercased with whitespace
+/// collapsed. It does not change when the clause moves to another page.
+///
+/// Identical violations share a fingerprint; [`ReportView::new`] tells them
+/// apart by their order in the report.
+pub fn fingerprint(rule_id: &str, excerpt: Option<&str>, message: &str) -> String {
+    let normalized = excerpt
+        .unwrap_or(message)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    digest(&[rule_id.as_bytes(), normalized.as_bytes()])
+}
+
+/// Fingerprint of the `occurrence`th repeat (1-based) of a violation
+fn repeated(fingerprint: &str, occurrence: usize) -> String {
+    digest(&[fingerprint.as_bytes(), occurrence.to_string().as_bytes()])
+}
+
+fn digest(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            hasher.update([0]);
+        }
+        hasher.update(part);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .take(16)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Whether a color is `#` followed by 3, 4, 6 or 8 hex digits, the forms
+/// both CSS and Typst's `rgb` accept
+pub fn is_hex_color(color: &str) -> bool {
+    color.strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+/// The brand color if it is a hex color, otherwise the default
+fn brand_color(color: &str) -> &str {
+    if is_hex_color(color) {
+        color
+    } else {
+        DEFAULT_BRAND_COLOR
+    }
+}
+
+/// Official text of a statute, for the codes with stable public URLs
+pub fn statute_url(statute: &str) -> Option<String> {
+    let section = |prefix: &str| -> Option<String> {
+        let rest = statute.strip_prefix(prefix)?.trim_start();
+        let rest = rest.strip_prefix('§').unwrap_or(rest).trim_start();
+        let section: String = rest
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '.' || *c == '-')
+            .collect();
+        let section = section.trim_end_matches('.').to_string();
+        (!section.is_empty()).then_some(section)
+    };
+
+    if let Some(s) = section("F.S.") {
+        let (chapter, _) = s.split_once('.')?;
+        let chapter: u32 = chapter.parse().ok()?;
+        let range = chapter / 100 * 100;
+        return Some(format!(
+            "http://www.leg.state.fl.us/statutes/index.cfm?App_mode=Display_Statute&URL={:04}-{:04}/{:04}/Sections/{:04}.{}.html",
+            range,
+            range + 99,
+            chapter,
+            chapter,
+            s.split_once('.')?.1
+        ));
+    }
+    if let Some(s) = section("Tex. Prop. Code") {
+        let (chapter, _) = s.split_once('.')?;
+        return Some(format!(
+            "https://statutes.capitol.texas.gov/Docs/PR/htm/PR.{}.htm#{}",
+            chapter, s
+        ));
+    }
+    if let Some(s) = section("CA Civil Code").or_else(|| section("Cal. Civ. Code")) {
+        return Some(format!(
+            "https://leginfo.legislature.ca.gov/faces/codes_displaySection.xhtml?lawCode=CIV&sectionNum={}",
+            s
+        ));
+    }
+    if let Some(s) = section("RCW") {
+        return Some(format!(
+            "https://app.leg.wa.gov/RCW/default.aspx?cite={}",
+            s
+        ));
+    }
+    let (title, rest) = statute.split_once(" U.S.C.")?;
+    let title: u32 = title.trim().parse().ok()?;
+    let rest = rest.trim_start().strip_prefix('§').unwrap_or(rest).trim();
+    let section: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+    (!section.is_empty()).then(|| {
+        format!(
+            "https://www.law.cornell.edu/uscode/text/{}/{}",
+            title, section
+        )
+    })
+}
+
+/// Inputs for typst-engine's `compliance_report` template
+pub fn typst_inputs(view: &ReportView) -> HashMap<String, serde_json::Value> {
+    match serde_json::to_value(view) {
+        Ok(serde_json::Value::Object(mut map)) => {
+            map.insert(
+                "brand_color".to_string(),
+                brand_color(&view.brand_color).into(),
+            );
+            map.into_iter().collect()
+        }
+        _ => HashMap::new(),
+    }
+}
+
+fn entry(violation: &Violation, document: Option<&LeaseDocument>) -> ReportEntry {
+    let rule_id = rule_id(&violation.statute);
+    let matched = document.and_then(|d| matched_text(violation, d));
+    let excerpt = matched
+        .clone()
+        .or_else(|| violation.text_snippet.clone())
+        .map(|text| truncate(text.trim()));
+
+    ReportEntry {
+        fingerprint: fingerprint(&rule_id, matched.as_deref(), &violation.message),
+        statute_url: statute_url(&violation.statute),
+        statute: violation.statute.clone(),
+        severity: violation.severity,
+        message: violation.message.clone(),
+        page: violation.page,
+        start_offset: violation.text_position.as_ref().map(|p| p.start_offset),
+        end_offset: violation.text_position.as_ref().map(|p| p.end_offset),
+        excerpt,
+        suggested_clause: violation.remediation.as_ref().map(|r| r.clause.clone()),
+        no_model_clause: violation.remediation.is_none()
+            && remediation::model_clause(&violation.statute).is_none(),
+        rule_id,
+    }
+}
+
+/// Text at a violation's page-local position
+fn matched_text(violation: &Violation, document: &LeaseDocument) -> Option<String> {
+    let index = violation.page?.checked_sub(1)?;
+    let page = document.text_content.get(index as usize)?;
+    let position = violation.text_position?;
+    page.get(position.start_offset..position.end_offset)
+        .map(str::to_string)
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_EXCERPT_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(MAX_EXCERPT_CHARS).collect();
+    format!("{}…", cut.trim_end())
+}
+
+fn coverage_label(coverage: &Coverage) -> String {
+    match coverage {
+        Coverage::Full => "Full statutory coverage".to_string(),
+        Coverage::Core => "Core statutory limits only".to_string(),
+        Coverage::Unavailable { reason } => format!("Not checked: {}", reason),
+    }
+}
+
+fn local_coverage_label(coverage: LocalCoverage) -> &'static str {
+    match coverage {
+        LocalCoverage::Checked => "City and county ordinances checked",
+        LocalCoverage::NoOrdinance => "No city or county ordinances apply",
+        LocalCoverage::Unknown => "Not checked: city and county unknown",
+        LocalCoverage::NotApplicable => "Not applicable to this document type",
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use shared_types::TextPosition;
+
+    pub(crate) fn sample() -> (ComplianceReport, LeaseDocument) {
+        let page = "The tenant waives all rights to notice.";
+        let document = LeaseDocument {
+            id: "lease-1".to_string(),
+            filename: "lease.pdf".to_string(),
+            pages: 2,
+            text_content: vec!["Cover page".to_string(), page.to_string()],
+            created_at: 0,
+            layout: Vec::new(),
+        };
+        let start = page.find("waives").unwrap();
+        let report = ComplianceReport {
+            document_id: "lease-1".to_string(),
+            violations: vec![
+                Violation {
+                    statute: "F.S. § 83.47(1)(a)".to_string(),
+                    severity: Severity::Critical,
+                    message: "Waiver of notice".to_string(),
+                    page: Some(2),
+                    text_snippet: None,
+                    text_position: Some(TextPosition::new(start, page.len())),
+                    remediation: None,
+                    bounding_boxes: Vec::new(),
+                },
+                Violation {
+                    statute: "42 U.S.C. § 4852d".to_string(),
+                    severity: Severity::Warning,
+                    message: "Missing lead paint disclosure <pre-1978>".to_string(),
+                    page: None,
+                    text_snippet: None,
+                    text_position: None,
+                    remediation: None,
+                    bounding_boxes: Vec::new(),
+                },
+            ],
+            checked_at: 1_750_000_000,
+            coverage: Coverage::Full,
+            track: shared_types::Track::Residential,
+            local_coverage: LocalCoverage::Unknown,
+        };
+        (report, document)
+    }
+
+    #[test]
+    fn test_rule_ids() {
+        assert_eq!(rule_id("F.S. § 83.47(1)(a)"), "fs-83.47-1-a");
+        assert_eq!(
+            rule_id("Tex. Prop. Code § 92.0081"),
+            "tex-prop-code-92.0081"
+        );
+        assert_eq!(rule_id("42 U.S.C. § 4852d"), "42-usc-4852d");
+        assert_eq!(rule_id("RCW 59.18.260"), "rcw-59.18.260");
+    }
+
+    #[test]
+    fn test_statute_urls() {
+        assert_eq!(
+            statute_url("F.S. § 83.49(3)(a)").unwrap(),
+            "http://www.leg.state.fl.us/statutes/index.cfm?App_mode=Display_Statute&URL=0000-0099/0083/Sections/0083.49.html"
+        );
+        assert_eq!(
+            statute_url("Tex. Prop. Code § 92.0081").unwrap(),
+            "https://statutes.capitol.texas.gov/Docs/PR/htm/PR.92.htm#92.0081"
+        );
+        assert_eq!(
+            statute_url("42 U.S.C. § 4852d").unwrap(),
+            "https://www.law.cornell.edu/uscode/text/42/4852d"
+        );
+        assert!(statute_url("RCW 59.18.260").is_some());
+        assert_eq!(statute_url("Chicago Mun. Code § 5-12-080"), None);
+    }
+
+    #[test]
+    fn test_view_quotes_page_text() {
+        let (report, document) = sample();
+        let view = ReportView::new(&report, Some(&document), &ReportOptions::default());
+
+        assert_eq!(
+            view.summary,
+            Summary {
+                critical: 1,
+                warning: 1,
+                info: 0,
+                total: 2
+            }
+        );
+        assert_eq!(view.document_name.as_deref(), Some("lease.pdf"));
+        assert_eq!(
+            view.entries[0].excerpt.as_deref(),
+            Some("waives all rights to notice.")
+        );
+        assert_eq!(view.entries[1].excerpt, None);
+        assert!(view.entries.iter().all(|e| !e.no_model_clause));
+
+        let mut uncovered = report.clone();
+        uncovered.violations[1].statute = "T.C.A. § 66-28-505".to_string();
+        let view = ReportView::new(&uncovered, Some(&document), &ReportOptions::default());
+        assert!(view.entries[1].no_model_clause);
+        assert!(view.checked_at.starts_with("2025-06-15"));
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_page_and_whitespace() {
+        let (mut report, mut document) = sample();
+        let before = ReportView::new(&report, Some(&document), &ReportOptions::default());
+
+        // Same clause, moved to page 1 and reflowed
+        document.text_content = vec!["The tenant waives all\n rights to   notice.".to_string()];
+        report.violations[0].page = Some(1);
+        report.violations[0].text_position = Some(TextPosition::new(11, 42));
+        let after = ReportView::new(&report, Some(&document), &ReportOptions::default());
+
+        assert_eq!(before.entries[0].fingerprint, after.entries[0].fingerprint);
+        assert_ne!(before.entries[0].fingerprint, before.entries[1].fingerprint);
+        assert_eq!(before.entries[0].fingerprint.len(), 32);
+    }
+
+    #[test]
+    fn test_identical_violations_get_distinct_fingerprints() {
+        let (mut report, document) = sample();
+        report.violations.push(report.violations[1].clone());
+        report.violations.push(report.violations[1].clone());
+        let view = ReportView::new(&report, Some(&document), &ReportOptions::default());
+
+        let fingerprints: std::collections::HashSet<_> = view
+            .entries
+            .iter()
+            .map(|e| e.fingerprint.as_str())
+            .collect();
+        assert_eq!(fingerprints.len(), 4);
+        // The first occurrence keeps its plain fingerprint
+        assert_eq!(
+            view.entries[1].fingerprint,
+            fingerprint("42-usc-4852d", None, &report.violations[1].message)
+        );
+    }
+
+    #[test]
+    fn test_page_zero_has_no_excerpt() {
+        let (mut report, document) = sample();
+        report.violations[0].page = Some(0);
+        let view = ReportView::new(&report, Some(&document), &ReportOptions::default());
+        assert_eq!(view.entries[0].excerpt, None);
+    }
+
+    #[test]
+    fn test_brand_color_must_be_hex() {
+        assert!(is_hex_color("#1e40af"));
+        assert!(is_hex_color("#FFF"));
+        assert!(is_hex_color("#1e40af80"));
+        assert!(!is_hex_color("1e40af"));
+        assert!(!is_hex_color("#12345"));
+        assert!(!is_hex_color("red; background: url(x)"));
+
+        let (report, document) = sample();
+        let options = ReportOptions {
+            brand_color: "red}</style><script>".to_string(),
+            ..ReportOptions::default()
+        };
+        let view = ReportView::new(&report, Some(&document), &options);
+        assert_eq!(view.brand_color, DEFAULT_BRAND_COLOR);
+    }
+
+    #[test]
+    fn test_typst_inputs() {
+        let (report, document) = sample();
+        let view = ReportView::new(&report, Some(&document), &ReportOptions::default());
+        let inputs = typst_inputs(&view);
+        assert_eq!(inputs["title"], "Lease Compliance Report");
+        assert_eq!(inputs["entries"].as_array().unwrap().len(), 2);
+
+        // A view built by hand is checked as well
+        let view = ReportView {
+            brand_color: "rgb(0, 0, 0)".to_string(),
+            ..view
+        };
+        assert_eq!(typst_inputs(&view)["brand_color"], DEFAULT_BRAND_COLOR);
+    }
+}
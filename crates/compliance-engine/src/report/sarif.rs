@@ -0,0 +1,228 @@
+//! SARIF-style machine report
+//!
+//! Output follows the shape of SARIF 2.1.0 so existing CI tooling can read
+//! it: one run, rules keyed by stable rule ID, and results carrying a
+//! fingerprint. Given a [`Baseline`] from an earlier run, results already
+//! present are marked `unchanged` and suppressed, so a build only fails on
+//! [`new_entries`].
+
+use super::{ReportEntry, ReportView};
+use serde_json::{json, Value};
+use shared_types::Severity;
+use std::collections::{BTreeMap, HashSet};
+
+/// Key of the violation fingerprint in `partialFingerprints`
+pub const FINGERPRINT_KEY: &str = "complianceFingerprint/v1";
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Fingerprints of violations accepted in an earlier run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    fingerprints: HashSet<String>,
+}
+
+impl Baseline {
+    pub fn from_fingerprints<I, S>(fingerprints: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            fingerprints: fingerprints.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Read the fingerprints of every result in an earlier SARIF report
+    pub fn from_sarif(json: &str) -> Result<Self, serde_json::Error> {
+        let sarif: Value = serde_json::from_str(json)?;
+        let fingerprints = sarif["runs"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|run| run["results"].as_array().into_iter().flatten())
+            .filter_map(|result| result["partialFingerprints"][FINGERPRINT_KEY].as_str())
+            .map(str::to_string)
+            .collect();
+        Ok(Self { fingerprints })
+    }
+
+    pub fn contains(&self, fingerprint: &str) -> bool {
+        self.fingerprints.contains(fingerprint)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fingerprints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+}
+
+/// Entries not in the baseline; CI fails when this is non-empty
+pub fn new_entries<'a>(view: &'a ReportView, baseline: &Baseline) -> Vec<&'a ReportEntry> {
+    view.entries
+        .iter()
+        .filter(|entry| !baseline.contains(&entry.fingerprint))
+        .collect()
+}
+
+/// Render a report as SARIF JSON
+pub fn render(view: &ReportView, baseline: Option<&Baseline>) -> Value {
+    let mut rules: BTreeMap<&str, &ReportEntry> = BTreeMap::new();
+    for entry in &view.entries {
+        rules.entry(entry.rule_id.as_str()).or_insert(entry);
+    }
+    let rule_index: BTreeMap<&str, usize> = rules
+        .keys()
+        .enumerate()
+        .map(|(index, id)| (*id, index))
+        .collect();
+
+    let rules: Vec<Value> = rules
+        .values()
+        .map(|entry| {
+            let mut rule = json!({
+                "id": entry.rule_id,
+                "name": entry.statute,
+                "shortDescription": { "text": entry.statute },
+                "defaultConfiguration": { "level": level(entry.severity) },
+            });
+            if let Some(url) = &entry.statute_url {
+                rule["helpUri"] = json!(url);
+            }
+            rule
+        })
+        .collect();
+
+    let results: Vec<Value> = view
+        .entries
+        .iter()
+        .map(|entry| {
+            let mut result = json!({
+                "ruleId": entry.rule_id,
+                "ruleIndex": rule_index[entry.rule_id.as_str()],
+                "level": level(entry.severity),
+                "message": { "text": entry.message },
+                "partialFingerprints": { FINGERPRINT_KEY: entry.fingerprint },
+                "locations": [location(view, entry)],
+            });
+            if let Some(baseline) = baseline {
+                let known = baseline.contains(&entry.fingerprint);
+                result["baselineState"] = json!(if known { "unchanged" } else { "new" });
+                if known {
+                    result["suppressions"] = json!([{
+                        "kind": "external",
+                        "justification": "Present in baseline",
+                    }]);
+                }
+            }
+            if let Some(page) = entry.page {
+                result["properties"] = json!({ "page": page });
+            }
+            result
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "compliance-engine",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "invocations": [{
+                "executionSuccessful": true,
+                "endTimeUtc": view.checked_at,
+            }],
+            "properties": {
+                "coverage": view.coverage,
+                "localCoverage": view.local_coverage,
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn location(view: &ReportView, entry: &ReportEntry) -> Value {
+    let uri = view.document_name.as_deref().unwrap_or(&view.document_id);
+    let mut physical = json!({ "artifactLocation": { "uri": uri } });
+    if let (Some(start), Some(end)) = (entry.start_offset, entry.end_offset) {
+        let mut region = json!({ "byteOffset": start, "byteLength": end - start });
+        if let Some(excerpt) = &entry.excerpt {
+            region["snippet"] = json!({ "text": excerpt });
+        }
+        physical["region"] = region;
+    }
+    json!({ "physicalLocation": physical })
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::sample;
+    use crate::report::ReportOptions;
+
+    fn view() -> ReportView {
+        let (report, document) = sample();
+        ReportView::new(&report, Some(&document), &ReportOptions::default())
+    }
+
+    #[test]
+    fn test_render_sarif() {
+        let view = view();
+        let sarif = render(&view, None);
+        let run = &sarif["runs"][0];
+
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "fs-83.47-1-a");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["properties"]["page"], 2);
+        assert_eq!(
+            result["partialFingerprints"][FINGERPRINT_KEY],
+            view.entries[0].fingerprint.as_str()
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "lease.pdf"
+        );
+        assert!(result.get("baselineState").is_none());
+        assert_eq!(run["results"][1]["level"], "warning");
+    }
+
+    #[test]
+    fn test_baseline_suppresses_known_violations() {
+        let view = view();
+        let previous = render(&view, None).to_string();
+        let baseline = Baseline::from_sarif(&previous).unwrap();
+        assert_eq!(baseline.len(), 2);
+        assert!(new_entries(&view, &baseline).is_empty());
+
+        let partial = Baseline::from_fingerprints([view.entries[0].fingerprint.clone()]);
+        let sarif = render(&view, Some(&partial));
+        let results = &sarif["runs"][0]["results"];
+        assert_eq!(results[0]["baselineState"], "unchanged");
+        assert_eq!(results[0]["suppressions"][0]["kind"], "external");
+        assert_eq!(results[1]["baselineState"], "new");
+        assert!(results[1].get("suppressions").is_none());
+
+        let new = new_entries(&view, &partial);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].rule_id, "42-usc-4852d");
+    }
+}
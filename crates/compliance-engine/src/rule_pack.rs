@@ -0,0 +1,1248 @@
+//! Declarative rule packs
+//!
+//! A rule pack describes a state's statutory checks as data instead of Rust,
+//! so rules can be maintained and versioned without a release. Packs are
+//! written in TOML or YAML and validated when loaded:
+//!
+//! ```toml
+//! [pack]
+//! id = "us-fl-residential"
+//! state = "FL"
+//! version = "2025.1.0"
+//! title = "Florida Residential Landlord and Tenant Act"
+//!
+//! [keywords]
+//! waiver = ["waive", "waiver", "relinquish"]
+//! notice = ["notice", "notify"]
+//!
+//! [[rules]]
+//! id = "waiver-of-notice"
+//! statute = "F.S. § 83.47(1)(a)"
+//! severity = "Critical"
+//! message = "Lease contains prohibited waiver of tenant's right to notice"
+//! when = { cluster = { groups = ["waiver", "notice"] } }
+//! ```
+//!
+//! A rule fires when its `when` condition holds (default: always) and its
+//! `unless` condition (typically a required clause) does not. `when` is
+//! matched against the operative text only (see [`crate::clauses`]), so a
+//! negated, quoted or recited clause does not trigger a rule. Conditions are
+//! either a plain phrase, matched case-insensitively, or one of:
+//!
+//! - `any` / `all` / `not` - combinators
+//! - `group` - any keyword from a named `[keywords]` group
+//! - `cluster` - keywords from at least `min` (default 2) groups, as
+//!   [`crate::patterns::contains_semantic_cluster`] checks
+//! - `raw` - case-sensitive substring, for formatting markers like `<b>`
+//! - `regex` - pattern matched against the lowercased text
+//! - `count` - a phrase occurring at least `min` times
+//! - `caps_near` - text around a group's first keyword is in capitals
+//!
+//! Rules may also carry a `numeric` bound on an extracted value (rendered as
+//! `{value}` in the message) or a list of `required` elements, firing when any
+//! is missing (rendered as `{missing}`).
+//!
+//! `effective_from` / `effective_to` (quoted `"YYYY-MM-DD"`, both inclusive)
+//! limit when a rule is in force; [`RulePack::check_as_of`] skips rules
+//! outside their range.
+//!
+//! Packs are loaded with [`RulePack::load`] or [`load_packs_from_dir`] and
+//! handed to [`ComplianceEngine::with_rule_pack`](crate::ComplianceEngine::with_rule_pack).
+//! The MCP server takes a directory through `--rule-packs`, and the web app
+//! registers packs through `load_rule_pack` in agentpdf-wasm.
+//!
+//! # Retiring the Rust rules
+//!
+//! `rule_packs/florida.toml` and `rule_packs/texas.toml` duplicate
+//! [`crate::states::florida`] and [`crate::states::texas`]. The parity tests
+//! below keep both in step until the Rust modules are removed:
+//!
+//! 1. Statute changes for FL and TX land in the pack first and in Rust in
+//!    the same change, so parity keeps holding.
+//! 2. `ComplianceEngine::new` switches to the bundled packs for FL and TX;
+//!    the Rust checks remain reachable only through the parity tests.
+//! 3. Once a release has shipped on the packs, the two modules, their
+//!    `states::check_state_compliance` arms and the parity tests are
+//!    deleted, and the remediation and language tests that call them move
+//!    to the packs.
+
+use crate::clauses;
+use crate::effective::{CoveredStatute, EffectiveRange};
+use crate::extractors::terms::LeaseTerms;
+use crate::jurisdiction::State;
+use crate::patterns::{
+    ceil_char_boundary, extract_snippet, find_text_position, floor_char_boundary,
+};
+use crate::states::texas::extract_context;
+use chrono::NaiveDate;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use shared_types::{Severity, TextPosition, Violation};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+/// Named value extractors available to `numeric` checks, read from
+/// [`LeaseTerms`]. Dollar amounts are rounded to whole dollars;
+/// `deposit_to_rent_percent` is the security deposit as a percentage of
+/// monthly rent, so "deposit ≤ 2× rent" is `above = 200`.
+pub const EXTRACTORS: &[&str] = &[
+    "deposit_return_days",
+    "monthly_rent",
+    "security_deposit",
+    "pet_deposit",
+    "deposit_to_rent_percent",
+    "late_fee_amount",
+    "late_fee_percent",
+    "grace_period_days",
+];
+
+/// Errors raised while loading or validating a rule pack
+#[derive(Debug, thiserror::Error)]
+pub enum RulePackError {
+    #[error("failed to read rule pack {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("invalid rule pack: {0}")]
+    Parse(String),
+    #[error("rule '{rule}': {message}")]
+    Invalid { rule: String, message: String },
+}
+
+/// A versioned set of rules for one state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulePack {
+    pub pack: PackInfo,
+    /// Named keyword groups referenced by `group`, `cluster` and `caps_near`
+    #[serde(default)]
+    pub keywords: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub rules: Vec<RuleSpec>,
+    #[serde(skip)]
+    regexes: HashMap<String, Regex>,
+}
+
+/// Pack identity and provenance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackInfo {
+    pub id: String,
+    pub state: State,
+    pub version: String,
+    #[serde(default)]
+    pub title: String,
+}
+
+/// A single declarative rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSpec {
+    pub id: String,
+    pub statute: String,
+    pub severity: Severity,
+    /// Message template; may use `{value}` or `{missing}`
+    pub message: String,
+    #[serde(default)]
+    pub when: Option<Condition>,
+    #[serde(default)]
+    pub unless: Option<Condition>,
+    #[serde(default)]
+    pub numeric: Option<NumericCheck>,
+    #[serde(default)]
+    pub required: Vec<RequiredElement>,
+    #[serde(default)]
+    pub snippet: Snippet,
+    #[serde(default)]
+    pub position: Position,
+    /// First day the rule is in force
+    #[serde(default)]
+    pub effective_from: Option<NaiveDate>,
+    /// Last day the rule is in force
+    #[serde(default)]
+    pub effective_to: Option<NaiveDate>,
+}
+
+impl RuleSpec {
+    pub fn effective_range(&self) -> EffectiveRange {
+        EffectiveRange::new(self.effective_from, self.effective_to)
+    }
+}
+
+/// A phrase or a structured condition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Condition {
+    Phrase(String),
+    Expr(Expr),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Expr {
+    Any(Vec<Condition>),
+    All(Vec<Condition>),
+    Not(Box<Condition>),
+    Group(String),
+    Cluster {
+        groups: Vec<String>,
+        #[serde(default = "default_cluster_min")]
+        min: usize,
+    },
+    Raw(String),
+    Regex(String),
+    Count {
+        phrase: String,
+        min: usize,
+    },
+    CapsNear(String),
+}
+
+fn default_cluster_min() -> usize {
+    2
+}
+
+/// Bound on a number extracted from the text.
+/// The rule fires for each value that is above, below or equal to the set bounds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NumericCheck {
+    /// Named extractor from [`EXTRACTORS`]
+    #[serde(default)]
+    pub extractor: Option<String>,
+    /// Patterns whose first capture group is the value
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Check every pattern's match instead of only the first pattern that matches
+    #[serde(default)]
+    pub each_pattern: bool,
+    #[serde(default)]
+    pub above: Option<i64>,
+    #[serde(default)]
+    pub below: Option<i64>,
+    #[serde(default)]
+    pub equals: Option<i64>,
+}
+
+/// An element that must be present, listed in `{missing}` when absent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequiredElement {
+    pub label: String,
+    pub when: Condition,
+}
+
+/// How the violation's text snippet is chosen
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Snippet {
+    #[default]
+    None,
+    /// Text matched by the numeric pattern
+    Match,
+    /// First N characters of the text
+    Prefix(usize),
+    /// The text, cut at N bytes with an ellipsis
+    Truncate(usize),
+    /// Context around a keyword
+    Keyword(String),
+    /// Context around the first of these keywords found
+    Keywords(Vec<String>),
+    /// Context around a keyword if present, otherwise the first N characters
+    KeywordOrPrefix { keyword: String, prefix: usize },
+    /// Context around the first of these keywords found, otherwise the first
+    /// N characters
+    KeywordsOrPrefix {
+        keywords: Vec<String>,
+        prefix: usize,
+    },
+    /// Context around the first keyword of a group
+    Context(String),
+}
+
+/// How the violation's highlight position is chosen
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Position {
+    #[default]
+    None,
+    /// Context window around the first of these keywords found
+    Keywords(Vec<String>),
+    /// Fixed-width span at the first keyword of a group
+    GroupStart { group: String, width: usize },
+}
+
+/// Text prepared once per check
+struct Context<'a> {
+    text: &'a str,
+    lower: String,
+    terms: &'a LeaseTerms,
+}
+
+impl<'a> Context<'a> {
+    fn new(text: &'a str, terms: &'a LeaseTerms) -> Self {
+        Self {
+            text,
+            lower: text.to_lowercase(),
+            terms,
+        }
+    }
+}
+
+impl RulePack {
+    /// Parse and validate a TOML rule pack
+    pub fn from_toml(source: &str) -> Result<Self, RulePackError> {
+        let pack: Self = toml::from_str(source).map_err(|e| RulePackError::Parse(e.to_string()))?;
+        pack.prepare()
+    }
+
+    /// Parse and validate a YAML rule pack
+    pub fn from_yaml(source: &str) -> Result<Self, RulePackError> {
+        let pack: Self =
+            serde_yaml::from_str(source).map_err(|e| RulePackError::Parse(e.to_string()))?;
+        pack.prepare()
+    }
+
+    /// Parse and validate a pack in the given format: `toml`, `yaml` or `yml`
+    pub fn parse(source: &str, format: &str) -> Result<Self, RulePackError> {
+        match format.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Self::from_yaml(source),
+            "toml" => Self::from_toml(source),
+            other => Err(RulePackError::Parse(format!(
+                "unknown rule pack format '{}': expected toml, yaml or yml",
+                other
+            ))),
+        }
+    }
+
+    /// Load a rule pack from a `.toml`, `.yaml` or `.yml` file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RulePackError> {
+        let path = path.as_ref();
+        let Some(format) = path.extension().and_then(|e| e.to_str()) else {
+            return Err(RulePackError::Parse(format!(
+                "{}: expected a .toml, .yaml or .yml file",
+                path.display()
+            )));
+        };
+        let source = std::fs::read_to_string(path).map_err(|source| RulePackError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Self::parse(&source, format).map_err(|e| match e {
+            RulePackError::Parse(message) => {
+                RulePackError::Parse(format!("{}: {}", path.display(), message))
+            }
+            other => other,
+        })
+    }
+
+    /// State this pack applies to
+    pub fn state(&self) -> State {
+        self.pack.state
+    }
+
+    /// Run every rule against the text, in pack order
+    pub fn check(&self, text: &str) -> Vec<Violation> {
+        let terms = LeaseTerms::extract(text);
+        let operative = clauses::operative_text(text);
+        let ctx = Context::new(text, &terms);
+        let trigger = Context::new(&operative, &terms);
+        self.rules
+            .iter()
+            .flat_map(|rule| self.evaluate(rule, &ctx, &trigger))
+            .collect()
+    }
+
+    /// Run the rules in force on `as_of` against the text, in pack order
+    pub fn check_as_of(&self, text: &str, as_of: NaiveDate) -> Vec<Violation> {
+        self.check_terms_as_of(text, &LeaseTerms::extract(text), as_of)
+    }
+
+    /// [`Self::check_as_of`] with terms the caller already extracted
+    pub fn check_terms_as_of(
+        &self,
+        text: &str,
+        terms: &LeaseTerms,
+        as_of: NaiveDate,
+    ) -> Vec<Violation> {
+        let operative = clauses::operative_text(text);
+        let ctx = Context::new(text, terms);
+        let trigger = Context::new(&operative, terms);
+        self.rules
+            .iter()
+            .filter(|rule| rule.effective_range().contains(as_of))
+            .flat_map(|rule| self.evaluate(rule, &ctx, &trigger))
+            .collect()
+    }
+
+    /// Statutes the pack checks, each with the widest range any of its rules
+    /// is in force
+    pub fn covered_statutes(&self) -> Vec<CoveredStatute> {
+        let mut covered: Vec<CoveredStatute> = Vec::new();
+        for rule in &self.rules {
+            let range = rule.effective_range();
+            match covered.iter_mut().find(|c| c.citation == rule.statute) {
+                Some(entry) => {
+                    let merged = &mut entry.range;
+                    merged.effective_from = merged.effective_from.min(range.effective_from);
+                    merged.effective_to = merged
+                        .effective_to
+                        .zip(range.effective_to)
+                        .map(|(a, b)| a.max(b));
+                }
+                None => covered.push(CoveredStatute {
+                    citation: rule.statute.clone(),
+                    range,
+                }),
+            }
+        }
+        covered
+    }
+
+    // ------------------------------------------------------------------------
+    // Loading
+    // ------------------------------------------------------------------------
+
+    /// Lowercase phrases and keywords, compile regexes and check references
+    fn prepare(mut self) -> Result<Self, RulePackError> {
+        for (name, keywords) in self.keywords.iter_mut() {
+            if keywords.is_empty() {
+                return Err(RulePackError::Parse(format!(
+                    "keyword group '{}' is empty",
+                    name
+                )));
+            }
+            for keyword in keywords.iter_mut() {
+                *keyword = keyword.to_lowercase();
+            }
+        }
+        // Keyword positions are found in the original text, so their offsets
+        // hold even where lowercasing changes a character's length
+        let keywords: Vec<String> = self.keywords.values().flatten().cloned().collect();
+        for keyword in keywords {
+            self.compile(&keyword_pattern(&keyword))
+                .map_err(RulePackError::Parse)?;
+        }
+
+        let mut rules = std::mem::take(&mut self.rules);
+        let mut ids = HashSet::new();
+        for rule in &mut rules {
+            if !ids.insert(rule.id.clone()) {
+                return Err(invalid(rule, "duplicate rule id"));
+            }
+            self.prepare_rule(rule)?;
+        }
+        self.rules = rules;
+        Ok(self)
+    }
+
+    fn prepare_rule(&mut self, rule: &mut RuleSpec) -> Result<(), RulePackError> {
+        if let (Some(from), Some(to)) = (rule.effective_from, rule.effective_to) {
+            if from > to {
+                return Err(invalid(rule, "effective_from is after effective_to"));
+            }
+        }
+
+        let mut conditions: Vec<&mut Condition> = Vec::new();
+        conditions.extend(rule.when.as_mut());
+        conditions.extend(rule.unless.as_mut());
+        conditions.extend(rule.required.iter_mut().map(|r| &mut r.when));
+        for condition in conditions {
+            self.prepare_condition(condition)
+                .map_err(|message| RulePackError::Invalid {
+                    rule: rule.id.clone(),
+                    message,
+                })?;
+        }
+
+        if let Some(numeric) = &rule.numeric {
+            match (&numeric.extractor, numeric.patterns.is_empty()) {
+                (Some(name), true) if !EXTRACTORS.contains(&name.as_str()) => {
+                    return Err(invalid(rule, &format!("unknown extractor '{}'", name)));
+                }
+                (Some(_), true) | (None, false) => {}
+                _ => {
+                    return Err(invalid(
+                        rule,
+                        "numeric needs exactly one of 'extractor' or 'patterns'",
+                    ))
+                }
+            }
+            for pattern in &numeric.patterns {
+                let re = self
+                    .compile(pattern)
+                    .map_err(|message| invalid(rule, &message))?;
+                if re.captures_len() < 2 {
+                    return Err(invalid(
+                        rule,
+                        &format!("pattern '{}' has no capture group", pattern),
+                    ));
+                }
+            }
+            if numeric.above.is_none() && numeric.below.is_none() && numeric.equals.is_none() {
+                return Err(invalid(
+                    rule,
+                    "numeric needs at least one of 'above', 'below' or 'equals'",
+                ));
+            }
+        }
+        if rule.numeric.is_some() && !rule.required.is_empty() {
+            return Err(invalid(
+                rule,
+                "use either 'numeric' or 'required', not both",
+            ));
+        }
+
+        let group = match &rule.snippet {
+            Snippet::Context(group) => Some(group),
+            _ => None,
+        };
+        let position_group = match &rule.position {
+            Position::GroupStart { group, .. } => Some(group),
+            _ => None,
+        };
+        for group in group.into_iter().chain(position_group) {
+            if !self.keywords.contains_key(group) {
+                return Err(invalid(rule, &format!("unknown keyword group '{}'", group)));
+            }
+        }
+        let no_keywords = match &rule.snippet {
+            Snippet::Keywords(keywords) | Snippet::KeywordsOrPrefix { keywords, .. } => {
+                keywords.is_empty()
+            }
+            _ => false,
+        };
+        if no_keywords {
+            return Err(invalid(rule, "snippet needs at least one keyword"));
+        }
+        if matches!(rule.snippet, Snippet::Match)
+            && rule.numeric.as_ref().is_none_or(|n| n.patterns.is_empty())
+        {
+            return Err(invalid(rule, "snippet 'match' needs numeric patterns"));
+        }
+
+        for placeholder in placeholders(&rule.message) {
+            let allowed = match placeholder {
+                "value" => rule.numeric.is_some(),
+                "missing" => !rule.required.is_empty(),
+                _ => false,
+            };
+            if !allowed {
+                return Err(invalid(
+                    rule,
+                    &format!("message uses unavailable placeholder {{{}}}", placeholder),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn prepare_condition(&mut self, condition: &mut Condition) -> Result<(), String> {
+        match condition {
+            Condition::Phrase(phrase) => *phrase = phrase.to_lowercase(),
+            Condition::Expr(Expr::Any(items) | Expr::All(items)) => {
+                for item in items {
+                    self.prepare_condition(item)?;
+                }
+            }
+            Condition::Expr(Expr::Not(inner)) => self.prepare_condition(inner)?,
+            Condition::Expr(Expr::Group(group) | Expr::CapsNear(group)) => {
+                self.require_group(group)?
+            }
+            Condition::Expr(Expr::Cluster { groups, min }) => {
+                for group in groups.iter() {
+                    self.require_group(group)?;
+                }
+                if *min == 0 || *min > groups.len() {
+                    return Err(format!(
+                        "cluster min {} must be between 1 and {}",
+                        min,
+                        groups.len()
+                    ));
+                }
+            }
+            Condition::Expr(Expr::Regex(pattern)) => {
+                self.compile(pattern)?;
+            }
+            Condition::Expr(Expr::Count { phrase, .. }) => *phrase = phrase.to_lowercase(),
+            Condition::Expr(Expr::Raw(_)) => {}
+        }
+        Ok(())
+    }
+
+    fn require_group(&self, group: &str) -> Result<(), String> {
+        if self.keywords.contains_key(group) {
+            Ok(())
+        } else {
+            Err(format!("unknown keyword group '{}'", group))
+        }
+    }
+
+    fn compile(&mut self, pattern: &str) -> Result<&Regex, String> {
+        if !self.regexes.contains_key(pattern) {
+            let re = Regex::new(pattern).map_err(|e| format!("invalid pattern: {}", e))?;
+            self.regexes.insert(pattern.to_string(), re);
+        }
+        Ok(&self.regexes[pattern])
+    }
+
+    // ------------------------------------------------------------------------
+    // Evaluation
+    // ------------------------------------------------------------------------
+
+    /// `trigger` is the operative text `when` is matched against
+    fn evaluate(&self, rule: &RuleSpec, ctx: &Context, trigger: &Context) -> Vec<Violation> {
+        if rule
+            .when
+            .as_ref()
+            .is_some_and(|c| !self.matches(c, trigger))
+        {
+            return Vec::new();
+        }
+        if rule.unless.as_ref().is_some_and(|c| self.matches(c, ctx)) {
+            return Vec::new();
+        }
+
+        if let Some(numeric) = &rule.numeric {
+            return self
+                .extract_values(numeric, ctx)
+                .into_iter()
+                .filter(|(value, _)| {
+                    numeric.above.is_some_and(|b| *value > b)
+                        || numeric.below.is_some_and(|b| *value < b)
+                        || numeric.equals.is_some_and(|b| *value == b)
+                })
+                .map(|(value, matched)| {
+                    let message = rule.message.replace("{value}", &value.to_string());
+                    self.violation(rule, ctx, message, matched)
+                })
+                .collect();
+        }
+
+        if !rule.required.is_empty() {
+            let missing: Vec<&str> = rule
+                .required
+                .iter()
+                .filter(|r| !self.matches(&r.when, ctx))
+                .map(|r| r.label.as_str())
+                .collect();
+            if missing.is_empty() {
+                return Vec::new();
+            }
+            let message = rule.message.replace("{missing}", &missing.join(", "));
+            return vec![self.violation(rule, ctx, message, None)];
+        }
+
+        vec![self.violation(rule, ctx, rule.message.clone(), None)]
+    }
+
+    fn matches(&self, condition: &Condition, ctx: &Context) -> bool {
+        match condition {
+            Condition::Phrase(phrase) => ctx.lower.contains(phrase.as_str()),
+            Condition::Expr(expr) => match expr {
+                Expr::Any(items) => items.iter().any(|c| self.matches(c, ctx)),
+                Expr::All(items) => items.iter().all(|c| self.matches(c, ctx)),
+                Expr::Not(inner) => !self.matches(inner, ctx),
+                Expr::Group(group) => self.group_matches(group, ctx),
+                Expr::Cluster { groups, min } => {
+                    groups.iter().filter(|g| self.group_matches(g, ctx)).count() >= *min
+                }
+                Expr::Raw(needle) => ctx.text.contains(needle.as_str()),
+                Expr::Regex(pattern) => self.regexes[pattern].is_match(&ctx.lower),
+                Expr::Count { phrase, min } => ctx.lower.matches(phrase.as_str()).count() >= *min,
+                Expr::CapsNear(group) => self.caps_near(group, ctx),
+            },
+        }
+    }
+
+    fn group_matches(&self, group: &str, ctx: &Context) -> bool {
+        self.keywords[group]
+            .iter()
+            .any(|kw| ctx.lower.contains(kw.as_str()))
+    }
+
+    /// Offset in the original text of the first keyword of a group that
+    /// occurs, in group order
+    fn group_position(&self, group: &str, ctx: &Context) -> Option<usize> {
+        self.keywords[group].iter().find_map(|kw| {
+            self.regexes[&keyword_pattern(kw)]
+                .find(ctx.text)
+                .map(|m| m.start())
+        })
+    }
+
+    /// Whether the letters around a group's first keyword are all capitals
+    fn caps_near(&self, group: &str, ctx: &Context) -> bool {
+        let Some(pos) = self.group_position(group, ctx) else {
+            return false;
+        };
+        let start = floor_char_boundary(ctx.text, pos.saturating_sub(50));
+        let end = ceil_char_boundary(ctx.text, pos + 100);
+        ctx.text[start..end]
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .take(20)
+            .all(|c| c.is_uppercase())
+    }
+
+    /// Values for a numeric check with the text they were matched in
+    fn extract_values(&self, numeric: &NumericCheck, ctx: &Context) -> Vec<(i64, Option<String>)> {
+        if let Some(extractor) = &numeric.extractor {
+            let terms = ctx.terms;
+            let dollars = |amount: f64| amount.round() as i64;
+            let value = match extractor.as_str() {
+                "deposit_return_days" => terms.deposit_return_days.as_ref().map(|d| d.value as i64),
+                "monthly_rent" => terms.rent.as_ref().map(|r| dollars(r.value)),
+                "security_deposit" => terms.security_deposit.as_ref().map(|d| dollars(d.value)),
+                "pet_deposit" => terms.pet_deposit.as_ref().map(|d| dollars(d.value)),
+                "deposit_to_rent_percent" => terms
+                    .deposit_and_rent()
+                    .filter(|(_, rent)| *rent > 0.0)
+                    .map(|(deposit, rent)| (deposit * 100.0 / rent).round() as i64),
+                "late_fee_amount" => terms.late_fee_flat().map(dollars),
+                "late_fee_percent" => terms.late_fee_percent().map(|p| p.round() as i64),
+                "grace_period_days" => terms.grace_period_days.as_ref().map(|g| g.value as i64),
+                _ => None,
+            };
+            return value.map(|v| (v, None)).into_iter().collect();
+        }
+
+        let mut values = numeric.patterns.iter().filter_map(|pattern| {
+            let caps = self.regexes[pattern].captures(&ctx.lower)?;
+            let value = caps.get(1)?.as_str().parse::<u32>().ok()?;
+            Some((value as i64, Some(caps[0].to_string())))
+        });
+        if numeric.each_pattern {
+            values.collect()
+        } else {
+            values.next().into_iter().collect()
+        }
+    }
+
+    fn violation(
+        &self,
+        rule: &RuleSpec,
+        ctx: &Context,
+        message: String,
+        matched: Option<String>,
+    ) -> Violation {
+        let text = ctx.text;
+        let text_snippet = match &rule.snippet {
+            Snippet::None => None,
+            Snippet::Match => matched,
+            Snippet::Prefix(n) => Some(text.chars().take(*n).collect()),
+            Snippet::Truncate(n) => Some(if text.len() <= *n {
+                text.to_string()
+            } else {
+                let mut end = *n;
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                format!("{}...", &text[..end])
+            }),
+            Snippet::Keyword(keyword) => Some(extract_snippet(text, keyword)),
+            Snippet::Keywords(keywords) => Some(extract_snippet(
+                text,
+                first_found(keywords, ctx).unwrap_or(&keywords[0]),
+            )),
+            Snippet::KeywordOrPrefix { keyword, prefix } => {
+                if ctx.lower.contains(keyword.to_lowercase().as_str()) {
+                    Some(extract_snippet(text, keyword))
+                } else {
+                    Some(text.chars().take(*prefix).collect())
+                }
+            }
+            Snippet::KeywordsOrPrefix { keywords, prefix } => match first_found(keywords, ctx) {
+                Some(keyword) => Some(extract_snippet(text, keyword)),
+                None => Some(text.chars().take(*prefix).collect()),
+            },
+            Snippet::Context(group) => Some(extract_context(
+                text,
+                self.group_position(group, ctx).unwrap_or(0),
+            )),
+        };
+
+        let text_position = match &rule.position {
+            Position::None => None,
+            Position::Keywords(keywords) => keywords
+                .iter()
+                .find_map(|kw| find_text_position(text, kw))
+                .map(|(start, end)| TextPosition::new(start, end)),
+            Position::GroupStart { group, width } => self
+                .group_position(group, ctx)
+                .map(|start| TextPosition::new(start, ceil_char_boundary(text, start + width))),
+        };
+
+        Violation {
+            statute: rule.statute.clone(),
+            severity: rule.severity,
+            message,
+            page: None,
+            text_snippet,
+            text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        }
+    }
+}
+
+/// Case-insensitive pattern matching a (lowercased) keyword literally
+fn keyword_pattern(keyword: &str) -> String {
+    format!("(?i){}", regex::escape(keyword))
+}
+
+/// First of `keywords`, in list order, that occurs in the text
+fn first_found<'k>(keywords: &'k [String], ctx: &Context) -> Option<&'k str> {
+    keywords
+        .iter()
+        .map(String::as_str)
+        .find(|kw| ctx.lower.contains(kw.to_lowercase().as_str()))
+}
+
+fn invalid(rule: &RuleSpec, message: &str) -> RulePackError {
+    RulePackError::Invalid {
+        rule: rule.id.clone(),
+        message: message.to_string(),
+    }
+}
+
+/// `{name}` placeholders in a message template
+fn placeholders(template: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        found.push(&rest[open + 1..open + close]);
+        rest = &rest[open + close + 1..];
+    }
+    found
+}
+
+// ============================================================================
+// Bundled packs
+// ============================================================================
+
+const FLORIDA_PACK: &str = include_str!("../rule_packs/florida.toml");
+const TEXAS_PACK: &str = include_str!("../rule_packs/texas.toml");
+
+/// Rule pack shipped with the crate for a state, if one exists
+pub fn builtin_pack(state: State) -> Option<RulePack> {
+    let source = match state {
+        State::FL => FLORIDA_PACK,
+        State::TX => TEXAS_PACK,
+        _ => return None,
+    };
+    Some(RulePack::from_toml(source).expect("bundled rule pack is valid"))
+}
+
+/// All rule packs shipped with the crate
+pub fn builtin_packs() -> Vec<RulePack> {
+    [State::FL, State::TX]
+        .into_iter()
+        .filter_map(builtin_pack)
+        .collect()
+}
+
+/// Load every `.toml`, `.yaml` and `.yml` pack in a directory, in file
+/// name order; other files are ignored
+pub fn load_packs_from_dir(dir: impl AsRef<Path>) -> Result<Vec<RulePack>, RulePackError> {
+    let dir = dir.as_ref();
+    let io_error = |source| RulePackError::Io {
+        path: dir.display().to_string(),
+        source,
+    };
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(io_error)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error)?;
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| matches!(e, "toml" | "yaml" | "yml"))
+        })
+        .map(RulePack::load)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::terms::LeaseTerms;
+    use crate::states::{florida, texas};
+
+    /// Texts covering every Florida and Texas rule, compliant and not
+    const PARITY_CORPUS: &[&str] = &[
+        "",
+        "Tenant waives notice. Deposit returned in 45 days. Tenant pays landlord attorney fees.",
+        "Tenant waives notice before termination or eviction.",
+        "Tenant waives all rights under Florida law.",
+        "Landlord may dispose of any property left by tenant.",
+        "Property is rented AS-IS including the roof and plumbing.",
+        "Landlord shall return deposit within 45 days.",
+        "Deposit will be returned within 30 days.",
+        "Deposit will be returned within 30 days, less any claim for damages.",
+        "Deposit will be returned within 15 days. Held at First National Bank, Miami, Florida.",
+        "Security deposit held at First National Bank, Miami, Florida. \
+         Landlord returns deposit within 15 days if no claim. \
+         The prevailing party shall be entitled to reasonable attorney fees. \
+         Tenant shall receive 3 business days notice for nonpayment of rent.",
+        "Landlord is entitled to attorney fees and costs.",
+        "Tenant agrees to pay all legal costs and attorney fees of landlord.",
+        "Landlord is entitled to attorney fees. Tenant is entitled to attorney fees.",
+        "Tenant shall receive 1 day notice for nonpayment of rent.",
+        "For any violation tenant gets 2 days written notice.",
+        "Month-to-month tenancy: either party may terminate with 7 days notice.",
+        "Notices may be sent via email. Monthly rent: $2,000.",
+        "ELECTRONIC NOTICE CONSENT: Pursuant to Florida Statute § 83.56 as amended by HB 615, \
+         Tenant expressly consents to receive all legally required notices via electronic mail.",
+        "FLOOD DISCLOSURE: Pursuant to Florida Statute § 83.512, Landlord discloses: \
+         (1) Landlord has no knowledge of prior flooding at this property. \
+         (2) No flood insurance claims have been filed for this property. \
+         (3) No federal flood assistance has been received for this property.",
+        "This property may be in a flood zone. Check with FEMA for details.",
+        "Application fee of $50 required for background check.",
+        "Application fee of $50 required. Please review our Selection Criteria before applying.",
+        "Unauthorized vehicles will be towed at owner's expense.",
+        "See Parking Addendum for vehicle rules. Unauthorized vehicles may be towed.",
+        "Security deposit will be returned within 60 days of move-out.",
+        "Security deposit will be returned within 30 days of move-out. Provide a forwarding address.",
+        "Tenant waives landlord's duty to repair.",
+        "Tenant hereby waives the right to jury trial.",
+        "Late fee shall be 20% of monthly rent.",
+        "Late fee shall be 10% of monthly rent.",
+        "Repairs will be handled promptly.",
+        "Repair requests require written notice; landlord responds within reasonable time.",
+        "Landlord may change locks and deny access if rent is late.",
+        "**LOCKOUT POLICY**: Tenant may be locked out for nonpayment.",
+        "TENANT ACKNOWLEDGES THE LOCKOUT POLICY AND AGREES TO ITS TERMS IN FULL.",
+        "This lease includes the Parking Addendum. \
+         See Selection Criteria notice attached. \
+         Security deposit returned within 30 days. \
+         Repair requests must be in writing. \
+         Landlord will respond within reasonable time. \
+         **LOCKOUT POLICY**: Tenant may be locked out for nonpayment.",
+        "Application fee $50. Deposit returned in 60 days. Vehicles will be towed.",
+        "Depósito devuelto en 20 días. Tenant waives notice of eviction — señor.",
+        "El Inquilino renuncia a cualquier aviso antes del desalojo. El Arrendador podrá \
+         desechar las pertenencias dejadas por el Inquilino.",
+        "El Inquilino acepta la unidad tal como está, incluida la plomería, y renuncia a \
+         todos sus derechos según el capítulo 83.",
+        "El Arrendador devolverá el depósito dentro de los 45 días. El Inquilino pagará los \
+         honorarios de abogado del Arrendador. Los avisos se enviarán por correo electrónico.",
+        "El Inquilino recibirá 2 días de aviso por falta de pago de la renta.",
+        "Divulgación de inundaciones: el Arrendador no tiene conocimiento de inundaciones \
+         previas y no ha recibido asistencia federal.",
+        "Se cobra un cargo por solicitud. Los vehículos no autorizados serán remolcados. \
+         El Arrendador puede realizar un cambio de cerradura.",
+        "El Inquilino renuncia a las reparaciones y a un juicio con jurado. Cargo por pago \
+         atrasado del 15%. Las solicitudes de reparación deben hacerse por escrito.",
+    ];
+
+    fn as_json(violations: &[Violation]) -> serde_json::Value {
+        serde_json::to_value(violations).unwrap()
+    }
+
+    #[test]
+    fn test_florida_pack_matches_rust_rules() {
+        let pack = builtin_pack(State::FL).unwrap();
+        for text in PARITY_CORPUS {
+            assert_eq!(
+                as_json(&pack.check(text)),
+                as_json(&florida::check_florida_compliance(
+                    text,
+                    &LeaseTerms::extract(text)
+                )),
+                "Florida parity mismatch for: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_texas_pack_matches_rust_rules() {
+        let pack = builtin_pack(State::TX).unwrap();
+        for text in PARITY_CORPUS {
+            assert_eq!(
+                as_json(&pack.check(text)),
+                as_json(&texas::check_texas_compliance(text)),
+                "Texas parity mismatch for: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_packs_from_dir() {
+        let dir = std::env::temp_dir().join(format!("rule_packs_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b-texas.toml"), TEXAS_PACK).unwrap();
+        std::fs::write(dir.join("a-florida.toml"), FLORIDA_PACK).unwrap();
+        std::fs::write(dir.join("README.md"), "not a pack").unwrap();
+
+        let packs = load_packs_from_dir(&dir).unwrap();
+        let states: Vec<State> = packs.iter().map(RulePack::state).collect();
+        assert_eq!(states, [State::FL, State::TX]);
+
+        std::fs::write(dir.join("c-broken.yaml"), "pack: [").unwrap();
+        let error = load_packs_from_dir(&dir).unwrap_err().to_string();
+        assert!(error.contains("c-broken.yaml"), "{}", error);
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert!(RulePack::parse(TEXAS_PACK, "TOML").is_ok());
+        assert!(RulePack::parse(TEXAS_PACK, "json").is_err());
+    }
+
+    #[test]
+    fn test_builtin_packs_load() {
+        let packs = builtin_packs();
+        assert_eq!(packs.len(), 2);
+        assert_eq!(packs[0].state(), State::FL);
+        assert!(packs.iter().all(|p| !p.rules.is_empty()));
+    }
+
+    #[test]
+    fn test_yaml_pack() {
+        let yaml = r#"
+pack:
+  id: us-ok-test
+  state: OK
+  version: "0.1.0"
+keywords:
+  pets: [pet, dog, cat]
+rules:
+  - id: pet-rent
+    statute: "Okla. Stat. tit. 41 § 115"
+    severity: Warning
+    message: "Pet deposit of {value}% exceeds the pack limit"
+    when:
+      group: pets
+    numeric:
+      patterns: ['pet deposit.*?(\d+)\s*%']
+      above: 50
+    snippet: match
+"#;
+        let pack = RulePack::from_yaml(yaml).unwrap();
+        assert_eq!(pack.state(), State::OK);
+
+        let violations = pack.check("Pet deposit equal to 75% of rent for a dog.");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Pet deposit of 75% exceeds the pack limit"
+        );
+        assert_eq!(
+            violations[0].text_snippet.as_deref(),
+            Some("pet deposit equal to 75%")
+        );
+        assert!(pack.check("Pet deposit equal to 25% of rent.").is_empty());
+    }
+
+    #[test]
+    fn test_phrases_are_case_insensitive() {
+        let pack = RulePack::from_toml(
+            r#"
+[pack]
+id = "test"
+state = "FL"
+version = "1"
+
+[[rules]]
+id = "r"
+statute = "S"
+severity = "Info"
+message = "m"
+when = { all = ["Hold Harmless", { not = "Landlord" }] }
+"#,
+        )
+        .unwrap();
+        assert_eq!(pack.check("Tenant shall HOLD HARMLESS.").len(), 1);
+        assert!(pack
+            .check("Tenant shall hold harmless the landlord.")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_group_positions_are_offsets_into_the_text() {
+        let pack = RulePack::from_toml(
+            r#"
+[pack]
+id = "test"
+state = "FL"
+version = "1"
+
+[keywords]
+waiver = ["waives"]
+
+[[rules]]
+id = "r"
+statute = "S"
+severity = "Info"
+message = "m"
+when = { group = "waiver" }
+snippet = { context = "waiver" }
+position = { group_start = { group = "waiver", width = 40 } }
+"#,
+        )
+        .unwrap();
+
+        // "İ" is two bytes, but three once lowercased
+        let text = "İİİİ Tenant WAIVES notice.";
+        let violation = &pack.check(text)[0];
+        let position = violation.text_position.unwrap();
+        assert_eq!(&text[position.start_offset..], "WAIVES notice.");
+        assert_eq!(position.end_offset, text.len());
+        assert_eq!(violation.text_snippet.as_deref(), Some(text));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let base = "[pack]\nid = \"t\"\nstate = \"FL\"\nversion = \"1\"\n";
+        let rule = |body: &str| {
+            format!(
+                "{}[[rules]]\nid = \"r\"\nstatute = \"S\"\nseverity = \"Info\"\n{}",
+                base, body
+            )
+        };
+
+        let unknown_group = rule("message = \"m\"\nwhen = { group = \"nope\" }");
+        assert!(matches!(
+            RulePack::from_toml(&unknown_group),
+            Err(RulePackError::Invalid { message, .. }) if message.contains("nope")
+        ));
+
+        let bad_regex = rule("message = \"m\"\nwhen = { regex = \"(\" }");
+        assert!(RulePack::from_toml(&bad_regex).is_err());
+
+        let bad_placeholder = rule("message = \"found {value}\"");
+        assert!(matches!(
+            RulePack::from_toml(&bad_placeholder),
+            Err(RulePackError::Invalid { message, .. }) if message.contains("{value}")
+        ));
+
+        let no_bound = rule("message = \"m\"\nnumeric = { extractor = \"deposit_return_days\" }");
+        assert!(RulePack::from_toml(&no_bound).is_err());
+
+        let unknown_extractor =
+            rule("message = \"m\"\nnumeric = { extractor = \"rent\", above = 1 }");
+        assert!(RulePack::from_toml(&unknown_extractor).is_err());
+
+        let no_capture = rule("message = \"m\"\nnumeric = { patterns = ['\\d+'], above = 1 }");
+        assert!(RulePack::from_toml(&no_capture).is_err());
+
+        let unknown_field = rule("message = \"m\"\nseverity_level = 3");
+        assert!(matches!(
+            RulePack::from_toml(&unknown_field),
+            Err(RulePackError::Parse(_))
+        ));
+
+        let inverted_dates =
+            rule("message = \"m\"\neffective_from = \"2025-07-01\"\neffective_to = \"2024-07-01\"");
+        assert!(matches!(
+            RulePack::from_toml(&inverted_dates),
+            Err(RulePackError::Invalid { message, .. }) if message.contains("effective_from")
+        ));
+
+        let bad_state = "[pack]\nid = \"t\"\nstate = \"ZZ\"\nversion = \"1\"\n";
+        assert!(RulePack::from_toml(bad_state).is_err());
+    }
+
+    #[test]
+    fn test_check_as_of_skips_rules_out_of_force() {
+        let pack = RulePack::from_toml(
+            r#"
+[pack]
+id = "test"
+state = "FL"
+version = "1"
+
+[[rules]]
+id = "repealed"
+statute = "S 1"
+severity = "Info"
+message = "m"
+effective_to = "2020-12-31"
+
+[[rules]]
+id = "new"
+statute = "S 2"
+severity = "Info"
+message = "m"
+effective_from = "2025-01-01"
+"#,
+        )
+        .unwrap();
+        let statutes = |date: &str| -> Vec<String> {
+            let as_of = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+            pack.check_as_of("text", as_of)
+                .into_iter()
+                .map(|v| v.statute)
+                .collect()
+        };
+        assert_eq!(statutes("2020-12-31"), vec!["S 1"]);
+        assert!(statutes("2023-01-01").is_empty());
+        assert_eq!(statutes("2025-01-01"), vec!["S 2"]);
+        // check() ignores dates
+        assert_eq!(pack.check("text").len(), 2);
+
+        let covered = pack.covered_statutes();
+        assert_eq!(
+            covered[0].range.effective_to,
+            NaiveDate::from_ymd_opt(2020, 12, 31)
+        );
+        assert_eq!(
+            covered[1].range.effective_from,
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_lease_term_extractors() {
+        let pack = RulePack::from_toml(
+            r#"
+[pack]
+id = "test"
+state = "FL"
+version = "1"
+
+[[rules]]
+id = "deposit-cap"
+statute = "S 1"
+severity = "Critical"
+message = "Deposit is {value}% of rent"
+numeric = { extractor = "deposit_to_rent_percent", above = 200 }
+
+[[rules]]
+id = "late-fee"
+statute = "S 2"
+severity = "Warning"
+message = "Late fee ${value}"
+numeric = { extractor = "late_fee_amount", above = 50 }
+"#,
+        )
+        .unwrap();
+
+        let over = pack.check("Monthly rent: $1,000. Security deposit: $2,500. Late fee: $75.");
+        let messages: Vec<_> = over.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(messages, vec!["Deposit is 250% of rent", "Late fee $75"]);
+
+        let within = pack.check("Monthly rent: $1,000. Security deposit: $2,000. Late fee: $50.");
+        assert!(within.is_empty());
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(
+            placeholders("a {value} b {missing}"),
+            vec!["value", "missing"]
+        );
+        assert!(placeholders("no placeholders").is_empty());
+    }
+}
@@ -62,6 +62,8 @@ pub fn check_attorney_fees(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(extract_snippet(text)),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -24,6 +24,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -36,6 +38,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -51,6 +55,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -67,6 +73,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -41,6 +41,8 @@ fn check_nonpayment_notice(text: &str) -> Vec<Violation> {
                                 page: None,
                                 text_snippet: Some(caps.get(0).unwrap().as_str().to_string()),
                                 text_position: None,
+                                remediation: None,
+                                bounding_boxes: Vec::new(),
                             });
                         }
                     }
@@ -79,6 +81,8 @@ fn check_lease_violation_notice(text: &str) -> Vec<Violation> {
                                 page: None,
                                 text_snippet: Some(caps.get(0).unwrap().as_str().to_string()),
                                 text_position: None,
+                                remediation: None,
+                                bounding_boxes: Vec::new(),
                             });
                         }
                     }
@@ -132,6 +136,8 @@ fn check_termination_notice(text: &str) -> Vec<Violation> {
                                 page: None,
                                 text_snippet: Some(caps.get(0).unwrap().as_str().to_string()),
                                 text_position: None,
+                                remediation: None,
+                                bounding_boxes: Vec::new(),
                             });
                         }
                         // Only report the first match to avoid duplicates
@@ -1,3 +1,4 @@
+use crate::clauses;
 use crate::patterns::{
     contains_semantic_cluster, extract_snippet, find_text_position, AS_IS_KEYWORDS,
     DISPOSAL_KEYWORDS, FL_LAW_KEYWORDS, NOTICE_KEYWORDS, PROPERTY_KEYWORDS, RIGHTS_KEYWORDS,
@@ -8,71 +9,72 @@ use shared_types::{Severity, TextPosition, Violation};
 /// Check for prohibited provisions under Florida Statute § 83.47
 pub fn check_prohibited_provisions(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    // Negated, quoted and recited clauses do not agree to anything
+    let operative = clauses::operative_text(text);
+    let text_lower = operative.to_lowercase();
 
     // Check for waiver of notice (§ 83.47(1)(c))
     if check_waiver_of_notice(&text_lower) {
-        let text_position = find_text_position(text, "waive").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position = find_text_position(&operative, "waive")
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "83.47(1)(a)".to_string(),
             severity: Severity::Critical,
             message: "Lease contains prohibited waiver of tenant's right to notice before termination or eviction".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "waive")),
+            text_snippet: Some(extract_snippet(&operative, "waive")),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for property disposal clause (§ 83.47(1)(b))
     if check_property_disposal(&text_lower) {
-        let text_position = find_text_position(text, "dispose").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position = find_text_position(&operative, "dispose")
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "83.47(1)(b)".to_string(),
             severity: Severity::Critical,
             message: "Lease contains prohibited authorization for landlord to dispose of tenant's property".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "dispose")),
+            text_snippet: Some(extract_snippet(&operative, "dispose")),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for AS-IS clauses that waive landlord's structural obligations (§ 83.51)
     if check_as_is_structural(&text_lower) {
-        let text_position = find_text_position(text, "as-is")
-            .or_else(|| find_text_position(text, "as is"))
-            .map(|(start, end)| TextPosition {
-                start_offset: start,
-                end_offset: end,
-            });
+        let text_position = find_text_position(&operative, "as-is")
+            .or_else(|| find_text_position(&operative, "as is"))
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "83.51(2)(a)".to_string(),
             severity: Severity::Critical,
             message: "AS-IS clause may improperly waive landlord's obligation to maintain structural components".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "as-is")),
+            text_snippet: Some(extract_snippet(&operative, "as-is")),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for general waiver of rights (§ 83.47(1)(a))
     if check_general_rights_waiver(&text_lower) {
-        let text_position = find_text_position(text, "waive").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position = find_text_position(&operative, "waive")
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "83.47(1)(a)".to_string(),
             severity: Severity::Critical,
             message: "Lease contains prohibited waiver of tenant's rights under Florida landlord-tenant law".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "waive")),
+            text_snippet: Some(extract_snippet(&operative, "waive")),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -108,13 +110,16 @@ fn check_as_is_structural(text: &str) -> bool {
 
 /// Detect general waiver of tenant rights under Florida law
 fn check_general_rights_waiver(text: &str) -> bool {
-    // Look for waiver + rights + Florida law reference
-    let has_waiver = WAIVER_KEYWORDS.iter().any(|kw| text.contains(kw));
-    let has_rights = RIGHTS_KEYWORDS.iter().any(|kw| text.contains(kw));
-    let has_law_ref = FL_LAW_KEYWORDS.iter().any(|kw| text.contains(kw));
-
-    // Must have waiver + rights, and ideally law reference
-    has_waiver && has_rights && (has_law_ref || text.contains("all"))
+    clauses::segment(text).iter().any(|clause| {
+        let clause = clause.text;
+        // Look for waiver + rights + Florida law reference in one clause
+        let has_waiver = WAIVER_KEYWORDS.iter().any(|kw| clause.contains(kw));
+        let has_rights = RIGHTS_KEYWORDS.iter().any(|kw| clause.contains(kw));
+        let has_law_ref = FL_LAW_KEYWORDS.iter().any(|kw| clause.contains(kw));
+
+        // Must have waiver + rights, and ideally law reference
+        has_waiver && has_rights && (has_law_ref || clause.contains("all"))
+    })
 }
 
 #[cfg(test)]
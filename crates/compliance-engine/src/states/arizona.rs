@@ -8,26 +8,16 @@
 //! - Tenant Handbook Reference
 //! - 10-day notice for material breach
 
-use lazy_static::lazy_static;
-use regex::Regex;
+use crate::clauses;
+use crate::extractors::terms::LeaseTerms;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-}
-
 /// Check all Arizona-specific compliance requirements
-pub fn check_arizona_compliance(text: &str) -> Vec<Violation> {
+pub fn check_arizona_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_bed_bug_disclosure(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_pool_safety(text));
     violations.extend(check_tenant_handbook(text));
     violations.extend(check_void_clauses(text));
@@ -63,6 +53,8 @@ pub fn check_bed_bug_disclosure(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -79,20 +71,14 @@ pub fn check_bed_bug_disclosure(text: &str) -> Vec<Violation> {
 /// - Maximum 1.5 months rent
 /// - Must return within 14 days
 /// - Non-refundable fees must be clearly stated
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (1.5 months max)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -108,6 +94,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -131,6 +119,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -149,6 +139,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                     page: None,
                     text_snippet: None,
                     text_position: None,
+                    remediation: None,
+                    bounding_boxes: Vec::new(),
                 });
             }
         }
@@ -185,6 +177,8 @@ pub fn check_pool_safety(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -219,6 +213,8 @@ pub fn check_tenant_handbook(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -232,7 +228,7 @@ pub fn check_tenant_handbook(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Arizona law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's duty to maintain
     if text_lower.contains("waive")
@@ -246,6 +242,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -259,6 +257,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -272,6 +272,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -282,6 +284,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_arizona_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Bed Bug Disclosure Tests
     // ========================================================================
@@ -289,7 +295,7 @@ mod tests {
     #[test]
     fn test_warns_missing_bed_bug_disclosure() {
         let text = "Monthly rent: $1,500. Security deposit: $1,500.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -302,7 +308,7 @@ mod tests {
     #[test]
     fn test_accepts_bed_bug_disclosure() {
         let text = "Bed Bug Disclosure: No known infestations. See attached addendum.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -319,7 +325,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -332,7 +338,7 @@ mod tests {
     #[test]
     fn test_accepts_compliant_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $3,000.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -349,7 +355,7 @@ mod tests {
     #[test]
     fn test_warns_missing_pool_safety() {
         let text = "Property includes community pool and fitness center.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -362,7 +368,7 @@ mod tests {
     #[test]
     fn test_accepts_pool_safety_notice() {
         let text = "Pool Safety Notice: See attached drowning prevention information.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -379,7 +385,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive warranty of habitability.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -392,7 +398,7 @@ mod tests {
     #[test]
     fn test_detects_tenant_remedy_waiver() {
         let text = "Tenant waives all remedies under Arizona landlord-tenant law.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -405,7 +411,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -425,7 +431,7 @@ mod tests {
                     Deposit returned within 14 days. \
                     Bed Bug Disclosure attached per A.R.S. § 33-1319. \
                     Arizona Tenant Rights handbook referenced.";
-        let violations = check_arizona_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -7,57 +7,68 @@
 //! - Just Cause Exemption (AB 1482)
 //! - Void Clauses (Civil Code 1953) - waiver of jury/notice/habitability
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
+use crate::patterns::{ceil_char_boundary, floor_char_boundary};
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, TextPosition, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[DEPOSIT_CAP, JUST_CAUSE, JUNK_FEES];
+
+/// AB 12 one-month deposit cap
+pub const DEPOSIT_CAP: DatedRule =
+    DatedRule::from("CA Civil Code § 1950.5 (AB 12)", date(2024, 7, 1));
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// AB 1482 just cause eviction
+pub const JUST_CAUSE: DatedRule =
+    DatedRule::from("CA Civil Code § 1946.2 (AB 1482)", date(2020, 1, 1));
 
+/// SB 611 junk fee limits
+pub const JUNK_FEES: DatedRule =
+    DatedRule::from("CA Civil Code § 1946.2 (SB 611)", date(2025, 7, 1));
+
+lazy_static! {
     /// Void clause patterns (Civil Code 1953)
     static ref VOID_CLAUSE_PATTERNS: Vec<(Regex, &'static str)> = vec![
         (
-            Regex::new(r"(?i)\b(waive|waiver|waives).*?\b(jury\s+trial|right\s+to\s+jury)\b").unwrap(),
+            Regex::new(r"(?i)\b(waive|waiver|waives|renuncia\w*).*?\b(jury\s+trial|right\s+to\s+jury|juicio\s+(?:con|por|ante)\s+jurado)\b").unwrap(),
             "jury trial rights",
         ),
         (
-            Regex::new(r"(?i)\b(waive|waiver|waives).*?\b(notice|notification)\b").unwrap(),
+            Regex::new(r"(?i)\b(waive|waiver|waives|renuncia\w*).*?\b(notice|notification|aviso|notificaci[oó]n)\b").unwrap(),
             "statutory notice requirements",
         ),
         (
-            Regex::new(r"(?i)\b(waive|waiver|waives).*?\b(habitability|habitable)\b").unwrap(),
+            Regex::new(r"(?i)\b(waive|waiver|waives|renuncia\w*).*?\b(habitability|habitable|habitabilidad)\b").unwrap(),
             "implied warranty of habitability",
         ),
         (
-            Regex::new(r"(?i)\b(waive|waiver|waives).*?\b(repair|maintenance)\b").unwrap(),
+            Regex::new(r"(?i)\b(waive|waiver|waives|renuncia\w*).*?\b(repair|maintenance|reparaci[oó]n(?:es)?|mantenimiento)\b").unwrap(),
             "landlord repair obligations",
         ),
         (
-            Regex::new(r"(?i)\b(waive|waiver|waives).*?\b(rights?\s+under|statutory)\b").unwrap(),
+            Regex::new(r"(?i)\b(waive|waiver|waives|renuncia\w*).*?\b(rights?\s+under|statutory|derechos?\s+(?:bajo|seg[uú]n|conforme\s+a)|derechos\s+legales)\b").unwrap(),
             "statutory tenant rights",
         ),
     ];
 
     /// Junk fees pattern - non-itemized bundled fees
     static ref BUNDLED_FEES_PATTERN: Regex =
-        Regex::new(r"(?i)(administrative\s+fee|processing\s+fee|move.in\s+fee|amenity\s+fee)").unwrap();
+        Regex::new(r"(?i)(administrative\s+fee|processing\s+fee|move.in\s+fee|amenity\s+fee|cargo\s+administrativo|tarifa\s+administrativa|cargo\s+por\s+(?:procesamiento|mudanza|amenidades))").unwrap();
 
     /// AB 1482 Just Cause patterns
     static ref JUST_CAUSE_EXEMPT_PATTERN: Regex =
-        Regex::new(r"(?i)(exempt\s+from\s+(?:AB\s*1482|just\s+cause)|single.family|owner.occupied)").unwrap();
+        Regex::new(r"(?i)(exempt\s+from\s+(?:AB\s*1482|just\s+cause)|single.family|owner.occupied|exent[oa]\s+de\s+(?:AB\s*1482|causa\s+justa)|vivienda\s+unifamiliar|ocupad[oa]\s+por\s+(?:el\s+)?propietario)").unwrap();
 }
 
 /// Check all California-specific compliance requirements
-pub fn check_california_compliance(text: &str) -> Vec<Violation> {
+pub fn check_california_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    violations.extend(check_security_deposit_cap(text));
+    violations.extend(check_security_deposit_cap(terms));
     violations.extend(check_void_clauses(text));
     violations.extend(check_junk_fees(text));
     violations.extend(check_just_cause_disclosure(text));
@@ -74,25 +85,19 @@ pub fn check_california_compliance(text: &str) -> Vec<Violation> {
 ///
 /// Per CA Civil Code 1950.5 as amended by AB 12:
 /// Security deposit cannot exceed 1 month's rent (effective July 1, 2024)
-pub fn check_security_deposit_cap(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit_cap(terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
         if deposit_amt > rent_amt {
             violations.push(Violation {
-                statute: "CA Civil Code § 1950.5 (AB 12)".to_string(),
+                statute: DEPOSIT_CAP.citation.to_string(),
                 severity: Severity::Critical,
                 message: format!(
                     "Security deposit (${:.2}) exceeds 1 month's rent (${:.2}). \
@@ -102,6 +107,8 @@ pub fn check_security_deposit_cap(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -119,9 +126,10 @@ pub fn check_security_deposit_cap(text: &str) -> Vec<Violation> {
 /// Certain waivers are void and unenforceable
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
+    let operative = clauses::operative_text(text);
 
     for (pattern, waived_right) in VOID_CLAUSE_PATTERNS.iter() {
-        if let Some(m) = pattern.find(text) {
+        if let Some(m) = pattern.find(&operative) {
             let snippet = extract_context(text, m.start(), m.end());
             violations.push(Violation {
                 statute: "CA Civil Code § 1953".to_string(),
@@ -132,10 +140,9 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
                 ),
                 page: None,
                 text_snippet: Some(snippet),
-                text_position: Some(TextPosition {
-                    start_offset: m.start(),
-                    end_offset: m.end(),
-                }),
+                text_position: Some(TextPosition::new(m.start(), m.end())),
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -161,11 +168,14 @@ pub fn check_junk_fees(text: &str) -> Vec<Violation> {
         let has_itemization = text_lower.contains("itemized")
             || text_lower.contains("breakdown")
             || text_lower.contains("fee schedule")
-            || (text_lower.contains("$") && text_lower.contains("each"));
+            || (text_lower.contains("$") && text_lower.contains("each"))
+            || text_lower.contains("detallad")
+            || text_lower.contains("desglose")
+            || (text_lower.contains("$") && text_lower.contains("cada"));
 
         if !has_itemization {
             violations.push(Violation {
-                statute: "CA Civil Code § 1946.2 (SB 611)".to_string(),
+                statute: JUNK_FEES.citation.to_string(),
                 severity: Severity::Warning,
                 message: "Administrative/processing fees must be itemized. \
                          SB 611 requires all mandatory fees to be disclosed individually."
@@ -173,6 +183,8 @@ pub fn check_junk_fees(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -195,18 +207,22 @@ pub fn check_just_cause_disclosure(text: &str) -> Vec<Violation> {
     // Check if lease mentions eviction/termination
     let mentions_eviction = text_lower.contains("evict")
         || text_lower.contains("termination")
-        || text_lower.contains("terminate");
+        || text_lower.contains("terminate")
+        || text_lower.contains("desalojo")
+        || text_lower.contains("terminación");
 
     if mentions_eviction {
         // Check for Just Cause disclosure or exemption
         let has_just_cause_disclosure = text_lower.contains("just cause")
             || text_lower.contains("ab 1482")
             || text_lower.contains("tenant protection act")
+            || text_lower.contains("causa justa")
+            || text_lower.contains("ley de protección al inquilino")
             || JUST_CAUSE_EXEMPT_PATTERN.is_match(text);
 
         if !has_just_cause_disclosure {
             violations.push(Violation {
-                statute: "CA Civil Code § 1946.2 (AB 1482)".to_string(),
+                statute: JUST_CAUSE.citation.to_string(),
                 severity: Severity::Warning,
                 message: "Just Cause disclosure recommended. Include AB 1482 \
                          Just Cause notice or exemption statement if applicable."
@@ -214,6 +230,8 @@ pub fn check_just_cause_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -235,13 +253,23 @@ pub fn check_rent_increase_notice(text: &str) -> Vec<Violation> {
     let text_lower = text.to_lowercase();
 
     // Check if lease mentions rent increases
-    if text_lower.contains("rent increase") || text_lower.contains("increase rent") {
+    let mentions_increase = text_lower.contains("rent increase")
+        || text_lower.contains("increase rent")
+        || text_lower.contains("aumento de renta")
+        || text_lower.contains("aumento de la renta")
+        || text_lower.contains("aumento del alquiler")
+        || text_lower.contains("aumentar la renta");
+    if mentions_increase {
         // Check for proper notice period disclosure
         let has_notice_period = text_lower.contains("30 days")
             || text_lower.contains("30-day")
             || text_lower.contains("90 days")
             || text_lower.contains("90-day")
-            || text_lower.contains("notice period");
+            || text_lower.contains("notice period")
+            || text_lower.contains("30 días")
+            || text_lower.contains("90 días")
+            || text_lower.contains("período de aviso")
+            || text_lower.contains("plazo de aviso");
 
         if !has_notice_period {
             violations.push(Violation {
@@ -253,6 +281,8 @@ pub fn check_rent_increase_notice(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -263,8 +293,8 @@ pub fn check_rent_increase_notice(text: &str) -> Vec<Violation> {
 /// Extract context around a match for display
 fn extract_context(text: &str, start: usize, end: usize) -> String {
     let context_size = 50;
-    let ctx_start = start.saturating_sub(context_size);
-    let ctx_end = (end + context_size).min(text.len());
+    let ctx_start = floor_char_boundary(text, start.saturating_sub(context_size));
+    let ctx_end = ceil_char_boundary(text, end + context_size);
 
     let mut result = String::new();
     if ctx_start > 0 {
@@ -281,6 +311,10 @@ fn extract_context(text: &str, start: usize, end: usize) -> String {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_california_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // AB 12 - Security Deposit Cap Tests
     // ========================================================================
@@ -288,7 +322,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $4,000.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -301,7 +335,7 @@ mod tests {
     #[test]
     fn test_accepts_compliant_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $2,000.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -314,7 +348,7 @@ mod tests {
     #[test]
     fn test_accepts_lower_deposit() {
         let text = "Monthly rent: $2,500. Security deposit: $1,500.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -331,7 +365,7 @@ mod tests {
     #[test]
     fn test_detects_jury_trial_waiver() {
         let text = "Tenant waives the right to jury trial in any dispute.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -344,7 +378,7 @@ mod tests {
     #[test]
     fn test_detects_notice_waiver() {
         let text = "Tenant hereby waives all statutory notice requirements.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -357,7 +391,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant accepts unit as-is and waives implied warranty of habitability.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -370,7 +404,7 @@ mod tests {
     #[test]
     fn test_detects_repair_waiver() {
         let text = "Tenant waives landlord's repair obligations for the term of lease.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -384,7 +418,7 @@ mod tests {
     fn test_no_waiver_detection_for_compliant_text() {
         let text = "Landlord agrees to maintain the property in habitable condition. \
                     Tenant will receive proper notice for all inspections.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations.iter().any(|v| v.statute.contains("1953")),
@@ -399,7 +433,7 @@ mod tests {
     #[test]
     fn test_detects_non_itemized_admin_fee() {
         let text = "An administrative fee will be charged at move-in.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -413,7 +447,7 @@ mod tests {
     fn test_accepts_itemized_fees() {
         let text = "Administrative fee: $50 (itemized: $25 credit check, $25 processing). \
                     Fee schedule attached.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -431,7 +465,7 @@ mod tests {
     fn test_warns_missing_just_cause_disclosure() {
         let text = "Landlord may terminate this lease for any violation. \
                     Eviction proceedings will follow standard process.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -445,7 +479,7 @@ mod tests {
     fn test_accepts_just_cause_disclosure() {
         let text = "This property is subject to AB 1482 Just Cause eviction requirements. \
                     Termination will only occur for Just Cause as defined by law.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -459,7 +493,7 @@ mod tests {
     fn test_accepts_just_cause_exemption() {
         let text = "This single-family home is exempt from AB 1482 Just Cause requirements. \
                     Owner-occupied exemption applies.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -481,7 +515,7 @@ mod tests {
                     Rent increases require 30 days written notice for increases up to 10%, \
                     or 90 days for increases over 10%. \
                     Landlord maintains property in habitable condition.";
-        let violations = check_california_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -492,4 +526,49 @@ mod tests {
             "Compliant lease should have no critical violations"
         );
     }
+
+    // ========================================================================
+    // Spanish Leases
+    // ========================================================================
+
+    #[test]
+    fn test_spanish_rules() {
+        let cases = [
+            (
+                "CA Civil Code § 1950.5 (AB 12)",
+                "Renta mensual: $2,000. Depósito de seguridad: $4,000.",
+            ),
+            (
+                "CA Civil Code § 1953",
+                "El Inquilino renuncia a su derecho a un juicio con jurado.",
+            ),
+            (
+                "CA Civil Code § 1946.2 (SB 611)",
+                "Se aplica un cargo administrativo al firmar.",
+            ),
+            (
+                "CA Civil Code § 1946.2 (AB 1482)",
+                "El Arrendador puede solicitar el desalojo del Inquilino.",
+            ),
+            (
+                "CA Civil Code § 827",
+                "El Arrendador puede hacer un aumento de la renta en cualquier momento.",
+            ),
+        ];
+        for (statute, text) in cases {
+            assert!(
+                check(text).iter().any(|v| v.statute == statute),
+                "{statute} not found in {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compliant_spanish_lease() {
+        let text = "Renta mensual: $2,000. Depósito de seguridad: $2,000. El Arrendador no \
+                    exigirá que el Inquilino renuncie a un juicio con jurado. El desalojo \
+                    requiere causa justa según la AB 1482.";
+        let violations = check(text);
+        assert!(violations.is_empty(), "{:?}", violations);
+    }
 }
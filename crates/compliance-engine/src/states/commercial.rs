@@ -0,0 +1,412 @@
+//! Commercial (Non-Residential) Lease Compliance
+//!
+//! Commercial tenancies sit outside residential landlord-tenant law: there
+//! are no deposit caps, habitability duties or residential notice periods,
+//! so residential rules must not run on them. This module detects commercial
+//! documents and checks the terms every commercial lease should get right;
+//! states with commercial statutes add their own rules (see
+//! [`super::florida_commercial`]).
+//!
+//! General checks:
+//! - CAM reconciliation - estimated common area charges need a true-up
+//! - Personal guaranty - signed by the guarantor, with its scope stated
+
+use super::florida_commercial;
+use crate::jurisdiction::State;
+use crate::patterns::{floor_char_boundary, snippet_around};
+use lazy_static::lazy_static;
+use regex::Regex;
+use shared_types::{Severity, TextPosition, Violation};
+
+/// Characters from the start of a document read as its title
+const TITLE_CHARS: usize = 300;
+
+lazy_static! {
+    /// Commercial lease titles
+    static ref COMMERCIAL_TITLE: Regex = Regex::new(
+        r"(?i)\b(?:commercial|office|retail|industrial|ground|net|non-?residential)\s+(?:lease|rental\s+agreement|tenancy)\b|\blease\s+of\s+(?:commercial|non-?residential|office|retail)\s+(?:premises|property|space)\b"
+    )
+    .unwrap();
+    /// Clauses putting the premises to commercial use, or the tenancy under
+    /// Florida's non-residential statute
+    static ref COMMERCIAL_USE: Regex = Regex::new(
+        r"(?i)\bpermitted\s+use(?:\s+of\s+(?:the\s+)?premises)?\s*[:\-]\s*[^.;\n]+|\b(?:used|occupied)\s+(?:solely\s+|only\s+|exclusively\s+|primarily\s+)?(?:for|as)\s+(?:an?\s+)?(?:commercial|business|office|retail|industrial|warehouse|restaurant|non-?residential)\b|\bchapter\s+83,?\s+part\s+i\b"
+    )
+    .unwrap();
+    /// A residential title
+    static ref RESIDENTIAL_TITLE: Regex = Regex::new(r"(?i)\bresidential\b").unwrap();
+    /// Markers of a lease for a dwelling
+    static ref DWELLING: Regex = Regex::new(
+        r"(?i)\b(?:dwelling\s+unit|residential\s+(?:premises|tenancy|lease|rental\s+agreement)|private\s+residence)\b"
+    )
+    .unwrap();
+    /// Words before a phrase in its sentence that negate it
+    static ref NEGATION: Regex = Regex::new(
+        r"(?i)\b(?:not|no|never|nor|neither|without|except|prohibit\w*|forbid\w*)\b"
+    )
+    .unwrap();
+    /// Words after a phrase in its sentence that forbid or disclaim it
+    static ref PROHIBITION: Regex = Regex::new(
+        r"(?i)\b(?:prohibited|forbidden|not\s+(?:permitted|allowed)|do(?:es)?\s+not\s+apply)\b"
+    )
+    .unwrap();
+    static ref CAM_PATTERN: Regex =
+        Regex::new(r"(?i)\b(common\s+area\s+maintenance|(?-i:CAM)\b|operating\s+expenses)").unwrap();
+    static ref RECONCILIATION_PATTERN: Regex = Regex::new(
+        r"(?i)(reconcil|true.up|annual\s+statement|year.end\s+statement|statement\s+of\s+actual)"
+    )
+    .unwrap();
+    static ref AUDIT_PATTERN: Regex = Regex::new(
+        r"(?i)(audit|inspect\s+(?:landlord's\s+)?(?:books|records)|examine\s+(?:landlord's\s+)?(?:books|records))"
+    )
+    .unwrap();
+    static ref GUARANTY_PATTERN: Regex =
+        Regex::new(r"(?i)\b(personal(?:ly)?\s+guarant(?:y|ee|ees|eed)|guarantor)\b").unwrap();
+    static ref GUARANTOR_SIGNATURE_PATTERN: Regex = Regex::new(
+        r"(?i)(guarantor\s*(?:signature|name)?\s*:|signature\s+of\s+guarantor|signed\s+by\s+(?:the\s+)?guarantor)"
+    )
+    .unwrap();
+    static ref GUARANTY_SCOPE_PATTERN: Regex = Regex::new(
+        r"(?i)(unlimited|limited\s+to|not\s+(?:to\s+)?exceed|maximum\s+(?:liability|amount)|cap(?:ped)?\s+(?:at|of)|burn.?down)"
+    )
+    .unwrap();
+}
+
+/// Kind of commercial document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommercialDocumentType {
+    /// Commercial (non-residential) lease
+    Lease,
+    /// Stand-alone guaranty of a commercial lease
+    Guaranty,
+    /// Not a commercial document
+    Unknown,
+}
+
+impl CommercialDocumentType {
+    /// Detect a commercial document from text content
+    ///
+    /// Needs a commercial title ("Commercial Lease Agreement") or a clause
+    /// putting the premises to commercial use ("Permitted Use: ...", "used
+    /// solely for office purposes", Florida's Chapter 83, Part I). Phrases
+    /// that are negated or prohibited do not count, and a document titled
+    /// residential or leasing a dwelling unit is never commercial.
+    pub fn detect(text: &str) -> Self {
+        let text_lower = text.to_lowercase();
+
+        let is_lease = text_lower.contains("lease")
+            || (text_lower.contains("landlord") && text_lower.contains("tenant"));
+        if !is_lease {
+            return Self::Unknown;
+        }
+
+        let head = &text[..floor_char_boundary(text, TITLE_CHARS)];
+        if affirmed(head, &RESIDENTIAL_TITLE) || affirmed(text, &DWELLING) {
+            return Self::Unknown;
+        }
+
+        // A guaranty stands alone when it, not a lease, is the title
+        let head_lower = head.to_lowercase();
+        if head_lower.contains("guaranty")
+            && !head_lower.contains("lease agreement")
+            && text_lower.contains("guarantor")
+        {
+            return Self::Guaranty;
+        }
+
+        if affirmed(head, &COMMERCIAL_TITLE) || affirmed(text, &COMMERCIAL_USE) {
+            Self::Lease
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// Whether `pattern` matches somewhere it is neither negated nor prohibited
+/// in its sentence, nor the tail of a "non-" word. A permitted use that is
+/// residential does not count either.
+fn affirmed(text: &str, pattern: &Regex) -> bool {
+    pattern.find_iter(text).any(|m| {
+        let before = &text[..m.start()];
+        let after = &text[m.end()..];
+        let sentence_start = before.rfind(SENTENCE_ENDS).map_or(0, |i| i + 1);
+        let sentence_end = after.find(SENTENCE_ENDS).unwrap_or(after.len());
+
+        !after_non(before)
+            && !NEGATION.is_match(&before[sentence_start..])
+            && !PROHIBITION.is_match(&after[..sentence_end])
+            && !residential_use(m.as_str())
+    })
+}
+
+const SENTENCE_ENDS: [char; 5] = ['.', ';', '!', '?', '\n'];
+
+fn after_non(before: &str) -> bool {
+    let before = before.to_lowercase();
+    before.ends_with("non-") || before.ends_with("non")
+}
+
+/// "Permitted Use: residential purposes only" and the like
+fn residential_use(clause: &str) -> bool {
+    let clause = clause.to_lowercase();
+    clause.starts_with("permitted use")
+        && (clause.contains("dwelling")
+            || clause
+                .match_indices("residential")
+                .any(|(i, _)| !after_non(&clause[..i])))
+}
+
+/// Whether a state has commercial lease statutes implemented
+pub fn has_commercial_rules(state: State) -> bool {
+    state == State::FL
+}
+
+/// Check a commercial document: general terms, then the state's statutes
+pub fn check_commercial_compliance(
+    state: State,
+    text: &str,
+    doc_type: CommercialDocumentType,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if doc_type != CommercialDocumentType::Guaranty {
+        violations.extend(check_cam_reconciliation(text));
+    }
+    violations.extend(check_personal_guaranty(state, text));
+
+    if state == State::FL && doc_type == CommercialDocumentType::Lease {
+        violations.extend(florida_commercial::check_florida_commercial_compliance(
+            text,
+        ));
+    }
+
+    violations
+}
+
+/// Estimated CAM or operating expense charges need a year-end
+/// reconciliation, and tenants should be able to audit the landlord's books
+pub fn check_cam_reconciliation(text: &str) -> Vec<Violation> {
+    let Some(cam) = CAM_PATTERN.find(text) else {
+        return Vec::new();
+    };
+    let position = Some(TextPosition::new(cam.start(), cam.end()));
+    let mut violations = Vec::new();
+
+    if !RECONCILIATION_PATTERN.is_match(text) {
+        violations.push(Violation {
+            statute: "Commercial Lease - CAM Reconciliation".to_string(),
+            severity: Severity::Warning,
+            message: "Lease passes through common area maintenance or operating expenses \
+                      but does not provide an annual reconciliation of estimated charges \
+                      against actual costs."
+                .to_string(),
+            page: None,
+            text_snippet: Some(snippet_around(text, cam.start(), cam.end())),
+            text_position: position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    if !AUDIT_PATTERN.is_match(text) {
+        violations.push(Violation {
+            statute: "Commercial Lease - CAM Audit Right".to_string(),
+            severity: Severity::Info,
+            message: "Lease does not give Tenant the right to audit or inspect the records \
+                      behind CAM and operating expense charges."
+                .to_string(),
+            page: None,
+            text_snippet: Some(snippet_around(text, cam.start(), cam.end())),
+            text_position: position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+/// A personal guaranty must be signed by the guarantor (statute of frauds)
+/// and should state how far the guarantor's liability extends
+pub fn check_personal_guaranty(state: State, text: &str) -> Vec<Violation> {
+    let Some(guaranty) = GUARANTY_PATTERN.find(text) else {
+        return Vec::new();
+    };
+    let position = Some(TextPosition::new(guaranty.start(), guaranty.end()));
+    let mut violations = Vec::new();
+
+    if !GUARANTOR_SIGNATURE_PATTERN.is_match(text) {
+        violations.push(Violation {
+            statute: statute_of_frauds(state).to_string(),
+            severity: Severity::Warning,
+            message: "Lease refers to a personal guaranty but has no guarantor signature \
+                      block. A promise to answer for another's debt is unenforceable \
+                      unless signed by the guarantor."
+                .to_string(),
+            page: None,
+            text_snippet: Some(snippet_around(text, guaranty.start(), guaranty.end())),
+            text_position: position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    if !GUARANTY_SCOPE_PATTERN.is_match(text) {
+        violations.push(Violation {
+            statute: "Commercial Lease - Guaranty Scope Disclosure".to_string(),
+            severity: Severity::Info,
+            message: "Personal guaranty does not state whether the guarantor's liability \
+                      is unlimited or capped. State the maximum amount or that it is \
+                      unlimited."
+                .to_string(),
+            page: None,
+            text_snippet: Some(snippet_around(text, guaranty.start(), guaranty.end())),
+            text_position: position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    violations
+}
+
+/// Statute of frauds provision covering guaranties
+fn statute_of_frauds(state: State) -> &'static str {
+    match state {
+        State::FL => "F.S. § 725.01",
+        State::TX => "Tex. Bus. & Com. Code § 26.01",
+        State::CA => "CA Civil Code § 2793",
+        State::NY => "N.Y. Gen. Oblig. Law § 5-701",
+        _ => "Statute of Frauds (guaranty)",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMERCIAL_LEASE: &str = "COMMERCIAL LEASE AGREEMENT. This commercial lease is \
+        governed by Florida Statutes Chapter 83, Part I (Non-Residential Tenancies). \
+        Permitted Use: retail sale of coffee. Tenant shall pay its proportionate share of \
+        Common Area Maintenance and Operating Expenses as Additional Rent.";
+
+    #[test]
+    fn test_detect_commercial_lease() {
+        assert_eq!(
+            CommercialDocumentType::detect(COMMERCIAL_LEASE),
+            CommercialDocumentType::Lease
+        );
+        // A use clause without the word "commercial"
+        assert_eq!(
+            CommercialDocumentType::detect(
+                "Lease. Tenant pays triple net charges. Permitted Use: dental office."
+            ),
+            CommercialDocumentType::Lease
+        );
+        assert_eq!(
+            CommercialDocumentType::detect(
+                "Lease. The Premises shall be used solely for office purposes."
+            ),
+            CommercialDocumentType::Lease
+        );
+    }
+
+    #[test]
+    fn test_detect_guaranty() {
+        let text = "GUARANTY OF LEASE. For value received, Guarantor guarantees Tenant's \
+                    obligations under the lease.";
+        assert_eq!(
+            CommercialDocumentType::detect(text),
+            CommercialDocumentType::Guaranty
+        );
+    }
+
+    #[test]
+    fn test_residential_lease_is_not_commercial() {
+        let residential = "RESIDENTIAL LEASE. Landlord leases the dwelling unit to Tenant. \
+                           Security deposit: $1,000. No commercial use of the premises.";
+        assert_eq!(
+            CommercialDocumentType::detect(residential),
+            CommercialDocumentType::Unknown
+        );
+        // Commercial terms without a title or use clause are not enough
+        assert_eq!(
+            CommercialDocumentType::detect(
+                "Lease. Tenant pays operating expenses and CAM charges on a triple net basis."
+            ),
+            CommercialDocumentType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_negated_commercial_use_is_not_commercial() {
+        for text in [
+            "Lease. The Premises shall not be used for any non-residential purpose.",
+            "Lease. Tenant shall not use the premises as an office.",
+            "Lease. Use of the premises for commercial purposes is prohibited.",
+            "Lease. This is not a commercial lease.",
+            "Lease. Permitted Use: residential purposes only.",
+        ] {
+            assert_eq!(
+                CommercialDocumentType::detect(text),
+                CommercialDocumentType::Unknown,
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_residential_title_or_dwelling_stays_residential() {
+        // A commercial use clause cannot override a residential title
+        assert_eq!(
+            CommercialDocumentType::detect(
+                "RESIDENTIAL LEASE. Tenant may use one room as a home office. \
+                 The premises may be used as an office."
+            ),
+            CommercialDocumentType::Unknown
+        );
+        assert_eq!(
+            CommercialDocumentType::detect(
+                "LEASE. Landlord leases the dwelling unit to Tenant. Permitted Use: home office."
+            ),
+            CommercialDocumentType::Unknown
+        );
+        // ...but a commercial lease may forbid residential use
+        assert_eq!(
+            CommercialDocumentType::detect(
+                "OFFICE LEASE. Residential tenant protections do not apply. \
+                 No part of the premises shall be used as a dwelling unit."
+            ),
+            CommercialDocumentType::Lease
+        );
+    }
+
+    #[test]
+    fn test_cam_without_reconciliation() {
+        let violations = check_cam_reconciliation(COMMERCIAL_LEASE);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(violations[1].statute, "Commercial Lease - CAM Audit Right");
+
+        let reconciled = format!(
+            "{} Within 90 days after year end Landlord shall deliver an annual statement \
+             reconciling estimated and actual charges. Tenant may audit Landlord's records.",
+            COMMERCIAL_LEASE
+        );
+        assert!(check_cam_reconciliation(&reconciled).is_empty());
+    }
+
+    #[test]
+    fn test_personal_guaranty() {
+        let text = "Tenant's obligations are personally guaranteed by its owner.";
+        let violations = check_personal_guaranty(State::FL, text);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].statute, "F.S. § 725.01");
+
+        let signed = "Tenant's obligations are personally guaranteed by its owner, \
+                      limited to twelve months' rent. Guarantor Signature: ________";
+        assert!(check_personal_guaranty(State::FL, signed).is_empty());
+        assert!(check_personal_guaranty(State::FL, "No guaranty here.").is_empty());
+    }
+}
@@ -3,27 +3,49 @@
 //! Florida Statutes Chapter 83, Part II (§ 83.40-83.682)
 //! "Florida Residential Landlord and Tenant Act"
 
-use crate::extractors::numeric::{
-    extract_days_near_deposit_return, has_bank_location, has_claim_context,
-};
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::numeric::{has_bank_location, has_claim_context};
+use crate::extractors::terms::LeaseTerms;
 use crate::patterns::{
-    contains_semantic_cluster, extract_snippet, find_text_position, AS_IS_KEYWORDS,
-    DISPOSAL_KEYWORDS, FL_LAW_KEYWORDS, NOTICE_KEYWORDS, PROPERTY_KEYWORDS, RIGHTS_KEYWORDS,
-    STRUCTURAL_KEYWORDS, TENANT_KEYWORDS, TERMINATION_KEYWORDS, WAIVER_KEYWORDS,
+    contains_semantic_cluster, extract_snippet, find_text_position, first_keyword,
+    floor_char_boundary, ALL_KEYWORDS, AS_IS_KEYWORDS, DISPOSAL_KEYWORDS, FL_LAW_KEYWORDS,
+    NOTICE_KEYWORDS, PROPERTY_KEYWORDS, RIGHTS_KEYWORDS, STRUCTURAL_KEYWORDS, TENANT_KEYWORDS,
+    TERMINATION_KEYWORDS, WAIVER_KEYWORDS,
 };
+use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, TextPosition, Violation};
 
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[ELECTRONIC_NOTICE, FLOOD_DISCLOSURE];
+
+/// HB 615 electronic notice consent
+pub const ELECTRONIC_NOTICE: DatedRule = DatedRule::from("F.S. § 83.56 (HB 615)", date(2025, 7, 1));
+
+/// SB 948 flood disclosure for residential leases
+pub const FLOOD_DISCLOSURE: DatedRule =
+    DatedRule::from("F.S. § 83.512 (SB 948)", date(2025, 10, 1));
+
+lazy_static! {
+    /// A waiver and a jury trial in the same sentence, either order
+    static ref JURY_WAIVER: Regex = Regex::new(
+        r"(?:waive|waiver|relinquish|forgo|renunci)[^.;]*\b(?:jury|jurado)\b|\b(?:jury|jurado)\b[^.;]*(?:waive|waiver|relinquish|forgo|renunci)"
+    )
+    .unwrap();
+}
+
 /// Check all Florida-specific compliance requirements
-pub fn check_florida_compliance(text: &str) -> Vec<Violation> {
+pub fn check_florida_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_prohibited_provisions(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_attorney_fees(text));
     violations.extend(check_notice_requirements(text));
     violations.extend(check_electronic_notice_consent(text));
     violations.extend(check_flood_disclosure(text));
+    violations.extend(check_radon_notification(text));
 
     violations
 }
@@ -35,77 +57,106 @@ pub fn check_florida_compliance(text: &str) -> Vec<Violation> {
 /// Check for prohibited provisions under Florida Statute § 83.47
 pub fn check_prohibited_provisions(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    // Negated, quoted and recited clauses do not agree to anything
+    let operative = clauses::operative_text(text);
+    let text_lower = operative.to_lowercase();
+
+    // Highlight whichever keyword the clause used, English or Spanish
+    let waiver = first_keyword(&text_lower, WAIVER_KEYWORDS).unwrap_or("waive");
+    let disposal = first_keyword(&text_lower, DISPOSAL_KEYWORDS).unwrap_or("dispose");
+    let as_is = first_keyword(&text_lower, AS_IS_KEYWORDS).unwrap_or("as-is");
 
     // Check for waiver of notice (§ 83.47(1)(c))
     if check_waiver_of_notice(&text_lower) {
-        let text_position = find_text_position(text, "waive").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position = find_text_position(&operative, waiver)
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "F.S. § 83.47(1)(a)".to_string(),
             severity: Severity::Critical,
             message: "Lease contains prohibited waiver of tenant's right to notice before termination or eviction".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "waive")),
+            text_snippet: Some(extract_snippet(&operative, waiver)),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for property disposal clause (§ 83.47(1)(b))
     if check_property_disposal(&text_lower) {
-        let text_position = find_text_position(text, "dispose").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position = find_text_position(&operative, disposal)
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "F.S. § 83.47(1)(b)".to_string(),
             severity: Severity::Critical,
             message: "Lease contains prohibited authorization for landlord to dispose of tenant's property".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "dispose")),
+            text_snippet: Some(extract_snippet(&operative, disposal)),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for AS-IS clauses that waive landlord's structural obligations (§ 83.51)
     if check_as_is_structural(&text_lower) {
-        let text_position = find_text_position(text, "as-is")
-            .or_else(|| find_text_position(text, "as is"))
-            .map(|(start, end)| TextPosition {
-                start_offset: start,
-                end_offset: end,
-            });
+        let text_position =
+            find_text_position(&operative, as_is).map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "F.S. § 83.51(2)(a)".to_string(),
             severity: Severity::Critical,
             message: "AS-IS clause may improperly waive landlord's obligation to maintain structural components".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "as-is")),
+            text_snippet: Some(extract_snippet(&operative, as_is)),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for general waiver of rights (§ 83.47(1)(a))
     if check_general_rights_waiver(&text_lower) {
-        let text_position = find_text_position(text, "waive").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position = find_text_position(&operative, waiver)
+            .map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
             statute: "F.S. § 83.47(1)(a)".to_string(),
             severity: Severity::Critical,
             message: "Lease contains prohibited waiver of tenant's rights under Florida landlord-tenant law".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "waive")),
+            text_snippet: Some(extract_snippet(&operative, waiver)),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        });
+    }
+
+    // Check for waiver of jury trial (§ 83.47(1)(a))
+    if let Some(jury) = jury_trial_waiver(&text_lower) {
+        let text_position =
+            find_text_position(text, jury).map(|(start, end)| TextPosition::new(start, end));
+        violations.push(Violation {
+            statute: "F.S. § 83.47(1)(a)".to_string(),
+            severity: Severity::Critical,
+            message: "Lease contains a waiver of the tenant's right to a jury trial".to_string(),
+            page: None,
+            text_snippet: Some(extract_snippet(text, jury)),
+            text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     violations
 }
 
+/// The jury keyword, if a sentence both waives and mentions a jury trial
+fn jury_trial_waiver(text: &str) -> Option<&'static str> {
+    JURY_WAIVER
+        .is_match(text)
+        .then(|| first_keyword(text, &["jury", "jurado"]))
+        .flatten()
+}
+
 fn check_waiver_of_notice(text: &str) -> bool {
     contains_semantic_cluster(
         text,
@@ -116,8 +167,9 @@ fn check_waiver_of_notice(text: &str) -> bool {
 fn check_property_disposal(text: &str) -> bool {
     let has_disposal = DISPOSAL_KEYWORDS.iter().any(|kw| text.contains(kw));
     let has_property = PROPERTY_KEYWORDS.iter().any(|kw| text.contains(kw));
-    let has_tenant_context =
-        TENANT_KEYWORDS.iter().any(|kw| text.contains(kw)) || text.contains("left by");
+    let has_tenant_context = TENANT_KEYWORDS.iter().any(|kw| text.contains(kw))
+        || text.contains("left by")
+        || text.contains("dejad");
 
     has_disposal && has_property && has_tenant_context
 }
@@ -130,11 +182,16 @@ fn check_as_is_structural(text: &str) -> bool {
 }
 
 fn check_general_rights_waiver(text: &str) -> bool {
-    let has_waiver = WAIVER_KEYWORDS.iter().any(|kw| text.contains(kw));
-    let has_rights = RIGHTS_KEYWORDS.iter().any(|kw| text.contains(kw));
-    let has_law_ref = FL_LAW_KEYWORDS.iter().any(|kw| text.contains(kw));
+    clauses::segment(text).iter().any(|clause| {
+        let clause = clause.text;
+        let has_waiver = WAIVER_KEYWORDS.iter().any(|kw| clause.contains(kw));
+        let has_rights = RIGHTS_KEYWORDS.iter().any(|kw| clause.contains(kw));
+        let has_law_ref = FL_LAW_KEYWORDS.iter().any(|kw| clause.contains(kw));
 
-    has_waiver && has_rights && (has_law_ref || text.contains("all"))
+        let is_general = ALL_KEYWORDS.iter().any(|kw| clause.contains(kw));
+
+        has_waiver && has_rights && (has_law_ref || is_general)
+    })
 }
 
 // ============================================================================
@@ -142,11 +199,11 @@ fn check_general_rights_waiver(text: &str) -> bool {
 // ============================================================================
 
 /// Validates security deposit return timelines and requirements
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Check for deposit return period
-    if let Some(days) = extract_days_near_deposit_return(text) {
+    if let Some(days) = terms.deposit_return_days.as_ref().map(|d| d.value) {
         let has_claim = has_claim_context(text);
 
         // 15-day rule for no claim (§ 83.49(3)(a))
@@ -161,6 +218,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -173,6 +232,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -188,13 +249,17 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
 
     // Check for bank location requirement (§ 83.49(2))
     let text_lower = text.to_lowercase();
-    let mentions_deposit = text_lower.contains("deposit") || text_lower.contains("security");
+    let mentions_deposit = text_lower.contains("deposit")
+        || text_lower.contains("security")
+        || text_lower.contains("depósito");
 
     if mentions_deposit && !has_bank_location(text) {
         violations.push(Violation {
@@ -204,6 +269,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -220,12 +287,17 @@ pub fn check_attorney_fees(text: &str) -> Vec<Violation> {
     let text_lower = text.to_lowercase();
 
     // Check for compliant reciprocal clauses first
-    let has_prevailing_party = text_lower.contains("prevailing party");
+    let has_prevailing_party = text_lower.contains("prevailing party")
+        || text_lower.contains("parte vencedora")
+        || text_lower.contains("parte ganadora")
+        || text_lower.contains("parte que prevalezca");
     let has_both_parties = text_lower.contains("both parties")
         || text_lower.contains("either party")
         || (text_lower.contains("both")
             && text_lower.contains("landlord")
-            && text_lower.contains("tenant"));
+            && text_lower.contains("tenant"))
+        || text_lower.contains("ambas partes")
+        || text_lower.contains("cualquiera de las partes");
 
     if has_prevailing_party || has_both_parties {
         return violations;
@@ -233,7 +305,9 @@ pub fn check_attorney_fees(text: &str) -> Vec<Violation> {
 
     // Check for attorney fee mentions
     let has_attorney_fees = text_lower.contains("attorney")
-        && (text_lower.contains("fee") || text_lower.contains("cost"));
+        && (text_lower.contains("fee") || text_lower.contains("cost"))
+        || text_lower.contains("abogado")
+            && (text_lower.contains("honorarios") || text_lower.contains("costos"));
 
     if !has_attorney_fees {
         return violations;
@@ -244,7 +318,10 @@ pub fn check_attorney_fees(text: &str) -> Vec<Violation> {
         || text_lower.contains("landlord shall be entitled to attorney fee")
         || text_lower.contains("lessor is entitled to attorney fee")
         || text_lower.contains("landlord is entitled to recover attorney fee")
-        || text_lower.contains("landlord may recover attorney fee");
+        || text_lower.contains("landlord may recover attorney fee")
+        || text_lower.contains("arrendador tendrá derecho a recuperar los honorarios")
+        || text_lower.contains("arrendador tendrá derecho a los honorarios")
+        || text_lower.contains("arrendador podrá recuperar los honorarios");
 
     // Check if tenant has obligation to pay landlord's fees/costs
     let has_tenant_pay_obligation = text_lower.contains("tenant shall pay")
@@ -252,14 +329,21 @@ pub fn check_attorney_fees(text: &str) -> Vec<Violation> {
         || text_lower.contains("tenant agrees to pay")
             && (text_lower.contains("landlord")
                 || text_lower.contains("attorney")
-                || text_lower.contains("legal"));
+                || text_lower.contains("legal"))
+        || (text_lower.contains("inquilino pagará")
+            || text_lower.contains("inquilino deberá pagar")
+            || text_lower.contains("inquilino se compromete a pagar"))
+            && (text_lower.contains("arrendador") || text_lower.contains("abogado"));
 
     // Check if tenant has reciprocal rights
     let has_tenant_fee_right = text_lower.contains("tenant is entitled to attorney fee")
         || text_lower.contains("tenant shall be entitled to attorney fee")
         || text_lower.contains("lessee is entitled to attorney fee")
         || text_lower.contains("tenant is entitled to recover attorney fee")
-        || text_lower.contains("tenant may recover attorney fee");
+        || text_lower.contains("tenant may recover attorney fee")
+        || text_lower.contains("inquilino tendrá derecho a recuperar los honorarios")
+        || text_lower.contains("inquilino tendrá derecho a los honorarios")
+        || text_lower.contains("inquilino podrá recuperar los honorarios");
 
     if (has_landlord_fee_right || has_tenant_pay_obligation) && !has_tenant_fee_right {
         violations.push(Violation {
@@ -267,8 +351,10 @@ pub fn check_attorney_fees(text: &str) -> Vec<Violation> {
             severity: Severity::Critical,
             message: "Attorney fee clause is not reciprocal. Florida Statute § 83.48 requires that if the landlord can recover attorney fees, the tenant must have the same right. Use 'prevailing party' language or ensure mutual fee recovery rights.".to_string(),
             page: None,
-            text_snippet: Some(if text.len() <= 200 { text.to_string() } else { format!("{}...", &text[..200]) }),
+            text_snippet: Some(if text.len() <= 200 { text.to_string() } else { format!("{}...", &text[..floor_char_boundary(text, 200)]) }),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -298,6 +384,8 @@ fn check_nonpayment_notice(text: &str) -> Vec<Violation> {
     let nonpayment_patterns = [
         r"(\d+)\s*(?:day|business\s*day)s?\s*(?:notice|written\s*notice).*?(?:nonpayment|non-payment|rent)",
         r"(?:nonpayment|non-payment|rent).*?(\d+)\s*(?:day|business\s*day)s?\s*(?:notice|written\s*notice)",
+        r"(\d+)\s*d[ií]as?\s*(?:h[aá]biles\s*)?de\s*(?:aviso|notificaci[oó]n|preaviso).*?(?:falta\s+de\s+pago|renta|alquiler)",
+        r"(?:falta\s+de\s+pago|renta|alquiler).*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*d[ií]as?",
     ];
 
     for pattern in &nonpayment_patterns {
@@ -316,6 +404,8 @@ fn check_nonpayment_notice(text: &str) -> Vec<Violation> {
                                 page: None,
                                 text_snippet: Some(caps.get(0).unwrap().as_str().to_string()),
                                 text_position: None,
+                                remediation: None,
+                                bounding_boxes: Vec::new(),
                             });
                         }
                     }
@@ -334,6 +424,8 @@ fn check_lease_violation_notice(text: &str) -> Vec<Violation> {
     let violation_patterns = [
         r"(\d+)\s*(?:day|business\s*day)s?\s*(?:notice|written\s*notice).*?(?:violation|breach|default|noncompliance|non-compliance)",
         r"(?:violation|breach|default|noncompliance|non-compliance).*?(\d+)\s*(?:day|business\s*day)s?\s*(?:notice|written\s*notice)",
+        r"(\d+)\s*d[ií]as?\s*(?:h[aá]biles\s*)?de\s*(?:aviso|notificaci[oó]n|preaviso).*?(?:incumplimiento|violaci[oó]n|infracci[oó]n)",
+        r"(?:incumplimiento|violaci[oó]n|infracci[oó]n).*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*d[ií]as?",
     ];
 
     for pattern in &violation_patterns {
@@ -352,6 +444,8 @@ fn check_lease_violation_notice(text: &str) -> Vec<Violation> {
                                 page: None,
                                 text_snippet: Some(caps.get(0).unwrap().as_str().to_string()),
                                 text_position: None,
+                                remediation: None,
+                                bounding_boxes: Vec::new(),
                             });
                         }
                     }
@@ -370,6 +464,8 @@ fn check_termination_notice(text: &str) -> Vec<Violation> {
     let termination_patterns = [
         r"(\d+)\s*(?:day|business\s*day)s?\s*(?:notice|written\s*notice).*?(?:terminat|end|cancel).*?(?:month-to-month|monthly)",
         r"(?:month-to-month|monthly).*?(?:terminat|end|cancel).*?(\d+)\s*(?:day|business\s*day)s?\s*(?:notice|written\s*notice)",
+        r"(\d+)\s*d[ií]as?\s*de\s*(?:aviso|notificaci[oó]n|preaviso).*?(?:terminar|terminaci[oó]n|rescindir|cancelar).*?(?:mes\s+a\s+mes|mensual)",
+        r"(?:mes\s+a\s+mes|mensual).*?(?:terminar|terminaci[oó]n|rescindir|cancelar).*?(?:aviso|notificaci[oó]n|preaviso)(?:\s+por\s+escrito)?\s+de\s+(\d+)\s*d[ií]as?",
     ];
 
     for pattern in &termination_patterns {
@@ -388,6 +484,8 @@ fn check_termination_notice(text: &str) -> Vec<Violation> {
                                 page: None,
                                 text_snippet: Some(caps.get(0).unwrap().as_str().to_string()),
                                 text_position: None,
+                                remediation: None,
+                                bounding_boxes: Vec::new(),
                             });
                         }
                     }
@@ -415,7 +513,9 @@ pub fn check_electronic_notice_consent(text: &str) -> Vec<Violation> {
     // Check if lease mentions electronic/email notices
     let mentions_email_notices = (text_lower.contains("email")
         || text_lower.contains("electronic"))
-        && text_lower.contains("notice");
+        && text_lower.contains("notice")
+        || (text_lower.contains("correo electrónico") || text_lower.contains("electrónic"))
+            && (text_lower.contains("aviso") || text_lower.contains("notificaci"));
 
     if !mentions_email_notices {
         // No email notice mentioned, no check needed
@@ -434,21 +534,27 @@ pub fn check_electronic_notice_consent(text: &str) -> Vec<Violation> {
         || text_lower.contains("explicitly consent")
         || text_lower.contains("agree to receive")
             && text_lower.contains("electronic")
-            && text_lower.contains("notice");
+            && text_lower.contains("notice")
+        || text_lower.contains("consiente expresamente")
+        || text_lower.contains("consentimiento expreso")
+        || text_lower.contains("acepta recibir")
+            && text_lower.contains("electrónic")
+            && (text_lower.contains("aviso") || text_lower.contains("notificaci"));
 
     if !has_hb615_consent && !has_explicit_consent {
-        let text_position = find_text_position(text, "email").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let email = first_keyword(&text_lower, &["email", "correo electrónico"]).unwrap_or("email");
+        let text_position =
+            find_text_position(text, email).map(|(start, end)| TextPosition::new(start, end));
         violations.push(Violation {
-            statute: "F.S. § 83.56 (HB 615)".to_string(),
+            statute: ELECTRONIC_NOTICE.citation.to_string(),
             severity: Severity::Warning,
             message: "Lease mentions electronic/email notices but lacks explicit consent language required by HB 615. \
                      Add an Electronic Notice Consent addendum with explicit tenant consent to receive legally required notices via email.".to_string(),
             page: None,
-            text_snippet: Some(extract_snippet(text, "email")),
+            text_snippet: Some(extract_snippet(text, email)),
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -472,24 +578,33 @@ pub fn check_flood_disclosure(text: &str) -> Vec<Violation> {
     let text_lower = text.to_lowercase();
 
     // Check for proper flood disclosure language
-    let has_flood_disclosure_header =
-        text_lower.contains("flood disclosure") || text_lower.contains("83.512");
+    let has_flood_disclosure_header = text_lower.contains("flood disclosure")
+        || text_lower.contains("83.512")
+        || text_lower.contains("divulgación de inundaciones")
+        || text_lower.contains("divulgación sobre inundaciones");
 
     // Required disclosure elements per SB 948
     let has_flooding_history = text_lower.contains("knowledge of")
         && (text_lower.contains("flood") || text_lower.contains("flooding"))
         || text_lower.contains("prior flooding")
         || text_lower.contains("past flooding")
-        || text_lower.contains("no knowledge of") && text_lower.contains("flood");
+        || text_lower.contains("no knowledge of") && text_lower.contains("flood")
+        || text_lower.contains("conocimiento de") && text_lower.contains("inundaci")
+        || text_lower.contains("inundaciones previas")
+        || text_lower.contains("inundaciones anteriores");
 
     let has_insurance_claims = text_lower.contains("flood insurance")
         && text_lower.contains("claim")
-        || text_lower.contains("insurance claim") && text_lower.contains("flood");
+        || text_lower.contains("insurance claim") && text_lower.contains("flood")
+        || (text_lower.contains("seguro contra inundaciones")
+            || text_lower.contains("seguro de inundación"))
+            && (text_lower.contains("reclamo") || text_lower.contains("reclamación"));
 
     let has_federal_assistance = text_lower.contains("fema")
         || text_lower.contains("federal")
             && (text_lower.contains("flood") || text_lower.contains("assistance"))
-        || text_lower.contains("federal flood assistance");
+        || text_lower.contains("federal flood assistance")
+        || text_lower.contains("asistencia federal");
 
     // Full compliance requires all three elements
     let is_fully_compliant = has_flood_disclosure_header
@@ -498,10 +613,9 @@ pub fn check_flood_disclosure(text: &str) -> Vec<Violation> {
         && has_federal_assistance;
 
     if !is_fully_compliant {
-        let text_position = find_text_position(text, "flood").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let flood = first_keyword(&text_lower, &["flood", "inundaci"]);
+        let text_position = find_text_position(text, flood.unwrap_or("flood"))
+            .map(|(start, end)| TextPosition::new(start, end));
 
         let mut missing_elements = Vec::new();
         if !has_flood_disclosure_header {
@@ -518,7 +632,7 @@ pub fn check_flood_disclosure(text: &str) -> Vec<Violation> {
         }
 
         violations.push(Violation {
-            statute: "F.S. § 83.512 (SB 948)".to_string(),
+            statute: FLOOD_DISCLOSURE.citation.to_string(),
             severity: Severity::Warning,
             message: format!(
                 "Lease is missing required flood disclosure elements per § 83.512. Missing: {}. \
@@ -526,39 +640,139 @@ pub fn check_flood_disclosure(text: &str) -> Vec<Violation> {
                 missing_elements.join(", ")
             ),
             page: None,
-            text_snippet: if text_lower.contains("flood") {
-                Some(extract_snippet(text, "flood"))
+            text_snippet: if let Some(flood) = flood {
+                Some(extract_snippet(text, flood))
             } else {
                 Some(text.chars().take(100).collect())
             },
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     violations
 }
 
+// ============================================================================
+// § 404.056(5) - Radon Gas Notification
+// ============================================================================
+
+/// Check for the radon gas notification per Florida Statute § 404.056(5)
+///
+/// Every rental agreement for a building in Florida must include the
+/// statutory notice that radon is a naturally occurring radioactive gas.
+pub fn check_radon_notification(text: &str) -> Vec<Violation> {
+    let text_lower = text.to_lowercase();
+    let radon = first_keyword(&text_lower, &["radon", "radón"]);
+    let has_radioactive_gas = (text_lower.contains("radioactive")
+        || text_lower.contains("radiactivo"))
+        && text_lower.contains("gas");
+
+    if radon.is_some() && has_radioactive_gas {
+        return Vec::new();
+    }
+
+    let message = if radon.is_some() {
+        "Radon notice does not state that radon is a naturally occurring radioactive gas, as the \
+         statutory notification requires"
+    } else {
+        "Lease is missing the radon gas notification that § 404.056(5) requires in every rental \
+         agreement"
+    };
+    let text_position = radon
+        .and_then(|radon| find_text_position(text, radon))
+        .map(|(start, end)| TextPosition::new(start, end));
+    vec![Violation {
+        statute: "F.S. § 404.056".to_string(),
+        severity: Severity::Critical,
+        message: message.to_string(),
+        page: None,
+        text_snippet: Some(match radon {
+            Some(radon) => extract_snippet(text, radon),
+            None => text.chars().take(100).collect(),
+        }),
+        text_position,
+        remediation: None,
+        bounding_boxes: Vec::new(),
+    }]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_florida_compliance(text, &LeaseTerms::extract(text))
+    }
+
     #[test]
     fn test_florida_combined_check() {
         let text = "Tenant waives notice. Deposit returned in 45 days. Tenant pays landlord attorney fees.";
-        let violations = check_florida_compliance(text);
+        let violations = check(text);
 
         // Should detect multiple violations
         assert!(violations.len() >= 2);
     }
 
+    #[test]
+    fn test_negated_waiver_is_not_prohibited() {
+        let text = "Landlord shall NOT require Tenant to waive any rights or notice \
+                    before termination.";
+        assert!(check_prohibited_provisions(text).is_empty());
+
+        let text = "Landlord shall not enter without notice, and Tenant waives notice \
+                    before termination.";
+        let violations = check_prohibited_provisions(text);
+        let waiver = &violations[0];
+        assert_eq!(waiver.statute, "F.S. § 83.47(1)(a)");
+        let position = waiver.text_position.as_ref().unwrap();
+        assert!(text[position.start_offset..position.end_offset].contains("waives"));
+    }
+
+    #[test]
+    fn test_radon_notification() {
+        let text = "RADON GAS: Radon is a naturally occurring radioactive gas that, when it \
+                    has accumulated in a building in sufficient quantities, may present \
+                    health risks.";
+        assert!(check_radon_notification(text).is_empty());
+
+        let violations = check_radon_notification("Radon testing is available on request.");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].statute, "F.S. § 404.056");
+        assert!(violations[0].message.contains("radioactive gas"));
+        assert!(violations[0].text_position.is_some());
+
+        let violations = check_radon_notification("This is a lease agreement.");
+        assert!(violations[0].message.contains("missing"));
+    }
+
+    #[test]
+    fn test_jury_trial_waiver() {
+        let violations =
+            check_prohibited_provisions("Tenant hereby waives all rights to jury trial.");
+        assert!(violations
+            .iter()
+            .any(|v| v.message.contains("jury trial") && v.severity == Severity::Critical));
+
+        // A jury mentioned in another sentence is not waived
+        let text = "Tenant waives the right to a parking space. Any dispute may go to a jury.";
+        assert!(check_prohibited_provisions(text)
+            .iter()
+            .all(|v| !v.message.contains("jury trial")));
+        let text = "Landlord shall not require Tenant to waive trial by jury.";
+        assert!(check_prohibited_provisions(text).is_empty());
+    }
+
     #[test]
     fn test_compliant_florida_lease() {
         let text = "This residential lease is for property at 123 Main St. \
                     Security deposit held at First National Bank, Miami, Florida. \
                     Landlord returns deposit within 15 days if no claim. \
                     The prevailing party shall be entitled to reasonable attorney fees. \
-                    Tenant shall receive 3 business days notice for nonpayment of rent.";
-        let violations = check_florida_compliance(text);
+                    Tenant shall receive 3 business days notice for nonpayment of rent. \
+                    RADON GAS: Radon is a naturally occurring radioactive gas.";
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -575,7 +789,7 @@ mod tests {
     fn test_hb615_missing_email_consent_warns() {
         // Lease mentions email for notices but lacks explicit HB 615 consent
         let text = "Notices may be sent via email. Monthly rent: $2,000.";
-        let violations = check_florida_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.statute.contains("83.56")
@@ -594,7 +808,7 @@ mod tests {
                     Tenant email: tenant@example.com. \
                     Security deposit held at First National Bank, Miami, Florida. \
                     Landlord returns deposit within 15 days if no claim.";
-        let violations = check_florida_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -616,7 +830,7 @@ mod tests {
         let text = "This residential lease is for property at 123 Main St, Tampa, FL. \
                     Security deposit held at First National Bank, Miami, Florida. \
                     Landlord returns deposit within 15 days if no claim.";
-        let violations = check_florida_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.statute.contains("83.512")
@@ -636,7 +850,7 @@ mod tests {
                     (3) No federal flood assistance has been received for this property. \
                     Security deposit held at First National Bank, Miami, Florida. \
                     Landlord returns deposit within 15 days if no claim.";
-        let violations = check_florida_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations.iter().any(|v| v.statute.contains("83.512")),
@@ -650,7 +864,7 @@ mod tests {
         // Lease mentions flood but doesn't have all required elements
         let text = "This property may be in a flood zone. Check with FEMA for details. \
                     Security deposit held at First National Bank, Miami, Florida.";
-        let violations = check_florida_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -660,4 +874,96 @@ mod tests {
             violations
         );
     }
+
+    // ========================================================================
+    // Spanish Leases
+    // ========================================================================
+
+    #[test]
+    fn test_spanish_rules() {
+        let cases = [
+            (
+                "F.S. § 83.47(1)(a)",
+                "El Inquilino renuncia a cualquier aviso antes de la terminación del contrato.",
+            ),
+            (
+                "F.S. § 83.47(1)(b)",
+                "El Arrendador podrá desechar las pertenencias dejadas por el Inquilino.",
+            ),
+            (
+                "F.S. § 83.51(2)(a)",
+                "El Inquilino acepta la unidad tal como está, incluida la plomería.",
+            ),
+            (
+                "F.S. § 83.47(1)(a)",
+                "El Inquilino renuncia a todos sus derechos según el capítulo 83.",
+            ),
+            (
+                "F.S. § 83.49(3)(a)",
+                "El Arrendador devolverá el depósito dentro de los 20 días.",
+            ),
+            (
+                "F.S. § 83.49(3)(b)",
+                "El Arrendador devolverá el depósito dentro de los 45 días.",
+            ),
+            (
+                "F.S. § 83.49(2)",
+                "El depósito de seguridad se guardará en una cuenta.",
+            ),
+            (
+                "F.S. § 83.48",
+                "El Inquilino pagará los honorarios de abogado del Arrendador.",
+            ),
+            (
+                "F.S. § 83.56(3)",
+                "El Inquilino recibirá 2 días de aviso por falta de pago de la renta.",
+            ),
+            (
+                "F.S. § 83.56(2)",
+                "En caso de incumplimiento, el Inquilino recibirá un aviso de 3 días.",
+            ),
+            (
+                "F.S. § 83.57",
+                "Cualquiera de las partes puede dar 7 días de aviso para terminar el \
+                 arrendamiento de mes a mes.",
+            ),
+            (
+                "F.S. § 83.56 (HB 615)",
+                "Los avisos se enviarán por correo electrónico.",
+            ),
+            ("F.S. § 83.512 (SB 948)", "Renta mensual: $1,500."),
+        ];
+        for (statute, text) in cases {
+            assert!(
+                check(text).iter().any(|v| v.statute == statute),
+                "{statute} not found in {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_spanish_compliant_clauses() {
+        // Negated waiver
+        let text =
+            "El Arrendador no exigirá que el Inquilino renuncie al aviso antes del desalojo.";
+        assert!(!check(text).iter().any(|v| v.statute.contains("83.47")));
+
+        // Reciprocal attorney fees
+        let text = "La parte vencedora tendrá derecho a recuperar los honorarios de abogado.";
+        assert!(!check(text).iter().any(|v| v.statute == "F.S. § 83.48"));
+
+        // Full flood disclosure and bank location
+        let text = "Divulgación de inundaciones (§ 83.512): El Arrendador no tiene conocimiento \
+                    de inundaciones previas. No se han presentado reclamos al seguro contra \
+                    inundaciones. No se ha recibido asistencia federal de FEMA. El depósito se \
+                    guarda en Banco Popular, Miami, Florida.";
+        let violations = check(text);
+        assert!(
+            !violations
+                .iter()
+                .any(|v| v.statute.contains("83.512") || v.statute == "F.S. § 83.49(2)"),
+            "{:?}",
+            violations
+        );
+    }
 }
@@ -0,0 +1,245 @@
+//! Florida Commercial Lease Compliance
+//!
+//! Florida Statutes Chapter 83, Part I (Non-Residential Tenancies) and the
+//! sales tax on commercial rent.
+//!
+//! Key Statutes:
+//! - § 83.05(2) - Possession recovered only through the courts, surrender or abandonment
+//! - § 83.20(2) - 3 days' written notice before removal for nonpayment
+//! - § 212.031 - Sales tax on commercial rent, repealed October 1, 2025
+
+use crate::effective::{date, DatedRule};
+use crate::patterns::snippet_around;
+use lazy_static::lazy_static;
+use regex::Regex;
+use shared_types::{Severity, TextPosition, Violation};
+
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[SALES_TAX_COLLECTION, SALES_TAX_REPEAL];
+
+/// Sales tax on commercial rent, collected through September 2025
+pub const SALES_TAX_COLLECTION: DatedRule =
+    DatedRule::until("F.S. § 212.031 (collection)", date(2025, 9, 30));
+
+/// HB 7031 repeal of the sales tax on commercial rent
+pub const SALES_TAX_REPEAL: DatedRule =
+    DatedRule::from("F.S. § 212.031 (repeal)", date(2025, 10, 1));
+
+lazy_static! {
+    /// Landlord retaking the premises itself
+    static ref SELF_HELP_PATTERN: Regex = Regex::new(
+        r"(?i)(change\s+(?:the\s+)?locks|re-?key|lock\s+(?:out|tenant\s+out)|re-?enter|retake\s+possession|remove\s+tenant)"
+    )
+    .unwrap();
+
+    /// Language making the above conditional on no court process
+    static ref WITHOUT_PROCESS_PATTERN: Regex = Regex::new(
+        r"(?i)(without\s+(?:resort\s+to\s+)?(?:legal|judicial)\s+(?:process|proceedings?)|without\s+(?:a\s+)?court\s+order|self.help)"
+    )
+    .unwrap();
+
+    /// "N days' notice" in a sentence about unpaid rent
+    static ref NOTICE_DAYS_PATTERN: Regex = Regex::new(
+        r"(?i)\b(\d+|one|two)\s*(?:\(\d+\)\s*)?(?:business\s+|calendar\s+)?days?['’]?\s+(?:prior\s+)?(?:written\s+)?notice"
+    )
+    .unwrap();
+
+    static ref NONPAYMENT_PATTERN: Regex =
+        Regex::new(r"(?i)(nonpayment|non-payment|fail(?:s|ure)?\s+to\s+pay|unpaid|default\s+in\s+(?:the\s+)?payment)").unwrap();
+
+    static ref SALES_TAX_PATTERN: Regex =
+        Regex::new(r"(?i)(sales\s+tax|212\.031)").unwrap();
+
+    /// Language limiting the tax to periods before the repeal
+    static ref REPEAL_PATTERN: Regex = Regex::new(
+        r"(?i)(repeal|september\s+30,\s+2025|october\s+1,\s+2025|to\s+the\s+extent\s+(?:required|imposed)\s+by\s+law|if\s+applicable)"
+    )
+    .unwrap();
+}
+
+/// Check all Florida commercial lease requirements
+pub fn check_florida_commercial_compliance(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    violations.extend(check_self_help_possession(text));
+    violations.extend(check_nonpayment_notice(text));
+    violations.extend(check_sales_tax_on_rent(text));
+
+    violations
+}
+
+/// § 83.05(2): a landlord may recover possession only through an action
+/// for possession, or after the tenant surrenders or abandons the premises
+pub fn check_self_help_possession(text: &str) -> Vec<Violation> {
+    sentences(text)
+        .filter(|(_, sentence)| {
+            SELF_HELP_PATTERN.is_match(sentence) && WITHOUT_PROCESS_PATTERN.is_match(sentence)
+        })
+        .take(1)
+        .map(|(start, sentence)| Violation {
+            statute: "F.S. § 83.05(2)".to_string(),
+            severity: Severity::Critical,
+            message: "Lease lets Landlord retake the premises without court process. \
+                      A Florida commercial landlord may recover possession only through \
+                      an action for possession or after surrender or abandonment."
+                .to_string(),
+            page: None,
+            text_snippet: Some(snippet_around(text, start, start + sentence.len())),
+            text_position: Some(TextPosition::new(start, start + sentence.len())),
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        })
+        .collect()
+}
+
+/// § 83.20(2): removal for nonpayment requires 3 days' written notice
+pub fn check_nonpayment_notice(text: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for (start, sentence) in sentences(text) {
+        if !NONPAYMENT_PATTERN.is_match(sentence) {
+            continue;
+        }
+        for caps in NOTICE_DAYS_PATTERN.captures_iter(sentence) {
+            let days = match caps[1].to_lowercase().as_str() {
+                "one" => 1,
+                "two" => 2,
+                n => n.parse().unwrap_or(u32::MAX),
+            };
+            if days < 3 {
+                let found = caps.get(0).unwrap();
+                violations.push(Violation {
+                    statute: "F.S. § 83.20(2)".to_string(),
+                    severity: Severity::Warning,
+                    message: format!(
+                        "Notice for nonpayment of rent must be at least 3 days in writing \
+                         before Landlord may seek possession. Found: {} day(s)",
+                        days
+                    ),
+                    page: None,
+                    text_snippet: Some(snippet_around(
+                        text,
+                        start + found.start(),
+                        start + found.end(),
+                    )),
+                    text_position: Some(TextPosition::new(
+                        start + found.start(),
+                        start + found.end(),
+                    )),
+                    remediation: None,
+                    bounding_boxes: Vec::new(),
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// § 212.031: until September 30, 2025 the lease should say who pays the
+/// sales tax on rent; from October 1, 2025 the tax is repealed and the
+/// tenant should not be charged it
+pub fn check_sales_tax_on_rent(text: &str) -> Vec<Violation> {
+    let Some(tax) = SALES_TAX_PATTERN.find(text) else {
+        return vec![Violation {
+            statute: SALES_TAX_COLLECTION.citation.to_string(),
+            severity: Severity::Warning,
+            message: "Lease does not say who pays Florida sales tax on commercial rent. \
+                      The tax applied to rent for occupancy through September 30, 2025."
+                .to_string(),
+            page: None,
+            text_snippet: Some(text.chars().take(100).collect()),
+            text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
+        }];
+    };
+
+    if REPEAL_PATTERN.is_match(text) {
+        return Vec::new();
+    }
+    vec![Violation {
+        statute: SALES_TAX_REPEAL.citation.to_string(),
+        severity: Severity::Warning,
+        message: "Lease charges Tenant sales tax on rent without accounting for its repeal. \
+                  Florida's sales tax on commercial rent does not apply to occupancy \
+                  periods beginning on or after October 1, 2025."
+            .to_string(),
+        page: None,
+        text_snippet: Some(snippet_around(text, tax.start(), tax.end())),
+        text_position: Some(TextPosition::new(tax.start(), tax.end())),
+        remediation: None,
+        bounding_boxes: Vec::new(),
+    }]
+}
+
+/// Get covered statutes for Florida commercial leases
+pub fn covered_commercial_statutes() -> Vec<&'static str> {
+    vec![
+        "F.S. § 83.05(2) - Recovery of possession",
+        "F.S. § 83.20(2) - Nonpayment notice",
+        "F.S. § 212.031 (collection) - Sales tax on commercial rent",
+        "F.S. § 212.031 (repeal) - Sales tax repeal",
+        "F.S. § 725.01 - Guaranty signed by guarantor",
+    ]
+}
+
+/// Sentences with their byte offsets
+fn sentences(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_inclusive(['.', '\n'])
+        .scan(0, |offset, sentence| {
+            let start = *offset;
+            *offset += sentence.len();
+            Some((start, sentence))
+        })
+        .filter(|(_, sentence)| !sentence.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_help_possession() {
+        let text = "Upon default Landlord may change the locks and re-enter the Premises \
+                    without resort to legal process.";
+        let violations = check_self_help_possession(text);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].statute, "F.S. § 83.05(2)");
+        assert_eq!(violations[0].severity, Severity::Critical);
+
+        let lawful = "Upon default Landlord may re-enter the Premises after obtaining a \
+                      judgment for possession.";
+        assert!(check_self_help_possession(lawful).is_empty());
+    }
+
+    #[test]
+    fn test_nonpayment_notice() {
+        let text = "If Tenant fails to pay rent, Landlord may terminate this Lease on one \
+                    (1) day written notice.";
+        let violations = check_nonpayment_notice(text);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Found: 1 day"));
+
+        let compliant = "If Tenant fails to pay rent, Landlord may terminate this Lease on \
+                         three (3) days' written notice.";
+        assert!(check_nonpayment_notice(compliant).is_empty());
+        // Short notice about something other than rent is not covered
+        assert!(check_nonpayment_notice("Landlord gives 1 day notice before entry.").is_empty());
+    }
+
+    #[test]
+    fn test_sales_tax_on_rent() {
+        let silent = check_sales_tax_on_rent("Base Rent is $4,000 per month.");
+        assert_eq!(silent[0].statute, "F.S. § 212.031 (collection)");
+
+        let charged = check_sales_tax_on_rent(
+            "Tenant shall pay Florida sales tax on all Base Rent and Additional Rent.",
+        );
+        assert_eq!(charged[0].statute, "F.S. § 212.031 (repeal)");
+
+        let transitional = "Tenant shall pay sales tax on rent for occupancy periods \
+                            through September 30, 2025; no tax is due after the repeal.";
+        assert!(check_sales_tax_on_rent(transitional).is_empty());
+    }
+}
@@ -14,10 +14,18 @@
 //! - 42 U.S.C. § 4852d - Lead Paint Disclosure (pre-1978)
 //! - Johnson v. Davis (1985) - Material Defect Disclosure
 
+use crate::effective::{date, DatedRule};
 use crate::patterns::{extract_snippet, find_text_position};
 use regex::Regex;
 use shared_types::{Severity, TextPosition, Violation};
 
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[FLOOD_DISCLOSURE];
+
+/// SB 948 flood disclosure for sales
+pub const FLOOD_DISCLOSURE: DatedRule =
+    DatedRule::from("F.S. § 689.302 (SB 948)", date(2025, 10, 1));
+
 /// Document type for Florida real estate transactions
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RealEstateDocumentType {
@@ -160,10 +168,8 @@ pub fn check_radon_disclosure(text: &str) -> Vec<Violation> {
         && has_testing_recommendation;
 
     if !is_compliant {
-        let text_position = find_text_position(text, "radon").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position =
+            find_text_position(text, "radon").map(|(start, end)| TextPosition::new(start, end));
 
         let mut missing = Vec::new();
         if !has_radon_header {
@@ -197,6 +203,8 @@ pub fn check_radon_disclosure(text: &str) -> Vec<Violation> {
                 Some(text.chars().take(100).collect())
             },
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -226,10 +234,8 @@ pub fn check_property_tax_disclosure(text: &str) -> Vec<Violation> {
     let is_compliant = has_tax_disclosure && has_reassessment_warning;
 
     if !is_compliant {
-        let text_position = find_text_position(text, "tax").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position =
+            find_text_position(text, "tax").map(|(start, end)| TextPosition::new(start, end));
 
         let mut missing = Vec::new();
         if !has_tax_disclosure {
@@ -257,6 +263,8 @@ pub fn check_property_tax_disclosure(text: &str) -> Vec<Violation> {
                 Some(text.chars().take(100).collect())
             },
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -300,10 +308,8 @@ pub fn check_flood_disclosure_realestate(text: &str) -> Vec<Violation> {
         has_flood_header && has_flooding_history && has_insurance_claims && has_federal_assistance;
 
     if !is_fully_compliant {
-        let text_position = find_text_position(text, "flood").map(|(start, end)| TextPosition {
-            start_offset: start,
-            end_offset: end,
-        });
+        let text_position =
+            find_text_position(text, "flood").map(|(start, end)| TextPosition::new(start, end));
 
         let mut missing = Vec::new();
         if !has_flood_header {
@@ -320,7 +326,7 @@ pub fn check_flood_disclosure_realestate(text: &str) -> Vec<Violation> {
         }
 
         violations.push(Violation {
-            statute: "F.S. § 689.302 (SB 948)".to_string(),
+            statute: FLOOD_DISCLOSURE.citation.to_string(),
             severity: Severity::Critical,
             message: format!(
                 "Missing required flood disclosure elements per § 689.302. Effective October 1, 2025, \
@@ -334,6 +340,8 @@ pub fn check_flood_disclosure_realestate(text: &str) -> Vec<Violation> {
                 Some(text.chars().take(100).collect())
             },
             text_position,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -370,6 +378,8 @@ pub fn check_hoa_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: Some(text.chars().take(100).collect()),
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
         return violations;
@@ -419,10 +429,9 @@ pub fn check_hoa_disclosure(text: &str) -> Vec<Violation> {
             ),
             page: None,
             text_snippet: Some(extract_snippet(text, "hoa")),
-            text_position: find_text_position(text, "hoa").map(|(start, end)| TextPosition {
-                start_offset: start,
-                end_offset: end,
-            }),
+            text_position: find_text_position(text, "hoa").map(|(start, end)| TextPosition::new(start, end)),
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -459,6 +468,8 @@ pub fn check_energy_efficiency_disclosure(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -498,6 +509,8 @@ pub fn check_material_defect_disclosure(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -561,6 +574,8 @@ pub fn check_lead_paint_disclosure(text: &str, year_built: Option<u32>) -> Vec<V
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     } else {
         // Check for complete disclosure
@@ -585,10 +600,10 @@ pub fn check_lead_paint_disclosure(text: &str, year_built: Option<u32>) -> Vec<V
                 ),
                 page: None,
                 text_snippet: Some(extract_snippet(text, "lead")),
-                text_position: find_text_position(text, "lead").map(|(start, end)| TextPosition {
-                    start_offset: start,
-                    end_offset: end,
-                }),
+                text_position: find_text_position(text, "lead")
+                    .map(|(start, end)| TextPosition::new(start, end)),
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -660,6 +675,8 @@ pub fn check_earnest_money(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(extract_snippet(text, "earnest")),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -672,6 +689,8 @@ pub fn check_earnest_money(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -685,6 +704,8 @@ pub fn check_earnest_money(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -713,6 +734,8 @@ pub fn check_closing_provisions(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -728,6 +751,8 @@ pub fn check_closing_provisions(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -772,6 +797,8 @@ pub fn check_escalation_addendum(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -784,6 +811,8 @@ pub fn check_escalation_addendum(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -795,6 +824,8 @@ pub fn check_escalation_addendum(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -808,6 +839,8 @@ pub fn check_escalation_addendum(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -824,6 +857,8 @@ pub fn check_escalation_addendum(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -878,6 +913,8 @@ pub fn check_brokerage_relationship(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     } else if !has_relationship_type {
         violations.push(Violation {
@@ -888,10 +925,10 @@ pub fn check_brokerage_relationship(text: &str) -> Vec<Violation> {
                 .to_string(),
             page: None,
             text_snippet: Some(extract_snippet(text, "brokerage")),
-            text_position: find_text_position(text, "brokerage").map(|(start, end)| TextPosition {
-                start_offset: start,
-                end_offset: end,
-            }),
+            text_position: find_text_position(text, "brokerage")
+                .map(|(start, end)| TextPosition::new(start, end)),
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -912,6 +949,8 @@ pub fn check_brokerage_relationship(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -954,6 +993,8 @@ pub fn check_listing_expiration(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -977,6 +1018,8 @@ pub fn check_commission_provisions(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
         return violations;
     }
@@ -1001,6 +1044,8 @@ pub fn check_commission_provisions(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(extract_snippet(text, "commission")),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -1018,6 +1063,8 @@ pub fn check_commission_provisions(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -1043,6 +1090,8 @@ pub fn check_broker_license(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: Some(text.chars().take(100).collect()),
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -1058,7 +1107,7 @@ pub fn covered_realestate_statutes() -> Vec<&'static str> {
     vec![
         "F.S. § 404.056 - Radon Gas Disclosure",
         "F.S. § 689.261 - Property Tax Disclosure",
-        "F.S. § 689.302 - Flood Disclosure (SB 948)",
+        "F.S. § 689.302 (SB 948) - Flood Disclosure",
         "F.S. § 720.401 - HOA Disclosure",
         "F.S. § 553.996 - Energy Efficiency Disclosure",
         "F.S. § 475.278 - Brokerage Relationship Disclosure",
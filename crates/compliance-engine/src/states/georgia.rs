@@ -8,10 +8,19 @@
 //! - Flooding Disclosure - required if 3+ floods in 5 years
 //! - Move-in/Move-out Inspection requirements
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, TextPosition, Violation};
 
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[HABITABILITY];
+
+/// HB 404 Safe at Home Act
+pub const HABITABILITY: DatedRule = DatedRule::from("GA Code § 44-7-13 (HB 404)", date(2024, 7, 1));
+
 lazy_static! {
     /// As-is clause patterns
     static ref AS_IS_PATTERN: Regex =
@@ -21,13 +30,7 @@ lazy_static! {
     static ref HABITABILITY_WAIVER_PATTERN: Regex =
         Regex::new(r"(?i)\b(waive|waiver|waives).*?\b(habitability|habitable|warranty)\b").unwrap();
 
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
 
     /// Nonpayment notice period pattern
     static ref NOTICE_PERIOD_PATTERN: Regex =
@@ -39,12 +42,12 @@ lazy_static! {
 }
 
 /// Check all Georgia-specific compliance requirements
-pub fn check_georgia_compliance(text: &str) -> Vec<Violation> {
+pub fn check_georgia_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_habitability_requirement(text));
     violations.extend(check_notice_period(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_flooding_disclosure(text));
     violations.extend(check_move_inspection(text));
     violations.extend(check_void_clauses(text));
@@ -62,34 +65,36 @@ pub fn check_georgia_compliance(text: &str) -> Vec<Violation> {
 /// As-Is clauses are void; landlord has duty of habitability
 pub fn check_habitability_requirement(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
+    let operative = clauses::operative_text(text);
 
     // Check for as-is clauses
-    if let Some(m) = AS_IS_PATTERN.find(text) {
+    if let Some(m) = AS_IS_PATTERN.find(&operative) {
         let snippet = extract_context(text, m.start(), m.end());
         violations.push(Violation {
-            statute: "GA Code § 44-7-13 (HB 404)".to_string(),
+            statute: HABITABILITY.citation.to_string(),
             severity: Severity::Critical,
             message: "As-Is clauses are void under Georgia's Safe at Home Act. \
                      Landlord has non-waivable duty to maintain habitable premises."
                 .to_string(),
             page: None,
             text_snippet: Some(snippet),
-            text_position: Some(TextPosition {
-                start_offset: m.start(),
-                end_offset: m.end(),
-            }),
+            text_position: Some(TextPosition::new(m.start(), m.end())),
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for habitability waivers
-    if HABITABILITY_WAIVER_PATTERN.is_match(text) {
+    if HABITABILITY_WAIVER_PATTERN.is_match(&operative) {
         violations.push(Violation {
-            statute: "GA Code § 44-7-13 (HB 404)".to_string(),
+            statute: HABITABILITY.citation.to_string(),
             severity: Severity::Critical,
             message: "Waiver of warranty of habitability is void under Georgia law.".to_string(),
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -126,6 +131,8 @@ pub fn check_notice_period(text: &str) -> Vec<Violation> {
                             page: None,
                             text_snippet: None,
                             text_position: None,
+                            remediation: None,
+                            bounding_boxes: Vec::new(),
                         });
                     }
                 }
@@ -144,21 +151,15 @@ pub fn check_notice_period(text: &str) -> Vec<Violation> {
 ///
 /// Georgia has no statutory cap, but best practice is 2 months max
 /// Per GA Code § 44-7-30-36: specific handling requirements
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
     let text_lower = text.to_lowercase();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Warning for high deposits (more than 2 months)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -175,6 +176,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -196,6 +199,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -232,6 +237,8 @@ pub fn check_flooding_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -271,6 +278,8 @@ pub fn check_move_inspection(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -285,7 +294,7 @@ pub fn check_move_inspection(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Georgia law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for liability waiver for landlord's negligence
     if text_lower.contains("waive")
@@ -300,6 +309,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -312,6 +323,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -339,6 +352,10 @@ fn extract_context(text: &str, start: usize, end: usize) -> String {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_georgia_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Habitability Tests (HB 404)
     // ========================================================================
@@ -346,7 +363,7 @@ mod tests {
     #[test]
     fn test_detects_as_is_clause() {
         let text = "Tenant accepts the property as-is with no warranty.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -359,7 +376,7 @@ mod tests {
     #[test]
     fn test_detects_as_is_with_space() {
         let text = "Property is rented as is without any repairs.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -372,7 +389,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant waives the warranty of habitability.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -385,7 +402,7 @@ mod tests {
     #[test]
     fn test_no_violation_for_normal_text() {
         let text = "Landlord will maintain the property in good condition.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -402,7 +419,7 @@ mod tests {
     #[test]
     fn test_detects_insufficient_notice() {
         let text = "For nonpayment of rent, tenant has 1 day notice to pay or vacate.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -415,7 +432,7 @@ mod tests {
     #[test]
     fn test_accepts_3_day_notice() {
         let text = "For nonpayment of rent, tenant has 3 days notice to cure.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -432,7 +449,7 @@ mod tests {
     #[test]
     fn test_warns_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -445,7 +462,7 @@ mod tests {
     #[test]
     fn test_accepts_reasonable_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -462,7 +479,7 @@ mod tests {
     #[test]
     fn test_warns_flood_without_disclosure() {
         let text = "The basement has flooded in the past. Tenant accepts risk.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -476,7 +493,7 @@ mod tests {
     fn test_accepts_proper_flood_disclosure() {
         let text = "Flood Disclosure: Property is in flood zone X. \
                     Flooding history attached.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -493,7 +510,7 @@ mod tests {
     #[test]
     fn test_detects_negligence_waiver() {
         let text = "Tenant waives any claims for landlord negligence.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.statute.contains("44-7-2")),
@@ -504,7 +521,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -524,7 +541,7 @@ mod tests {
                     Move-in inspection will be conducted within 3 days of occupancy. \
                     Landlord maintains property in habitable condition. \
                     For nonpayment, 3 days notice required before legal action.";
-        let violations = check_georgia_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -9,10 +9,22 @@
 //! - Landlord Retaliation Act (2025)
 //! - Bed Bug Disclosure (Chicago)
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[ELECTRONIC_PAYMENT, RETALIATION];
+
+/// Ban on requiring electronic rent payment
+pub const ELECTRONIC_PAYMENT: DatedRule = DatedRule::from("765 ILCS 705/2.5", date(2025, 1, 1));
+
+/// Landlord Retaliation Act
+pub const RETALIATION: DatedRule = DatedRule::from("765 ILCS 720", date(2025, 1, 1));
+
 // Chicago zip codes (partial list - major ranges)
 const CHICAGO_ZIP_PREFIXES: &[&str] = &[
     "606", "607", "608", // Chicago proper
@@ -23,13 +35,7 @@ lazy_static! {
     static ref ELECTRONIC_ONLY_PATTERN: Regex =
         Regex::new(r"(?i)(must\s+pay|payment\s+required|only\s+accept).*(online|electronic|app|portal)").unwrap();
 
-    /// Security deposit pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
 }
 
 /// Check if zip code is in Chicago
@@ -44,7 +50,7 @@ fn is_chicago_zip(text: &str) -> bool {
 }
 
 /// Check all Illinois-specific compliance requirements
-pub fn check_illinois_compliance(text: &str) -> Vec<Violation> {
+pub fn check_illinois_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
     let is_chicago = is_chicago_zip(text);
 
@@ -57,7 +63,7 @@ pub fn check_illinois_compliance(text: &str) -> Vec<Violation> {
 
     // Statewide checks
     violations.extend(check_electronic_payment(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_retaliation_disclosure(text));
     violations.extend(check_void_clauses(text));
 
@@ -93,6 +99,8 @@ pub fn check_rlto_summary(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -126,6 +134,8 @@ pub fn check_deposit_interest(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -156,6 +166,8 @@ pub fn check_bed_bug_disclosure(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -175,7 +187,7 @@ pub fn check_electronic_payment(text: &str) -> Vec<Violation> {
 
     if ELECTRONIC_ONLY_PATTERN.is_match(text) {
         violations.push(Violation {
-            statute: "765 ILCS 705/2.5".to_string(),
+            statute: ELECTRONIC_PAYMENT.citation.to_string(),
             severity: Severity::Critical,
             message: "Cannot require electronic-only payments. \
                      Illinois law requires acceptance of alternative payment methods."
@@ -183,6 +195,8 @@ pub fn check_electronic_payment(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -193,21 +207,15 @@ pub fn check_electronic_payment(text: &str) -> Vec<Violation> {
 ///
 /// Per 765 ILCS 710:
 /// Specific requirements for deposit handling
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
     let text_lower = text.to_lowercase();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Illinois has no statutory cap, but check for return timeline
     let mentions_deposit = text_lower.contains("deposit") || text_lower.contains("security");
@@ -229,6 +237,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -247,6 +257,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -271,7 +283,7 @@ pub fn check_retaliation_disclosure(text: &str) -> Vec<Violation> {
 
         if !has_retaliation_protection {
             violations.push(Violation {
-                statute: "765 ILCS 720".to_string(),
+                statute: RETALIATION.citation.to_string(),
                 severity: Severity::Info,
                 message: "Consider noting tenant's protection against retaliation. \
                          Illinois prohibits retaliation for exercising tenant rights."
@@ -279,6 +291,8 @@ pub fn check_retaliation_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -289,7 +303,7 @@ pub fn check_retaliation_disclosure(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Illinois law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord liability
     if text_lower.contains("waive")
@@ -303,6 +317,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -316,6 +332,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -328,6 +346,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -338,6 +358,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_illinois_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Chicago RLTO Tests
     // ========================================================================
@@ -345,7 +369,7 @@ mod tests {
     #[test]
     fn test_detects_missing_rlto_summary_chicago() {
         let text = "Property located at 123 Main St, Chicago, IL 60601. Monthly rent: $1,500.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -359,7 +383,7 @@ mod tests {
     fn test_accepts_rlto_summary_present() {
         let text = "Property in Chicago, IL 60601. \
                     RLTO Summary attached. Residential Landlord and Tenant Ordinance applies.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -372,7 +396,7 @@ mod tests {
     #[test]
     fn test_no_rlto_requirement_outside_chicago() {
         let text = "Property in Springfield, IL 62701. Monthly rent: $1,200.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations.iter().any(|v| v.statute.contains("5-12-170")),
@@ -383,7 +407,7 @@ mod tests {
     #[test]
     fn test_warns_missing_bed_bug_disclosure_chicago() {
         let text = "Chicago, IL 60602. Deposit: $1,500. RLTO Summary attached.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -397,7 +421,7 @@ mod tests {
     fn test_accepts_bed_bug_disclosure() {
         let text = "Chicago, IL 60602. RLTO Summary attached. \
                     Bed Bug Disclosure: City brochure provided.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -415,7 +439,7 @@ mod tests {
     fn test_detects_electronic_only_requirement() {
         let text = "Tenant must pay rent online through the landlord portal. \
                     No other payment methods accepted.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -428,7 +452,7 @@ mod tests {
     #[test]
     fn test_accepts_multiple_payment_methods() {
         let text = "Rent may be paid by check, money order, or online portal.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -445,7 +469,7 @@ mod tests {
     #[test]
     fn test_detects_liability_waiver() {
         let text = "Tenant waives any claims of negligence against landlord.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -458,7 +482,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -471,7 +495,7 @@ mod tests {
     #[test]
     fn test_detects_jury_waiver() {
         let text = "Tenant waives right to jury trial in any dispute.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -494,7 +518,7 @@ mod tests {
                     Bed bug disclosure and brochure provided. \
                     Deposit returned within 30 days of move-out. \
                     Rent may be paid by check or online.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -511,7 +535,7 @@ mod tests {
         let text = "Property in Springfield, IL 62701. Monthly rent: $1,200. \
                     Security deposit: $1,200 returned within 30 days. \
                     Rent may be paid by check or money order.";
-        let violations = check_illinois_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
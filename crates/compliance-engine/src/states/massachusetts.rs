@@ -8,29 +8,30 @@
 //! - Tenant Rights Statement required
 //! - Interest on security deposits
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[BROKER_FEES];
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Broker fee reform
+pub const BROKER_FEES: DatedRule = DatedRule::from("M.G.L. c. 186 (2025 Reform)", date(2025, 8, 1));
 
+lazy_static! {
     /// Broker fee pattern
     static ref BROKER_FEE_PATTERN: Regex =
         Regex::new(r"(?i)(broker|realtor|agent)\s+(fee|commission)[:\s]+").unwrap();
 }
 
 /// Check all Massachusetts-specific compliance requirements
-pub fn check_massachusetts_compliance(text: &str) -> Vec<Violation> {
+pub fn check_massachusetts_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_broker_fee(text));
     violations.extend(check_tenant_rights_statement(text));
     violations.extend(check_deposit_interest(text));
@@ -50,20 +51,14 @@ pub fn check_massachusetts_compliance(text: &str) -> Vec<Violation> {
 /// - Last month's rent separate
 /// - Must hold in separate interest-bearing account
 /// - Return within 30 days
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (1 month max)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -79,6 +74,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -102,6 +99,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -144,7 +143,7 @@ pub fn check_broker_fee(text: &str) -> Vec<Violation> {
 
         if tenant_pays {
             violations.push(Violation {
-                statute: "M.G.L. c. 186 (2025 Reform)".to_string(),
+                statute: BROKER_FEES.citation.to_string(),
                 severity: Severity::Critical,
                 message: "Tenant cannot be required to pay landlord's broker fee. \
                          Massachusetts 2025 broker fee reform requires landlord to pay own broker."
@@ -152,6 +151,8 @@ pub fn check_broker_fee(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -187,6 +188,8 @@ pub fn check_tenant_rights_statement(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -223,6 +226,8 @@ pub fn check_deposit_interest(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -237,7 +242,7 @@ pub fn check_deposit_interest(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Massachusetts law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of security deposit rights
     if text_lower.contains("waive") && text_lower.contains("deposit") {
@@ -249,6 +254,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -262,6 +269,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -276,6 +285,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -286,6 +297,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_massachusetts_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Security Deposit Tests
     // ========================================================================
@@ -293,7 +308,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $4,000.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -306,7 +321,7 @@ mod tests {
     #[test]
     fn test_accepts_one_month_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $2,000.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations.iter().any(|v| v.statute.contains("186 § 15B")
@@ -319,7 +334,7 @@ mod tests {
     #[test]
     fn test_warns_missing_separate_account() {
         let text = "Security deposit: $1,500 held by landlord.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -337,7 +352,7 @@ mod tests {
     fn test_detects_tenant_broker_fee() {
         let text = "Tenant pays broker fee of one month's rent. \
                     Agent commission due at signing.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -350,7 +365,7 @@ mod tests {
     #[test]
     fn test_no_violation_landlord_pays_broker() {
         let text = "Landlord pays all broker fees. No broker fee to tenant.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -367,7 +382,7 @@ mod tests {
     #[test]
     fn test_warns_missing_interest_disclosure() {
         let text = "Security deposit: $2,000. Deposit held in escrow account.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -381,7 +396,7 @@ mod tests {
     fn test_accepts_interest_disclosure() {
         let text = "Security deposit held in interest-bearing separate account. \
                     Interest paid annually to tenant.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -398,7 +413,7 @@ mod tests {
     #[test]
     fn test_detects_deposit_rights_waiver() {
         let text = "Tenant agrees to waive all rights regarding security deposit.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.statute.contains("186 § 15B")
@@ -411,7 +426,7 @@ mod tests {
     #[test]
     fn test_detects_quiet_enjoyment_waiver() {
         let text = "Tenant waives covenant of quiet enjoyment.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -424,7 +439,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -445,7 +460,7 @@ mod tests {
                     Interest paid annually per M.G.L. c. 186 § 15B. \
                     Statement of tenant rights attached. \
                     No broker fee charged to tenant.";
-        let violations = check_massachusetts_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
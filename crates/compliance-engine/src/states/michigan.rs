@@ -7,30 +7,32 @@
 //! - Inventory checklist required
 //! - 30-day deposit return
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[SOURCE_OF_INCOME];
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Source of income protection
+pub const SOURCE_OF_INCOME: DatedRule =
+    DatedRule::from("M.C.L. 37.2502a (SB 205-207)", date(2025, 4, 2));
 
+lazy_static! {
     /// Source of income discrimination pattern
     static ref INCOME_DISCRIMINATION_PATTERN: Regex =
         Regex::new(r"(?i)(no\s+section\s+8|no\s+voucher|no\s+housing\s+assistance|income\s+source\s+(?:not\s+accepted|prohibited))").unwrap();
 }
 
 /// Check all Michigan-specific compliance requirements
-pub fn check_michigan_compliance(text: &str) -> Vec<Violation> {
+pub fn check_michigan_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_source_of_income(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_inventory_checklist(text));
     violations.extend(check_void_clauses(text));
 
@@ -51,7 +53,7 @@ pub fn check_source_of_income(text: &str) -> Vec<Violation> {
 
     if INCOME_DISCRIMINATION_PATTERN.is_match(text) {
         violations.push(Violation {
-            statute: "M.C.L. 37.2502a (SB 205-207)".to_string(),
+            statute: SOURCE_OF_INCOME.citation.to_string(),
             severity: Severity::Critical,
             message: "Source of income discrimination prohibited. Michigan law prohibits \
                      discrimination based on lawful source of income including housing vouchers."
@@ -59,6 +61,8 @@ pub fn check_source_of_income(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -75,20 +79,14 @@ pub fn check_source_of_income(text: &str) -> Vec<Violation> {
 /// - Maximum 1.5 months rent
 /// - Must return within 30 days
 /// - Inventory checklist required
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (1.5 months max)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -104,6 +102,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -127,6 +127,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -167,6 +169,8 @@ pub fn check_inventory_checklist(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -181,7 +185,7 @@ pub fn check_inventory_checklist(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Michigan law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of statutory rights
     if text_lower.contains("waive")
@@ -195,6 +199,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -209,6 +215,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -222,6 +230,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -232,6 +242,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_michigan_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Source of Income Tests
     // ========================================================================
@@ -239,7 +253,7 @@ mod tests {
     #[test]
     fn test_detects_section_8_discrimination() {
         let text = "No Section 8 vouchers accepted. All applicants must have employment income.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -252,7 +266,7 @@ mod tests {
     #[test]
     fn test_detects_housing_assistance_discrimination() {
         let text = "No housing assistance or government subsidies accepted.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -265,7 +279,7 @@ mod tests {
     #[test]
     fn test_no_violation_accepts_vouchers() {
         let text = "All lawful sources of income accepted including housing vouchers.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -282,7 +296,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -295,7 +309,7 @@ mod tests {
     #[test]
     fn test_accepts_compliant_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $3,000.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -312,7 +326,7 @@ mod tests {
     #[test]
     fn test_warns_missing_inventory() {
         let text = "Security deposit: $2,000. Deposit held by landlord.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -325,7 +339,7 @@ mod tests {
     #[test]
     fn test_accepts_inventory_checklist() {
         let text = "Security deposit: $2,000. Move-in inventory checklist attached.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -342,7 +356,7 @@ mod tests {
     #[test]
     fn test_detects_truth_in_renting_waiver() {
         let text = "Tenant agrees to waive all rights under Truth in Renting Act.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -355,7 +369,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -368,7 +382,7 @@ mod tests {
     #[test]
     fn test_detects_negligence_waiver() {
         let text = "Tenant waives landlord's liability for negligence.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -387,7 +401,7 @@ mod tests {
         let text = "Monthly rent: $2,000. Security deposit: $2,500. \
                     Deposit returned within 30 days. Inventory checklist attached. \
                     All lawful income sources accepted.";
-        let violations = check_michigan_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -5,7 +5,9 @@
 
 // Tier 1: Big Five
 pub mod california;
+pub mod commercial;
 pub mod florida;
+pub mod florida_commercial;
 pub mod florida_realestate;
 pub mod georgia;
 pub mod illinois;
@@ -24,40 +26,68 @@ pub mod tennessee;
 pub mod virginia;
 pub mod washington;
 
+// Tier 3 and the long tail: statutory profiles
+pub mod profile;
+pub mod profiles;
+
+use crate::extractors::terms::LeaseTerms;
 use crate::jurisdiction::State;
-use shared_types::Violation;
+use shared_types::{Coverage, Violation};
 
 /// Get state-specific compliance violations
 ///
 /// Layer 2 of the Layer Cake: State statutory requirements.
 /// Local ordinances (Chicago RLTO, NYC rent control) are handled in Layer 3.
-pub fn check_state_compliance(state: State, text: &str) -> Vec<Violation> {
+pub fn check_state_compliance(state: State, text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     match state {
         // Tier 1: Big Five
-        State::FL => florida::check_florida_compliance(text),
+        State::FL => florida::check_florida_compliance(text, terms),
         State::TX => texas::check_texas_compliance(text),
-        State::CA => california::check_california_compliance(text),
-        State::NY => new_york::check_new_york_compliance(text),
-        State::GA => georgia::check_georgia_compliance(text),
-        State::IL => illinois::check_illinois_compliance(text),
+        State::CA => california::check_california_compliance(text, terms),
+        State::NY => new_york::check_new_york_compliance(text, terms),
+        State::GA => georgia::check_georgia_compliance(text, terms),
+        State::IL => illinois::check_illinois_compliance(text, terms),
         // Tier 2: Growth Hubs
-        State::PA => pennsylvania::check_pennsylvania_compliance(text),
-        State::NJ => new_jersey::check_new_jersey_compliance(text),
-        State::VA => virginia::check_virginia_compliance(text),
-        State::MA => massachusetts::check_massachusetts_compliance(text),
-        State::OH => ohio::check_ohio_compliance(text),
-        State::MI => michigan::check_michigan_compliance(text),
-        State::WA => washington::check_washington_compliance(text),
-        State::AZ => arizona::check_arizona_compliance(text),
-        State::NC => north_carolina::check_north_carolina_compliance(text),
-        State::TN => tennessee::check_tennessee_compliance(text),
-        // States without implementation return empty
-        _ => Vec::new(),
+        State::PA => pennsylvania::check_pennsylvania_compliance(text, terms),
+        State::NJ => new_jersey::check_new_jersey_compliance(text, terms),
+        State::VA => virginia::check_virginia_compliance(text, terms),
+        State::MA => massachusetts::check_massachusetts_compliance(text, terms),
+        State::OH => ohio::check_ohio_compliance(text, terms),
+        State::MI => michigan::check_michigan_compliance(text, terms),
+        State::WA => washington::check_washington_compliance(text, terms),
+        State::AZ => arizona::check_arizona_compliance(text, terms),
+        State::NC => north_carolina::check_north_carolina_compliance(text, terms),
+        State::TN => tennessee::check_tennessee_compliance(text, terms),
+        // Remaining states are checked against their statutory profile
+        _ => profiles::profile(state)
+            .map(|p| profile::check_profile(p, text, terms))
+            .unwrap_or_default(),
     }
 }
 
-/// Check if state has full compliance implementation
+/// Check if state has any compliance implementation
 pub fn has_implementation(state: State) -> bool {
+    has_module(state) || profiles::profile(state).is_some()
+}
+
+/// How much of a state's lease law the hand-written rules cover
+///
+/// Dedicated modules are [`Coverage::Full`]; states checked against a
+/// [`profile::StateProfile`] are [`Coverage::Core`].
+pub fn coverage(state: State) -> Coverage {
+    if has_module(state) {
+        Coverage::Full
+    } else if profiles::profile(state).is_some() {
+        Coverage::Core
+    } else {
+        Coverage::Unavailable {
+            reason: format!("No residential lease rules for {}", state.name()),
+        }
+    }
+}
+
+/// Check if state has a dedicated compliance module
+fn has_module(state: State) -> bool {
     matches!(
         state,
         State::FL
@@ -90,10 +120,12 @@ pub fn covered_statutes(state: State) -> Vec<&'static str> {
             "F.S. § 83.51 - Landlord obligations",
             "F.S. § 83.56 - Termination notices",
             "F.S. § 83.57 - Month-to-month tenancy",
+            "F.S. § 83.56 (HB 615) - Electronic notice consent",
+            "F.S. § 83.512 (SB 948) - Flood disclosure",
             // Real Estate Transactions
             "F.S. § 404.056 - Radon Gas Disclosure",
             "F.S. § 689.261 - Property Tax Disclosure",
-            "F.S. § 689.302 - Flood Disclosure (SB 948)",
+            "F.S. § 689.302 (SB 948) - Flood Disclosure",
             "F.S. § 720.401 - HOA Disclosure",
             "F.S. § 553.996 - Energy Efficiency Disclosure",
             "F.S. § 475.278 - Brokerage Relationship Disclosure",
@@ -107,10 +139,10 @@ pub fn covered_statutes(state: State) -> Vec<&'static str> {
             "Tex. Prop. Code § 92.056 - Landlord repair duties",
         ],
         State::CA => vec![
-            "CA Civil Code § 1950.5 - Security deposits (AB 12)",
+            "CA Civil Code § 1950.5 (AB 12) - Security deposits",
             "CA Civil Code § 1953 - Void lease provisions",
-            "CA Civil Code § 1946.2 - Just Cause (AB 1482)",
-            "CA Civil Code § 1946.2 - Junk fees (SB 611)",
+            "CA Civil Code § 1946.2 (AB 1482) - Just Cause",
+            "CA Civil Code § 1946.2 (SB 611) - Junk fees",
             "CA Civil Code § 827 - Rent increase notice",
         ],
         State::NY => vec![
@@ -121,7 +153,7 @@ pub fn covered_statutes(state: State) -> Vec<&'static str> {
             "NY GOL § 5-321 - Void liability waivers",
         ],
         State::GA => vec![
-            "GA Code § 44-7-13 - Habitability (HB 404)",
+            "GA Code § 44-7-13 (HB 404) - Habitability",
             "GA Code § 44-7-50 - Notice requirements",
             "GA Code § 44-7-30 - Security deposits",
             "GA Code § 44-7-20 - Flooding disclosure",
@@ -155,7 +187,7 @@ pub fn covered_statutes(state: State) -> Vec<&'static str> {
         ],
         State::MA => vec![
             "M.G.L. c. 186 § 15B - Security deposit (1 month)",
-            "M.G.L. c. 186 (2025) - Broker fee reform",
+            "M.G.L. c. 186 (2025 Reform) - Broker fee reform",
             "M.G.L. c. 186 § 14 - Quiet enjoyment",
         ],
         State::OH => vec![
@@ -164,7 +196,7 @@ pub fn covered_statutes(state: State) -> Vec<&'static str> {
             "O.R.C. § 5321.06 - Prohibited provisions",
         ],
         State::MI => vec![
-            "M.C.L. 37.2502a - Source of income protection",
+            "M.C.L. 37.2502a (SB 205-207) - Source of income protection",
             "M.C.L. 554.602 - Security deposit (1.5 months)",
             "M.C.L. 554.608 - Inventory checklist",
             "M.C.L. 554.633 - Void provisions",
@@ -193,6 +225,8 @@ pub fn covered_statutes(state: State) -> Vec<&'static str> {
             "T.C.A. § 66-28-505 - 14-day nonpayment notice",
             "T.C.A. § 66-28-104 - Prohibited provisions",
         ],
+        // Profile states build their list from the profile; see
+        // `profile::covered_statutes`
         _ => vec![],
     }
 }
@@ -9,19 +9,13 @@
 //! - Lead Paint Disclosure
 //! - Rent Control (local municipalities)
 
+use crate::clauses;
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
 lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
     /// Late fee pattern
     static ref LATE_FEE_PATTERN: Regex =
         Regex::new(r"(?i)late\s+(?:fee|charge|penalty)[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
@@ -40,11 +34,11 @@ const RENT_CONTROL_MUNICIPALITIES: &[&str] = &[
 ];
 
 /// Check all New Jersey-specific compliance requirements
-pub fn check_new_jersey_compliance(text: &str) -> Vec<Violation> {
+pub fn check_new_jersey_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_truth_in_renting(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_window_guard_notice(text));
     violations.extend(check_rent_control(text));
     violations.extend(check_void_clauses(text));
@@ -82,6 +76,8 @@ pub fn check_truth_in_renting(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -98,20 +94,14 @@ pub fn check_truth_in_renting(text: &str) -> Vec<Violation> {
 /// - Maximum 1.5 months rent
 /// - Must be held in interest-bearing account
 /// - Return within 30 days (or 5 days if fire/flood)
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (1.5 months max)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -127,6 +117,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -150,6 +142,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -186,6 +180,8 @@ pub fn check_window_guard_notice(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -223,6 +219,8 @@ pub fn check_rent_control(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -237,7 +235,7 @@ pub fn check_rent_control(text: &str) -> Vec<Violation> {
 /// Check for void clauses under New Jersey law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of habitability
     if text_lower.contains("waive")
@@ -251,6 +249,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -266,6 +266,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -280,6 +282,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -293,6 +297,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -303,6 +309,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_new_jersey_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Truth in Renting Tests
     // ========================================================================
@@ -310,7 +320,7 @@ mod tests {
     #[test]
     fn test_warns_missing_truth_in_renting() {
         let text = "Property in Newark, NJ. Monthly rent: $1,500.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -323,7 +333,7 @@ mod tests {
     #[test]
     fn test_accepts_truth_in_renting() {
         let text = "Truth in Renting Statement attached per DCA requirements.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -340,7 +350,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -353,7 +363,7 @@ mod tests {
     #[test]
     fn test_accepts_compliant_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $3,000.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -366,7 +376,7 @@ mod tests {
     #[test]
     fn test_warns_missing_interest_account() {
         let text = "Security deposit: $2,000 will be held by landlord.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -383,7 +393,7 @@ mod tests {
     #[test]
     fn test_warns_rent_control_jersey_city() {
         let text = "Property located in Jersey City, NJ. Monthly rent: $2,500.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -396,7 +406,7 @@ mod tests {
     #[test]
     fn test_no_rent_control_warning_outside_regulated_area() {
         let text = "Property located in Princeton, NJ. Monthly rent: $2,500.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -413,7 +423,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive the implied warranty of habitability.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -426,7 +436,7 @@ mod tests {
     #[test]
     fn test_detects_anti_eviction_waiver() {
         let text = "Tenant waives all tenant rights under the Anti-Eviction Act.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.statute.contains("2A:18-61.1")),
@@ -437,7 +447,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -450,7 +460,7 @@ mod tests {
     #[test]
     fn test_warns_acceleration_clause() {
         let text = "Upon default, all remaining rent shall be accelerated and due immediately.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -469,7 +479,7 @@ mod tests {
         let text = "Monthly rent: $2,000. Security deposit: $2,500 held in interest-bearing bank account. \
                     Truth in Renting Statement attached per DCA requirements. \
                     Landlord maintains property in habitable condition.";
-        let violations = check_new_jersey_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
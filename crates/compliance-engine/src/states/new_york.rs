@@ -7,42 +7,41 @@
 //! - Rent Stabilization - DHCR Rider for pre-1974 buildings (NYC)
 //! - Security Deposit Cap - 1 month max (statewide)
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::{LateFee, LeaseTerms};
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Late fee pattern
-    static ref LATE_FEE_PATTERN: Regex =
-        Regex::new(r"(?i)late\s*fee[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[LATE_FEE_CAP, DEPOSIT_CAP, GOOD_CAUSE];
 
-    /// Late fee percentage pattern
-    static ref LATE_FEE_PERCENT_PATTERN: Regex =
-        Regex::new(r"(?i)late\s*fee.*?(\d+)\s*%").unwrap();
+/// Housing Stability and Tenant Protection Act late fee cap
+pub const LATE_FEE_CAP: DatedRule = DatedRule::from("NY RPL § 238-a", date(2019, 6, 14));
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Housing Stability and Tenant Protection Act deposit cap
+pub const DEPOSIT_CAP: DatedRule = DatedRule::from("NY RPL § 7-108", date(2019, 6, 14));
 
-    /// Security deposit pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Good Cause Eviction
+pub const GOOD_CAUSE: DatedRule = DatedRule::from("NY RPL § 226-c", date(2024, 4, 20));
 
+lazy_static! {
     /// NYC zip code ranges
     static ref NYC_ZIP_PATTERN: Regex =
         Regex::new(r"\b(100\d{2}|101\d{2}|102\d{2}|103\d{2}|104\d{2}|110\d{2}|111\d{2}|112\d{2}|113\d{2}|114\d{2}|116\d{2})\b").unwrap();
 
     /// Pre-1974 building pattern
     static ref PRE_1974_PATTERN: Regex =
-        Regex::new(r"(?i)(?:built|constructed|year\s+built)(?:\s+in)?[:\s]+(19[0-6]\d|19[7][0-3])").unwrap();
+        Regex::new(r"(?i)(?:built|constructed|year\s+built|construid[oa]|a[ñn]o\s+de\s+construcci[oó]n)(?:\s+(?:in|en))?[:\s]+(19[0-6]\d|19[7][0-3])").unwrap();
 }
 
 /// Check all New York-specific compliance requirements
-pub fn check_new_york_compliance(text: &str) -> Vec<Violation> {
+pub fn check_new_york_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    violations.extend(check_late_fee_cap(text));
-    violations.extend(check_security_deposit_cap(text));
+    violations.extend(check_late_fee_cap(terms));
+    violations.extend(check_security_deposit_cap(terms));
     violations.extend(check_good_cause_disclosure(text));
     violations.extend(check_rent_stabilization(text));
     violations.extend(check_lease_renewal_notice(text));
@@ -59,50 +58,43 @@ pub fn check_new_york_compliance(text: &str) -> Vec<Violation> {
 ///
 /// Per NY Real Property Law § 238-a:
 /// Late fee cannot exceed $50 or 5% of monthly rent, whichever is less
-pub fn check_late_fee_cap(text: &str) -> Vec<Violation> {
+pub fn check_late_fee_cap(terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
-
-    // Check for dollar amount late fee
-    if let Some(caps) = LATE_FEE_PATTERN.captures(text) {
-        if let Some(fee_str) = caps.get(1) {
-            if let Ok(fee) = fee_str.as_str().replace(",", "").parse::<f64>() {
+    let rent = terms.rent.as_ref().map(|r| r.value);
+
+    for fee in &terms.late_fees {
+        match fee.value {
+            // Dollar amount late fee
+            LateFee::Flat(amount) => {
                 let max_allowed = if let Some(rent_amt) = rent {
                     (rent_amt * 0.05).min(50.0)
                 } else {
                     50.0
                 };
 
-                if fee > max_allowed {
+                if amount > max_allowed {
                     violations.push(Violation {
-                        statute: "NY RPL § 238-a".to_string(),
+                        statute: LATE_FEE_CAP.citation.to_string(),
                         severity: Severity::Critical,
                         message: format!(
                             "Late fee (${:.2}) exceeds maximum allowed (${:.2}). \
                              NY law caps late fees at $50 or 5% of rent, whichever is less.",
-                            fee, max_allowed
+                            amount, max_allowed
                         ),
                         page: None,
                         text_snippet: None,
-                        text_position: None,
+                        text_position: Some(fee.span),
+                        remediation: None,
+                        bounding_boxes: Vec::new(),
                     });
                 }
             }
-        }
-    }
-
-    // Check for percentage late fee
-    if let Some(caps) = LATE_FEE_PERCENT_PATTERN.captures(text) {
-        if let Some(pct_str) = caps.get(1) {
-            if let Ok(pct) = pct_str.as_str().parse::<u32>() {
-                if pct > 5 {
+            // Percentage late fee
+            LateFee::Percent(pct) => {
+                if pct > 5.0 {
                     violations.push(Violation {
-                        statute: "NY RPL § 238-a".to_string(),
+                        statute: LATE_FEE_CAP.citation.to_string(),
                         severity: Severity::Critical,
                         message: format!(
                             "Late fee percentage ({}%) exceeds 5% maximum allowed under NY law.",
@@ -110,7 +102,9 @@ pub fn check_late_fee_cap(text: &str) -> Vec<Violation> {
                         ),
                         page: None,
                         text_snippet: None,
-                        text_position: None,
+                        text_position: Some(fee.span),
+                        remediation: None,
+                        bounding_boxes: Vec::new(),
                     });
                 }
             }
@@ -128,25 +122,19 @@ pub fn check_late_fee_cap(text: &str) -> Vec<Violation> {
 ///
 /// Per NY Real Property Law § 7-108:
 /// Security deposit cannot exceed 1 month's rent (statewide)
-pub fn check_security_deposit_cap(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit_cap(terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
         if deposit_amt > rent_amt {
             violations.push(Violation {
-                statute: "NY RPL § 7-108".to_string(),
+                statute: DEPOSIT_CAP.citation.to_string(),
                 severity: Severity::Critical,
                 message: format!(
                     "Security deposit (${:.2}) exceeds 1 month's rent (${:.2}). \
@@ -156,6 +144,8 @@ pub fn check_security_deposit_cap(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -183,18 +173,22 @@ pub fn check_good_cause_disclosure(text: &str) -> Vec<Violation> {
         || text_lower.contains("brooklyn")
         || text_lower.contains("queens")
         || text_lower.contains("bronx")
-        || text_lower.contains("staten island");
+        || text_lower.contains("staten island")
+        || text_lower.contains("ciudad de nueva york");
 
     if is_likely_nyc {
         // Check for Good Cause disclosure
         let has_good_cause = text_lower.contains("good cause")
             || text_lower.contains("just cause")
             || text_lower.contains("rpl 226-c")
-            || text_lower.contains("housing stability");
+            || text_lower.contains("housing stability")
+            || text_lower.contains("causa justificada")
+            || text_lower.contains("causa justa")
+            || text_lower.contains("estabilidad de vivienda");
 
         if !has_good_cause {
             violations.push(Violation {
-                statute: "NY RPL § 226-c".to_string(),
+                statute: GOOD_CAUSE.citation.to_string(),
                 severity: Severity::Warning,
                 message: "Good Cause Eviction disclosure recommended for NYC properties. \
                          Include Good Cause notice or exemption statement if applicable."
@@ -202,6 +196,8 @@ pub fn check_good_cause_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -225,7 +221,9 @@ pub fn check_rent_stabilization(text: &str) -> Vec<Violation> {
     let is_pre_1974 = PRE_1974_PATTERN.is_match(text);
 
     // Check if NYC
-    let is_nyc = NYC_ZIP_PATTERN.is_match(text) || text_lower.contains("new york city");
+    let is_nyc = NYC_ZIP_PATTERN.is_match(text)
+        || text_lower.contains("new york city")
+        || text_lower.contains("ciudad de nueva york");
 
     if is_pre_1974 && is_nyc {
         // Check for rent stabilization disclosure
@@ -234,7 +232,10 @@ pub fn check_rent_stabilization(text: &str) -> Vec<Violation> {
             || text_lower.contains("division of housing")
             || text_lower.contains("rent controlled")
             || text_lower.contains("421-a")
-            || text_lower.contains("j-51");
+            || text_lower.contains("j-51")
+            || text_lower.contains("renta estabilizada")
+            || text_lower.contains("estabilización de renta")
+            || text_lower.contains("renta controlada");
 
         if !has_rent_stab {
             violations.push(Violation {
@@ -246,6 +247,8 @@ pub fn check_rent_stabilization(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -268,16 +271,20 @@ pub fn check_lease_renewal_notice(text: &str) -> Vec<Violation> {
     let text_lower = text.to_lowercase();
 
     // Check if lease mentions renewal or non-renewal
-    if text_lower.contains("renewal") || text_lower.contains("non-renewal") {
+    if text_lower.contains("renewal") || text_lower.contains("renovación") {
         // Check for proper notice disclosure
         let has_notice_period = text_lower.contains("30 days")
             || text_lower.contains("60 days")
             || text_lower.contains("90 days")
-            || text_lower.contains("advance notice");
+            || text_lower.contains("advance notice")
+            || text_lower.contains("30 días")
+            || text_lower.contains("60 días")
+            || text_lower.contains("90 días")
+            || text_lower.contains("aviso previo");
 
         if !has_notice_period {
             violations.push(Violation {
-                statute: "NY RPL § 226-c".to_string(),
+                statute: GOOD_CAUSE.citation.to_string(),
                 severity: Severity::Info,
                 message: "Consider specifying renewal notice periods. \
                          NY requires 30/60/90 day non-renewal notice based on tenancy length."
@@ -285,6 +292,8 @@ pub fn check_lease_renewal_notice(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -302,12 +311,19 @@ pub fn check_lease_renewal_notice(text: &str) -> Vec<Violation> {
 /// Certain waivers and exemptions are void
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for liability waiver
-    if (text_lower.contains("waive") || text_lower.contains("release"))
-        && (text_lower.contains("liability") || text_lower.contains("negligence"))
-    {
+    let has_waiver = text_lower.contains("waive")
+        || text_lower.contains("release")
+        || text_lower.contains("renuncia")
+        || text_lower.contains("exime")
+        || text_lower.contains("exonera");
+    let has_liability = text_lower.contains("liability")
+        || text_lower.contains("negligence")
+        || text_lower.contains("responsabilidad")
+        || text_lower.contains("negligencia");
+    if has_waiver && has_liability {
         violations.push(Violation {
             statute: "NY GOL § 5-321".to_string(),
             severity: Severity::Critical,
@@ -317,11 +333,17 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for confession of judgment
-    if text_lower.contains("confession of judgment") || text_lower.contains("confess judgment") {
+    if text_lower.contains("confession of judgment")
+        || text_lower.contains("confess judgment")
+        || text_lower.contains("confesión de juicio")
+        || text_lower.contains("confesión de sentencia")
+    {
         violations.push(Violation {
             statute: "NY CPLR § 3218".to_string(),
             severity: Severity::Critical,
@@ -329,6 +351,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -339,6 +363,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_new_york_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Late Fee Cap Tests
     // ========================================================================
@@ -346,7 +374,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_late_fee_dollar() {
         let text = "Monthly rent: $2,000. Late fee: $100 if rent not received by 5th.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -359,7 +387,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_late_fee_percent() {
         let text = "Monthly rent: $1,500. Late fee shall be 10% of monthly rent.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -372,7 +400,7 @@ mod tests {
     #[test]
     fn test_accepts_compliant_late_fee() {
         let text = "Monthly rent: $2,000. Late fee: $50 if rent not paid by 5th.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -385,7 +413,7 @@ mod tests {
     #[test]
     fn test_accepts_5_percent_late_fee() {
         let text = "Monthly rent: $1,000. Late fee: 5% of rent.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -402,7 +430,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $4,000.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -415,7 +443,7 @@ mod tests {
     #[test]
     fn test_accepts_one_month_deposit() {
         let text = "Monthly rent: $2,500. Security deposit: $2,500.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -433,7 +461,7 @@ mod tests {
     fn test_warns_nyc_without_good_cause() {
         let text = "Property located at 123 Main St, New York City, NY 10001. \
                     Landlord may terminate for any lease violation.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -447,7 +475,7 @@ mod tests {
     fn test_accepts_good_cause_disclosure() {
         let text = "Property in Brooklyn, NY 11201. \
                     This lease is subject to Good Cause eviction requirements under RPL 226-c.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -464,7 +492,7 @@ mod tests {
     #[test]
     fn test_warns_pre1974_nyc_no_stabilization_info() {
         let text = "Building constructed in 1965. Property in NYC 10021.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -478,7 +506,7 @@ mod tests {
     fn test_accepts_rent_stabilization_disclosure() {
         let text = "Building built in 1960. NYC 10001. \
                     This unit is rent stabilized. DHCR Lease Rider attached.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -496,7 +524,7 @@ mod tests {
     fn test_detects_liability_waiver() {
         let text =
             "Tenant agrees to waive any claims of liability against landlord for negligence.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -509,7 +537,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for any unpaid rent.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -530,7 +558,7 @@ mod tests {
                     This lease is subject to Good Cause eviction requirements. \
                     Landlord maintains property per warranty of habitability. \
                     Non-renewal notice: 60 days advance notice required.";
-        let violations = check_new_york_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -541,4 +569,58 @@ mod tests {
             "Compliant lease should have no critical violations"
         );
     }
+
+    // ========================================================================
+    // Spanish Leases
+    // ========================================================================
+
+    #[test]
+    fn test_spanish_rules() {
+        let cases = [
+            (
+                "NY RPL § 238-a",
+                "Renta mensual: $2,000. Cargo por pago atrasado: $100.",
+            ),
+            (
+                "NY RPL § 238-a",
+                "Cargo por pago atrasado del 10% de la renta.",
+            ),
+            (
+                "NY RPL § 7-108",
+                "Renta mensual: $2,000. Depósito de seguridad: $4,000.",
+            ),
+            ("NY RPL § 226-c", "Propiedad en la ciudad de Nueva York."),
+            (
+                "NY RPL § 226-c",
+                "El contrato incluye una opción de renovación.",
+            ),
+            (
+                "NYC Admin Code § 26-504",
+                "Edificio construido en 1960 en la ciudad de Nueva York.",
+            ),
+            (
+                "NY GOL § 5-321",
+                "El Inquilino exime al Arrendador de toda responsabilidad por negligencia.",
+            ),
+            (
+                "NY CPLR § 3218",
+                "El Inquilino acepta la confesión de juicio.",
+            ),
+        ];
+        for (statute, text) in cases {
+            assert!(
+                check(text).iter().any(|v| v.statute == statute),
+                "{statute} not found in {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compliant_spanish_lease() {
+        let text = "Renta mensual: $2,000. Depósito de seguridad: $2,000. Cargo por pago \
+                    atrasado: $50. Ciudad de Nueva York, NY 10001. Aviso de desalojo por causa \
+                    justificada adjunto. Aviso de renovación con 60 días de anticipación.";
+        let violations = check(text);
+        assert!(violations.is_empty(), "{:?}", violations);
+    }
 }
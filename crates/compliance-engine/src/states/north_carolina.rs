@@ -7,29 +7,23 @@
 //! - Trust Account requirement for deposits
 //! - Landlord must provide contact information
 
+use crate::clauses;
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
 lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
     /// Pet deposit pattern
     static ref PET_DEPOSIT_PATTERN: Regex =
         Regex::new(r"(?i)pet\s+(?:deposit|fee)[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
 }
 
 /// Check all North Carolina-specific compliance requirements
-pub fn check_north_carolina_compliance(text: &str) -> Vec<Violation> {
+pub fn check_north_carolina_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_pet_deposit_terminology(text));
     violations.extend(check_landlord_contact(text));
     violations.extend(check_trust_account(text));
@@ -49,20 +43,14 @@ pub fn check_north_carolina_compliance(text: &str) -> Vec<Violation> {
 /// - Month-to-month: 1.5 months max
 /// - Longer term: 2 months max
 /// - Must return within 30 days
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (2 months max for standard leases)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -78,6 +66,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -101,6 +91,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -138,6 +130,8 @@ pub fn check_pet_deposit_terminology(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -158,6 +152,8 @@ pub fn check_pet_deposit_terminology(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -190,6 +186,8 @@ pub fn check_landlord_contact(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -227,6 +225,8 @@ pub fn check_trust_account(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -241,7 +241,7 @@ pub fn check_trust_account(text: &str) -> Vec<Violation> {
 /// Check for void clauses under North Carolina law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's duty to maintain
     if text_lower.contains("waive")
@@ -256,6 +256,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -270,6 +272,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -283,6 +287,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -293,6 +299,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_north_carolina_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Security Deposit Tests
     // ========================================================================
@@ -300,7 +310,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -313,7 +323,7 @@ mod tests {
     #[test]
     fn test_accepts_two_month_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -330,7 +340,7 @@ mod tests {
     #[test]
     fn test_warns_unclear_pet_deposit() {
         let text = "Pet deposit: $500 required for all pet owners.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -343,7 +353,7 @@ mod tests {
     #[test]
     fn test_warns_unclear_pet_fee() {
         let text = "Pet fee: $250 required at move-in.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -356,7 +366,7 @@ mod tests {
     #[test]
     fn test_accepts_clear_pet_deposit() {
         let text = "Refundable pet deposit: $500 (part of security deposit).";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -369,7 +379,7 @@ mod tests {
     #[test]
     fn test_accepts_clear_pet_fee() {
         let text = "Non-refundable pet fee: $250.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations.iter().any(|v| v.statute.contains("42-53")
@@ -386,7 +396,7 @@ mod tests {
     #[test]
     fn test_warns_missing_trust_account() {
         let text = "Security deposit: $2,000 held by landlord.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -399,7 +409,7 @@ mod tests {
     #[test]
     fn test_accepts_trust_account() {
         let text = "Security deposit: $2,000 held in trust account at licensed bank.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -416,7 +426,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive implied warranty of habitability.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -429,7 +439,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -449,7 +459,7 @@ mod tests {
                     Monthly rent: $1,800. Security deposit: $1,800 held in trust account. \
                     Deposit returned within 30 days. \
                     Non-refundable pet fee: $250.";
-        let violations = check_north_carolina_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -7,25 +7,15 @@
 //! - No statutory deposit cap
 //! - Written lease recommended for 3+ years
 
-use lazy_static::lazy_static;
-use regex::Regex;
+use crate::clauses;
+use crate::extractors::terms::LeaseTerms;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-}
-
 /// Check all Ohio-specific compliance requirements
-pub fn check_ohio_compliance(text: &str) -> Vec<Violation> {
+pub fn check_ohio_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_landlord_obligations(text));
     violations.extend(check_void_clauses(text));
 
@@ -42,20 +32,14 @@ pub fn check_ohio_compliance(text: &str) -> Vec<Violation> {
 /// - No statutory cap, but reasonable amounts expected
 /// - Must return within 30 days
 /// - Itemized deduction statement required
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Warn about high deposits (more than 2 months is unusual)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -71,6 +55,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -94,6 +80,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -112,6 +100,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -143,6 +133,8 @@ pub fn check_landlord_obligations(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -156,7 +148,7 @@ pub fn check_landlord_obligations(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Ohio law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's duty to maintain
     if text_lower.contains("waive")
@@ -171,6 +163,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -184,6 +178,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -197,6 +193,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -207,6 +205,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_ohio_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Security Deposit Tests
     // ========================================================================
@@ -214,7 +216,7 @@ mod tests {
     #[test]
     fn test_warns_high_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -227,7 +229,7 @@ mod tests {
     #[test]
     fn test_accepts_reasonable_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -240,7 +242,7 @@ mod tests {
     #[test]
     fn test_warns_missing_30_day_return() {
         let text = "Security deposit: $1,500. Deposit will be returned after move-out.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -253,7 +255,7 @@ mod tests {
     #[test]
     fn test_accepts_30_day_return() {
         let text = "Security deposit: $1,500 returned within 30 days with itemized statement.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations.iter().any(|v| v.statute.contains("5321.16")
@@ -270,7 +272,7 @@ mod tests {
     #[test]
     fn test_warns_as_is_clause() {
         let text = "Property rented as-is with no warranty.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -287,7 +289,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive warranty of habitability.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -300,7 +302,7 @@ mod tests {
     #[test]
     fn test_detects_tenant_remedy_waiver() {
         let text = "Tenant waives all remedies under Ohio landlord-tenant law.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -313,7 +315,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -332,7 +334,7 @@ mod tests {
         let text = "Monthly rent: $1,800. Security deposit: $1,800. \
                     Deposit returned within 30 days with itemized deduction statement. \
                     Landlord maintains property per O.R.C. § 5321.04.";
-        let violations = check_ohio_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -8,19 +8,13 @@
 //! - 30-day notice for termination
 //! - Written lease required for rentals > 1 year
 
+use crate::clauses;
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
 lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
     /// Complex legal jargon patterns (Plain Language Act)
     static ref LEGAL_JARGON_PATTERNS: Vec<Regex> = vec![
         Regex::new(r"(?i)\b(hereinafter|heretofore|herein|whereas)\b").unwrap(),
@@ -34,10 +28,10 @@ lazy_static! {
 }
 
 /// Check all Pennsylvania-specific compliance requirements
-pub fn check_pennsylvania_compliance(text: &str) -> Vec<Violation> {
+pub fn check_pennsylvania_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_plain_language(text));
     violations.extend(check_deposit_interest(text));
     violations.extend(check_termination_notice(text));
@@ -56,20 +50,14 @@ pub fn check_pennsylvania_compliance(text: &str) -> Vec<Violation> {
 /// - First year: Max 2 months rent
 /// - After first year: Max 1 month rent
 /// - Must return within 30 days
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (2 months max in first year)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -86,6 +74,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -109,6 +99,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -140,6 +132,8 @@ pub fn check_plain_language(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
             break; // Only flag once for jargon
         }
@@ -166,6 +160,8 @@ pub fn check_plain_language(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -205,6 +201,8 @@ pub fn check_deposit_interest(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -242,6 +240,8 @@ pub fn check_termination_notice(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -256,7 +256,7 @@ pub fn check_termination_notice(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Pennsylvania law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's duty to maintain
     if text_lower.contains("waive")
@@ -270,6 +270,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -284,6 +286,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -297,6 +301,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -307,6 +313,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_pennsylvania_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Security Deposit Tests
     // ========================================================================
@@ -314,7 +324,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -327,7 +337,7 @@ mod tests {
     #[test]
     fn test_accepts_two_month_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -340,7 +350,7 @@ mod tests {
     #[test]
     fn test_accepts_one_month_deposit() {
         let text = "Monthly rent: $2,000. Security deposit: $2,000.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -357,7 +367,7 @@ mod tests {
     #[test]
     fn test_detects_legal_jargon_hereinafter() {
         let text = "The Tenant, hereinafter referred to as the Lessee, agrees to pay rent.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -370,7 +380,7 @@ mod tests {
     #[test]
     fn test_detects_legal_jargon_witnesseth() {
         let text = "WITNESSETH: The landlord agrees to provide housing.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -384,7 +394,7 @@ mod tests {
     fn test_accepts_plain_language() {
         let text = "The tenant agrees to pay rent of $1,500 per month. \
                     The landlord will maintain the property in good condition.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -401,7 +411,7 @@ mod tests {
     #[test]
     fn test_warns_missing_interest_disclosure() {
         let text = "Security deposit: $1,500. Deposit will be held by landlord.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -414,7 +424,7 @@ mod tests {
     #[test]
     fn test_accepts_interest_disclosure() {
         let text = "Security deposit: $1,500. Deposit held in escrow account with interest.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -431,7 +441,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive implied warranty of habitability.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -444,7 +454,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -457,7 +467,7 @@ mod tests {
     #[test]
     fn test_detects_jury_waiver() {
         let text = "Tenant waives right to jury trial in any dispute.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.message.contains("jury")),
@@ -474,7 +484,7 @@ mod tests {
         let text = "Monthly rent: $1,800. Security deposit: $1,800 held in escrow with interest. \
                     Deposit returned within 30 days of lease termination. \
                     Landlord maintains property in habitable condition.";
-        let violations = check_pennsylvania_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -0,0 +1,516 @@
+//! Statutory profiles for states without a dedicated module
+//!
+//! Most states' core landlord-tenant limits differ only in their numbers:
+//! how large a deposit may be and how fast it comes back, how much notice
+//! nonpayment, month-to-month termination and landlord entry require, how
+//! large a late fee may be, and which disclosures a lease must carry. A
+//! [`StateProfile`] records those numbers with their citations and
+//! [`check_profile`] applies them, so the long tail of states is data in
+//! [`super::profiles`] rather than one module each.
+//!
+//! Profile-backed states report [`shared_types::Coverage::Core`]: void-clause
+//! and state-specific checks still need a dedicated module.
+
+use crate::extractors::terms::LeaseTerms;
+use crate::jurisdiction::State;
+use lazy_static::lazy_static;
+use regex::Regex;
+use shared_types::{Severity, TextPosition, Violation};
+
+/// Core statutory limits for one state
+#[derive(Debug, Clone, Copy)]
+pub struct StateProfile {
+    pub state: State,
+    /// Maximum security deposit in months of rent
+    pub deposit_cap: Option<DepositCap>,
+    /// Days the landlord has to return the deposit
+    pub deposit_return: Option<DayLimit>,
+    /// Minimum notice before terminating for nonpayment of rent
+    pub nonpayment_notice: Option<DayLimit>,
+    /// Minimum notice to end a month-to-month tenancy
+    pub termination_notice: Option<DayLimit>,
+    /// Minimum notice before non-emergency landlord entry
+    pub entry_notice: Option<HourLimit>,
+    pub late_fee: Option<LateFeeCap>,
+    pub disclosures: &'static [Disclosure],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DepositCap {
+    pub months: f64,
+    pub statute: &'static str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DayLimit {
+    pub days: u32,
+    pub statute: &'static str,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HourLimit {
+    pub hours: u32,
+    pub statute: &'static str,
+}
+
+/// Late fee limit; with both a percentage and an amount the cap is the
+/// greater of the two unless `lesser` is set
+#[derive(Debug, Clone, Copy)]
+pub struct LateFeeCap {
+    /// Percent of monthly rent
+    pub percent: Option<f64>,
+    /// Dollar amount
+    pub amount: Option<f64>,
+    pub lesser: bool,
+    /// Days after the due date before any fee may be charged
+    pub grace_days: Option<u32>,
+    pub statute: &'static str,
+}
+
+/// A disclosure the lease must contain
+#[derive(Debug, Clone, Copy)]
+pub struct Disclosure {
+    pub label: &'static str,
+    /// Phrases showing the disclosure is present (any one suffices)
+    pub keywords: &'static [&'static str],
+    /// Phrases that make the disclosure required; empty means always
+    pub when: &'static [&'static str],
+    pub statute: &'static str,
+}
+
+lazy_static! {
+    static ref ENTRY_WITHOUT_NOTICE_PATTERN: Regex = Regex::new(
+        r"(?i)(?:enter|entry|access)[^.]*?(?:without\s+(?:prior\s+|advance\s+)?notice|at\s+any\s+time)"
+    )
+    .unwrap();
+}
+
+/// Check a lease against a state's core statutory limits
+pub fn check_profile(profile: &StateProfile, text: &str, terms: &LeaseTerms) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let state = profile.state.name();
+
+    if let Some(cap) = profile.deposit_cap {
+        if let Some((deposit, limit)) = terms.deposit_over(cap.months) {
+            violations.push(violation(
+                cap.statute,
+                Severity::Critical,
+                format!(
+                    "Security deposit (${:.2}) exceeds {}'s limit of {} rent (${:.2}).",
+                    deposit,
+                    state,
+                    months(cap.months),
+                    limit
+                ),
+                terms.security_deposit.as_ref().map(|d| d.span),
+            ));
+        }
+    }
+
+    if let Some(limit) = profile.deposit_return {
+        if let Some(found) = &terms.deposit_return_days {
+            let days = found.value;
+            if days > limit.days {
+                violations.push(violation(
+                    limit.statute,
+                    Severity::Critical,
+                    format!(
+                        "Security deposit must be returned within {} days in {}. Found: {} days",
+                        limit.days, state, days
+                    ),
+                    Some(found.span),
+                ));
+            }
+        }
+    }
+
+    if let Some(limit) = profile.nonpayment_notice {
+        for notice in &terms.nonpayment_notice_days {
+            let days = notice.value;
+            if days < limit.days {
+                violations.push(violation(
+                    limit.statute,
+                    Severity::Critical,
+                    format!(
+                        "Notice for nonpayment of rent must be at least {} days in {}. Found: {} day(s)",
+                        limit.days, state, days
+                    ),
+                    Some(notice.span),
+                ));
+            }
+        }
+    }
+
+    if let Some(limit) = profile.termination_notice {
+        for notice in &terms.termination_notice_days {
+            let days = notice.value;
+            if days < limit.days {
+                violations.push(violation(
+                    limit.statute,
+                    Severity::Critical,
+                    format!(
+                        "Notice to end a month-to-month tenancy must be at least {} days in {}. Found: {} day(s)",
+                        limit.days, state, days
+                    ),
+                    Some(notice.span),
+                ));
+            }
+        }
+    }
+
+    if let Some(limit) = profile.entry_notice {
+        violations.extend(check_entry_notice(limit, state, text, terms));
+    }
+
+    if let Some(cap) = profile.late_fee {
+        violations.extend(check_late_fee(cap, state, terms));
+    }
+
+    let text_lower = text.to_lowercase();
+    for disclosure in profile.disclosures {
+        let required =
+            disclosure.when.is_empty() || disclosure.when.iter().any(|w| text_lower.contains(w));
+        let present = disclosure.keywords.iter().any(|k| text_lower.contains(k));
+        if required && !present {
+            violations.push(violation(
+                disclosure.statute,
+                Severity::Warning,
+                format!(
+                    "Missing required disclosure: {}. {} requires it in residential leases.",
+                    disclosure.label, state
+                ),
+                None,
+            ));
+        }
+    }
+
+    violations
+}
+
+/// Statutes a profile checks, as "citation - description"
+pub fn covered_statutes(profile: &StateProfile) -> Vec<String> {
+    let mut covered = Vec::new();
+    if let Some(cap) = profile.deposit_cap {
+        covered.push(format!(
+            "{} - Security deposit ({} max)",
+            cap.statute,
+            months(cap.months)
+        ));
+    }
+    if let Some(limit) = profile.deposit_return {
+        covered.push(format!(
+            "{} - Deposit return ({} days)",
+            limit.statute, limit.days
+        ));
+    }
+    if let Some(limit) = profile.nonpayment_notice {
+        covered.push(format!(
+            "{} - {}-day nonpayment notice",
+            limit.statute, limit.days
+        ));
+    }
+    if let Some(limit) = profile.termination_notice {
+        covered.push(format!(
+            "{} - {}-day month-to-month termination notice",
+            limit.statute, limit.days
+        ));
+    }
+    if let Some(limit) = profile.entry_notice {
+        covered.push(format!(
+            "{} - {}-hour entry notice",
+            limit.statute, limit.hours
+        ));
+    }
+    if let Some(cap) = profile.late_fee {
+        covered.push(format!("{} - Late fee limit", cap.statute));
+    }
+    for disclosure in profile.disclosures {
+        covered.push(format!("{} - {}", disclosure.statute, disclosure.label));
+    }
+    covered
+}
+
+fn check_entry_notice(
+    limit: HourLimit,
+    state: &str,
+    text: &str,
+    terms: &LeaseTerms,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if let Some(m) = ENTRY_WITHOUT_NOTICE_PATTERN.find(text) {
+        let lower = m.as_str().to_lowercase();
+        if !lower.contains("emergenc") {
+            violations.push(violation(
+                limit.statute,
+                Severity::Critical,
+                format!(
+                    "Landlord entry without notice is not permitted in {} except in emergencies. \
+                     At least {} hours' notice is required.",
+                    state, limit.hours
+                ),
+                Some(position(m.start(), m.end())),
+            ));
+        }
+    }
+
+    for notice in &terms.entry_notice_hours {
+        let hours = notice.value;
+        if hours < limit.hours {
+            violations.push(violation(
+                limit.statute,
+                Severity::Critical,
+                format!(
+                    "Landlord entry requires at least {} hours' notice in {}. Found: {} hour(s)",
+                    limit.hours, state, hours
+                ),
+                Some(notice.span),
+            ));
+        }
+    }
+
+    violations
+}
+
+fn check_late_fee(cap: LateFeeCap, state: &str, terms: &LeaseTerms) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if let (Some(min), Some(grace)) = (cap.grace_days, &terms.grace_period_days) {
+        if grace.value < min {
+            violations.push(violation(
+                cap.statute,
+                Severity::Critical,
+                format!(
+                    "Late fees may not be charged in {} until rent is {} days late. \
+                     Found: {}-day grace period",
+                    state, min, grace.value
+                ),
+                Some(grace.span),
+            ));
+        }
+    }
+
+    violations.extend(check_late_fee_amount(cap, state, terms));
+    violations
+}
+
+fn check_late_fee_amount(cap: LateFeeCap, state: &str, terms: &LeaseTerms) -> Option<Violation> {
+    let rent = terms.rent.as_ref().map(|r| r.value);
+    let percent = terms.late_fee_percent();
+    let fee_amount = terms.late_fee_flat();
+
+    // Cap in dollars when rent is known
+    let cap_dollars = match (
+        cap.percent.zip(rent).map(|(p, r)| r * p / 100.0),
+        cap.amount,
+    ) {
+        (Some(a), Some(b)) if cap.lesser => Some(a.min(b)),
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    let fee_dollars = fee_amount.or(percent.zip(rent).map(|(p, r)| r * p / 100.0));
+
+    let excessive = match (fee_dollars, cap_dollars) {
+        (Some(fee), Some(limit)) => fee > limit + 0.005,
+        // Without rent, compare like with like
+        _ => match (percent, cap.percent, fee_amount, cap.amount) {
+            (Some(p), Some(max), _, None) => p > max,
+            (_, None, Some(a), Some(max)) => a > max,
+            _ => false,
+        },
+    };
+    if !excessive {
+        return None;
+    }
+
+    let limit = match (cap.percent, cap.amount) {
+        (Some(p), Some(a)) if cap.lesser => format!("the lesser of {}% of rent or ${:.0}", p, a),
+        (Some(p), Some(a)) => format!("the greater of {}% of rent or ${:.0}", p, a),
+        (Some(p), None) => format!("{}% of monthly rent", p),
+        (None, Some(a)) => format!("${:.2}", a),
+        (None, None) => return None,
+    };
+    let grace = cap
+        .grace_days
+        .map(|days| format!(" and may not be charged until rent is {} days late", days))
+        .unwrap_or_default();
+    let found = match (fee_amount, percent) {
+        (Some(a), _) => format!("${:.2}", a),
+        (None, Some(p)) => format!("{}%", p),
+        (None, None) => String::new(),
+    };
+
+    Some(violation(
+        cap.statute,
+        Severity::Critical,
+        format!(
+            "Late fee exceeds {}'s limit: fees are capped at {}{}. Found: {}",
+            state, limit, grace, found
+        ),
+        terms.late_fees.first().map(|fee| fee.span),
+    ))
+}
+
+fn months(months: f64) -> String {
+    if months == 1.0 {
+        "1 month's".to_string()
+    } else {
+        format!("{} months'", months)
+    }
+}
+
+fn position(start: usize, end: usize) -> TextPosition {
+    TextPosition::new(start, end)
+}
+
+fn violation(
+    statute: &str,
+    severity: Severity,
+    message: String,
+    text_position: Option<TextPosition>,
+) -> Violation {
+    Violation {
+        statute: statute.to_string(),
+        severity,
+        message,
+        page: None,
+        text_snippet: None,
+        text_position,
+        remediation: None,
+        bounding_boxes: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_PROFILE: StateProfile = StateProfile {
+        state: State::CO,
+        deposit_cap: Some(DepositCap {
+            months: 1.5,
+            statute: "Cap § 1",
+        }),
+        deposit_return: Some(DayLimit {
+            days: 30,
+            statute: "Return § 2",
+        }),
+        nonpayment_notice: Some(DayLimit {
+            days: 10,
+            statute: "Nonpayment § 3",
+        }),
+        termination_notice: Some(DayLimit {
+            days: 21,
+            statute: "Termination § 4",
+        }),
+        entry_notice: Some(HourLimit {
+            hours: 24,
+            statute: "Entry § 5",
+        }),
+        late_fee: Some(LateFeeCap {
+            percent: Some(5.0),
+            amount: Some(50.0),
+            lesser: false,
+            grace_days: Some(7),
+            statute: "Late § 6",
+        }),
+        disclosures: &[Disclosure {
+            label: "radon disclosure",
+            keywords: &["radon"],
+            when: &[],
+            statute: "Radon § 7",
+        }],
+    };
+
+    fn statutes(text: &str) -> Vec<String> {
+        check_profile(&TEST_PROFILE, text, &LeaseTerms::extract(text))
+            .into_iter()
+            .map(|v| v.statute)
+            .collect()
+    }
+
+    #[test]
+    fn test_deposit_cap_and_return() {
+        let text = "Monthly rent: $1,000. Security deposit: $2,000. \
+                    Deposit returned within 45 days. Radon disclosure attached.";
+        let found = statutes(text);
+        assert!(found.contains(&"Cap § 1".to_string()));
+        assert!(found.contains(&"Return § 2".to_string()));
+
+        let text = "Monthly rent: $1,000. Security deposit: $1,500. \
+                    Deposit returned within 30 days. Radon disclosure attached.";
+        assert!(statutes(text).is_empty());
+    }
+
+    #[test]
+    fn test_notice_periods() {
+        let text = "Landlord may terminate after 3 days notice for nonpayment of rent. \
+                    Either party may end a month-to-month tenancy on 7 days written notice. \
+                    Radon disclosure attached.";
+        let found = statutes(text);
+        assert!(found.contains(&"Nonpayment § 3".to_string()));
+        assert!(found.contains(&"Termination § 4".to_string()));
+
+        let text = "Landlord may terminate after 10 days notice for nonpayment of rent. \
+                    Either party may end a month-to-month tenancy on 30 days written notice. \
+                    Radon disclosure attached.";
+        assert!(statutes(text).is_empty());
+    }
+
+    #[test]
+    fn test_entry_notice() {
+        let text = "Landlord may enter the premises at any time. Radon disclosure attached.";
+        assert_eq!(statutes(text), vec!["Entry § 5"]);
+
+        let text = "Landlord will give 12 hours notice before entry. Radon disclosure attached.";
+        assert_eq!(statutes(text), vec!["Entry § 5"]);
+
+        let text = "Landlord will give 1 day notice before entry, except in emergencies. \
+                    Radon disclosure attached.";
+        assert!(statutes(text).is_empty());
+    }
+
+    #[test]
+    fn test_late_fee_greater_of_cap() {
+        // Cap is the greater of 5% ($100) or $50
+        let text = "Monthly rent: $2,000. A late fee of $150 applies. Radon disclosure attached.";
+        assert_eq!(statutes(text), vec!["Late § 6"]);
+
+        let text = "Monthly rent: $2,000. A late fee of $100 applies. Radon disclosure attached.";
+        assert!(statutes(text).is_empty());
+
+        // Without rent, a percentage is compared only when there is no dollar cap
+        let text = "A late fee of 8% applies. Radon disclosure attached.";
+        assert!(statutes(text).is_empty());
+    }
+
+    #[test]
+    fn test_late_fee_grace_period() {
+        let text = "A late fee of $25 applies after a grace period of 3 days. \
+                    Radon disclosure attached.";
+        let violations = check_profile(&TEST_PROFILE, text, &LeaseTerms::extract(text));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].statute, "Late § 6");
+        assert!(violations[0].message.contains("until rent is 7 days late"));
+
+        let text = "A late fee of $25 applies after a grace period of 7 days. \
+                    Radon disclosure attached.";
+        assert!(statutes(text).is_empty());
+    }
+
+    #[test]
+    fn test_missing_disclosure() {
+        let text = "Monthly rent: $1,000.";
+        let violations = check_profile(&TEST_PROFILE, text, &LeaseTerms::extract(text));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert!(violations[0].message.contains("radon"));
+    }
+
+    #[test]
+    fn test_covered_statutes() {
+        let covered = covered_statutes(&TEST_PROFILE);
+        assert_eq!(covered.len(), 7);
+        assert_eq!(covered[0], "Cap § 1 - Security deposit (1.5 months' max)");
+    }
+}
@@ -0,0 +1,721 @@
+//! Statutory profiles for the Tier 3 URLTA block and the long tail
+//!
+//! One [`StateProfile`] per state (and DC) without a dedicated module. Limits
+//! are the statewide minimums for residential tenancies; where a statute
+//! sets different limits by lease type, the profile uses the one that applies
+//! to a typical unfurnished, non-subsidized unit and says so in a comment.
+
+use super::profile::{DayLimit, DepositCap, Disclosure, HourLimit, LateFeeCap, StateProfile};
+use crate::jurisdiction::State;
+
+const fn cap(months: f64, statute: &'static str) -> Option<DepositCap> {
+    Some(DepositCap { months, statute })
+}
+
+const fn days(days: u32, statute: &'static str) -> Option<DayLimit> {
+    Some(DayLimit { days, statute })
+}
+
+const fn hours(hours: u32, statute: &'static str) -> Option<HourLimit> {
+    Some(HourLimit { hours, statute })
+}
+
+const fn late_percent(
+    percent: f64,
+    grace_days: Option<u32>,
+    statute: &'static str,
+) -> Option<LateFeeCap> {
+    Some(LateFeeCap {
+        percent: Some(percent),
+        amount: None,
+        lesser: false,
+        grace_days,
+        statute,
+    })
+}
+
+/// Phrases showing the lease names who manages the property and where
+/// notices go, which most URLTA states require
+const OWNER_KEYWORDS: &[&str] = &[
+    "property manager",
+    "managing agent",
+    "authorized to manage",
+    "authorized agent",
+    "address for notices",
+    "notices to landlord",
+    "notice to landlord",
+    "owner's address",
+    "landlord's address",
+];
+
+const fn owner_disclosure(statute: &'static str) -> Disclosure {
+    Disclosure {
+        label: "name and address of the owner or managing agent",
+        keywords: OWNER_KEYWORDS,
+        when: &[],
+        statute,
+    }
+}
+
+const DEPOSIT_WORDS: &[&str] = &["deposit"];
+
+/// Profiles for every state without a dedicated module
+pub const PROFILES: &[StateProfile] = &[
+    // ------------------------------------------------------------------------
+    // Tier 3: URLTA Block
+    // ------------------------------------------------------------------------
+    StateProfile {
+        state: State::AK,
+        // Two months unless monthly rent exceeds $2,000
+        deposit_cap: cap(2.0, "AS 34.03.070(a)"),
+        deposit_return: days(14, "AS 34.03.070(g)"),
+        nonpayment_notice: days(7, "AS 34.03.220(b)"),
+        termination_notice: days(30, "AS 34.03.290(b)"),
+        entry_notice: hours(24, "AS 34.03.140(c)"),
+        late_fee: None,
+        disclosures: &[owner_disclosure("AS 34.03.080")],
+    },
+    StateProfile {
+        state: State::KS,
+        // Unfurnished; 1.5 months furnished
+        deposit_cap: cap(1.0, "K.S.A. 58-2550(a)"),
+        deposit_return: days(30, "K.S.A. 58-2550(b)"),
+        nonpayment_notice: days(3, "K.S.A. 58-2564(b)"),
+        termination_notice: days(30, "K.S.A. 58-2570(b)"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[owner_disclosure("K.S.A. 58-2551")],
+    },
+    StateProfile {
+        state: State::KY,
+        deposit_cap: None,
+        deposit_return: days(30, "KRS 383.580(6)"),
+        nonpayment_notice: days(7, "KRS 383.660(2)"),
+        termination_notice: days(30, "KRS 383.695(2)"),
+        entry_notice: hours(48, "KRS 383.615(3)"),
+        late_fee: None,
+        disclosures: &[
+            Disclosure {
+                label: "bank and account number where the deposit is held",
+                keywords: &["account number", "bank", "financial institution"],
+                when: DEPOSIT_WORDS,
+                statute: "KRS 383.580(1)",
+            },
+            owner_disclosure("KRS 383.585"),
+        ],
+    },
+    StateProfile {
+        state: State::NE,
+        deposit_cap: cap(1.0, "Neb. Rev. Stat. § 76-1416(1)"),
+        deposit_return: days(14, "Neb. Rev. Stat. § 76-1416(2)"),
+        nonpayment_notice: days(3, "Neb. Rev. Stat. § 76-1431(2)"),
+        termination_notice: days(30, "Neb. Rev. Stat. § 76-1437(2)"),
+        entry_notice: hours(24, "Neb. Rev. Stat. § 76-1423(3)"),
+        late_fee: None,
+        disclosures: &[owner_disclosure("Neb. Rev. Stat. § 76-1417")],
+    },
+    StateProfile {
+        state: State::NM,
+        // Leases under one year; longer leases may exceed it with interest
+        deposit_cap: cap(1.0, "NMSA § 47-8-18(A)"),
+        deposit_return: days(30, "NMSA § 47-8-18(C)"),
+        nonpayment_notice: days(3, "NMSA § 47-8-33(D)"),
+        termination_notice: days(30, "NMSA § 47-8-37(B)"),
+        entry_notice: hours(24, "NMSA § 47-8-24(A)"),
+        late_fee: late_percent(10.0, None, "NMSA § 47-8-15(D)"),
+        disclosures: &[owner_disclosure("NMSA § 47-8-19")],
+    },
+    StateProfile {
+        state: State::OR,
+        deposit_cap: None,
+        deposit_return: days(31, "ORS 90.300(13)"),
+        // 10-day notice served on the 8th day of the rental period
+        nonpayment_notice: days(10, "ORS 90.394(2)"),
+        termination_notice: days(30, "ORS 90.427(2)"),
+        entry_notice: hours(24, "ORS 90.322(1)"),
+        late_fee: None,
+        disclosures: &[
+            Disclosure {
+                label: "smoking policy",
+                keywords: &["smoking"],
+                when: &[],
+                statute: "ORS 90.220(10)",
+            },
+            Disclosure {
+                label: "whether the dwelling is in a 100-year flood plain",
+                keywords: &["flood plain", "floodplain", "flood zone"],
+                when: &[],
+                statute: "ORS 90.228",
+            },
+            owner_disclosure("ORS 90.305"),
+        ],
+    },
+    StateProfile {
+        state: State::RI,
+        deposit_cap: cap(1.0, "R.I. Gen. Laws § 34-18-19(a)"),
+        deposit_return: days(20, "R.I. Gen. Laws § 34-18-19(b)"),
+        nonpayment_notice: days(5, "R.I. Gen. Laws § 34-18-35(a)"),
+        termination_notice: days(30, "R.I. Gen. Laws § 34-18-37(a)"),
+        entry_notice: hours(48, "R.I. Gen. Laws § 34-18-26(c)"),
+        late_fee: None,
+        disclosures: &[owner_disclosure("R.I. Gen. Laws § 34-18-20")],
+    },
+    // ------------------------------------------------------------------------
+    // Tier 4: Long Tail
+    // ------------------------------------------------------------------------
+    StateProfile {
+        state: State::AL,
+        deposit_cap: cap(1.0, "Ala. Code § 35-9A-201(a)"),
+        deposit_return: days(60, "Ala. Code § 35-9A-201(b)"),
+        nonpayment_notice: days(7, "Ala. Code § 35-9A-421(b)"),
+        termination_notice: days(30, "Ala. Code § 35-9A-441(b)"),
+        entry_notice: hours(48, "Ala. Code § 35-9A-303(c)"),
+        late_fee: None,
+        disclosures: &[owner_disclosure("Ala. Code § 35-9A-202")],
+    },
+    StateProfile {
+        state: State::AR,
+        deposit_cap: cap(2.0, "Ark. Code Ann. § 18-16-304"),
+        deposit_return: days(60, "Ark. Code Ann. § 18-16-305(a)"),
+        nonpayment_notice: days(3, "Ark. Code Ann. § 18-60-304"),
+        termination_notice: days(30, "Ark. Code Ann. § 18-17-704"),
+        entry_notice: None,
+        late_fee: Some(LateFeeCap {
+            percent: Some(8.0),
+            amount: Some(20.0),
+            lesser: false,
+            grace_days: Some(5),
+            statute: "Ark. Code Ann. § 18-17-701",
+        }),
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::CO,
+        deposit_cap: None,
+        // One month unless the lease allows more, never over 60 days
+        deposit_return: days(60, "C.R.S. § 38-12-103(1)"),
+        nonpayment_notice: days(10, "C.R.S. § 13-40-104(1)(d)"),
+        termination_notice: days(21, "C.R.S. § 13-40-107(1)(b)"),
+        entry_notice: None,
+        late_fee: Some(LateFeeCap {
+            percent: Some(5.0),
+            amount: Some(50.0),
+            lesser: false,
+            grace_days: Some(7),
+            statute: "C.R.S. § 38-12-105",
+        }),
+        disclosures: &[Disclosure {
+            label: "radon disclosure",
+            keywords: &["radon"],
+            when: &[],
+            statute: "C.R.S. § 38-12-803",
+        }],
+    },
+    StateProfile {
+        state: State::CT,
+        // One month for tenants 62 or older
+        deposit_cap: cap(2.0, "C.G.S. § 47a-21(b)"),
+        deposit_return: days(21, "C.G.S. § 47a-21(d)"),
+        nonpayment_notice: days(3, "C.G.S. § 47a-23"),
+        termination_notice: None,
+        entry_notice: None,
+        late_fee: Some(LateFeeCap {
+            percent: Some(5.0),
+            amount: Some(50.0),
+            lesser: true,
+            grace_days: Some(9),
+            statute: "C.G.S. § 47a-15a",
+        }),
+        disclosures: &[owner_disclosure("C.G.S. § 47a-6")],
+    },
+    StateProfile {
+        state: State::DE,
+        // Leases of one year or more
+        deposit_cap: cap(1.0, "25 Del. C. § 5514(a)"),
+        deposit_return: days(20, "25 Del. C. § 5514(f)"),
+        nonpayment_notice: days(5, "25 Del. C. § 5502(a)"),
+        termination_notice: days(60, "25 Del. C. § 5106(c)"),
+        entry_notice: hours(48, "25 Del. C. § 5509(b)"),
+        late_fee: late_percent(5.0, Some(5), "25 Del. C. § 5501(d)"),
+        disclosures: &[Disclosure {
+            label: "summary of the Residential Landlord-Tenant Code",
+            keywords: &["landlord-tenant code", "landlord tenant code"],
+            when: &[],
+            statute: "25 Del. C. § 5118",
+        }],
+    },
+    StateProfile {
+        state: State::HI,
+        deposit_cap: cap(1.0, "HRS § 521-44(b)"),
+        deposit_return: days(14, "HRS § 521-44(c)"),
+        nonpayment_notice: days(5, "HRS § 521-68(a)"),
+        // Tenant notice; a landlord must give 45 days
+        termination_notice: days(28, "HRS § 521-71(b)"),
+        entry_notice: hours(48, "HRS § 521-53(a)"),
+        late_fee: late_percent(8.0, None, "HRS § 521-21(f)"),
+        disclosures: &[
+            owner_disclosure("HRS § 521-43"),
+            Disclosure {
+                label: "move-in inventory and condition report",
+                keywords: &["inventory", "condition report", "inspection", "checklist"],
+                when: &[],
+                statute: "HRS § 521-42(b)",
+            },
+        ],
+    },
+    StateProfile {
+        state: State::IA,
+        deposit_cap: cap(2.0, "Iowa Code § 562A.12(1)"),
+        deposit_return: days(30, "Iowa Code § 562A.12(3)"),
+        nonpayment_notice: days(3, "Iowa Code § 562A.27(2)"),
+        termination_notice: days(30, "Iowa Code § 562A.34(2)"),
+        entry_notice: hours(24, "Iowa Code § 562A.19(3)"),
+        // $60 a month when rent is $700 or less
+        late_fee: Some(LateFeeCap {
+            percent: None,
+            amount: Some(100.0),
+            lesser: false,
+            grace_days: None,
+            statute: "Iowa Code § 562A.9(4)",
+        }),
+        disclosures: &[owner_disclosure("Iowa Code § 562A.13")],
+    },
+    StateProfile {
+        state: State::ID,
+        deposit_cap: None,
+        // 21 days, or up to 30 if the lease says so
+        deposit_return: days(30, "Idaho Code § 6-321"),
+        nonpayment_notice: days(3, "Idaho Code § 6-303(2)"),
+        termination_notice: days(30, "Idaho Code § 55-208"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::IN,
+        deposit_cap: None,
+        deposit_return: days(45, "IC 32-31-3-12(a)"),
+        nonpayment_notice: days(10, "IC 32-31-1-6"),
+        termination_notice: days(30, "IC 32-31-1-1"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[owner_disclosure("IC 32-31-3-18")],
+    },
+    StateProfile {
+        state: State::LA,
+        deposit_cap: None,
+        deposit_return: days(30, "La. R.S. 9:3251(A)"),
+        nonpayment_notice: days(5, "La. C.C.P. art. 4701"),
+        termination_notice: days(10, "La. Civ. Code art. 2728"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::MD,
+        deposit_cap: cap(1.0, "Md. Code, Real Prop. § 8-203(b)"),
+        deposit_return: days(45, "Md. Code, Real Prop. § 8-203(e)"),
+        nonpayment_notice: days(10, "Md. Code, Real Prop. § 8-401(c)"),
+        termination_notice: days(30, "Md. Code, Real Prop. § 8-402(b)"),
+        entry_notice: None,
+        late_fee: late_percent(5.0, None, "Md. Code, Real Prop. § 8-208(d)(3)"),
+        disclosures: &[
+            Disclosure {
+                label: "Maryland Tenant Bill of Rights",
+                keywords: &["tenant bill of rights", "tenants' bill of rights"],
+                when: &[],
+                statute: "Md. Code, Real Prop. § 8-208(c)",
+            },
+            Disclosure {
+                label: "security deposit receipt stating the tenant's rights",
+                keywords: &["receipt"],
+                when: DEPOSIT_WORDS,
+                statute: "Md. Code, Real Prop. § 8-203.1",
+            },
+        ],
+    },
+    StateProfile {
+        state: State::ME,
+        deposit_cap: cap(2.0, "14 M.R.S. § 6032(1)"),
+        // 21 days for a tenancy at will
+        deposit_return: days(30, "14 M.R.S. § 6033(2)"),
+        nonpayment_notice: days(7, "14 M.R.S. § 6002(1)"),
+        termination_notice: days(30, "14 M.R.S. § 6002(1)"),
+        entry_notice: hours(24, "14 M.R.S. § 6025(1)"),
+        late_fee: late_percent(4.0, Some(15), "14 M.R.S. § 6028(1)"),
+        disclosures: &[
+            Disclosure {
+                label: "energy efficiency disclosure",
+                keywords: &["energy efficiency", "energy use", "heating"],
+                when: &[],
+                statute: "14 M.R.S. § 6030-C",
+            },
+            Disclosure {
+                label: "radon disclosure",
+                keywords: &["radon"],
+                when: &[],
+                statute: "14 M.R.S. § 6030-D",
+            },
+        ],
+    },
+    StateProfile {
+        state: State::MN,
+        deposit_cap: None,
+        deposit_return: days(21, "Minn. Stat. § 504B.178(3)"),
+        nonpayment_notice: days(14, "Minn. Stat. § 504B.321(1a)"),
+        termination_notice: days(30, "Minn. Stat. § 504B.135(a)"),
+        entry_notice: hours(24, "Minn. Stat. § 504B.211(2)"),
+        late_fee: late_percent(8.0, None, "Minn. Stat. § 504B.177(a)"),
+        disclosures: &[owner_disclosure("Minn. Stat. § 504B.181")],
+    },
+    StateProfile {
+        state: State::MO,
+        deposit_cap: cap(2.0, "RSMo § 535.300(1)"),
+        deposit_return: days(30, "RSMo § 535.300(2)"),
+        nonpayment_notice: None,
+        termination_notice: days(30, "RSMo § 441.060(4)"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::MS,
+        deposit_cap: None,
+        deposit_return: days(45, "Miss. Code § 89-8-21(3)"),
+        nonpayment_notice: days(3, "Miss. Code § 89-8-13(3)"),
+        termination_notice: days(30, "Miss. Code § 89-8-19(1)(b)"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::MT,
+        deposit_cap: None,
+        // 10 days when nothing is deducted
+        deposit_return: days(30, "MCA § 70-25-202"),
+        nonpayment_notice: days(3, "MCA § 70-24-422(2)"),
+        termination_notice: days(30, "MCA § 70-24-441(2)"),
+        entry_notice: hours(24, "MCA § 70-24-312(3)"),
+        late_fee: None,
+        disclosures: &[owner_disclosure("MCA § 70-24-301")],
+    },
+    StateProfile {
+        state: State::ND,
+        deposit_cap: cap(1.0, "N.D.C.C. § 47-16-07.1(1)"),
+        deposit_return: days(30, "N.D.C.C. § 47-16-07.1(3)"),
+        nonpayment_notice: days(3, "N.D.C.C. § 33-06-02"),
+        termination_notice: days(30, "N.D.C.C. § 47-16-15"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::NH,
+        // One month or $100, whichever is greater
+        deposit_cap: cap(1.0, "RSA 540-A:6(I)(a)"),
+        deposit_return: days(30, "RSA 540-A:7(I)"),
+        nonpayment_notice: days(7, "RSA 540:3(II)"),
+        termination_notice: days(30, "RSA 540:3(III)"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[Disclosure {
+            label: "deposit receipt naming the bank where the deposit is held",
+            keywords: &["bank", "financial institution"],
+            when: DEPOSIT_WORDS,
+            statute: "RSA 540-A:6(I)(b)",
+        }],
+    },
+    StateProfile {
+        state: State::NV,
+        deposit_cap: cap(3.0, "NRS 118A.242(1)"),
+        deposit_return: days(30, "NRS 118A.242(4)"),
+        nonpayment_notice: days(7, "NRS 40.2512"),
+        termination_notice: days(30, "NRS 40.251(1)(a)"),
+        entry_notice: hours(24, "NRS 118A.330(1)"),
+        late_fee: late_percent(5.0, None, "NRS 118A.210(4)"),
+        disclosures: &[
+            Disclosure {
+                label: "tenant's right to display the flag of the United States",
+                keywords: &["flag"],
+                when: &[],
+                statute: "NRS 118A.200(3)(o)",
+            },
+            // Only owed when the lease shows the landlord is in default
+            Disclosure {
+                label: "pending foreclosure or notice of default",
+                keywords: &[
+                    "pending foreclosure",
+                    "subject to foreclosure",
+                    "in foreclosure",
+                    "foreclosure disclosure",
+                    "no foreclosure",
+                    "notice of default has been",
+                ],
+                when: &[
+                    "notice of default",
+                    "notice of breach and election to sell",
+                    "trustee's sale",
+                    "trustee sale",
+                    "lis pendens",
+                ],
+                statute: "NRS 118A.275",
+            },
+        ],
+    },
+    StateProfile {
+        state: State::OK,
+        deposit_cap: None,
+        deposit_return: days(45, "41 O.S. § 115(B)"),
+        nonpayment_notice: days(5, "41 O.S. § 131(B)"),
+        termination_notice: days(30, "41 O.S. § 111(A)"),
+        entry_notice: hours(24, "41 O.S. § 128"),
+        late_fee: None,
+        disclosures: &[
+            // Owed when the premises have flooded in the past five years;
+            // a flood zone or flood insurance requirement suggests they may have
+            Disclosure {
+                label: "flooding history of the premises",
+                keywords: &[
+                    "has flooded",
+                    "have flooded",
+                    "been flooded",
+                    "not flooded",
+                    "no flooding",
+                    "flooding history",
+                    "history of flooding",
+                    "prior flooding",
+                    "previous flooding",
+                ],
+                when: &[
+                    "flood zone",
+                    "floodplain",
+                    "flood plain",
+                    "special flood hazard",
+                    "flood insurance",
+                    "flood damage",
+                ],
+                statute: "41 O.S. § 113a",
+            },
+            owner_disclosure("41 O.S. § 116"),
+        ],
+    },
+    StateProfile {
+        state: State::SC,
+        deposit_cap: None,
+        deposit_return: days(30, "S.C. Code § 27-40-410(a)"),
+        nonpayment_notice: days(5, "S.C. Code § 27-40-710(B)"),
+        termination_notice: days(30, "S.C. Code § 27-40-770(b)"),
+        entry_notice: hours(24, "S.C. Code § 27-40-530(c)"),
+        late_fee: None,
+        disclosures: &[owner_disclosure("S.C. Code § 27-40-420")],
+    },
+    StateProfile {
+        state: State::SD,
+        deposit_cap: cap(1.0, "SDCL 43-32-6.1"),
+        deposit_return: days(14, "SDCL 43-32-24"),
+        nonpayment_notice: days(3, "SDCL 21-16-2"),
+        termination_notice: days(30, "SDCL 43-32-13"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::UT,
+        deposit_cap: None,
+        deposit_return: days(30, "Utah Code § 57-17-3(2)"),
+        nonpayment_notice: days(3, "Utah Code § 78B-6-802(1)(c)"),
+        termination_notice: days(15, "Utah Code § 78B-6-802(1)(b)"),
+        entry_notice: hours(24, "Utah Code § 57-22-4(2)"),
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::VT,
+        deposit_cap: None,
+        deposit_return: days(14, "9 V.S.A. § 4461(c)"),
+        nonpayment_notice: days(14, "9 V.S.A. § 4467(a)"),
+        // Tenancies of two years or less
+        termination_notice: days(60, "9 V.S.A. § 4467(c)(1)"),
+        entry_notice: hours(48, "9 V.S.A. § 4460(b)"),
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::WI,
+        deposit_cap: None,
+        deposit_return: days(21, "Wis. Stat. § 704.28(4)"),
+        nonpayment_notice: days(5, "Wis. Stat. § 704.17(1)(a)"),
+        termination_notice: days(28, "Wis. Stat. § 704.19(3)"),
+        entry_notice: hours(12, "Wis. Admin. Code ATCP 134.09(2)"),
+        late_fee: None,
+        disclosures: &[
+            Disclosure {
+                label: "notice of domestic abuse protections",
+                keywords: &["domestic abuse"],
+                when: &[],
+                statute: "Wis. Stat. § 704.14",
+            },
+            Disclosure {
+                label: "check-in condition report",
+                keywords: &["check-in", "condition report", "inspection"],
+                when: &[],
+                statute: "Wis. Admin. Code ATCP 134.06(1)",
+            },
+        ],
+    },
+    StateProfile {
+        state: State::WV,
+        deposit_cap: None,
+        deposit_return: days(60, "W. Va. Code § 37-6A-2(a)"),
+        nonpayment_notice: None,
+        termination_notice: days(30, "W. Va. Code § 37-6-5"),
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::WY,
+        deposit_cap: None,
+        deposit_return: days(30, "Wyo. Stat. § 1-21-1208(a)"),
+        nonpayment_notice: days(3, "Wyo. Stat. § 1-21-1003"),
+        termination_notice: None,
+        entry_notice: None,
+        late_fee: None,
+        disclosures: &[],
+    },
+    StateProfile {
+        state: State::DC,
+        deposit_cap: cap(1.0, "D.C. Code § 42-3502.17(a)"),
+        deposit_return: days(45, "14 DCMR § 309.1"),
+        nonpayment_notice: days(30, "D.C. Code § 42-3505.01(a-1)"),
+        termination_notice: days(30, "D.C. Code § 42-3202"),
+        entry_notice: None,
+        late_fee: late_percent(5.0, Some(5), "D.C. Code § 42-3505.31"),
+        disclosures: &[Disclosure {
+            label: "District of Columbia Tenant Bill of Rights",
+            keywords: &["tenant bill of rights"],
+            when: &[],
+            statute: "D.C. Code § 42-3502.22",
+        }],
+    },
+];
+
+/// Profile for a state, if it has one
+pub fn profile(state: State) -> Option<&'static StateProfile> {
+    PROFILES.iter().find(|p| p.state == state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::terms::LeaseTerms;
+    use crate::states::profile::check_profile;
+
+    #[test]
+    fn test_one_profile_per_state() {
+        for (i, p) in PROFILES.iter().enumerate() {
+            assert!(
+                PROFILES[i + 1..].iter().all(|q| q.state != p.state),
+                "duplicate profile for {:?}",
+                p.state
+            );
+        }
+        assert_eq!(PROFILES.len(), 35);
+    }
+
+    #[test]
+    fn test_every_state_has_coverage() {
+        for state in State::implemented_states() {
+            assert_ne!(
+                crate::states::coverage(state),
+                shared_types::Coverage::Unavailable {
+                    reason: format!("No residential lease rules for {}", state.name())
+                },
+                "{:?} has no rules",
+                state
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_profile_checks_deposit_return() {
+        for p in PROFILES {
+            assert!(
+                p.deposit_return.is_some(),
+                "{:?} has no deposit return deadline",
+                p.state
+            );
+        }
+    }
+
+    #[test]
+    fn test_colorado_lease() {
+        let co = profile(State::CO).unwrap();
+        let text = "Monthly rent: $1,800. Security deposit: $1,800. \
+                    Deposit returned within 90 days. A late fee of $150 applies. \
+                    Either party may end a month-to-month tenancy with 10 days written notice.";
+        let violations = check_profile(co, text, &LeaseTerms::extract(text));
+
+        for statute in [
+            "C.R.S. § 38-12-103(1)",
+            "C.R.S. § 38-12-105",
+            "C.R.S. § 13-40-107(1)(b)",
+            "C.R.S. § 38-12-803",
+        ] {
+            assert!(
+                violations.iter().any(|v| v.statute == statute),
+                "missing {}: {:?}",
+                statute,
+                violations.iter().map(|v| &v.statute).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_maryland_deposit_cap() {
+        let md = profile(State::MD).unwrap();
+        let text = "Monthly rent: $2,000. Security deposit: $4,000. \
+                    Receipt provided. Maryland Tenant Bill of Rights attached.";
+        let violations = check_profile(md, text, &LeaseTerms::extract(text));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].statute, "Md. Code, Real Prop. § 8-203(b)");
+    }
+
+    #[test]
+    fn test_compliant_nevada_lease() {
+        let nv = profile(State::NV).unwrap();
+        let text = "Monthly rent: $1,500. Security deposit: $3,000. \
+                    Deposit returned within 30 days. Late fee of 5% of rent. \
+                    Landlord will give 24 hours notice before entry. \
+                    Tenant may display the flag of the United States. \
+                    No foreclosure proceedings are pending.";
+        assert!(check_profile(nv, text, &LeaseTerms::extract(text)).is_empty());
+    }
+
+    #[test]
+    fn test_conditional_disclosures_need_a_trigger() {
+        let nv = profile(State::NV).unwrap();
+        let ok = profile(State::OK).unwrap();
+        let statutes = |p, text: &str| -> Vec<String> {
+            check_profile(p, text, &LeaseTerms::extract(text))
+                .into_iter()
+                .map(|v| v.statute)
+                .collect()
+        };
+
+        let plain = "Monthly rent: $1,000.";
+        assert!(!statutes(nv, plain).contains(&"NRS 118A.275".to_string()));
+        assert!(!statutes(ok, plain).contains(&"41 O.S. § 113a".to_string()));
+
+        let default = "Monthly rent: $1,000. Rent is paid to the trustee named in the \
+                       recorded notice of default.";
+        assert!(statutes(nv, default).contains(&"NRS 118A.275".to_string()));
+        let disclosed = format!("{} The property is subject to foreclosure.", default);
+        assert!(!statutes(nv, &disclosed).contains(&"NRS 118A.275".to_string()));
+
+        let zone = "Monthly rent: $1,000. The premises are in a flood zone.";
+        assert!(statutes(ok, zone).contains(&"41 O.S. § 113a".to_string()));
+        let disclosed = format!("{} The premises have not flooded in five years.", zone);
+        assert!(!statutes(ok, &disclosed).contains(&"41 O.S. § 113a".to_string()));
+    }
+}
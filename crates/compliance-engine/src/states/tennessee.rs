@@ -8,20 +8,10 @@
 //! - Security deposit return varies by county
 //! - Landlord must provide written lease for terms > 1 year
 
-use lazy_static::lazy_static;
-use regex::Regex;
+use crate::clauses;
+use crate::extractors::terms::LeaseTerms;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
-}
-
 // Major Tennessee counties with URLTA applicability (population 75,000+)
 const URLTA_COUNTIES: &[&str] = &[
     "davidson",
@@ -50,7 +40,7 @@ const URLTA_COUNTIES: &[&str] = &[
 ];
 
 /// Check all Tennessee-specific compliance requirements
-pub fn check_tennessee_compliance(text: &str) -> Vec<Violation> {
+pub fn check_tennessee_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
     let text_lower = text.to_lowercase();
 
@@ -65,7 +55,7 @@ pub fn check_tennessee_compliance(text: &str) -> Vec<Violation> {
         violations.extend(check_non_urlta_requirements(text));
     }
 
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_void_clauses(text));
 
     violations
@@ -100,6 +90,8 @@ pub fn check_urlta_requirements(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -118,6 +110,8 @@ pub fn check_urlta_requirements(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -145,6 +139,8 @@ pub fn check_non_urlta_requirements(_text: &str) -> Vec<Violation> {
         page: None,
         text_snippet: None,
         text_position: None,
+        remediation: None,
+        bounding_boxes: Vec::new(),
     }]
 }
 
@@ -158,20 +154,14 @@ pub fn check_non_urlta_requirements(_text: &str) -> Vec<Violation> {
 /// - No statutory cap on deposit amount
 /// - Must return within reasonable time (no specific period for non-URLTA)
 /// - URLTA counties: landlord must provide itemized statement
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Warn about high deposits (more than 2 months is unusual)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -187,6 +177,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -209,6 +201,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -223,7 +217,7 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Tennessee law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's duty to maintain (URLTA counties)
     if text_lower.contains("waive")
@@ -238,6 +232,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -252,6 +248,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -265,6 +263,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -275,6 +275,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_tennessee_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // URLTA Applicability Tests
     // ========================================================================
@@ -282,7 +286,7 @@ mod tests {
     #[test]
     fn test_recognizes_urlta_county_nashville() {
         let text = "Property located in Nashville, Davidson County, TN.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -295,7 +299,7 @@ mod tests {
     #[test]
     fn test_recognizes_urlta_county_memphis() {
         let text = "Property in Memphis, Shelby County.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.message.contains("URLTA")),
@@ -306,7 +310,7 @@ mod tests {
     #[test]
     fn test_warns_non_urlta_county() {
         let text = "Property in rural Johnson County, TN.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -323,7 +327,7 @@ mod tests {
     #[test]
     fn test_warns_missing_14_day_notice() {
         let text = "Property in Nashville. For nonpayment of rent, 7 days notice required.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -340,7 +344,7 @@ mod tests {
     #[test]
     fn test_warns_high_deposit() {
         let text = "Property in Nashville. Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -353,7 +357,7 @@ mod tests {
     #[test]
     fn test_accepts_reasonable_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -370,7 +374,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive warranty of habitability.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -383,7 +387,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -396,7 +400,7 @@ mod tests {
     #[test]
     fn test_detects_tenant_remedy_waiver() {
         let text = "Tenant waives all remedies under Tennessee law.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -416,7 +420,7 @@ mod tests {
                     Monthly rent: $1,800. Security deposit: $1,800. \
                     Deposit returned within 30 days with itemized statement. \
                     Per URLTA, 14 days notice for nonpayment.";
-        let violations = check_tennessee_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
@@ -8,10 +8,20 @@
 //! - Security Deposit Return (30 days)
 //! - Repair Request Procedures
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::patterns::{ceil_char_boundary, floor_char_boundary};
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, TextPosition, Violation};
 
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[SCREENING_CRITERIA];
+
+/// Tenant screening transparency
+pub const SCREENING_CRITERIA: DatedRule =
+    DatedRule::from("Tex. Prop. Code § 92.3515", date(2025, 1, 1));
+
 // Keyword lists for Texas compliance
 const LOCKOUT_KEYWORDS: &[&str] = &[
     "lockout",
@@ -22,6 +32,12 @@ const LOCKOUT_KEYWORDS: &[&str] = &[
     "locked out",
     "deny access",
     "denying access",
+    // Spanish
+    "cambio de cerradura",
+    "cambiar la cerradura",
+    "cambiar las cerraduras",
+    "negar el acceso",
+    "impedir el acceso",
 ];
 
 const SCREENING_KEYWORDS: &[&str] = &[
@@ -31,6 +47,12 @@ const SCREENING_KEYWORDS: &[&str] = &[
     "credit check",
     "tenant screening",
     "rental application",
+    // Spanish
+    "cargo por solicitud",
+    "tarifa de solicitud",
+    "verificación de antecedentes",
+    "verificación de crédito",
+    "solicitud de alquiler",
 ];
 
 const SELECTION_CRITERIA_KEYWORDS: &[&str] = &[
@@ -38,6 +60,10 @@ const SELECTION_CRITERIA_KEYWORDS: &[&str] = &[
     "screening criteria",
     "qualification criteria",
     "rental criteria",
+    // Spanish
+    "criterios de selección",
+    "criterios de evaluación",
+    "criterios de calificación",
 ];
 
 const PARKING_TOWING_KEYWORDS: &[&str] = &[
@@ -47,15 +73,31 @@ const PARKING_TOWING_KEYWORDS: &[&str] = &[
     "parking",
     "vehicle removal",
     "unauthorized vehicle",
+    // Spanish
+    "remolque",
+    "remolcar",
+    "remolcado",
+    "estacionamiento",
+    "vehículo no autorizado",
 ];
 
 lazy_static! {
-    static ref DEPOSIT_RETURN_PATTERN: Regex =
+    /// Deposit return periods, tried in order
+    static ref DEPOSIT_RETURN_PATTERNS: Vec<Regex> = vec![
         Regex::new(r"(?i)(?:return|refund).*?(?:deposit|security).*?(?:within\s+)?(\d+)\s*days?")
-            .unwrap();
-    static ref DEPOSIT_RETURN_PATTERN_ALT: Regex =
+            .unwrap(),
         Regex::new(r"(?i)(?:deposit|security).*?(?:return|refund).*?(?:within\s+)?(\d+)\s*days?")
-            .unwrap();
+            .unwrap(),
+        Regex::new(r"(?i)(?:devol|reembols)\w*.*?dep[oó]sito.*?(?:dentro\s+de\s+(?:los\s+)?)?(\d+)\s*d[ií]as?")
+            .unwrap(),
+        Regex::new(r"(?i)dep[oó]sito.*?(?:devol|reembols)\w*.*?(?:dentro\s+de\s+(?:los\s+)?)?(\d+)\s*d[ií]as?")
+            .unwrap(),
+    ];
+
+    static ref LATE_FEE_PERCENT_PATTERN: Regex = Regex::new(
+        r"(?i)(?:late\s*fee|(?:cargo|recargo)\s+por\s+(?:pago\s+)?(?:atrasado|atraso|mora|retraso)).*?(\d+)\s*%"
+    )
+    .unwrap();
 }
 
 /// Check all Texas-specific compliance requirements
@@ -94,15 +136,17 @@ pub fn check_lockout_formatting(text: &str) -> Vec<Violation> {
             || text.contains("**")
             || text.contains("__")
             || text_lower.contains("bold")
-            || text_lower.contains("underline");
+            || text_lower.contains("underline")
+            || text_lower.contains("negrita")
+            || text_lower.contains("subrayad");
 
         // Also check for ALL CAPS as acceptable emphasis
         let lockout_pos = LOCKOUT_KEYWORDS.iter().find_map(|kw| text_lower.find(kw));
 
         let has_caps_emphasis = if let Some(pos) = lockout_pos {
             // Check if there's uppercase text around the lockout clause
-            let start = pos.saturating_sub(50);
-            let end = (pos + 100).min(text.len());
+            let start = floor_char_boundary(text, pos.saturating_sub(50));
+            let end = ceil_char_boundary(text, pos + 100);
             let context = &text[start..end];
             context
                 .chars()
@@ -121,10 +165,9 @@ pub fn check_lockout_formatting(text: &str) -> Vec<Violation> {
                 message: "Lockout clause must be in bold or underlined text. Texas law requires prominent formatting for lockout provisions.".to_string(),
                 page: None,
                 text_snippet: Some(snippet),
-                text_position: lockout_pos.map(|start| TextPosition {
-                    start_offset: start,
-                    end_offset: start + 20,
-                }),
+                text_position: lockout_pos.map(|start| TextPosition::new(start, start + 20)),
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -155,12 +198,14 @@ pub fn check_screening_notice(text: &str) -> Vec<Violation> {
 
         if !has_criteria_notice {
             violations.push(Violation {
-                statute: "Tex. Prop. Code § 92.3515".to_string(),
+                statute: SCREENING_CRITERIA.citation.to_string(),
                 severity: Severity::Critical,
                 message: "Must attach or reference Notice of Selection Criteria before accepting application fee. Include tenant screening criteria disclosure.".to_string(),
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -190,7 +235,9 @@ pub fn check_parking_towing(text: &str) -> Vec<Violation> {
             || text_lower.contains("parking rules addendum")
             || text_lower.contains("vehicle addendum")
             || text_lower.contains("towing addendum")
-            || (text_lower.contains("addendum") && text_lower.contains("parking"));
+            || (text_lower.contains("addendum") && text_lower.contains("parking"))
+            || ((text_lower.contains("anexo") || text_lower.contains("adenda"))
+                && text_lower.contains("estacionamiento"));
 
         if !has_parking_addendum {
             violations.push(Violation {
@@ -200,6 +247,8 @@ pub fn check_parking_towing(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -218,17 +267,12 @@ pub fn check_parking_towing(text: &str) -> Vec<Violation> {
 pub fn check_security_deposit(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    // Try both patterns
-    let days = DEPOSIT_RETURN_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().parse::<u32>().ok())
-        .or_else(|| {
-            DEPOSIT_RETURN_PATTERN_ALT
-                .captures(text)
-                .and_then(|caps| caps.get(1))
-                .and_then(|m| m.as_str().parse::<u32>().ok())
-        });
+    let days = DEPOSIT_RETURN_PATTERNS.iter().find_map(|pattern| {
+        pattern
+            .captures(text)
+            .and_then(|caps| caps.get(1))
+            .and_then(|m| m.as_str().parse::<u32>().ok())
+    });
 
     if let Some(days) = days {
         if days > 30 {
@@ -242,15 +286,22 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
 
     // Check for forwarding address requirement
     let text_lower = text.to_lowercase();
-    let mentions_deposit = text_lower.contains("deposit") || text_lower.contains("security");
-
-    if mentions_deposit && !text_lower.contains("forwarding address") {
+    let mentions_deposit = text_lower.contains("deposit")
+        || text_lower.contains("security")
+        || text_lower.contains("depósito");
+    let has_forwarding_address = text_lower.contains("forwarding address")
+        || text_lower.contains("dirección de reenvío")
+        || text_lower.contains("nueva dirección");
+
+    if mentions_deposit && !has_forwarding_address {
         violations.push(Violation {
             statute: "Tex. Prop. Code § 92.107".to_string(),
             severity: Severity::Info,
@@ -258,6 +309,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -279,17 +332,28 @@ pub fn check_repair_procedures(text: &str) -> Vec<Violation> {
     // Check if lease addresses repair requests
     let mentions_repair = text_lower.contains("repair")
         || text_lower.contains("maintenance")
-        || text_lower.contains("fix");
+        || text_lower.contains("fix")
+        || text_lower.contains("reparaci")
+        || text_lower.contains("reparar")
+        || text_lower.contains("mantenimiento");
 
     if mentions_repair {
         // Check for written notice requirement
-        let has_written_notice = text_lower.contains("written") && text_lower.contains("notice");
+        let has_written_notice = text_lower.contains("written") && text_lower.contains("notice")
+            || text_lower.contains("por escrito")
+                && (text_lower.contains("aviso")
+                    || text_lower.contains("notificaci")
+                    || text_lower.contains("solicitud"));
 
         // Check for reasonable time provision
         let has_reasonable_time = text_lower.contains("reasonable time")
             || text_lower.contains("reasonable period")
             || text_lower.contains("7 days")
-            || text_lower.contains("seven days");
+            || text_lower.contains("seven days")
+            || text_lower.contains("tiempo razonable")
+            || text_lower.contains("plazo razonable")
+            || text_lower.contains("7 días")
+            || text_lower.contains("siete días");
 
         if !has_written_notice {
             violations.push(Violation {
@@ -299,6 +363,8 @@ pub fn check_repair_procedures(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -310,6 +376,8 @@ pub fn check_repair_procedures(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -324,11 +392,15 @@ pub fn check_repair_procedures(text: &str) -> Vec<Violation> {
 /// Check for clauses that are void under Texas law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's repair duties (void under § 92.006)
-    if text_lower.contains("waive")
-        && (text_lower.contains("repair") || text_lower.contains("habitability"))
+    let has_waiver = text_lower.contains("waive") || text_lower.contains("renuncia");
+    if has_waiver
+        && (text_lower.contains("repair")
+            || text_lower.contains("habitability")
+            || text_lower.contains("reparaci")
+            || text_lower.contains("habitabilidad"))
     {
         violations.push(Violation {
             statute: "Tex. Prop. Code § 92.006".to_string(),
@@ -337,11 +409,13 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for waiver of jury trial (void under § 92.0062)
-    if text_lower.contains("waive") && text_lower.contains("jury") {
+    if has_waiver && (text_lower.contains("jury") || text_lower.contains("jurado")) {
         violations.push(Violation {
             statute: "Tex. Prop. Code § 92.0062".to_string(),
             severity: Severity::Critical,
@@ -349,13 +423,14 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
     // Check for excessive late fees
     // Texas allows "reasonable" late fees, typically 10-12% is accepted
-    let late_fee_pattern = Regex::new(r"(?i)late\s*fee.*?(\d+)\s*%").unwrap();
-    if let Some(caps) = late_fee_pattern.captures(&text_lower) {
+    if let Some(caps) = LATE_FEE_PERCENT_PATTERN.captures(&text_lower) {
         if let Some(pct) = caps.get(1).and_then(|m| m.as_str().parse::<u32>().ok()) {
             if pct > 12 {
                 violations.push(Violation {
@@ -368,6 +443,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
                     page: None,
                     text_snippet: None,
                     text_position: None,
+                    remediation: None,
+                    bounding_boxes: Vec::new(),
                 });
             }
         }
@@ -377,9 +454,9 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 }
 
 /// Extract context around a position for display
-fn extract_context(text: &str, pos: usize) -> String {
-    let start = pos.saturating_sub(30);
-    let end = (pos + 70).min(text.len());
+pub(crate) fn extract_context(text: &str, pos: usize) -> String {
+    let start = floor_char_boundary(text, pos.saturating_sub(30));
+    let end = ceil_char_boundary(text, pos + 70);
 
     let mut result = String::new();
     if start > 0 {
@@ -498,4 +575,67 @@ mod tests {
             .collect();
         assert!(critical.is_empty());
     }
+
+    #[test]
+    fn test_spanish_rules() {
+        let cases = [
+            (
+                "Tex. Prop. Code § 92.0081",
+                "El Arrendador puede realizar un cambio de cerradura si la renta no se paga.",
+            ),
+            (
+                "Tex. Prop. Code § 92.3515",
+                "Se cobra un cargo por solicitud para la verificación de antecedentes.",
+            ),
+            (
+                "Tex. Prop. Code § 92.0131",
+                "Los vehículos no autorizados serán remolcados.",
+            ),
+            (
+                "Tex. Prop. Code § 92.104",
+                "El Arrendador devolverá el depósito dentro de 45 días.",
+            ),
+            (
+                "Tex. Prop. Code § 92.107",
+                "El depósito de seguridad es de $1,000.",
+            ),
+            (
+                "Tex. Prop. Code § 92.056",
+                "El Inquilino debe pedir reparaciones al Arrendador.",
+            ),
+            (
+                "Tex. Prop. Code § 92.0561",
+                "El Inquilino debe pedir reparaciones al Arrendador.",
+            ),
+            (
+                "Tex. Prop. Code § 92.006",
+                "El Inquilino renuncia a las reparaciones de la vivienda.",
+            ),
+            (
+                "Tex. Prop. Code § 92.0062",
+                "El Inquilino renuncia a un juicio con jurado.",
+            ),
+            (
+                "Tex. Prop. Code § 92.019",
+                "Cargo por pago atrasado del 15% de la renta.",
+            ),
+        ];
+        for (statute, text) in cases {
+            assert!(
+                check_texas_compliance(text)
+                    .iter()
+                    .any(|v| v.statute == statute),
+                "{statute} not found in {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compliant_spanish_lease() {
+        let text = "Las solicitudes de reparación deben hacerse por escrito y el Arrendador \
+                    reparará en un tiempo razonable. El Inquilino dará una dirección de reenvío \
+                    para la devolución del depósito.";
+        let violations = check_texas_compliance(text);
+        assert!(violations.is_empty(), "{:?}", violations);
+    }
 }
@@ -9,30 +9,31 @@
 //! - Mold Disclosure
 //! - Written lease required for 3+ month terms
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[FEE_TRANSPARENCY];
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// HB 2430 fee transparency
+pub const FEE_TRANSPARENCY: DatedRule = DatedRule::from("VA Code § 55.1-1204", date(2025, 7, 1));
 
+lazy_static! {
     /// Fee pattern for fee transparency check
     static ref FEE_PATTERN: Regex =
         Regex::new(r"(?i)(application\s+fee|admin\s+fee|pet\s+fee|parking\s+fee|amenity\s+fee)[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
 }
 
 /// Check all Virginia-specific compliance requirements
-pub fn check_virginia_compliance(text: &str) -> Vec<Violation> {
+pub fn check_virginia_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_fee_transparency(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_mold_disclosure(text));
     violations.extend(check_move_inspection(text));
     violations.extend(check_void_clauses(text));
@@ -62,7 +63,7 @@ pub fn check_fee_transparency(text: &str) -> Vec<Violation> {
 
         if !has_itemization {
             violations.push(Violation {
-                statute: "VA Code § 55.1-1204".to_string(),
+                statute: FEE_TRANSPARENCY.citation.to_string(),
                 severity: Severity::Warning,
                 message: "Fees should be clearly itemized. Virginia HB 2430 requires fee \
                          transparency with all charges disclosed."
@@ -70,6 +71,8 @@ pub fn check_fee_transparency(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -88,6 +91,8 @@ pub fn check_fee_transparency(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -105,20 +110,14 @@ pub fn check_fee_transparency(text: &str) -> Vec<Violation> {
 /// - Maximum 2 months rent
 /// - Must return within 45 days
 /// - Itemized deduction statement required
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Check deposit cap (2 months max)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -134,6 +133,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -157,6 +158,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -192,6 +195,8 @@ pub fn check_mold_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -232,6 +237,8 @@ pub fn check_move_inspection(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -246,7 +253,7 @@ pub fn check_move_inspection(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Virginia law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of landlord's duty to maintain
     if text_lower.contains("waive")
@@ -260,6 +267,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -272,6 +281,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -286,6 +297,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -296,6 +309,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_virginia_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Fee Transparency Tests
     // ========================================================================
@@ -303,7 +320,7 @@ mod tests {
     #[test]
     fn test_warns_non_itemized_fees() {
         let text = "Application fee: $50. Admin fee: $100. Pet fee: $200.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -316,7 +333,7 @@ mod tests {
     #[test]
     fn test_accepts_itemized_fees() {
         let text = "Fee Schedule (itemized): Application fee $50, Credit check $25.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -333,7 +350,7 @@ mod tests {
     #[test]
     fn test_detects_excessive_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -346,7 +363,7 @@ mod tests {
     #[test]
     fn test_accepts_two_month_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $3,000.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -363,7 +380,7 @@ mod tests {
     #[test]
     fn test_warns_incomplete_mold_disclosure() {
         let text = "There may be mold in the basement. Tenant accepts risk.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -377,7 +394,7 @@ mod tests {
     fn test_accepts_proper_mold_disclosure() {
         let text = "Mold Disclosure: No known mold conditions exist. \
                     See attached mold disclosure form.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -394,7 +411,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive warranty of habitability.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -407,7 +424,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -427,7 +444,7 @@ mod tests {
                     Fee Schedule (itemized): Application $50, Credit check $25. \
                     Deposit returned within 45 days. Move-in inspection offered. \
                     Landlord maintains property in habitable condition.";
-        let violations = check_virginia_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
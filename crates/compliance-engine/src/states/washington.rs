@@ -8,19 +8,20 @@
 //! - Move-in inspection checklist required
 //! - Mold disclosure
 
+use crate::clauses;
+use crate::effective::{date, DatedRule};
+use crate::extractors::terms::LeaseTerms;
 use lazy_static::lazy_static;
 use regex::Regex;
 use shared_types::{Severity, Violation};
 
-lazy_static! {
-    /// Security deposit amount pattern
-    static ref DEPOSIT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:security\s+)?deposit[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// Rules in this module that are not in force on every date
+pub const DATED_RULES: &[DatedRule] = &[RENT_INCREASE_NOTICE];
 
-    /// Monthly rent pattern
-    static ref RENT_AMOUNT_PATTERN: Regex =
-        Regex::new(r"(?i)(?:monthly\s+)?rent[:\s]+\$?([\d,]+(?:\.\d{2})?)").unwrap();
+/// 90-day rent increase notice
+pub const RENT_INCREASE_NOTICE: DatedRule = DatedRule::from("RCW 59.18.140", date(2023, 7, 23));
 
+lazy_static! {
     /// Rent increase notice pattern
     static ref RENT_INCREASE_NOTICE_PATTERN: Regex =
         Regex::new(r"(?i)(\d+)\s*(?:day|days)\s*(?:notice|prior).*(?:rent\s+increase|increase\s+rent)").unwrap();
@@ -30,12 +31,12 @@ lazy_static! {
 const JUST_CAUSE_CITIES: &[&str] = &["seattle", "tacoma", "olympia", "burien"];
 
 /// Check all Washington-specific compliance requirements
-pub fn check_washington_compliance(text: &str) -> Vec<Violation> {
+pub fn check_washington_compliance(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     violations.extend(check_rent_increase_notice(text));
     violations.extend(check_just_cause(text));
-    violations.extend(check_security_deposit(text));
+    violations.extend(check_security_deposit(text, terms));
     violations.extend(check_mold_disclosure(text));
     violations.extend(check_void_clauses(text));
 
@@ -62,7 +63,7 @@ pub fn check_rent_increase_notice(text: &str) -> Vec<Violation> {
                 if let Ok(days) = days_str.as_str().parse::<u32>() {
                     if days < 90 {
                         violations.push(Violation {
-                            statute: "RCW 59.18.140".to_string(),
+                            statute: RENT_INCREASE_NOTICE.citation.to_string(),
                             severity: Severity::Critical,
                             message: format!(
                                 "Rent increase notice ({} days) is insufficient. \
@@ -72,6 +73,8 @@ pub fn check_rent_increase_notice(text: &str) -> Vec<Violation> {
                             page: None,
                             text_snippet: None,
                             text_position: None,
+                            remediation: None,
+                            bounding_boxes: Vec::new(),
                         });
                     }
                 }
@@ -84,13 +87,15 @@ pub fn check_rent_increase_notice(text: &str) -> Vec<Violation> {
 
             if !has_90_days {
                 violations.push(Violation {
-                    statute: "RCW 59.18.140".to_string(),
+                    statute: RENT_INCREASE_NOTICE.citation.to_string(),
                     severity: Severity::Warning,
                     message: "Rent increase requires 90 days written notice under Washington law."
                         .to_string(),
                     page: None,
                     text_snippet: None,
                     text_position: None,
+                    remediation: None,
+                    bounding_boxes: Vec::new(),
                 });
             }
         }
@@ -131,6 +136,8 @@ pub fn check_just_cause(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -148,20 +155,14 @@ pub fn check_just_cause(text: &str) -> Vec<Violation> {
 /// - No statutory cap, but must be reasonable
 /// - Must return within 21 days
 /// - Written checklist required at move-in
-pub fn check_security_deposit(text: &str) -> Vec<Violation> {
+pub fn check_security_deposit(text: &str, terms: &LeaseTerms) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Extract deposit amount
-    let deposit = DEPOSIT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let deposit = terms.security_deposit.as_ref().map(|d| d.value);
 
     // Extract rent amount
-    let rent = RENT_AMOUNT_PATTERN
-        .captures(text)
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().replace(",", "").parse::<f64>().ok());
+    let rent = terms.rent.as_ref().map(|r| r.value);
 
     // Warn about high deposits (more than 2 months is unusual)
     if let (Some(deposit_amt), Some(rent_amt)) = (deposit, rent) {
@@ -177,6 +178,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -200,6 +203,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
 
@@ -218,6 +223,8 @@ pub fn check_security_deposit(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -253,6 +260,8 @@ pub fn check_mold_disclosure(text: &str) -> Vec<Violation> {
                 page: None,
                 text_snippet: None,
                 text_position: None,
+                remediation: None,
+                bounding_boxes: Vec::new(),
             });
         }
     }
@@ -267,7 +276,7 @@ pub fn check_mold_disclosure(text: &str) -> Vec<Violation> {
 /// Check for void clauses under Washington law
 pub fn check_void_clauses(text: &str) -> Vec<Violation> {
     let mut violations = Vec::new();
-    let text_lower = text.to_lowercase();
+    let text_lower = clauses::operative_text(text).to_lowercase();
 
     // Check for waiver of statutory rights
     if text_lower.contains("waive") && text_lower.contains("tenant rights") {
@@ -278,6 +287,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -293,6 +304,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -307,6 +320,8 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
             page: None,
             text_snippet: None,
             text_position: None,
+            remediation: None,
+            bounding_boxes: Vec::new(),
         });
     }
 
@@ -317,6 +332,10 @@ pub fn check_void_clauses(text: &str) -> Vec<Violation> {
 mod tests {
     use super::*;
 
+    fn check(text: &str) -> Vec<Violation> {
+        check_washington_compliance(text, &LeaseTerms::extract(text))
+    }
+
     // ========================================================================
     // Rent Increase Notice Tests
     // ========================================================================
@@ -324,7 +343,7 @@ mod tests {
     #[test]
     fn test_detects_insufficient_rent_increase_notice() {
         let text = "60 days notice prior to any rent increase.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -337,7 +356,7 @@ mod tests {
     #[test]
     fn test_accepts_90_day_rent_increase_notice() {
         let text = "90 days notice required for any rent increase.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -354,7 +373,7 @@ mod tests {
     #[test]
     fn test_warns_missing_just_cause_seattle() {
         let text = "Property located in Seattle, WA. Monthly rent: $2,500.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations.iter().any(|v| v.message.contains("Just Cause")),
@@ -365,7 +384,7 @@ mod tests {
     #[test]
     fn test_accepts_just_cause_disclosure() {
         let text = "Property in Seattle. Just Cause eviction protections apply.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             !violations
@@ -382,7 +401,7 @@ mod tests {
     #[test]
     fn test_warns_high_deposit() {
         let text = "Monthly rent: $1,500. Security deposit: $5,000.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -395,7 +414,7 @@ mod tests {
     #[test]
     fn test_warns_missing_21_day_return() {
         let text = "Security deposit: $2,000. Deposit returned after move-out.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -412,7 +431,7 @@ mod tests {
     #[test]
     fn test_detects_tenant_rights_waiver() {
         let text = "Tenant waives all tenant rights under Washington law.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -425,7 +444,7 @@ mod tests {
     #[test]
     fn test_detects_habitability_waiver() {
         let text = "Tenant agrees to waive warranty of habitability.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -438,7 +457,7 @@ mod tests {
     #[test]
     fn test_detects_confession_of_judgment() {
         let text = "Tenant agrees to confession of judgment for unpaid rent.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         assert!(
             violations
@@ -458,7 +477,7 @@ mod tests {
                     Deposit returned within 21 days. Move-in checklist attached. \
                     90 days notice required for rent increase. \
                     Property in Seattle - Just Cause eviction applies.";
-        let violations = check_washington_compliance(text);
+        let violations = check(text);
 
         let critical: Vec<_> = violations
             .iter()
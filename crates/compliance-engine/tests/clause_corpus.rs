@@ -0,0 +1,81 @@
+//! Precision and recall of the keyword rules on the labeled clause corpus
+//!
+//! Run with `--nocapture` to see the score next to the recorded baseline and
+//! every misclassified case.
+
+use compliance_engine::{ComplianceEngine, Jurisdiction, NaiveDate, State};
+use serde::Deserialize;
+
+const CORPUS: &str = include_str!("corpus/clauses.toml");
+
+#[derive(Debug, Deserialize)]
+struct Corpus {
+    baseline: Score,
+    minimum: Score,
+    case: Vec<Case>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Score {
+    precision: f64,
+    recall: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Case {
+    id: String,
+    state: String,
+    rule: String,
+    expected: bool,
+    text: String,
+}
+
+#[test]
+fn test_clause_corpus_precision_and_recall() {
+    let corpus: Corpus = toml::from_str(CORPUS).expect("corpus/clauses.toml: invalid");
+    let engine = ComplianceEngine::new();
+    let as_of = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+    let (mut true_positives, mut false_positives, mut false_negatives) = (0, 0, 0);
+    for case in &corpus.case {
+        let state = State::parse_code(&case.state)
+            .unwrap_or_else(|| panic!("{}: bad state {:?}", case.id, case.state));
+        // Built after 1978 so the lead paint rules stay out of the way
+        let violations =
+            engine.check_text_as_of(&Jurisdiction::new(state), &case.text, Some(2000), as_of);
+        let reported = violations.iter().any(|v| v.statute.starts_with(&case.rule));
+
+        match (case.expected, reported) {
+            (true, true) => true_positives += 1,
+            (false, true) => false_positives += 1,
+            (true, false) => false_negatives += 1,
+            (false, false) => {}
+        }
+        if case.expected != reported {
+            println!(
+                "{}: expected {} {}, reported {}",
+                case.id, case.rule, case.expected, reported
+            );
+        }
+    }
+
+    let precision = true_positives as f64 / (true_positives + false_positives).max(1) as f64;
+    let recall = true_positives as f64 / (true_positives + false_negatives).max(1) as f64;
+    println!(
+        "precision {:.2} (baseline {:.2}), recall {:.2} (baseline {:.2})",
+        precision, corpus.baseline.precision, recall, corpus.baseline.recall
+    );
+
+    assert!(
+        precision >= corpus.minimum.precision,
+        "precision {:.2} below {:.2}",
+        precision,
+        corpus.minimum.precision
+    );
+    assert!(
+        recall >= corpus.minimum.recall,
+        "recall {:.2} below {:.2}",
+        recall,
+        corpus.minimum.recall
+    );
+}
@@ -2,6 +2,7 @@
 
 use crate::error::PdfJoinError;
 use crate::operations::{EditOperation, OperationLog, PdfRect, StyledTextSegment, TextStyle};
+use crate::repair::load_document;
 use lopdf::{Dictionary, Document, Object, ObjectId};
 
 /// Calculate approximate text width in points for a given string
@@ -41,14 +42,17 @@ fn parse_hex_color(color: &str) -> (f32, f32, f32) {
 }
 
 /// Apply all operations from the log to a PDF document
-pub fn apply_operations(pdf_bytes: &[u8], log: &OperationLog) -> Result<Vec<u8>, PdfJoinError> {
+pub fn apply_operations(
+    pdf_bytes: &[u8],
+    log: &OperationLog,
+    lenient: bool,
+) -> Result<Vec<u8>, PdfJoinError> {
     if log.is_empty() {
         // No changes, return original
         return Ok(pdf_bytes.to_vec());
     }
 
-    let mut doc =
-        Document::load_mem(pdf_bytes).map_err(|e| PdfJoinError::ParseError(e.to_string()))?;
+    let mut doc = load_document(pdf_bytes, lenient)?;
 
     let pages: Vec<(u32, ObjectId)> = doc.get_pages().into_iter().collect();
 
@@ -75,13 +79,13 @@ pub fn apply_operations(pdf_bytes: &[u8], log: &OperationLog) -> Result<Vec<u8>,
 pub fn apply_operations_flattened(
     pdf_bytes: &[u8],
     log: &OperationLog,
+    lenient: bool,
 ) -> Result<Vec<u8>, PdfJoinError> {
     if log.is_empty() {
         return Ok(pdf_bytes.to_vec());
     }
 
-    let mut doc =
-        Document::load_mem(pdf_bytes).map_err(|e| PdfJoinError::ParseError(e.to_string()))?;
+    let mut doc = load_document(pdf_bytes, lenient)?;
 
     let pages: Vec<(u32, ObjectId)> = doc.get_pages().into_iter().collect();
 
@@ -1106,7 +1110,7 @@ fn add_white_rect_annotation(
     add_annotation_to_page(doc, page_id, annot_id)
 }
 
-fn add_annotation_to_page(
+pub(crate) fn add_annotation_to_page(
     doc: &mut Document,
     page_id: ObjectId,
     annot_id: ObjectId,
@@ -1163,7 +1167,7 @@ mod tests {
     fn test_empty_log_returns_original() {
         let pdf = create_test_pdf();
         let log = OperationLog::new();
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         assert!(!result.is_empty());
     }
 
@@ -1184,7 +1188,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         assert!(result.starts_with(b"%PDF-"));
 
         // Verify the result is valid by loading it
@@ -1209,7 +1213,7 @@ mod tests {
             opacity: 0.5,
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
         assert_eq!(doc.get_pages().len(), 1);
     }
@@ -1230,7 +1234,7 @@ mod tests {
             checked: true,
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
         assert_eq!(doc.get_pages().len(), 1);
     }
@@ -1275,7 +1279,7 @@ mod tests {
             checked: false,
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
         assert_eq!(doc.get_pages().len(), 1);
     }
@@ -1305,7 +1309,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
 
         // Parse the output PDF text to verify white rectangle coordinates
         let output_str = String::from_utf8_lossy(&result);
@@ -1375,7 +1379,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -1439,7 +1443,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -1490,7 +1494,7 @@ mod tests {
             color: "#FFFFFF".to_string(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -1544,7 +1548,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -1670,7 +1674,7 @@ mod tests {
             },
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
 
         // The appearance stream should contain "Helvetica-Bold" as the font
         let output_str = String::from_utf8_lossy(&result);
@@ -1705,7 +1709,7 @@ mod tests {
             },
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
 
         // The appearance stream should contain "Helvetica-Oblique" as the font
         let output_str = String::from_utf8_lossy(&result);
@@ -1740,7 +1744,7 @@ mod tests {
             },
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
 
         // The appearance stream should contain "Times-BoldItalic" as the font
         let output_str = String::from_utf8_lossy(&result);
@@ -1782,7 +1786,7 @@ mod tests {
             },
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -1869,7 +1873,7 @@ mod tests {
             },
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -1939,7 +1943,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
 
         // Load the result and check for annotations
         let doc = Document::load_mem(&result).unwrap();
@@ -1983,7 +1987,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
 
         // The text should appear somewhere in the PDF content
         // (either in content stream or as a visible string)
@@ -2021,7 +2025,7 @@ mod tests {
         assert!(updated, "update_text should succeed");
 
         // Export the PDF
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         // CRITICAL: The exported PDF must contain the UPDATED text, NOT the original
@@ -2060,7 +2064,7 @@ mod tests {
 
         log.update_text(op_id, "UPDATED_FLATTENED_TEXT", None);
 
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         assert!(
@@ -2089,7 +2093,7 @@ mod tests {
             color: "#FFFFFF".to_string(),
         });
 
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
 
         // The white color (1 1 1 rg) should appear in the PDF
         let result_str = String::from_utf8_lossy(&result);
@@ -2116,7 +2120,7 @@ mod tests {
             color: "#000000".to_string(), // Black for redaction
         });
 
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
 
         // The black color (0 0 0 rg) should appear in the PDF
         let result_str = String::from_utf8_lossy(&result);
@@ -2205,7 +2209,7 @@ mod tests {
         eprintln!("Operations: {:?}", log.operations());
         eprintln!("Is empty: {}", log.is_empty());
 
-        let result = apply_operations_flattened(&pdf_bytes, &log).unwrap();
+        let result = apply_operations_flattened(&pdf_bytes, &log, false).unwrap();
 
         eprintln!("Result PDF size: {} bytes", result.len());
 
@@ -2235,7 +2239,7 @@ mod tests {
             color: "#000000".to_string(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -2285,7 +2289,7 @@ mod tests {
             color: "#FF0000".to_string(), // Red underline
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         let pages: Vec<_> = doc.get_pages().into_iter().collect();
@@ -2332,7 +2336,7 @@ mod tests {
             color: "#000000".to_string(),
         });
 
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         // Underline should use stroke (S) not fill (f)
@@ -2450,7 +2454,7 @@ mod tests {
         });
 
         // Test annotation-based export (compress)
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         assert!(
@@ -2500,7 +2504,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
 
         // Get the page and its annotations
@@ -2716,7 +2720,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf_bytes, &log).unwrap();
+        let result = apply_operations(&pdf_bytes, &log, false).unwrap();
         let result_doc = Document::load_mem(&result).unwrap();
 
         // Get annotations from the result
@@ -2828,7 +2832,7 @@ mod tests {
             },
         });
 
-        let result = apply_operations(&pdf_bytes, &log).unwrap();
+        let result = apply_operations(&pdf_bytes, &log, false).unwrap();
         let result_doc = Document::load_mem(&result).unwrap();
 
         // Check page 1 annotations
@@ -3000,7 +3004,7 @@ mod tests {
         }
 
         // STEP 6: Export (this is what happens when user clicks Download)
-        let result = apply_operations(&pdf_bytes, &log).unwrap();
+        let result = apply_operations(&pdf_bytes, &log, false).unwrap();
         let result_doc = Document::load_mem(&result).unwrap();
 
         // Check annotations on page 1
@@ -3104,7 +3108,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         // The text MUST appear in the PDF content
@@ -3160,7 +3164,7 @@ mod tests {
             style: TextStyle::default(),
         });
 
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         assert!(
@@ -3212,7 +3216,7 @@ mod tests {
         });
 
         // Test flattened export
-        let result = apply_operations_flattened(&pdf, &log).unwrap();
+        let result = apply_operations_flattened(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         assert!(
@@ -3264,7 +3268,7 @@ mod tests {
         });
 
         // Test regular export (annotations)
-        let result = apply_operations(&pdf, &log).unwrap();
+        let result = apply_operations(&pdf, &log, false).unwrap();
         let result_str = String::from_utf8_lossy(&result);
 
         assert!(
@@ -3276,7 +3280,7 @@ mod tests {
         );
 
         // Also test flattened export
-        let flat_result = apply_operations_flattened(&pdf, &log).unwrap();
+        let flat_result = apply_operations_flattened(&pdf, &log, false).unwrap();
         let flat_str = String::from_utf8_lossy(&flat_result);
 
         assert!(
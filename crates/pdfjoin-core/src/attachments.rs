@@ -0,0 +1,572 @@
+//! Embedded file attachments
+//!
+//! Reads and writes document-level attachments stored in the catalog's
+//! `/Names /EmbeddedFiles` name tree, and page-level attachments stored as
+//! `FileAttachment` annotations. Both point at a file specification whose
+//! `/EF` entry holds the `/EmbeddedFile` stream.
+//!
+//! Checksums are the MD5 of the uncompressed contents, the same value PDF
+//! stores in the embedded file's `/Params /CheckSum`.
+
+use crate::apply_operations::add_annotation_to_page;
+use crate::error::PdfJoinError;
+use crate::operations::PdfRect;
+use crate::repair::load_document;
+use crate::text_string::{encode_text_string, object_text};
+use lopdf::{Dictionary, Document, Object, ObjectId, Stream, StringFormat};
+use md5::{Digest, Md5};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A file to embed, or one extracted from a PDF
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EmbeddedFile {
+    pub name: String,
+    pub data: Vec<u8>,
+    /// MIME type, stored as the embedded file stream's `/Subtype`
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Summary of an attachment without its contents
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AttachmentInfo {
+    pub name: String,
+    pub mime_type: Option<String>,
+    pub description: Option<String>,
+    /// Uncompressed size in bytes
+    pub size: usize,
+    /// Lowercase hex MD5 of the uncompressed contents
+    pub checksum: String,
+    /// 1-based page for `FileAttachment` annotations, `None` for document-level files
+    pub page: Option<u32>,
+}
+
+/// Attach a file at document level (in the `/EmbeddedFiles` name tree).
+/// An existing attachment with the same name is replaced.
+pub fn add_attachment(
+    pdf_bytes: &[u8],
+    file: &EmbeddedFile,
+    lenient: bool,
+) -> Result<Vec<u8>, PdfJoinError> {
+    let mut doc = load_document(pdf_bytes, lenient)?;
+
+    let filespec_id = add_filespec(&mut doc, file);
+    let mut entries: Vec<(String, Object)> = embedded_file_entries(&doc)
+        .into_iter()
+        .filter(|(name, _)| name != &file.name)
+        .collect();
+    entries.push((file.name.clone(), Object::Reference(filespec_id)));
+    set_embedded_file_entries(&mut doc, entries)?;
+
+    save(&mut doc)
+}
+
+/// Attach a file to a page as a `FileAttachment` annotation (1-based page number)
+pub fn add_file_annotation(
+    pdf_bytes: &[u8],
+    page: u32,
+    rect: &PdfRect,
+    file: &EmbeddedFile,
+    lenient: bool,
+) -> Result<Vec<u8>, PdfJoinError> {
+    let mut doc = load_document(pdf_bytes, lenient)?;
+
+    let page_id = doc
+        .get_pages()
+        .get(&page)
+        .copied()
+        .ok_or_else(|| PdfJoinError::InvalidRange(format!("Page {} does not exist", page)))?;
+
+    let filespec_id = add_filespec(&mut doc, file);
+
+    let mut annot = Dictionary::new();
+    annot.set("Type", Object::Name(b"Annot".to_vec()));
+    annot.set("Subtype", Object::Name(b"FileAttachment".to_vec()));
+    annot.set(
+        "Rect",
+        Object::Array(vec![
+            Object::Real(rect.x as f32),
+            Object::Real(rect.y as f32),
+            Object::Real((rect.x + rect.width) as f32),
+            Object::Real((rect.y + rect.height) as f32),
+        ]),
+    );
+    annot.set("FS", Object::Reference(filespec_id));
+    annot.set(
+        "Contents",
+        encode_text_string(file.description.as_deref().unwrap_or(&file.name)),
+    );
+    annot.set("Name", Object::Name(b"PushPin".to_vec()));
+
+    let annot_id = doc.add_object(Object::Dictionary(annot));
+    add_annotation_to_page(&mut doc, page_id, annot_id)?;
+
+    save(&mut doc)
+}
+
+/// List document-level and page-level attachments
+pub fn list_attachments(
+    pdf_bytes: &[u8],
+    lenient: bool,
+) -> Result<Vec<AttachmentInfo>, PdfJoinError> {
+    let doc = load_document(pdf_bytes, lenient)?;
+
+    Ok(collect_attachments(&doc)
+        .into_iter()
+        .map(|(file, page)| AttachmentInfo {
+            size: file.data.len(),
+            checksum: md5_hex(&file.data),
+            name: file.name,
+            mime_type: file.mime_type,
+            description: file.description,
+            page,
+        })
+        .collect())
+}
+
+/// Extract the attachment with the given name.
+/// Document-level attachments take precedence over page annotations.
+pub fn extract_attachment(
+    pdf_bytes: &[u8],
+    name: &str,
+    lenient: bool,
+) -> Result<EmbeddedFile, PdfJoinError> {
+    let doc = load_document(pdf_bytes, lenient)?;
+
+    collect_attachments(&doc)
+        .into_iter()
+        .map(|(file, _)| file)
+        .find(|file| file.name == name)
+        .ok_or_else(|| PdfJoinError::OperationError(format!("Attachment not found: {}", name)))
+}
+
+fn save(doc: &mut Document) -> Result<Vec<u8>, PdfJoinError> {
+    let mut output = Vec::new();
+    doc.save_to(&mut output)
+        .map_err(|e| PdfJoinError::OperationError(e.to_string()))?;
+    Ok(output)
+}
+
+fn md5_hex(data: &[u8]) -> String {
+    Md5::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Create the embedded file stream and its file specification, returning the filespec ID
+fn add_filespec(doc: &mut Document, file: &EmbeddedFile) -> ObjectId {
+    let mut params = Dictionary::new();
+    params.set("Size", Object::Integer(file.data.len() as i64));
+    params.set(
+        "CheckSum",
+        Object::String(Md5::digest(&file.data).to_vec(), StringFormat::Hexadecimal),
+    );
+
+    let mut stream_dict = Dictionary::new();
+    stream_dict.set("Type", Object::Name(b"EmbeddedFile".to_vec()));
+    if let Some(mime) = &file.mime_type {
+        stream_dict.set("Subtype", Object::Name(mime.as_bytes().to_vec()));
+    }
+    stream_dict.set("Params", Object::Dictionary(params));
+
+    let mut stream = Stream::new(stream_dict, file.data.clone());
+    // Attachments are often already-compressed (images, xlsx); ignore failures
+    let _ = stream.compress();
+    let stream_id = doc.add_object(Object::Stream(stream));
+
+    let mut ef = Dictionary::new();
+    ef.set("F", Object::Reference(stream_id));
+    ef.set("UF", Object::Reference(stream_id));
+
+    let mut filespec = Dictionary::new();
+    filespec.set("Type", Object::Name(b"Filespec".to_vec()));
+    filespec.set("F", encode_text_string(&file.name));
+    filespec.set("UF", encode_text_string(&file.name));
+    if let Some(desc) = &file.description {
+        filespec.set("Desc", encode_text_string(desc));
+    }
+    filespec.set("EF", Object::Dictionary(ef));
+
+    doc.add_object(Object::Dictionary(filespec))
+}
+
+/// Every attachment in the document, name-tree entries first
+fn collect_attachments(doc: &Document) -> Vec<(EmbeddedFile, Option<u32>)> {
+    let mut seen_filespecs = BTreeSet::new();
+    let mut attachments = Vec::new();
+
+    for (name, filespec) in embedded_file_entries(doc) {
+        if let Ok(id) = filespec.as_reference() {
+            seen_filespecs.insert(id);
+        }
+        if let Some(mut file) = read_filespec(doc, &filespec) {
+            // The name tree key is authoritative for document-level files
+            file.name = name;
+            attachments.push((file, None));
+        }
+    }
+
+    for (page_num, page_id) in doc.get_pages() {
+        for annot in doc.get_page_annotations(page_id) {
+            let is_file_attachment = annot
+                .get(b"Subtype")
+                .and_then(Object::as_name_str)
+                .map(|s| s == "FileAttachment")
+                .unwrap_or(false);
+            let Ok(fs) = annot.get(b"FS") else {
+                continue;
+            };
+            if !is_file_attachment {
+                continue;
+            }
+            if let Ok(id) = fs.as_reference() {
+                if !seen_filespecs.insert(id) {
+                    continue;
+                }
+            }
+            if let Some(file) = read_filespec(doc, fs) {
+                attachments.push((file, Some(page_num)));
+            }
+        }
+    }
+
+    attachments
+}
+
+/// Decode a file specification and its embedded stream
+fn read_filespec(doc: &Document, filespec: &Object) -> Option<EmbeddedFile> {
+    let (_, filespec) = doc.dereference(filespec).ok()?;
+    let dict = filespec.as_dict().ok()?;
+
+    let name = dict
+        .get(b"UF")
+        .ok()
+        .or_else(|| dict.get(b"F").ok())
+        .and_then(object_text)
+        .unwrap_or_default();
+    let description = dict.get(b"Desc").ok().and_then(object_text);
+
+    let ef = dict.get(b"EF").ok()?;
+    let (_, ef) = doc.dereference(ef).ok()?;
+    let ef = ef.as_dict().ok()?;
+    let stream_ref = ef.get(b"UF").or_else(|_| ef.get(b"F")).ok()?;
+    let (_, stream) = doc.dereference(stream_ref).ok()?;
+    let stream = stream.as_stream().ok()?;
+
+    let mime_type = stream
+        .dict
+        .get(b"Subtype")
+        .and_then(Object::as_name_str)
+        .ok()
+        .map(str::to_string);
+    let data = if stream.dict.has(b"Filter") {
+        stream.decompressed_content().ok()?
+    } else {
+        stream.content.clone()
+    };
+
+    Some(EmbeddedFile {
+        name,
+        data,
+        mime_type,
+        description,
+    })
+}
+
+/// Flatten the `/EmbeddedFiles` name tree into (name, filespec) pairs
+pub(crate) fn embedded_file_entries(doc: &Document) -> Vec<(String, Object)> {
+    let mut entries = Vec::new();
+    let root = doc
+        .catalog()
+        .ok()
+        .and_then(|catalog| catalog.get(b"Names").ok())
+        .and_then(|names| doc.dereference(names).ok())
+        .and_then(|(_, names)| names.as_dict().ok())
+        .and_then(|names| names.get(b"EmbeddedFiles").ok());
+
+    if let Some(root) = root {
+        collect_name_tree(doc, root, &mut entries, 0);
+    }
+    entries
+}
+
+fn collect_name_tree(
+    doc: &Document,
+    node: &Object,
+    entries: &mut Vec<(String, Object)>,
+    depth: usize,
+) {
+    // Guard against reference cycles in malformed trees
+    if depth > 32 {
+        return;
+    }
+    let Some(node) = doc
+        .dereference(node)
+        .ok()
+        .and_then(|(_, n)| n.as_dict().ok())
+    else {
+        return;
+    };
+
+    if let Ok(names) = node.get(b"Names").and_then(Object::as_array) {
+        for pair in names.chunks_exact(2) {
+            if let Some(name) = object_text(&pair[0]) {
+                entries.push((name, pair[1].clone()));
+            }
+        }
+    }
+    if let Ok(kids) = node.get(b"Kids").and_then(Object::as_array) {
+        for kid in kids {
+            collect_name_tree(doc, kid, entries, depth + 1);
+        }
+    }
+}
+
+/// Append " (2)", " (3)", ... to a name already taken in a merged name tree
+pub(crate) fn unique_attachment_name(name: String, taken: impl Fn(&str) -> bool) -> String {
+    if !taken(&name) {
+        return name;
+    }
+    (2..)
+        .map(|i| format!("{} ({})", name, i))
+        .find(|candidate| !taken(candidate))
+        .unwrap()
+}
+
+/// Replace the `/EmbeddedFiles` name tree with a single sorted leaf
+pub(crate) fn set_embedded_file_entries(
+    doc: &mut Document,
+    entries: Vec<(String, Object)>,
+) -> Result<(), PdfJoinError> {
+    // Name trees are sorted by the bytes of their keys as written, which
+    // for UTF-16BE names differs from the order of the decoded strings
+    let mut keyed: Vec<(Object, Object)> = entries
+        .into_iter()
+        .map(|(name, filespec)| (encode_text_string(&name), filespec))
+        .collect();
+    keyed.sort_by(|a, b| a.0.as_str().unwrap_or(&[]).cmp(b.0.as_str().unwrap_or(&[])));
+
+    let mut names = Vec::with_capacity(keyed.len() * 2);
+    for (name, filespec) in keyed {
+        names.push(name);
+        names.push(filespec);
+    }
+    let mut tree = Dictionary::new();
+    tree.set("Names", Object::Array(names));
+
+    let names_ref = doc
+        .catalog()
+        .map_err(|e| PdfJoinError::OperationError(e.to_string()))?
+        .get(b"Names")
+        .ok()
+        .and_then(|n| n.as_reference().ok());
+
+    match names_ref {
+        Some(id) => {
+            let names_dict = doc
+                .get_dictionary_mut(id)
+                .map_err(|e| PdfJoinError::OperationError(e.to_string()))?;
+            names_dict.set("EmbeddedFiles", Object::Dictionary(tree));
+        }
+        None => {
+            let catalog = doc
+                .catalog_mut()
+                .map_err(|e| PdfJoinError::OperationError(e.to_string()))?;
+            let mut names_dict = catalog
+                .get(b"Names")
+                .and_then(Object::as_dict)
+                .cloned()
+                .unwrap_or_default();
+            names_dict.set("EmbeddedFiles", Object::Dictionary(tree));
+            catalog.set("Names", Object::Dictionary(names_dict));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::dictionary;
+
+    fn create_test_pdf(num_pages: u32) -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let kids: Vec<Object> = (0..num_pages)
+            .map(|_| {
+                doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+                })
+                .into()
+            })
+            .collect();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => num_pages,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut buffer = Vec::new();
+        doc.save_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    fn audit_json() -> EmbeddedFile {
+        EmbeddedFile {
+            name: "audit-trail.json".to_string(),
+            data: br#"{"events":[{"action":"signed"}]}"#.to_vec(),
+            mime_type: Some("application/json".to_string()),
+            description: Some("Signing audit trail".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_add_and_list_attachment() {
+        let pdf = add_attachment(&create_test_pdf(1), &audit_json(), false).unwrap();
+        let list = list_attachments(&pdf, false).unwrap();
+
+        assert_eq!(list.len(), 1);
+        let info = &list[0];
+        assert_eq!(info.name, "audit-trail.json");
+        assert_eq!(info.mime_type.as_deref(), Some("application/json"));
+        assert_eq!(info.description.as_deref(), Some("Signing audit trail"));
+        assert_eq!(info.size, audit_json().data.len());
+        assert_eq!(info.checksum, md5_hex(&audit_json().data));
+        assert_eq!(info.page, None);
+    }
+
+    #[test]
+    fn test_extract_round_trips_contents() {
+        let pdf = add_attachment(&create_test_pdf(1), &audit_json(), false).unwrap();
+        let file = extract_attachment(&pdf, "audit-trail.json", false).unwrap();
+        assert_eq!(file, audit_json());
+    }
+
+    #[test]
+    fn test_extract_missing_is_error() {
+        let pdf = create_test_pdf(1);
+        assert!(extract_attachment(&pdf, "nope.txt", false).is_err());
+    }
+
+    #[test]
+    fn test_adding_same_name_replaces() {
+        let pdf = add_attachment(&create_test_pdf(1), &audit_json(), false).unwrap();
+        let mut updated = audit_json();
+        updated.data = b"{}".to_vec();
+        let pdf = add_attachment(&pdf, &updated, false).unwrap();
+
+        let list = list_attachments(&pdf, false).unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].size, 2);
+    }
+
+    #[test]
+    fn test_name_tree_is_sorted() {
+        let mut pdf = create_test_pdf(1);
+        for name in ["zeta.txt", "alpha.txt", "mid.txt"] {
+            pdf = add_attachment(
+                &pdf,
+                &EmbeddedFile {
+                    name: name.to_string(),
+                    data: name.as_bytes().to_vec(),
+                    mime_type: None,
+                    description: None,
+                },
+                false,
+            )
+            .unwrap();
+        }
+
+        let names: Vec<String> = list_attachments(&pdf, false)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.name)
+            .collect();
+        assert_eq!(names, vec!["alpha.txt", "mid.txt", "zeta.txt"]);
+    }
+
+    #[test]
+    fn test_name_tree_is_sorted_by_encoded_bytes() {
+        // As UTF-16BE, the surrogate pair of U+1F4C4 (D8 3D) sorts before
+        // U+FF21 (FF 21), the reverse of their order as strings
+        let mut pdf = create_test_pdf(1);
+        for name in ["\u{FF21}.txt", "\u{1F4C4}.txt", "plain.txt"] {
+            pdf = add_attachment(
+                &pdf,
+                &EmbeddedFile {
+                    name: name.to_string(),
+                    data: name.as_bytes().to_vec(),
+                    mime_type: None,
+                    description: None,
+                },
+                false,
+            )
+            .unwrap();
+        }
+
+        let names: Vec<String> = list_attachments(&pdf, false)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.name)
+            .collect();
+        assert_eq!(names, vec!["plain.txt", "\u{1F4C4}.txt", "\u{FF21}.txt"]);
+    }
+
+    #[test]
+    fn test_file_annotation_is_listed_with_page() {
+        let rect = PdfRect {
+            x: 500.0,
+            y: 700.0,
+            width: 20.0,
+            height: 20.0,
+        };
+        let pdf = add_file_annotation(&create_test_pdf(2), 2, &rect, &audit_json(), false).unwrap();
+
+        let list = list_attachments(&pdf, false).unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].page, Some(2));
+        assert_eq!(
+            extract_attachment(&pdf, "audit-trail.json", false)
+                .unwrap()
+                .data,
+            audit_json().data
+        );
+    }
+
+    #[test]
+    fn test_file_annotation_invalid_page() {
+        let rect = PdfRect {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 10.0,
+        };
+        assert!(add_file_annotation(&create_test_pdf(1), 3, &rect, &audit_json(), false).is_err());
+    }
+
+    #[test]
+    fn test_unicode_attachment_name() {
+        let mut file = audit_json();
+        file.name = "fotografía_1.jpg".to_string();
+        let pdf = add_attachment(&create_test_pdf(1), &file, false).unwrap();
+        assert_eq!(
+            list_attachments(&pdf, false).unwrap()[0].name,
+            "fotografía_1.jpg"
+        );
+    }
+}
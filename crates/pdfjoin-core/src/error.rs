@@ -11,6 +11,9 @@ pub enum PdfJoinError {
     #[error("PDF operation failed: {0}")]
     OperationError(String),
 
+    #[error("Invalid search query: {0}")]
+    InvalidQuery(String),
+
     #[error("Serialization error: {0}")]
     SerializationError(String),
 }
@@ -0,0 +1,1120 @@
+//! Positioned text extraction
+//!
+//! Walks page content streams with a small text-state interpreter and reports
+//! text runs together with their bounding boxes. Coordinates are PDF user
+//! space (origin bottom-left, points), the same system used by `PdfRect` in
+//! edit operations, so a run's `bbox` can be passed straight to `ReplaceText`
+//! or `AddHighlight`.
+//!
+//! Glyph widths come from the font's `/Widths` (simple fonts) or `/W`
+//! (CID fonts) when present, and fall back to the same per-font averages used
+//! when laying out new text. Text is decoded through `/ToUnicode` when the
+//! font has one, otherwise through the font's base encoding.
+
+use crate::error::PdfJoinError;
+use crate::operations::PdfRect;
+use crate::repair::load_document;
+use lopdf::content::Content;
+use lopdf::{Dictionary, Document, Object, ObjectId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Form XObjects can nest; stop descending past this depth
+const MAX_FORM_DEPTH: usize = 8;
+
+/// Fraction of the font size that glyphs extend above the baseline
+const ASCENT: f64 = 0.8;
+
+/// Fraction of the font size that glyphs extend below the baseline
+const DESCENT: f64 = 0.2;
+
+/// A contiguous run of text drawn with one font on one baseline
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TextRun {
+    /// 1-based page number, matching `EditOperation::page`
+    pub page: u32,
+    pub text: String,
+    /// Bounding box of the whole run in PDF user space
+    pub bbox: PdfRect,
+    /// Base font name without subset prefix (e.g. "Helvetica-Bold")
+    pub font_name: String,
+    /// Effective font size in points (text size scaled by the text and CTM matrices)
+    pub font_size: f64,
+    /// One box per `char` of `text`, used to compute search hit rectangles
+    pub glyphs: Vec<PdfRect>,
+}
+
+/// Extract positioned text runs from every page of a PDF
+pub fn extract_text_runs(bytes: &[u8], lenient: bool) -> Result<Vec<TextRun>, PdfJoinError> {
+    let doc = load_document(bytes, lenient)?;
+    extract_text_runs_from_document(&doc)
+}
+
+/// Extract positioned text runs from an already-parsed document
+pub fn extract_text_runs_from_document(doc: &Document) -> Result<Vec<TextRun>, PdfJoinError> {
+    let mut runs = Vec::new();
+    for (page_num, page_id) in doc.get_pages() {
+        runs.extend(extract_page_runs(doc, page_num, page_id)?);
+    }
+    Ok(runs)
+}
+
+/// Extract positioned text runs from a single page (1-based page number)
+pub fn extract_page_text_runs(
+    bytes: &[u8],
+    page: u32,
+    lenient: bool,
+) -> Result<Vec<TextRun>, PdfJoinError> {
+    let doc = load_document(bytes, lenient)?;
+    let page_id = doc
+        .get_pages()
+        .get(&page)
+        .copied()
+        .ok_or_else(|| PdfJoinError::InvalidRange(format!("Page {} does not exist", page)))?;
+    extract_page_runs(&doc, page, page_id)
+}
+
+fn extract_page_runs(
+    doc: &Document,
+    page_num: u32,
+    page_id: ObjectId,
+) -> Result<Vec<TextRun>, PdfJoinError> {
+    let content = doc
+        .get_page_content(page_id)
+        .map_err(|e| PdfJoinError::ParseError(format!("Page {}: {}", page_num, e)))?;
+
+    let fonts = doc
+        .get_page_fonts(page_id)
+        .into_iter()
+        .map(|(name, dict)| (name, FontInfo::from_dict(doc, dict)))
+        .collect();
+    let xobjects = page_xobjects(doc, page_id);
+
+    let mut interpreter = Interpreter {
+        doc,
+        page: page_num,
+        runs: Vec::new(),
+    };
+    interpreter.run(&content, &fonts, &xobjects, IDENTITY, 0);
+    Ok(merge_adjacent_runs(interpreter.runs))
+}
+
+// ============================================================================
+// Matrices
+// ============================================================================
+
+type Matrix = [f64; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// Compute `a × b` using the PDF row-vector convention
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    [
+        a[0] * b[0] + a[1] * b[2],
+        a[0] * b[1] + a[1] * b[3],
+        a[2] * b[0] + a[3] * b[2],
+        a[2] * b[1] + a[3] * b[3],
+        a[4] * b[0] + a[5] * b[2] + b[4],
+        a[4] * b[1] + a[5] * b[3] + b[5],
+    ]
+}
+
+fn transform_point(m: &Matrix, x: f64, y: f64) -> (f64, f64) {
+    (m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5])
+}
+
+/// Axis-aligned bounds of a text-space rectangle after transforming it by `m`
+fn transform_rect(m: &Matrix, x0: f64, y0: f64, x1: f64, y1: f64) -> PdfRect {
+    let corners = [
+        transform_point(m, x0, y0),
+        transform_point(m, x1, y0),
+        transform_point(m, x0, y1),
+        transform_point(m, x1, y1),
+    ];
+    let min_x = corners.iter().map(|c| c.0).fold(f64::INFINITY, f64::min);
+    let max_x = corners
+        .iter()
+        .map(|c| c.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = corners.iter().map(|c| c.1).fold(f64::INFINITY, f64::min);
+    let max_y = corners
+        .iter()
+        .map(|c| c.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    PdfRect {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
+    }
+}
+
+/// Smallest rectangle containing both `a` and `b`
+pub(crate) fn union_rect(a: &PdfRect, b: &PdfRect) -> PdfRect {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+    PdfRect {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
+
+/// Vertical scale factor of a matrix (length of the transformed unit y vector)
+fn vertical_scale(m: &Matrix) -> f64 {
+    (m[2] * m[2] + m[3] * m[3]).sqrt()
+}
+
+fn operand_f64(obj: &Object) -> Option<f64> {
+    match obj {
+        Object::Integer(i) => Some(*i as f64),
+        Object::Real(r) => Some(*r as f64),
+        _ => None,
+    }
+}
+
+fn operands_matrix(operands: &[Object]) -> Option<Matrix> {
+    if operands.len() < 6 {
+        return None;
+    }
+    let mut m = [0.0; 6];
+    for (slot, operand) in m.iter_mut().zip(operands) {
+        *slot = operand_f64(operand)?;
+    }
+    Some(m)
+}
+
+// ============================================================================
+// Fonts
+// ============================================================================
+
+/// The subset of a font dictionary needed to decode and measure text
+#[derive(Debug, Clone, Default)]
+struct FontInfo {
+    base_font: String,
+    /// Type0 fonts use two-byte character codes
+    two_byte: bool,
+    encoding: Option<String>,
+    to_unicode: Option<BTreeMap<u32, String>>,
+    /// Glyph widths in thousandths of text space units, keyed by character code
+    widths: BTreeMap<u32, f64>,
+    default_width: f64,
+}
+
+impl FontInfo {
+    fn from_dict(doc: &Document, dict: &Dictionary) -> Self {
+        let base_font = dict
+            .get(b"BaseFont")
+            .and_then(Object::as_name_str)
+            .map(strip_subset_prefix)
+            .unwrap_or("Helvetica")
+            .to_string();
+        let two_byte = dict
+            .get(b"Subtype")
+            .and_then(Object::as_name_str)
+            .map(|s| s == "Type0")
+            .unwrap_or(false);
+        let encoding = dict
+            .get(b"Encoding")
+            .ok()
+            .and_then(|enc| resolve(doc, enc))
+            .and_then(|enc| match enc {
+                Object::Name(name) => Some(String::from_utf8_lossy(name).into_owned()),
+                Object::Dictionary(d) => d
+                    .get(b"BaseEncoding")
+                    .and_then(Object::as_name_str)
+                    .ok()
+                    .map(str::to_string),
+                _ => None,
+            });
+        let to_unicode = dict
+            .get(b"ToUnicode")
+            .ok()
+            .and_then(|obj| resolve(doc, obj))
+            .and_then(|obj| obj.as_stream().ok())
+            .and_then(|stream| {
+                stream
+                    .decompressed_content()
+                    .ok()
+                    .or_else(|| Some(stream.content.clone()))
+            })
+            .map(|data| parse_to_unicode(&data));
+
+        let mut info = FontInfo {
+            default_width: fallback_width(&base_font),
+            base_font,
+            two_byte,
+            encoding,
+            to_unicode,
+            widths: BTreeMap::new(),
+        };
+
+        if two_byte {
+            info.load_cid_widths(doc, dict);
+        } else {
+            info.load_simple_widths(doc, dict);
+        }
+        info
+    }
+
+    /// Used for content that selects a font missing from the page resources
+    fn fallback(name: &[u8]) -> Self {
+        let base_font = String::from_utf8_lossy(name).into_owned();
+        FontInfo {
+            default_width: fallback_width(&base_font),
+            base_font,
+            ..Default::default()
+        }
+    }
+
+    fn load_simple_widths(&mut self, doc: &Document, dict: &Dictionary) {
+        let first_char = dict
+            .get(b"FirstChar")
+            .and_then(Object::as_i64)
+            .unwrap_or(0)
+            .max(0) as u32;
+        let widths = dict
+            .get(b"Widths")
+            .ok()
+            .and_then(|w| resolve(doc, w))
+            .and_then(|w| w.as_array().ok());
+        if let Some(widths) = widths {
+            for (i, w) in widths.iter().enumerate() {
+                if let Some(w) = resolve(doc, w).and_then(operand_f64) {
+                    self.widths.insert(first_char + i as u32, w);
+                }
+            }
+        }
+    }
+
+    fn load_cid_widths(&mut self, doc: &Document, dict: &Dictionary) {
+        let descendant = dict
+            .get(b"DescendantFonts")
+            .ok()
+            .and_then(|d| resolve(doc, d))
+            .and_then(|d| d.as_array().ok())
+            .and_then(|arr| arr.first())
+            .and_then(|d| resolve(doc, d))
+            .and_then(|d| d.as_dict().ok());
+        let Some(descendant) = descendant else {
+            return;
+        };
+
+        self.default_width = descendant
+            .get(b"DW")
+            .ok()
+            .and_then(operand_f64)
+            .unwrap_or(1000.0);
+
+        let w = descendant
+            .get(b"W")
+            .ok()
+            .and_then(|w| resolve(doc, w))
+            .and_then(|w| w.as_array().ok());
+        let Some(w) = w else {
+            return;
+        };
+
+        // /W is a sequence of either `c [w1 w2 ...]` or `c_first c_last w`
+        let mut i = 0;
+        while i < w.len() {
+            let Some(start) = operand_f64(&w[i]) else {
+                break;
+            };
+            let start = start as u32;
+            match w.get(i + 1).and_then(|o| resolve(doc, o)) {
+                Some(Object::Array(list)) => {
+                    for (offset, width) in list.iter().enumerate() {
+                        let code = start.saturating_add(offset as u32);
+                        if code > MAX_CID {
+                            break;
+                        }
+                        if let Some(width) = operand_f64(width) {
+                            self.widths.insert(code, width);
+                        }
+                    }
+                    i += 2;
+                }
+                Some(end) => {
+                    let (Some(end), Some(width)) =
+                        (operand_f64(end), w.get(i + 2).and_then(operand_f64))
+                    else {
+                        break;
+                    };
+                    // CIDs stop at 65535, whatever a hostile range claims
+                    for code in start..=(end as u32).min(MAX_CID) {
+                        self.widths.insert(code, width);
+                    }
+                    i += 3;
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Split a string operand into (character code, decoded text) pairs
+    fn decode(&self, bytes: &[u8]) -> Vec<(u32, String)> {
+        let codes: Vec<u32> = if self.two_byte {
+            bytes
+                .chunks(2)
+                .map(|c| c.iter().fold(0u32, |acc, &b| (acc << 8) | b as u32))
+                .collect()
+        } else {
+            bytes.iter().map(|&b| b as u32).collect()
+        };
+
+        codes
+            .into_iter()
+            .map(|code| {
+                let text = match &self.to_unicode {
+                    Some(map) => map.get(&code).cloned().unwrap_or_default(),
+                    None if self.two_byte => String::new(),
+                    None => match &self.encoding {
+                        Some(enc) => Document::decode_text(Some(enc), &[code as u8]),
+                        None => (code as u8 as char).to_string(),
+                    },
+                };
+                (code, text)
+            })
+            .collect()
+    }
+
+    fn width(&self, code: u32) -> f64 {
+        self.widths
+            .get(&code)
+            .copied()
+            .unwrap_or(self.default_width)
+    }
+}
+
+/// Strip the six-letter subset tag from names like "ABCDEF+ArialMT"
+fn strip_subset_prefix(name: &str) -> &str {
+    match name.split_once('+') {
+        Some((tag, rest)) if tag.len() == 6 && tag.chars().all(|c| c.is_ascii_uppercase()) => rest,
+        _ => name,
+    }
+}
+
+/// Average glyph width (thousandths of an em) used when a font has no width table
+fn fallback_width(base_font: &str) -> f64 {
+    if base_font.contains("Courier") {
+        600.0
+    } else if base_font.contains("Times") {
+        500.0
+    } else {
+        550.0
+    }
+}
+
+fn resolve<'a>(doc: &'a Document, obj: &'a Object) -> Option<&'a Object> {
+    doc.dereference(obj).ok().map(|(_, o)| o)
+}
+
+/// Largest CID a font can use
+const MAX_CID: u32 = 0xFFFF;
+
+/// Most codes one `bfrange` entry may map
+const MAX_BFRANGE_LEN: u32 = 0x1_0000;
+
+/// Parse the `bfchar` and `bfrange` sections of a ToUnicode CMap
+fn parse_to_unicode(data: &[u8]) -> BTreeMap<u32, String> {
+    let text = String::from_utf8_lossy(data);
+    let tokens = cmap_tokens(&text);
+    let mut map = BTreeMap::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match tokens[i] {
+            CmapToken::Keyword("beginbfchar") => {
+                i += 1;
+                while i + 1 < tokens.len() {
+                    match (&tokens[i], &tokens[i + 1]) {
+                        (CmapToken::Hex(src), CmapToken::Hex(dst)) => {
+                            map.insert(hex_code(src), utf16_hex_to_string(dst));
+                            i += 2;
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            CmapToken::Keyword("beginbfrange") => {
+                i += 1;
+                while i + 2 < tokens.len() {
+                    let (CmapToken::Hex(lo), CmapToken::Hex(hi)) = (&tokens[i], &tokens[i + 1])
+                    else {
+                        break;
+                    };
+                    // A range cannot leave the code space of its first
+                    // code, and is capped so a hostile CMap cannot ask for
+                    // billions of entries
+                    let (lo, hi) = {
+                        let code = hex_code(lo);
+                        let hi = hex_code(hi)
+                            .min(code_space_max(lo))
+                            .min(code.saturating_add(MAX_BFRANGE_LEN - 1));
+                        (code, hi)
+                    };
+                    match &tokens[i + 2] {
+                        CmapToken::Hex(dst) => {
+                            let base = utf16_hex_to_string(dst);
+                            for (offset, code) in (lo..=hi).enumerate() {
+                                map.insert(code, offset_last_char(&base, offset as u32));
+                            }
+                            i += 3;
+                        }
+                        CmapToken::ArrayStart => {
+                            let mut j = i + 3;
+                            let mut code = Some(lo);
+                            while let Some(CmapToken::Hex(dst)) = tokens.get(j) {
+                                if let Some(c) = code.filter(|c| *c <= hi) {
+                                    map.insert(c, utf16_hex_to_string(dst));
+                                }
+                                code = code.and_then(|c| c.checked_add(1));
+                                j += 1;
+                            }
+                            // Skip the closing bracket
+                            i = j + 1;
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    map
+}
+
+#[derive(Debug, PartialEq)]
+enum CmapToken<'a> {
+    Hex(&'a str),
+    ArrayStart,
+    ArrayEnd,
+    Keyword(&'a str),
+}
+
+fn cmap_tokens(text: &str) -> Vec<CmapToken<'_>> {
+    let mut tokens = Vec::new();
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'<' if bytes.get(i + 1) != Some(&b'<') => {
+                let end = text[i + 1..]
+                    .find('>')
+                    .map(|p| i + 1 + p)
+                    .unwrap_or(text.len());
+                tokens.push(CmapToken::Hex(&text[i + 1..end]));
+                i = end + 1;
+            }
+            b'[' => {
+                tokens.push(CmapToken::ArrayStart);
+                i += 1;
+            }
+            b']' => {
+                tokens.push(CmapToken::ArrayEnd);
+                i += 1;
+            }
+            b if b.is_ascii_alphabetic() => {
+                let start = i;
+                while i < bytes.len() && bytes[i].is_ascii_alphanumeric() {
+                    i += 1;
+                }
+                tokens.push(CmapToken::Keyword(&text[start..i]));
+            }
+            _ => i += 1,
+        }
+    }
+    tokens
+}
+
+/// Largest code in the code space of a CMap code written as `hex`: one to
+/// four bytes wide
+fn code_space_max(hex: &str) -> u32 {
+    let digits = hex.chars().filter(|c| c.is_ascii_hexdigit()).count();
+    match digits.div_ceil(2) {
+        0 | 1 => 0xFF,
+        2 => 0xFFFF,
+        3 => 0xFF_FFFF,
+        _ => u32::MAX,
+    }
+}
+
+fn hex_code(hex: &str) -> u32 {
+    let digits: String = hex.chars().filter(|c| c.is_ascii_hexdigit()).collect();
+    u32::from_str_radix(&digits, 16).unwrap_or(0)
+}
+
+fn utf16_hex_to_string(hex: &str) -> String {
+    let digits: Vec<u8> = hex
+        .bytes()
+        .filter(|b| b.is_ascii_hexdigit())
+        .collect::<Vec<_>>()
+        .chunks(2)
+        .filter_map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect();
+    let units: Vec<u16> = digits
+        .chunks(2)
+        .map(|c| {
+            if c.len() == 2 {
+                u16::from_be_bytes([c[0], c[1]])
+            } else {
+                c[0] as u16
+            }
+        })
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// `bfrange` destinations increment the last character for each code in the range
+fn offset_last_char(base: &str, offset: u32) -> String {
+    let mut chars: Vec<char> = base.chars().collect();
+    if let Some(last) = chars.last_mut() {
+        if let Some(c) = char::from_u32(*last as u32 + offset) {
+            *last = c;
+        }
+    }
+    chars.into_iter().collect()
+}
+
+// ============================================================================
+// Content stream interpreter
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct GraphicsState {
+    ctm: Matrix,
+    font: Option<Vec<u8>>,
+    font_size: f64,
+    char_spacing: f64,
+    word_spacing: f64,
+    /// Horizontal scaling as a fraction (Tz / 100)
+    horizontal_scale: f64,
+    leading: f64,
+    rise: f64,
+}
+
+impl GraphicsState {
+    fn new(ctm: Matrix) -> Self {
+        Self {
+            ctm,
+            font: None,
+            font_size: 0.0,
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            horizontal_scale: 1.0,
+            leading: 0.0,
+            rise: 0.0,
+        }
+    }
+}
+
+fn page_xobjects(doc: &Document, page_id: ObjectId) -> BTreeMap<Vec<u8>, ObjectId> {
+    let (direct, inherited) = doc.get_page_resources(page_id);
+    let mut resources: Vec<&Dictionary> = inherited
+        .into_iter()
+        .filter_map(|id| doc.get_dictionary(id).ok())
+        .collect();
+    resources.extend(direct);
+    xobjects_from_resources(doc, resources)
+}
+
+fn xobjects_from_resources<'a>(
+    doc: &Document,
+    resources: impl IntoIterator<Item = &'a Dictionary>,
+) -> BTreeMap<Vec<u8>, ObjectId> {
+    let mut xobjects = BTreeMap::new();
+    for res in resources {
+        let dict = res
+            .get(b"XObject")
+            .ok()
+            .and_then(|x| resolve(doc, x))
+            .and_then(|x| x.as_dict().ok());
+        if let Some(dict) = dict {
+            for (name, value) in dict.iter() {
+                if let Ok(id) = value.as_reference() {
+                    xobjects.entry(name.clone()).or_insert(id);
+                }
+            }
+        }
+    }
+    xobjects
+}
+
+fn fonts_from_resources(doc: &Document, resources: &Dictionary) -> BTreeMap<Vec<u8>, FontInfo> {
+    let mut fonts = BTreeMap::new();
+    let dict = resources
+        .get(b"Font")
+        .ok()
+        .and_then(|f| resolve(doc, f))
+        .and_then(|f| f.as_dict().ok());
+    if let Some(dict) = dict {
+        for (name, value) in dict.iter() {
+            if let Some(Ok(font)) = resolve(doc, value).map(Object::as_dict) {
+                fonts.insert(name.clone(), FontInfo::from_dict(doc, font));
+            }
+        }
+    }
+    fonts
+}
+
+struct Interpreter<'a> {
+    doc: &'a Document,
+    page: u32,
+    runs: Vec<TextRun>,
+}
+
+impl Interpreter<'_> {
+    fn run(
+        &mut self,
+        content: &[u8],
+        fonts: &BTreeMap<Vec<u8>, FontInfo>,
+        xobjects: &BTreeMap<Vec<u8>, ObjectId>,
+        base_ctm: Matrix,
+        depth: usize,
+    ) {
+        let Ok(content) = Content::decode(content) else {
+            return;
+        };
+
+        let mut state = GraphicsState::new(base_ctm);
+        let mut stack: Vec<GraphicsState> = Vec::new();
+        let mut text_matrix = IDENTITY;
+        let mut line_matrix = IDENTITY;
+
+        for op in &content.operations {
+            let operands = &op.operands;
+            match op.operator.as_str() {
+                "q" => stack.push(state.clone()),
+                "Q" => {
+                    if let Some(saved) = stack.pop() {
+                        state = saved;
+                    }
+                }
+                "cm" => {
+                    if let Some(m) = operands_matrix(operands) {
+                        state.ctm = multiply(&m, &state.ctm);
+                    }
+                }
+                "BT" => {
+                    text_matrix = IDENTITY;
+                    line_matrix = IDENTITY;
+                }
+                "Tf" => {
+                    if let (Some(Object::Name(name)), Some(size)) =
+                        (operands.first(), operands.get(1).and_then(operand_f64))
+                    {
+                        state.font = Some(name.clone());
+                        state.font_size = size;
+                    }
+                }
+                "Tc" => {
+                    if let Some(v) = operands.first().and_then(operand_f64) {
+                        state.char_spacing = v;
+                    }
+                }
+                "Tw" => {
+                    if let Some(v) = operands.first().and_then(operand_f64) {
+                        state.word_spacing = v;
+                    }
+                }
+                "Tz" => {
+                    if let Some(v) = operands.first().and_then(operand_f64) {
+                        state.horizontal_scale = v / 100.0;
+                    }
+                }
+                "TL" => {
+                    if let Some(v) = operands.first().and_then(operand_f64) {
+                        state.leading = v;
+                    }
+                }
+                "Ts" => {
+                    if let Some(v) = operands.first().and_then(operand_f64) {
+                        state.rise = v;
+                    }
+                }
+                "Td" | "TD" => {
+                    if let (Some(tx), Some(ty)) = (
+                        operands.first().and_then(operand_f64),
+                        operands.get(1).and_then(operand_f64),
+                    ) {
+                        if op.operator == "TD" {
+                            state.leading = -ty;
+                        }
+                        line_matrix = multiply(&[1.0, 0.0, 0.0, 1.0, tx, ty], &line_matrix);
+                        text_matrix = line_matrix;
+                    }
+                }
+                "Tm" => {
+                    if let Some(m) = operands_matrix(operands) {
+                        line_matrix = m;
+                        text_matrix = m;
+                    }
+                }
+                "T*" => {
+                    line_matrix =
+                        multiply(&[1.0, 0.0, 0.0, 1.0, 0.0, -state.leading], &line_matrix);
+                    text_matrix = line_matrix;
+                }
+                "Tj" => {
+                    if let Some(Object::String(bytes, _)) = operands.first() {
+                        self.show(&state, fonts, &mut text_matrix, &[TjItem::Text(bytes)]);
+                    }
+                }
+                "'" | "\"" => {
+                    if op.operator == "\"" {
+                        if let Some(v) = operands.first().and_then(operand_f64) {
+                            state.word_spacing = v;
+                        }
+                        if let Some(v) = operands.get(1).and_then(operand_f64) {
+                            state.char_spacing = v;
+                        }
+                    }
+                    line_matrix =
+                        multiply(&[1.0, 0.0, 0.0, 1.0, 0.0, -state.leading], &line_matrix);
+                    text_matrix = line_matrix;
+                    if let Some(Object::String(bytes, _)) = operands.last() {
+                        self.show(&state, fonts, &mut text_matrix, &[TjItem::Text(bytes)]);
+                    }
+                }
+                "TJ" => {
+                    if let Some(Object::Array(items)) = operands.first() {
+                        let items: Vec<TjItem> = items
+                            .iter()
+                            .filter_map(|item| match item {
+                                Object::String(bytes, _) => Some(TjItem::Text(bytes)),
+                                other => operand_f64(other).map(TjItem::Adjust),
+                            })
+                            .collect();
+                        self.show(&state, fonts, &mut text_matrix, &items);
+                    }
+                }
+                "Do" if depth < MAX_FORM_DEPTH => {
+                    if let Some(Object::Name(name)) = operands.first() {
+                        if let Some(&id) = xobjects.get(name) {
+                            self.run_form(id, &state.ctm, depth);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Interpret a form XObject with its own resources and matrix
+    fn run_form(&mut self, id: ObjectId, ctm: &Matrix, depth: usize) {
+        let Ok(Object::Stream(stream)) = self.doc.get_object(id) else {
+            return;
+        };
+        let is_form = stream
+            .dict
+            .get(b"Subtype")
+            .and_then(Object::as_name_str)
+            .map(|s| s == "Form")
+            .unwrap_or(false);
+        if !is_form {
+            return;
+        }
+
+        let form_matrix = stream
+            .dict
+            .get(b"Matrix")
+            .ok()
+            .and_then(|m| m.as_array().ok())
+            .and_then(|m| operands_matrix(m))
+            .unwrap_or(IDENTITY);
+        let resources = stream
+            .dict
+            .get(b"Resources")
+            .ok()
+            .and_then(|r| resolve(self.doc, r))
+            .and_then(|r| r.as_dict().ok());
+        let (fonts, xobjects) = match resources {
+            Some(res) => (
+                fonts_from_resources(self.doc, res),
+                xobjects_from_resources(self.doc, [res]),
+            ),
+            None => (BTreeMap::new(), BTreeMap::new()),
+        };
+        let content = stream
+            .decompressed_content()
+            .unwrap_or_else(|_| stream.content.clone());
+
+        self.run(
+            &content,
+            &fonts,
+            &xobjects,
+            multiply(&form_matrix, ctm),
+            depth + 1,
+        );
+    }
+
+    /// Lay out a text-showing operation and record it as a run
+    fn show(
+        &mut self,
+        state: &GraphicsState,
+        fonts: &BTreeMap<Vec<u8>, FontInfo>,
+        text_matrix: &mut Matrix,
+        items: &[TjItem<'_>],
+    ) {
+        let font_key = state.font.clone().unwrap_or_default();
+        let fallback;
+        let font = match fonts.get(&font_key) {
+            Some(f) => f,
+            None => {
+                fallback = FontInfo::fallback(&font_key);
+                &fallback
+            }
+        };
+
+        let size = state.font_size;
+        let scale = state.horizontal_scale;
+        let mut text = String::new();
+        let mut glyphs = Vec::new();
+
+        for item in items {
+            match item {
+                TjItem::Adjust(n) => {
+                    let tx = -n / 1000.0 * size * scale;
+                    // Large negative adjustments are how many producers encode word gaps
+                    if *n < -200.0 && !text.is_empty() && !text.ends_with(' ') {
+                        let trm = multiply(text_matrix, &state.ctm);
+                        text.push(' ');
+                        glyphs.push(glyph_rect(&trm, 0.0, tx, size, state.rise));
+                    }
+                    *text_matrix = multiply(&[1.0, 0.0, 0.0, 1.0, tx, 0.0], text_matrix);
+                }
+                TjItem::Text(bytes) => {
+                    for (code, decoded) in font.decode(bytes) {
+                        let w0 = font.width(code) / 1000.0;
+                        let is_space = !font.two_byte && code == 32;
+                        let advance = (w0 * size
+                            + state.char_spacing
+                            + if is_space { state.word_spacing } else { 0.0 })
+                            * scale;
+
+                        let trm = multiply(text_matrix, &state.ctm);
+                        let rect = glyph_rect(&trm, 0.0, w0 * size * scale, size, state.rise);
+                        for c in decoded.chars() {
+                            text.push(c);
+                            glyphs.push(rect.clone());
+                        }
+
+                        *text_matrix = multiply(&[1.0, 0.0, 0.0, 1.0, advance, 0.0], text_matrix);
+                    }
+                }
+            }
+        }
+
+        if text.trim().is_empty() {
+            return;
+        }
+
+        let bbox = glyphs
+            .iter()
+            .skip(1)
+            .fold(glyphs[0].clone(), |acc, g| union_rect(&acc, g));
+        let effective_size = size * vertical_scale(&multiply(text_matrix, &state.ctm));
+
+        self.runs.push(TextRun {
+            page: self.page,
+            text,
+            bbox,
+            font_name: font.base_font.clone(),
+            font_size: effective_size,
+            glyphs,
+        });
+    }
+}
+
+enum TjItem<'a> {
+    Text(&'a [u8]),
+    Adjust(f64),
+}
+
+/// Box of a single glyph in user space, spanning descent to ascent
+fn glyph_rect(trm: &Matrix, x0: f64, x1: f64, size: f64, rise: f64) -> PdfRect {
+    transform_rect(trm, x0, rise - DESCENT * size, x1, rise + ASCENT * size)
+}
+
+/// Join consecutive runs that continue the same line in the same font.
+/// Producers often emit one `Tj` per word or even per glyph.
+fn merge_adjacent_runs(runs: Vec<TextRun>) -> Vec<TextRun> {
+    let mut merged: Vec<TextRun> = Vec::with_capacity(runs.len());
+
+    for run in runs {
+        if let Some(prev) = merged.last_mut() {
+            let same_style =
+                prev.font_name == run.font_name && (prev.font_size - run.font_size).abs() < 0.01;
+            let same_line = (prev.bbox.y - run.bbox.y).abs() < prev.font_size * 0.2;
+            let gap = run.bbox.x - (prev.bbox.x + prev.bbox.width);
+
+            if same_style && same_line && gap > -prev.font_size * 0.5 && gap < prev.font_size {
+                if gap > prev.font_size * 0.15 && !prev.text.ends_with(' ') {
+                    let space = PdfRect {
+                        x: prev.bbox.x + prev.bbox.width,
+                        y: prev.bbox.y,
+                        width: gap,
+                        height: prev.bbox.height,
+                    };
+                    prev.text.push(' ');
+                    prev.glyphs.push(space);
+                }
+                prev.bbox = union_rect(&prev.bbox, &run.bbox);
+                prev.text.push_str(&run.text);
+                prev.glyphs.extend(run.glyphs);
+                continue;
+            }
+        }
+        merged.push(run);
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::{dictionary, Stream};
+
+    /// Build a one-page PDF with the given content stream and a Helvetica /F1
+    fn pdf_with_content(content: &str) -> Vec<u8> {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.as_bytes().to_vec()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            "Contents" => content_id,
+            "Resources" => dictionary! {
+                "Font" => dictionary! { "F1" => font_id },
+            },
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut buffer = Vec::new();
+        doc.save_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn test_extracts_text_and_position() {
+        let pdf = pdf_with_content("BT /F1 12 Tf 72 700 Td (Hello World) Tj ET");
+        let runs = extract_text_runs(&pdf, false).unwrap();
+
+        assert_eq!(runs.len(), 1);
+        let run = &runs[0];
+        assert_eq!(run.page, 1);
+        assert_eq!(run.text, "Hello World");
+        assert_eq!(run.font_name, "Helvetica");
+        assert!((run.font_size - 12.0).abs() < 0.001);
+        assert!((run.bbox.x - 72.0).abs() < 0.001);
+        // Baseline at 700, box spans descent to ascent
+        assert!(run.bbox.y < 700.0 && run.bbox.y + run.bbox.height > 700.0);
+        assert_eq!(run.glyphs.len(), run.text.chars().count());
+    }
+
+    #[test]
+    fn test_tm_and_cm_are_applied() {
+        let pdf =
+            pdf_with_content("q 2 0 0 2 0 0 cm BT /F1 10 Tf 1 0 0 1 50 100 Tm (Scaled) Tj ET Q");
+        let runs = extract_text_runs(&pdf, false).unwrap();
+
+        assert_eq!(runs.len(), 1);
+        assert!((runs[0].bbox.x - 100.0).abs() < 0.001);
+        assert!((runs[0].font_size - 20.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_separate_lines_are_separate_runs() {
+        let pdf =
+            pdf_with_content("BT /F1 12 Tf 14 TL 72 700 Td (First line) Tj T* (Second line) Tj ET");
+        let runs = extract_text_runs(&pdf, false).unwrap();
+
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].text, "First line");
+        assert_eq!(runs[1].text, "Second line");
+        assert!((runs[0].bbox.y - runs[1].bbox.y - 14.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_tj_kerning_gap_becomes_space() {
+        let pdf = pdf_with_content("BT /F1 12 Tf 72 700 Td [(Hello) -250 (World)] TJ ET");
+        let runs = extract_text_runs(&pdf, false).unwrap();
+
+        assert_eq!(runs[0].text, "Hello World");
+    }
+
+    #[test]
+    fn test_word_per_tj_runs_are_merged() {
+        let pdf = pdf_with_content(
+            "BT /F1 12 Tf 72 700 Td (Late) Tj ET BT /F1 12 Tf 103 700 Td (fee) Tj ET",
+        );
+        let runs = extract_text_runs(&pdf, false).unwrap();
+
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, "Late fee");
+    }
+
+    #[test]
+    fn test_to_unicode_bfchar_and_bfrange() {
+        let cmap = b"begincmap\n2 beginbfchar\n<0001> <0048>\n<0002> <0069>\nendbfchar\n\
+            1 beginbfrange\n<0010> <0012> <0061>\nendbfrange\n\
+            1 beginbfrange\n<0020> <0021> [<0058> <0059>]\nendbfrange\nendcmap";
+        let map = parse_to_unicode(cmap);
+
+        assert_eq!(map.get(&1).map(String::as_str), Some("H"));
+        assert_eq!(map.get(&2).map(String::as_str), Some("i"));
+        assert_eq!(map.get(&0x11).map(String::as_str), Some("b"));
+        assert_eq!(map.get(&0x12).map(String::as_str), Some("c"));
+        assert_eq!(map.get(&0x21).map(String::as_str), Some("Y"));
+    }
+
+    #[test]
+    fn test_to_unicode_ranges_are_bounded() {
+        // The range is clamped to the two-byte code space of <0000>
+        let cmap = b"1 beginbfrange\n<0000> <FFFFFFFF> <0041>\nendbfrange";
+        let map = parse_to_unicode(cmap);
+        assert_eq!(map.len(), 0x1_0000);
+
+        // Array entries past the last code do not overflow
+        let cmap = b"1 beginbfrange\n<FFFFFFFF> <FFFFFFFF> [<0041> <0042>]\nendbfrange";
+        let map = parse_to_unicode(cmap);
+        assert_eq!(map.get(&u32::MAX).map(String::as_str), Some("A"));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_strip_subset_prefix() {
+        assert_eq!(strip_subset_prefix("ABCDEF+ArialMT"), "ArialMT");
+        assert_eq!(strip_subset_prefix("Helvetica"), "Helvetica");
+        assert_eq!(strip_subset_prefix("Foo+Bar"), "Foo+Bar");
+    }
+
+    #[test]
+    fn test_invalid_page_is_error() {
+        let pdf = pdf_with_content("BT /F1 12 Tf 72 700 Td (x) Tj ET");
+        assert!(extract_page_text_runs(&pdf, 2, false).is_err());
+        assert_eq!(extract_page_text_runs(&pdf, 1, false).unwrap().len(), 1);
+    }
+}
@@ -7,23 +7,36 @@
 //! - `streaming::split_streaming` / `streaming::merge_streaming`: Byte-level (faster, experimental)
 
 pub mod apply_operations;
+pub mod attachments;
 pub mod command;
 pub mod error;
+pub mod extract;
 pub mod merge;
+pub mod merge_writer;
+pub mod metadata;
 pub mod operations;
+pub mod repair;
+pub mod search;
 pub mod split;
 pub mod streaming;
+mod text_string;
+pub mod xmp;
 
+pub use attachments::{AttachmentInfo, EmbeddedFile};
 pub use command::{PdfCommand, ProcessMetrics, ProcessResult};
 pub use error::PdfJoinError;
+pub use extract::{extract_text_runs, TextRun};
 pub use merge::merge_documents;
+pub use merge_writer::{merge_to_writer, MergeWriter};
+pub use metadata::DocumentMetadata;
+pub use repair::{repair_pdf, RepairFix, RepairReport};
+pub use search::{search, SearchHit, SearchOptions};
 pub use split::split_document;
 pub use streaming::{merge_streaming, split_streaming};
 
 /// Parse PDF bytes and return page count
-pub fn get_page_count(bytes: &[u8]) -> Result<u32, PdfJoinError> {
-    let doc =
-        lopdf::Document::load_mem(bytes).map_err(|e| PdfJoinError::ParseError(e.to_string()))?;
+pub fn get_page_count(bytes: &[u8], lenient: bool) -> Result<u32, PdfJoinError> {
+    let doc = repair::load_document(bytes, lenient)?;
     Ok(doc.get_pages().len() as u32)
 }
 
@@ -80,6 +93,7 @@ pub fn parse_ranges(input: &str) -> Result<Vec<u32>, PdfJoinError> {
 ///
 /// # Arguments
 /// * `bytes` - The PDF file as a byte slice
+/// * `lenient` - Recover damaged files instead of failing (see [`repair`])
 ///
 /// # Returns
 /// * `Ok(true)` if the PDF contains signatures
@@ -92,15 +106,14 @@ pub fn parse_ranges(input: &str) -> Result<Vec<u32>, PdfJoinError> {
 ///
 /// // Check if PDF has signatures
 /// let pdf_bytes: &[u8] = b"%PDF-1.4..."; // Your PDF bytes here
-/// match has_signatures(&pdf_bytes) {
+/// match has_signatures(&pdf_bytes, false) {
 ///     Ok(true) => println!("PDF is signed"),
 ///     Ok(false) => println!("PDF is not signed"),
 ///     Err(e) => eprintln!("Error: {}", e),
 /// }
 /// ```
-pub fn has_signatures(bytes: &[u8]) -> Result<bool, PdfJoinError> {
-    let doc =
-        lopdf::Document::load_mem(bytes).map_err(|e| PdfJoinError::ParseError(e.to_string()))?;
+pub fn has_signatures(bytes: &[u8], lenient: bool) -> Result<bool, PdfJoinError> {
+    let doc = repair::load_document(bytes, lenient)?;
 
     // Check 1: Look for /AcroForm with /SigFlags in the catalog
     if let Ok(catalog) = doc.catalog() {
@@ -313,7 +326,7 @@ mod signature_tests {
     #[test]
     fn test_unsigned_pdf_returns_false() {
         let pdf = create_minimal_unsigned_pdf();
-        let result = has_signatures(&pdf);
+        let result = has_signatures(&pdf, false);
         if let Err(e) = &result {
             eprintln!("Error parsing unsigned PDF: {:?}", e);
         }
@@ -328,7 +341,7 @@ mod signature_tests {
     #[test]
     fn test_pdf_with_sigflags_returns_true() {
         let pdf = create_pdf_with_sigflags();
-        let result = has_signatures(&pdf);
+        let result = has_signatures(&pdf, false);
         assert!(
             result.is_ok(),
             "Should successfully parse PDF with SigFlags"
@@ -339,7 +352,7 @@ mod signature_tests {
     #[test]
     fn test_pdf_with_sig_object_returns_true() {
         let pdf = create_pdf_with_sig_object();
-        let result = has_signatures(&pdf);
+        let result = has_signatures(&pdf, false);
         assert!(
             result.is_ok(),
             "Should successfully parse PDF with /Type /Sig"
@@ -349,13 +362,13 @@ mod signature_tests {
 
     #[test]
     fn test_invalid_pdf_returns_error() {
-        let result = has_signatures(b"not a pdf");
+        let result = has_signatures(b"not a pdf", false);
         assert!(result.is_err(), "Invalid PDF should return error");
     }
 
     #[test]
     fn test_empty_bytes_returns_error() {
-        let result = has_signatures(&[]);
+        let result = has_signatures(&[], false);
         assert!(result.is_err(), "Empty bytes should return error");
     }
 }
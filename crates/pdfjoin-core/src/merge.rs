@@ -2,7 +2,11 @@
 //!
 //! Combines multiple PDFs into a single document.
 
+use crate::attachments::{
+    embedded_file_entries, set_embedded_file_entries, unique_attachment_name,
+};
 use crate::error::PdfJoinError;
+use crate::repair::{load_document, repair_pdf};
 use lopdf::{Document, Object, ObjectId};
 use std::collections::BTreeMap;
 
@@ -16,22 +20,37 @@ use std::collections::BTreeMap;
 ///    a. Calculate ID offset to avoid conflicts
 ///    b. Import all objects with remapped IDs
 ///    c. Append pages to the destination
+///    d. Carry over its `/EmbeddedFiles` entries
 /// 5. Compress and return the merged result
-pub fn merge_documents(documents: Vec<Vec<u8>>) -> Result<Vec<u8>, PdfJoinError> {
+///
+/// Page-level `FileAttachment` annotations travel with their pages. Document-level
+/// attachments from every input are combined into the output's name tree;
+/// duplicate names get a numeric suffix so no attachment is lost.
+///
+/// With `lenient`, damaged inputs are recovered (see [`crate::repair`]) rather
+/// than rejected.
+pub fn merge_documents(documents: Vec<Vec<u8>>, lenient: bool) -> Result<Vec<u8>, PdfJoinError> {
     if documents.is_empty() {
         return Err(PdfJoinError::OperationError("No documents to merge".into()));
     }
 
-    // Single document - return as-is
+    // Single document - return as-is (repaired if needed)
     if documents.len() == 1 {
-        return Ok(documents.into_iter().next().unwrap());
+        let document = documents.into_iter().next().unwrap();
+        if lenient {
+            return repair_pdf(&document).map(|(bytes, _)| bytes);
+        }
+        return Ok(document);
     }
 
     // Load all documents first
     let mut loaded_docs = Vec::new();
     for (i, doc_bytes) in documents.iter().enumerate() {
-        let doc = Document::load_mem(doc_bytes).map_err(|e| {
-            PdfJoinError::ParseError(format!("Failed to load document {}: {}", i, e))
+        let doc = load_document(doc_bytes, lenient).map_err(|e| match e {
+            PdfJoinError::ParseError(msg) => {
+                PdfJoinError::ParseError(format!("Failed to load document {}: {}", i, msg))
+            }
+            other => other,
         })?;
         loaded_docs.push(doc);
     }
@@ -42,15 +61,24 @@ pub fn merge_documents(documents: Vec<Vec<u8>>) -> Result<Vec<u8>, PdfJoinError>
 
     // Get the destination page list
     let mut dest_page_refs = get_page_references(&dest)?;
+    let mut attachments = embedded_file_entries(&dest);
 
     // Merge each remaining document
     for source in loaded_docs.into_iter() {
         // Get source pages before we start modifying the document
         let source_pages = get_page_references(&source)?;
+        let source_attachments = embedded_file_entries(&source);
 
         // Calculate offset for object IDs to avoid conflicts
         let id_offset = dest_max_id;
 
+        for (name, filespec) in source_attachments {
+            let name = unique_attachment_name(name, |candidate| {
+                attachments.iter().any(|(n, _)| n == candidate)
+            });
+            attachments.push((name, remap_object_refs(filespec, id_offset)));
+        }
+
         // Remap all object IDs in the source document
         let mut remapped_objects = BTreeMap::new();
         for (old_id, object) in source.objects.into_iter() {
@@ -77,6 +105,10 @@ pub fn merge_documents(documents: Vec<Vec<u8>>) -> Result<Vec<u8>, PdfJoinError>
     // Update the pages array in the destination document
     update_page_tree(&mut dest, dest_page_refs)?;
 
+    if !attachments.is_empty() {
+        set_embedded_file_entries(&mut dest, attachments)?;
+    }
+
     // Update max_id
     dest.max_id = dest_max_id;
 
@@ -243,7 +275,7 @@ mod tests {
 
     #[test]
     fn test_merge_empty_fails() {
-        let result = merge_documents(vec![]);
+        let result = merge_documents(vec![], false);
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -256,7 +288,7 @@ mod tests {
         let pdf = create_test_pdf(2, "Single");
         let original_len = pdf.len();
 
-        let result = merge_documents(vec![pdf.clone()]).unwrap();
+        let result = merge_documents(vec![pdf.clone()], false).unwrap();
 
         // Should return the same document (same size approximately)
         // Verify it has 2 pages
@@ -272,7 +304,7 @@ mod tests {
         let doc_a = create_test_pdf(2, "DocA");
         let doc_b = create_test_pdf(3, "DocB");
 
-        let merged = merge_documents(vec![doc_a, doc_b]).unwrap();
+        let merged = merge_documents(vec![doc_a, doc_b], false).unwrap();
 
         // Verify the merged document has 5 pages
         let doc = Document::load_mem(&merged).unwrap();
@@ -286,7 +318,7 @@ mod tests {
             .map(|i| create_test_pdf(1, &format!("Doc{}", i)))
             .collect();
 
-        let merged = merge_documents(docs).unwrap();
+        let merged = merge_documents(docs, false).unwrap();
 
         let doc = Document::load_mem(&merged).unwrap();
         let pages = doc.get_pages();
@@ -300,7 +332,7 @@ mod tests {
         let doc2 = create_test_pdf(1, "Second");
         let doc3 = create_test_pdf(2, "Third");
 
-        let merged = merge_documents(vec![doc1, doc2, doc3]).unwrap();
+        let merged = merge_documents(vec![doc1, doc2, doc3], false).unwrap();
 
         // Verify total page count
         let doc = Document::load_mem(&merged).unwrap();
@@ -318,19 +350,68 @@ mod tests {
         let doc2 = create_test_pdf(1, "Small");
         let doc3 = create_test_pdf(5, "Medium");
 
-        let merged = merge_documents(vec![doc1, doc2, doc3]).unwrap();
+        let merged = merge_documents(vec![doc1, doc2, doc3], false).unwrap();
 
         let doc = Document::load_mem(&merged).unwrap();
         let pages = doc.get_pages();
         assert_eq!(pages.len(), 16, "Merged document should have 16 pages");
     }
 
+    #[test]
+    fn test_merge_keeps_attachments_from_all_documents() {
+        use crate::attachments::{
+            add_attachment, extract_attachment, list_attachments, EmbeddedFile,
+        };
+
+        let file = |name: &str, data: &[u8]| EmbeddedFile {
+            name: name.to_string(),
+            data: data.to_vec(),
+            mime_type: Some("text/plain".to_string()),
+            description: None,
+        };
+        let doc1 = add_attachment(
+            &create_test_pdf(1, "A"),
+            &file("notes.txt", b"first"),
+            false,
+        )
+        .unwrap();
+        let doc2 = create_test_pdf(1, "B");
+        let doc3 = add_attachment(
+            &create_test_pdf(1, "C"),
+            &file("notes.txt", b"third"),
+            false,
+        )
+        .unwrap();
+        let doc3 = add_attachment(&doc3, &file("photo.jpg", b"jpeg"), false).unwrap();
+
+        let merged = merge_documents(vec![doc1, doc2, doc3], false).unwrap();
+
+        let names: Vec<String> = list_attachments(&merged, false)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.name)
+            .collect();
+        assert_eq!(names, vec!["notes.txt", "notes.txt (2)", "photo.jpg"]);
+        assert_eq!(
+            extract_attachment(&merged, "notes.txt", false)
+                .unwrap()
+                .data,
+            b"first"
+        );
+        assert_eq!(
+            extract_attachment(&merged, "notes.txt (2)", false)
+                .unwrap()
+                .data,
+            b"third"
+        );
+    }
+
     #[test]
     fn test_merged_document_is_valid_pdf() {
         let doc1 = create_test_pdf(2, "Valid1");
         let doc2 = create_test_pdf(2, "Valid2");
 
-        let merged = merge_documents(vec![doc1, doc2]).unwrap();
+        let merged = merge_documents(vec![doc1, doc2], false).unwrap();
 
         // Should be able to load the merged document without errors
         let doc = Document::load_mem(&merged);
@@ -341,4 +422,22 @@ mod tests {
         let pages = doc.get_pages();
         assert_eq!(pages.len(), 4);
     }
+
+    #[test]
+    fn test_merge_lenient_recovers_truncated_document() {
+        let doc1 = create_test_pdf(2, "Intact");
+        let mut doc2 = create_test_pdf(3, "Truncated");
+        let xref = doc2.windows(4).rposition(|w| w == b"xref").unwrap();
+        doc2.truncate(xref);
+
+        let strict = merge_documents(vec![doc1.clone(), doc2.clone()], false);
+        assert!(strict
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to load document 1"));
+
+        let merged = merge_documents(vec![doc1, doc2], true).unwrap();
+        let doc = Document::load_mem(&merged).unwrap();
+        assert_eq!(doc.get_pages().len(), 5);
+    }
 }
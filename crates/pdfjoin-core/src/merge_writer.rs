@@ -0,0 +1,1870 @@
+//! Bounded-memory merge for very large inputs
+//!
+//! `merge_documents` and `merge_streaming` hold every input and the whole
+//! output in memory. `MergeWriter` instead reads each source through
+//! `Read + Seek` and copies its objects one at a time to an `impl Write`,
+//! renumbering references as it goes. The xref table is written by `finish`.
+//! Stream data is copied in fixed-size chunks, so peak memory is bounded by
+//! the largest object (excluding stream data) plus one xref offset per object.
+//!
+//! Sources are read in place whether they use xref tables, cross-reference
+//! streams or both (including incremental updates). Objects packed in object
+//! streams are decoded one object stream at a time and written uncompressed.
+//! Sources that are encrypted or need repair are loaded with lopdf and
+//! re-serialized first, so for those the bound is the size of that one source.
+//!
+//! Each source's root `/Pages` node becomes a kid of the merged root, so
+//! inherited attributes (`/MediaBox`, `/Resources`, `/Rotate`) survive.
+//! Document-level attachments are merged into one `/EmbeddedFiles` name
+//! tree, with " (2)", " (3)", ... appended to repeated names. Other
+//! document-level structures such as outlines and forms are not merged.
+//!
+//! ```no_run
+//! use pdfjoin_core::merge_writer::MergeWriter;
+//! use std::fs::File;
+//! use std::io::{BufReader, BufWriter};
+//!
+//! let out = BufWriter::new(File::create("merged.pdf")?);
+//! let mut writer = MergeWriter::new(out)?;
+//! for path in ["a.pdf", "b.pdf"] {
+//!     writer.add_source(BufReader::new(File::open(path)?), false)?;
+//! }
+//! writer.finish()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::attachments::unique_attachment_name;
+use crate::error::PdfJoinError;
+use crate::repair::load_document;
+use crate::streaming::{extract_int_after, find_pattern};
+use crate::text_string::{decode_text_string, encode_text_string};
+use lopdf::xref::XrefType;
+use lopdf::{Dictionary, Object, Stream};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+
+/// Object number of the merged root `/Pages` node
+const PAGES_ID: u32 = 1;
+/// Object number of the merged catalog
+const CATALOG_ID: u32 = 2;
+/// Bytes read at a time while looking for the end of an object
+const CHUNK_SIZE: usize = 8 * 1024;
+/// Largest object number of a source or of the merged document, the
+/// implementation limit of PDF 1.7 (Annex C). Keeps the xref offsets held
+/// in memory bounded whatever object numbers a source declares.
+const MAX_OBJECT_NUMBER: u32 = 8_388_607;
+
+/// Streaming PDF merger that writes objects as soon as they are renumbered
+pub struct MergeWriter<W: Write> {
+    out: W,
+    position: u64,
+    /// Offset of each written object, indexed by object number (0 = free)
+    offsets: Vec<u64>,
+    /// Renumbered root `/Pages` node of each source, in order
+    source_roots: Vec<u32>,
+    page_count: u32,
+    /// Document-level attachments of every source: unique name and
+    /// renumbered file specification
+    attachments: Vec<(String, Vec<u8>)>,
+}
+
+impl<W: Write> MergeWriter<W> {
+    /// Start a merged document, writing the header to `out`.
+    /// Wrap files in a `BufWriter`; output is written in many small pieces.
+    pub fn new(out: W) -> Result<Self, PdfJoinError> {
+        let mut writer = Self {
+            out,
+            position: 0,
+            offsets: vec![0; CATALOG_ID as usize + 1],
+            source_roots: Vec::new(),
+            page_count: 0,
+            attachments: Vec::new(),
+        };
+        writer.write(b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n")?;
+        Ok(writer)
+    }
+
+    /// Pages added so far
+    pub fn page_count(&self) -> u32 {
+        self.page_count
+    }
+
+    /// Append every page of `source`, returning the number of pages added.
+    ///
+    /// With `lenient`, a source that cannot be read in place is recovered
+    /// with the repair parser (see [`crate::repair`]) instead of rejected.
+    pub fn add_source<R: Read + Seek>(
+        &mut self,
+        mut source: R,
+        lenient: bool,
+    ) -> Result<u32, PdfJoinError> {
+        match read_xref(&mut source) {
+            Ok(Some(xref)) => return self.copy_source(&mut source, &xref),
+            Ok(None) => {}
+            Err(e) if !lenient => return Err(e),
+            Err(_) => {}
+        }
+
+        // Fall back to a full parse of this one source
+        let mut bytes = Vec::new();
+        source.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        source.read_to_end(&mut bytes).map_err(io_error)?;
+        let mut doc = load_document(&bytes, lenient)?;
+        drop(bytes);
+        // Re-serializing writes an xref entry for every number up to the
+        // largest
+        if doc.max_id > MAX_OBJECT_NUMBER {
+            return Err(PdfJoinError::ParseError(format!(
+                "Object number {} is out of range",
+                doc.max_id
+            )));
+        }
+
+        doc.objects.retain(|_, obj| {
+            let kind = obj
+                .as_stream()
+                .ok()
+                .and_then(|s| s.dict.get(b"Type").ok())
+                .and_then(|t| t.as_name().ok());
+            !matches!(kind, Some(b"ObjStm") | Some(b"XRef"))
+        });
+        doc.reference_table.cross_reference_type = XrefType::CrossReferenceTable;
+        doc.trailer.remove(b"Encrypt");
+
+        let mut plain = Vec::new();
+        doc.save_to(&mut plain)
+            .map_err(|e| PdfJoinError::OperationError(format!("Save failed: {}", e)))?;
+        drop(doc);
+
+        let mut plain = Cursor::new(plain);
+        let xref = read_xref(&mut plain)?.ok_or_else(|| {
+            PdfJoinError::ParseError("Re-serialized source has no xref table".into())
+        })?;
+        self.copy_source(&mut plain, &xref)
+    }
+
+    /// Write the page tree, catalog, xref table and trailer, returning the writer
+    pub fn finish(mut self) -> Result<W, PdfJoinError> {
+        let kids: String = self
+            .source_roots
+            .iter()
+            .map(|id| format!(" {} 0 R", id))
+            .collect();
+        self.offsets[PAGES_ID as usize] = self.position;
+        self.write(
+            format!(
+                "{} 0 obj\n<< /Type /Pages /Kids [{} ] /Count {} >>\nendobj\n",
+                PAGES_ID, kids, self.page_count
+            )
+            .as_bytes(),
+        )?;
+        self.offsets[CATALOG_ID as usize] = self.position;
+        let mut catalog = format!(
+            "{} 0 obj\n<< /Type /Catalog /Pages {} 0 R",
+            CATALOG_ID, PAGES_ID
+        )
+        .into_bytes();
+        if !self.attachments.is_empty() {
+            catalog.extend_from_slice(b" /Names << /EmbeddedFiles << /Names [");
+            catalog.extend_from_slice(&embedded_files_array(std::mem::take(&mut self.attachments)));
+            catalog.extend_from_slice(b" ] >> >>");
+        }
+        catalog.extend_from_slice(b" >>\nendobj\n");
+        self.write(&catalog)?;
+
+        let xref_offset = self.position;
+        let size = self.offsets.len();
+        self.write(format!("xref\n0 {}\n0000000000 65535 f \n", size).as_bytes())?;
+        for i in 1..size {
+            let entry = match self.offsets[i] {
+                0 => "0000000000 00000 f \n".to_string(),
+                offset => format!("{:010} 00000 n \n", offset),
+            };
+            self.write(entry.as_bytes())?;
+        }
+        self.write(
+            format!(
+                "trailer\n<< /Size {} /Root {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+                size, CATALOG_ID, xref_offset
+            )
+            .as_bytes(),
+        )?;
+
+        self.out.flush().map_err(io_error)?;
+        Ok(self.out)
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), PdfJoinError> {
+        self.out.write_all(bytes).map_err(io_error)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Copy every object of a source except its catalog
+    fn copy_source<R: Read + Seek>(
+        &mut self,
+        source: &mut R,
+        xref: &SourceXref,
+    ) -> Result<u32, PdfJoinError> {
+        let catalog = read_object(source, xref, xref.root)?;
+        let pages_root = dict_value(&catalog.head, b"/Pages")
+            .and_then(as_reference)
+            .ok_or_else(|| PdfJoinError::ParseError("No /Pages in catalog".into()))?;
+        let pages = read_object(source, xref, pages_root)?;
+        let page_count = dict_int(&pages.head, b"/Count")
+            .and_then(|count| u32::try_from(count).ok())
+            .ok_or_else(|| PdfJoinError::ParseError("No /Count in Pages".into()))?;
+        let attachments = read_embedded_files(source, xref, &catalog.head);
+
+        // Source object N becomes base + N
+        let base = self.offsets.len() as u32 - 1;
+        let max_num = xref.entries.keys().next_back().copied().unwrap_or(0);
+        let last = base
+            .checked_add(max_num)
+            .filter(|&last| last <= MAX_OBJECT_NUMBER)
+            .ok_or_else(|| {
+                PdfJoinError::ParseError(format!(
+                    "Merged document would have more than {} objects",
+                    MAX_OBJECT_NUMBER
+                ))
+            })?;
+        self.offsets.resize(last as usize + 1, 0);
+        let target = SourceTarget {
+            base,
+            size: max_num + 1,
+            pages_root,
+        };
+
+        let mut object_streams = BTreeSet::new();
+        for (&num, &location) in &xref.entries {
+            match location {
+                _ if num == xref.root => {}
+                Location::Offset(_) => {
+                    let object = read_object(source, xref, num)?;
+                    // The merged xref replaces cross-reference streams, and
+                    // object streams are unpacked below
+                    if !matches!(object_type(&object.head), Some(b"XRef" | b"ObjStm")) {
+                        self.write_object(source, xref, &target, num, &object)?;
+                    }
+                }
+                Location::Compressed { stream, .. } => {
+                    object_streams.insert(stream);
+                }
+            }
+        }
+
+        // Objects from object streams are written uncompressed, decoding one
+        // stream at a time
+        for stream in object_streams {
+            for (num, head) in read_object_stream(source, xref, stream)? {
+                let current = matches!(
+                    xref.entries.get(&num),
+                    Some(Location::Compressed { stream: s, .. }) if *s == stream
+                );
+                // Only objects the xref lists are below the target's size
+                if num == xref.root || !current || self.offsets[(base + num) as usize] != 0 {
+                    continue;
+                }
+                let object = SourceObject {
+                    head,
+                    data_start: None,
+                };
+                self.write_object(source, xref, &target, num, &object)?;
+            }
+        }
+
+        for (name, filespec) in attachments {
+            let name = unique_attachment_name(name, |candidate| {
+                self.attachments.iter().any(|(n, _)| n == candidate)
+            });
+            self.attachments
+                .push((name, renumber_refs(&filespec, &target)));
+        }
+        self.source_roots.push(base + pages_root);
+        self.page_count += page_count;
+        Ok(page_count)
+    }
+
+    /// Write one source object under its renumbered object number
+    fn write_object<R: Read + Seek>(
+        &mut self,
+        source: &mut R,
+        xref: &SourceXref,
+        target: &SourceTarget,
+        num: u32,
+        object: &SourceObject,
+    ) -> Result<(), PdfJoinError> {
+        let mut body = renumber_refs(&object.head, target);
+        if num == target.pages_root {
+            body = insert_parent(&body, PAGES_ID);
+        }
+
+        let id = target.base + num;
+        self.offsets[id as usize] = self.position;
+        self.write(format!("{} 0 obj\n", id).as_bytes())?;
+        self.write(&body)?;
+
+        if let Some(data_start) = object.data_start {
+            let length = stream_length(source, xref, &object.head)?;
+            self.write(b"\nstream\n")?;
+            source.seek(SeekFrom::Start(data_start)).map_err(io_error)?;
+            let copied =
+                io::copy(&mut source.by_ref().take(length), &mut self.out).map_err(io_error)?;
+            if copied != length {
+                return Err(PdfJoinError::ParseError(format!(
+                    "Object {}: stream shorter than /Length",
+                    num
+                )));
+            }
+            self.position += copied;
+            self.write(b"\nendstream")?;
+        }
+        self.write(b"\nendobj\n")
+    }
+}
+
+/// How the objects of the source being copied are renumbered
+struct SourceTarget {
+    /// Source object N becomes base + N
+    base: u32,
+    /// One past the source's largest object number; references at or past
+    /// it point to no object
+    size: u32,
+    /// The source's root `/Pages` node, which gains a `/Parent`
+    pages_root: u32,
+}
+
+/// Merge sources straight into `out` without holding them in memory
+pub fn merge_to_writer<R, W, I>(sources: I, out: W, lenient: bool) -> Result<W, PdfJoinError>
+where
+    R: Read + Seek,
+    W: Write,
+    I: IntoIterator<Item = R>,
+{
+    let mut writer = MergeWriter::new(out)?;
+    for source in sources {
+        writer.add_source(source, lenient)?;
+    }
+    writer.finish()
+}
+
+fn io_error(e: io::Error) -> PdfJoinError {
+    PdfJoinError::OperationError(format!("I/O error: {}", e))
+}
+
+// =============================================================================
+// Reading sources in place
+// =============================================================================
+
+/// Where a source object is stored
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Location {
+    /// Byte offset of its `N G obj` header
+    Offset(u64),
+    /// Position inside a compressed object stream
+    Compressed { stream: u32, index: u32 },
+}
+
+/// In-use objects of a source
+struct SourceXref {
+    entries: BTreeMap<u32, Location>,
+    root: u32,
+}
+
+/// One xref section: object number -> location, or `None` for a free entry
+type XrefSection = BTreeMap<u32, Option<Location>>;
+
+/// An object read up to its `endobj` or `stream` keyword
+struct SourceObject {
+    /// Everything between `obj` and `endobj`/`stream`
+    head: Vec<u8>,
+    /// Offset of the stream data, for stream objects
+    data_start: Option<u64>,
+}
+
+/// Parse the xref tables and cross-reference streams of a source, following
+/// `/Prev` links. Returns `None` when the source needs a full parse
+/// (encryption).
+fn read_xref<R: Read + Seek>(source: &mut R) -> Result<Option<SourceXref>, PdfJoinError> {
+    let len = source.seek(SeekFrom::End(0)).map_err(io_error)?;
+    let tail_len = len.min(2048);
+    source
+        .seek(SeekFrom::Start(len - tail_len))
+        .map_err(io_error)?;
+    let mut tail = vec![0; tail_len as usize];
+    source.read_exact(&mut tail).map_err(io_error)?;
+
+    let startxref = tail
+        .windows(9)
+        .rposition(|w| w == b"startxref")
+        .ok_or_else(|| PdfJoinError::ParseError("startxref not found".into()))?;
+    let mut next = extract_int_after(&tail[startxref..], b"startxref");
+
+    let mut entries = XrefSection::new();
+    let mut root = None;
+    let mut size = None;
+    let mut visited = HashSet::new();
+
+    while let Some(offset) = next {
+        if offset < 0 || offset as u64 >= len || !visited.insert(offset) {
+            return Err(PdfJoinError::ParseError(format!(
+                "Invalid xref offset {}",
+                offset
+            )));
+        }
+        source
+            .seek(SeekFrom::Start(offset as u64))
+            .map_err(io_error)?;
+        let mut tokens = Tokens::new(source);
+
+        let (mut section, trailer) = if tokens.next_token()?.as_deref() == Some(b"xref".as_slice())
+        {
+            let section = read_xref_table(&mut tokens)?;
+            let trailer = tokens.read_dictionary()?;
+            (section, trailer)
+        } else {
+            drop(tokens);
+            read_xref_stream(source, offset as u64)?
+        };
+
+        if dict_value(&trailer, b"/Encrypt").is_some() {
+            return Ok(None);
+        }
+        // A hybrid file lists its compressed objects in a stream next to the
+        // table, which usually marks them free
+        if let Some(stream_offset) = dict_int(&trailer, b"/XRefStm") {
+            let offset = u64::try_from(stream_offset)
+                .ok()
+                .filter(|&o| o < len)
+                .ok_or_else(|| {
+                    PdfJoinError::ParseError(format!("Invalid /XRefStm offset {}", stream_offset))
+                })?;
+            let (stream_section, _) = read_xref_stream(source, offset)?;
+            for (num, location) in stream_section {
+                let slot = section.entry(num).or_insert(None);
+                if slot.is_none() {
+                    *slot = location;
+                }
+            }
+        }
+        // Sections are read newest first; keep the newest entry
+        for (num, location) in section {
+            entries.entry(num).or_insert(location);
+        }
+
+        if root.is_none() {
+            root = dict_value(&trailer, b"/Root").and_then(as_reference);
+        }
+        if size.is_none() {
+            size = dict_int(&trailer, b"/Size");
+        }
+        next = dict_int(&trailer, b"/Prev");
+    }
+
+    let root = root.ok_or_else(|| PdfJoinError::ParseError("No /Root in trailer".into()))?;
+    let size = size
+        .and_then(|size| u32::try_from(size).ok())
+        .ok_or_else(|| PdfJoinError::ParseError("No /Size in trailer".into()))?;
+    let entries: BTreeMap<u32, Location> = entries
+        .into_iter()
+        .filter_map(|(num, location)| Some((num, location?)))
+        .filter(|&(num, location)| num > 0 && location != Location::Offset(0))
+        .collect();
+    // The newest trailer's /Size covers every section
+    if let Some(&num) = entries.keys().next_back() {
+        if num >= size || num > MAX_OBJECT_NUMBER {
+            return Err(PdfJoinError::ParseError(format!(
+                "Object number {} is out of range (/Size {})",
+                num, size
+            )));
+        }
+    }
+    Ok(Some(SourceXref { entries, root }))
+}
+
+/// Read the subsections of a classic xref table, up to its `trailer` keyword
+fn read_xref_table<R: Read>(tokens: &mut Tokens<'_, R>) -> Result<XrefSection, PdfJoinError> {
+    let mut section = XrefSection::new();
+    loop {
+        let token = tokens.expect_token()?;
+        if token == b"trailer" {
+            return Ok(section);
+        }
+        let start = parse_token::<u32>(&token)?;
+        let count = parse_token::<u32>(&tokens.expect_token()?)?;
+        for num in start..start.saturating_add(count) {
+            let entry_offset = parse_token::<u64>(&tokens.expect_token()?)?;
+            let _generation = tokens.expect_token()?;
+            let kind = tokens.expect_token()?;
+            section
+                .entry(num)
+                .or_insert((kind == b"n").then_some(Location::Offset(entry_offset)));
+        }
+    }
+}
+
+/// Read the cross-reference stream at `offset`, returning its entries and
+/// its dictionary, which doubles as the trailer
+fn read_xref_stream<R: Read + Seek>(
+    source: &mut R,
+    offset: u64,
+) -> Result<(XrefSection, Vec<u8>), PdfJoinError> {
+    let object = read_object_at(source, offset)?;
+    let invalid =
+        |what: &str| PdfJoinError::ParseError(format!("Cross-reference stream: {}", what));
+    let data_start = object.data_start.ok_or_else(|| invalid("no stream data"))?;
+    // Every value in a cross-reference stream dictionary is direct
+    let length = dict_int(&object.head, b"/Length")
+        .and_then(|l| u64::try_from(l).ok())
+        .ok_or_else(|| invalid("no /Length"))?;
+    let data = read_stream_data(source, &object.head, data_start, length)?;
+
+    let widths: Vec<usize> = dict_ints(&object.head, b"/W")
+        .filter(|w| w.len() == 3)
+        .map(|w| w.into_iter().map(|n| n.clamp(0, 8) as usize).collect())
+        .ok_or_else(|| invalid("no /W"))?;
+    let size = dict_int(&object.head, b"/Size").ok_or_else(|| invalid("no /Size"))?;
+    let index = dict_ints(&object.head, b"/Index").unwrap_or_else(|| vec![0, size]);
+
+    let row_len: usize = widths.iter().sum();
+    let mut rows = data.chunks_exact(row_len.max(1));
+    let mut section = XrefSection::new();
+    for pair in index.chunks_exact(2) {
+        let (Ok(start), Ok(count)) = (u32::try_from(pair[0]), u32::try_from(pair[1])) else {
+            return Err(invalid("invalid /Index"));
+        };
+        for num in start..start.saturating_add(count) {
+            let Some(row) = rows.next() else {
+                return Ok((section, object.head));
+            };
+            let (kind, rest) = row.split_at(widths[0]);
+            let (field2, field3) = rest.split_at(widths[1]);
+            // A zero-width type field means every entry is in use
+            let kind = if widths[0] == 0 { 1 } else { be_int(kind) };
+            let location = match kind {
+                1 => Some(Location::Offset(be_int(field2))),
+                2 => Some(Location::Compressed {
+                    stream: be_int(field2) as u32,
+                    index: be_int(field3) as u32,
+                }),
+                _ => None,
+            };
+            section.entry(num).or_insert(location);
+        }
+    }
+    Ok((section, object.head))
+}
+
+/// Big-endian unsigned integer of up to eight bytes
+fn be_int(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |n, &b| (n << 8) | u64::from(b))
+}
+
+/// Read an object's head, stopping before any stream data. Objects inside
+/// object streams are returned as their plain body.
+fn read_object<R: Read + Seek>(
+    source: &mut R,
+    xref: &SourceXref,
+    num: u32,
+) -> Result<SourceObject, PdfJoinError> {
+    match xref.entries.get(&num) {
+        Some(Location::Offset(offset)) => read_object_at(source, *offset),
+        Some(Location::Compressed { stream, index }) => {
+            let objects = read_object_stream(source, xref, *stream)?;
+            let body = match objects.get(*index as usize) {
+                Some((n, _)) if *n == num => objects.into_iter().nth(*index as usize),
+                _ => objects.into_iter().find(|(n, _)| *n == num),
+            };
+            body.map(|(_, head)| SourceObject {
+                head,
+                data_start: None,
+            })
+            .ok_or_else(|| {
+                PdfJoinError::ParseError(format!("Object {} not in object stream {}", num, stream))
+            })
+        }
+        None => Err(PdfJoinError::ParseError(format!(
+            "Object {} not found",
+            num
+        ))),
+    }
+}
+
+/// Read the object whose `N G obj` header is at `offset`
+fn read_object_at<R: Read + Seek>(
+    source: &mut R,
+    offset: u64,
+) -> Result<SourceObject, PdfJoinError> {
+    source.seek(SeekFrom::Start(offset)).map_err(io_error)?;
+
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; CHUNK_SIZE];
+    let mut scanned: usize = 0;
+    loop {
+        let read = source.read(&mut chunk).map_err(io_error)?;
+        buf.extend_from_slice(&chunk[..read]);
+
+        let from = scanned.saturating_sub(9);
+        if let Some(end) = find_object_end(&buf[from..], read == 0) {
+            let head_start = find_pattern(&buf, b"obj")
+                .map(|p| p + 3)
+                .filter(|&p| p <= from + end.head_end)
+                .ok_or_else(|| {
+                    PdfJoinError::ParseError(format!(
+                        "Object at offset {}: missing obj keyword",
+                        offset
+                    ))
+                })?;
+            let head = buf[head_start..from + end.head_end].trim_ascii().to_vec();
+            let data_start = end.data_start.map(|d| offset + (from + d) as u64);
+            return Ok(SourceObject { head, data_start });
+        }
+        if read == 0 {
+            return Err(PdfJoinError::ParseError(format!(
+                "Object at offset {}: endobj not found",
+                offset
+            )));
+        }
+        scanned = buf.len();
+    }
+}
+
+/// Bodies of the objects in an object stream, in stream order
+fn read_object_stream<R: Read + Seek>(
+    source: &mut R,
+    xref: &SourceXref,
+    stream: u32,
+) -> Result<Vec<(u32, Vec<u8>)>, PdfJoinError> {
+    let invalid =
+        |what: &str| PdfJoinError::ParseError(format!("Object stream {}: {}", stream, what));
+    let Some(Location::Offset(offset)) = xref.entries.get(&stream) else {
+        return Err(invalid("not found"));
+    };
+    let object = read_object_at(source, *offset)?;
+    let data_start = object.data_start.ok_or_else(|| invalid("no stream data"))?;
+    let length = stream_length(source, xref, &object.head)?;
+    let data = read_stream_data(source, &object.head, data_start, length)?;
+
+    let first = dict_int(&object.head, b"/First")
+        .and_then(|f| usize::try_from(f).ok())
+        .filter(|&f| f <= data.len())
+        .ok_or_else(|| invalid("invalid /First"))?;
+    let header: Vec<usize> = data[..first]
+        .split(|b| b.is_ascii_whitespace())
+        .filter(|t| !t.is_empty())
+        .map(parse_token::<usize>)
+        .collect::<Result<_, _>>()?;
+
+    let pairs: Vec<(u32, usize)> = header
+        .chunks_exact(2)
+        .map(|pair| {
+            (
+                pair[0] as u32,
+                first.saturating_add(pair[1]).min(data.len()),
+            )
+        })
+        .collect();
+    Ok(pairs
+        .iter()
+        .enumerate()
+        .map(|(i, &(num, start))| {
+            let end = pairs
+                .get(i + 1)
+                .map_or(data.len(), |&(_, next)| next)
+                .max(start);
+            (num, data[start..end].trim_ascii().to_vec())
+        })
+        .collect())
+}
+
+/// Read `length` bytes of stream data and undo its filters
+fn read_stream_data<R: Read + Seek>(
+    source: &mut R,
+    head: &[u8],
+    data_start: u64,
+    length: u64,
+) -> Result<Vec<u8>, PdfJoinError> {
+    source.seek(SeekFrom::Start(data_start)).map_err(io_error)?;
+    // Read through `take` so a bogus /Length cannot force a huge allocation
+    let mut raw = Vec::new();
+    source
+        .by_ref()
+        .take(length)
+        .read_to_end(&mut raw)
+        .map_err(io_error)?;
+
+    let filters: Vec<Object> = match dict_value(head, b"/Filter") {
+        Some(value) if value.starts_with(b"[") => array_items(value)
+            .into_iter()
+            .filter_map(|name| name.strip_prefix(b"/"))
+            .map(|name| Object::Name(name.to_vec()))
+            .collect(),
+        Some(value) => value
+            .strip_prefix(b"/")
+            .map(|name| vec![Object::Name(name.to_vec())])
+            .unwrap_or_default(),
+        None => vec![],
+    };
+    if filters.is_empty() {
+        return Ok(raw);
+    }
+
+    let mut dict = Dictionary::new();
+    dict.set("Filter", Object::Array(filters));
+    if let Some(params) = dict_value(head, b"/DecodeParms") {
+        let mut decode_parms = Dictionary::new();
+        for key in ["Predictor", "Columns", "Colors", "BitsPerComponent"] {
+            if let Some(value) = dict_int(params, format!("/{}", key).as_bytes()) {
+                decode_parms.set(key, value);
+            }
+        }
+        dict.set("DecodeParms", decode_parms);
+    }
+    Stream::new(dict, raw)
+        .decompressed_content()
+        .map_err(|e| PdfJoinError::ParseError(format!("Cannot decode stream: {}", e)))
+}
+
+/// Entries of a single-leaf `/EmbeddedFiles` name tree, sorted by the bytes
+/// of their encoded keys
+fn embedded_files_array(attachments: Vec<(String, Vec<u8>)>) -> Vec<u8> {
+    let mut keyed: Vec<(Vec<u8>, Vec<u8>)> = attachments
+        .into_iter()
+        .map(|(name, filespec)| {
+            let key = match encode_text_string(&name) {
+                Object::String(bytes, _) => bytes,
+                _ => name.into_bytes(),
+            };
+            (key, filespec)
+        })
+        .collect();
+    keyed.sort();
+
+    let mut out = Vec::new();
+    for (key, filespec) in keyed {
+        out.push(b' ');
+        out.push(b'<');
+        for b in key {
+            out.extend_from_slice(format!("{:02X}", b).as_bytes());
+        }
+        out.extend_from_slice(b"> ");
+        out.extend_from_slice(&filespec);
+    }
+    out
+}
+
+/// Document-level attachments of a source, as (name, file specification)
+/// pairs in source numbering. A malformed name tree yields what could be read.
+fn read_embedded_files<R: Read + Seek>(
+    source: &mut R,
+    xref: &SourceXref,
+    catalog: &[u8],
+) -> Vec<(String, Vec<u8>)> {
+    let mut entries = Vec::new();
+    let names = dict_value(catalog, b"/Names").and_then(|n| resolve(source, xref, n));
+    if let Some(tree) = names
+        .as_deref()
+        .and_then(|n| dict_value(n, b"/EmbeddedFiles"))
+    {
+        collect_name_tree(source, xref, tree, &mut entries, 0);
+    }
+    entries
+}
+
+fn collect_name_tree<R: Read + Seek>(
+    source: &mut R,
+    xref: &SourceXref,
+    node: &[u8],
+    entries: &mut Vec<(String, Vec<u8>)>,
+    depth: usize,
+) {
+    // Guard against reference cycles in malformed trees
+    if depth > 32 {
+        return;
+    }
+    let Some(node) = resolve(source, xref, node) else {
+        return;
+    };
+
+    if let Some(names) = dict_value(&node, b"/Names") {
+        for pair in array_items(names).chunks_exact(2) {
+            if let Some(name) = string_bytes(pair[0]) {
+                entries.push((decode_text_string(&name), pair[1].to_vec()));
+            }
+        }
+    }
+    if let Some(kids) = dict_value(&node, b"/Kids") {
+        for kid in array_items(kids) {
+            collect_name_tree(source, xref, kid, entries, depth + 1);
+        }
+    }
+}
+
+/// The object a value refers to, or the value itself when it is direct
+fn resolve<R: Read + Seek>(source: &mut R, xref: &SourceXref, value: &[u8]) -> Option<Vec<u8>> {
+    match as_reference(value) {
+        Some(num) => read_object(source, xref, num)
+            .ok()
+            .map(|object| object.head),
+        None => Some(value.to_vec()),
+    }
+}
+
+/// `/Type` of an object's dictionary
+fn object_type(head: &[u8]) -> Option<&[u8]> {
+    dict_value(head, b"/Type")?.strip_prefix(b"/")
+}
+
+struct ObjectEnd {
+    head_end: usize,
+    data_start: Option<usize>,
+}
+
+/// Find `endobj` or a `stream` keyword followed by an end-of-line
+fn find_object_end(bytes: &[u8], at_eof: bool) -> Option<ObjectEnd> {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"endobj") {
+            return Some(ObjectEnd {
+                head_end: i,
+                data_start: None,
+            });
+        }
+        if bytes[i..].starts_with(b"stream") && !(i >= 3 && &bytes[i - 3..i] == b"end") {
+            match bytes.get(i + 6) {
+                Some(b'\n') => {
+                    return Some(ObjectEnd {
+                        head_end: i,
+                        data_start: Some(i + 7),
+                    })
+                }
+                Some(b'\r') => {
+                    let eol = if bytes.get(i + 7) == Some(&b'\n') {
+                        2
+                    } else if bytes.len() > i + 7 || at_eof {
+                        1
+                    } else {
+                        // Need one more byte to tell \r from \r\n
+                        return None;
+                    };
+                    return Some(ObjectEnd {
+                        head_end: i,
+                        data_start: Some(i + 6 + eol),
+                    });
+                }
+                None if !at_eof => return None,
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Resolve a stream's `/Length`, which may be an indirect object
+fn stream_length<R: Read + Seek>(
+    source: &mut R,
+    xref: &SourceXref,
+    head: &[u8],
+) -> Result<u64, PdfJoinError> {
+    let value = dict_value(head, b"/Length")
+        .ok_or_else(|| PdfJoinError::ParseError("Stream without /Length".into()))?;
+    let length = match as_reference(value) {
+        Some(num) => parse_token::<i64>(&read_object(source, xref, num)?.head)?,
+        None => parse_token::<i64>(value)?,
+    };
+    u64::try_from(length).map_err(|_| PdfJoinError::ParseError("Negative /Length".into()))
+}
+
+fn parse_token<T: std::str::FromStr>(token: &[u8]) -> Result<T, PdfJoinError> {
+    std::str::from_utf8(token)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| {
+            PdfJoinError::ParseError(format!(
+                "Unexpected token in xref: {}",
+                String::from_utf8_lossy(token)
+            ))
+        })
+}
+
+/// Whitespace-separated tokens from a reader, one byte at a time through a buffer
+struct Tokens<'a, R: Read> {
+    reader: io::BufReader<&'a mut R>,
+}
+
+impl<'a, R: Read> Tokens<'a, R> {
+    fn new(reader: &'a mut R) -> Self {
+        Self {
+            reader: io::BufReader::new(reader),
+        }
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>, PdfJoinError> {
+        let mut byte = [0u8; 1];
+        match self.reader.read(&mut byte).map_err(io_error)? {
+            0 => Ok(None),
+            _ => Ok(Some(byte[0])),
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Vec<u8>>, PdfJoinError> {
+        let mut token = Vec::new();
+        while let Some(b) = self.next_byte()? {
+            if b.is_ascii_whitespace() {
+                if !token.is_empty() {
+                    break;
+                }
+            } else {
+                token.push(b);
+                // "trailer<<" is legal; stop before the dictionary
+                if token == b"trailer" {
+                    break;
+                }
+            }
+        }
+        Ok((!token.is_empty()).then_some(token))
+    }
+
+    fn expect_token(&mut self) -> Result<Vec<u8>, PdfJoinError> {
+        self.next_token()?
+            .ok_or_else(|| PdfJoinError::ParseError("Unexpected end of xref".into()))
+    }
+
+    /// Read a `<< ... >>` dictionary, including nested dictionaries
+    fn read_dictionary(&mut self) -> Result<Vec<u8>, PdfJoinError> {
+        let mut dict = Vec::new();
+        let mut depth = 0usize;
+        let mut prev = 0u8;
+        while let Some(b) = self.next_byte()? {
+            if depth == 0 && b != b'<' {
+                continue;
+            }
+            dict.push(b);
+            if b == b'<' && prev == b'<' {
+                depth += 1;
+                prev = 0;
+                continue;
+            }
+            if b == b'>' && prev == b'>' {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(dict);
+                }
+                prev = 0;
+                continue;
+            }
+            prev = b;
+        }
+        Err(PdfJoinError::ParseError("Unterminated trailer".into()))
+    }
+}
+
+// =============================================================================
+// Parsing object syntax
+// =============================================================================
+
+/// Containers nested deeper than this are treated as malformed
+const MAX_NESTING: usize = 64;
+
+/// Skip whitespace and comments
+fn skip_space(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() {
+        match bytes[i] {
+            b if b.is_ascii_whitespace() || b == 0 => i += 1,
+            b'%' => {
+                while i < bytes.len() && bytes[i] != b'\n' && bytes[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            _ => break,
+        }
+    }
+    i
+}
+
+/// Span of the object starting at or after `from`: a string, name, number,
+/// reference, keyword, array or dictionary
+fn next_item(bytes: &[u8], from: usize) -> Option<(usize, usize)> {
+    next_item_nested(bytes, from, 0)
+}
+
+fn next_item_nested(bytes: &[u8], from: usize, depth: usize) -> Option<(usize, usize)> {
+    let start = skip_space(bytes, from);
+    let end = match *bytes.get(start)? {
+        b'(' => literal_string_end(bytes, start),
+        b'<' if bytes.get(start + 1) == Some(&b'<') => {
+            container_end(bytes, start + 2, b">>", depth)?
+        }
+        b'<' => find_pattern(&bytes[start..], b">").map_or(bytes.len(), |p| start + p + 1),
+        b'[' => container_end(bytes, start + 1, b"]", depth)?,
+        b'/' => regular_end(bytes, start + 1),
+        b if b.is_ascii_digit() => {
+            parse_reference(bytes, start).map_or_else(|| regular_end(bytes, start), |r| r.1)
+        }
+        b if is_regular(b) => regular_end(bytes, start),
+        // A stray closing delimiter
+        _ => start + 1,
+    };
+    Some((start, end))
+}
+
+/// End of an array or dictionary whose items start at `from`
+fn container_end(bytes: &[u8], mut from: usize, close: &[u8], depth: usize) -> Option<usize> {
+    if depth >= MAX_NESTING {
+        return None;
+    }
+    loop {
+        from = skip_space(bytes, from);
+        if bytes[from..].starts_with(close) {
+            return Some(from + close.len());
+        }
+        from = next_item_nested(bytes, from, depth + 1)?.1;
+    }
+}
+
+fn regular_end(bytes: &[u8], from: usize) -> usize {
+    bytes[from..]
+        .iter()
+        .position(|&b| !is_regular(b))
+        .map_or(bytes.len(), |p| from + p)
+}
+
+/// Value of `key` (including its slash) in a `<< ... >>` dictionary
+fn dict_value<'a>(dict: &'a [u8], key: &[u8]) -> Option<&'a [u8]> {
+    let mut i = skip_space(dict, 0);
+    if !dict[i..].starts_with(b"<<") {
+        return None;
+    }
+    i += 2;
+    loop {
+        i = skip_space(dict, i);
+        if dict[i..].starts_with(b">>") {
+            return None;
+        }
+        let (key_start, key_end) = next_item(dict, i)?;
+        let (value_start, value_end) = next_item(dict, key_end)?;
+        if &dict[key_start..key_end] == key {
+            return Some(&dict[value_start..value_end]);
+        }
+        i = value_end;
+    }
+}
+
+/// Items of a `[ ... ]` array
+fn array_items(array: &[u8]) -> Vec<&[u8]> {
+    let mut items = Vec::new();
+    let Some(mut i) = array.strip_prefix(b"[").map(|_| 1) else {
+        return items;
+    };
+    while let Some((start, end)) = next_item(array, i) {
+        if array[start] == b']' {
+            break;
+        }
+        items.push(&array[start..end]);
+        i = end;
+    }
+    items
+}
+
+/// Direct integer value of `key`
+fn dict_int(dict: &[u8], key: &[u8]) -> Option<i64> {
+    parse_token(dict_value(dict, key)?).ok()
+}
+
+/// Direct array of integers under `key`
+fn dict_ints(dict: &[u8], key: &[u8]) -> Option<Vec<i64>> {
+    array_items(dict_value(dict, key)?)
+        .into_iter()
+        .map(|item| parse_token(item).ok())
+        .collect()
+}
+
+/// Object number of an `N G R` value
+fn as_reference(value: &[u8]) -> Option<u32> {
+    parse_reference(value, 0)
+        .filter(|&(_, end)| end == value.len())
+        .map(|(num, _)| num)
+}
+
+/// Bytes of a literal `( ... )` or hex `< ... >` string token
+fn string_bytes(token: &[u8]) -> Option<Vec<u8>> {
+    if let Some(hex) = token.strip_prefix(b"<") {
+        let digits: Vec<u8> = hex
+            .strip_suffix(b">")?
+            .iter()
+            .filter(|b| !b.is_ascii_whitespace())
+            .map(|&b| (b as char).to_digit(16).map(|d| d as u8))
+            .collect::<Option<_>>()?;
+        // An odd final digit is followed by an implied zero
+        return Some(
+            digits
+                .chunks(2)
+                .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0))
+                .collect(),
+        );
+    }
+
+    let literal = token.strip_prefix(b"(")?.strip_suffix(b")")?;
+    let mut out = Vec::with_capacity(literal.len());
+    let mut i = 0;
+    while i < literal.len() {
+        let b = literal[i];
+        i += 1;
+        if b != b'\\' {
+            out.push(b);
+            continue;
+        }
+        let Some(&escaped) = literal.get(i) else {
+            break;
+        };
+        i += 1;
+        match escaped {
+            b'n' => out.push(b'\n'),
+            b'r' => out.push(b'\r'),
+            b't' => out.push(b'\t'),
+            b'b' => out.push(0x08),
+            b'f' => out.push(0x0c),
+            b'0'..=b'7' => {
+                let mut value = u32::from(escaped - b'0');
+                for _ in 0..2 {
+                    match literal.get(i) {
+                        Some(&d @ b'0'..=b'7') => {
+                            value = value * 8 + u32::from(d - b'0');
+                            i += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                out.push(value as u8);
+            }
+            // A backslash before an end-of-line continues the string
+            b'\r' => {
+                if literal.get(i) == Some(&b'\n') {
+                    i += 1;
+                }
+            }
+            b'\n' => {}
+            other => out.push(other),
+        }
+    }
+    Some(out)
+}
+
+// =============================================================================
+// Rewriting object bodies
+// =============================================================================
+
+fn is_delimiter(b: u8) -> bool {
+    matches!(
+        b,
+        b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%'
+    )
+}
+
+fn is_regular(b: u8) -> bool {
+    !b.is_ascii_whitespace() && !is_delimiter(b)
+}
+
+/// Add the target's base to every `N G R` reference, skipping strings, names
+/// and comments. Generations are reset to 0, matching the renumbered `N 0 obj`
+/// headers. References to objects the source doesn't have become `null`, which
+/// is what they resolve to.
+fn renumber_refs(bytes: &[u8], target: &SourceTarget) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len() + 16);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' => {
+                let end = literal_string_end(bytes, i);
+                out.extend_from_slice(&bytes[i..end]);
+                i = end;
+            }
+            b'<' if bytes.get(i + 1) == Some(&b'<') => {
+                out.extend_from_slice(b"<<");
+                i += 2;
+            }
+            b'<' => {
+                let end = find_pattern(&bytes[i..], b">").map_or(bytes.len(), |p| i + p + 1);
+                out.extend_from_slice(&bytes[i..end]);
+                i = end;
+            }
+            b'%' => {
+                let end = bytes[i..]
+                    .iter()
+                    .position(|&b| b == b'\n' || b == b'\r')
+                    .map_or(bytes.len(), |p| i + p);
+                out.extend_from_slice(&bytes[i..end]);
+                i = end;
+            }
+            b'/' => {
+                let end = bytes[i + 1..]
+                    .iter()
+                    .position(|&b| !is_regular(b))
+                    .map_or(bytes.len(), |p| i + 1 + p);
+                out.extend_from_slice(&bytes[i..end]);
+                i = end;
+            }
+            b if b.is_ascii_digit() && (i == 0 || !is_regular(bytes[i - 1])) => {
+                match parse_reference(bytes, i) {
+                    Some((num, end)) => {
+                        if num < target.size {
+                            out.extend_from_slice(format!("{} 0 R", num + target.base).as_bytes());
+                        } else {
+                            out.extend_from_slice(b"null");
+                        }
+                        i = end;
+                    }
+                    None => {
+                        let end = bytes[i..]
+                            .iter()
+                            .position(|&b| !is_regular(b))
+                            .map_or(bytes.len(), |p| i + p);
+                        out.extend_from_slice(&bytes[i..end]);
+                        i = end;
+                    }
+                }
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+/// Parse `N G R` at `start`, returning the object number and end offset
+fn parse_reference(bytes: &[u8], start: usize) -> Option<(u32, usize)> {
+    let digits = |from: usize| {
+        let len = bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        (len > 0).then_some(from + len)
+    };
+    let spaces = |from: usize| {
+        let len = bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_whitespace())
+            .count();
+        (len > 0).then_some(from + len)
+    };
+
+    let num_end = digits(start)?;
+    let gen_start = spaces(num_end)?;
+    let gen_end = digits(gen_start)?;
+    let r = spaces(gen_end)?;
+    if bytes.get(r) != Some(&b'R') || bytes.get(r + 1).is_some_and(|&b| is_regular(b)) {
+        return None;
+    }
+    let num = std::str::from_utf8(&bytes[start..num_end])
+        .ok()?
+        .parse()
+        .ok()?;
+    Some((num, r + 1))
+}
+
+/// End offset (exclusive) of a literal string starting at `start`
+fn literal_string_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Point a source's root `/Pages` node at the merged root
+fn insert_parent(body: &[u8], parent: u32) -> Vec<u8> {
+    let Some(pos) = find_pattern(body, b"<<") else {
+        return body.to_vec();
+    };
+    let mut out = body[..pos + 2].to_vec();
+    out.extend_from_slice(format!(" /Parent {} 0 R", parent).as_bytes());
+    out.extend_from_slice(&body[pos + 2..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use lopdf::content::{Content, Operation};
+    use lopdf::{dictionary, Document, Object, Stream};
+
+    /// PDF whose pages inherit `/MediaBox` from the page tree root
+    fn create_test_pdf(num_pages: u32, label: &str) -> Document {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let mut kids = Vec::new();
+        for i in 0..num_pages {
+            let content = Content {
+                operations: vec![
+                    Operation::new("BT", vec![]),
+                    Operation::new("Td", vec![72.into(), 700.into()]),
+                    Operation::new(
+                        "Tj",
+                        vec![Object::string_literal(format!("{} {}", label, i + 1))],
+                    ),
+                    Operation::new("ET", vec![]),
+                ],
+            };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            });
+            kids.push(page_id.into());
+        }
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => num_pages,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc
+    }
+
+    /// Save with a classic xref table so the source is read in place
+    fn save(doc: &mut Document) -> Vec<u8> {
+        doc.reference_table.cross_reference_type = XrefType::CrossReferenceTable;
+        let mut buffer = Vec::new();
+        doc.save_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    fn merge(sources: Vec<Vec<u8>>) -> Document {
+        let out = merge_to_writer(sources.into_iter().map(Cursor::new), Vec::new(), false).unwrap();
+        Document::load_mem(&out).unwrap()
+    }
+
+    fn page_text(doc: &Document, page: u32) -> String {
+        let page_id = *doc.get_pages().get(&page).unwrap();
+        String::from_utf8_lossy(&doc.get_page_content(page_id).unwrap()).into_owned()
+    }
+
+    #[test]
+    fn test_merge_keeps_pages_in_order() {
+        let a = save(&mut create_test_pdf(2, "A"));
+        let b = save(&mut create_test_pdf(3, "B"));
+        let doc = merge(vec![a, b]);
+
+        assert_eq!(doc.get_pages().len(), 5);
+        assert!(page_text(&doc, 1).contains("(A 1)"));
+        assert!(page_text(&doc, 3).contains("(B 1)"));
+        assert!(page_text(&doc, 5).contains("(B 3)"));
+    }
+
+    #[test]
+    fn test_inherited_attributes_survive() {
+        let doc = merge(vec![save(&mut create_test_pdf(1, "A"))]);
+        let page_id = *doc.get_pages().get(&1).unwrap();
+        let page = doc.get_dictionary(page_id).unwrap();
+        assert!(!page.has(b"MediaBox"));
+
+        let parent = page.get(b"Parent").unwrap().as_reference().unwrap();
+        let media_box = doc
+            .get_dictionary(parent)
+            .unwrap()
+            .get(b"MediaBox")
+            .unwrap()
+            .as_array()
+            .unwrap();
+        assert_eq!(media_box[2].as_i64().unwrap(), 595);
+    }
+
+    /// Serialize numbered object bodies with a classic xref table
+    fn raw_pdf(objects: &[&str], root: u32) -> Vec<u8> {
+        let mut bytes = b"%PDF-1.7\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, body) in objects.iter().enumerate() {
+            offsets.push(bytes.len());
+            bytes.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, body).as_bytes());
+        }
+        let xref = bytes.len();
+        bytes.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+        );
+        for offset in offsets {
+            bytes.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        bytes.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                root,
+                xref
+            )
+            .as_bytes(),
+        );
+        bytes
+    }
+
+    #[test]
+    fn test_indirect_stream_length() {
+        let data = "BT 72 700 Td (Indirect) Tj ET";
+        let bytes = raw_pdf(
+            &[
+                "<< /Type /Catalog /Pages 2 0 R >>",
+                "<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] >>",
+                "<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+                &format!("<< /Length 5 0 R >>\nstream\n{}\nendstream", data),
+                &data.len().to_string(),
+            ],
+            1,
+        );
+
+        let doc = merge(vec![bytes]);
+        assert!(page_text(&doc, 1).contains("(Indirect)"));
+    }
+
+    #[test]
+    fn test_incremental_update_uses_newest_objects() {
+        let mut doc = create_test_pdf(1, "A");
+        let mut bytes = save(&mut doc);
+        let page_id = *doc.get_pages().get(&1).unwrap();
+        let content_id = doc
+            .get_dictionary(page_id)
+            .unwrap()
+            .get(b"Contents")
+            .unwrap()
+            .as_reference()
+            .unwrap();
+        let prev = extract_int_after(&bytes[bytes.len() - 40..], b"startxref").unwrap();
+
+        let object_offset = bytes.len();
+        bytes.extend_from_slice(
+            format!(
+                "{} 0 obj\n<< /Type /Page /Parent {} 0 R /Contents {} 0 R /Rotate 90 >>\nendobj\n",
+                page_id.0, 1, content_id.0
+            )
+            .as_bytes(),
+        );
+        let xref_offset = bytes.len();
+        bytes.extend_from_slice(
+            format!(
+                "xref\n{} 1\n{:010} 00000 n \ntrailer\n<< /Size {} /Root {} 0 R /Prev {} >>\nstartxref\n{}\n%%EOF\n",
+                page_id.0,
+                object_offset,
+                doc.max_id + 1,
+                doc.trailer.get(b"Root").unwrap().as_reference().unwrap().0,
+                prev,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+
+        let merged = merge(vec![bytes]);
+        let page_id = *merged.get_pages().get(&1).unwrap();
+        let rotate = merged.get_dictionary(page_id).unwrap().get(b"Rotate");
+        assert_eq!(rotate.unwrap().as_i64().unwrap(), 90);
+    }
+
+    #[test]
+    fn test_xref_stream_source_is_read_in_place() {
+        let mut doc = create_test_pdf(2, "X");
+        doc.reference_table.cross_reference_type = XrefType::CrossReferenceStream;
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        let xref = read_xref(&mut Cursor::new(&bytes)).unwrap().unwrap();
+        assert_eq!(
+            xref.root,
+            doc.trailer.get(b"Root").unwrap().as_reference().unwrap().0
+        );
+
+        let merged = merge(vec![save(&mut create_test_pdf(1, "A")), bytes]);
+        assert_eq!(merged.get_pages().len(), 3);
+        assert!(page_text(&merged, 3).contains("(X 2)"));
+    }
+
+    fn deflate(data: Vec<u8>) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// Serialize numbered object bodies, packing the objects in `packed` into
+    /// a compressed object stream. The cross-reference stream is compressed
+    /// with a PNG predictor, or with `hybrid` left plain and referenced by
+    /// `/XRefStm` from a classic table that lists only the unpacked objects.
+    fn packed_pdf(objects: &[&str], packed: &[u32], hybrid: bool) -> Vec<u8> {
+        let object_stream = objects.len() + 1;
+        let xref_stream = objects.len() + 2;
+        // (type, offset or object stream, index) per object number
+        let mut entries = vec![(0u8, 0u32, 0u16); xref_stream + 1];
+
+        let mut bytes = b"%PDF-1.7\n".to_vec();
+        for (i, body) in objects.iter().enumerate() {
+            if !packed.contains(&(i as u32 + 1)) {
+                entries[i + 1] = (1, bytes.len() as u32, 0);
+                bytes.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, body).as_bytes());
+            }
+        }
+
+        let (mut header, mut body) = (String::new(), String::new());
+        for (index, &num) in packed.iter().enumerate() {
+            header.push_str(&format!("{} {} ", num, body.len()));
+            body.push_str(objects[num as usize - 1]);
+            body.push('\n');
+            entries[num as usize] = (2, object_stream as u32, index as u16);
+        }
+        let data = deflate(format!("{}{}", header, body).into_bytes());
+        entries[object_stream] = (1, bytes.len() as u32, 0);
+        bytes.extend_from_slice(
+            format!(
+                "{} 0 obj\n<< /Type /ObjStm /N {} /First {} /Filter /FlateDecode /Length {} >>\nstream\n",
+                object_stream,
+                packed.len(),
+                header.len(),
+                data.len()
+            )
+            .as_bytes(),
+        );
+        bytes.extend_from_slice(&data);
+        bytes.extend_from_slice(b"\nendstream\nendobj\n");
+
+        let xref_offset = bytes.len();
+        entries[xref_stream] = (1, xref_offset as u32, 0);
+        let rows: Vec<Vec<u8>> = entries
+            .iter()
+            .map(|&(kind, field2, field3)| {
+                let mut row = vec![kind];
+                row.extend_from_slice(&field2.to_be_bytes());
+                row.extend_from_slice(&field3.to_be_bytes());
+                row
+            })
+            .collect();
+        let (data, filter) = if hybrid {
+            (rows.concat(), String::new())
+        } else {
+            // PNG "Up" predictor: each byte minus the one above it
+            let mut predicted = Vec::new();
+            let mut previous = vec![0u8; 7];
+            for row in rows {
+                predicted.push(2);
+                predicted.extend(row.iter().zip(&previous).map(|(b, p)| b.wrapping_sub(*p)));
+                previous = row;
+            }
+            (
+                deflate(predicted),
+                " /Filter /FlateDecode /DecodeParms << /Predictor 12 /Columns 7 >>".to_string(),
+            )
+        };
+        bytes.extend_from_slice(
+            format!(
+                "{} 0 obj\n<< /Type /XRef /Size {} /W [1 4 2] /Root 1 0 R{} /Length {} >>\nstream\n",
+                xref_stream,
+                xref_stream + 1,
+                filter,
+                data.len()
+            )
+            .as_bytes(),
+        );
+        bytes.extend_from_slice(&data);
+        bytes.extend_from_slice(b"\nendstream\nendobj\n");
+
+        let startxref = if hybrid {
+            let table_offset = bytes.len();
+            bytes.extend_from_slice(
+                format!("xref\n0 {}\n0000000000 65535 f \n", xref_stream + 1).as_bytes(),
+            );
+            for &(kind, offset, _) in &entries[1..] {
+                let entry = match kind {
+                    1 if offset as usize != xref_offset => format!("{:010} 00000 n \n", offset),
+                    _ => "0000000000 00000 f \n".to_string(),
+                };
+                bytes.extend_from_slice(entry.as_bytes());
+            }
+            bytes.extend_from_slice(
+                format!(
+                    "trailer\n<< /Size {} /Root 1 0 R /XRefStm {} >>\n",
+                    xref_stream + 1,
+                    xref_offset
+                )
+                .as_bytes(),
+            );
+            table_offset
+        } else {
+            xref_offset
+        };
+        bytes.extend_from_slice(format!("startxref\n{}\n%%EOF\n", startxref).as_bytes());
+        bytes
+    }
+
+    const PACKED_OBJECTS: [&str; 5] = [
+        "<< /Type /Catalog /Pages 2 0 R >>",
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] >>",
+        "<< /Type /Page /Parent 2 0 R /Contents 4 0 R /Resources 5 0 R >>",
+        "<< /Length 26 >>\nstream\nBT 72 700 Td (Packed) Tj ET\nendstream",
+        "<< /ProcSet [/PDF /Text] >>",
+    ];
+
+    #[test]
+    fn test_object_streams_are_unpacked() {
+        let bytes = packed_pdf(&PACKED_OBJECTS, &[1, 2, 3, 5], false);
+        let xref = read_xref(&mut Cursor::new(&bytes)).unwrap().unwrap();
+        assert_eq!(
+            xref.entries.get(&3),
+            Some(&Location::Compressed {
+                stream: 6,
+                index: 2
+            })
+        );
+
+        let merged = merge(vec![save(&mut create_test_pdf(1, "A")), bytes]);
+        assert_eq!(merged.get_pages().len(), 2);
+        assert!(page_text(&merged, 2).contains("(Packed)"));
+        let page_id = *merged.get_pages().get(&2).unwrap();
+        let resources = merged
+            .get_dictionary(page_id)
+            .unwrap()
+            .get(b"Resources")
+            .unwrap()
+            .as_reference()
+            .unwrap();
+        assert!(merged.get_dictionary(resources).unwrap().has(b"ProcSet"));
+        assert!(!merged
+            .objects
+            .values()
+            .any(|o| o.type_name().is_ok_and(|t| t == "ObjStm" || t == "XRef")));
+    }
+
+    #[test]
+    fn test_hybrid_file_uses_xref_stream_entries() {
+        let bytes = packed_pdf(&PACKED_OBJECTS, &[3, 5], true);
+        let xref = read_xref(&mut Cursor::new(&bytes)).unwrap().unwrap();
+        assert!(matches!(
+            xref.entries.get(&3),
+            Some(Location::Compressed { .. })
+        ));
+        assert!(matches!(xref.entries.get(&4), Some(Location::Offset(_))));
+
+        let merged = merge(vec![bytes]);
+        assert!(page_text(&merged, 1).contains("(Packed)"));
+    }
+
+    /// One-page PDF with document-level attachments whose file
+    /// specifications are in an object stream
+    fn pdf_with_attachments(names: &[&str]) -> Vec<u8> {
+        let mut objects: Vec<String> = [
+            "<< /Type /Catalog /Pages 2 0 R /Names << /EmbeddedFiles 5 0 R >> >>",
+            PACKED_OBJECTS[1],
+            "<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+            PACKED_OBJECTS[3],
+        ]
+        .map(String::from)
+        .to_vec();
+        let mut leaf = String::new();
+        for (i, name) in names.iter().enumerate() {
+            let filespec = 7 + i * 2;
+            leaf.push_str(&format!(" ({}) {} 0 R", name, filespec));
+        }
+        objects.push("<< /Kids [6 0 R] >>".to_string());
+        objects.push(format!("<< /Names [{} ] >>", leaf));
+        for name in names {
+            objects.push(format!(
+                "<< /Type /Filespec /F ({0}) /UF ({0}) /EF << /F {1} 0 R >> >>",
+                name,
+                objects.len() + 2
+            ));
+            let data = format!("contents of {}", name);
+            objects.push(format!(
+                "<< /Type /EmbeddedFile /Length {} >>\nstream\n{}\nendstream",
+                data.len(),
+                data
+            ));
+        }
+        let objects: Vec<&str> = objects.iter().map(String::as_str).collect();
+        let filespecs: Vec<u32> = (0..names.len()).map(|i| 7 + i as u32 * 2).collect();
+        packed_pdf(&objects, &filespecs, false)
+    }
+
+    #[test]
+    fn test_attachments_are_merged() {
+        let a = pdf_with_attachments(&["data.csv", "notes.txt"]);
+        let b = pdf_with_attachments(&["data.csv"]);
+        let out = merge_to_writer([a, b].into_iter().map(Cursor::new), Vec::new(), false).unwrap();
+
+        let files = crate::attachments::list_attachments(&out, false).unwrap();
+        let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["data.csv", "data.csv (2)", "notes.txt"]);
+        assert_eq!(files[1].size, "contents of data.csv".len());
+    }
+
+    #[test]
+    fn test_dict_value() {
+        let dict = b"<< /A (x >> /B) /B << /C 1 >> /D [1 0 R /B] /Length 7 0 R >>";
+        assert_eq!(dict_value(dict, b"/B"), Some(&b"<< /C 1 >>"[..]));
+        assert_eq!(dict_value(dict, b"/C"), None);
+        assert_eq!(dict_value(dict, b"/Length").and_then(as_reference), Some(7));
+        assert_eq!(
+            array_items(dict_value(dict, b"/D").unwrap()),
+            [&b"1 0 R"[..], b"/B"]
+        );
+        assert_eq!(dict_int(b"<</W 12>>", b"/W"), Some(12));
+    }
+
+    #[test]
+    fn test_string_bytes() {
+        assert_eq!(string_bytes(b"(a\\(b\\)\\101\\\nc)").unwrap(), b"a(b)Ac");
+        assert_eq!(
+            string_bytes(b"<FEFF 0041 4>").unwrap(),
+            [0xFE, 0xFF, 0x00, 0x41, 0x40]
+        );
+        assert_eq!(string_bytes(b"/Name"), None);
+    }
+
+    #[test]
+    fn test_damaged_source_needs_lenient() {
+        let mut damaged = save(&mut create_test_pdf(2, "D"));
+        let xref = damaged.windows(4).rposition(|w| w == b"xref").unwrap();
+        damaged.truncate(xref);
+
+        let mut writer = MergeWriter::new(Vec::new()).unwrap();
+        assert!(writer
+            .add_source(Cursor::new(damaged.clone()), false)
+            .is_err());
+        assert_eq!(writer.add_source(Cursor::new(damaged), true).unwrap(), 2);
+        let out = writer.finish().unwrap();
+        assert_eq!(Document::load_mem(&out).unwrap().get_pages().len(), 2);
+    }
+
+    #[test]
+    fn test_no_sources_gives_empty_document() {
+        let out = MergeWriter::new(Vec::new()).unwrap().finish().unwrap();
+        let doc = Document::load_mem(&out).unwrap();
+        assert_eq!(doc.get_pages().len(), 0);
+    }
+
+    #[test]
+    fn test_renumber_refs_skips_names_and_strings() {
+        let body = b"<< /F0 21 0 R /S (see 3 0 R) /H <30> /A [1 0 R 2 3 R] /N 4 >>";
+        let target = SourceTarget {
+            base: 10,
+            size: 22,
+            pages_root: 2,
+        };
+        assert_eq!(
+            renumber_refs(body, &target),
+            b"<< /F0 31 0 R /S (see 3 0 R) /H <30> /A [11 0 R 12 0 R] /N 4 >>".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_renumber_refs_nulls_missing_objects() {
+        let target = SourceTarget {
+            base: 10,
+            size: 5,
+            pages_root: 2,
+        };
+        assert_eq!(
+            renumber_refs(b"[4 0 R 5 0 R 4294967295 0 R]", &target),
+            b"[14 0 R null null]".to_vec()
+        );
+    }
+
+    /// One-page-tree PDF with an extra object numbered `num`, and a trailer
+    /// declaring `size` objects
+    fn pdf_with_object_number(num: u32, size: u32) -> Vec<u8> {
+        let mut bytes = b"%PDF-1.7\n".to_vec();
+        let mut offsets = Vec::new();
+        for (id, body) in [
+            (1, "<< /Type /Catalog /Pages 2 0 R >>"),
+            (2, "<< /Type /Pages /Kids [] /Count 0 >>"),
+            (num, "(far away)"),
+        ] {
+            offsets.push((id, bytes.len()));
+            bytes.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", id, body).as_bytes());
+        }
+        let xref = bytes.len();
+        bytes.extend_from_slice(b"xref\n0 1\n0000000000 65535 f \n");
+        for (id, offset) in offsets {
+            bytes.extend_from_slice(format!("{} 1\n{:010} 00000 n \n", id, offset).as_bytes());
+        }
+        bytes.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                size, xref
+            )
+            .as_bytes(),
+        );
+        bytes
+    }
+
+    #[test]
+    fn test_rejects_huge_object_numbers() {
+        // Would need 3.2 GB of xref offsets
+        let bytes = pdf_with_object_number(400_000_000, 400_000_001);
+        let mut writer = MergeWriter::new(Vec::new()).unwrap();
+        assert!(writer.add_source(Cursor::new(bytes), false).is_err());
+
+        // Past the trailer's /Size
+        let bytes = pdf_with_object_number(40, 4);
+        assert!(writer.add_source(Cursor::new(bytes), false).is_err());
+
+        // Nor is it re-serialized by the lenient fallback
+        let bytes = pdf_with_object_number(400_000_000, 400_000_001);
+        assert!(writer.add_source(Cursor::new(bytes), true).is_err());
+
+        // The merged document still comes out whole
+        writer
+            .add_source(Cursor::new(pdf_with_object_number(3, 4)), false)
+            .unwrap();
+        let out = writer.finish().unwrap();
+        assert!(Document::load_mem(&out).is_ok());
+    }
+
+    #[test]
+    fn test_object_numbers_near_u32_max_do_not_overflow() {
+        let mut writer = MergeWriter::new(Vec::new()).unwrap();
+        writer
+            .add_source(Cursor::new(save(&mut create_test_pdf(1, "A"))), false)
+            .unwrap();
+        let bytes = pdf_with_object_number(4_294_967_290, u32::MAX);
+        assert!(writer.add_source(Cursor::new(bytes), false).is_err());
+
+        // Each source is within the limit, but not both together
+        let bytes = pdf_with_object_number(5_000_000, 5_000_001);
+        writer
+            .add_source(Cursor::new(bytes.clone()), false)
+            .unwrap();
+        assert!(writer.add_source(Cursor::new(bytes), false).is_err());
+    }
+
+    #[test]
+    fn test_find_object_end() {
+        let end = find_object_end(b"<< /Length 3 >>\r\nstream\r\nabc", false).unwrap();
+        assert_eq!(end.head_end, 17);
+        assert_eq!(end.data_start, Some(25));
+
+        let end = find_object_end(b"[1 2 3]\nendobj", false).unwrap();
+        assert_eq!(end.data_start, None);
+
+        // Cannot decide between \r and \r\n yet
+        assert!(find_object_end(b"<< >>\nstream\r", false).is_none());
+    }
+}
@@ -0,0 +1,573 @@
+//! Document metadata: `/Info` dictionary and XMP
+//!
+//! `read_metadata` prefers values from `/Info` and falls back to the XMP
+//! packet for anything `/Info` lacks. `write_metadata` replaces `/Info` and
+//! rewrites the catalog's `/Metadata` stream so the two never disagree.
+//!
+//! Dates are exposed as ISO 8601 strings (as in XMP) and converted to the
+//! PDF `D:YYYYMMDDHHmmSSOHH'mm'` form when written to `/Info`.
+
+use crate::error::PdfJoinError;
+use crate::repair::load_document;
+use crate::text_string::{encode_text_string, object_text};
+use crate::xmp::XmpMetadata;
+use lopdf::{Dictionary, Document, Object, Stream, StringFormat};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Keys of `/Info` that map to named fields rather than `custom`
+const STANDARD_INFO_KEYS: &[&str] = &[
+    "Title",
+    "Author",
+    "Subject",
+    "Keywords",
+    "Creator",
+    "Producer",
+    "CreationDate",
+    "ModDate",
+    "Trapped",
+];
+
+/// Document-level metadata shared by `/Info` and XMP
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DocumentMetadata {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub keywords: Option<String>,
+    /// Application that created the original document (`/Creator`, `xmp:CreatorTool`)
+    #[serde(default)]
+    pub creator: Option<String>,
+    /// Application that produced the PDF (`/Producer`, `pdf:Producer`)
+    #[serde(default)]
+    pub producer: Option<String>,
+    /// ISO 8601 creation date
+    #[serde(default)]
+    pub creation_date: Option<String>,
+    /// ISO 8601 modification date
+    #[serde(default)]
+    pub modification_date: Option<String>,
+    /// Non-standard `/Info` keys, mirrored to `pdfx:` properties in XMP
+    #[serde(default)]
+    pub custom: BTreeMap<String, String>,
+}
+
+/// Read metadata from PDF bytes
+pub fn read_metadata(bytes: &[u8], lenient: bool) -> Result<DocumentMetadata, PdfJoinError> {
+    let doc = load_document(bytes, lenient)?;
+    Ok(read_document_metadata(&doc))
+}
+
+/// Replace the document's metadata, updating both `/Info` and XMP
+pub fn write_metadata(
+    bytes: &[u8],
+    metadata: &DocumentMetadata,
+    lenient: bool,
+) -> Result<Vec<u8>, PdfJoinError> {
+    let mut doc = load_document(bytes, lenient)?;
+    write_document_metadata(&mut doc, metadata)?;
+    save(&mut doc)
+}
+
+/// Remove personal and provenance metadata before sharing a document.
+///
+/// Clears author, creator tool, producer, dates, custom properties, XMP
+/// document/instance IDs and edit history, and the catalog's and pages'
+/// `/PieceInfo` private application data. Title, subject and keywords are kept.
+pub fn scrub_metadata(bytes: &[u8], lenient: bool) -> Result<Vec<u8>, PdfJoinError> {
+    let mut doc = load_document(bytes, lenient)?;
+
+    let current = read_document_metadata(&doc);
+    let scrubbed = DocumentMetadata {
+        title: current.title,
+        subject: current.subject,
+        keywords: current.keywords,
+        ..Default::default()
+    };
+
+    if let Ok(catalog) = doc.catalog_mut() {
+        catalog.remove(b"PieceInfo");
+    }
+    let page_ids: Vec<_> = doc.get_pages().into_values().collect();
+    for page_id in page_ids {
+        if let Ok(page) = doc.get_dictionary_mut(page_id) {
+            page.remove(b"PieceInfo");
+        }
+    }
+
+    write_xmp(&mut doc, &xmp_for(&scrubbed, &XmpMetadata::default()))?;
+    write_info(&mut doc, &scrubbed);
+    save(&mut doc)
+}
+
+/// Read metadata from a parsed document
+pub fn read_document_metadata(doc: &Document) -> DocumentMetadata {
+    let xmp = read_xmp(doc).map(|xml| XmpMetadata::parse(&xml));
+    let info = info_dict(doc);
+
+    let info_text = |key: &[u8]| -> Option<String> {
+        info.and_then(|d| d.get(key).ok())
+            .and_then(|v| doc.dereference(v).ok())
+            .and_then(|(_, v)| object_text(v))
+            .filter(|s| !s.is_empty())
+    };
+    let info_date = |key: &[u8]| info_text(key).map(|d| pdf_date_to_iso(&d));
+
+    let mut custom = BTreeMap::new();
+    if let Some(xmp) = &xmp {
+        custom.extend(xmp.custom.clone());
+    }
+    if let Some(info) = info {
+        for (key, value) in info.iter() {
+            let key = String::from_utf8_lossy(key).into_owned();
+            if STANDARD_INFO_KEYS.contains(&key.as_str()) {
+                continue;
+            }
+            let text = doc
+                .dereference(value)
+                .ok()
+                .and_then(|(_, v)| object_text(v));
+            if let Some(text) = text {
+                custom.insert(key, text);
+            }
+        }
+    }
+
+    let xmp = xmp.unwrap_or_default();
+    DocumentMetadata {
+        title: info_text(b"Title").or(xmp.title),
+        author: info_text(b"Author")
+            .or_else(|| (!xmp.creators.is_empty()).then(|| xmp.creators.join("; "))),
+        subject: info_text(b"Subject").or(xmp.description),
+        keywords: info_text(b"Keywords").or(xmp.keywords),
+        creator: info_text(b"Creator").or(xmp.creator_tool),
+        producer: info_text(b"Producer").or(xmp.producer),
+        creation_date: info_date(b"CreationDate").or(xmp.create_date),
+        modification_date: info_date(b"ModDate").or(xmp.modify_date),
+        custom,
+    }
+}
+
+/// Write metadata into a parsed document (`/Info` and XMP)
+pub fn write_document_metadata(
+    doc: &mut Document,
+    metadata: &DocumentMetadata,
+) -> Result<(), PdfJoinError> {
+    // Keep PDF/A identification and document IDs from any existing packet
+    let existing = read_xmp(doc)
+        .map(|xml| XmpMetadata::parse(&xml))
+        .unwrap_or_default();
+    write_xmp(doc, &xmp_for(metadata, &existing))?;
+    write_info(doc, metadata);
+    Ok(())
+}
+
+fn save(doc: &mut Document) -> Result<Vec<u8>, PdfJoinError> {
+    let mut output = Vec::new();
+    doc.save_to(&mut output)
+        .map_err(|e| PdfJoinError::OperationError(e.to_string()))?;
+    Ok(output)
+}
+
+fn info_dict(doc: &Document) -> Option<&Dictionary> {
+    let info = doc.trailer.get(b"Info").ok()?;
+    doc.dereference(info).ok()?.1.as_dict().ok()
+}
+
+fn write_info(doc: &mut Document, metadata: &DocumentMetadata) {
+    let mut info = Dictionary::new();
+    let text_fields = [
+        ("Title", &metadata.title),
+        ("Author", &metadata.author),
+        ("Subject", &metadata.subject),
+        ("Keywords", &metadata.keywords),
+        ("Creator", &metadata.creator),
+        ("Producer", &metadata.producer),
+    ];
+    for (key, value) in text_fields {
+        if let Some(value) = value {
+            info.set(key, encode_text_string(value));
+        }
+    }
+    let date_fields = [
+        ("CreationDate", &metadata.creation_date),
+        ("ModDate", &metadata.modification_date),
+    ];
+    for (key, value) in date_fields {
+        if let Some(value) = value {
+            info.set(
+                key,
+                Object::String(iso_to_pdf_date(value).into_bytes(), StringFormat::Literal),
+            );
+        }
+    }
+    for (key, value) in &metadata.custom {
+        if !STANDARD_INFO_KEYS.contains(&key.as_str()) {
+            info.set(key.as_bytes().to_vec(), encode_text_string(value));
+        }
+    }
+
+    match doc.trailer.get(b"Info").and_then(Object::as_reference) {
+        Ok(id) => {
+            doc.objects.insert(id, Object::Dictionary(info));
+        }
+        Err(_) => {
+            let id = doc.add_object(Object::Dictionary(info));
+            doc.trailer.set("Info", Object::Reference(id));
+        }
+    }
+}
+
+fn xmp_for(metadata: &DocumentMetadata, existing: &XmpMetadata) -> XmpMetadata {
+    XmpMetadata {
+        title: metadata.title.clone(),
+        creators: metadata.author.iter().cloned().collect(),
+        description: metadata.subject.clone(),
+        keywords: metadata.keywords.clone(),
+        producer: metadata.producer.clone(),
+        creator_tool: metadata.creator.clone(),
+        create_date: metadata.creation_date.clone(),
+        modify_date: metadata.modification_date.clone(),
+        document_id: existing.document_id.clone(),
+        instance_id: existing.instance_id.clone(),
+        pdfa_part: existing.pdfa_part.clone(),
+        pdfa_conformance: existing.pdfa_conformance.clone(),
+        custom: metadata.custom.clone(),
+    }
+}
+
+fn read_xmp(doc: &Document) -> Option<String> {
+    let metadata = doc.catalog().ok()?.get(b"Metadata").ok()?;
+    let stream = doc.dereference(metadata).ok()?.1.as_stream().ok()?;
+    let data = if stream.dict.has(b"Filter") {
+        stream.decompressed_content().ok()?
+    } else {
+        stream.content.clone()
+    };
+    Some(String::from_utf8_lossy(&data).into_owned())
+}
+
+fn write_xmp(doc: &mut Document, xmp: &XmpMetadata) -> Result<(), PdfJoinError> {
+    let mut dict = Dictionary::new();
+    dict.set("Type", Object::Name(b"Metadata".to_vec()));
+    dict.set("Subtype", Object::Name(b"XML".to_vec()));
+    // Metadata streams stay uncompressed so non-PDF tools can find the packet
+    let stream = Stream::new(dict, xmp.to_xml().into_bytes()).with_compression(false);
+
+    let existing = doc
+        .catalog()
+        .map_err(|e| PdfJoinError::OperationError(e.to_string()))?
+        .get(b"Metadata")
+        .and_then(Object::as_reference)
+        .ok();
+
+    match existing {
+        Some(id) => {
+            doc.objects.insert(id, Object::Stream(stream));
+        }
+        None => {
+            let id = doc.add_object(Object::Stream(stream));
+            doc.catalog_mut()
+                .map_err(|e| PdfJoinError::OperationError(e.to_string()))?
+                .set("Metadata", Object::Reference(id));
+        }
+    }
+    Ok(())
+}
+
+/// Convert `D:YYYYMMDDHHmmSSOHH'mm'` to ISO 8601.
+/// Partial dates keep their precision; unrecognized input is returned unchanged.
+pub fn pdf_date_to_iso(date: &str) -> String {
+    let raw = date.trim().strip_prefix("D:").unwrap_or(date.trim());
+    let digits: String = raw.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if !matches!(digits.len(), 4 | 6 | 8 | 10 | 12 | 14) {
+        return date.to_string();
+    }
+
+    let field = |start: usize| digits.get(start..start + 2);
+    let mut iso = digits[..4].to_string();
+    if let Some(month) = field(4) {
+        iso.push('-');
+        iso.push_str(month);
+    }
+    if let Some(day) = field(6) {
+        iso.push('-');
+        iso.push_str(day);
+    }
+    let Some(hour) = field(8) else {
+        return iso;
+    };
+    iso.push_str(&format!(
+        "T{}:{}:{}",
+        hour,
+        field(10).unwrap_or("00"),
+        field(12).unwrap_or("00")
+    ));
+
+    let tz = raw[digits.len()..].replace('\'', "");
+    match tz.chars().next() {
+        Some('Z') => iso.push('Z'),
+        Some(sign @ ('+' | '-')) => {
+            let digits = |range| {
+                tz.get(range)
+                    .filter(|d: &&str| d.bytes().all(|b| b.is_ascii_digit()))
+            };
+            if let Some(hours) = digits(1..3) {
+                let minutes = digits(3..5).unwrap_or("00");
+                iso.push_str(&format!("{}{}:{}", sign, hours, minutes));
+            }
+        }
+        _ => {}
+    }
+    iso
+}
+
+/// Convert an ISO 8601 date to `D:YYYYMMDDHHmmSSOHH'mm'`.
+/// Values already in PDF form are returned unchanged.
+pub fn iso_to_pdf_date(date: &str) -> String {
+    let date = date.trim();
+    if date.starts_with("D:") {
+        return date.to_string();
+    }
+
+    let (datetime, tz) = match date
+        .find(['Z', '+'])
+        .or_else(|| date.rfind('-').filter(|&i| i > 10))
+    {
+        Some(i) => (&date[..i], &date[i..]),
+        None => (date, ""),
+    };
+    // Drop fractional seconds
+    let datetime = datetime.split('.').next().unwrap_or(datetime);
+    let digits: String = datetime.chars().filter(char::is_ascii_digit).collect();
+
+    let tz = match tz {
+        "" => String::new(),
+        "Z" => "Z".to_string(),
+        offset if !offset.starts_with('Z') => {
+            let sign = &offset[..1];
+            // Offsets that aren't `hh`, `hhmm` or `hh:mm` are dropped
+            let digits =
+                |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+            let parts: Vec<&str> = offset[1..].split(':').collect();
+            match parts.as_slice() {
+                [h, m] if digits(h, 2) && digits(m, 2) => format!("{}{}'{}'", sign, h, m),
+                [hm] if digits(hm, 4) => format!("{}{}'{}'", sign, &hm[..2], &hm[2..]),
+                [h] if digits(h, 2) => format!("{}{}'00'", sign, h),
+                _ => String::new(),
+            }
+        }
+        _ => String::new(),
+    };
+    format!("D:{}{}", digits, tz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::dictionary;
+
+    fn create_test_pdf(info: Option<Dictionary>) -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            "PieceInfo" => dictionary! { "Illustrator" => dictionary! {} },
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        if let Some(info) = info {
+            let info_id = doc.add_object(info);
+            doc.trailer.set("Info", info_id);
+        }
+
+        let mut buffer = Vec::new();
+        doc.save_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    fn sample_metadata() -> DocumentMetadata {
+        let mut custom = BTreeMap::new();
+        custom.insert("PropertyId".to_string(), "TPA-0042".to_string());
+        DocumentMetadata {
+            title: Some("Residential Lease – Unit 4B".to_string()),
+            author: Some("Jane Landlord".to_string()),
+            subject: Some("Lease agreement".to_string()),
+            keywords: Some("lease, florida".to_string()),
+            creator: Some("agentPDF".to_string()),
+            producer: Some("pdfjoin".to_string()),
+            creation_date: Some("2024-01-15T09:30:00-05:00".to_string()),
+            modification_date: Some("2024-02-01T12:00:00Z".to_string()),
+            custom,
+        }
+    }
+
+    #[test]
+    fn test_read_info_dictionary() {
+        let pdf = create_test_pdf(Some(dictionary! {
+            "Title" => Object::string_literal("Old Title"),
+            "Author" => Object::string_literal("Someone"),
+            "CreationDate" => Object::string_literal("D:20230405060708+02'00'"),
+            "Matter" => Object::string_literal("A-1"),
+        }));
+        let meta = read_metadata(&pdf, false).unwrap();
+
+        assert_eq!(meta.title.as_deref(), Some("Old Title"));
+        assert_eq!(meta.author.as_deref(), Some("Someone"));
+        assert_eq!(
+            meta.creation_date.as_deref(),
+            Some("2023-04-05T06:07:08+02:00")
+        );
+        assert_eq!(meta.custom.get("Matter").map(String::as_str), Some("A-1"));
+    }
+
+    #[test]
+    fn test_write_then_read_round_trip() {
+        let pdf = create_test_pdf(None);
+        let pdf = write_metadata(&pdf, &sample_metadata(), false).unwrap();
+
+        assert_eq!(read_metadata(&pdf, false).unwrap(), sample_metadata());
+    }
+
+    #[test]
+    fn test_write_keeps_xmp_in_sync() {
+        let pdf = write_metadata(&create_test_pdf(None), &sample_metadata(), false).unwrap();
+        let doc = Document::load_mem(&pdf).unwrap();
+        let xmp = XmpMetadata::parse(&read_xmp(&doc).unwrap());
+
+        assert_eq!(xmp.title.as_deref(), Some("Residential Lease – Unit 4B"));
+        assert_eq!(xmp.creators, vec!["Jane Landlord"]);
+        assert_eq!(xmp.creator_tool.as_deref(), Some("agentPDF"));
+        assert_eq!(
+            xmp.custom.get("PropertyId").map(String::as_str),
+            Some("TPA-0042")
+        );
+
+        // /Info dates are written in PDF form
+        let info = info_dict(&doc).unwrap();
+        assert_eq!(
+            object_text(info.get(b"CreationDate").unwrap()).as_deref(),
+            Some("D:20240115093000-05'00'")
+        );
+    }
+
+    #[test]
+    fn test_write_replaces_previous_info() {
+        let pdf = create_test_pdf(Some(dictionary! {
+            "Title" => Object::string_literal("First document title"),
+            "Stale" => Object::string_literal("x"),
+        }));
+        let pdf = write_metadata(
+            &pdf,
+            &DocumentMetadata {
+                title: Some("Closing Packet".to_string()),
+                ..Default::default()
+            },
+            false,
+        )
+        .unwrap();
+
+        let meta = read_metadata(&pdf, false).unwrap();
+        assert_eq!(meta.title.as_deref(), Some("Closing Packet"));
+        assert!(meta.custom.is_empty());
+        assert!(meta.author.is_none());
+    }
+
+    #[test]
+    fn test_xmp_fallback_when_info_missing() {
+        let mut doc = Document::load_mem(&create_test_pdf(None)).unwrap();
+        let xmp = XmpMetadata {
+            title: Some("From XMP".to_string()),
+            creators: vec!["A".to_string(), "B".to_string()],
+            ..Default::default()
+        };
+        write_xmp(&mut doc, &xmp).unwrap();
+
+        let meta = read_document_metadata(&doc);
+        assert_eq!(meta.title.as_deref(), Some("From XMP"));
+        assert_eq!(meta.author.as_deref(), Some("A; B"));
+    }
+
+    #[test]
+    fn test_scrub_removes_personal_metadata() {
+        let pdf = write_metadata(&create_test_pdf(None), &sample_metadata(), false).unwrap();
+        let scrubbed = scrub_metadata(&pdf, false).unwrap();
+
+        let meta = read_metadata(&scrubbed, false).unwrap();
+        assert_eq!(meta.title, sample_metadata().title);
+        assert_eq!(meta.keywords, sample_metadata().keywords);
+        assert!(meta.author.is_none());
+        assert!(meta.creator.is_none());
+        assert!(meta.producer.is_none());
+        assert!(meta.creation_date.is_none());
+        assert!(meta.modification_date.is_none());
+        assert!(meta.custom.is_empty());
+
+        let doc = Document::load_mem(&scrubbed).unwrap();
+        let xml = read_xmp(&doc).unwrap();
+        assert!(!xml.contains("Jane Landlord"));
+        assert!(!xml.contains("agentPDF"));
+        let page_id = *doc.get_pages().get(&1).unwrap();
+        assert!(!doc.get_dictionary(page_id).unwrap().has(b"PieceInfo"));
+    }
+
+    #[test]
+    fn test_pdf_date_to_iso() {
+        assert_eq!(pdf_date_to_iso("D:20240115093000Z"), "2024-01-15T09:30:00Z");
+        assert_eq!(
+            pdf_date_to_iso("D:20240115093000-05'00'"),
+            "2024-01-15T09:30:00-05:00"
+        );
+        assert_eq!(pdf_date_to_iso("D:2024"), "2024");
+        assert_eq!(pdf_date_to_iso("D:202401151200"), "2024-01-15T12:00:00");
+        assert_eq!(pdf_date_to_iso("yesterday"), "yesterday");
+        // Malformed timezones are dropped rather than sliced mid-character
+        assert_eq!(pdf_date_to_iso("D:20240115093000+é"), "2024-01-15T09:30:00");
+        assert_eq!(pdf_date_to_iso("D:20240115093000-5"), "2024-01-15T09:30:00");
+    }
+
+    #[test]
+    fn test_iso_to_pdf_date() {
+        assert_eq!(iso_to_pdf_date("2024-01-15T09:30:00Z"), "D:20240115093000Z");
+        assert_eq!(
+            iso_to_pdf_date("2024-01-15T09:30:00.123+05:30"),
+            "D:20240115093000+05'30'"
+        );
+        assert_eq!(iso_to_pdf_date("2024-01-15"), "D:20240115");
+        assert_eq!(iso_to_pdf_date("D:2024"), "D:2024");
+        assert_eq!(
+            iso_to_pdf_date("2024-01-15T09:30:00-0800"),
+            "D:20240115093000-08'00'"
+        );
+    }
+
+    #[test]
+    fn test_iso_to_pdf_date_drops_malformed_offsets() {
+        for date in [
+            "2024-01-15T09:30:00+1é1",
+            "2024-01-15T09:30:00+é:1",
+            "2024-01-15T09:30:00+05:3x",
+            "2024-01-15T09:30:00+5",
+            "2024-01-15T09:30:00Z05:00",
+        ] {
+            assert_eq!(iso_to_pdf_date(date), "D:20240115093000", "{}", date);
+        }
+    }
+}
@@ -0,0 +1,908 @@
+//! Recovery parser for damaged or truncated PDFs
+//!
+//! Old scanners and interrupted uploads produce files with wrong xref
+//! offsets, a missing `%%EOF`, truncated streams or no trailer `/Root`, all
+//! of which lopdf rejects. In lenient mode we scan the raw bytes for
+//! `N G obj` markers (the same byte-level approach as `streaming`), write the
+//! objects we find into a clean file with a fresh xref table, and rebuild the
+//! page tree if the catalog did not survive.
+//!
+//! Every public operation takes a `lenient` flag. With `lenient = false`
+//! parsing is strict and behaves exactly as before.
+
+use crate::error::PdfJoinError;
+use crate::streaming::{extract_ref_after, find_pattern, ObjRef};
+use lopdf::{dictionary, Dictionary, Document, Object, ObjectId};
+use regex::bytes::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// One problem found and fixed while recovering a file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RepairFix {
+    /// Bytes before `%PDF-` were skipped
+    HeaderOffset { bytes: usize },
+    /// No `%PDF-` header; PDF 1.4 was assumed
+    MissingHeader,
+    /// The cross-reference table was rebuilt from object markers
+    RebuiltXref { objects: usize },
+    /// The file did not end with `%%EOF`
+    MissingEof,
+    /// A stream's `/Length` did not match the data before `endstream`
+    StreamLength {
+        object: u32,
+        declared: Option<i64>,
+        actual: usize,
+    },
+    /// An object or stream was cut off; whatever was present was kept
+    TruncatedObject { object: u32 },
+    /// The trailer had no usable `/Root`; the catalog was found by scanning
+    RecoveredRoot { object: u32 },
+    /// The page tree was unreachable and was rebuilt from the page objects
+    RebuiltPageTree { pages: usize },
+}
+
+impl fmt::Display for RepairFix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepairFix::HeaderOffset { bytes } => {
+                write!(f, "skipped {} bytes before the PDF header", bytes)
+            }
+            RepairFix::MissingHeader => write!(f, "missing PDF header, assumed version 1.4"),
+            RepairFix::RebuiltXref { objects } => {
+                write!(f, "rebuilt cross-reference table ({} objects)", objects)
+            }
+            RepairFix::MissingEof => write!(f, "missing %%EOF marker"),
+            RepairFix::StreamLength {
+                object,
+                declared: Some(declared),
+                actual,
+            } => write!(
+                f,
+                "object {}: stream length {} corrected to {}",
+                object, declared, actual
+            ),
+            RepairFix::StreamLength {
+                object,
+                declared: None,
+                actual,
+            } => write!(f, "object {}: stream length set to {}", object, actual),
+            RepairFix::TruncatedObject { object } => write!(f, "object {} was truncated", object),
+            RepairFix::RecoveredRoot { object } => {
+                write!(f, "recovered document catalog from object {}", object)
+            }
+            RepairFix::RebuiltPageTree { pages } => {
+                write!(f, "rebuilt page tree ({} pages)", pages)
+            }
+        }
+    }
+}
+
+/// What recovery changed. Empty when the file parsed cleanly.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RepairReport {
+    pub fixes: Vec<RepairFix>,
+}
+
+impl RepairReport {
+    /// True when the file needed no repair
+    pub fn is_clean(&self) -> bool {
+        self.fixes.is_empty()
+    }
+}
+
+/// Parse a document, falling back to recovery when `lenient` is set
+pub fn load_document(bytes: &[u8], lenient: bool) -> Result<Document, PdfJoinError> {
+    if lenient {
+        load_lenient(bytes).map(|(doc, _)| doc)
+    } else {
+        Document::load_mem(bytes).map_err(|e| PdfJoinError::ParseError(e.to_string()))
+    }
+}
+
+/// Parse a document with recovery, reporting what had to be fixed
+pub fn load_lenient(bytes: &[u8]) -> Result<(Document, RepairReport), PdfJoinError> {
+    let mut report = RepairReport::default();
+
+    if let Ok(mut doc) = Document::load_mem(bytes) {
+        if page_tree_intact(&doc) {
+            return Ok((doc, report));
+        }
+        // lopdf drops objects it cannot read; only patch the tree in place
+        // when the catalog itself survived, otherwise rescan the bytes
+        if doc.catalog().is_ok() && has_page_objects(&doc) {
+            fix_page_tree(&mut doc, &mut report)?;
+            return Ok((doc, report));
+        }
+    }
+
+    let rebuilt = rebuild_file(bytes, &mut report)?;
+    let mut doc = Document::load_mem(&rebuilt)
+        .map_err(|e| PdfJoinError::ParseError(format!("Recovery failed: {}", e)))?;
+    if !page_tree_intact(&doc) {
+        fix_page_tree(&mut doc, &mut report)?;
+    }
+    Ok((doc, report))
+}
+
+/// Repair a damaged PDF, returning clean bytes and a report of the fixes.
+/// An undamaged file is returned unchanged with an empty report.
+pub fn repair_pdf(bytes: &[u8]) -> Result<(Vec<u8>, RepairReport), PdfJoinError> {
+    let (mut doc, report) = load_lenient(bytes)?;
+    if report.is_clean() {
+        return Ok((bytes.to_vec(), report));
+    }
+
+    let mut buffer = Vec::new();
+    doc.save_to(&mut buffer)
+        .map_err(|e| PdfJoinError::OperationError(format!("Save failed: {}", e)))?;
+    Ok((buffer, report))
+}
+
+// =============================================================================
+// Byte-level recovery
+// =============================================================================
+
+/// An object recovered from the raw bytes, ready to be written back out
+struct RawObject {
+    generation: u16,
+    body: Vec<u8>,
+}
+
+fn object_marker_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?-u)(?:\A|\s)(\d{1,10})\s+(\d{1,5})\s+obj\b").unwrap())
+}
+
+/// Keywords that end the object section (an unterminated object stops here)
+fn boundary_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?-u)(?:\A|\s)(?:xref|trailer|startxref)\b").unwrap())
+}
+
+fn length_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?-u)/Length\s+(\d+)(\s+\d+\s+R)?").unwrap())
+}
+
+fn type_regex(name: &str) -> Regex {
+    Regex::new(&format!(r"(?-u)/Type\s*/{}\b", name)).unwrap()
+}
+
+/// Rebuild a parseable file from whatever objects can be found in `bytes`
+fn rebuild_file(bytes: &[u8], report: &mut RepairReport) -> Result<Vec<u8>, PdfJoinError> {
+    let head = &bytes[..bytes.len().min(1024)];
+    let (bytes, version) = match find_pattern(head, b"%PDF-") {
+        Some(offset) => {
+            if offset > 0 {
+                report.fixes.push(RepairFix::HeaderOffset { bytes: offset });
+            }
+            let bytes = &bytes[offset..];
+            (bytes, header_version(bytes))
+        }
+        None => {
+            report.fixes.push(RepairFix::MissingHeader);
+            (bytes, "1.4".to_string())
+        }
+    };
+
+    let xref_index = report.fixes.len();
+    let mut objects = scan_objects(bytes, report);
+
+    // Cross-reference streams describe the broken layout we are replacing
+    let xref_type = type_regex("XRef");
+    let xref_streams: Vec<u32> = objects
+        .iter()
+        .filter(|(_, obj)| xref_type.is_match(&obj.body))
+        .map(|(&num, _)| num)
+        .collect();
+    let xref_dicts: Vec<Vec<u8>> = xref_streams
+        .iter()
+        .filter_map(|num| objects.remove(num))
+        .map(|obj| obj.body)
+        .collect();
+
+    if objects.is_empty() {
+        return Err(PdfJoinError::ParseError(
+            "No objects could be recovered".into(),
+        ));
+    }
+    report.fixes.insert(
+        xref_index,
+        RepairFix::RebuiltXref {
+            objects: objects.len(),
+        },
+    );
+
+    let tail = &bytes[bytes.len().saturating_sub(1024)..];
+    if find_pattern(tail, b"%%EOF").is_none() {
+        report.fixes.push(RepairFix::MissingEof);
+    }
+
+    let trailer = rfind_pattern(bytes, b"trailer").map(|pos| &bytes[pos..]);
+    let trailer_ref = |key: &[u8]| {
+        trailer
+            .and_then(|t| extract_ref_after(t, key))
+            .or_else(|| xref_dicts.iter().find_map(|d| extract_ref_after(d, key)))
+            .filter(|r| objects.contains_key(&r.0))
+    };
+
+    let catalog_type = type_regex("Catalog");
+    let is_catalog = |r: &ObjRef| {
+        objects
+            .get(&r.0)
+            .is_some_and(|o| catalog_type.is_match(&o.body))
+    };
+    let root = match trailer_ref(b"/Root").filter(is_catalog) {
+        Some(root) => Some(root),
+        None => {
+            let found = objects
+                .iter()
+                .rev()
+                .find(|(_, o)| catalog_type.is_match(&o.body))
+                .map(|(&num, o)| ObjRef(num, o.generation));
+            if let Some(root) = found {
+                report
+                    .fixes
+                    .push(RepairFix::RecoveredRoot { object: root.0 });
+            }
+            found
+        }
+    };
+    let info = trailer_ref(b"/Info");
+
+    Ok(write_file(&version, &objects, root, info))
+}
+
+/// Scan for `N G obj` markers and extract each object's body.
+/// Later definitions of the same object number win, as in an incremental update.
+fn scan_objects(bytes: &[u8], report: &mut RepairReport) -> BTreeMap<u32, RawObject> {
+    let marker = object_marker_regex();
+    let boundaries = Boundaries::find(bytes);
+    let mut objects = BTreeMap::new();
+    let mut pos = 0;
+
+    while let Some(caps) = marker.captures_at(bytes, pos) {
+        let whole = caps.get(0).unwrap();
+        let number = parse_num::<u32>(caps.get(1).unwrap().as_bytes());
+        let generation = parse_num::<u16>(caps.get(2).unwrap().as_bytes());
+        let body_start = whole.end();
+
+        let limit = boundaries.next(body_start, bytes.len());
+        let (body, end) = match (number, generation) {
+            (Some(number), Some(_)) => extract_body(bytes, body_start, limit, number, report),
+            _ => (Vec::new(), body_start),
+        };
+
+        if let (Some(number), Some(generation)) = (number, generation) {
+            objects.insert(number, RawObject { generation, body });
+        }
+        pos = end.max(body_start);
+    }
+
+    objects
+}
+
+/// Body of one object (between `obj` and `endobj`) and the offset to resume scanning
+fn extract_body(
+    bytes: &[u8],
+    start: usize,
+    limit: usize,
+    number: u32,
+    report: &mut RepairReport,
+) -> (Vec<u8>, usize) {
+    // Nothing past the next boundary belongs to this object, so searching
+    // beyond it would only rescan the rest of the file for every object
+    let rest = &bytes[start..limit];
+    let endobj = find_pattern(rest, b"endobj");
+
+    if let Some(stream) = find_stream_keyword(rest) {
+        if endobj.is_none_or(|e| stream < e) && start + stream < limit {
+            return extract_stream_body(bytes, start, stream, limit, number, report);
+        }
+    }
+
+    match endobj.map(|e| start + e).filter(|&e| e <= limit) {
+        Some(end) => (trim(&bytes[start..end]).to_vec(), end + b"endobj".len()),
+        None => {
+            report
+                .fixes
+                .push(RepairFix::TruncatedObject { object: number });
+            (trim(&bytes[start..limit]).to_vec(), limit)
+        }
+    }
+}
+
+fn extract_stream_body(
+    bytes: &[u8],
+    start: usize,
+    stream_kw: usize,
+    limit: usize,
+    number: u32,
+    report: &mut RepairReport,
+) -> (Vec<u8>, usize) {
+    let dict = &bytes[start..start + stream_kw];
+    let mut data_start = start + stream_kw + b"stream".len();
+    if bytes.get(data_start) == Some(&b'\r') {
+        data_start += 1;
+    }
+    if bytes.get(data_start) == Some(&b'\n') {
+        data_start += 1;
+    }
+
+    let declared = length_regex().captures(dict).and_then(|c| {
+        if c.get(2).is_some() {
+            None
+        } else {
+            parse_num::<i64>(c.get(1).unwrap().as_bytes())
+        }
+    });
+
+    let (data, end) = match find_pattern(&bytes[data_start..], b"endstream") {
+        Some(rel) => {
+            let endstream = data_start + rel;
+            // /Length may be anything, including more than fits in a usize
+            let declared_end = declared
+                .and_then(|len| usize::try_from(len).ok())
+                .and_then(|len| data_start.checked_add(len));
+            let data = match declared_end {
+                // Trust /Length when it lands exactly on `endstream`
+                Some(data_end)
+                    if data_end <= endstream && trim(&bytes[data_end..endstream]).is_empty() =>
+                {
+                    &bytes[data_start..data_end]
+                }
+                _ => trim_eol(&bytes[data_start..endstream]),
+            };
+            let mut end = endstream + b"endstream".len();
+            let after = &bytes[end..];
+            let skipped = after.len() - trim_start(after).len();
+            if trim_start(after).starts_with(b"endobj") {
+                end += skipped + b"endobj".len();
+            }
+            (data, end)
+        }
+        None => {
+            report
+                .fixes
+                .push(RepairFix::TruncatedObject { object: number });
+            let stop = limit.max(data_start);
+            (trim_eol(&bytes[data_start..stop]), stop)
+        }
+    };
+
+    if declared != Some(data.len() as i64) {
+        report.fixes.push(RepairFix::StreamLength {
+            object: number,
+            declared,
+            actual: data.len(),
+        });
+    }
+
+    let mut body = set_length(trim(dict), data.len());
+    body.extend_from_slice(b"\nstream\n");
+    body.extend_from_slice(data);
+    body.extend_from_slice(b"\nendstream");
+    (body, end)
+}
+
+/// Replace (or add) a direct `/Length` entry in a stream dictionary
+fn set_length(dict: &[u8], length: usize) -> Vec<u8> {
+    let replacement = format!("/Length {}", length);
+    if length_regex().is_match(dict) {
+        return length_regex()
+            .replace(dict, replacement.as_bytes())
+            .into_owned();
+    }
+    match find_pattern(dict, b"<<") {
+        Some(pos) => {
+            let mut out = dict[..pos + 2].to_vec();
+            out.push(b' ');
+            out.extend_from_slice(replacement.as_bytes());
+            out.extend_from_slice(&dict[pos + 2..]);
+            out
+        }
+        None => dict.to_vec(),
+    }
+}
+
+/// Serialize recovered objects with a fresh xref table and trailer
+fn write_file(
+    version: &str,
+    objects: &BTreeMap<u32, RawObject>,
+    root: Option<ObjRef>,
+    info: Option<ObjRef>,
+) -> Vec<u8> {
+    let mut out = format!("%PDF-{}\n", version).into_bytes();
+    out.extend_from_slice(b"%\xE2\xE3\xCF\xD3\n");
+    let mut offsets = BTreeMap::new();
+
+    for (&number, obj) in objects {
+        offsets.insert(number, (out.len(), obj.generation));
+        out.extend_from_slice(format!("{} {} obj\n", number, obj.generation).as_bytes());
+        out.extend_from_slice(&obj.body);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+
+    let size = objects.keys().next_back().map_or(1, |&n| n + 1);
+    let xref_offset = out.len();
+    out.extend_from_slice(format!("xref\n0 {}\n", size).as_bytes());
+    for number in 0..size {
+        let entry = match offsets.get(&number) {
+            Some((offset, generation)) => format!("{:010} {:05} n \n", offset, generation),
+            None if number == 0 => "0000000000 65535 f \n".to_string(),
+            None => "0000000000 00000 f \n".to_string(),
+        };
+        out.extend_from_slice(entry.as_bytes());
+    }
+
+    let mut trailer = format!("trailer\n<< /Size {}", size);
+    if let Some(root) = root {
+        trailer.push_str(&format!(" /Root {} {} R", root.0, root.1));
+    }
+    if let Some(info) = info {
+        trailer.push_str(&format!(" /Info {} {} R", info.0, info.1));
+    }
+    trailer.push_str(&format!(" >>\nstartxref\n{}\n%%EOF\n", xref_offset));
+    out.extend_from_slice(trailer.as_bytes());
+    out
+}
+
+fn header_version(bytes: &[u8]) -> String {
+    let version: String = bytes[5..]
+        .iter()
+        .take(3)
+        .take_while(|b| b.is_ascii_digit() || **b == b'.')
+        .map(|&b| b as char)
+        .collect();
+    if version.len() == 3 {
+        version
+    } else {
+        "1.4".to_string()
+    }
+}
+
+/// Offsets of every object marker and structural keyword, found in one pass
+/// so each object's limit is a lookup rather than a rescan of the file
+struct Boundaries(Vec<usize>);
+
+impl Boundaries {
+    fn find(bytes: &[u8]) -> Self {
+        let mut offsets: Vec<usize> = object_marker_regex()
+            .find_iter(bytes)
+            .chain(boundary_regex().find_iter(bytes))
+            .map(|m| m.start())
+            .collect();
+        offsets.sort_unstable();
+        Self(offsets)
+    }
+
+    /// Offset of the next object marker or structural keyword at or after
+    /// `from`, or `end` if there is none
+    fn next(&self, from: usize, end: usize) -> usize {
+        let index = self.0.partition_point(|&offset| offset < from);
+        self.0.get(index).copied().unwrap_or(end)
+    }
+}
+
+/// Position of a `stream` keyword (not `endstream`) followed by an end-of-line
+fn find_stream_keyword(bytes: &[u8]) -> Option<usize> {
+    let mut from = 0;
+    while let Some(rel) = find_pattern(&bytes[from..], b"stream") {
+        let pos = from + rel;
+        let after = bytes.get(pos + 6);
+        let is_end = pos >= 3 && &bytes[pos - 3..pos] == b"end";
+        if !is_end && matches!(after, Some(b'\r') | Some(b'\n')) {
+            return Some(pos);
+        }
+        from = pos + 6;
+    }
+    None
+}
+
+fn rfind_pattern(bytes: &[u8], pattern: &[u8]) -> Option<usize> {
+    bytes
+        .windows(pattern.len())
+        .rposition(|window| window == pattern)
+}
+
+fn parse_num<T: std::str::FromStr>(digits: &[u8]) -> Option<T> {
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+fn trim_start(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn trim(bytes: &[u8]) -> &[u8] {
+    let bytes = trim_start(bytes);
+    let end = bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |p| p + 1);
+    &bytes[..end]
+}
+
+/// Drop the single end-of-line that precedes `endstream`
+fn trim_eol(bytes: &[u8]) -> &[u8] {
+    bytes
+        .strip_suffix(b"\r\n")
+        .or_else(|| bytes.strip_suffix(b"\n"))
+        .or_else(|| bytes.strip_suffix(b"\r"))
+        .unwrap_or(bytes)
+}
+
+// =============================================================================
+// Page tree recovery
+// =============================================================================
+
+fn is_type(obj: &Object, name: &[u8]) -> bool {
+    obj.as_dict().is_ok_and(|d| dict_type_is(d, name))
+}
+
+fn dict_type_is(dict: &Dictionary, name: &[u8]) -> bool {
+    dict.get(b"Type").and_then(Object::as_name).ok() == Some(name)
+}
+
+fn has_page_objects(doc: &Document) -> bool {
+    doc.objects.values().any(|o| is_type(o, b"Page"))
+}
+
+/// The catalog reaches every page its root `/Count` promises
+fn page_tree_intact(doc: &Document) -> bool {
+    let Ok(catalog) = doc.catalog() else {
+        return false;
+    };
+    let found = doc.get_pages().len() as i64;
+    let promised = catalog
+        .get(b"Pages")
+        .and_then(|p| doc.dereference(p))
+        .and_then(|(_, p)| p.as_dict())
+        .and_then(|p| p.get(b"Count"))
+        .and_then(Object::as_i64)
+        .unwrap_or(found);
+    (found > 0 || !has_page_objects(doc)) && found == promised
+}
+
+/// Count pages reachable from a `/Pages` node
+fn count_pages(doc: &Document, node: ObjectId, visited: &mut HashSet<ObjectId>) -> usize {
+    if !visited.insert(node) {
+        return 0;
+    }
+    let Ok(dict) = doc.get_dictionary(node) else {
+        return 0;
+    };
+    if dict_type_is(dict, b"Page") {
+        return 1;
+    }
+    let Ok(kids) = dict.get(b"Kids").and_then(Object::as_array) else {
+        return 0;
+    };
+    kids.iter()
+        .filter_map(|k| k.as_reference().ok())
+        .map(|kid| count_pages(doc, kid, visited))
+        .sum()
+}
+
+/// Point the catalog at a working page tree, creating the catalog if needed.
+///
+/// Prefers a surviving top-level `/Pages` node (keeping page order and
+/// inherited attributes); otherwise all page objects are attached in object
+/// number order to a new `/Pages` node.
+fn fix_page_tree(doc: &mut Document, report: &mut RepairReport) -> Result<(), PdfJoinError> {
+    let page_ids: Vec<ObjectId> = doc
+        .objects
+        .iter()
+        .filter(|(_, o)| is_type(o, b"Page"))
+        .map(|(&id, _)| id)
+        .collect();
+    if page_ids.is_empty() {
+        return Err(PdfJoinError::ParseError(
+            "No pages could be recovered".into(),
+        ));
+    }
+
+    let orphan_root = doc
+        .objects
+        .iter()
+        .filter(|(_, o)| is_type(o, b"Pages"))
+        .filter(|(_, o)| {
+            o.as_dict()
+                .ok()
+                .and_then(|d| d.get(b"Parent").ok())
+                .and_then(|p| p.as_reference().ok())
+                .is_none_or(|parent| !doc.objects.contains_key(&parent))
+        })
+        .map(|(&id, _)| (id, count_pages(doc, id, &mut HashSet::new())))
+        .filter(|&(_, count)| count > 0)
+        .max_by_key(|&(_, count)| count);
+
+    // A surviving root is only worth keeping if it reaches every page
+    let pages_id = match orphan_root {
+        Some((id, count)) if count == page_ids.len() => {
+            if let Ok(dict) = doc.get_dictionary_mut(id) {
+                dict.remove(b"Parent");
+                dict.set("Count", count as i64);
+            }
+            id
+        }
+        _ => {
+            let pages_id = doc.new_object_id();
+            for &page_id in &page_ids {
+                if let Ok(page) = doc.get_dictionary_mut(page_id) {
+                    page.set("Parent", pages_id);
+                }
+            }
+            let kids: Vec<Object> = page_ids.iter().map(|&id| id.into()).collect();
+            doc.objects.insert(
+                pages_id,
+                Object::Dictionary(dictionary! {
+                    "Type" => "Pages",
+                    "Kids" => kids,
+                    "Count" => page_ids.len() as i64,
+                }),
+            );
+            pages_id
+        }
+    };
+
+    match doc.catalog_mut() {
+        Ok(catalog) => catalog.set("Pages", pages_id),
+        Err(_) => {
+            let catalog_id = doc.add_object(dictionary! {
+                "Type" => "Catalog",
+                "Pages" => pages_id,
+            });
+            doc.trailer.set("Root", catalog_id);
+        }
+    }
+
+    report.fixes.push(RepairFix::RebuiltPageTree {
+        pages: doc.get_pages().len(),
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "BT /F1 12 Tf 72 712 Td (Hello) Tj ET";
+
+    /// Hand-written two-page PDF; `trailer` is appended after the objects
+    fn pdf_with_trailer(trailer: &str) -> Vec<u8> {
+        format!(
+            "%PDF-1.4\n\
+             1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+             2 0 obj\n<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 /MediaBox [0 0 612 792] >>\nendobj\n\
+             3 0 obj\n<< /Type /Page /Parent 2 0 R /Contents 5 0 R >>\nendobj\n\
+             4 0 obj\n<< /Type /Page /Parent 2 0 R >>\nendobj\n\
+             5 0 obj\n<< /Length {} >>\nstream\n{}\nendstream\nendobj\n{}",
+            CONTENT.len(),
+            CONTENT,
+            trailer
+        )
+        .into_bytes()
+    }
+
+    fn page_content(doc: &Document, page: u32) -> Vec<u8> {
+        let page_id = *doc.get_pages().get(&page).unwrap();
+        doc.get_page_content(page_id).unwrap()
+    }
+
+    #[test]
+    fn test_clean_file_needs_no_repair() {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        let mut clean = Vec::new();
+        doc.save_to(&mut clean).unwrap();
+
+        let (bytes, report) = repair_pdf(&clean).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(bytes, clean);
+    }
+
+    #[test]
+    fn test_broken_xref_offsets() {
+        let pdf = pdf_with_trailer(
+            "xref\n0 6\n0000000000 65535 f \n0000000999 00000 n \n0000000999 00000 n \n\
+             0000000999 00000 n \n0000000999 00000 n \n0000000999 00000 n \n\
+             trailer\n<< /Size 6 /Root 1 0 R >>\nstartxref\n99999\n%%EOF\n",
+        );
+        assert!(load_document(&pdf, false).is_err());
+
+        let (doc, report) = load_lenient(&pdf).unwrap();
+        assert_eq!(doc.get_pages().len(), 2);
+        assert_eq!(page_content(&doc, 1), CONTENT.as_bytes());
+        assert!(report
+            .fixes
+            .contains(&RepairFix::RebuiltXref { objects: 5 }));
+    }
+
+    #[test]
+    fn test_missing_eof_and_trailer() {
+        let pdf = pdf_with_trailer("");
+        let (doc, report) = load_lenient(&pdf).unwrap();
+
+        assert_eq!(doc.get_pages().len(), 2);
+        assert!(report.fixes.contains(&RepairFix::MissingEof));
+        // No trailer at all, so the catalog had to be found by scanning
+        assert!(report
+            .fixes
+            .contains(&RepairFix::RecoveredRoot { object: 1 }));
+    }
+
+    #[test]
+    fn test_truncated_stream() {
+        let mut pdf = pdf_with_trailer("");
+        let cut = pdf.len() - "(Hello) Tj ET\nendstream\nendobj\n".len();
+        pdf.truncate(cut);
+
+        let (doc, report) = load_lenient(&pdf).unwrap();
+        assert_eq!(doc.get_pages().len(), 2);
+        assert_eq!(page_content(&doc, 1), b"BT /F1 12 Tf 72 712 Td ");
+        assert!(report
+            .fixes
+            .contains(&RepairFix::TruncatedObject { object: 5 }));
+    }
+
+    #[test]
+    fn test_wrong_stream_length() {
+        let pdf = String::from_utf8(pdf_with_trailer(""))
+            .unwrap()
+            .replace(&format!("/Length {}", CONTENT.len()), "/Length 9999");
+        let (doc, report) = load_lenient(pdf.as_bytes()).unwrap();
+
+        assert_eq!(page_content(&doc, 1), CONTENT.as_bytes());
+        assert!(report.fixes.contains(&RepairFix::StreamLength {
+            object: 5,
+            declared: Some(9999),
+            actual: CONTENT.len(),
+        }));
+    }
+
+    #[test]
+    fn test_out_of_range_stream_length() {
+        for length in ["4294967295", "9223372036854775807"] {
+            let pdf = String::from_utf8(pdf_with_trailer("")).unwrap().replace(
+                &format!("/Length {}", CONTENT.len()),
+                &format!("/Length {}", length),
+            );
+            let (doc, report) = load_lenient(pdf.as_bytes()).unwrap();
+            assert_eq!(page_content(&doc, 1), CONTENT.as_bytes(), "{}", length);
+            assert!(report.fixes.contains(&RepairFix::StreamLength {
+                object: 5,
+                declared: length.parse().ok(),
+                actual: CONTENT.len(),
+            }));
+        }
+    }
+
+    #[test]
+    fn test_page_tree_rebuilt_without_catalog() {
+        let pdf = String::from_utf8(pdf_with_trailer(""))
+            .unwrap()
+            .replace("1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n", "");
+        let (doc, report) = load_lenient(pdf.as_bytes()).unwrap();
+
+        assert_eq!(doc.get_pages().len(), 2);
+        assert_eq!(page_content(&doc, 1), CONTENT.as_bytes());
+        assert!(report
+            .fixes
+            .contains(&RepairFix::RebuiltPageTree { pages: 2 }));
+    }
+
+    #[test]
+    fn test_page_tree_rebuilt_without_pages_node() {
+        let pdf = String::from_utf8(pdf_with_trailer("")).unwrap().replace(
+            "2 0 obj\n<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 /MediaBox [0 0 612 792] >>\nendobj\n",
+            "",
+        );
+        let (doc, report) = load_lenient(pdf.as_bytes()).unwrap();
+
+        assert_eq!(doc.get_pages().len(), 2);
+        assert!(report
+            .fixes
+            .contains(&RepairFix::RebuiltPageTree { pages: 2 }));
+    }
+
+    #[test]
+    fn test_junk_before_header() {
+        let mut pdf = b"garbage from the scanner\n".to_vec();
+        pdf.extend(pdf_with_trailer(""));
+        let (doc, report) = load_lenient(&pdf).unwrap();
+
+        assert_eq!(doc.get_pages().len(), 2);
+        assert_eq!(report.fixes[0], RepairFix::HeaderOffset { bytes: 25 });
+    }
+
+    #[test]
+    fn test_later_object_definition_wins() {
+        let pdf = pdf_with_trailer("4 0 obj\n<< /Type /Page /Parent 2 0 R /Rotate 90 >>\nendobj\n");
+        let (doc, _) = load_lenient(&pdf).unwrap();
+        let page_id = *doc.get_pages().get(&2).unwrap();
+
+        let rotate = doc.get_dictionary(page_id).unwrap().get(b"Rotate").unwrap();
+        assert_eq!(rotate.as_i64().unwrap(), 90);
+    }
+
+    #[test]
+    fn test_many_objects_without_trailer() {
+        // No structural keyword anywhere: each object's limit used to be
+        // found by scanning to the end of the file
+        let mut bytes = b"%PDF-1.7\n".to_vec();
+        for number in 1..=20_000 {
+            bytes.extend_from_slice(format!("{} 0 obj\n({})\nendobj\n", number, number).as_bytes());
+        }
+        let mut report = RepairReport::default();
+        let objects = scan_objects(&bytes, &mut report);
+
+        assert_eq!(objects.len(), 20_000);
+        assert_eq!(objects[&20_000].body, b"(20000)");
+        assert!(report.fixes.is_empty());
+    }
+
+    #[test]
+    fn test_boundaries_next() {
+        let bytes = b"1 0 obj\n1\nendobj\n2 0 obj\n2\nendobj\ntrailer\n<<>>";
+        let boundaries = Boundaries::find(bytes);
+        let second = find_pattern(bytes, b"\n2 0 obj").unwrap();
+        let trailer = find_pattern(bytes, b"\ntrailer").unwrap();
+
+        assert_eq!(boundaries.next(1, bytes.len()), second);
+        assert_eq!(boundaries.next(second + 1, bytes.len()), trailer);
+        assert_eq!(boundaries.next(trailer + 1, bytes.len()), bytes.len());
+    }
+
+    #[test]
+    fn test_repair_pdf_output_loads_strictly() {
+        let (bytes, report) = repair_pdf(&pdf_with_trailer("")).unwrap();
+        assert!(!report.is_clean());
+
+        let doc = Document::load_mem(&bytes).unwrap();
+        assert_eq!(doc.get_pages().len(), 2);
+    }
+
+    #[test]
+    fn test_not_a_pdf() {
+        assert!(load_lenient(b"just some text").is_err());
+    }
+
+    #[test]
+    fn test_report_display() {
+        let fix = RepairFix::StreamLength {
+            object: 5,
+            declared: Some(10),
+            actual: 36,
+        };
+        assert_eq!(
+            fix.to_string(),
+            "object 5: stream length 10 corrected to 36"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Full-text search over positioned text
+//!
+//! Builds a per-page string from the runs returned by `extract` and maps
+//! every match back to the glyph boxes it covers. A hit that spans several
+//! runs (e.g. wraps onto the next line) yields one rectangle per run.
+
+use crate::error::PdfJoinError;
+use crate::extract::{extract_text_runs, union_rect, TextRun};
+use crate::operations::PdfRect;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+/// How a query is interpreted
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SearchOptions {
+    /// Match case exactly (searches are case-insensitive by default)
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Only match at word boundaries
+    #[serde(default)]
+    pub whole_word: bool,
+    /// Treat the query as a regular expression instead of literal text
+    #[serde(default)]
+    pub regex: bool,
+}
+
+/// A single match with the rectangles needed to highlight it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchHit {
+    /// 1-based page number
+    pub page: u32,
+    /// The matched text as it appears in the document
+    pub text: String,
+    /// One rectangle per run the match touches, in PDF user space
+    pub rects: Vec<PdfRect>,
+}
+
+/// Search a PDF for `query` and return every hit with its rectangles
+pub fn search(
+    bytes: &[u8],
+    query: &str,
+    options: &SearchOptions,
+    lenient: bool,
+) -> Result<Vec<SearchHit>, PdfJoinError> {
+    let runs = extract_text_runs(bytes, lenient)?;
+    search_runs(&runs, query, options)
+}
+
+/// Search previously extracted runs, avoiding a re-parse for repeated queries
+pub fn search_runs(
+    runs: &[TextRun],
+    query: &str,
+    options: &SearchOptions,
+) -> Result<Vec<SearchHit>, PdfJoinError> {
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let matcher = build_matcher(query, options)?;
+
+    let mut hits = Vec::new();
+    let mut start = 0;
+    while start < runs.len() {
+        let page = runs[start].page;
+        let end = runs[start..]
+            .iter()
+            .position(|r| r.page != page)
+            .map(|p| start + p)
+            .unwrap_or(runs.len());
+
+        let page_text = PageText::build(&runs[start..end]);
+        for m in matcher.find_iter(&page_text.text) {
+            if m.is_empty() {
+                continue;
+            }
+            let rects = page_text.rects_for(&runs[start..end], m.start(), m.end());
+            if !rects.is_empty() {
+                hits.push(SearchHit {
+                    page,
+                    text: m.as_str().to_string(),
+                    rects,
+                });
+            }
+        }
+
+        start = end;
+    }
+
+    Ok(hits)
+}
+
+fn build_matcher(query: &str, options: &SearchOptions) -> Result<Regex, PdfJoinError> {
+    let pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    let pattern = if options.whole_word {
+        format!(r"\b(?:{})\b", pattern)
+    } else {
+        pattern
+    };
+
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+        .map_err(|e| PdfJoinError::InvalidQuery(e.to_string()))
+}
+
+/// The searchable text of one page with a byte-offset → glyph index
+struct PageText {
+    text: String,
+    /// For each char: (byte offset in `text`, run index, glyph index).
+    /// Separators inserted between runs have no glyph.
+    chars: Vec<(usize, Option<(usize, usize)>)>,
+}
+
+impl PageText {
+    fn build(runs: &[TextRun]) -> Self {
+        let mut text = String::new();
+        let mut chars = Vec::new();
+
+        for (run_idx, run) in runs.iter().enumerate() {
+            if run_idx > 0 {
+                let prev = &runs[run_idx - 1];
+                let same_line = (prev.bbox.y - run.bbox.y).abs() < prev.font_size * 0.2;
+                chars.push((text.len(), None));
+                text.push(if same_line { ' ' } else { '\n' });
+            }
+            for (glyph_idx, c) in run.text.chars().enumerate() {
+                chars.push((text.len(), Some((run_idx, glyph_idx))));
+                text.push(c);
+            }
+        }
+
+        Self { text, chars }
+    }
+
+    /// Union of glyph boxes covered by the byte range, grouped per run
+    fn rects_for(&self, runs: &[TextRun], start: usize, end: usize) -> Vec<PdfRect> {
+        let mut rects: Vec<(usize, PdfRect)> = Vec::new();
+
+        for &(offset, glyph) in &self.chars {
+            if offset < start || offset >= end {
+                continue;
+            }
+            let Some((run_idx, glyph_idx)) = glyph else {
+                continue;
+            };
+            let Some(glyph_rect) = runs[run_idx].glyphs.get(glyph_idx) else {
+                continue;
+            };
+            match rects.last_mut() {
+                Some((idx, rect)) if *idx == run_idx => *rect = union_rect(rect, glyph_rect),
+                _ => rects.push((run_idx, glyph_rect.clone())),
+            }
+        }
+
+        rects.into_iter().map(|(_, rect)| rect).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> PdfRect {
+        PdfRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// A run whose glyphs are laid out as 10pt-wide cells starting at `x`
+    fn run(page: u32, text: &str, x: f64, y: f64) -> TextRun {
+        let glyphs: Vec<PdfRect> = (0..text.chars().count())
+            .map(|i| rect(x + i as f64 * 10.0, y, 10.0, 12.0))
+            .collect();
+        TextRun {
+            page,
+            text: text.to_string(),
+            bbox: rect(x, y, glyphs.len() as f64 * 10.0, 12.0),
+            font_name: "Helvetica".to_string(),
+            font_size: 12.0,
+            glyphs,
+        }
+    }
+
+    #[test]
+    fn test_case_insensitive_by_default() {
+        let runs = vec![run(1, "Security Deposit", 0.0, 700.0)];
+        let hits = search_runs(&runs, "deposit", &SearchOptions::default()).unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].text, "Deposit");
+        assert_eq!(hits[0].rects, vec![rect(90.0, 700.0, 70.0, 12.0)]);
+    }
+
+    #[test]
+    fn test_case_sensitive() {
+        let runs = vec![run(1, "Security Deposit", 0.0, 700.0)];
+        let options = SearchOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        assert!(search_runs(&runs, "deposit", &options).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_whole_word() {
+        let runs = vec![run(1, "rent and parent", 0.0, 700.0)];
+        let options = SearchOptions {
+            whole_word: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            search_runs(&runs, "rent", &SearchOptions::default())
+                .unwrap()
+                .len(),
+            2
+        );
+        let hits = search_runs(&runs, "rent", &options).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].rects[0].x, 0.0);
+    }
+
+    #[test]
+    fn test_regex_query() {
+        let runs = vec![run(1, "Rent is $1,500 per month", 0.0, 700.0)];
+        let options = SearchOptions {
+            regex: true,
+            ..Default::default()
+        };
+        let hits = search_runs(&runs, r"\$[\d,]+", &options).unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].text, "$1,500");
+    }
+
+    #[test]
+    fn test_literal_query_escapes_regex_syntax() {
+        let runs = vec![run(1, "Fee (late)", 0.0, 700.0)];
+        let hits = search_runs(&runs, "(late)", &SearchOptions::default()).unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_regex_is_error() {
+        let options = SearchOptions {
+            regex: true,
+            ..Default::default()
+        };
+        let result = search_runs(&[], "(unclosed", &options);
+        assert!(matches!(result, Err(PdfJoinError::InvalidQuery(_))));
+    }
+
+    #[test]
+    fn test_hit_spanning_lines_has_rect_per_run() {
+        let runs = vec![run(1, "thirty", 0.0, 700.0), run(1, "days", 0.0, 680.0)];
+        let hits = search_runs(
+            &runs,
+            r"thirty\s+days",
+            &SearchOptions {
+                regex: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].rects.len(), 2);
+        assert_eq!(hits[0].rects[1].y, 680.0);
+    }
+
+    #[test]
+    fn test_hits_do_not_cross_pages() {
+        let runs = vec![run(1, "notice", 0.0, 700.0), run(2, "period", 0.0, 700.0)];
+        assert!(
+            search_runs(&runs, "notice period", &SearchOptions::default())
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            search_runs(&runs, "period", &SearchOptions::default()).unwrap()[0].page,
+            2
+        );
+    }
+
+    #[test]
+    fn test_empty_query_returns_nothing() {
+        let runs = vec![run(1, "anything", 0.0, 700.0)];
+        assert!(search_runs(&runs, "", &SearchOptions::default())
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -5,15 +5,21 @@
 //! 2. Fall back to lopdf (full parse) for edge cases
 
 use crate::error::PdfJoinError;
+use crate::repair::load_document;
 use crate::streaming;
-use lopdf::Document;
 use std::collections::HashSet;
 
 /// Split a PDF, extracting only the specified pages (1-indexed)
 ///
 /// Uses streaming (byte-level) approach first for speed, with lopdf fallback
-/// for compatibility with edge-case PDF formats.
-pub fn split_document(bytes: &[u8], pages: Vec<u32>) -> Result<Vec<u8>, PdfJoinError> {
+/// for compatibility with edge-case PDF formats. With `lenient`, the file is
+/// parsed through the recovery parser instead, since the streaming path
+/// trusts the xref offsets a damaged file gets wrong.
+pub fn split_document(
+    bytes: &[u8],
+    pages: Vec<u32>,
+    lenient: bool,
+) -> Result<Vec<u8>, PdfJoinError> {
     if pages.is_empty() {
         return Err(PdfJoinError::InvalidRange("No pages specified".into()));
     }
@@ -25,6 +31,10 @@ pub fn split_document(bytes: &[u8], pages: Vec<u32>) -> Result<Vec<u8>, PdfJoinE
         ));
     }
 
+    if lenient {
+        return split_document_lopdf(bytes, pages, true);
+    }
+
     // Try fast streaming approach first (works for 99% of real-world PDFs)
     match streaming::split_streaming(bytes, pages.clone()) {
         Ok(result) => return Ok(result),
@@ -34,12 +44,16 @@ pub fn split_document(bytes: &[u8], pages: Vec<u32>) -> Result<Vec<u8>, PdfJoinE
     }
 
     // Fallback: Full parse with lopdf
-    split_document_lopdf(bytes, pages)
+    split_document_lopdf(bytes, pages, false)
 }
 
 /// Split using lopdf (full parse) - slower but handles all PDF formats
-fn split_document_lopdf(bytes: &[u8], pages: Vec<u32>) -> Result<Vec<u8>, PdfJoinError> {
-    let doc = Document::load_mem(bytes).map_err(|e| PdfJoinError::ParseError(e.to_string()))?;
+fn split_document_lopdf(
+    bytes: &[u8],
+    pages: Vec<u32>,
+    lenient: bool,
+) -> Result<Vec<u8>, PdfJoinError> {
+    let doc = load_document(bytes, lenient)?;
 
     let page_count = doc.get_pages().len() as u32;
 
@@ -162,14 +176,14 @@ mod tests {
     #[test]
     fn test_split_empty_pages_fails() {
         let pdf = create_test_pdf(5);
-        let result = split_document(&pdf, vec![]);
+        let result = split_document(&pdf, vec![], false);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_split_extracts_single_page() {
         let pdf = create_test_pdf(5);
-        let result = split_document(&pdf, vec![1]).unwrap();
+        let result = split_document(&pdf, vec![1], false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
         assert_eq!(doc.get_pages().len(), 1);
     }
@@ -177,7 +191,7 @@ mod tests {
     #[test]
     fn test_split_extracts_multiple_pages() {
         let pdf = create_test_pdf(5);
-        let result = split_document(&pdf, vec![1, 3, 5]).unwrap();
+        let result = split_document(&pdf, vec![1, 3, 5], false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
         assert_eq!(doc.get_pages().len(), 3);
     }
@@ -185,7 +199,7 @@ mod tests {
     #[test]
     fn test_split_extracts_range() {
         let pdf = create_test_pdf(10);
-        let result = split_document(&pdf, vec![2, 3, 4, 5]).unwrap();
+        let result = split_document(&pdf, vec![2, 3, 4, 5], false).unwrap();
         let doc = Document::load_mem(&result).unwrap();
         assert_eq!(doc.get_pages().len(), 4);
     }
@@ -193,14 +207,14 @@ mod tests {
     #[test]
     fn test_split_invalid_page_number_fails() {
         let pdf = create_test_pdf(5);
-        let result = split_document(&pdf, vec![10]); // Page 10 doesn't exist
+        let result = split_document(&pdf, vec![10], false); // Page 10 doesn't exist
         assert!(result.is_err());
     }
 
     #[test]
     fn test_split_page_zero_fails() {
         let pdf = create_test_pdf(5);
-        let result = split_document(&pdf, vec![0]); // Pages are 1-indexed
+        let result = split_document(&pdf, vec![0], false); // Pages are 1-indexed
         assert!(result.is_err());
     }
 
@@ -214,7 +228,7 @@ mod tests {
 
         // Split pages 5-17 (the exact range that failed in production)
         let pages: Vec<u32> = (5..=17).collect();
-        let result = split_document(&pdf, pages.clone()).expect("split should succeed");
+        let result = split_document(&pdf, pages.clone(), false).expect("split should succeed");
 
         // The output must be parseable by lopdf
         let doc = Document::load_mem(&result).expect("output PDF must be valid and parseable");
@@ -247,7 +261,7 @@ mod tests {
         eprintln!("Loaded real PDF: {} bytes", pdf_bytes.len());
 
         // Get page count
-        let page_count = crate::get_page_count(&pdf_bytes).expect("get page count");
+        let page_count = crate::get_page_count(&pdf_bytes, false).expect("get page count");
         eprintln!("Page count: {}", page_count);
         assert_eq!(
             page_count, 17,
@@ -256,7 +270,7 @@ mod tests {
 
         // Split pages 5-17 (the exact operation that failed)
         let pages: Vec<u32> = (5..=17).collect();
-        let result = split_document(&pdf_bytes, pages).expect("split should succeed");
+        let result = split_document(&pdf_bytes, pages, false).expect("split should succeed");
         eprintln!("Split result: {} bytes", result.len());
 
         // The critical test: output must be parseable by lopdf
@@ -330,7 +344,7 @@ mod tests {
         let pages: Vec<u32> = (4..=14).collect();
         eprintln!("Splitting pages: {:?}", pages);
 
-        let result = split_document(&pdf_bytes, pages).expect("split should succeed");
+        let result = split_document(&pdf_bytes, pages, false).expect("split should succeed");
         eprintln!("Output PDF: {} bytes", result.len());
 
         // Write to /tmp for manual inspection
@@ -478,7 +492,7 @@ mod tests {
     #[test]
     fn test_get_page_count_with_generated_pdf() {
         let pdf = create_test_pdf(7);
-        let result = crate::get_page_count(&pdf);
+        let result = crate::get_page_count(&pdf, false);
         assert!(
             result.is_ok(),
             "get_page_count should work. Error: {:?}",
@@ -486,4 +500,16 @@ mod tests {
         );
         assert_eq!(result.unwrap(), 7);
     }
+
+    #[test]
+    fn test_split_lenient_recovers_missing_xref() {
+        let mut pdf = create_test_pdf(3);
+        let xref = pdf.windows(4).rposition(|w| w == b"xref").unwrap();
+        pdf.truncate(xref);
+
+        assert!(split_document(&pdf, vec![2], false).is_err());
+        let result = split_document(&pdf, vec![2, 3], true).unwrap();
+        let doc = Document::load_mem(&result).unwrap();
+        assert_eq!(doc.get_pages().len(), 2);
+    }
 }
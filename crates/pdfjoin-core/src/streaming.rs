@@ -357,7 +357,7 @@ fn extract_object_bytes(bytes: &[u8], start: usize) -> Result<Vec<u8>, PdfJoinEr
 }
 
 /// Find pattern in bytes
-fn find_pattern(bytes: &[u8], pattern: &[u8]) -> Option<usize> {
+pub(crate) fn find_pattern(bytes: &[u8], pattern: &[u8]) -> Option<usize> {
     bytes
         .windows(pattern.len())
         .position(|window| window == pattern)
@@ -369,7 +369,7 @@ fn contains_pattern(bytes: &[u8], pattern: &[u8]) -> bool {
 }
 
 /// Extract reference after a key (e.g., "/Root 1 0 R")
-fn extract_ref_after(bytes: &[u8], key: &[u8]) -> Option<ObjRef> {
+pub(crate) fn extract_ref_after(bytes: &[u8], key: &[u8]) -> Option<ObjRef> {
     let pos = find_pattern(bytes, key)?;
     let after = &bytes[pos + key.len()..];
 
@@ -392,7 +392,7 @@ fn extract_ref_after(bytes: &[u8], key: &[u8]) -> Option<ObjRef> {
 }
 
 /// Extract integer after a key (e.g., "/Size 100")
-fn extract_int_after(bytes: &[u8], key: &[u8]) -> Option<i64> {
+pub(crate) fn extract_int_after(bytes: &[u8], key: &[u8]) -> Option<i64> {
     let pos = find_pattern(bytes, key)?;
     let after = &bytes[pos + key.len()..];
 
@@ -0,0 +1,66 @@
+//! PDF text string encoding helpers
+//!
+//! Text strings (names of attachments, `/Info` values, descriptions) are
+//! either PDFDocEncoding or UTF-16BE with a byte order mark.
+
+use lopdf::{Object, StringFormat};
+
+/// Encode a Rust string as a PDF text string object.
+/// ASCII stays a plain literal; anything else is written as UTF-16BE with BOM.
+pub(crate) fn encode_text_string(text: &str) -> Object {
+    if text.is_ascii() {
+        return Object::String(text.as_bytes().to_vec(), StringFormat::Literal);
+    }
+    let mut bytes = vec![0xFE, 0xFF];
+    for unit in text.encode_utf16() {
+        bytes.extend_from_slice(&unit.to_be_bytes());
+    }
+    Object::String(bytes, StringFormat::Hexadecimal)
+}
+
+/// Decode the bytes of a PDF text string
+pub(crate) fn decode_text_string(bytes: &[u8]) -> String {
+    if bytes.len() >= 2 && bytes[0] == 0xFE && bytes[1] == 0xFF {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    if bytes.len() >= 3 && bytes[..3] == [0xEF, 0xBB, 0xBF] {
+        return String::from_utf8_lossy(&bytes[3..]).into_owned();
+    }
+    // PDFDocEncoding matches Latin-1 for printable characters
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+/// Decode a string object, returning `None` for any other object type
+pub(crate) fn object_text(obj: &Object) -> Option<String> {
+    match obj {
+        Object::String(bytes, _) => Some(decode_text_string(bytes)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_round_trip() {
+        let obj = encode_text_string("audit.json");
+        assert!(matches!(obj, Object::String(_, StringFormat::Literal)));
+        assert_eq!(object_text(&obj).unwrap(), "audit.json");
+    }
+
+    #[test]
+    fn test_unicode_round_trip() {
+        let obj = encode_text_string("Título – año.pdf");
+        assert_eq!(object_text(&obj).unwrap(), "Título – año.pdf");
+    }
+
+    #[test]
+    fn test_latin1_bytes() {
+        assert_eq!(decode_text_string(b"caf\xe9"), "café");
+    }
+}
@@ -0,0 +1,361 @@
+//! XMP metadata packets
+//!
+//! A deliberately small XMP model covering the properties that mirror the
+//! `/Info` dictionary, plus PDF/A identification and `pdfx:` custom
+//! properties. Parsing is tolerant and regex based (both attribute and
+//! element forms of `rdf:Description` properties are understood);
+//! serialization always produces a fresh, well-formed packet.
+//!
+//! Properties outside this model (including `xmpMM:History`) are not
+//! carried over when a packet is rewritten.
+
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+/// Namespaces emitted on the `rdf:Description` element
+const NAMESPACES: &[(&str, &str)] = &[
+    ("dc", "http://purl.org/dc/elements/1.1/"),
+    ("xmp", "http://ns.adobe.com/xap/1.0/"),
+    ("pdf", "http://ns.adobe.com/pdf/1.3/"),
+    ("xmpMM", "http://ns.adobe.com/xap/1.0/mm/"),
+    ("pdfaid", "http://www.aiim.org/pdfa/ns/id/"),
+    ("pdfx", "http://ns.adobe.com/pdfx/1.3/"),
+];
+
+/// Properties of an XMP packet that pdfjoin understands
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XmpMetadata {
+    /// `dc:title` (x-default entry)
+    pub title: Option<String>,
+    /// `dc:creator` sequence
+    pub creators: Vec<String>,
+    /// `dc:description` (x-default entry)
+    pub description: Option<String>,
+    /// `pdf:Keywords`
+    pub keywords: Option<String>,
+    /// `pdf:Producer`
+    pub producer: Option<String>,
+    /// `xmp:CreatorTool`
+    pub creator_tool: Option<String>,
+    /// `xmp:CreateDate`, ISO 8601
+    pub create_date: Option<String>,
+    /// `xmp:ModifyDate`, ISO 8601
+    pub modify_date: Option<String>,
+    /// `xmpMM:DocumentID`
+    pub document_id: Option<String>,
+    /// `xmpMM:InstanceID`
+    pub instance_id: Option<String>,
+    /// `pdfaid:part`, kept so rewriting does not drop a PDF/A claim
+    pub pdfa_part: Option<String>,
+    /// `pdfaid:conformance`
+    pub pdfa_conformance: Option<String>,
+    /// `pdfx:*` custom properties
+    pub custom: BTreeMap<String, String>,
+}
+
+impl XmpMetadata {
+    /// Parse the properties we model out of an XMP packet
+    pub fn parse(xml: &str) -> Self {
+        let mut custom = BTreeMap::new();
+        for caps in pdfx_element_regex().captures_iter(xml) {
+            custom.insert(caps[1].to_string(), unescape_xml(caps[2].trim()));
+        }
+        for caps in pdfx_attribute_regex().captures_iter(xml) {
+            custom
+                .entry(caps[1].to_string())
+                .or_insert_with(|| unescape_xml(&caps[2]));
+        }
+
+        Self {
+            title: first_value(xml, "dc:title"),
+            creators: property_values(xml, "dc:creator"),
+            description: first_value(xml, "dc:description"),
+            keywords: first_value(xml, "pdf:Keywords"),
+            producer: first_value(xml, "pdf:Producer"),
+            creator_tool: first_value(xml, "xmp:CreatorTool"),
+            create_date: first_value(xml, "xmp:CreateDate"),
+            modify_date: first_value(xml, "xmp:ModifyDate"),
+            document_id: first_value(xml, "xmpMM:DocumentID"),
+            instance_id: first_value(xml, "xmpMM:InstanceID"),
+            pdfa_part: first_value(xml, "pdfaid:part"),
+            pdfa_conformance: first_value(xml, "pdfaid:conformance"),
+            custom,
+        }
+    }
+
+    /// Serialize as a complete `<?xpacket?>`-wrapped XMP packet
+    pub fn to_xml(&self) -> String {
+        let mut body = String::new();
+        body.push_str("      <dc:format>application/pdf</dc:format>\n");
+        if let Some(title) = &self.title {
+            push_lang_alt(&mut body, "dc:title", title);
+        }
+        if !self.creators.is_empty() {
+            body.push_str("      <dc:creator>\n        <rdf:Seq>\n");
+            for creator in &self.creators {
+                body.push_str(&format!(
+                    "          <rdf:li>{}</rdf:li>\n",
+                    escape_xml(creator)
+                ));
+            }
+            body.push_str("        </rdf:Seq>\n      </dc:creator>\n");
+        }
+        if let Some(description) = &self.description {
+            push_lang_alt(&mut body, "dc:description", description);
+        }
+
+        let simple = [
+            ("pdf:Keywords", &self.keywords),
+            ("pdf:Producer", &self.producer),
+            ("xmp:CreatorTool", &self.creator_tool),
+            ("xmp:CreateDate", &self.create_date),
+            ("xmp:ModifyDate", &self.modify_date),
+            ("xmpMM:DocumentID", &self.document_id),
+            ("xmpMM:InstanceID", &self.instance_id),
+            ("pdfaid:part", &self.pdfa_part),
+            ("pdfaid:conformance", &self.pdfa_conformance),
+        ];
+        for (name, value) in simple {
+            if let Some(value) = value {
+                push_simple(&mut body, name, value);
+            }
+        }
+        for (key, value) in &self.custom {
+            if is_xml_name(key) {
+                push_simple(&mut body, &format!("pdfx:{}", key), value);
+            }
+        }
+
+        let namespaces: String = NAMESPACES
+            .iter()
+            .map(|(prefix, uri)| format!("\n        xmlns:{}=\"{}\"", prefix, uri))
+            .collect();
+
+        format!(
+            "<?xpacket begin=\"\u{FEFF}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n\
+             <x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n  \
+             <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n    \
+             <rdf:Description rdf:about=\"\"{}>\n{}    </rdf:Description>\n  \
+             </rdf:RDF>\n</x:xmpmeta>\n<?xpacket end=\"w\"?>",
+            namespaces, body
+        )
+    }
+}
+
+fn push_simple(body: &mut String, name: &str, value: &str) {
+    body.push_str(&format!(
+        "      <{name}>{}</{name}>\n",
+        escape_xml(value),
+        name = name
+    ));
+}
+
+fn push_lang_alt(body: &mut String, name: &str, value: &str) {
+    body.push_str(&format!(
+        "      <{name}>\n        <rdf:Alt>\n          \
+         <rdf:li xml:lang=\"x-default\">{}</rdf:li>\n        \
+         </rdf:Alt>\n      </{name}>\n",
+        escape_xml(value),
+        name = name
+    ));
+}
+
+/// All values of a property: the `rdf:li` items of a container, or its single value
+fn property_values(xml: &str, name: &'static str) -> Vec<String> {
+    let (element, attribute) = property_regexes(name);
+
+    if let Some(caps) = element.captures(xml) {
+        let inner = &caps[1];
+        if inner.contains("<rdf:li") {
+            return li_regex()
+                .captures_iter(inner)
+                .map(|c| unescape_xml(c[1].trim()))
+                .collect();
+        }
+        return vec![unescape_xml(inner.trim())];
+    }
+
+    attribute
+        .captures(xml)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .map(|m| vec![unescape_xml(m.as_str())])
+        .unwrap_or_default()
+}
+
+/// Element and attribute regexes of a property, compiled once per name
+fn property_regexes(name: &'static str) -> (Regex, Regex) {
+    static CACHE: OnceLock<Mutex<HashMap<&'static str, (Regex, Regex)>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    cache
+        .entry(name)
+        .or_insert_with(|| {
+            let element = Regex::new(&format!(
+                r"(?s)<{name}(?:\s[^>]*)?>(.*?)</{name}>",
+                name = regex::escape(name)
+            ))
+            .expect("property regex is valid");
+            let attribute = Regex::new(&format!(
+                r#"[\s<]{}\s*=\s*(?:"([^"]*)"|'([^']*)')"#,
+                regex::escape(name)
+            ))
+            .expect("attribute regex is valid");
+            (element, attribute)
+        })
+        .clone()
+}
+
+fn first_value(xml: &str, name: &'static str) -> Option<String> {
+    property_values(xml, name)
+        .into_iter()
+        .next()
+        .filter(|v| !v.is_empty())
+}
+
+fn li_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?s)<rdf:li(?:\s[^>]*)?>(.*?)</rdf:li>").unwrap())
+}
+
+fn pdfx_element_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?s)<pdfx:([A-Za-z_][\w.-]*)(?:\s[^>]*)?>([^<]*)</pdfx:[\w.-]+>").unwrap()
+    })
+}
+
+fn pdfx_attribute_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#"\spdfx:([A-Za-z_][\w.-]*)\s*=\s*"([^"]*)""#).unwrap())
+}
+
+/// Whether a custom key can be used as an XML element name
+fn is_xml_name(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn unescape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                .ok()
+                .and_then(char::from_u32),
+            _ if entity.starts_with('#') => entity[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACROBAT_PACKET: &str = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:pdf="http://ns.adobe.com/pdf/1.3/"
+    xmlns:pdfx="http://ns.adobe.com/pdfx/1.3/"
+    xmp:CreatorTool="Word &amp; Friends"
+    pdf:Producer="Scanner 9000"
+    pdfx:Matter="2024-117">
+   <xmp:CreateDate>2024-01-15T09:30:00-05:00</xmp:CreateDate>
+   <dc:title><rdf:Alt><rdf:li xml:lang="x-default">Lease &lt;Draft&gt;</rdf:li></rdf:Alt></dc:title>
+   <dc:creator><rdf:Seq><rdf:li>Jane Doe</rdf:li><rdf:li>John Roe</rdf:li></rdf:Seq></dc:creator>
+   <xmpMM:History><rdf:Seq><rdf:li>saved</rdf:li></rdf:Seq></xmpMM:History>
+  </rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
+
+    #[test]
+    fn test_parse_element_and_attribute_forms() {
+        let xmp = XmpMetadata::parse(ACROBAT_PACKET);
+
+        assert_eq!(xmp.title.as_deref(), Some("Lease <Draft>"));
+        assert_eq!(xmp.creators, vec!["Jane Doe", "John Roe"]);
+        assert_eq!(xmp.creator_tool.as_deref(), Some("Word & Friends"));
+        assert_eq!(xmp.producer.as_deref(), Some("Scanner 9000"));
+        assert_eq!(
+            xmp.create_date.as_deref(),
+            Some("2024-01-15T09:30:00-05:00")
+        );
+        assert_eq!(
+            xmp.custom.get("Matter").map(String::as_str),
+            Some("2024-117")
+        );
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let mut xmp = XmpMetadata::parse(ACROBAT_PACKET);
+        xmp.keywords = Some("lease, florida".to_string());
+        xmp.pdfa_part = Some("2".to_string());
+
+        let reparsed = XmpMetadata::parse(&xmp.to_xml());
+        assert_eq!(reparsed, xmp);
+    }
+
+    #[test]
+    fn test_serialize_drops_history() {
+        let xml = XmpMetadata::parse(ACROBAT_PACKET).to_xml();
+        assert!(!xml.contains("History"));
+        assert!(xml.starts_with("<?xpacket begin="));
+        assert!(xml.ends_with("<?xpacket end=\"w\"?>"));
+    }
+
+    #[test]
+    fn test_invalid_custom_keys_are_skipped() {
+        let mut xmp = XmpMetadata::default();
+        xmp.custom
+            .insert("Case Number".to_string(), "1".to_string());
+        xmp.custom.insert("CaseNumber".to_string(), "2".to_string());
+
+        let xml = xmp.to_xml();
+        assert!(xml.contains("<pdfx:CaseNumber>2</pdfx:CaseNumber>"));
+        assert!(!xml.contains("Case Number"));
+    }
+
+    #[test]
+    fn test_unescape_numeric_entities() {
+        assert_eq!(unescape_xml("caf&#233; &#x2013; &bogus;"), "café – &bogus;");
+    }
+}
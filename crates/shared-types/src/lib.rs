@@ -3,4 +3,7 @@ pub mod crypto;
 pub mod pdf;
 pub mod types;
 
-pub use types::{ComplianceReport, LeaseDocument, Severity, TextPosition, Violation};
+pub use types::{
+    BoundingBox, ComplianceReport, Coverage, LeaseDocument, LocalCoverage, PageLayout,
+    PositionedRun, Remediation, Severity, TextPosition, Track, Violation,
+};
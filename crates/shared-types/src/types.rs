@@ -5,6 +5,33 @@ pub struct LeaseDocument {
     pub pages: u32,
     pub text_content: Vec<String>, // Per-page text
     pub created_at: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layout: Vec<PageLayout>, // Per-page positioned text, when the extractor provides it
+}
+
+/// Positioned text for one page
+///
+/// Each run covers a byte range of the page's entry in
+/// `LeaseDocument::text_content` and the box it occupies on the page.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PageLayout {
+    pub runs: Vec<PositionedRun>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PositionedRun {
+    pub start_offset: usize,
+    pub end_offset: usize,
+    pub bbox: BoundingBox,
+}
+
+/// Rectangle on a PDF page, in points with the origin at the bottom left
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BoundingBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -12,14 +39,78 @@ pub struct ComplianceReport {
     pub document_id: String,
     pub violations: Vec<Violation>,
     pub checked_at: u64,
+    #[serde(default)]
+    pub coverage: Coverage, // How much of the jurisdiction's law was checked
+    #[serde(default)]
+    pub track: Track, // Which body of law the document was checked under
+    #[serde(default)]
+    pub local_coverage: LocalCoverage, // Whether city and county ordinances were checked
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// Body of law a document was checked under, from its detected type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Track {
+    /// Residential landlord-tenant law
+    #[default]
+    Residential,
+    /// Commercial lease law; residential rules did not run
+    Commercial,
+    /// Real estate purchase, listing and escalation rules
+    RealEstate,
+}
+
+/// How much of a jurisdiction's law a compliance check covered
+///
+/// An empty report only means "compliant" when coverage is not `Unavailable`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Coverage {
+    /// All statutory rules implemented for the jurisdiction were checked
+    #[default]
+    Full,
+    /// Only core statutory limits were checked: deposits, notice periods,
+    /// entry notice, late fees and required disclosures
+    Core,
+    /// No rules exist for the jurisdiction and document type
+    Unavailable { reason: String },
+}
+
+/// Whether a compliance check covered city and county ordinances
+///
+/// `Unknown` and `NoOrdinance` both mean no local violations were reported,
+/// but only `NoOrdinance` means there is no local law to check.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LocalCoverage {
+    /// The property's city or county has ordinances, and they were checked
+    Checked,
+    /// The property's city and county are known and have no ordinances the
+    /// local layer checks
+    NoOrdinance,
+    /// The city and county could not be resolved (no ZIP code, or one outside
+    /// the locality table), so local ordinances were not checked
+    #[default]
+    Unknown,
+    /// No local layer runs for the document's type
+    NotApplicable,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct TextPosition {
     pub start_offset: usize, // Character offset in the page text
     pub end_offset: usize,   // End character offset
 }
 
+impl TextPosition {
+    pub const fn new(start_offset: usize, end_offset: usize) -> Self {
+        Self {
+            start_offset,
+            end_offset,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Violation {
     pub statute: String, // e.g., "83.47(1)(a)"
@@ -28,6 +119,25 @@ pub struct Violation {
     pub page: Option<u32>,
     pub text_snippet: Option<String>,
     pub text_position: Option<TextPosition>, // Position for highlighting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Remediation>, // Suggested fix, if one is known
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bounding_boxes: Vec<BoundingBox>, // Boxes for text_position, one per line, when layout is known
+}
+
+/// Suggested fix for a violation
+///
+/// `clause` is model text that may contain `{{variable}}` placeholders, listed
+/// in `variables`, to be filled in before the clause is applied. When
+/// `replace` is set the clause replaces that span of the checked text;
+/// otherwise the clause is missing from the document and should be added.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Remediation {
+    pub clause: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<String>,
+    pub replace: Option<TextPosition>,
+    pub explanation: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
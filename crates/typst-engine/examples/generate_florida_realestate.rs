@@ -146,6 +146,7 @@ fn generate_purchase_contract(output_dir: &std::path::Path) {
         assets: HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     let response = compile_document_sync(request).expect("Compilation failed");
@@ -214,6 +215,7 @@ fn generate_escalation_addendum(output_dir: &std::path::Path) {
         assets: HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     let response = compile_document_sync(request).expect("Compilation failed");
@@ -337,6 +339,7 @@ fn generate_listing_agreement(output_dir: &std::path::Path) {
         assets: HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     let response = compile_document_sync(request).expect("Compilation failed");
@@ -85,6 +85,7 @@ fn verify_purchase_contract() -> bool {
         assets: HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     match compile_document_sync(request) {
@@ -140,6 +141,7 @@ fn verify_escalation_addendum() -> bool {
         assets: HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     match compile_document_sync(request) {
@@ -200,6 +202,7 @@ fn verify_listing_agreement() -> bool {
         assets: HashMap::new(),
         format: OutputFormat::Pdf,
         ppi: None,
+        ..Default::default()
     };
 
     match compile_document_sync(request) {
@@ -0,0 +1,692 @@
+//! Accessibility checks for any PDF
+//!
+//! The checks follow what Section 508 and PDF/UA reviewers look at first:
+//! a structure tree covering all content and link annotations, alt text on
+//! figures, headings that don't skip levels, table rows and cells inside a
+//! table, and the document language and title. Passing them does not make
+//! a PDF conform to PDF/UA; that takes a full validator such as veraPDF.
+
+use std::collections::{HashMap, HashSet};
+
+use lopdf::content::{Content, Operation};
+use lopdf::{Dictionary, Object, ObjectId};
+use serde::{Deserialize, Serialize};
+
+use super::AccessibilityError;
+
+/// Operators that put text, images or painted paths on the page
+const CONTENT_OPERATORS: &[&str] = &[
+    "Tj", "TJ", "'", "\"", "Do", "sh", "BI", "S", "s", "f", "F", "f*", "B", "B*", "b", "b*",
+];
+
+/// Nesting limit for the structure tree, against malformed files
+const MAX_STRUCTURE_DEPTH: usize = 256;
+
+/// Kind of accessibility problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// No structure tree, or content that is neither tagged nor an artifact
+    UntaggedContent,
+    /// A figure or image without alt text
+    MissingAltText,
+    /// A heading more than one level below the previous heading
+    HeadingSkip,
+    /// A table row, cell or row group outside the table element it belongs
+    /// in
+    TableStructure,
+    /// No document language in the catalog
+    MissingLanguage,
+    /// No title in the metadata, or viewers aren't told to show it
+    MissingTitle,
+}
+
+/// One accessibility problem found in a PDF
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessibilityIssue {
+    pub kind: IssueKind,
+    /// One-indexed page, for problems on a specific page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+    pub message: String,
+}
+
+/// Result of checking a PDF for accessibility
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessibilityReport {
+    /// Whether the document is marked as tagged and has a structure tree
+    pub tagged: bool,
+    /// Document language from the catalog
+    pub language: Option<String>,
+    /// Document title from the metadata
+    pub title: Option<String>,
+    pub issues: Vec<AccessibilityIssue>,
+}
+
+impl AccessibilityReport {
+    /// Whether no issues were found
+    pub fn passes(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Issues of one kind
+    pub fn issues_of(&self, kind: IssueKind) -> impl Iterator<Item = &AccessibilityIssue> {
+        self.issues.iter().filter(move |issue| issue.kind == kind)
+    }
+}
+
+/// Check a PDF for missing tags, alt text, heading structure, language
+/// and title
+pub fn check_accessibility(pdf: &[u8]) -> Result<AccessibilityReport, AccessibilityError> {
+    let pdf = lopdf::Document::load_mem(pdf)?;
+    let catalog = pdf.catalog()?;
+    let pages = pdf.get_pages();
+    let page_numbers: HashMap<ObjectId, usize> = pages
+        .iter()
+        .map(|(&number, &id)| (id, number as usize))
+        .collect();
+
+    let mut issues = Vec::new();
+
+    let structure_root = catalog
+        .get(b"StructTreeRoot")
+        .ok()
+        .and_then(|root| dictionary(&pdf, root));
+    let marked = catalog
+        .get(b"MarkInfo")
+        .ok()
+        .and_then(|info| dictionary(&pdf, info))
+        .and_then(|info| info.get(b"Marked").ok())
+        .and_then(|marked| marked.as_bool().ok())
+        .unwrap_or(false);
+    let tagged = marked && structure_root.is_some();
+    if !tagged {
+        issues.push(AccessibilityIssue {
+            kind: IssueKind::UntaggedContent,
+            page: None,
+            message: "The document is not tagged, so assistive technology cannot tell \
+                      headings, paragraphs and figures apart or follow the reading order"
+                .to_string(),
+        });
+    }
+
+    for (&number, &page_id) in &pages {
+        let content = Content::decode(&pdf.get_page_content(page_id)?)?;
+        let scan = scan_content(&content.operations, &image_names(&pdf, page_id));
+        let untagged_links = untagged_links(&pdf, page_id);
+        if tagged && untagged_links > 0 {
+            issues.push(AccessibilityIssue {
+                kind: IssueKind::UntaggedContent,
+                page: Some(number as usize),
+                message: format!(
+                    "{} link annotation(s) are not in the structure tree",
+                    untagged_links
+                ),
+            });
+        }
+        if tagged && scan.untagged > 0 {
+            issues.push(AccessibilityIssue {
+                kind: IssueKind::UntaggedContent,
+                page: Some(number as usize),
+                message: format!(
+                    "{} content operation(s) are neither tagged nor marked as artifacts",
+                    scan.untagged
+                ),
+            });
+        }
+        if !tagged && scan.images_without_alt > 0 {
+            issues.push(AccessibilityIssue {
+                kind: IssueKind::MissingAltText,
+                page: Some(number as usize),
+                message: format!("{} image(s) have no alt text", scan.images_without_alt),
+            });
+        }
+    }
+
+    if let Some(root) = structure_root {
+        let role_map = root
+            .get(b"RoleMap")
+            .ok()
+            .and_then(|map| dictionary(&pdf, map));
+        let mut walker = StructureWalker {
+            pdf: &pdf,
+            role_map,
+            page_numbers: &page_numbers,
+            visited: HashSet::new(),
+            elements: Vec::new(),
+        };
+        if let Ok(kids) = root.get(b"K") {
+            walker.walk(kids, None, 0);
+        }
+        check_structure(&walker.elements, &mut issues);
+    }
+
+    let language = catalog
+        .get(b"Lang")
+        .ok()
+        .and_then(|lang| pdf.dereference(lang).ok())
+        .and_then(|(_, lang)| lang.as_str().ok())
+        .map(decode_text)
+        .filter(|lang| !lang.trim().is_empty());
+    if language.is_none() {
+        issues.push(AccessibilityIssue {
+            kind: IssueKind::MissingLanguage,
+            page: None,
+            message: "The document language is not set, so screen readers may use the \
+                      wrong pronunciation"
+                .to_string(),
+        });
+    }
+
+    let title = info_title(&pdf).or_else(|| xmp_title(&pdf, catalog));
+    let displays_title = catalog
+        .get(b"ViewerPreferences")
+        .ok()
+        .and_then(|preferences| dictionary(&pdf, preferences))
+        .and_then(|preferences| preferences.get(b"DisplayDocTitle").ok())
+        .and_then(|display| display.as_bool().ok())
+        .unwrap_or(false);
+    if title.is_none() {
+        issues.push(AccessibilityIssue {
+            kind: IssueKind::MissingTitle,
+            page: None,
+            message: "The document has no title in its metadata".to_string(),
+        });
+    } else if !displays_title {
+        issues.push(AccessibilityIssue {
+            kind: IssueKind::MissingTitle,
+            page: None,
+            message: "Viewers are not told to show the document title (DisplayDocTitle) \
+                      instead of the file name"
+                .to_string(),
+        });
+    }
+
+    Ok(AccessibilityReport {
+        tagged,
+        language,
+        title,
+        issues,
+    })
+}
+
+/// Counts from one page's content stream
+#[derive(Debug, Default)]
+struct ContentScan {
+    /// Content operations outside tagged content and artifacts
+    untagged: usize,
+    /// Image XObjects painted outside marked content with alt text
+    images_without_alt: usize,
+}
+
+fn scan_content(operations: &[Operation], images: &HashSet<Vec<u8>>) -> ContentScan {
+    let mut scan = ContentScan::default();
+    // For each open marked-content sequence: (tagged or artifact, has alt)
+    let mut stack: Vec<(bool, bool)> = Vec::new();
+
+    for operation in operations {
+        let (tagged, has_alt) = stack.last().copied().unwrap_or((false, false));
+        match operation.operator.as_str() {
+            "BMC" | "BDC" => {
+                let is_artifact = operation
+                    .operands
+                    .first()
+                    .and_then(|tag| tag.as_name().ok())
+                    == Some(b"Artifact".as_slice());
+                let properties = operation
+                    .operands
+                    .get(1)
+                    .and_then(|properties| properties.as_dict().ok());
+                let has_mcid = properties.is_some_and(|p| p.has(b"MCID"));
+                let alt = properties.is_some_and(|p| p.has(b"Alt") || p.has(b"ActualText"));
+                stack.push((tagged || is_artifact || has_mcid, has_alt || alt));
+            }
+            "EMC" => {
+                stack.pop();
+            }
+            operator if CONTENT_OPERATORS.contains(&operator) => {
+                if !tagged {
+                    scan.untagged += 1;
+                }
+                let is_image = operator == "Do"
+                    && operation
+                        .operands
+                        .first()
+                        .and_then(|name| name.as_name().ok())
+                        .is_some_and(|name| images.contains(name));
+                if is_image && !has_alt {
+                    scan.images_without_alt += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    scan
+}
+
+/// Number of the page's link annotations without a `StructParent`, which
+/// ties an annotation to its `Link` element
+fn untagged_links(pdf: &lopdf::Document, page_id: ObjectId) -> usize {
+    let Some(annotations) = pdf
+        .get_dictionary(page_id)
+        .ok()
+        .and_then(|page| page.get(b"Annots").ok())
+        .and_then(|annotations| pdf.dereference(annotations).ok())
+        .and_then(|(_, annotations)| annotations.as_array().ok())
+    else {
+        return 0;
+    };
+    annotations
+        .iter()
+        .filter_map(|annotation| dictionary(pdf, annotation))
+        .filter(|annotation| {
+            annotation.get(b"Subtype").and_then(Object::as_name).ok() == Some(b"Link".as_slice())
+                && !annotation.has(b"StructParent")
+        })
+        .count()
+}
+
+/// Names of the image XObjects in a page's resources
+fn image_names(pdf: &lopdf::Document, page_id: ObjectId) -> HashSet<Vec<u8>> {
+    let (resources, resource_ids) = pdf.get_page_resources(page_id);
+    let resources = resources.into_iter().chain(
+        resource_ids
+            .into_iter()
+            .filter_map(|id| pdf.get_dictionary(id).ok()),
+    );
+
+    let mut names = HashSet::new();
+    for resources in resources {
+        let Some(xobjects) = resources
+            .get(b"XObject")
+            .ok()
+            .and_then(|xobjects| dictionary(pdf, xobjects))
+        else {
+            continue;
+        };
+        for (name, xobject) in xobjects.iter() {
+            let is_image = pdf
+                .dereference(xobject)
+                .ok()
+                .and_then(|(_, xobject)| xobject.as_stream().ok())
+                .and_then(|stream| stream.dict.get(b"Subtype").ok())
+                .and_then(|subtype| subtype.as_name().ok())
+                == Some(b"Image".as_slice());
+            if is_image {
+                names.insert(name.clone());
+            }
+        }
+    }
+    names
+}
+
+/// A structure element, in document order
+struct StructureElement {
+    /// Standard role, after the role map
+    role: String,
+    /// Standard role of the parent element; `None` under the root
+    parent: Option<String>,
+    page: Option<usize>,
+    has_alt: bool,
+}
+
+struct StructureWalker<'a> {
+    pdf: &'a lopdf::Document,
+    role_map: Option<&'a Dictionary>,
+    page_numbers: &'a HashMap<ObjectId, usize>,
+    visited: HashSet<ObjectId>,
+    elements: Vec<StructureElement>,
+}
+
+impl<'a> StructureWalker<'a> {
+    fn walk(&mut self, object: &'a Object, parent: Option<&str>, depth: usize) {
+        if depth > MAX_STRUCTURE_DEPTH {
+            return;
+        }
+        if let Object::Reference(id) = object {
+            if !self.visited.insert(*id) {
+                return;
+            }
+        }
+        let Ok((_, object)) = self.pdf.dereference(object) else {
+            return;
+        };
+        match object {
+            Object::Array(kids) => {
+                for kid in kids {
+                    self.walk(kid, parent, depth + 1);
+                }
+            }
+            Object::Dictionary(element) => {
+                // Marked-content and object references are leaves
+                let Ok(role) = element.get(b"S").and_then(Object::as_name) else {
+                    return;
+                };
+                let page = element
+                    .get(b"Pg")
+                    .and_then(Object::as_reference)
+                    .ok()
+                    .and_then(|page| self.page_numbers.get(&page).copied());
+                let role = self.standard_role(role);
+                self.elements.push(StructureElement {
+                    role: role.clone(),
+                    parent: parent.map(str::to_string),
+                    page,
+                    has_alt: element.has(b"Alt") || element.has(b"ActualText"),
+                });
+                if let Ok(kids) = element.get(b"K") {
+                    self.walk(kids, Some(&role), depth + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Follow the role map from a custom role to a standard one
+    fn standard_role(&self, role: &[u8]) -> String {
+        let mut role = role;
+        for _ in 0..8 {
+            match self
+                .role_map
+                .and_then(|map| map.get(role).ok())
+                .and_then(|mapped| mapped.as_name().ok())
+            {
+                Some(mapped) if mapped != role => role = mapped,
+                _ => break,
+            }
+        }
+        String::from_utf8_lossy(role).into_owned()
+    }
+}
+
+/// Figures without alt text, skipped heading levels and misplaced table
+/// elements
+fn check_structure(elements: &[StructureElement], issues: &mut Vec<AccessibilityIssue>) {
+    let mut previous_level = 0;
+    for element in elements {
+        if let Some(parents) = table_parents(&element.role) {
+            let parent = element.parent.as_deref();
+            if !parent.is_some_and(|parent| parents.contains(&parent)) {
+                issues.push(AccessibilityIssue {
+                    kind: IssueKind::TableStructure,
+                    page: element.page,
+                    message: format!(
+                        "A {} element is in {} rather than in {}",
+                        element.role,
+                        parent.unwrap_or("the structure root"),
+                        parents.join(" or ")
+                    ),
+                });
+            }
+        }
+
+        if element.role == "Figure" && !element.has_alt {
+            issues.push(AccessibilityIssue {
+                kind: IssueKind::MissingAltText,
+                page: element.page,
+                message: "A figure has no alt text".to_string(),
+            });
+        }
+
+        let Some(level) = heading_level(&element.role) else {
+            continue;
+        };
+        if level > previous_level + 1 {
+            let message = if previous_level == 0 {
+                format!("The first heading is H{} rather than H1", level)
+            } else {
+                format!(
+                    "H{} follows H{} without an H{} in between",
+                    level,
+                    previous_level,
+                    previous_level + 1
+                )
+            };
+            issues.push(AccessibilityIssue {
+                kind: IssueKind::HeadingSkip,
+                page: element.page,
+                message,
+            });
+        }
+        previous_level = level;
+    }
+}
+
+/// Roles a table element may be a child of
+fn table_parents(role: &str) -> Option<&'static [&'static str]> {
+    match role {
+        "TR" => Some(&["Table", "THead", "TBody", "TFoot"]),
+        "TH" | "TD" => Some(&["TR"]),
+        "THead" | "TBody" | "TFoot" => Some(&["Table"]),
+        _ => None,
+    }
+}
+
+fn heading_level(role: &str) -> Option<usize> {
+    let level = role.strip_prefix('H')?.parse().ok()?;
+    (1..=6).contains(&level).then_some(level)
+}
+
+fn dictionary<'a>(pdf: &'a lopdf::Document, object: &'a Object) -> Option<&'a Dictionary> {
+    pdf.dereference(object)
+        .ok()
+        .and_then(|(_, object)| object.as_dict().ok())
+}
+
+/// Title from the document information dictionary
+fn info_title(pdf: &lopdf::Document) -> Option<String> {
+    let info = dictionary(pdf, pdf.trailer.get(b"Info").ok()?)?;
+    let (_, title) = pdf.dereference(info.get(b"Title").ok()?).ok()?;
+    Some(decode_text(title.as_str().ok()?)).filter(|title| !title.trim().is_empty())
+}
+
+/// Title from the XMP metadata (`dc:title`)
+fn xmp_title(pdf: &lopdf::Document, catalog: &Dictionary) -> Option<String> {
+    let (_, metadata) = pdf.dereference(catalog.get(b"Metadata").ok()?).ok()?;
+    let stream = metadata.as_stream().ok()?;
+    let xml = stream
+        .decompressed_content()
+        .unwrap_or_else(|_| stream.content.clone());
+    let xml = String::from_utf8_lossy(&xml);
+    let title = &xml[xml.find("<dc:title>")?..];
+    let item = &title[title.find("<rdf:li")?..];
+    let text = &item[item.find('>')? + 1..item.find("</rdf:li>")?];
+    Some(text.trim().to_string()).filter(|title| !title.is_empty())
+}
+
+/// Decode a PDF text string: UTF-16BE with a byte order mark, otherwise
+/// PDFDocEncoding, which matches Latin-1 for printable text
+fn decode_text(bytes: &[u8]) -> String {
+    match bytes {
+        [0xFE, 0xFF, rest @ ..] => {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => bytes.iter().map(|&byte| byte as char).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::{compile_document_sync, RenderRequest};
+    use base64::Engine;
+    use std::collections::HashMap;
+
+    /// A 1x1 PNG
+    const PIXEL: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+    fn render(source: &str, tagged: bool) -> Vec<u8> {
+        let response = compile_document_sync(RenderRequest {
+            source: source.to_string(),
+            assets: HashMap::from([("pixel.png".to_string(), PIXEL.to_string())]),
+            tagged,
+            ..Default::default()
+        })
+        .unwrap();
+        base64::engine::general_purpose::STANDARD
+            .decode(response.artifact.unwrap().data_base64)
+            .unwrap()
+    }
+
+    fn kinds(report: &AccessibilityReport) -> Vec<IssueKind> {
+        report.issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn test_untagged_pdf() {
+        let pdf = render("= Notice\n\n#image(\"pixel.png\", width: 10pt)", false);
+        let report = check_accessibility(&pdf).unwrap();
+
+        assert!(!report.tagged);
+        assert_eq!(report.language.as_deref(), Some("en"));
+        assert_eq!(report.title, None);
+        assert_eq!(
+            kinds(&report),
+            [
+                IssueKind::UntaggedContent,
+                IssueKind::MissingAltText,
+                IssueKind::MissingTitle
+            ]
+        );
+        assert_eq!(report.issues[0].page, None);
+        assert_eq!(report.issues[1].page, Some(1));
+    }
+
+    #[test]
+    fn test_untagged_image_with_alt_text() {
+        let pdf = render(
+            "#set document(title: \"Notice\")\n#image(\"pixel.png\", width: 10pt, alt: \"Logo\")",
+            false,
+        );
+        let report = check_accessibility(&pdf).unwrap();
+
+        assert_eq!(report.issues_of(IssueKind::MissingAltText).count(), 0);
+        assert_eq!(report.title.as_deref(), Some("Notice"));
+    }
+
+    #[test]
+    fn test_tagged_figure_without_alt_text() {
+        let pdf = render(
+            "#set document(title: \"Notice\")\nText\n#pagebreak()\n#image(\"pixel.png\", width: 10pt)",
+            true,
+        );
+        let report = check_accessibility(&pdf).unwrap();
+
+        assert!(report.tagged);
+        assert_eq!(kinds(&report), [IssueKind::MissingAltText]);
+        assert_eq!(report.issues[0].page, Some(2));
+    }
+
+    #[test]
+    fn test_heading_skips() {
+        let pdf = render(
+            "#set document(title: \"Notice\")\n=== Late start\n= Title\n=== Detail\n== Section",
+            true,
+        );
+        let report = check_accessibility(&pdf).unwrap();
+
+        let messages: Vec<_> = report
+            .issues_of(IssueKind::HeadingSkip)
+            .map(|issue| issue.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "The first heading is H3 rather than H1",
+                "H3 follows H1 without an H2 in between"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_untagged_content_in_tagged_pdf() {
+        let tagged = render("#set document(title: \"Notice\")\nText", true);
+        let mut pdf = lopdf::Document::load_mem(&tagged).unwrap();
+        let page = *pdf.get_pages().get(&1).unwrap();
+        let mut content = pdf.get_page_content(page).unwrap();
+        content.extend_from_slice(b"\n0 0 10 10 re f");
+        pdf.change_page_content(page, content).unwrap();
+        let mut buffer = Vec::new();
+        pdf.save_to(&mut buffer).unwrap();
+
+        let report = check_accessibility(&buffer).unwrap();
+        assert_eq!(kinds(&report), [IssueKind::UntaggedContent]);
+        assert_eq!(report.issues[0].page, Some(1));
+    }
+
+    /// Change a tagged PDF in place and save it again
+    fn edit(pdf: &[u8], change: impl FnOnce(&mut lopdf::Document)) -> Vec<u8> {
+        let mut pdf = lopdf::Document::load_mem(pdf).unwrap();
+        change(&mut pdf);
+        let mut buffer = Vec::new();
+        pdf.save_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn test_table_cells_outside_a_row() {
+        let tagged = render(
+            "#set document(title: \"Rent\")\n#table(columns: 2, [Rent], [\\$1,500])",
+            true,
+        );
+        assert!(check_accessibility(&tagged).unwrap().passes());
+
+        let pdf = edit(&tagged, |pdf| {
+            for object in pdf.objects.values_mut() {
+                if let Ok(element) = object.as_dict_mut() {
+                    if element.get(b"S").and_then(Object::as_name).ok() == Some(b"TR".as_slice()) {
+                        element.set("S", "Sect");
+                    }
+                }
+            }
+        });
+        let report = check_accessibility(&pdf).unwrap();
+        let messages: Vec<_> = report
+            .issues_of(IssueKind::TableStructure)
+            .map(|issue| issue.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "A TD element is in Sect rather than in TR",
+                "A TD element is in Sect rather than in TR"
+            ]
+        );
+        assert_eq!(report.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_untagged_link_annotation() {
+        let tagged = render(
+            "#set document(title: \"Links\")\nSee #link(\"https://example.com\")[the site].",
+            true,
+        );
+        assert!(check_accessibility(&tagged).unwrap().passes());
+
+        let pdf = edit(&tagged, |pdf| {
+            for object in pdf.objects.values_mut() {
+                if let Ok(annotation) = object.as_dict_mut() {
+                    annotation.remove(b"StructParent");
+                }
+            }
+        });
+        let report = check_accessibility(&pdf).unwrap();
+        assert_eq!(kinds(&report), [IssueKind::UntaggedContent]);
+        assert_eq!(
+            report.issues[0].message,
+            "1 link annotation(s) are not in the structure tree"
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_pdf() {
+        assert!(matches!(
+            check_accessibility(b"not a pdf"),
+            Err(AccessibilityError::InvalidPdf(_))
+        ));
+    }
+}
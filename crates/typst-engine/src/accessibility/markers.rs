@@ -0,0 +1,260 @@
+//! Table markers for the tagger
+//!
+//! Tables and their cells are not locatable, so nothing in the laid-out
+//! frames says which text belongs to which cell: typst inlines small cell
+//! frames into their row. Tagged renders add two built-in show rules that
+//! put labelled `metadata` around every table and cell. Metadata is
+//! locatable, so each marker shows up as a [`Tag`](typst::introspection::Tag)
+//! in frame order, and [`Marker::from_content`] reads it back.
+
+use comemo::Tracked;
+use once_cell::sync::Lazy;
+use typst::diag::SourceResult;
+use typst::engine::Engine;
+use typst::foundations::{
+    Args, CastInfo, Content, Context, Dict, Func, IntoValue, Label, NativeElement, NativeFuncData,
+    Packed, Recipe, Scope, Selector, Style, StyleChain, Styles, Transformation, Value,
+};
+use typst::introspection::MetadataElem;
+use typst::model::{TableCell, TableChild, TableElem, TableItem};
+use typst::syntax::Span;
+
+/// Label of the markers, so they can't be mistaken for a template's own
+/// metadata
+const MARKER_LABEL: &str = "agentpdf-structure";
+
+/// Structure boundary recorded in the frames of a tagged render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Marker {
+    /// Start of a table whose first `header_rows` rows are its header
+    Table {
+        header_rows: usize,
+    },
+    TableEnd,
+    /// Start of the cell at column `x` and row `y`
+    Cell {
+        x: usize,
+        y: usize,
+    },
+    CellEnd,
+}
+
+impl Marker {
+    /// The marker a tag's element stands for, if it is one
+    pub(super) fn from_content(content: &Content) -> Option<Marker> {
+        if content.label() != Some(Label::new(MARKER_LABEL)) {
+            return None;
+        }
+        let Value::Dict(dict) = &content.to_packed::<MetadataElem>()?.value else {
+            return None;
+        };
+        let number = |key: &str| match dict.get(key) {
+            Ok(Value::Int(value)) => usize::try_from(*value).ok(),
+            _ => None,
+        };
+        match dict.get("kind").ok()? {
+            Value::Str(kind) => match kind.as_str() {
+                "table" => Some(Marker::Table {
+                    header_rows: number("header-rows")?,
+                }),
+                "table-end" => Some(Marker::TableEnd),
+                "cell" => Some(Marker::Cell {
+                    x: number("x")?,
+                    y: number("y")?,
+                }),
+                "cell-end" => Some(Marker::CellEnd),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn into_content(self) -> Content {
+        let mut dict = Dict::new();
+        let kind = match self {
+            Marker::Table { header_rows } => {
+                dict.insert("header-rows".into(), (header_rows as i64).into_value());
+                "table"
+            }
+            Marker::TableEnd => "table-end",
+            Marker::Cell { x, y } => {
+                dict.insert("x".into(), (x as i64).into_value());
+                dict.insert("y".into(), (y as i64).into_value());
+                "cell"
+            }
+            Marker::CellEnd => "cell-end",
+        };
+        dict.insert("kind".into(), kind.into_value());
+        MetadataElem::new(Value::Dict(dict))
+            .pack()
+            .labelled(Label::new(MARKER_LABEL))
+    }
+}
+
+/// Show rules that mark tables and cells, for the library of a tagged
+/// render
+pub(crate) fn structure_markers() -> Styles {
+    let mut styles = Styles::new();
+    for (elem, func) in [
+        (TableElem::elem(), &MARK_TABLE),
+        (TableCell::elem(), &MARK_CELL),
+    ] {
+        styles.apply_one(Style::from(Recipe::new(
+            Some(Selector::Elem(elem, None)),
+            Transformation::Func(Func::from(func)),
+            Span::detached(),
+        )));
+    }
+    styles
+}
+
+static MARK_TABLE: NativeFuncData = marker_func("mark-table", "Mark Table", mark_table);
+static MARK_CELL: NativeFuncData = marker_func("mark-cell", "Mark Cell", mark_cell);
+
+const fn marker_func(
+    name: &'static str,
+    title: &'static str,
+    function: fn(&mut Engine, Tracked<Context>, &mut Args) -> SourceResult<Value>,
+) -> NativeFuncData {
+    NativeFuncData {
+        function,
+        name,
+        title,
+        docs: "",
+        keywords: &[],
+        contextual: false,
+        scope: Lazy::new(Scope::new),
+        params: Lazy::new(Vec::new),
+        returns: Lazy::new(|| CastInfo::Any),
+    }
+}
+
+/// `show table`: a start marker with the number of header rows, the table,
+/// and an end marker
+fn mark_table(_: &mut Engine, context: Tracked<Context>, args: &mut Args) -> SourceResult<Value> {
+    let content: Content = args.expect("it")?;
+    args.take().finish()?;
+    let header_rows = match (content.to_packed::<TableElem>(), context.styles()) {
+        (Some(table), Ok(styles)) => header_rows(table, styles),
+        _ => 0,
+    };
+    Ok(Value::Content(Content::sequence([
+        Marker::Table { header_rows }.into_content(),
+        content,
+        Marker::TableEnd.into_content(),
+    ])))
+}
+
+/// `show table.cell`: the cell between a start marker with its position
+/// and an end marker
+fn mark_cell(_: &mut Engine, context: Tracked<Context>, args: &mut Args) -> SourceResult<Value> {
+    let content: Content = args.expect("it")?;
+    args.take().finish()?;
+    let (Some(cell), Ok(styles)) = (content.to_packed::<TableCell>(), context.styles()) else {
+        return Ok(Value::Content(content));
+    };
+    let (x, y) = (
+        cell.x(styles).custom().unwrap_or(0),
+        cell.y(styles).custom().unwrap_or(0),
+    );
+    Ok(Value::Content(Content::sequence([
+        Marker::Cell { x, y }.into_content(),
+        content,
+        Marker::CellEnd.into_content(),
+    ])))
+}
+
+/// Rows taken up by the table's header, counting the header's cells and
+/// their column spans against the number of columns (row spans inside a
+/// header are not accounted for)
+fn header_rows(table: &Packed<TableElem>, styles: StyleChain) -> usize {
+    let columns = table.columns(styles).0.len().max(1);
+    let spans: usize = table
+        .children()
+        .iter()
+        .filter_map(|child| match child {
+            TableChild::Header(header) => Some(header.children()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|item| match item {
+            TableItem::Cell(cell) => Some(cell.colspan(styles).get()),
+            _ => None,
+        })
+        .sum();
+    spans.div_ceil(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::VirtualWorld;
+    use std::collections::HashMap;
+    use typst::layout::{Frame, FrameItem, Point};
+
+    /// Text of every text run with its position on the page
+    fn texts(frame: &Frame, offset: Point, out: &mut Vec<(String, Point)>) {
+        for (pos, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => texts(&group.frame, offset + *pos, out),
+                FrameItem::Text(text) => out.push((text.text.to_string(), offset + *pos)),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_markers_keep_the_layout() {
+        let source = r#"Before
+#table(
+  columns: (1fr, auto),
+  table.header([*Item*], [*Amount*]),
+  [Rent], [\$1,500],
+  table.cell(colspan: 2)[Due on the first],
+)
+After"#;
+        let layout = |tagged: bool| {
+            let world = VirtualWorld::new(source.to_string(), HashMap::new(), HashMap::new())
+                .unwrap()
+                .with_structure_markers(tagged);
+            let document = typst::compile(&world).output.unwrap();
+            let mut out = Vec::new();
+            texts(&document.pages[0].frame, Point::zero(), &mut out);
+            out
+        };
+
+        let marked = layout(true);
+        assert_eq!(marked.len(), 7);
+        assert_eq!(marked, layout(false));
+    }
+
+    #[test]
+    fn test_header_rows() {
+        let world = VirtualWorld::new(
+            "#table(columns: 2, table.header([A], [B], table.cell(colspan: 2)[C]), [1], [2])"
+                .to_string(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap()
+        .with_structure_markers(true);
+        let document = typst::compile(&world).output.unwrap();
+
+        let mut markers = Vec::new();
+        let mut frames = vec![&document.pages[0].frame];
+        while let Some(frame) = frames.pop() {
+            for (_, item) in frame.items() {
+                match item {
+                    FrameItem::Group(group) => frames.push(&group.frame),
+                    FrameItem::Tag(typst::introspection::Tag::Start(content)) => {
+                        markers.extend(Marker::from_content(content))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        assert!(markers.contains(&Marker::Table { header_rows: 2 }));
+        assert!(markers.contains(&Marker::Cell { x: 0, y: 2 }));
+        assert_eq!(markers.iter().filter(|m| **m == Marker::CellEnd).count(), 5);
+    }
+}
@@ -0,0 +1,38 @@
+//! Accessible PDF output and accessibility checks
+//!
+//! typst-pdf writes untagged PDFs. [`tag_pdf`] adds the structure tree,
+//! reading order, figure alt text and viewer settings that Section 508
+//! reviewers and screen readers rely on, driven by the compiled document's
+//! frames. [`check_accessibility`] inspects any PDF, tagged by us or not,
+//! and reports what a screen reader would trip over.
+//!
+//! Neither makes or proves a PDF/UA document. The tagged output has no
+//! PDF/UA identifier and has not been validated with veraPDF or another
+//! PDF/UA validator; it covers the structure the checker looks at and
+//! nothing more.
+
+pub mod checker;
+mod markers;
+pub mod tagging;
+
+pub use checker::{check_accessibility, AccessibilityIssue, AccessibilityReport, IssueKind};
+pub(crate) use markers::structure_markers;
+pub use tagging::tag_pdf;
+
+use thiserror::Error;
+
+/// Errors that can occur while tagging or checking a PDF
+#[derive(Error, Debug)]
+pub enum AccessibilityError {
+    #[error("Invalid PDF: {0}")]
+    InvalidPdf(String),
+
+    #[error("PDF tagging failed: {0}")]
+    TaggingError(String),
+}
+
+impl From<lopdf::Error> for AccessibilityError {
+    fn from(error: lopdf::Error) -> Self {
+        AccessibilityError::InvalidPdf(error.to_string())
+    }
+}
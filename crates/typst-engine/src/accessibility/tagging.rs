@@ -0,0 +1,1103 @@
+//! Structure tagging for typst-pdf output
+//!
+//! typst-pdf writes one text object (`BT` .. `ET`) per text run, one `Do`
+//! per image and one link annotation per link, in frame order. Walking the
+//! same frames tells us what each of them is: heading text (inside a
+//! heading's introspection tags), body text, text in a table cell (between
+//! the [markers](super::markers) of a tagged render), link text (under a
+//! link's area), or an image with its alt text. Each page's content stream
+//! is then rewritten so those operations carry an MCID and everything else
+//! (rules, table borders, backgrounds) is an artifact, and a structure tree
+//! is built over them in reading order.
+//!
+//! The result is a tagged PDF, not a PDF/UA one: there is no PDF/UA
+//! identifier in the metadata, and nothing here checks fonts, color
+//! contrast or the other PDF/UA requirements a validator such as veraPDF
+//! would.
+
+use std::collections::{HashMap, VecDeque};
+
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Dictionary, Object, ObjectId, StringFormat};
+use typst::foundations::StyleChain;
+use typst::introspection::{Location, Tag};
+use typst::layout::{Frame, FrameItem, Point, Size, Transform};
+use typst::model::{Document, HeadingElem};
+use typst::text::color::should_outline;
+use typst::text::TextItem;
+
+use super::markers::Marker;
+use super::AccessibilityError;
+
+/// Operators that start or extend a path
+const PATH_CONSTRUCTION: &[&str] = &["m", "l", "c", "v", "y", "h", "re"];
+
+/// Operators that end a path, painting it or not
+const PATH_PAINTING: &[&str] = &["S", "s", "f", "F", "f*", "B", "B*", "b", "b*", "n"];
+
+/// Body text runs this many font sizes below the previous run start a new
+/// paragraph; line spacing in the templates stays well under it, paragraph
+/// spacing well over it
+const PARAGRAPH_GAP: f64 = 1.6;
+
+/// Document language written when typst-pdf found no text to take it from
+const DEFAULT_LANG: &str = "en";
+
+/// Add a structure tree to a PDF that typst-pdf exported from `document`
+///
+/// `pages` are the indices of the document's pages that the PDF contains,
+/// in order (all of them unless the export used page ranges). The result
+/// is a tagged PDF: marked content in reading order under a `Document`
+/// element, `H1`-`H6` for headings, `P` for other text, `Table` with `TR`,
+/// `TH` and `TD` for tables (when the document was compiled with
+/// structure markers), `Link` with the link annotation for links and
+/// `Figure` with `Alt` for images, plus the document language and
+/// `DisplayDocTitle`. The title itself comes from `set document(title:)`.
+///
+/// Fails when the PDF's content streams don't match the document's frames.
+pub fn tag_pdf(
+    document: &Document,
+    pages: &[usize],
+    pdf: &[u8],
+) -> Result<Vec<u8>, AccessibilityError> {
+    let mut pdf = lopdf::Document::load_mem(pdf)?;
+    let page_ids: Vec<ObjectId> = pdf.get_pages().into_values().collect();
+    if page_ids.len() != pages.len() {
+        return Err(AccessibilityError::TaggingError(format!(
+            "PDF has {} pages but {} were exported",
+            page_ids.len(),
+            pages.len()
+        )));
+    }
+
+    let mut walker = FrameWalker::default();
+    let mut tree = StructureBuilder::default();
+    for (number, (&index, &page_id)) in pages.iter().zip(&page_ids).enumerate() {
+        let page = document.pages.get(index).ok_or_else(|| {
+            AccessibilityError::TaggingError(format!("Document has no page {}", index + 1))
+        })?;
+        let mut marks = PageMarks::default();
+        walker.walk(&page.frame, Transform::identity(), &mut marks);
+        marks.assign_links();
+
+        let annotations = link_annotations(&pdf, page_id)?;
+        let content = Content::decode(&pdf.get_page_content(page_id)?)?;
+        let tagged = tree
+            .tag_page(
+                page_id,
+                content.operations,
+                marks,
+                &annotations,
+                &walker.table_parents,
+            )
+            .map_err(|mismatch| {
+                AccessibilityError::TaggingError(format!(
+                    "Page {} of the PDF has {} than the document's frames",
+                    number + 1,
+                    mismatch
+                ))
+            })?;
+        pdf.change_page_content(page_id, Content { operations: tagged }.encode()?)?;
+    }
+
+    tree.write(&mut pdf, &page_ids)?;
+    mark_catalog(&mut pdf)?;
+
+    let mut buffer = Vec::new();
+    pdf.save_to(&mut buffer)
+        .map_err(|e| AccessibilityError::TaggingError(e.to_string()))?;
+    Ok(buffer)
+}
+
+/// What a text run in the content stream belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    /// Text of the `id`-th heading in the document
+    Heading {
+        level: usize,
+        id: usize,
+    },
+    Body,
+}
+
+/// A table cell, by the number of its table in the document and its
+/// position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Cell {
+    table: usize,
+    x: usize,
+    y: usize,
+    /// Whether the cell is in one of the table's header rows
+    header: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TextRun {
+    role: Role,
+    /// Innermost table cell the run is in
+    cell: Option<Cell>,
+    /// Index of the link on the page whose area the run is in
+    link: Option<usize>,
+    /// Baseline from the top of the page, in points
+    baseline: f64,
+    /// Horizontal middle of the run from the left of the page, in points
+    center: f64,
+    /// Font size in points
+    size: f64,
+}
+
+impl TextRun {
+    /// Whether `next` reads as part of the same heading or paragraph
+    fn continued_by(&self, next: &TextRun) -> bool {
+        if self.cell != next.cell {
+            return false;
+        }
+        match (self.role, next.role) {
+            (Role::Heading { id, .. }, Role::Heading { id: next_id, .. }) => id == next_id,
+            (Role::Body, Role::Body) => {
+                let drop = next.baseline - self.baseline;
+                drop >= -self.size && drop <= PARAGRAPH_GAP * self.size.max(next.size)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// An image and the table cell it is in
+struct ImageMark {
+    alt: Option<String>,
+    cell: Option<Cell>,
+}
+
+/// Clickable area of a link, from the top left of the page, in points
+struct LinkArea {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+impl LinkArea {
+    /// The bounding box of a link of `size` at `pos` in a frame placed
+    /// with `ts`, as typst-pdf computes it for the annotation
+    fn new(pos: Point, size: Size, ts: Transform) -> Self {
+        let corners = [
+            pos,
+            pos + Point::with_x(size.x),
+            pos + Point::with_y(size.y),
+            pos + size.to_point(),
+        ]
+        .map(|corner| corner.transform(ts));
+        let (xs, ys) = (corners.map(|c| c.x.to_pt()), corners.map(|c| c.y.to_pt()));
+        LinkArea {
+            left: xs.into_iter().fold(f64::INFINITY, f64::min),
+            top: ys.into_iter().fold(f64::INFINITY, f64::min),
+            right: xs.into_iter().fold(f64::NEG_INFINITY, f64::max),
+            bottom: ys.into_iter().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        (self.left..=self.right).contains(&x) && (self.top..=self.bottom).contains(&y)
+    }
+}
+
+/// Text runs, images and links of one page, in content stream order
+#[derive(Default)]
+struct PageMarks {
+    texts: VecDeque<TextRun>,
+    images: VecDeque<ImageMark>,
+    /// Links, in the order of the page's link annotations
+    links: Vec<LinkArea>,
+}
+
+impl PageMarks {
+    /// Put each text run whose middle lies in a link's area in that link
+    fn assign_links(&mut self) {
+        for run in &mut self.texts {
+            // A point a quarter of the font size above the baseline is
+            // inside the line box the link area covers
+            let y = run.baseline - run.size / 4.0;
+            run.link = self
+                .links
+                .iter()
+                .position(|link| link.contains(run.center, y));
+        }
+    }
+}
+
+/// Walks frames in the order typst-pdf writes them
+#[derive(Default)]
+struct FrameWalker {
+    /// Headings whose start tag has been seen but not their end tag
+    open_headings: Vec<(Location, Role)>,
+    headings: usize,
+    /// Tables whose start marker has been seen but not their end marker,
+    /// with their number of header rows
+    open_tables: Vec<(usize, usize)>,
+    /// The cell each table is nested in, by table number
+    table_parents: Vec<Option<Cell>>,
+    /// Cells whose start marker has been seen but not their end marker
+    open_cells: Vec<Cell>,
+}
+
+impl FrameWalker {
+    fn walk(&mut self, frame: &Frame, ts: Transform, marks: &mut PageMarks) {
+        for (pos, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => {
+                    let ts = ts
+                        .pre_concat(Transform::translate(pos.x, pos.y))
+                        .pre_concat(group.transform);
+                    self.walk(&group.frame, ts, marks);
+                }
+                FrameItem::Text(text) => {
+                    let start = pos.transform(ts);
+                    let end = (*pos + Point::with_x(text.width())).transform(ts);
+                    let run = TextRun {
+                        role: self
+                            .open_headings
+                            .last()
+                            .map_or(Role::Body, |(_, role)| *role),
+                        cell: self.open_cells.last().copied(),
+                        link: None,
+                        baseline: start.y.to_pt(),
+                        center: (start.x.to_pt() + end.x.to_pt()) / 2.0,
+                        size: text.size.to_pt(),
+                    };
+                    marks
+                        .texts
+                        .extend(std::iter::repeat_n(run, text_objects(text)));
+                }
+                FrameItem::Image(image, _, _) => {
+                    marks.images.push_back(ImageMark {
+                        alt: image.alt().map(Into::into),
+                        cell: self.open_cells.last().copied(),
+                    });
+                }
+                FrameItem::Link(_, size) => {
+                    marks.links.push(LinkArea::new(*pos, *size, ts));
+                }
+                FrameItem::Tag(Tag::Start(content)) => {
+                    if let Some(marker) = Marker::from_content(content) {
+                        self.enter(marker);
+                    } else if let (Some(heading), Some(location)) =
+                        (content.to_packed::<HeadingElem>(), content.location())
+                    {
+                        self.headings += 1;
+                        let role = Role::Heading {
+                            level: heading_level(heading),
+                            id: self.headings,
+                        };
+                        self.open_headings.push((location, role));
+                    }
+                }
+                FrameItem::Tag(Tag::End(location, _)) => {
+                    self.open_headings.retain(|(open, _)| open != location);
+                }
+                FrameItem::Shape(..) => {}
+            }
+        }
+    }
+
+    /// Open or close a table or cell
+    fn enter(&mut self, marker: Marker) {
+        match marker {
+            Marker::Table { header_rows } => {
+                self.table_parents.push(self.open_cells.last().copied());
+                self.open_tables
+                    .push((self.table_parents.len() - 1, header_rows));
+            }
+            Marker::TableEnd => {
+                self.open_tables.pop();
+            }
+            Marker::Cell { x, y } => {
+                if let Some(&(table, header_rows)) = self.open_tables.last() {
+                    self.open_cells.push(Cell {
+                        table,
+                        x,
+                        y,
+                        header: y < header_rows,
+                    });
+                }
+            }
+            Marker::CellEnd => {
+                self.open_cells.pop();
+            }
+        }
+    }
+}
+
+/// Level of a heading as laid out
+///
+/// The start tag carries the heading as it was prepared for layout, with
+/// the `set heading` rules in effect (such as `offset`) already written
+/// into its fields, so no style chain is needed to resolve them.
+fn heading_level(heading: &HeadingElem) -> usize {
+    heading.resolve_level(StyleChain::default()).get()
+}
+
+/// Number of text objects typst-pdf writes for a text run: it splits runs
+/// that mix outlined glyphs with color glyphs
+fn text_objects(text: &TextItem) -> usize {
+    let mut objects = 0;
+    let mut previous = None;
+    for glyph in &text.glyphs {
+        let outline = should_outline(&text.font, glyph);
+        if previous != Some(outline) {
+            objects += 1;
+            previous = Some(outline);
+        }
+    }
+    objects.max(1)
+}
+
+/// The page's link annotations, in the order typst-pdf wrote them
+fn link_annotations(
+    pdf: &lopdf::Document,
+    page_id: ObjectId,
+) -> Result<Vec<ObjectId>, AccessibilityError> {
+    let annotations = match pdf.get_dictionary(page_id)?.get(b"Annots") {
+        Ok(annotations) => pdf.dereference(annotations)?.1.as_array()?.clone(),
+        Err(_) => return Ok(vec![]),
+    };
+    let mut links = Vec::new();
+    for annotation in annotations {
+        let id = annotation.as_reference()?;
+        if pdf.get_dictionary(id)?.get(b"Subtype")?.as_name()? == b"Link" {
+            links.push(id);
+        }
+    }
+    Ok(links)
+}
+
+/// Content of a structure element
+enum Kid {
+    Mcid {
+        page: ObjectId,
+        mcid: i64,
+    },
+    Element(usize),
+    Annotation {
+        page: ObjectId,
+        annotation: ObjectId,
+    },
+}
+
+/// A structure element
+struct Element {
+    role: String,
+    /// Index of the parent element; `None` for children of `Document`
+    parent: Option<usize>,
+    /// Page the element starts on
+    page: ObjectId,
+    kids: Vec<Kid>,
+    alt: Option<String>,
+}
+
+/// Elements of one table
+#[derive(Default)]
+struct TableElements {
+    table: usize,
+    /// `TR` element of each row
+    rows: HashMap<usize, usize>,
+    /// `TH` or `TD` element of each cell, by column and row
+    cells: HashMap<(usize, usize), usize>,
+}
+
+#[derive(Default)]
+struct StructureBuilder {
+    elements: Vec<Element>,
+    /// Children of the `Document` element
+    roots: Vec<usize>,
+    /// For each page, the element containing each MCID
+    parents: Vec<Vec<usize>>,
+    /// Each link annotation with its page and `Link` element
+    annotations: Vec<(ObjectId, ObjectId, usize)>,
+    /// Elements of each table, by table number
+    tables: HashMap<usize, TableElements>,
+}
+
+impl StructureBuilder {
+    /// Mark up one page's content stream, adding its elements
+    ///
+    /// `annotations` are the page's link annotations and `table_parents`
+    /// the cell each table is nested in. Fails with what the content stream
+    /// has more or fewer of than `marks`.
+    fn tag_page(
+        &mut self,
+        page: ObjectId,
+        operations: Vec<Operation>,
+        mut marks: PageMarks,
+        annotations: &[ObjectId],
+        table_parents: &[Option<Cell>],
+    ) -> Result<Vec<Operation>, &'static str> {
+        if annotations.len() != marks.links.len() {
+            return Err(if annotations.len() > marks.links.len() {
+                "more link annotations"
+            } else {
+                "fewer link annotations"
+            });
+        }
+
+        let mut parents = Vec::new();
+        let mut previous_run: Option<TextRun> = None;
+        // Element of the current heading or paragraph, and the current
+        // link in it with its element
+        let mut text_element = 0;
+        let mut link_element: Option<(usize, usize)> = None;
+        let mut tagged = Vec::with_capacity(operations.len() * 2);
+        let mut in_artifact = false;
+
+        for operation in operations {
+            match operation.operator.as_str() {
+                // typst-pdf's spans around images with alt text; the alt
+                // text moves to the figure element
+                "BMC" | "BDC" | "EMC" => {}
+                "BT" => {
+                    end_artifact(&mut tagged, &mut in_artifact);
+                    let run = marks.texts.pop_front().ok_or("more text objects")?;
+                    if run.cell.is_some_and(|cell| self.is_repeated(cell, page)) {
+                        // A header row repeated on a later page
+                        previous_run = None;
+                        tagged.push(begin_artifact());
+                        tagged.push(operation);
+                        continue;
+                    }
+                    if !previous_run.is_some_and(|previous| previous.continued_by(&run)) {
+                        let parent = self.container(run.cell, page, table_parents);
+                        let role = match run.role {
+                            Role::Heading { level, .. } => format!("H{}", level.clamp(1, 6)),
+                            Role::Body => "P".to_string(),
+                        };
+                        text_element = self.push(role, parent, page);
+                        link_element = None;
+                    }
+                    previous_run = Some(run);
+
+                    let element = match (run.link, link_element) {
+                        (Some(link), Some((current, element))) if link == current => element,
+                        (Some(link), _) => {
+                            let element = self.push("Link".to_string(), Some(text_element), page);
+                            self.add_annotation(element, page, annotations[link]);
+                            link_element = Some((link, element));
+                            element
+                        }
+                        (None, _) => {
+                            link_element = None;
+                            text_element
+                        }
+                    };
+                    let mcid = self.add_mcid(element, page, &mut parents);
+                    tagged.push(begin_marked(&self.elements[element].role, mcid));
+                    tagged.push(operation);
+                }
+                "ET" => {
+                    tagged.push(operation);
+                    tagged.push(Operation::new("EMC", vec![]));
+                }
+                "Do" => {
+                    end_artifact(&mut tagged, &mut in_artifact);
+                    let image = marks.images.pop_front().ok_or("more images")?;
+                    previous_run = None;
+                    let parent = self.container(image.cell, page, table_parents);
+                    let element = self.push("Figure".to_string(), parent, page);
+                    self.elements[element].alt = image.alt;
+                    let mcid = self.add_mcid(element, page, &mut parents);
+                    tagged.push(begin_marked("Figure", mcid));
+                    tagged.push(operation);
+                    tagged.push(Operation::new("EMC", vec![]));
+                }
+                "sh" => {
+                    tagged.push(begin_artifact());
+                    tagged.push(operation);
+                    tagged.push(Operation::new("EMC", vec![]));
+                }
+                operator if PATH_CONSTRUCTION.contains(&operator) => {
+                    if !in_artifact {
+                        tagged.push(begin_artifact());
+                        in_artifact = true;
+                    }
+                    tagged.push(operation);
+                }
+                operator if PATH_PAINTING.contains(&operator) => {
+                    tagged.push(operation);
+                    end_artifact(&mut tagged, &mut in_artifact);
+                }
+                _ => tagged.push(operation),
+            }
+        }
+        end_artifact(&mut tagged, &mut in_artifact);
+        if !marks.texts.is_empty() {
+            return Err("fewer text objects");
+        }
+        if !marks.images.is_empty() {
+            return Err("fewer images");
+        }
+
+        // Links without text, such as linked images or boxes, still get an
+        // element so the annotation is in the structure tree
+        for &annotation in annotations {
+            if !self.annotations.iter().any(|&(id, _, _)| id == annotation) {
+                let element = self.push("Link".to_string(), None, page);
+                self.add_annotation(element, page, annotation);
+            }
+        }
+
+        self.parents.push(parents);
+        Ok(tagged)
+    }
+
+    /// Add an element under `parent`, or under `Document`
+    fn push(&mut self, role: String, parent: Option<usize>, page: ObjectId) -> usize {
+        let index = self.elements.len();
+        self.elements.push(Element {
+            role,
+            parent,
+            page,
+            kids: Vec::new(),
+            alt: None,
+        });
+        match parent {
+            Some(parent) => self.elements[parent].kids.push(Kid::Element(index)),
+            None => self.roots.push(index),
+        }
+        index
+    }
+
+    /// The element that content in `cell` goes under: the cell's `TH` or
+    /// `TD`, created with its `TR` and `Table` as needed
+    fn container(
+        &mut self,
+        cell: Option<Cell>,
+        page: ObjectId,
+        table_parents: &[Option<Cell>],
+    ) -> Option<usize> {
+        let cell = cell?;
+        if !self.tables.contains_key(&cell.table) {
+            let outer = table_parents.get(cell.table).copied().flatten();
+            let parent = self.container(outer, page, table_parents);
+            let table = self.push("Table".to_string(), parent, page);
+            self.tables.insert(
+                cell.table,
+                TableElements {
+                    table,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let elements = &self.tables[&cell.table];
+        if let Some(&element) = elements.cells.get(&(cell.x, cell.y)) {
+            return Some(element);
+        }
+        let table = elements.table;
+        let row = match elements.rows.get(&cell.y) {
+            Some(&row) => row,
+            None => {
+                let row = self.push("TR".to_string(), Some(table), page);
+                if let Some(elements) = self.tables.get_mut(&cell.table) {
+                    elements.rows.insert(cell.y, row);
+                }
+                row
+            }
+        };
+        let role = if cell.header { "TH" } else { "TD" };
+        let element = self.push(role.to_string(), Some(row), page);
+        if let Some(elements) = self.tables.get_mut(&cell.table) {
+            elements.cells.insert((cell.x, cell.y), element);
+        }
+        Some(element)
+    }
+
+    /// Whether `cell` is a header cell already tagged on an earlier page,
+    /// so this is the header repeated at the top of the next one
+    fn is_repeated(&self, cell: Cell, page: ObjectId) -> bool {
+        cell.header
+            && self
+                .tables
+                .get(&cell.table)
+                .and_then(|elements| elements.cells.get(&(cell.x, cell.y)))
+                .is_some_and(|&element| self.elements[element].page != page)
+    }
+
+    /// Give `element` the page's next MCID
+    fn add_mcid(&mut self, element: usize, page: ObjectId, parents: &mut Vec<usize>) -> i64 {
+        let mcid = parents.len() as i64;
+        self.elements[element].kids.push(Kid::Mcid { page, mcid });
+        parents.push(element);
+        mcid
+    }
+
+    /// Put a link annotation in `element`, unless it already is in another
+    fn add_annotation(&mut self, element: usize, page: ObjectId, annotation: ObjectId) {
+        if self.annotations.iter().any(|&(id, _, _)| id == annotation) {
+            return;
+        }
+        self.elements[element]
+            .kids
+            .push(Kid::Annotation { page, annotation });
+        self.annotations.push((annotation, page, element));
+    }
+
+    /// Write the structure tree and link the pages and annotations to it
+    fn write(
+        self,
+        pdf: &mut lopdf::Document,
+        page_ids: &[ObjectId],
+    ) -> Result<(), AccessibilityError> {
+        let root_id = pdf.new_object_id();
+        let document_id = pdf.new_object_id();
+        let element_ids: Vec<ObjectId> =
+            self.elements.iter().map(|_| pdf.new_object_id()).collect();
+
+        for (element, &id) in self.elements.into_iter().zip(&element_ids) {
+            let mut kids: Vec<Object> = element
+                .kids
+                .iter()
+                .map(|kid| match *kid {
+                    Kid::Mcid { page, mcid } if page == element.page => Object::Integer(mcid),
+                    Kid::Mcid { page, mcid } => dictionary! {
+                        "Type" => "MCR",
+                        "Pg" => page,
+                        "MCID" => mcid,
+                    }
+                    .into(),
+                    Kid::Element(index) => element_ids[index].into(),
+                    Kid::Annotation { page, annotation } => dictionary! {
+                        "Type" => "OBJR",
+                        "Pg" => page,
+                        "Obj" => annotation,
+                    }
+                    .into(),
+                })
+                .collect();
+            let kids = match kids.len() {
+                1 => kids.remove(0),
+                _ => Object::Array(kids),
+            };
+            let mut dict = dictionary! {
+                "Type" => "StructElem",
+                "S" => element.role.as_str(),
+                "P" => element.parent.map_or(document_id, |parent| element_ids[parent]),
+                "Pg" => element.page,
+                "K" => kids,
+            };
+            if let Some(alt) = element.alt {
+                dict.set("Alt", text_string(&alt));
+            }
+            if element.role == "TH" {
+                dict.set("A", dictionary! { "O" => "Table", "Scope" => "Column" });
+            }
+            pdf.objects.insert(id, dict.into());
+        }
+
+        pdf.objects.insert(
+            document_id,
+            dictionary! {
+                "Type" => "StructElem",
+                "S" => "Document",
+                "P" => root_id,
+                "K" => self.roots.iter().map(|&index| element_ids[index].into()).collect::<Vec<Object>>(),
+            }
+            .into(),
+        );
+
+        // Pages take the first keys of the parent tree, annotations the
+        // ones after
+        let mut nums = Vec::new();
+        for (index, parents) in self.parents.iter().enumerate() {
+            nums.push(Object::Integer(index as i64));
+            nums.push(Object::Array(
+                parents
+                    .iter()
+                    .map(|&element| element_ids[element].into())
+                    .collect(),
+            ));
+        }
+        for (offset, &(annotation, _, element)) in self.annotations.iter().enumerate() {
+            let key = (page_ids.len() + offset) as i64;
+            nums.push(Object::Integer(key));
+            nums.push(element_ids[element].into());
+            pdf.get_dictionary_mut(annotation)?.set("StructParent", key);
+        }
+        pdf.objects.insert(
+            root_id,
+            dictionary! {
+                "Type" => "StructTreeRoot",
+                "K" => document_id,
+                "ParentTree" => dictionary! { "Nums" => nums },
+                "ParentTreeNextKey" => (page_ids.len() + self.annotations.len()) as i64,
+            }
+            .into(),
+        );
+
+        for (index, &page_id) in page_ids.iter().enumerate() {
+            let page = pdf.get_dictionary_mut(page_id)?;
+            page.set("StructParents", index as i64);
+            page.set("Tabs", "S");
+        }
+        pdf.catalog_mut()?.set("StructTreeRoot", root_id);
+        Ok(())
+    }
+}
+
+/// Mark the document as tagged, with a language and a displayed title
+fn mark_catalog(pdf: &mut lopdf::Document) -> Result<(), AccessibilityError> {
+    let preferences = pdf.catalog()?.get(b"ViewerPreferences").ok().cloned();
+    match preferences {
+        Some(Object::Reference(id)) => {
+            pdf.get_dictionary_mut(id)?.set("DisplayDocTitle", true);
+        }
+        preferences => {
+            let mut dict = match preferences {
+                Some(Object::Dictionary(dict)) => dict,
+                _ => Dictionary::new(),
+            };
+            dict.set("DisplayDocTitle", true);
+            pdf.catalog_mut()?.set("ViewerPreferences", dict);
+        }
+    }
+
+    let catalog = pdf.catalog_mut()?;
+    catalog.set("MarkInfo", dictionary! { "Marked" => true });
+    if !catalog.has(b"Lang") {
+        catalog.set("Lang", Object::string_literal(DEFAULT_LANG));
+    }
+    Ok(())
+}
+
+fn begin_marked(role: &str, mcid: i64) -> Operation {
+    Operation::new(
+        "BDC",
+        vec![role.into(), dictionary! { "MCID" => mcid }.into()],
+    )
+}
+
+fn begin_artifact() -> Operation {
+    Operation::new("BMC", vec!["Artifact".into()])
+}
+
+fn end_artifact(tagged: &mut Vec<Operation>, in_artifact: &mut bool) {
+    if *in_artifact {
+        tagged.push(Operation::new("EMC", vec![]));
+        *in_artifact = false;
+    }
+}
+
+/// A PDF text string: PDFDocEncoding for ASCII, UTF-16BE otherwise
+fn text_string(text: &str) -> Object {
+    if text.is_ascii() {
+        return Object::string_literal(text);
+    }
+    let mut bytes = vec![0xFE, 0xFF];
+    bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+    Object::String(bytes, StringFormat::Hexadecimal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tag_pdf;
+    use crate::accessibility::{check_accessibility, AccessibilityError};
+    use crate::compiler::{compile_document_sync, OutputFormat, RenderRequest};
+    use crate::templates::list_templates;
+    use crate::verifier::template_state;
+    use crate::world::VirtualWorld;
+    use base64::Engine;
+    use std::collections::HashMap;
+
+    fn render(request: RenderRequest) -> Vec<u8> {
+        let response = compile_document_sync(request).unwrap();
+        let artifact = response
+            .artifact
+            .unwrap_or_else(|| panic!("no artifact: {:?}", response.errors));
+        base64::engine::general_purpose::STANDARD
+            .decode(artifact.data_base64)
+            .unwrap()
+    }
+
+    /// A 1x1 PNG
+    const PIXEL: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+    /// Roles of the `Document` element's children, and their alt text
+    fn structure(pdf: &[u8]) -> Vec<(String, Option<String>)> {
+        let pdf = lopdf::Document::load_mem(pdf).unwrap();
+        let catalog = pdf.catalog().unwrap();
+        let root = catalog
+            .get(b"StructTreeRoot")
+            .unwrap()
+            .as_reference()
+            .unwrap();
+        let root = pdf.get_dictionary(root).unwrap();
+        let document = pdf
+            .get_dictionary(root.get(b"K").unwrap().as_reference().unwrap())
+            .unwrap();
+        assert_eq!(
+            document.get(b"S").unwrap().as_name_str().unwrap(),
+            "Document"
+        );
+        document
+            .get(b"K")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|kid| {
+                let element = pdf.get_dictionary(kid.as_reference().unwrap()).unwrap();
+                let role = element
+                    .get(b"S")
+                    .unwrap()
+                    .as_name_str()
+                    .unwrap()
+                    .to_string();
+                let alt = element
+                    .get(b"Alt")
+                    .ok()
+                    .map(|alt| String::from_utf8(alt.as_str().unwrap().to_vec()).unwrap());
+                (role, alt)
+            })
+            .collect()
+    }
+
+    /// The structure tree below `Document`, as roles with their children
+    /// in parentheses; link annotations show as `OBJR`
+    fn outline(pdf: &[u8]) -> String {
+        fn kid(pdf: &lopdf::Document, object: &lopdf::Object) -> Option<String> {
+            let element = match object {
+                lopdf::Object::Reference(id) => pdf.get_dictionary(*id).unwrap(),
+                lopdf::Object::Dictionary(dict) => dict,
+                _ => return None,
+            };
+            if let Ok(kind) = element.get(b"Type").and_then(lopdf::Object::as_name) {
+                match kind {
+                    b"OBJR" => return Some("OBJR".to_string()),
+                    b"MCR" => return None,
+                    _ => {}
+                }
+            }
+            let role = element.get(b"S").unwrap().as_name_str().unwrap();
+            let kids: Vec<String> = match element.get(b"K").unwrap() {
+                lopdf::Object::Array(kids) => kids.iter().filter_map(|k| kid(pdf, k)).collect(),
+                kid_object => kid(pdf, kid_object).into_iter().collect(),
+            };
+            Some(match kids.is_empty() {
+                true => role.to_string(),
+                false => format!("{}({})", role, kids.join(", ")),
+            })
+        }
+
+        let pdf = lopdf::Document::load_mem(pdf).unwrap();
+        let root = pdf.catalog().unwrap().get(b"StructTreeRoot").unwrap();
+        let root = pdf.get_dictionary(root.as_reference().unwrap()).unwrap();
+        kid(&pdf, root.get(b"K").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_tags_headings_paragraphs_and_figures() {
+        let pdf = render(RenderRequest {
+            source: r#"#set document(title: "Notice")
+= Notice to Tenant
+
+This notice concerns the unit.
+It continues on a second line of the same paragraph.
+
+== Photos
+
+#image("unit.png", width: 20pt, alt: "Front door of the unit")
+
+Second paragraph."#
+                .to_string(),
+            assets: HashMap::from([("unit.png".to_string(), PIXEL.to_string())]),
+            tagged: true,
+            ..Default::default()
+        });
+
+        let roles: Vec<_> = structure(&pdf);
+        assert_eq!(
+            roles,
+            [
+                ("H1".to_string(), None),
+                ("P".to_string(), None),
+                ("H2".to_string(), None),
+                (
+                    "Figure".to_string(),
+                    Some("Front door of the unit".to_string())
+                ),
+                ("P".to_string(), None),
+            ]
+        );
+
+        let report = check_accessibility(&pdf).unwrap();
+        assert!(report.passes(), "{:?}", report.issues);
+        assert_eq!(report.title.as_deref(), Some("Notice"));
+    }
+
+    #[test]
+    fn test_tags_tables_by_cell() {
+        let pdf = render(RenderRequest {
+            source: r#"#set document(title: "Rent")
+Rent schedule:
+
+#table(
+  columns: 2,
+  table.header([*Item*], [*Amount*]),
+  [First Month's Rent], [\$1,500],
+  [Security Deposit], [\$1,500],
+)
+
+After the table."#
+                .to_string(),
+            tagged: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            outline(&pdf),
+            "Document(P, Table(TR(TH(P), TH(P)), TR(TD(P), TD(P)), TR(TD(P), TD(P))), P)"
+        );
+        let report = check_accessibility(&pdf).unwrap();
+        assert!(report.passes(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_repeated_table_header_is_an_artifact() {
+        let rows: String = (1..=80)
+            .map(|row| format!("[Row {}], [{}],", row, row))
+            .collect();
+        let pdf = render(RenderRequest {
+            source: format!(
+                "#set document(title: \"Rows\")\n#table(columns: 2, table.header([Name], [Value]), {})",
+                rows
+            ),
+            tagged: true,
+            ..Default::default()
+        });
+
+        let outline = outline(&pdf);
+        assert_eq!(outline.matches("TH(P)").count(), 2, "{}", outline);
+        assert_eq!(outline.matches("TD(P)").count(), 160);
+        let report = check_accessibility(&pdf).unwrap();
+        assert!(report.passes(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_tags_links_with_their_annotations() {
+        let pdf = render(RenderRequest {
+            source: r#"#set document(title: "Links")
+See #link("https://example.com")[the landlord's site] for details.
+
+#link("https://example.com/unit")[#box(width: 10pt, height: 10pt, fill: gray)]"#
+                .to_string(),
+            tagged: true,
+            ..Default::default()
+        });
+
+        assert_eq!(outline(&pdf), "Document(P(Link(OBJR)), Link(OBJR))");
+
+        let document = lopdf::Document::load_mem(&pdf).unwrap();
+        let page = *document.get_pages().get(&1).unwrap();
+        let annotations = document
+            .get_dictionary(page)
+            .unwrap()
+            .get(b"Annots")
+            .unwrap()
+            .as_array()
+            .unwrap();
+        assert_eq!(annotations.len(), 2);
+        for annotation in annotations {
+            let annotation = document
+                .get_dictionary(annotation.as_reference().unwrap())
+                .unwrap();
+            assert!(annotation.get(b"StructParent").is_ok());
+        }
+        let report = check_accessibility(&pdf).unwrap();
+        assert!(report.passes(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_rejects_pdf_of_another_document() {
+        let compile = |source: &str| {
+            let world =
+                VirtualWorld::new(source.to_string(), HashMap::new(), HashMap::new()).unwrap();
+            typst::compile(&world).output.unwrap()
+        };
+        let document = compile("One paragraph.\n\nAnother paragraph.");
+        let other = compile("One paragraph.");
+        let pdf = typst_pdf::pdf(&other, &typst_pdf::PdfOptions::default()).unwrap();
+
+        let error = tag_pdf(&document, &[0], &pdf).unwrap_err();
+        assert!(
+            matches!(&error, AccessibilityError::TaggingError(message)
+                if message == "Page 1 of the PDF has fewer text objects than the document's frames"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_heading_offset() {
+        let pdf = render(RenderRequest {
+            source: "#set document(title: \"Offsets\")\n= Top\n#set heading(offset: 1)\n= Nested\n#heading(level: 1)[Explicit]"
+                .to_string(),
+            tagged: true,
+            ..Default::default()
+        });
+
+        let roles: Vec<_> = structure(&pdf).into_iter().map(|(role, _)| role).collect();
+        assert_eq!(roles, ["H1", "H2", "H1"]);
+    }
+
+    #[test]
+    fn test_tags_selected_pages() {
+        let pdf = render(RenderRequest {
+            source:
+                "#set document(title: \"Pages\")\n= One\n#pagebreak()\n= Two\n#pagebreak()\n= Three"
+                    .to_string(),
+            pages: Some("2-".parse().unwrap()),
+            tagged: true,
+            ..Default::default()
+        });
+
+        let roles: Vec<_> = structure(&pdf).into_iter().map(|(role, _)| role).collect();
+        assert_eq!(roles, ["H1", "H1"]);
+        assert!(check_accessibility(&pdf).unwrap().passes());
+    }
+
+    #[test]
+    fn test_every_lease_template_is_accessible() {
+        let inputs: HashMap<String, serde_json::Value> = [
+            ("landlord_name", "Sunshine Properties LLC"),
+            ("tenant_name", "Jordan Rivera"),
+            ("property_address", "100 Main Street"),
+            ("property_city", "Springfield"),
+            ("property_zip", "33101"),
+            ("monthly_rent", "1500"),
+            ("security_deposit", "1500"),
+            ("lease_start", "January 1, 2026"),
+            ("lease_end", "December 31, 2026"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), serde_json::json!(v)))
+        .collect();
+
+        let leases: Vec<_> = list_templates()
+            .into_iter()
+            .filter(|t| template_state(&t.name).is_some())
+            .collect();
+        assert!(!leases.is_empty());
+        for lease in leases {
+            let pdf = render(RenderRequest {
+                source: format!("typst://templates/{}", lease.name),
+                inputs: inputs.clone(),
+                format: OutputFormat::Pdf,
+                tagged: true,
+                ..Default::default()
+            });
+            let report = check_accessibility(&pdf).unwrap();
+            assert!(report.passes(), "{}: {:?}", lease.name, report.issues);
+            assert!(report.tagged);
+            assert_eq!(report.language.as_deref(), Some("en"));
+            assert!(report.title.unwrap().contains("Lease Agreement"));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Content-hash cache of finished renders
+//!
+//! A render is fully determined by the source it compiles and the request's
+//! inputs, assets and export options, so identical requests can be served
+//! from the artifacts of an earlier one without compiling again.
+
+use std::collections::{HashMap, VecDeque};
+
+use typst::utils::hash128;
+
+use super::{RenderRequest, RenderResponse};
+use crate::templates::PackageIdentity;
+
+/// Number of renders a [`CompilerSession`](super::CompilerSession) keeps by default
+pub const DEFAULT_CACHE_CAPACITY: usize = 32;
+
+/// A cached successful render
+#[derive(Debug, Clone)]
+pub struct CachedRender {
+    pub response: RenderResponse,
+    /// Hash of every page of the compiled document, to report which pages
+    /// changed when the render is served again
+    pub page_hashes: Vec<u128>,
+}
+
+/// Least-recently-used cache of renders, keyed by [`request_key`]
+#[derive(Debug)]
+pub struct ArtifactCache {
+    capacity: usize,
+    entries: HashMap<u128, CachedRender>,
+    /// Keys from least to most recently used
+    order: VecDeque<u128>,
+}
+
+impl ArtifactCache {
+    /// Create a cache holding at most `capacity` renders; zero disables it
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Look up a render, marking it as most recently used
+    pub fn get(&mut self, key: u128) -> Option<&CachedRender> {
+        if self.entries.contains_key(&key) {
+            self.touch(key);
+        }
+        self.entries.get(&key)
+    }
+
+    /// Store a render, evicting the least recently used one when full
+    pub fn insert(&mut self, key: u128, render: CachedRender) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(key, render).is_some() {
+            self.touch(key);
+            return;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn touch(&mut self, key: u128) {
+        if let Some(position) = self.order.iter().position(|k| *k == key) {
+            self.order.remove(position);
+        }
+        self.order.push_back(key);
+    }
+}
+
+/// Cache key of a render: the resolved source text, the template package
+/// it came from and every request field
+///
+/// The package matters even when the source is the same: its other files
+/// and fonts can differ between versions, or between two registrations of
+/// the same version. The request is hashed through its JSON form, whose
+/// object keys are sorted, so the order inputs and assets were inserted in
+/// does not matter.
+pub fn request_key(
+    source: &str,
+    package: Option<&PackageIdentity>,
+    request: &RenderRequest,
+) -> u128 {
+    let request = serde_json::to_value(request)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    hash128(&(source, package, request))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::RenderStatus;
+
+    fn render(page_count: usize) -> CachedRender {
+        CachedRender {
+            response: RenderResponse {
+                status: RenderStatus::Success,
+                artifact: None,
+                pages: vec![],
+                errors: vec![],
+                warnings: vec![],
+                input_errors: vec![],
+                changed_pages: None,
+            },
+            page_hashes: vec![0; page_count],
+        }
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = ArtifactCache::new(2);
+        cache.insert(1, render(1));
+        cache.insert(2, render(2));
+        assert!(cache.get(1).is_some());
+
+        cache.insert(3, render(3));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(2).is_none());
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(3).is_some());
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_cache() {
+        let mut cache = ArtifactCache::new(0);
+        cache.insert(1, render(1));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_request_key() {
+        let mut request = RenderRequest {
+            source: "= Hello".to_string(),
+            ..Default::default()
+        };
+        request.inputs.insert("a".to_string(), serde_json::json!(1));
+        request.inputs.insert("b".to_string(), serde_json::json!(2));
+        let key = request_key("= Hello", None, &request);
+
+        let mut reordered = request.clone();
+        reordered.inputs = [("b", 2), ("a", 1)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), serde_json::json!(v)))
+            .collect();
+        assert_eq!(request_key("= Hello", None, &reordered), key);
+
+        let mut changed = request.clone();
+        changed.inputs.insert("a".to_string(), serde_json::json!(3));
+        assert_ne!(request_key("= Hello", None, &changed), key);
+        assert_ne!(request_key("= Hi", None, &request), key);
+
+        let package = PackageIdentity {
+            name: "notice".to_string(),
+            version: semver::Version::new(1, 0, 0),
+            content_hash: 1,
+        };
+        let packaged = request_key("= Hello", Some(&package), &request);
+        assert_ne!(packaged, key);
+        let republished = PackageIdentity {
+            content_hash: 2,
+            ..package
+        };
+        assert_ne!(
+            request_key("= Hello", Some(&republished), &request),
+            packaged
+        );
+    }
+}
@@ -35,6 +35,31 @@ pub struct CompileError {
     pub severity: ErrorSeverity,
 }
 
+/// A template input that is missing or does not match the template's schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputError {
+    /// Input name, with a path into arrays and objects ("items[1].price")
+    pub field: String,
+    /// What is wrong with the value ("is required", "must be a date ...")
+    pub message: String,
+}
+
+impl InputError {
+    /// Create a new input error
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.field, self.message)
+    }
+}
+
 /// Error severity level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -52,6 +77,9 @@ pub enum ServerError {
     #[error("Template not found: {0}")]
     TemplateNotFound(String),
 
+    #[error("Invalid template package: {0}")]
+    InvalidPackage(String),
+
     #[error("Compilation failed")]
     CompileError(Vec<CompileError>),
 
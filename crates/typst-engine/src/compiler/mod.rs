@@ -1,12 +1,15 @@
 //! Typst compilation wrapper with timeout and error handling
 
+pub mod cache;
 pub mod errors;
 pub mod output;
 pub mod render;
+pub mod session;
 
-pub use errors::{CompileError, RenderStatus, ServerError};
-pub use output::OutputFormat;
+pub use errors::{CompileError, InputError, RenderStatus, ServerError};
+pub use output::{OutputFormat, PageSelection};
 pub use render::{compile_document_sync, validate_syntax};
+pub use session::{CompilerSession, SessionStats};
 
 #[cfg(feature = "server")]
 pub use render::compile_document;
@@ -23,11 +26,12 @@ pub enum SourceInput {
 }
 
 /// Request to render a document
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RenderRequest {
     /// Raw Typst source code or template URI
     pub source: String,
-    /// Variables injected into sys.inputs
+    /// Variables injected into sys.inputs; for templates, checked against
+    /// the template's input schema first
     #[serde(default)]
     pub inputs: HashMap<String, serde_json::Value>,
     /// Binary assets as base64 strings
@@ -38,18 +42,47 @@ pub struct RenderRequest {
     pub format: OutputFormat,
     /// Pixels per inch for PNG output
     pub ppi: Option<u32>,
+    /// Pages to export ("1-3,5"); all pages when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages: Option<PageSelection>,
+    /// For SVG and PNG, zip the pages into a single artifact instead of
+    /// returning one artifact per page
+    #[serde(default)]
+    pub bundle: bool,
+    /// For PNG, render pages at most this many pixels wide instead of at
+    /// `ppi`, for page pickers and previews
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    /// For PDF, write a tagged PDF (structure tree with tables and links,
+    /// reading order, alt text, language) for screen readers. The output is
+    /// not PDF/UA conformant; see [`crate::accessibility`]
+    #[serde(default)]
+    pub tagged: bool,
 }
 
 /// Response from rendering
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RenderResponse {
     pub status: RenderStatus,
+    /// The rendered document: the PDF, the zipped bundle, or for SVG and
+    /// PNG the first exported page
     #[serde(skip_serializing_if = "Option::is_none")]
     pub artifact: Option<RenderArtifact>,
+    /// For SVG and PNG without `bundle`, one artifact per exported page
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<RenderArtifact>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<CompileError>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<CompileError>,
+    /// Template inputs rejected by the template's schema; nothing is
+    /// compiled when there are any
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_errors: Vec<InputError>,
+    /// For renders in a [`CompilerSession`], the one-indexed pages that
+    /// differ from the session's previous render
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changed_pages: Option<Vec<usize>>,
 }
 
 /// Rendered artifact with base64-encoded data
@@ -57,5 +90,9 @@ pub struct RenderResponse {
 pub struct RenderArtifact {
     pub data_base64: String,
     pub mime_type: String,
+    /// Pages in the compiled document
     pub page_count: usize,
+    /// One-indexed page number, for single-page artifacts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
 }
@@ -54,3 +54,127 @@ impl std::str::FromStr for OutputFormat {
         }
     }
 }
+
+/// One-indexed pages to export, written like "1-3,5,8-"
+///
+/// A range without an end runs to the last page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PageSelection {
+    ranges: Vec<(usize, Option<usize>)>,
+}
+
+impl PageSelection {
+    /// Whether the (one-indexed) page is selected
+    pub fn includes(&self, page: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(first, last)| page >= first && last.is_none_or(|last| page <= last))
+    }
+
+    /// Zero-based indices of the selected pages of a document with
+    /// `page_count` pages, in document order
+    pub fn indices(&self, page_count: usize) -> Vec<usize> {
+        (0..page_count).filter(|i| self.includes(i + 1)).collect()
+    }
+
+    /// The selection as Typst page ranges, for PDF export
+    pub fn to_page_ranges(&self) -> typst::layout::PageRanges {
+        typst::layout::PageRanges::new(
+            self.ranges
+                .iter()
+                .map(|&(first, last)| {
+                    std::num::NonZeroUsize::new(first)..=last.and_then(std::num::NonZeroUsize::new)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl std::str::FromStr for PageSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let page = |text: &str| -> Result<usize, String> {
+            match text.trim().parse::<usize>() {
+                Ok(page) if page > 0 => Ok(page),
+                _ => Err(format!("Invalid page number '{}' in '{}'", text.trim(), s)),
+            }
+        };
+
+        let ranges = s
+            .split(',')
+            .map(|part| match part.split_once('-') {
+                Some((first, last)) if last.trim().is_empty() => Ok((page(first)?, None)),
+                Some((first, last)) => {
+                    let (first, last) = (page(first)?, page(last)?);
+                    if last < first {
+                        return Err(format!(
+                            "Page range '{}' ends before it starts",
+                            part.trim()
+                        ));
+                    }
+                    Ok((first, Some(last)))
+                }
+                None => page(part).map(|page| (page, Some(page))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { ranges })
+    }
+}
+
+impl TryFrom<String> for PageSelection {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<PageSelection> for String {
+    fn from(selection: PageSelection) -> Self {
+        selection.to_string()
+    }
+}
+
+impl std::fmt::Display for PageSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranges: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|&(first, last)| match last {
+                Some(last) if last == first => first.to_string(),
+                Some(last) => format!("{}-{}", first, last),
+                None => format!("{}-", first),
+            })
+            .collect();
+        write!(f, "{}", ranges.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_selection_parse() {
+        let selection: PageSelection = "1-3, 5,8-".parse().unwrap();
+        assert_eq!(selection.indices(10), [0, 1, 2, 4, 7, 8, 9]);
+        assert_eq!(selection.indices(2), [0, 1]);
+        assert_eq!(selection.to_string(), "1-3,5,8-");
+
+        for invalid in ["", "0", "3-1", "a-2", "1,,2"] {
+            assert!(invalid.parse::<PageSelection>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_page_selection_serde() {
+        let selection: PageSelection = serde_json::from_value(serde_json::json!("2-")).unwrap();
+        assert!(!selection.includes(1));
+        assert!(selection.includes(200));
+        assert_eq!(serde_json::to_value(&selection).unwrap(), "2-");
+        assert!(serde_json::from_value::<PageSelection>(serde_json::json!("x")).is_err());
+    }
+}
@@ -12,12 +12,14 @@ use std::collections::HashMap;
 use base64::Engine;
 use typst::diag::{Severity, SourceDiagnostic};
 use typst::foundations::Bytes;
+use typst::layout::Page;
 use typst::model::Document;
 
-use super::errors::{CompileError, RenderStatus, ServerError};
+use super::errors::{CompileError, InputError, RenderStatus, ServerError};
 use super::output::OutputFormat;
 use super::{RenderArtifact, RenderRequest, RenderResponse};
-use crate::templates;
+use crate::accessibility;
+use crate::templates::{self, PackageIdentity};
 use crate::world::VirtualWorld;
 
 /// Compile a Typst document synchronously (WASM-compatible)
@@ -26,19 +28,29 @@ use crate::world::VirtualWorld;
 /// Use this for browser/WASM environments where tokio is not available.
 pub fn compile_document_sync(request: RenderRequest) -> Result<RenderResponse, ServerError> {
     // 1. Resolve source - check if it's a template URI or raw source
-    let source = resolve_source(&request.source)?;
+    check_export_options(&request)?;
+    let resolved = resolve_source(&request.source)?;
 
-    // 2. Decode base64 assets
-    let assets = decode_assets(&request.assets)?;
+    // 2. Check template inputs against the template's schema
+    let inputs = match prepare_inputs(&request) {
+        Ok(inputs) => inputs,
+        Err(input_errors) => return Ok(invalid_inputs_response(input_errors)),
+    };
+
+    // 3. Decode base64 assets, on top of the template package's files
+    let mut assets = resolved.assets;
+    assets.extend(decode_assets(&request.assets)?);
 
-    // 3. Create VirtualWorld
-    let world = VirtualWorld::new(source, request.inputs.clone(), assets)?;
+    // 4. Create VirtualWorld
+    let world = VirtualWorld::new(resolved.source, inputs, assets)?
+        .with_fonts(resolved.fonts)
+        .with_structure_markers(request.tagged);
 
-    // 4. Compile directly (no timeout in sync mode)
+    // 5. Compile directly (no timeout in sync mode)
     let compile_result = typst::compile(&world);
 
-    // 5. Process compilation result
-    process_compile_result(compile_result, request.format, request.ppi)
+    // 6. Process compilation result
+    process_compile_result(compile_result, &request)
 }
 
 /// Compile a Typst document with timeout (requires `server` feature)
@@ -50,22 +62,32 @@ pub async fn compile_document(
     use std::time::Duration;
 
     // 1. Resolve source - check if it's a template URI or raw source
-    let source = resolve_source(&request.source)?;
+    check_export_options(&request)?;
+    let resolved = resolve_source(&request.source)?;
 
-    // 2. Decode base64 assets
-    let assets = decode_assets(&request.assets)?;
+    // 2. Check template inputs against the template's schema
+    let inputs = match prepare_inputs(&request) {
+        Ok(inputs) => inputs,
+        Err(input_errors) => return Ok(invalid_inputs_response(input_errors)),
+    };
 
-    // 3. Create VirtualWorld
-    let world = VirtualWorld::new(source, request.inputs.clone(), assets)?;
+    // 3. Decode base64 assets, on top of the template package's files
+    let mut assets = resolved.assets;
+    assets.extend(decode_assets(&request.assets)?);
 
-    // 4. Compile with timeout
+    // 4. Create VirtualWorld
+    let world = VirtualWorld::new(resolved.source, inputs, assets)?
+        .with_fonts(resolved.fonts)
+        .with_structure_markers(request.tagged);
+
+    // 5. Compile with timeout
     let result = tokio::time::timeout(
         Duration::from_millis(timeout_ms),
         tokio::task::spawn_blocking(move || typst::compile(&world)),
     )
     .await;
 
-    // 5. Handle timeout and join error
+    // 6. Handle timeout and join error
     let compile_result = match result {
         Ok(Ok(result)) => result,
         Ok(Err(join_error)) => {
@@ -79,38 +101,108 @@ pub async fn compile_document(
         }
     };
 
-    // 6. Process compilation result
-    process_compile_result(compile_result, request.format, request.ppi)
+    // 7. Process compilation result
+    process_compile_result(compile_result, &request)
+}
+
+/// Inputs to compile with: for templates, validated against the template's
+/// schema with defaults filled in; otherwise passed through
+pub(super) fn prepare_inputs(
+    request: &RenderRequest,
+) -> Result<HashMap<String, serde_json::Value>, Vec<InputError>> {
+    match templates::registry::parse_template_uri(&request.source)
+        .and_then(templates::template_schema)
+    {
+        Some(schema) => schema.prepare(&request.inputs),
+        None => Ok(request.inputs.clone()),
+    }
+}
+
+/// Response for a request whose inputs failed validation
+pub(super) fn invalid_inputs_response(input_errors: Vec<InputError>) -> RenderResponse {
+    RenderResponse {
+        status: RenderStatus::Error,
+        artifact: None,
+        pages: vec![],
+        errors: vec![],
+        warnings: vec![],
+        input_errors,
+        changed_pages: None,
+    }
+}
+
+/// Typst source to compile, with the files and fonts of its template package
+pub(super) struct ResolvedSource {
+    pub(super) source: String,
+    pub(super) assets: HashMap<String, Bytes>,
+    pub(super) fonts: Vec<Bytes>,
+    /// The template package the source came from, if any
+    pub(super) package: Option<PackageIdentity>,
 }
 
 /// Resolve source string to actual Typst source
-fn resolve_source(source: &str) -> Result<String, ServerError> {
-    if source.starts_with("typst://templates/") {
-        let template_name = source.strip_prefix("typst://templates/").unwrap_or(source);
-        templates::get_template_source(template_name)
-    } else {
-        Ok(source.to_string())
+pub(super) fn resolve_source(source: &str) -> Result<ResolvedSource, ServerError> {
+    let resolved = |source: String| ResolvedSource {
+        source,
+        assets: HashMap::new(),
+        fonts: vec![],
+        package: None,
+    };
+
+    match templates::registry::parse_template_uri(source) {
+        Some(spec) => match templates::find_package(spec) {
+            Some(package) => Ok(ResolvedSource {
+                source: package.source().to_string(),
+                assets: package.assets(),
+                fonts: package.fonts(),
+                package: Some(package.identity()),
+            }),
+            None => templates::get_template_source(spec).map(resolved),
+        },
+        None => Ok(resolved(source.to_string())),
     }
 }
 
 /// Process the compilation result into a RenderResponse
 fn process_compile_result(
-    compile_result: typst::diag::Warned<Result<Document, ecow::EcoVec<SourceDiagnostic>>>,
-    format: OutputFormat,
-    ppi: Option<u32>,
+    compile_result: CompileResult,
+    request: &RenderRequest,
+) -> Result<RenderResponse, ServerError> {
+    process_compile_result_with(compile_result, |document| {
+        export_document(document, request)
+    })
+}
+
+/// Result of `typst::compile`
+pub(super) type CompileResult =
+    typst::diag::Warned<Result<Document, ecow::EcoVec<SourceDiagnostic>>>;
+
+/// Process the compilation result into a RenderResponse, exporting a
+/// successfully compiled document with `export`
+pub(super) fn process_compile_result_with(
+    compile_result: CompileResult,
+    export: impl FnOnce(&Document) -> Result<Vec<RenderArtifact>, ServerError>,
 ) -> Result<RenderResponse, ServerError> {
     let compilation_warnings = compile_result.warnings.clone();
 
     match compile_result.output {
         Ok(document) => {
             // Export to requested format
-            let artifact = export_document(&document, format, ppi)?;
+            let mut artifacts = export(&document)?;
+            let (artifact, pages) = if artifacts.len() == 1 && artifacts[0].page.is_none() {
+                (artifacts.pop(), vec![])
+            } else {
+                (artifacts.first().cloned(), artifacts)
+            };
             let (_, warnings) = categorize_diagnostics(&compilation_warnings);
             Ok(RenderResponse {
                 status: RenderStatus::Success,
-                artifact: Some(artifact),
+                artifact,
+                pages,
                 errors: vec![],
                 warnings,
+                input_errors: vec![],
+                changed_pages: None,
             })
         }
         Err(diagnostics) => {
@@ -125,14 +217,40 @@ fn process_compile_result(
                 Ok(RenderResponse {
                     status: RenderStatus::Error,
                     artifact: None,
+                    pages: vec![],
                     errors,
                     warnings,
+                    input_errors: vec![],
+                    changed_pages: None,
                 })
             }
         }
     }
 }
 
+/// Reject export options that do not apply to the requested format
+pub(super) fn check_export_options(request: &RenderRequest) -> Result<(), ServerError> {
+    if request.bundle && request.format == OutputFormat::Pdf {
+        return Err(ServerError::InvalidArgument(
+            "bundle applies to SVG and PNG output; a PDF is already one file".to_string(),
+        ));
+    }
+    if request.tagged && request.format != OutputFormat::Pdf {
+        return Err(ServerError::InvalidArgument(
+            "tagged applies to PDF output only".to_string(),
+        ));
+    }
+    match request.thumbnail_width {
+        Some(_) if request.format != OutputFormat::Png => Err(ServerError::InvalidArgument(
+            "thumbnail_width applies to PNG output only".to_string(),
+        )),
+        Some(0) => Err(ServerError::InvalidArgument(
+            "thumbnail_width must be at least 1 pixel".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Validate Typst syntax without full compilation
 pub fn validate_syntax(source: &str) -> Vec<CompileError> {
     use typst::syntax::parse;
@@ -150,7 +268,9 @@ pub fn validate_syntax(source: &str) -> Vec<CompileError> {
 }
 
 /// Decode base64-encoded assets
-fn decode_assets(assets: &HashMap<String, String>) -> Result<HashMap<String, Bytes>, ServerError> {
+pub(super) fn decode_assets(
+    assets: &HashMap<String, String>,
+) -> Result<HashMap<String, Bytes>, ServerError> {
     let engine = base64::engine::general_purpose::STANDARD;
 
     assets
@@ -165,61 +285,130 @@ fn decode_assets(assets: &HashMap<String, String>) -> Result<HashMap<String, Byt
 }
 
 /// Export a compiled document to the requested format
+///
+/// PDF exports to one artifact. SVG and PNG export one artifact per
+/// selected page, or a single zip of them when `bundle` is set.
 fn export_document(
     document: &Document,
-    format: OutputFormat,
-    ppi: Option<u32>,
-) -> Result<RenderArtifact, ServerError> {
+    request: &RenderRequest,
+) -> Result<Vec<RenderArtifact>, ServerError> {
+    export_document_with(document, request, |_, page| export_page(page, request))
+}
+
+/// Export a compiled document, getting each SVG or PNG page from
+/// `export_page`, which is given the page's index
+pub(super) fn export_document_with(
+    document: &Document,
+    request: &RenderRequest,
+    mut export_page: impl FnMut(usize, &Page) -> Result<Vec<u8>, ServerError>,
+) -> Result<Vec<RenderArtifact>, ServerError> {
     let engine = base64::engine::general_purpose::STANDARD;
+    let format = request.format;
+    let page_count = document.pages.len();
 
-    match format {
-        OutputFormat::Pdf => {
-            let pdf_bytes = typst_pdf::pdf(document, &typst_pdf::PdfOptions::default())
-                .map_err(|e| ServerError::SourceError(format!("PDF export failed: {:?}", e)))?;
-            Ok(RenderArtifact {
-                data_base64: engine.encode(&pdf_bytes),
-                mime_type: format.mime_type().to_string(),
-                page_count: document.pages.len(),
-            })
-        }
-        OutputFormat::Svg => {
-            // Export first page as SVG
-            if let Some(page) = document.pages.first() {
-                let svg = typst_svg::svg(page);
-                Ok(RenderArtifact {
-                    data_base64: engine.encode(svg.as_bytes()),
-                    mime_type: format.mime_type().to_string(),
-                    page_count: document.pages.len(),
-                })
-            } else {
-                Err(ServerError::SourceError(
-                    "Document has no pages".to_string(),
-                ))
-            }
+    let indices = match &request.pages {
+        Some(selection) => selection.indices(page_count),
+        None => (0..page_count).collect(),
+    };
+    if indices.is_empty() {
+        return Err(match &request.pages {
+            Some(selection) => ServerError::InvalidArgument(format!(
+                "Pages '{}' are not in the document, which has {} pages",
+                selection, page_count
+            )),
+            None => ServerError::SourceError("Document has no pages".to_string()),
+        });
+    }
+
+    if format == OutputFormat::Pdf {
+        let options = typst_pdf::PdfOptions {
+            page_ranges: request.pages.as_ref().map(|p| p.to_page_ranges()),
+            ..Default::default()
+        };
+        let mut pdf_bytes = typst_pdf::pdf(document, &options)
+            .map_err(|e| ServerError::SourceError(format!("PDF export failed: {:?}", e)))?;
+        if request.tagged {
+            pdf_bytes = accessibility::tag_pdf(document, &indices, &pdf_bytes)
+                .map_err(|e| ServerError::SourceError(e.to_string()))?;
         }
+        return Ok(vec![RenderArtifact {
+            data_base64: engine.encode(&pdf_bytes),
+            mime_type: format.mime_type().to_string(),
+            page_count,
+            page: None,
+        }]);
+    }
+
+    let pages = indices
+        .into_iter()
+        .map(|index| Ok((index + 1, export_page(index, &document.pages[index])?)))
+        .collect::<Result<Vec<_>, ServerError>>()?;
+
+    if request.bundle {
+        return Ok(vec![RenderArtifact {
+            data_base64: engine.encode(bundle_pages(&pages, format)?),
+            mime_type: "application/zip".to_string(),
+            page_count,
+            page: None,
+        }]);
+    }
+
+    Ok(pages
+        .into_iter()
+        .map(|(page, data)| RenderArtifact {
+            data_base64: engine.encode(&data),
+            mime_type: format.mime_type().to_string(),
+            page_count,
+            page: Some(page),
+        })
+        .collect())
+}
+
+/// Export one page as SVG or PNG
+pub(super) fn export_page(page: &Page, request: &RenderRequest) -> Result<Vec<u8>, ServerError> {
+    match request.format {
+        OutputFormat::Svg => Ok(typst_svg::svg(page).into_bytes()),
         OutputFormat::Png => {
-            // Export first page as PNG
-            let pixels_per_point = ppi.unwrap_or(144) as f32 / 72.0;
-
-            if let Some(page) = document.pages.first() {
-                let pixmap = typst_render::render(page, pixels_per_point);
-                let png_bytes = pixmap
-                    .encode_png()
-                    .map_err(|e| ServerError::SourceError(format!("PNG encoding failed: {}", e)))?;
-                Ok(RenderArtifact {
-                    data_base64: engine.encode(&png_bytes),
-                    mime_type: format.mime_type().to_string(),
-                    page_count: document.pages.len(),
-                })
-            } else {
-                Err(ServerError::SourceError(
-                    "Document has no pages".to_string(),
-                ))
+            let mut pixels_per_point = request.ppi.unwrap_or(144) as f32 / 72.0;
+            // Thumbnails render at a lower resolution rather than scaling
+            // down a full-resolution render
+            if let Some(max_width) = request.thumbnail_width {
+                let width = page.frame.width().to_pt() as f32;
+                if width > 0.0 {
+                    pixels_per_point = pixels_per_point.min(max_width as f32 / width);
+                }
             }
+            typst_render::render(page, pixels_per_point)
+                .encode_png()
+                .map_err(|e| ServerError::SourceError(format!("PNG encoding failed: {}", e)))
         }
+        OutputFormat::Pdf => unreachable!("PDF is exported as a whole document"),
     }
 }
 
+/// Zip exported pages as page-1.png, page-2.png, ...
+fn bundle_pages(pages: &[(usize, Vec<u8>)], format: OutputFormat) -> Result<Vec<u8>, ServerError> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    // PNG data is already compressed
+    let options = SimpleFileOptions::default().compression_method(match format {
+        OutputFormat::Png => zip::CompressionMethod::Stored,
+        _ => zip::CompressionMethod::Deflated,
+    });
+    let bundle_error = |e: zip::result::ZipError| {
+        ServerError::SourceError(format!("Bundling pages failed: {}", e))
+    };
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+    for (page, data) in pages {
+        zip.start_file(format!("page-{}.{}", page, format.extension()), options)
+            .map_err(bundle_error)?;
+        zip.write_all(data)?;
+    }
+    Ok(zip.finish().map_err(bundle_error)?.into_inner())
+}
+
 /// Categorize diagnostics into errors and warnings
 fn categorize_diagnostics(
     diagnostics: &[SourceDiagnostic],
@@ -264,6 +453,7 @@ mod tests {
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -287,6 +477,7 @@ Hello, #name!"#
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -301,6 +492,7 @@ Hello, #name!"#
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -313,12 +505,18 @@ Hello, #name!"#
 
     #[test]
     fn test_compile_sync_template() {
+        let mut inputs = HashMap::new();
+        inputs.insert("sender_name".to_string(), serde_json::json!("Alice"));
+        inputs.insert("recipient_name".to_string(), serde_json::json!("Bob"));
+        inputs.insert("body".to_string(), serde_json::json!("Hello."));
+
         let request = RenderRequest {
             source: "typst://templates/letter".to_string(),
-            inputs: HashMap::new(),
+            inputs,
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document_sync(request);
@@ -328,6 +526,221 @@ Hello, #name!"#
         assert_eq!(response.status, RenderStatus::Success);
     }
 
+    #[test]
+    fn test_compile_sync_template_rejects_invalid_inputs() {
+        let mut inputs = HashMap::new();
+        inputs.insert("landlord_name".to_string(), serde_json::json!("Owner LLC"));
+        inputs.insert("monthly_rent".to_string(), serde_json::json!("TBD"));
+        inputs.insert(
+            "tenant_email".to_string(),
+            serde_json::json!("tenant at example"),
+        );
+
+        let request = RenderRequest {
+            source: "typst://templates/florida_lease".to_string(),
+            inputs,
+            assets: HashMap::new(),
+            format: OutputFormat::Pdf,
+            ppi: None,
+            ..Default::default()
+        };
+
+        let response = compile_document_sync(request).unwrap();
+        assert_eq!(response.status, RenderStatus::Error);
+        assert!(response.artifact.is_none());
+        assert!(response.errors.is_empty());
+
+        let fields: Vec<&str> = response
+            .input_errors
+            .iter()
+            .map(|e| e.field.as_str())
+            .collect();
+        assert!(fields.contains(&"tenant_name"));
+        assert!(fields.contains(&"monthly_rent"));
+        assert!(fields.contains(&"tenant_email"));
+        assert!(!fields.contains(&"landlord_name"));
+    }
+
+    #[test]
+    fn test_compile_sync_template_package() {
+        let package = templates::TemplatePackage::from_files(vec![
+            (
+                "memo/template.toml".to_string(),
+                b"[template]\nname = \"render_test_memo\"\nversion = \"0.3.1\"\nrequired_inputs = [\"to\"]\n"
+                    .to_vec(),
+            ),
+            (
+                "memo/main.typ".to_string(),
+                br#"#import "partials/header.typ": header
+#header
+To: #sys.inputs.at("to")"#
+                    .to_vec(),
+            ),
+            (
+                "memo/partials/header.typ".to_string(),
+                b"#let header = [= Memo]".to_vec(),
+            ),
+        ])
+        .unwrap();
+        templates::register_package(package).unwrap();
+
+        let request = |source: &str, inputs: serde_json::Value| RenderRequest {
+            source: source.to_string(),
+            inputs: serde_json::from_value(inputs).unwrap(),
+            assets: HashMap::new(),
+            format: OutputFormat::Pdf,
+            ppi: None,
+            ..Default::default()
+        };
+
+        let response = compile_document_sync(request(
+            "typst://templates/render_test_memo@0.3.1",
+            serde_json::json!({"to": "Staff"}),
+        ))
+        .unwrap();
+        assert_eq!(
+            response.status,
+            RenderStatus::Success,
+            "{:?}",
+            response.errors
+        );
+
+        // The manifest's required inputs are enforced like any template's
+        let response = compile_document_sync(request(
+            "typst://templates/render_test_memo",
+            serde_json::json!({}),
+        ))
+        .unwrap();
+        assert_eq!(response.input_errors[0].field, "to");
+
+        assert!(matches!(
+            compile_document_sync(request(
+                "typst://templates/render_test_memo@1",
+                serde_json::json!({"to": "Staff"}),
+            )),
+            Err(ServerError::TemplateNotFound(_))
+        ));
+    }
+
+    fn three_pages(format: OutputFormat) -> RenderRequest {
+        RenderRequest {
+            source:
+                "#set page(width: 200pt, height: 100pt)\nOne #pagebreak() Two #pagebreak() Three"
+                    .to_string(),
+            format,
+            ..Default::default()
+        }
+    }
+
+    fn decode(artifact: &RenderArtifact) -> Vec<u8> {
+        base64::engine::general_purpose::STANDARD
+            .decode(&artifact.data_base64)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_compile_sync_exports_every_page() {
+        for format in [OutputFormat::Svg, OutputFormat::Png] {
+            let response = compile_document_sync(three_pages(format)).unwrap();
+            let pages: Vec<_> = response.pages.iter().map(|p| p.page).collect();
+            assert_eq!(pages, [Some(1), Some(2), Some(3)], "{}", format);
+            assert!(response.pages.iter().all(|p| p.page_count == 3));
+            assert_eq!(response.artifact.unwrap().page, Some(1));
+        }
+    }
+
+    #[test]
+    fn test_compile_sync_page_range() {
+        let response = compile_document_sync(RenderRequest {
+            pages: Some("2-".parse().unwrap()),
+            ..three_pages(OutputFormat::Svg)
+        })
+        .unwrap();
+        let pages: Vec<_> = response.pages.iter().map(|p| p.page).collect();
+        assert_eq!(pages, [Some(2), Some(3)]);
+
+        let response = compile_document_sync(RenderRequest {
+            pages: Some("2".parse().unwrap()),
+            ..three_pages(OutputFormat::Pdf)
+        })
+        .unwrap();
+        assert!(response.pages.is_empty());
+        let pdf = decode(response.artifact.as_ref().unwrap());
+        let pages = pdf_extract::extract_text_from_mem_by_pages(&pdf).unwrap();
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].contains("Two"));
+
+        let result = compile_document_sync(RenderRequest {
+            pages: Some("7-9".parse().unwrap()),
+            ..three_pages(OutputFormat::Png)
+        });
+        assert!(matches!(result, Err(ServerError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_compile_sync_bundle() {
+        let response = compile_document_sync(RenderRequest {
+            bundle: true,
+            ..three_pages(OutputFormat::Png)
+        })
+        .unwrap();
+        assert!(response.pages.is_empty());
+        let artifact = response.artifact.unwrap();
+        assert_eq!(artifact.mime_type, "application/zip");
+
+        let zip = zip::ZipArchive::new(std::io::Cursor::new(decode(&artifact))).unwrap();
+        let mut names: Vec<_> = zip.file_names().collect();
+        names.sort();
+        assert_eq!(names, ["page-1.png", "page-2.png", "page-3.png"]);
+    }
+
+    #[test]
+    fn test_compile_sync_thumbnail() {
+        let png_width = |request: RenderRequest| {
+            let response = compile_document_sync(request).unwrap();
+            let png = decode(&response.pages[0]);
+            // Width is the first field of the IHDR chunk
+            u32::from_be_bytes(png[16..20].try_into().unwrap())
+        };
+
+        // 200pt at 144 ppi is 400px; a thumbnail caps that
+        assert_eq!(png_width(three_pages(OutputFormat::Png)), 400);
+        let thumbnail = png_width(RenderRequest {
+            thumbnail_width: Some(100),
+            ..three_pages(OutputFormat::Png)
+        });
+        assert_eq!(thumbnail, 100);
+        // Never upscales past the requested ppi
+        let small = png_width(RenderRequest {
+            thumbnail_width: Some(1000),
+            ppi: Some(72),
+            ..three_pages(OutputFormat::Png)
+        });
+        assert_eq!(small, 200);
+    }
+
+    #[test]
+    fn test_compile_sync_rejects_mismatched_export_options() {
+        let bundled_pdf = RenderRequest {
+            bundle: true,
+            ..three_pages(OutputFormat::Pdf)
+        };
+        let svg_thumbnail = RenderRequest {
+            thumbnail_width: Some(100),
+            ..three_pages(OutputFormat::Svg)
+        };
+        let tagged_svg = RenderRequest {
+            tagged: true,
+            ..three_pages(OutputFormat::Svg)
+        };
+        for request in [bundled_pdf, svg_thumbnail, tagged_svg] {
+            assert!(matches!(
+                compile_document_sync(request),
+                Err(ServerError::InvalidArgument(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_validate_syntax_valid() {
         let errors = validate_syntax("Hello, World!");
@@ -351,6 +764,7 @@ Hello, #name!"#
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document(request, 5000).await;
@@ -375,6 +789,7 @@ Hello, #name!"#
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document(request, 5000).await;
@@ -390,6 +805,7 @@ Hello, #name!"#
             assets: HashMap::new(),
             format: OutputFormat::Pdf,
             ppi: None,
+            ..Default::default()
         };
 
         let result = compile_document(request, 5000).await;
@@ -0,0 +1,423 @@
+//! Persistent compiler session for interactive editing
+//!
+//! [`compile_document_sync`](super::compile_document_sync) builds a fresh
+//! world for every request. A [`CompilerSession`] keeps one world alive
+//! between renders instead: a new request only swaps the main source and
+//! `sys.inputs`, so comemo reuses parsing and the layout of everything the
+//! change did not touch, and fonts stay loaded. Pages whose frames did not
+//! change are not exported again, and identical requests are served from an
+//! [`ArtifactCache`] without compiling at all.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use typst::layout::Page;
+use typst::utils::hash128;
+
+use super::cache::{request_key, ArtifactCache, CachedRender, DEFAULT_CACHE_CAPACITY};
+use super::errors::{RenderStatus, ServerError};
+use super::render::{
+    check_export_options, decode_assets, export_document_with, export_page,
+    invalid_inputs_response, prepare_inputs, process_compile_result_with, resolve_source,
+    ResolvedSource,
+};
+use super::{RenderRequest, RenderResponse};
+use crate::templates;
+use crate::world::VirtualWorld;
+
+/// Renders since last use after which comemo drops a memoized result
+const MAX_MEMO_AGE: usize = 10;
+
+/// Counters of the work a session did and avoided
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SessionStats {
+    /// Render requests whose inputs passed validation
+    pub renders: usize,
+    /// Renders served from the artifact cache
+    pub cache_hits: usize,
+    /// Times the world was built, on the first render and whenever the
+    /// template, its package, the assets or tagging changed
+    pub world_builds: usize,
+}
+
+/// A compiler that stays warm across renders of the same document
+///
+/// Renders through a session report `changed_pages`: the one-indexed pages
+/// that differ from the previous successful render with the same format,
+/// page selection and export options, or that are new. Every page counts as
+/// changed on the first render of each such view, so a PDF download in
+/// between two previews does not hide what changed from the preview.
+///
+/// Compilation runs synchronously, without the timeout of
+/// [`compile_document`](super::compile_document).
+pub struct CompilerSession {
+    world: Option<VirtualWorld>,
+    /// Hash of the template, package, assets and tagging the world was
+    /// built with
+    world_key: Option<u128>,
+    /// Page hashes of the previous successful render, keyed by
+    /// [`view_key`]
+    page_hashes: HashMap<u128, Vec<u128>>,
+    /// Pages exported by the previous compiled render, keyed by
+    /// [`page_export_key`]
+    page_exports: HashMap<u128, Vec<u8>>,
+    cache: ArtifactCache,
+    stats: SessionStats,
+}
+
+impl Default for CompilerSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompilerSession {
+    pub fn new() -> Self {
+        Self::with_cache_capacity(DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Create a session caching at most `capacity` finished renders
+    pub fn with_cache_capacity(capacity: usize) -> Self {
+        Self {
+            world: None,
+            world_key: None,
+            page_hashes: HashMap::new(),
+            page_exports: HashMap::new(),
+            cache: ArtifactCache::new(capacity),
+            stats: SessionStats::default(),
+        }
+    }
+
+    /// Render a document, reusing as much of the previous render as possible
+    pub fn render(&mut self, request: &RenderRequest) -> Result<RenderResponse, ServerError> {
+        check_export_options(request)?;
+        let resolved = resolve_source(&request.source)?;
+        let inputs = match prepare_inputs(request) {
+            Ok(inputs) => inputs,
+            Err(input_errors) => return Ok(invalid_inputs_response(input_errors)),
+        };
+        self.stats.renders += 1;
+
+        let key = request_key(&resolved.source, resolved.package.as_ref(), request);
+        let view = view_key(request);
+        if let Some(cached) = self.cache.get(key) {
+            let mut response = cached.response.clone();
+            let page_hashes = cached.page_hashes.clone();
+            response.changed_pages = Some(self.update_view(view, page_hashes));
+            self.stats.cache_hits += 1;
+            return Ok(response);
+        }
+
+        let world = self.prepare_world(request, resolved, inputs)?;
+        let compile_result = typst::compile(world);
+        comemo::evict(MAX_MEMO_AGE);
+
+        let previous_exports = std::mem::take(&mut self.page_exports);
+        let mut page_exports = HashMap::new();
+        let mut page_hashes = vec![];
+        let response = process_compile_result_with(compile_result, |document| {
+            page_hashes = document.pages.iter().map(page_hash).collect();
+            export_document_with(document, request, |index, page| {
+                let key = page_export_key(page_hashes[index], request);
+                let data = match previous_exports.get(&key) {
+                    Some(data) => data.clone(),
+                    None => export_page(page, request)?,
+                };
+                page_exports.insert(key, data.clone());
+                Ok(data)
+            })
+        });
+
+        let mut response = match response {
+            Ok(response) if response.status == RenderStatus::Success => response,
+            other => {
+                // Keep diffing against the last good render
+                self.page_exports = previous_exports;
+                return other;
+            }
+        };
+        self.cache.insert(
+            key,
+            CachedRender {
+                response: response.clone(),
+                page_hashes: page_hashes.clone(),
+            },
+        );
+        response.changed_pages = Some(self.update_view(view, page_hashes));
+        self.page_exports = page_exports;
+        Ok(response)
+    }
+
+    /// Record the page hashes of a view's latest render, returning the
+    /// pages that changed since its previous one
+    fn update_view(&mut self, view: u128, page_hashes: Vec<u128>) -> Vec<usize> {
+        let previous = self.page_hashes.insert(view, page_hashes);
+        changed_pages(
+            previous.as_deref().unwrap_or_default(),
+            &self.page_hashes[&view],
+        )
+    }
+
+    /// Drop all cached renders; the warm world is kept
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn stats(&self) -> SessionStats {
+        self.stats
+    }
+
+    /// The world for `request`, updated in place when the template, its
+    /// package, the assets and tagging are the same as last time and
+    /// rebuilt otherwise
+    fn prepare_world(
+        &mut self,
+        request: &RenderRequest,
+        resolved: ResolvedSource,
+        inputs: HashMap<String, serde_json::Value>,
+    ) -> Result<&VirtualWorld, ServerError> {
+        let assets: BTreeMap<_, _> = request.assets.iter().collect();
+        let world_key = hash128(&(
+            templates::registry::parse_template_uri(&request.source),
+            &resolved.package,
+            assets,
+            request.tagged,
+        ));
+        if self.world_key != Some(world_key) {
+            self.world = None;
+        }
+
+        match self.world {
+            Some(ref mut world) => {
+                world.set_source(resolved.source);
+                world.set_inputs(inputs)?;
+                Ok(world)
+            }
+            None => {
+                let mut assets = resolved.assets;
+                assets.extend(decode_assets(&request.assets)?);
+                let world = VirtualWorld::new(resolved.source, inputs, assets)?
+                    .with_fonts(resolved.fonts)
+                    .with_structure_markers(request.tagged);
+                self.world_key = Some(world_key);
+                self.stats.world_builds += 1;
+                Ok(self.world.insert(world))
+            }
+        }
+    }
+}
+
+/// Hash of everything that ends up on a page
+fn page_hash(page: &Page) -> u128 {
+    hash128(&(&page.frame, &page.fill))
+}
+
+/// Key of what a client sees of a render: the template, the format, the
+/// selected pages and the export options, but not the inputs. Raw sources
+/// share one view per set of options.
+fn view_key(request: &RenderRequest) -> u128 {
+    hash128(&(
+        templates::registry::parse_template_uri(&request.source),
+        request.format.extension(),
+        request.pages.as_ref().map(ToString::to_string),
+        request.ppi,
+        request.thumbnail_width,
+        request.bundle,
+        request.tagged,
+    ))
+}
+
+/// Key of a page exported with the request's export options
+fn page_export_key(page_hash: u128, request: &RenderRequest) -> u128 {
+    hash128(&(
+        page_hash,
+        request.format.extension(),
+        request.ppi,
+        request.thumbnail_width,
+    ))
+}
+
+/// One-indexed pages of `current` that differ from `previous` or are new
+fn changed_pages(previous: &[u128], current: &[u128]) -> Vec<usize> {
+    current
+        .iter()
+        .enumerate()
+        .filter(|(index, hash)| previous.get(*index) != Some(*hash))
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::OutputFormat;
+
+    const SOURCE: &str = r#"#set page(width: 200pt, height: 100pt)
+#let name = sys.inputs.at("name", default: "nobody")
+First page
+#pagebreak()
+Hello #name
+#pagebreak()
+Last page"#;
+
+    fn request(name: &str) -> RenderRequest {
+        RenderRequest {
+            source: SOURCE.to_string(),
+            inputs: HashMap::from([("name".to_string(), serde_json::json!(name))]),
+            format: OutputFormat::Svg,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_reports_changed_pages() {
+        let mut session = CompilerSession::new();
+
+        let first = session.render(&request("Alice")).unwrap();
+        assert_eq!(first.status, RenderStatus::Success);
+        assert_eq!(first.changed_pages, Some(vec![1, 2, 3]));
+
+        let second = session.render(&request("Bob")).unwrap();
+        assert_eq!(second.changed_pages, Some(vec![2]));
+        assert_eq!(second.pages.len(), 3);
+        assert_eq!(second.pages[0].data_base64, first.pages[0].data_base64);
+        assert_ne!(second.pages[1].data_base64, first.pages[1].data_base64);
+
+        assert_eq!(session.stats().world_builds, 1);
+        assert_eq!(session.stats().cache_hits, 0);
+    }
+
+    #[test]
+    fn test_identical_request_is_served_from_cache() {
+        let mut session = CompilerSession::new();
+        let first = session.render(&request("Alice")).unwrap();
+        session.render(&request("Bob")).unwrap();
+
+        let again = session.render(&request("Alice")).unwrap();
+        assert_eq!(session.stats().cache_hits, 1);
+        assert_eq!(again.changed_pages, Some(vec![2]));
+        assert_eq!(again.pages[1].data_base64, first.pages[1].data_base64);
+
+        let repeated = session.render(&request("Alice")).unwrap();
+        assert_eq!(repeated.changed_pages, Some(vec![]));
+        assert_eq!(session.stats().cache_hits, 2);
+    }
+
+    #[test]
+    fn test_views_report_their_own_changes() {
+        let mut session = CompilerSession::new();
+        session.render(&request("Alice")).unwrap();
+
+        let mut pdf = request("Bob");
+        pdf.format = OutputFormat::Pdf;
+        let download = session.render(&pdf).unwrap();
+        assert_eq!(download.changed_pages, Some(vec![1, 2, 3]));
+
+        // The SVG preview last showed Alice
+        let preview = session.render(&request("Bob")).unwrap();
+        assert_eq!(preview.changed_pages, Some(vec![2]));
+
+        let mut first_page = request("Bob");
+        first_page.pages = Some("1".parse().unwrap());
+        let selected = session.render(&first_page).unwrap();
+        assert_eq!(selected.changed_pages, Some(vec![1, 2, 3]));
+        assert_eq!(session.stats().world_builds, 1);
+
+        // Another template's preview doesn't reset this one's
+        let mut letter = request("Bob");
+        letter.source = "typst://templates/letter".to_string();
+        letter.inputs = HashMap::from([
+            ("sender_name".to_string(), serde_json::json!("Bob")),
+            ("recipient_name".to_string(), serde_json::json!("Alice")),
+            ("body".to_string(), serde_json::json!("Hello")),
+        ]);
+        session.render(&letter).unwrap();
+        let preview = session.render(&request("Carol")).unwrap();
+        assert_eq!(preview.changed_pages, Some(vec![2]));
+    }
+
+    #[test]
+    fn test_republished_package_is_not_served_stale() {
+        let publish = |body: &str| {
+            let package = templates::TemplatePackage::from_files(vec![
+                (
+                    "template.toml".to_string(),
+                    b"[template]\nname = \"session_test_notice\"\nversion = \"1.0.0\"\n".to_vec(),
+                ),
+                ("main.typ".to_string(), b"#include \"body.typ\"".to_vec()),
+                ("body.typ".to_string(), body.as_bytes().to_vec()),
+            ])
+            .unwrap();
+            templates::register_package(package).unwrap();
+        };
+        let request = RenderRequest {
+            source: "typst://templates/session_test_notice@1.0.0".to_string(),
+            format: OutputFormat::Svg,
+            ..Default::default()
+        };
+        let mut session = CompilerSession::new();
+
+        publish("First edition");
+        let first = session.render(&request).unwrap();
+        publish("Second edition");
+        let second = session.render(&request).unwrap();
+
+        assert_eq!(second.status, RenderStatus::Success);
+        assert_ne!(second.pages[0].data_base64, first.pages[0].data_base64);
+        assert_eq!(second.changed_pages, Some(vec![1]));
+        assert_eq!(session.stats().cache_hits, 0);
+        assert_eq!(session.stats().world_builds, 2);
+    }
+
+    #[test]
+    fn test_source_edit_keeps_world() {
+        let mut session = CompilerSession::with_cache_capacity(0);
+        session.render(&request("Alice")).unwrap();
+
+        let mut edited = request("Alice");
+        edited.source = SOURCE.replace("Last page", "Final page\n#pagebreak()\nAppendix");
+        let response = session.render(&edited).unwrap();
+        assert_eq!(response.changed_pages, Some(vec![3, 4]));
+        assert_eq!(session.stats().world_builds, 1);
+
+        let mut with_asset = edited.clone();
+        with_asset
+            .assets
+            .insert("data.txt".to_string(), "aGk=".to_string());
+        session.render(&with_asset).unwrap();
+        assert_eq!(session.stats().world_builds, 2);
+    }
+
+    #[test]
+    fn test_errors_keep_previous_render() {
+        let mut session = CompilerSession::new();
+        session.render(&request("Alice")).unwrap();
+
+        let mut broken = request("Alice");
+        broken.source = format!("{}\n#unknown_function()", SOURCE);
+        let response = session.render(&broken).unwrap();
+        assert_eq!(response.status, RenderStatus::Error);
+        assert_eq!(response.changed_pages, None);
+
+        let fixed = session.render(&request("Bob")).unwrap();
+        assert_eq!(fixed.changed_pages, Some(vec![2]));
+    }
+
+    #[test]
+    fn test_template_inputs_are_validated() {
+        let mut session = CompilerSession::new();
+        let response = session
+            .render(&RenderRequest {
+                source: "typst://templates/invoice".to_string(),
+                inputs: HashMap::from([(
+                    "items".to_string(),
+                    serde_json::json!([{"description": "Hosting", "price": "free"}]),
+                )]),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(response.status, RenderStatus::Error);
+        assert!(!response.input_errors.is_empty());
+        assert_eq!(session.stats().renders, 0);
+    }
+}
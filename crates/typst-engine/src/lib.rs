@@ -3,21 +3,28 @@
 //! This crate provides Typst compilation and document verification
 //! functionality, including:
 //! - Document rendering to PDF/SVG/PNG (sync and async)
-//! - Florida lease verification
+//! - Warm compiler sessions with an artifact cache for interactive previews
+//! - Lease verification for every state compliance-engine covers
+//! - Compliance check of rendered lease templates (`render_and_verify`)
 //! - Template management
+//! - Tagged (accessible) PDF output and accessibility checks
 //!
 //! # Feature Flags
 //!
 //! - `server` (default): Enables async `compile_document` with timeout (requires tokio)
 //! - `wasm`: For browser/WASM environments (use `compile_document_sync`)
 
+pub mod accessibility;
 pub mod compiler;
 pub mod templates;
 pub mod verifier;
 pub mod world;
 
+pub use accessibility::{check_accessibility, AccessibilityReport};
+
 // Always export sync version (WASM-compatible)
-pub use compiler::{compile_document_sync, RenderRequest, RenderResponse};
+pub use compiler::{compile_document_sync, CompilerSession, RenderRequest, RenderResponse};
+pub use verifier::{render_and_verify, ViolationPolicy};
 
 // Export async version only with server feature
 #[cfg(feature = "server")]
@@ -46,6 +46,10 @@ const FLORIDA_COMMERCIAL_LEASE_TEMPLATE: &str =
 /// Professional property valuation report with comps and investment metrics
 const CMA_TEMPLATE: &str = include_str!("../../templates/cma.typ");
 
+/// Compliance Report template - loaded from templates/compliance_report.typ
+/// Branded findings report fed by compliance_engine::report::typst_inputs
+const COMPLIANCE_REPORT_TEMPLATE: &str = include_str!("../../templates/compliance_report.typ");
+
 /// Get an embedded template by name
 pub fn get_embedded_template(name: &str) -> Option<String> {
     match name {
@@ -60,6 +64,7 @@ pub fn get_embedded_template(name: &str) -> Option<String> {
         "florida_purchase_as_is" => Some(FLORIDA_PURCHASE_AS_IS_TEMPLATE.to_string()),
         "florida_commercial_lease" => Some(FLORIDA_COMMERCIAL_LEASE_TEMPLATE.to_string()),
         "cma" => Some(CMA_TEMPLATE.to_string()),
+        "compliance_report" => Some(COMPLIANCE_REPORT_TEMPLATE.to_string()),
         _ => None,
     }
 }
@@ -78,6 +83,7 @@ pub fn list_embedded_templates() -> Vec<&'static str> {
         "florida_purchase_as_is",
         "florida_commercial_lease",
         "cma",
+        "compliance_report",
     ]
 }
 
@@ -583,7 +589,7 @@ mod tests {
     #[test]
     fn test_list_embedded_templates() {
         let templates = list_embedded_templates();
-        assert_eq!(templates.len(), 11);
+        assert_eq!(templates.len(), 12);
         assert!(templates.contains(&"invoice"));
         assert!(templates.contains(&"letter"));
         assert!(templates.contains(&"florida_lease"));
@@ -595,6 +601,15 @@ mod tests {
         assert!(templates.contains(&"florida_purchase_as_is"));
         assert!(templates.contains(&"florida_commercial_lease"));
         assert!(templates.contains(&"cma"));
+        assert!(templates.contains(&"compliance_report"));
+    }
+
+    #[test]
+    fn test_get_compliance_report_template() {
+        let content = get_embedded_template("compliance_report").unwrap();
+        assert!(content.contains("sys.inputs"));
+        assert!(content.contains("link(entry.statute_url)"));
+        assert!(content.contains("entry.excerpt"));
     }
 
     #[test]
@@ -1,6 +1,13 @@
-//! Template management and embedded templates
+//! Template management: embedded templates and runtime template packages
 
 pub mod embedded;
+pub mod package;
 pub mod registry;
+pub mod schema;
 
-pub use registry::{get_template_source, list_templates, TemplateInfo};
+pub use package::{PackageIdentity, PackageManifest, TemplatePackage};
+pub use registry::{
+    find_package, get_template_source, list_templates, load_packages_from_dir, register_package,
+    template_schema, TemplateInfo,
+};
+pub use schema::{FieldFormat, FieldSchema, FieldType, InputSchema};
@@ -0,0 +1,614 @@
+//! User-supplied template packages
+//!
+//! A package is a directory or zip archive holding a `template.toml`
+//! manifest, an entrypoint `.typ` file, and the partials, images and fonts
+//! it uses:
+//!
+//! ```text
+//! letterhead/
+//! ├── template.toml
+//! ├── main.typ
+//! ├── partials/header.typ
+//! ├── images/logo.png
+//! └── fonts/Brand-Regular.otf
+//! ```
+//!
+//! ```toml
+//! [template]
+//! name = "letterhead"
+//! version = "1.2.0"
+//! description = "Firm letter on letterhead"
+//! entrypoint = "main.typ"
+//! required_inputs = ["recipient_name", "body"]
+//! optional_inputs = ["date"]
+//! ```
+//!
+//! Files are mounted at their package paths, so `#import "partials/header.typ"`
+//! and `image("images/logo.png")` work as they would on disk.
+//!
+//! # Input types
+//!
+//! Inputs are typed the way the built-in templates are (see
+//! [`super::schema`]). An input read through a `get_num` helper is a number
+//! and one read through `get_bool` a boolean; anything else is text, with
+//! the default from `get("x", default: "y")`. Only the helper names matter,
+//! so a package defines them as the built-in templates do:
+//!
+//! ```typst
+//! #let data = sys.inputs
+//! #let get(key, default: "") = data.at(key, default: default)
+//! #let get_bool(key) = {
+//!   let val = data.at(key, default: false)
+//!   if type(val) == str { val == "true" } else { val == true }
+//! }
+//! #let get_num(key, default: 0) = float(data.at(key, default: default))
+//! ```
+//!
+//! Anything the source cannot tell (formats, allowed values, help text, or
+//! a type read some other way) is declared in the manifest. Declared fields
+//! replace the inferred ones and must also be listed as required or
+//! optional:
+//!
+//! ```toml
+//! [[template.inputs]]
+//! name = "date"
+//! type = "string"
+//! format = "date"
+//! help = "Date printed under the letterhead"
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+use std::path::Path;
+
+use semver::Version;
+use serde::Deserialize;
+use typst::foundations::Bytes;
+use typst::utils::hash128;
+
+use super::registry::TemplateInfo;
+use super::schema::{build_schema, FieldSchema};
+use crate::compiler::errors::ServerError;
+
+/// Name of the manifest file at the package root
+pub const MANIFEST_FILE: &str = "template.toml";
+
+/// File extensions loaded as fonts rather than mounted as files
+const FONT_EXTENSIONS: &[&str] = &["ttf", "otf", "ttc", "otc"];
+
+/// Most entries a package archive may hold
+const MAX_ZIP_ENTRIES: usize = 1024;
+
+/// Largest uncompressed size of one archive entry
+const MAX_ZIP_ENTRY_SIZE: u64 = 32 * 1024 * 1024;
+
+/// Largest uncompressed size of a whole archive
+const MAX_ZIP_TOTAL_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The `[template]` table of a package manifest
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackageManifest {
+    /// Template name, used in `typst://templates/<name>@<version>` URIs
+    pub name: String,
+    /// Semantic version of this release of the template
+    pub version: Version,
+    /// Human-readable description
+    #[serde(default)]
+    pub description: String,
+    /// Main `.typ` file, relative to the package root
+    #[serde(default = "default_entrypoint")]
+    pub entrypoint: String,
+    /// Required input fields
+    #[serde(default)]
+    pub required_inputs: Vec<String>,
+    /// Optional input fields
+    #[serde(default)]
+    pub optional_inputs: Vec<String>,
+    /// Schemas of inputs whose type cannot be read from the source
+    #[serde(default)]
+    pub inputs: Vec<FieldSchema>,
+}
+
+fn default_entrypoint() -> String {
+    "main.typ".to_string()
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    template: PackageManifest,
+}
+
+/// Which release of a package, down to its exact files
+///
+/// A package can be registered again under the same name and version with
+/// different files, so renders are keyed by the content hash as well.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackageIdentity {
+    pub name: String,
+    pub version: Version,
+    /// Hash of the manifest and every file of the package
+    pub content_hash: u128,
+}
+
+/// A template package loaded into memory
+#[derive(Debug, Clone)]
+pub struct TemplatePackage {
+    /// Parsed manifest
+    pub manifest: PackageManifest,
+    /// Source of the entrypoint
+    source: String,
+    /// Every other file, keyed by its path from the package root
+    files: BTreeMap<String, Bytes>,
+    /// Hash of the manifest and every file
+    content_hash: u128,
+}
+
+impl TemplatePackage {
+    /// Load a package from a directory containing `template.toml`
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, ServerError> {
+        let dir = dir.as_ref();
+        let mut files = vec![];
+        read_dir_files(dir, dir, &mut files)?;
+        Self::from_files(files)
+    }
+
+    /// Load a package from a zip archive
+    ///
+    /// The manifest may be at the archive root or inside a single top-level
+    /// directory, as produced by zipping the package directory itself.
+    /// Archives with more than 1024 entries, an entry over 32 MiB or over
+    /// 64 MiB in all once uncompressed are rejected.
+    pub fn from_zip(data: &[u8]) -> Result<Self, ServerError> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data))
+            .map_err(|e| ServerError::InvalidPackage(format!("Unreadable zip archive: {}", e)))?;
+        if archive.len() > MAX_ZIP_ENTRIES {
+            return Err(ServerError::InvalidPackage(format!(
+                "Archive has {} entries, more than the limit of {}",
+                archive.len(),
+                MAX_ZIP_ENTRIES
+            )));
+        }
+
+        let mut files = vec![];
+        let mut remaining = MAX_ZIP_TOTAL_SIZE;
+        for index in 0..archive.len() {
+            let entry = archive
+                .by_index(index)
+                .map_err(|e| ServerError::InvalidPackage(e.to_string()))?;
+            if entry.is_dir() {
+                continue;
+            }
+            let name = entry.name().to_string();
+            let too_large = || {
+                ServerError::InvalidPackage(format!(
+                    "Archive entry '{}' is larger than the package size limit",
+                    name
+                ))
+            };
+            // The declared size may lie, so the read itself is bounded too
+            let limit = MAX_ZIP_ENTRY_SIZE.min(remaining);
+            if entry.size() > limit {
+                return Err(too_large());
+            }
+            let mut content = vec![];
+            entry.take(limit + 1).read_to_end(&mut content)?;
+            if content.len() as u64 > limit {
+                return Err(too_large());
+            }
+            remaining -= content.len() as u64;
+            files.push((name, content));
+        }
+        Self::from_files(files)
+    }
+
+    /// Build a package from its files, keyed by path
+    pub fn from_files(
+        files: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<Self, ServerError> {
+        let files: Vec<(String, Vec<u8>)> = files
+            .into_iter()
+            .map(|(path, content)| (path.replace('\\', "/"), content))
+            .collect();
+
+        // The package root is wherever the shallowest manifest lives
+        let root = files
+            .iter()
+            .filter_map(|(path, _)| path.strip_suffix(MANIFEST_FILE))
+            .filter(|prefix| prefix.is_empty() || prefix.ends_with('/'))
+            .min_by_key(|prefix| prefix.len())
+            .map(str::to_string)
+            .ok_or_else(|| ServerError::InvalidPackage(format!("No {} found", MANIFEST_FILE)))?;
+
+        let mut manifest = None;
+        let mut package_files = BTreeMap::new();
+        for (path, content) in files {
+            let Some(relative) = path.strip_prefix(&root) else {
+                continue;
+            };
+            let relative = relative.trim_start_matches('/');
+            if relative.split('/').any(|part| part == "..") {
+                return Err(ServerError::PathSecurityViolation(format!(
+                    "Package file '{}' escapes the package root",
+                    path
+                )));
+            }
+            if relative == MANIFEST_FILE {
+                manifest = Some(content);
+            } else {
+                package_files.insert(relative.to_string(), Bytes::from(content));
+            }
+        }
+
+        let manifest = manifest.unwrap_or_default();
+        let content_hash = hash128(&(&manifest, &package_files));
+        let manifest = std::str::from_utf8(&manifest)
+            .map_err(|_| ServerError::InvalidPackage(format!("{} is not UTF-8", MANIFEST_FILE)))?;
+        let manifest = toml::from_str::<ManifestFile>(manifest)
+            .map_err(|e| ServerError::InvalidPackage(format!("Invalid {}: {}", MANIFEST_FILE, e)))?
+            .template;
+        validate_manifest(&manifest)?;
+
+        let entrypoint = package_files.remove(&manifest.entrypoint).ok_or_else(|| {
+            ServerError::InvalidPackage(format!(
+                "Entrypoint '{}' is not in the package",
+                manifest.entrypoint
+            ))
+        })?;
+        let source = String::from_utf8(entrypoint.to_vec()).map_err(|_| {
+            ServerError::InvalidPackage(format!(
+                "Entrypoint '{}' is not UTF-8",
+                manifest.entrypoint
+            ))
+        })?;
+
+        Ok(Self {
+            manifest,
+            source,
+            files: package_files,
+            content_hash,
+        })
+    }
+
+    /// Template name
+    pub fn name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    /// Template version
+    pub fn version(&self) -> &Version {
+        &self.manifest.version
+    }
+
+    /// Name, version and content hash of this package
+    pub fn identity(&self) -> PackageIdentity {
+        PackageIdentity {
+            name: self.manifest.name.clone(),
+            version: self.manifest.version.clone(),
+            content_hash: self.content_hash,
+        }
+    }
+
+    /// Versioned URI of this package (`typst://templates/<name>@<version>`)
+    pub fn uri(&self) -> String {
+        format!(
+            "typst://templates/{}@{}",
+            self.manifest.name, self.manifest.version
+        )
+    }
+
+    /// Source of the entrypoint
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Files to mount next to the entrypoint: partials, images and data
+    pub fn assets(&self) -> HashMap<String, Bytes> {
+        self.files
+            .iter()
+            .filter(|(path, _)| !is_font(path))
+            .map(|(path, content)| (path.clone(), content.clone()))
+            .collect()
+    }
+
+    /// Font files shipped with the package
+    pub fn fonts(&self) -> Vec<Bytes> {
+        self.files
+            .iter()
+            .filter(|(path, _)| is_font(path))
+            .map(|(_, content)| content.clone())
+            .collect()
+    }
+
+    /// Registry entry for this package
+    pub fn info(&self) -> TemplateInfo {
+        let manifest = &self.manifest;
+        TemplateInfo {
+            name: manifest.name.clone(),
+            description: manifest.description.clone(),
+            uri: self.uri(),
+            required_inputs: manifest.required_inputs.clone(),
+            optional_inputs: manifest.optional_inputs.clone(),
+            schema: build_schema(
+                &manifest.name,
+                &self.source,
+                &manifest.required_inputs,
+                &manifest.optional_inputs,
+                &manifest.inputs,
+            ),
+            version: Some(manifest.version.to_string()),
+        }
+    }
+}
+
+fn validate_manifest(manifest: &PackageManifest) -> Result<(), ServerError> {
+    let valid_name = !manifest.name.is_empty()
+        && manifest
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !valid_name {
+        return Err(ServerError::InvalidPackage(format!(
+            "Template name '{}' may only contain letters, digits, '_' and '-'",
+            manifest.name
+        )));
+    }
+    // Imports resolve against the entrypoint's directory, which is mounted
+    // as the root of the virtual filesystem
+    if manifest.entrypoint.contains('/') || !manifest.entrypoint.ends_with(".typ") {
+        return Err(ServerError::InvalidPackage(format!(
+            "Entrypoint '{}' must be a .typ file at the package root",
+            manifest.entrypoint
+        )));
+    }
+    for field in &manifest.inputs {
+        let listed = manifest
+            .required_inputs
+            .iter()
+            .chain(&manifest.optional_inputs)
+            .any(|name| *name == field.name);
+        if !listed {
+            return Err(ServerError::InvalidPackage(format!(
+                "Input '{}' is declared but not listed in required_inputs or optional_inputs",
+                field.name
+            )));
+        }
+        if let Some(error) = field.default_error() {
+            return Err(ServerError::InvalidPackage(format!(
+                "Input '{}': {}",
+                field.name, error
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn is_font(path: &str) -> bool {
+    path.rsplit_once('.').is_some_and(|(_, ext)| {
+        FONT_EXTENSIONS
+            .iter()
+            .any(|font| ext.eq_ignore_ascii_case(font))
+    })
+}
+
+/// Recursively collect the files under `dir`, keyed by path from `root`
+fn read_dir_files(
+    root: &Path,
+    dir: &Path,
+    files: &mut Vec<(String, Vec<u8>)>,
+) -> Result<(), ServerError> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_dir_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            files.push((relative, std::fs::read(&path)?));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::schema::FieldFormat;
+    use std::io::Write;
+
+    const MANIFEST: &str = r#"
+        [template]
+        name = "letterhead"
+        version = "1.2.0"
+        description = "Firm letter on letterhead"
+        required_inputs = ["recipient_name"]
+        optional_inputs = ["date"]
+    "#;
+
+    fn files(prefix: &str) -> Vec<(String, Vec<u8>)> {
+        vec![
+            (format!("{}template.toml", prefix), MANIFEST.into()),
+            (
+                format!("{}main.typ", prefix),
+                br#"#import "partials/header.typ": header
+#header()
+Dear #sys.inputs.at("recipient_name"),"#
+                    .to_vec(),
+            ),
+            (
+                format!("{}partials/header.typ", prefix),
+                b"#let header() = [Smith & Jones LLP]".to_vec(),
+            ),
+            (format!("{}fonts/Brand.otf", prefix), vec![0, 1, 0, 0]),
+        ]
+    }
+
+    #[test]
+    fn test_from_files() {
+        let package = TemplatePackage::from_files(files("")).unwrap();
+        assert_eq!(package.name(), "letterhead");
+        assert_eq!(package.version(), &Version::new(1, 2, 0));
+        assert_eq!(package.uri(), "typst://templates/letterhead@1.2.0");
+        assert!(package.source().contains("#import"));
+        assert_eq!(
+            package.assets().keys().collect::<Vec<_>>(),
+            ["partials/header.typ"]
+        );
+        assert_eq!(package.fonts().len(), 1);
+
+        let info = package.info();
+        assert_eq!(info.version.as_deref(), Some("1.2.0"));
+        assert!(info.schema.field("recipient_name").unwrap().required);
+    }
+
+    #[test]
+    fn test_manifest_declares_inputs() {
+        let declared = r#"
+        [[template.inputs]]
+        name = "date"
+        type = "string"
+        format = "date"
+        help = "Date printed under the letterhead"
+
+        [[template.inputs]]
+        name = "copies"
+        type = "integer"
+        default = 2
+        "#;
+        let mut package = files("");
+        package[0].1 = format!(
+            "{}{}",
+            MANIFEST.replace(r#"["date"]"#, r#"["date", "copies"]"#),
+            declared
+        )
+        .into();
+        let info = TemplatePackage::from_files(package).unwrap().info();
+        let date = info.schema.field("date").unwrap();
+        assert_eq!(date.format, Some(FieldFormat::Date));
+        assert!(!date.required);
+        assert_eq!(
+            info.schema.field("copies").unwrap().default,
+            Some(serde_json::json!(2))
+        );
+
+        let mut unlisted = files("");
+        unlisted[0].1 = format!("{}{}", MANIFEST, declared).into();
+        assert!(matches!(
+            TemplatePackage::from_files(unlisted),
+            Err(ServerError::InvalidPackage(_))
+        ));
+
+        let mut quoted = files("");
+        quoted[0].1 = format!(
+            "{}{}",
+            MANIFEST.replace(r#"["date"]"#, r#"["date", "copies"]"#),
+            declared.replace("default = 2", r#"default = "2""#)
+        )
+        .into();
+        assert!(matches!(
+            TemplatePackage::from_files(quoted),
+            Err(ServerError::InvalidPackage(message)) if message.contains("copies")
+        ));
+    }
+
+    #[test]
+    fn test_from_zip_with_top_level_directory() {
+        let mut data = std::io::Cursor::new(vec![]);
+        let mut zip = zip::ZipWriter::new(&mut data);
+        for (path, content) in files("letterhead/") {
+            zip.start_file(path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(&content).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let package = TemplatePackage::from_zip(data.get_ref()).unwrap();
+        assert_eq!(package.name(), "letterhead");
+        assert!(package.assets().contains_key("partials/header.typ"));
+    }
+
+    fn zip_of(entries: impl IntoIterator<Item = (String, Vec<u8>)>) -> Vec<u8> {
+        let mut data = std::io::Cursor::new(vec![]);
+        let mut zip = zip::ZipWriter::new(&mut data);
+        for (path, content) in entries {
+            zip.start_file(path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(&content).unwrap();
+        }
+        zip.finish().unwrap();
+        data.into_inner()
+    }
+
+    #[test]
+    fn test_from_zip_limits() {
+        let is_invalid = |data: Vec<u8>| {
+            matches!(
+                TemplatePackage::from_zip(&data),
+                Err(ServerError::InvalidPackage(_))
+            )
+        };
+
+        let mut many = files("");
+        many.extend((0..MAX_ZIP_ENTRIES).map(|i| (format!("extra/{}.typ", i), vec![])));
+        assert!(is_invalid(zip_of(many)));
+
+        let mut large = files("");
+        large.push((
+            "images/huge.png".to_string(),
+            vec![0; MAX_ZIP_ENTRY_SIZE as usize + 1],
+        ));
+        assert!(is_invalid(zip_of(large)));
+
+        let mut total = files("");
+        for i in 0..3 {
+            total.push((
+                format!("images/{}.png", i),
+                vec![0; MAX_ZIP_TOTAL_SIZE as usize / 3 + 1],
+            ));
+        }
+        assert!(is_invalid(zip_of(total.clone())));
+        total.pop();
+        assert!(TemplatePackage::from_zip(&zip_of(total)).is_ok());
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let dir = std::env::temp_dir().join(format!("typst-package-{}", std::process::id()));
+        for (path, content) in files("") {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+
+        let package = TemplatePackage::from_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(package.unwrap().name(), "letterhead");
+    }
+
+    #[test]
+    fn test_invalid_packages() {
+        let without = |name: &str| {
+            files("")
+                .into_iter()
+                .filter(|(path, _)| path != name)
+                .collect::<Vec<_>>()
+        };
+        assert!(matches!(
+            TemplatePackage::from_files(without("template.toml")),
+            Err(ServerError::InvalidPackage(_))
+        ));
+        assert!(matches!(
+            TemplatePackage::from_files(without("main.typ")),
+            Err(ServerError::InvalidPackage(_))
+        ));
+
+        let mut bad_version = files("");
+        bad_version[0].1 = MANIFEST.replace("1.2.0", "one").into();
+        assert!(TemplatePackage::from_files(bad_version).is_err());
+
+        let mut traversal = files("");
+        traversal.push(("../secret.typ".to_string(), vec![]));
+        assert!(matches!(
+            TemplatePackage::from_files(traversal),
+            Err(ServerError::PathSecurityViolation(_))
+        ));
+    }
+}
@@ -1,11 +1,18 @@
 //! Template registry and metadata
 
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
 use super::embedded;
+use super::package::{TemplatePackage, MANIFEST_FILE};
+use super::schema::{build_schema, InputSchema};
 use crate::compiler::errors::ServerError;
+use once_cell::sync::Lazy;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
 
 /// Information about an available template
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TemplateInfo {
     /// Template name (used in URIs)
     pub name: String,
@@ -17,10 +24,147 @@ pub struct TemplateInfo {
     pub required_inputs: Vec<String>,
     /// Optional input fields
     pub optional_inputs: Vec<String>,
+    /// Typed schema of the required and optional inputs
+    #[serde(default)]
+    pub schema: InputSchema,
+    /// Package version, for templates loaded from a template package
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Registered templates with their schemas, built once
+static TEMPLATES: Lazy<Vec<TemplateInfo>> = Lazy::new(|| {
+    registered_templates()
+        .into_iter()
+        .map(|mut template| {
+            let source = embedded::get_embedded_template(&template.name).unwrap_or_default();
+            template.schema = build_schema(
+                &template.name,
+                &source,
+                &template.required_inputs,
+                &template.optional_inputs,
+                &[],
+            );
+            template
+        })
+        .collect()
+});
+
+/// A template package registered at runtime
+#[derive(Clone)]
+struct RegisteredPackage {
+    package: Arc<TemplatePackage>,
+    info: TemplateInfo,
 }
 
-/// List all available templates
+/// Registered packages, sorted by name and then newest version first
+static PACKAGES: Lazy<RwLock<Vec<RegisteredPackage>>> = Lazy::new(Default::default);
+
+/// List all available templates: the embedded ones, then every registered
+/// package version
 pub fn list_templates() -> Vec<TemplateInfo> {
+    let packages = PACKAGES.read().unwrap_or_else(|e| e.into_inner());
+    TEMPLATES
+        .iter()
+        .cloned()
+        .chain(packages.iter().map(|p| p.info.clone()))
+        .collect()
+}
+
+/// Input schema of a template, by name or `name@version`
+pub fn template_schema(spec: &str) -> Option<InputSchema> {
+    TEMPLATES
+        .iter()
+        .find(|t| t.name == spec)
+        .map(|t| t.schema.clone())
+        .or_else(|| find_registered(spec).map(|p| p.info.schema))
+}
+
+/// Register a template package so it can be listed and rendered
+///
+/// Registering the same name and version again replaces the earlier
+/// package. Names of embedded templates are reserved.
+pub fn register_package(package: TemplatePackage) -> Result<TemplateInfo, ServerError> {
+    if embedded::get_embedded_template(package.name()).is_some() {
+        return Err(ServerError::InvalidPackage(format!(
+            "'{}' is the name of a built-in template",
+            package.name()
+        )));
+    }
+
+    let info = package.info();
+    let mut packages = PACKAGES.write().unwrap_or_else(|e| e.into_inner());
+    packages.retain(|p| !(p.info.name == info.name && p.package.version() == package.version()));
+    packages.push(RegisteredPackage {
+        package: Arc::new(package),
+        info: info.clone(),
+    });
+    packages.sort_by(|a, b| {
+        a.info
+            .name
+            .cmp(&b.info.name)
+            .then_with(|| b.package.version().cmp(a.package.version()))
+    });
+
+    tracing::info!("Registered template package {}", info.uri);
+    Ok(info)
+}
+
+/// Register every package in a directory: each subdirectory with a
+/// `template.toml` and each `.zip` archive
+pub fn load_packages_from_dir(dir: impl AsRef<Path>) -> Result<Vec<TemplateInfo>, ServerError> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    let mut loaded = vec![];
+    for path in entries {
+        let package = if path.join(MANIFEST_FILE).is_file() {
+            TemplatePackage::from_dir(&path)?
+        } else if path.extension().is_some_and(|ext| ext == "zip") {
+            TemplatePackage::from_zip(&std::fs::read(&path)?)?
+        } else {
+            continue;
+        };
+        loaded.push(register_package(package)?);
+    }
+    Ok(loaded)
+}
+
+/// Find the registered package for a template name or `name@version`
+///
+/// A full version (`letterhead@1.2.0`) must match exactly; a requirement
+/// (`letterhead@1`, `letterhead@^1.2`) or a bare name picks the newest
+/// matching version.
+pub fn find_package(spec: &str) -> Option<Arc<TemplatePackage>> {
+    find_registered(spec).map(|p| p.package)
+}
+
+fn find_registered(spec: &str) -> Option<RegisteredPackage> {
+    let (name, requested) = match spec.split_once('@') {
+        Some((name, version)) => (name, Some(version)),
+        None => (spec, None),
+    };
+    let packages = PACKAGES.read().unwrap_or_else(|e| e.into_inner());
+    packages
+        .iter()
+        .filter(|p| p.info.name == name)
+        .find(|p| version_matches(p.package.version(), requested))
+        .cloned()
+}
+
+fn version_matches(version: &Version, requested: Option<&str>) -> bool {
+    let Some(requested) = requested else {
+        return true;
+    };
+    match Version::parse(requested) {
+        Ok(exact) => *version == exact,
+        Err(_) => VersionReq::parse(requested).is_ok_and(|req| req.matches(version)),
+    }
+}
+
+fn registered_templates() -> Vec<TemplateInfo> {
     vec![
         TemplateInfo {
             name: "invoice".to_string(),
@@ -39,6 +183,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "due_date".to_string(),
                 "notes".to_string(),
             ],
+            ..Default::default()
         },
         TemplateInfo {
             name: "letter".to_string(),
@@ -56,6 +201,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "subject".to_string(),
                 "closing".to_string(),
             ],
+            ..Default::default()
         },
         TemplateInfo {
             name: "florida_lease".to_string(),
@@ -78,6 +224,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "year_built".to_string(),
                 "is_pre_1978".to_string(),
                 "deposit_details".to_string(),
+                "deposit_return_days".to_string(), // at most 15 (§ 83.49(3)(a))
                 // NOTE: email_consent (HB 615) belongs in SIGNATURE CEREMONY,
                 // not template form. The TENANT consents during signing, not
                 // the landlord filling out the template. See docsign-web.
@@ -111,6 +258,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 // Mold Prevention Addendum
                 "mold_addendum".to_string(), // boolean: enables mold prevention addendum
             ],
+            ..Default::default()
         },
         // Florida Real Estate Purchase Contract
         TemplateInfo {
@@ -189,6 +337,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "additional_provisions".to_string(),
                 "mediation_required".to_string(),
             ],
+            ..Default::default()
         },
         // Florida Escalation Addendum
         TemplateInfo {
@@ -228,6 +377,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "additional_seller_name".to_string(),
                 "additional_terms".to_string(),
             ],
+            ..Default::default()
         },
         // Florida Listing Agreement
         TemplateInfo {
@@ -299,6 +449,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "additional_provisions".to_string(),
                 "agreement_date".to_string(),
             ],
+            ..Default::default()
         },
         // Texas Residential Lease
         TemplateInfo {
@@ -339,6 +490,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 // Security deposit (§ 92.103-109)
                 "deposit_holder".to_string(),
                 "deposit_bank_name".to_string(),
+                "deposit_return_days".to_string(), // at most 30
                 // Lockout policy (§ 92.0081)
                 "lockout_policy_acknowledged".to_string(),
                 // Landlord disclosure (§ 92.201)
@@ -383,6 +535,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 // Other
                 "smoking_allowed".to_string(),
             ],
+            ..Default::default()
         },
         // Florida Flood Disclosure (Standalone - SB 948 / HB 1015 - § 83.512)
         TemplateInfo {
@@ -412,6 +565,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "flood_fema_status".to_string(),
                 "fema_details".to_string(),
             ],
+            ..Default::default()
         },
         // Florida Purchase As-Is Contract - FAR/BAR "As-Is" with sole discretion inspection
         TemplateInfo {
@@ -490,6 +644,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 "additional_terms".to_string(),
                 "contract_date".to_string(),
             ],
+            ..Default::default()
         },
         // Florida Commercial Lease - Chapter 83 Part I (Non-Residential)
         TemplateInfo {
@@ -593,6 +748,7 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 // Additional terms
                 "additional_terms".to_string(),
             ],
+            ..Default::default()
         },
         // CMA (Comparative Market Analysis) Report - Professional 5-Page Template
         TemplateInfo {
@@ -642,13 +798,36 @@ pub fn list_templates() -> Vec<TemplateInfo> {
                 // Metadata
                 "generated_at".to_string(),
             ],
+            ..Default::default()
+        },
+        // Compliance Report - findings from compliance-engine
+        TemplateInfo {
+            name: "compliance_report".to_string(),
+            description: "Branded lease compliance report with summary table, page excerpts and statute links".to_string(),
+            uri: "typst://templates/compliance_report".to_string(),
+            required_inputs: vec![
+                "title".to_string(),
+                "document_id".to_string(),
+                "summary".to_string(),
+                "entries".to_string(),
+            ],
+            optional_inputs: vec![
+                "brand_name".to_string(),
+                "brand_color".to_string(),
+                "document_name".to_string(),
+                "checked_at".to_string(),
+                "coverage".to_string(),
+                "local_coverage".to_string(),
+            ],
+            ..Default::default()
         },
     ]
 }
 
-/// Get the source code for a template by name
+/// Get the source code for a template by name or `name@version`
 pub fn get_template_source(name: &str) -> Result<String, ServerError> {
     embedded::get_embedded_template(name)
+        .or_else(|| find_package(name).map(|p| p.source().to_string()))
         .ok_or_else(|| ServerError::TemplateNotFound(name.to_string()))
 }
 
@@ -675,6 +854,51 @@ mod tests {
         assert!(templates.iter().any(|t| t.name == "florida_lease"));
     }
 
+    #[test]
+    fn test_every_template_has_a_schema() {
+        for template in list_templates() {
+            let schema = template_schema(&template.name).unwrap();
+            assert_eq!(
+                schema.fields.len(),
+                template.required_inputs.len() + template.optional_inputs.len(),
+                "{}",
+                template.name
+            );
+            for name in &template.required_inputs {
+                assert!(
+                    schema.field(name).unwrap().required,
+                    "{}.{}",
+                    template.name,
+                    name
+                );
+            }
+            // Defaults must pass the template's own validation
+            let prepared = schema.prepare(
+                &template
+                    .required_inputs
+                    .iter()
+                    .map(|name| (name.clone(), sample_value(schema.field(name).unwrap())))
+                    .collect(),
+            );
+            assert!(prepared.is_ok(), "{}: {:?}", template.name, prepared);
+        }
+    }
+
+    fn sample_value(field: &crate::templates::FieldSchema) -> serde_json::Value {
+        use crate::templates::{FieldFormat, FieldType};
+        match (field.field_type, field.format) {
+            (FieldType::Array, _) => serde_json::json!([]),
+            (FieldType::Object, _) => serde_json::json!({}),
+            (FieldType::Boolean, _) => serde_json::json!(true),
+            (_, Some(FieldFormat::Year)) => serde_json::json!(1990),
+            (FieldType::Number | FieldType::Integer, _) => serde_json::json!(100),
+            (_, Some(FieldFormat::Date)) => serde_json::json!("2026-01-31"),
+            (_, Some(FieldFormat::Email)) => serde_json::json!("a@example.com"),
+            (_, Some(FieldFormat::Phone)) => serde_json::json!("555-123-4567"),
+            _ => serde_json::json!("text"),
+        }
+    }
+
     #[test]
     fn test_get_template_source() {
         let source = get_template_source("invoice");
@@ -697,6 +921,68 @@ mod tests {
         assert_eq!(parse_template_uri("invalid://uri"), None);
     }
 
+    fn package(name: &str, version: &str) -> TemplatePackage {
+        let manifest = format!(
+            "[template]\nname = \"{}\"\nversion = \"{}\"\nrequired_inputs = [\"body\"]\n",
+            name, version
+        );
+        TemplatePackage::from_files(vec![
+            ("template.toml".to_string(), manifest.into_bytes()),
+            (
+                "main.typ".to_string(),
+                format!("Version {}", version).into_bytes(),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_package_versions_resolve() {
+        for version in ["1.0.0", "1.4.2", "2.0.0-beta.1", "2.0.0"] {
+            register_package(package("registry_test_memo", version)).unwrap();
+        }
+
+        let source = |spec| get_template_source(spec).unwrap();
+        assert_eq!(source("registry_test_memo"), "Version 2.0.0");
+        assert_eq!(source("registry_test_memo@1"), "Version 1.4.2");
+        assert_eq!(source("registry_test_memo@1.0.0"), "Version 1.0.0");
+        assert_eq!(
+            source("registry_test_memo@2.0.0-beta.1"),
+            "Version 2.0.0-beta.1"
+        );
+        assert!(get_template_source("registry_test_memo@3").is_err());
+
+        let listed: Vec<String> = list_templates()
+            .into_iter()
+            .filter(|t| t.name == "registry_test_memo")
+            .map(|t| t.uri)
+            .collect();
+        assert_eq!(
+            listed,
+            [
+                "typst://templates/registry_test_memo@2.0.0",
+                "typst://templates/registry_test_memo@2.0.0-beta.1",
+                "typst://templates/registry_test_memo@1.4.2",
+                "typst://templates/registry_test_memo@1.0.0",
+            ]
+        );
+        assert!(
+            template_schema("registry_test_memo@1")
+                .unwrap()
+                .field("body")
+                .unwrap()
+                .required
+        );
+    }
+
+    #[test]
+    fn test_builtin_template_names_are_reserved() {
+        assert!(matches!(
+            register_package(package("invoice", "1.0.0")),
+            Err(ServerError::InvalidPackage(_))
+        ));
+    }
+
     // ============================================================
     // SCRIVENER ADHERENCE TESTS - Strict neutrality requirements
     // ============================================================
@@ -0,0 +1,830 @@
+//! Typed input schemas for templates
+//!
+//! Every template has an [`InputSchema`] describing its `sys.inputs`: each
+//! field's type, format (currency, date, email, ...), allowed values, default
+//! and help text. [`InputSchema::prepare`] checks a request's inputs against
+//! it before compiling, so a missing tenant name or a rent of "TBD" is
+//! reported against the field instead of as a Typst error deep inside the
+//! template.
+//!
+//! Schemas are built from the registry's field lists. A field's type comes
+//! from how the template reads it (`get_num` is a number, `get_bool` a
+//! boolean) and its default from the template's own `default:`; formats
+//! follow the field name, and [`overrides`] adds what neither can tell, such
+//! as enums, help text and the item schema of invoice `items`. Template
+//! packages can declare fields in their manifest instead (see
+//! [`super::package`]).
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::compiler::errors::InputError;
+
+/// JSON type of a template input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldType {
+    /// Text; numbers are accepted and shown as written
+    String,
+    Number,
+    Integer,
+    /// `true`/`false`, or the strings "true"/"false"/"yes"/"no"
+    Boolean,
+    Array,
+    Object,
+}
+
+/// Format a value must follow on top of its type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldFormat {
+    /// Dollar amount; "$1,500.00" is accepted and normalized to "1500.00"
+    Currency,
+    /// Percentage; a trailing "%" is accepted and dropped
+    Percent,
+    /// "2026-01-31", "01/31/2026" or "January 31, 2026"
+    Date,
+    Email,
+    Phone,
+    /// Four-digit year
+    Year,
+}
+
+/// Schema of one template input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldSchema {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: FieldType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<FieldFormat>,
+    #[serde(default)]
+    pub required: bool,
+    /// Allowed values, for string fields
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enum_values: Vec<String>,
+    /// Schema of each element, for array fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<FieldSchema>>,
+    /// Schemas of the members, for object fields
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<FieldSchema>,
+    /// Value used when the input is missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Help text shown next to the field in forms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+}
+
+impl FieldSchema {
+    /// Create an optional field of the given type
+    pub fn new(name: impl Into<String>, field_type: FieldType) -> Self {
+        Self {
+            name: name.into(),
+            field_type,
+            format: None,
+            required: false,
+            enum_values: vec![],
+            items: None,
+            properties: vec![],
+            default: None,
+            help: None,
+        }
+    }
+
+    /// Mark the field as required
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Set the format
+    pub fn with_format(mut self, format: FieldFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Restrict the field to the given values
+    pub fn with_enum(mut self, values: &[&str]) -> Self {
+        self.enum_values = values.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    /// Set the schema of each array element
+    pub fn with_items(mut self, items: FieldSchema) -> Self {
+        self.items = Some(Box::new(items));
+        self
+    }
+
+    /// Set the schemas of the object members
+    pub fn with_properties(mut self, properties: Vec<FieldSchema>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Set the default value
+    pub fn with_default(mut self, default: impl Into<Value>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Set the help text
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Check a value against this field, returning it normalized
+    ///
+    /// `path` names the value in error messages ("items[2].price").
+    pub(crate) fn check(&self, path: &str, value: &Value) -> Result<Value, InputError> {
+        let fail = |message: String| Err(InputError::new(path, message));
+
+        let value = match (self.field_type, value) {
+            (FieldType::String, Value::String(_) | Value::Number(_)) => value.clone(),
+            (FieldType::String, _) => return fail("must be text".to_string()),
+
+            (FieldType::Number | FieldType::Integer, _) => {
+                let Some((number, normalized)) = self.number(value) else {
+                    return fail(match self.format {
+                        Some(FieldFormat::Currency) => "must be a dollar amount".to_string(),
+                        Some(FieldFormat::Percent) => "must be a percentage".to_string(),
+                        Some(FieldFormat::Year) => "must be a year".to_string(),
+                        _ => "must be a number".to_string(),
+                    });
+                };
+                if self.field_type == FieldType::Integer && number.fract() != 0.0 {
+                    return fail("must be a whole number".to_string());
+                }
+                if self.format == Some(FieldFormat::Year) && !(1600.0..=2100.0).contains(&number) {
+                    return fail(format!("{} is not a plausible year", number));
+                }
+                if self.format == Some(FieldFormat::Currency) && number < 0.0 {
+                    return fail("must not be negative".to_string());
+                }
+                normalized
+            }
+
+            (FieldType::Boolean, Value::Bool(_)) => value.clone(),
+            (FieldType::Boolean, Value::String(s)) => match s.trim().to_lowercase().as_str() {
+                "true" | "yes" => Value::Bool(true),
+                "false" | "no" => Value::Bool(false),
+                _ => return fail("must be true or false".to_string()),
+            },
+            (FieldType::Boolean, _) => return fail("must be true or false".to_string()),
+
+            (FieldType::Array, Value::Array(elements)) => match &self.items {
+                Some(items) => Value::Array(
+                    elements
+                        .iter()
+                        .enumerate()
+                        .map(|(i, element)| items.check(&format!("{}[{}]", path, i), element))
+                        .collect::<Result<_, _>>()?,
+                ),
+                None => value.clone(),
+            },
+            (FieldType::Array, _) => return fail("must be a list".to_string()),
+
+            (FieldType::Object, Value::Object(members)) => {
+                let mut members = members.clone();
+                for property in &self.properties {
+                    let member_path = format!("{}.{}", path, property.name);
+                    match members.get(&property.name).filter(|v| !v.is_null()) {
+                        Some(member) => {
+                            // Templates do arithmetic on nested values directly
+                            // rather than through get_num, so they must be numbers
+                            let checked = match property.check(&member_path, member)? {
+                                Value::String(s) if property.is_numeric() => {
+                                    serde_json::from_str(&s).unwrap_or(Value::String(s))
+                                }
+                                checked => checked,
+                            };
+                            members.insert(property.name.clone(), checked);
+                        }
+                        None if property.required => {
+                            return Err(InputError::new(member_path, "is required"));
+                        }
+                        None => {
+                            if let Some(default) = &property.default {
+                                members.insert(property.name.clone(), default.clone());
+                            }
+                        }
+                    }
+                }
+                Value::Object(members)
+            }
+            (FieldType::Object, _) => return fail("must be an object".to_string()),
+        };
+
+        if let Value::String(s) = &value {
+            if !self.enum_values.is_empty() && !self.enum_values.iter().any(|v| v == s) {
+                return fail(format!("must be one of: {}", self.enum_values.join(", ")));
+            }
+            match self.format {
+                Some(FieldFormat::Date) if parse_date(s).is_none() => {
+                    return fail("must be a date such as 2026-01-31".to_string())
+                }
+                Some(FieldFormat::Email) if !EMAIL.is_match(s.trim()) => {
+                    return fail("must be an email address".to_string())
+                }
+                Some(FieldFormat::Phone) if !is_phone(s) => {
+                    return fail("must be a phone number".to_string())
+                }
+                _ => {}
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Why the default does not fit the field, if it does not
+    ///
+    /// Defaults reach the template as written, so numeric and boolean
+    /// fields need JSON numbers and booleans, not strings.
+    pub(crate) fn default_error(&self) -> Option<String> {
+        let default = self.default.as_ref()?;
+        if let Err(error) = self.check(&self.name, default) {
+            return Some(format!("default {}", error.message));
+        }
+        let fits = match self.field_type {
+            FieldType::Number | FieldType::Integer => default.is_number(),
+            FieldType::Boolean => default.is_boolean(),
+            _ => true,
+        };
+        (!fits).then(|| {
+            let type_name = serde_json::to_value(self.field_type).unwrap_or_default();
+            format!(
+                "default must be a JSON {}",
+                type_name.as_str().unwrap_or("")
+            )
+        })
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self.field_type, FieldType::Number | FieldType::Integer)
+    }
+
+    /// Parse a numeric value, returning it with the form to pass on
+    ///
+    /// Numbers pass through; numeric strings stay strings, without the
+    /// currency symbol, thousands separators or percent sign.
+    fn number(&self, value: &Value) -> Option<(f64, Value)> {
+        match value {
+            Value::Number(n) => Some((n.as_f64()?, value.clone())),
+            Value::String(s) => {
+                let mut text = s.trim();
+                match self.format {
+                    Some(FieldFormat::Currency) => text = text.trim_start_matches('$').trim(),
+                    Some(FieldFormat::Percent) => text = text.trim_end_matches('%').trim(),
+                    _ => {}
+                }
+                let text = if self.format == Some(FieldFormat::Currency) {
+                    text.replace(',', "")
+                } else {
+                    text.to_string()
+                };
+                let number: f64 = text.parse().ok().filter(|n: &f64| n.is_finite())?;
+                Some((number, Value::String(text)))
+            }
+            _ => None,
+        }
+    }
+
+    /// JSON Schema for this field
+    pub fn to_json_schema(&self) -> Value {
+        let mut schema = Map::new();
+        let type_name = match self.field_type {
+            FieldType::String => "string",
+            FieldType::Number => "number",
+            FieldType::Integer => "integer",
+            FieldType::Boolean => "boolean",
+            FieldType::Array => "array",
+            FieldType::Object => "object",
+        };
+        schema.insert("type".to_string(), json!(type_name));
+        if let Some(format) = self.format {
+            schema.insert(
+                "format".to_string(),
+                serde_json::to_value(format).unwrap_or_default(),
+            );
+        }
+        if !self.enum_values.is_empty() {
+            schema.insert("enum".to_string(), json!(self.enum_values));
+        }
+        if let Some(items) = &self.items {
+            schema.insert("items".to_string(), items.to_json_schema());
+        }
+        if !self.properties.is_empty() {
+            schema.insert(
+                "properties".to_string(),
+                properties_schema(&self.properties),
+            );
+            schema.insert("required".to_string(), required_names(&self.properties));
+        }
+        if let Some(default) = &self.default {
+            schema.insert("default".to_string(), default.clone());
+        }
+        if let Some(help) = &self.help {
+            schema.insert("description".to_string(), json!(help));
+        }
+        Value::Object(schema)
+    }
+}
+
+/// Input schema of a template
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputSchema {
+    pub fields: Vec<FieldSchema>,
+}
+
+impl InputSchema {
+    /// Look up a field by name
+    pub fn field(&self, name: &str) -> Option<&FieldSchema> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Validate inputs and fill in defaults
+    ///
+    /// Returns the inputs to compile with, or one error per invalid or
+    /// missing field. Inputs the schema does not list are passed through
+    /// unchecked.
+    pub fn prepare(
+        &self,
+        inputs: &HashMap<String, Value>,
+    ) -> Result<HashMap<String, Value>, Vec<InputError>> {
+        let mut prepared = inputs.clone();
+        let mut errors = vec![];
+
+        for field in &self.fields {
+            let value = inputs
+                .get(&field.name)
+                .filter(|v| !v.is_null() && v.as_str().is_none_or(|s| !s.trim().is_empty()));
+            match (value, &field.default) {
+                (Some(value), _) => match field.check(&field.name, value) {
+                    Ok(value) => {
+                        prepared.insert(field.name.clone(), value);
+                    }
+                    Err(error) => errors.push(error),
+                },
+                (None, _) if field.required => {
+                    errors.push(InputError::new(&field.name, "is required"));
+                }
+                (None, Some(default)) => {
+                    prepared.insert(field.name.clone(), default.clone());
+                }
+                (None, None) => {
+                    prepared.remove(&field.name);
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(prepared)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// JSON Schema of the inputs object, for tool definitions and forms
+    pub fn to_json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": properties_schema(&self.fields),
+            "required": required_names(&self.fields),
+            "additionalProperties": true
+        })
+    }
+}
+
+fn properties_schema(fields: &[FieldSchema]) -> Value {
+    Value::Object(
+        fields
+            .iter()
+            .map(|f| (f.name.clone(), f.to_json_schema()))
+            .collect(),
+    )
+}
+
+fn required_names(fields: &[FieldSchema]) -> Value {
+    json!(fields
+        .iter()
+        .filter(|f| f.required)
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>())
+}
+
+static EMAIL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").expect("valid regex"));
+
+/// Fields a template reads with `get_num`
+static NUMBER_USE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"get_num\("(\w+)""#).expect("valid regex"));
+
+/// Fields a template reads with `get_bool`
+static BOOL_USE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"get_bool\("(\w+)"\)"#).expect("valid regex"));
+
+/// Defaults a template gives its reads: `get("x", default: "y")`,
+/// `get_num("x", default: 5)`, `data.at("x", default: "y")`
+static DEFAULT_USE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:get|get_num|data\.at)\("(\w+)", default: ("[^"]*"|-?\d+(?:\.\d+)?)\)"#)
+        .expect("valid regex")
+});
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    let s = s.trim();
+    ["%Y-%m-%d", "%m/%d/%Y", "%B %d, %Y", "%b %d, %Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(s, format).ok())
+}
+
+fn is_phone(s: &str) -> bool {
+    let digits = s.chars().filter(|c| c.is_ascii_digit()).count();
+    (7..=15).contains(&digits)
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || " ()+-.x".contains(c))
+}
+
+/// Build a template's schema from its field lists and source
+///
+/// `declared` fields, from a package manifest, take precedence over
+/// everything read from the source.
+pub fn build_schema(
+    template: &str,
+    source: &str,
+    required: &[String],
+    optional: &[String],
+    declared: &[FieldSchema],
+) -> InputSchema {
+    let numbers: Vec<&str> = NUMBER_USE
+        .captures_iter(source)
+        .filter_map(|c| c.get(1).map(|m| m.as_str()))
+        .collect();
+    let booleans: Vec<&str> = BOOL_USE
+        .captures_iter(source)
+        .filter_map(|c| c.get(1).map(|m| m.as_str()))
+        .collect();
+    let mut defaults: HashMap<&str, Value> = HashMap::new();
+    for caps in DEFAULT_USE.captures_iter(source) {
+        let (Some(name), Some(raw)) = (caps.get(1), caps.get(2)) else {
+            continue;
+        };
+        let default = match raw.as_str().strip_prefix('"') {
+            Some(text) => {
+                let text = text.trim_end_matches('"');
+                // Placeholders such as "[County]" or "---" are not values
+                if text.is_empty() || text.starts_with('[') || text.starts_with("---") {
+                    continue;
+                }
+                json!(text)
+            }
+            None => match serde_json::from_str(raw.as_str()) {
+                Ok(number) => number,
+                Err(_) => continue,
+            },
+        };
+        defaults.entry(name.as_str()).or_insert(default);
+    }
+
+    let overrides = overrides(template);
+    let fields = required
+        .iter()
+        .map(|name| (name, true))
+        .chain(optional.iter().map(|name| (name, false)))
+        .map(|(name, is_required)| {
+            if let Some(field) = declared.iter().chain(&overrides).find(|f| &f.name == name) {
+                let mut field = field.clone();
+                field.required = is_required;
+                return field;
+            }
+
+            let field_type = if booleans.contains(&name.as_str()) {
+                FieldType::Boolean
+            } else if numbers.contains(&name.as_str()) {
+                FieldType::Number
+            } else {
+                FieldType::String
+            };
+            let mut field = infer_format(FieldSchema::new(name.clone(), field_type));
+            field.required = is_required;
+            if !is_required {
+                // A numeric field read with `get` has a quoted default
+                field.default = defaults
+                    .get(name.as_str())
+                    .and_then(|d| field.check(name, d).ok())
+                    .map(|d| match d {
+                        Value::String(s) if field.is_numeric() => {
+                            serde_json::from_str(&s).unwrap_or(Value::String(s))
+                        }
+                        d => d,
+                    });
+            }
+            field
+        })
+        .collect();
+
+    InputSchema { fields }
+}
+
+/// Format implied by a field's name
+fn infer_format(field: FieldSchema) -> FieldSchema {
+    const MONEY_WORDS: &[&str] = &[
+        "rent",
+        "deposit",
+        "fee",
+        "amount",
+        "price",
+        "money",
+        "funds",
+        "assessment",
+        "value",
+    ];
+
+    let name = field.name.as_str();
+    match field.field_type {
+        FieldType::Number if name.ends_with("year_built") => FieldSchema {
+            field_type: FieldType::Integer,
+            ..field
+        }
+        .with_format(FieldFormat::Year),
+        FieldType::Number if name.ends_with("_percent") || name.ends_with("_percentage") => {
+            field.with_format(FieldFormat::Percent)
+        }
+        FieldType::Number if MONEY_WORDS.iter().any(|w| name.contains(w)) => {
+            field.with_format(FieldFormat::Currency)
+        }
+        FieldType::String if name.ends_with("_percent") || name.ends_with("_percentage") => {
+            FieldSchema {
+                field_type: FieldType::Number,
+                ..field
+            }
+            .with_format(FieldFormat::Percent)
+        }
+        FieldType::String if name.ends_with("year_built") => FieldSchema {
+            field_type: FieldType::Integer,
+            ..field
+        }
+        .with_format(FieldFormat::Year),
+        FieldType::String if name.ends_with("email") => field.with_format(FieldFormat::Email),
+        FieldType::String if name.ends_with("phone") => field.with_format(FieldFormat::Phone),
+        FieldType::String
+            if name == "date"
+                || name.ends_with("_date")
+                || name == "lease_start"
+                || name == "lease_end" =>
+        {
+            field.with_format(FieldFormat::Date)
+        }
+        _ => field,
+    }
+}
+
+/// Fields whose schema cannot be read from the template source or name
+fn overrides(template: &str) -> Vec<FieldSchema> {
+    use FieldType::*;
+
+    let tristate = |name: &str| {
+        FieldSchema::new(name, String)
+            .with_enum(&["yes", "no", "unknown"])
+            .with_default("unknown")
+            .with_help("Answer \"unknown\" if you do not know or recently acquired the property")
+    };
+    let financing = || {
+        FieldSchema::new("financing_type", String)
+            .with_enum(&["cash", "conventional", "fha", "va"])
+            .with_default("conventional")
+    };
+
+    match template {
+        "invoice" => vec![
+            FieldSchema::new("items", Array)
+                .with_items(FieldSchema::new("item", Object).with_properties(vec![
+                    FieldSchema::new("description", String).required(),
+                    FieldSchema::new("qty", Number).with_default(1),
+                    FieldSchema::new("price", Number)
+                        .with_format(FieldFormat::Currency)
+                        .required(),
+                ]))
+                .with_help("Line items, each with a description, quantity and unit price"),
+            FieldSchema::new("date", String)
+                .with_format(FieldFormat::Date)
+                .with_help("Invoice date; defaults to today"),
+            FieldSchema::new("due_date", String).with_format(FieldFormat::Date),
+        ],
+        "letter" => vec![
+            FieldSchema::new("date", String)
+                .with_format(FieldFormat::Date)
+                .with_help("Letter date; defaults to today"),
+            FieldSchema::new("body", String).with_help("Text of the letter"),
+        ],
+        "florida_lease" => vec![
+            tristate("flood_history_status"),
+            tristate("flood_claims_status"),
+            tristate("flood_fema_status"),
+            FieldSchema::new("early_termination_amount", Number)
+                .with_format(FieldFormat::Currency)
+                .with_help("Liquidated damages fee; at most two months' rent (§ 83.595)"),
+            FieldSchema::new("deposit_return_days", Integer)
+                .with_default(15)
+                .with_help(
+                    "Days to return the deposit when no claim is made; at most 15 (§ 83.49(3)(a))",
+                ),
+        ],
+        "florida_purchase_contract" => vec![
+            financing(),
+            FieldSchema::new("inspection_contingency_type", String)
+                .with_enum(&["standard", "as_is"])
+                .with_default("standard"),
+        ],
+        "florida_purchase_as_is" => vec![financing()],
+        "florida_escalation_addendum" => vec![financing()],
+        "florida_listing_agreement" => vec![
+            FieldSchema::new("brokerage_relationship", String)
+                .with_enum(&["single_agent", "transaction_broker"])
+                .with_help("Brokerage relationship disclosed under § 475.278"),
+            FieldSchema::new("commission_type", String)
+                .with_enum(&["percentage", "flat"])
+                .with_default("percentage"),
+        ],
+        "texas_lease" => vec![FieldSchema::new("deposit_return_days", Integer)
+            .with_default(30)
+            .with_help("Days to return the deposit after move-out; at most 30 (§ 92.103)")],
+        "cma" => vec![
+            FieldSchema::new("comps", Array)
+                .with_items(FieldSchema::new("comp", Object))
+                .with_help("Comparable sales, each with its adjustments"),
+            FieldSchema::new("adjustment_config", Object)
+                .with_help("Adjustment values: bedroom_value, bathroom_value, sqft_factor"),
+        ],
+        "compliance_report" => vec![
+            FieldSchema::new("summary", Object).with_properties(vec![
+                FieldSchema::new("critical", Integer),
+                FieldSchema::new("warning", Integer),
+                FieldSchema::new("info", Integer),
+                FieldSchema::new("total", Integer),
+            ]),
+            FieldSchema::new("entries", Array).with_items(FieldSchema::new("entry", Object)),
+        ],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn schema() -> InputSchema {
+        InputSchema {
+            fields: vec![
+                FieldSchema::new("tenant_name", FieldType::String).required(),
+                FieldSchema::new("monthly_rent", FieldType::Number)
+                    .with_format(FieldFormat::Currency)
+                    .required(),
+                FieldSchema::new("lease_start", FieldType::String).with_format(FieldFormat::Date),
+                FieldSchema::new("tenant_email", FieldType::String).with_format(FieldFormat::Email),
+                FieldSchema::new("has_pets", FieldType::Boolean).with_default(false),
+                FieldSchema::new("status", FieldType::String).with_enum(&["yes", "no"]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_prepare_reports_each_field() {
+        let errors = schema()
+            .prepare(&inputs(json!({
+                "monthly_rent": "TBD",
+                "lease_start": "someday",
+                "tenant_email": "not-an-email",
+                "status": "maybe"
+            })))
+            .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "tenant_name",
+                "monthly_rent",
+                "lease_start",
+                "tenant_email",
+                "status"
+            ]
+        );
+        assert_eq!(errors[0].message, "is required");
+    }
+
+    #[test]
+    fn test_prepare_normalizes_and_fills_defaults() {
+        let prepared = schema()
+            .prepare(&inputs(json!({
+                "tenant_name": "Jane",
+                "monthly_rent": "$1,500.00",
+                "lease_start": "January 1, 2026",
+                "status": "yes",
+                "extra": 1
+            })))
+            .unwrap();
+        assert_eq!(prepared["monthly_rent"], json!("1500.00"));
+        assert_eq!(prepared["has_pets"], json!(false));
+        assert_eq!(prepared["extra"], json!(1));
+    }
+
+    #[test]
+    fn test_array_items_are_checked() {
+        let schema = InputSchema {
+            fields: overrides("invoice"),
+        };
+        let errors = schema
+            .prepare(&inputs(json!({
+                "items": [
+                    {"description": "Design", "qty": 2, "price": "$100"},
+                    {"description": "Hosting", "price": "free"}
+                ]
+            })))
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "items[1].price");
+
+        let prepared = schema
+            .prepare(&inputs(json!({
+                "items": [{"description": "Design", "price": "$1,250.50"}]
+            })))
+            .unwrap();
+        assert_eq!(
+            prepared["items"],
+            json!([{"description": "Design", "qty": 1, "price": 1250.5}])
+        );
+    }
+
+    #[test]
+    fn test_build_schema_reads_template_source() {
+        let source = r#"
+            #let rent = get_num("monthly_rent")
+            #if get_bool("has_hoa") [HOA]
+            #get("grace_period_days", default: "5th")
+            #get("county", default: "[County]")
+        "#;
+        let schema = build_schema(
+            "florida_lease",
+            source,
+            &["monthly_rent".to_string()],
+            &[
+                "has_hoa".to_string(),
+                "grace_period_days".to_string(),
+                "county".to_string(),
+                "tenant_email".to_string(),
+                "flood_history_status".to_string(),
+            ],
+            &[],
+        );
+        let field = |name| schema.field(name).unwrap();
+        assert_eq!(field("monthly_rent").field_type, FieldType::Number);
+        assert_eq!(field("monthly_rent").format, Some(FieldFormat::Currency));
+        assert!(field("monthly_rent").required);
+        assert_eq!(field("has_hoa").field_type, FieldType::Boolean);
+        assert_eq!(field("grace_period_days").default, Some(json!("5th")));
+        assert_eq!(field("county").default, None);
+        assert_eq!(field("tenant_email").format, Some(FieldFormat::Email));
+        assert_eq!(field("flood_history_status").enum_values.len(), 3);
+    }
+
+    #[test]
+    fn test_defaults_match_their_type() {
+        for template in crate::templates::list_templates() {
+            for field in &template.schema.fields {
+                for field in std::iter::once(field).chain(&field.properties) {
+                    assert_eq!(
+                        field.default_error(),
+                        None,
+                        "{}: {}",
+                        template.name,
+                        field.name
+                    );
+                }
+            }
+        }
+
+        let quoted = FieldSchema::new("deposit_return_days", FieldType::Integer).with_default("30");
+        assert_eq!(
+            quoted.default_error().as_deref(),
+            Some("default must be a JSON integer")
+        );
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let schema = schema().to_json_schema();
+        assert_eq!(schema["required"], json!(["tenant_name", "monthly_rent"]));
+        assert_eq!(schema["properties"]["monthly_rent"]["format"], "currency");
+        assert_eq!(schema["properties"]["status"]["enum"], json!(["yes", "no"]));
+    }
+}
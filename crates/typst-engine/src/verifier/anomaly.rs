@@ -4,6 +4,7 @@
 //! parsed lease structure against canonical templates.
 
 use crate::verifier::parser::ParsedLease;
+use compliance_engine::State;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
@@ -201,95 +202,179 @@ pub struct ExpectedSection {
 impl CanonicalStructure {
     /// Create canonical structure for Florida residential leases
     pub fn florida_residential() -> Self {
-        Self {
-            expected_sections: vec![
-                ExpectedSection {
-                    title_patterns: vec![
-                        "basic terms",
-                        "summary",
-                        "key terms",
-                        "lease terms",
-                        "fundamental terms",
-                    ],
-                    required: true,
-                    typical_subsections: vec!["rent", "deposit", "term", "utilities"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["parties", "landlord", "tenant", "lessee", "lessor"],
-                    required: true,
-                    typical_subsections: vec!["name", "address", "contact"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["premises", "property", "rental property", "unit"],
-                    required: true,
-                    typical_subsections: vec!["address", "description", "parking"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["rent", "payment", "monthly rent"],
-                    required: true,
-                    typical_subsections: vec!["amount", "due date", "method"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["deposit", "security deposit", "security"],
-                    required: true,
-                    typical_subsections: vec!["amount", "return", "deductions"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["term", "lease term", "duration", "period"],
-                    required: true,
-                    typical_subsections: vec!["start", "end", "renewal"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["utilities", "services"],
-                    required: false,
-                    typical_subsections: vec!["electric", "water", "gas", "internet"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["maintenance", "repairs", "upkeep"],
-                    required: false,
-                    typical_subsections: vec!["tenant", "landlord", "emergency"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["access", "entry", "right of entry"],
-                    required: false,
-                    typical_subsections: vec!["notice", "emergency", "showing"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["default", "breach", "violation"],
-                    required: false,
-                    typical_subsections: vec!["cure period", "remedies"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["termination", "early termination", "ending"],
-                    required: false,
-                    typical_subsections: vec!["notice", "penalties", "buyout"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["pet", "animal", "pet policy"],
-                    required: false,
-                    typical_subsections: vec!["deposit", "restrictions", "breeds"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["parking", "vehicle"],
-                    required: false,
-                    typical_subsections: vec!["assigned", "guest", "restrictions"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["radon", "radon gas"],
-                    required: true, // Required by Florida law
-                    typical_subsections: vec![],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["signature", "execution", "agreement"],
-                    required: true,
-                    typical_subsections: vec!["date", "witness"],
-                },
-                ExpectedSection {
-                    title_patterns: vec!["addendum", "exhibit", "attachment", "appendix"],
-                    required: false,
-                    typical_subsections: vec![],
-                },
+        Self::residential(State::FL)
+    }
+
+    /// Create canonical structure for a state's residential leases
+    ///
+    /// Every state shares the common lease sections; states whose law
+    /// requires a disclosure or move-in document add it on top.
+    pub fn residential(state: State) -> Self {
+        let mut expected_sections = Self::common_residential_sections();
+        expected_sections.extend(Self::state_sections(state));
+        Self { expected_sections }
+    }
+
+    /// Sections found in residential leases in every state
+    fn common_residential_sections() -> Vec<ExpectedSection> {
+        vec![
+            ExpectedSection {
+                title_patterns: vec![
+                    "basic terms",
+                    "summary",
+                    "key terms",
+                    "lease terms",
+                    "fundamental terms",
+                ],
+                required: true,
+                typical_subsections: vec!["rent", "deposit", "term", "utilities"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["parties", "landlord", "tenant", "lessee", "lessor"],
+                required: true,
+                typical_subsections: vec!["name", "address", "contact"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["premises", "property", "rental property", "unit"],
+                required: true,
+                typical_subsections: vec!["address", "description", "parking"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["rent", "payment", "monthly rent"],
+                required: true,
+                typical_subsections: vec!["amount", "due date", "method"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["deposit", "security deposit", "security"],
+                required: true,
+                typical_subsections: vec!["amount", "return", "deductions"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["term", "lease term", "duration", "period"],
+                required: true,
+                typical_subsections: vec!["start", "end", "renewal"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["utilities", "services"],
+                required: false,
+                typical_subsections: vec!["electric", "water", "gas", "internet"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["maintenance", "repairs", "upkeep"],
+                required: false,
+                typical_subsections: vec!["tenant", "landlord", "emergency"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["access", "entry", "right of entry"],
+                required: false,
+                typical_subsections: vec!["notice", "emergency", "showing"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["default", "breach", "violation"],
+                required: false,
+                typical_subsections: vec!["cure period", "remedies"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["termination", "early termination", "ending"],
+                required: false,
+                typical_subsections: vec!["notice", "penalties", "buyout"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["pet", "animal", "pet policy"],
+                required: false,
+                typical_subsections: vec!["deposit", "restrictions", "breeds"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["parking", "vehicle"],
+                required: false,
+                typical_subsections: vec!["assigned", "guest", "restrictions"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["signature", "execution", "agreement"],
+                required: true,
+                typical_subsections: vec!["date", "witness"],
+            },
+            ExpectedSection {
+                title_patterns: vec!["addendum", "exhibit", "attachment", "appendix"],
+                required: false,
+                typical_subsections: vec![],
+            },
+        ]
+    }
+
+    /// Disclosures and attachments specific to a state's landlord-tenant law
+    fn state_sections(state: State) -> Vec<ExpectedSection> {
+        let section = |title_patterns: Vec<&'static str>, required: bool| ExpectedSection {
+            title_patterns,
+            required,
+            typical_subsections: vec![],
+        };
+
+        match state {
+            // F.S. § 404.056
+            State::FL => vec![section(vec!["radon", "radon gas"], true)],
+            // Tex. Prop. Code §§ 92.056, 92.0131, 92.151
+            State::TX => vec![
+                section(vec!["repair request", "repairs"], true),
+                section(vec!["towing", "parking rules"], false),
+                section(vec!["security device", "locks"], false),
+            ],
+            // CA Civil Code §§ 1954.603, 1940.7, 26147
+            State::CA => vec![
+                section(vec!["bed bug", "bedbug"], true),
+                section(vec!["ordnance", "military"], false),
+                section(vec!["mold"], false),
+                section(vec!["megan's law", "sex offender"], false),
+            ],
+            // NY RPL § 231-a, NYC Admin Code § 27-2018.1
+            State::NY => vec![
+                section(vec!["sprinkler"], true),
+                section(vec!["bedbug", "bed bug"], false),
+                section(vec!["rent stabilization", "rider"], false),
+            ],
+            // GA Code §§ 44-7-20, 44-7-33
+            State::GA => vec![
+                section(vec!["flood", "flooding"], true),
+                section(vec!["move-in inspection", "move-in", "inspection"], false),
+            ],
+            // 765 ILCS 705, Chicago RLTO
+            State::IL => vec![
+                section(vec!["radon"], false),
+                section(vec!["bed bug", "bedbug"], false),
+            ],
+            // N.J.S.A. 46:8-50, 2A:42-84.1
+            State::NJ => vec![
+                section(vec!["flood", "flood zone"], true),
+                section(vec!["truth in renting"], false),
             ],
+            // VA Code §§ 55.1-1215, 55.1-1226
+            State::VA => vec![
+                section(vec!["mold"], true),
+                section(vec!["move-in inspection", "move-in", "inspection"], true),
+            ],
+            // M.G.L. c. 186 § 15B
+            State::MA => vec![
+                section(vec!["statement of condition", "condition"], true),
+                section(vec!["last month"], false),
+            ],
+            // M.C.L. 554.608, 554.634
+            State::MI => vec![
+                section(vec!["inventory checklist", "inventory", "checklist"], true),
+                section(vec!["truth in renting"], false),
+            ],
+            // RCW 59.18.060, 59.18.260
+            State::WA => vec![
+                section(vec!["mold"], true),
+                section(vec!["move-in checklist", "checklist", "condition"], true),
+            ],
+            // A.R.S. §§ 33-1321, 36-1681
+            State::AZ => vec![
+                section(vec!["move-in", "inspection", "condition"], true),
+                section(vec!["pool", "swimming pool"], false),
+            ],
+            // 68 P.S. § 250.512
+            State::PA => vec![section(vec!["escrow"], false)],
+            _ => vec![],
         }
     }
 }
@@ -436,5 +521,32 @@ mod tests {
             .filter(|s| s.required)
             .collect();
         assert!(required.len() >= 5); // At least basic terms, parties, property, rent, deposit
+        assert!(required.iter().any(|s| s.title_patterns.contains(&"radon")));
+    }
+
+    fn requires(pattern: &str, state: State) -> bool {
+        CanonicalStructure::residential(state)
+            .expected_sections
+            .iter()
+            .any(|s| s.required && s.title_patterns.contains(&pattern))
+    }
+
+    #[test]
+    fn test_state_canonical_structures() {
+        assert!(
+            !requires("radon", State::TX),
+            "radon is only required in Florida"
+        );
+
+        assert!(requires("bed bug", State::CA));
+        assert!(requires("sprinkler", State::NY));
+        assert!(requires("flood", State::NJ));
+
+        // States without extra disclosures still get the common sections
+        let ohio = CanonicalStructure::residential(State::OH);
+        assert_eq!(
+            ohio.expected_sections.len(),
+            CanonicalStructure::common_residential_sections().len()
+        );
     }
 }
@@ -1,19 +1,27 @@
 //! Lease verification module
 //!
 //! Provides PDF text extraction, document parsing, compliance rule checking,
-//! and anomaly detection for Florida residential leases.
+//! and anomaly detection for residential leases in every state
+//! compliance-engine covers, and a compliance
+//! check of rendered lease templates (see [`template`]).
 
 pub mod anomaly;
 pub mod extract;
 pub mod parser;
 pub mod rules;
+pub mod template;
 
 // Re-export main types
 pub use anomaly::{Anomaly, AnomalyDetector, CanonicalStructure};
 pub use extract::{ExtractedDocument, PageContent, PdfExtractor, PdfMetadata};
 pub use parser::{FinancialTerms, LeaseDates, LeaseParser, LeaseSection, ParsedLease, Parties};
-pub use rules::{get_florida_rules, ComplianceRule, RuleResult, Severity};
+pub use rules::{
+    get_florida_rules, get_state_rules, get_state_rules_as_of, get_state_rules_with_clock,
+    ComplianceRule, RuleResult, Severity,
+};
+pub use template::{render_and_verify, template_state, VerifiedRender, ViolationPolicy};
 
+use compliance_engine::{Clock, State};
 use thiserror::Error;
 
 /// Errors that can occur during lease verification
@@ -36,6 +44,21 @@ pub enum VerifierError {
 
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+
+    #[error("Rendering failed: {0}")]
+    RenderError(#[from] crate::compiler::ServerError),
+
+    #[error("Unsupported state: {0}")]
+    UnsupportedState(String),
+
+    #[error("No compliance rules for template: {0}")]
+    UnsupportedTemplate(String),
+
+    #[error("Rendered {template} violates {} compliance rule(s)", .violations.len())]
+    NonCompliant {
+        template: String,
+        violations: Vec<shared_types::Violation>,
+    },
 }
 
 /// Complete verification report for a lease document
@@ -159,11 +182,27 @@ impl VerificationReport {
 }
 
 /// Main verification function
+///
+/// `state` is a state code or name ("FL", "texas"); any state
+/// compliance-engine has a profile for is accepted.
 pub fn verify_lease(
     pdf_bytes: &[u8],
     state: &str,
     detect_anomalies: bool,
 ) -> Result<VerificationReport, VerifierError> {
+    verify_lease_with_clock(pdf_bytes, state, detect_anomalies, Clock::System)
+}
+
+/// [`verify_lease`], checking only the statutes in force on `clock`'s today
+pub fn verify_lease_with_clock(
+    pdf_bytes: &[u8],
+    state: &str,
+    detect_anomalies: bool,
+    clock: Clock,
+) -> Result<VerificationReport, VerifierError> {
+    let state = State::parse_code(state.trim())
+        .ok_or_else(|| VerifierError::UnsupportedState(state.to_string()))?;
+
     // 1. Extract text from PDF
     let document = PdfExtractor::extract_text(pdf_bytes)?;
 
@@ -171,15 +210,7 @@ pub fn verify_lease(
     let parsed = LeaseParser::parse(&document)?;
 
     // 3. Get rules for the specified state
-    let rules = match state.to_lowercase().as_str() {
-        "florida" | "fl" => get_florida_rules(),
-        _ => {
-            return Err(VerifierError::ParsingError(format!(
-                "Unsupported state: {}",
-                state
-            )))
-        }
-    };
+    let rules = get_state_rules_with_clock(state, clock);
 
     // 4. Run compliance checks
     let compliance_results: Vec<ComplianceCheckResult> = rules
@@ -193,7 +224,7 @@ pub fn verify_lease(
 
     // 5. Detect anomalies if requested
     let anomalies = if detect_anomalies {
-        AnomalyDetector::detect(&parsed, &CanonicalStructure::florida_residential())
+        AnomalyDetector::detect(&parsed, &CanonicalStructure::residential(state))
     } else {
         vec![]
     };
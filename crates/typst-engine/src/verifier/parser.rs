@@ -10,6 +10,9 @@ use serde::{Deserialize, Serialize};
 /// A parsed lease document with structured sections and extracted data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParsedLease {
+    /// Full text of the document, as extracted
+    #[serde(default)]
+    pub raw_text: String,
     /// All detected sections in the lease
     pub sections: Vec<LeaseSection>,
     /// Information about landlord and tenant
@@ -138,6 +141,7 @@ impl LeaseParser {
         let (addenda, unknown_sections) = Self::categorize_sections(&sections);
 
         Ok(ParsedLease {
+            raw_text: text.clone(),
             sections,
             parties,
             property,
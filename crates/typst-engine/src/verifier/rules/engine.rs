@@ -0,0 +1,485 @@
+//! Compliance rules backed by compliance-engine
+//!
+//! Every statute compliance-engine covers for a state becomes one
+//! [`ComplianceRule`], so the verifier checks the same law as the rest of the
+//! workspace instead of keeping its own copy. The engine runs once per lease;
+//! each rule reports the violations whose most specific covered citation is
+//! its statute.
+//!
+//! Rule packs registered with [`register_rule_pack`] or
+//! [`load_rule_packs_from_dir`] replace the built-in rules of their state for
+//! every later check in the process.
+
+use super::{ComplianceRule, RuleResult, Severity};
+use crate::verifier::parser::ParsedLease;
+use compliance_engine::effective::cites;
+use compliance_engine::rule_pack::{self, PackInfo};
+use compliance_engine::{
+    Clock, ComplianceEngine, EffectiveRange, Jurisdiction, NaiveDate, RulePack, RulePackError,
+    State,
+};
+use shared_types::Violation;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Federal rules checked in every state: (citation, name)
+const FEDERAL_RULES: &[(&str, &str)] = &[
+    ("42 U.S.C. § 4852d", "Lead-Based Paint Disclosure"),
+    ("24 CFR 35.92", "Lead Hazard Pamphlet"),
+    ("42 U.S.C. § 3604", "Fair Housing Act"),
+];
+
+/// Rule packs registered for this process, at most one per state
+static RULE_PACKS: RwLock<Vec<RulePack>> = RwLock::new(Vec::new());
+
+/// Check leases of the pack's state against it instead of the built-in
+/// rules, replacing any pack registered earlier for that state
+pub fn register_rule_pack(pack: RulePack) -> PackInfo {
+    let info = pack.pack.clone();
+    let mut packs = RULE_PACKS.write().unwrap_or_else(|e| e.into_inner());
+    packs.retain(|p| p.state() != pack.state());
+    packs.push(pack);
+    info
+}
+
+/// Register every rule pack in a directory (see
+/// [`rule_pack::load_packs_from_dir`]). Nothing is registered if any pack
+/// fails to load.
+pub fn load_rule_packs_from_dir(dir: impl AsRef<Path>) -> Result<Vec<PackInfo>, RulePackError> {
+    let packs = rule_pack::load_packs_from_dir(dir)?;
+    Ok(packs.into_iter().map(register_rule_pack).collect())
+}
+
+/// Packs registered so far
+pub fn registered_rule_packs() -> Vec<PackInfo> {
+    let packs = RULE_PACKS.read().unwrap_or_else(|e| e.into_inner());
+    packs.iter().map(|p| p.pack.clone()).collect()
+}
+
+/// A compliance engine using the registered rule packs
+pub fn compliance_engine() -> ComplianceEngine {
+    let packs = RULE_PACKS.read().unwrap_or_else(|e| e.into_inner());
+    packs
+        .iter()
+        .cloned()
+        .fold(ComplianceEngine::new(), ComplianceEngine::with_rule_pack)
+}
+
+/// Lease text and year built a result was computed for, and the result
+type CachedCheck = (String, Option<u32>, Vec<Violation>);
+
+/// Engine results for the last lease checked, shared by a state's rules
+struct StateCheck {
+    engine: ComplianceEngine,
+    jurisdiction: Jurisdiction,
+    as_of: NaiveDate,
+    /// Citations of the state's [`StatuteRule`]s
+    citations: Vec<String>,
+    last: Mutex<Option<CachedCheck>>,
+}
+
+impl StateCheck {
+    /// The rule citation a violation belongs to: the longest covered
+    /// citation it cites, so "F.S. § 83.56 (HB 615)" is not also claimed by
+    /// "F.S. § 83.56"
+    fn rule_citation(&self, violation: &Violation) -> Option<&str> {
+        self.citations
+            .iter()
+            .filter(|citation| cites(&violation.statute, citation))
+            .max_by_key(|citation| citation.len())
+            .map(String::as_str)
+    }
+
+    fn violations(&self, lease: &ParsedLease) -> Vec<Violation> {
+        let year_built = lease.property.year_built;
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((text, year, violations)) = last.as_ref() {
+            if *text == lease.raw_text && *year == year_built {
+                return violations.clone();
+            }
+        }
+
+        let violations = self.engine.check_text_as_of(
+            &self.jurisdiction,
+            &lease.raw_text,
+            year_built,
+            self.as_of,
+        );
+        *last = Some((lease.raw_text.clone(), year_built, violations.clone()));
+        violations
+    }
+}
+
+/// One statute covered by compliance-engine
+pub struct StatuteRule {
+    name: String,
+    citation: String,
+    range: EffectiveRange,
+    check: Arc<StateCheck>,
+}
+
+impl ComplianceRule for StatuteRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn statute_reference(&self) -> &str {
+        &self.citation
+    }
+
+    fn check(&self, lease: &ParsedLease) -> RuleResult {
+        if !self.range.contains(self.check.as_of) {
+            return RuleResult::NotApplicable;
+        }
+        let violations: Vec<Violation> = self
+            .check
+            .violations(lease)
+            .into_iter()
+            .filter(|v| self.check.rule_citation(v) == Some(self.citation.as_str()))
+            .collect();
+        rule_result(&violations)
+    }
+}
+
+/// Violations of state and local law no [`StatuteRule`] claims, such as
+/// city ordinances and statutes outside the covered list
+pub struct OtherRequirementsRule {
+    name: String,
+    check: Arc<StateCheck>,
+}
+
+impl ComplianceRule for OtherRequirementsRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn statute_reference(&self) -> &str {
+        "Other state and local law"
+    }
+
+    fn check(&self, lease: &ParsedLease) -> RuleResult {
+        let violations: Vec<Violation> = self
+            .check
+            .violations(lease)
+            .into_iter()
+            .filter(|v| self.check.rule_citation(v).is_none())
+            .collect();
+        rule_result(&violations)
+    }
+}
+
+/// Fold the violations of one rule into a result
+///
+/// Critical violations fail the rule as critical and warnings as high;
+/// informational findings only warn.
+fn rule_result(violations: &[Violation]) -> RuleResult {
+    let reason = |vs: &[&Violation]| {
+        vs.iter()
+            .map(|v| format!("{}: {}", v.statute, v.message))
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+
+    let failures: Vec<&Violation> = violations
+        .iter()
+        .filter(|v| v.severity != shared_types::Severity::Info)
+        .collect();
+    if !failures.is_empty() {
+        let severity = if failures
+            .iter()
+            .any(|v| v.severity == shared_types::Severity::Critical)
+        {
+            Severity::Critical
+        } else {
+            Severity::High
+        };
+        return RuleResult::Fail {
+            reason: reason(&failures),
+            severity,
+        };
+    }
+
+    if violations.is_empty() {
+        RuleResult::Pass
+    } else {
+        RuleResult::Warning {
+            reason: reason(&violations.iter().collect::<Vec<_>>()),
+        }
+    }
+}
+
+/// Rules for a state's residential leases as of today
+pub fn get_state_rules(state: State) -> Vec<Box<dyn ComplianceRule>> {
+    get_state_rules_with_clock(state, Clock::System)
+}
+
+/// Rules for a state's residential leases as of `clock`'s today
+pub fn get_state_rules_with_clock(state: State, clock: Clock) -> Vec<Box<dyn ComplianceRule>> {
+    get_state_rules_as_of(state, clock.today())
+}
+
+/// Rules for a state's residential leases, skipping statutes not in force
+/// on `as_of`
+///
+/// The federal rules come first, then each statute in
+/// [`ComplianceEngine::covered_statutes`], then a rule for anything else
+/// the engine reports.
+pub fn get_state_rules_as_of(state: State, as_of: NaiveDate) -> Vec<Box<dyn ComplianceRule>> {
+    let engine = compliance_engine();
+
+    // (name, citation, range) of each statute rule
+    let mut statutes: Vec<(String, String, EffectiveRange)> = FEDERAL_RULES
+        .iter()
+        .map(|(citation, name)| {
+            (
+                name.to_string(),
+                citation.to_string(),
+                EffectiveRange::ALWAYS,
+            )
+        })
+        .collect();
+    for statute in engine.covered_statutes(state) {
+        // Entries read "citation - description"
+        let (citation, name) = statute
+            .citation
+            .split_once(" - ")
+            .unwrap_or((statute.citation.as_str(), statute.citation.as_str()));
+        if statutes.iter().any(|(_, c, _)| c == citation) {
+            continue;
+        }
+        statutes.push((name.to_string(), citation.to_string(), statute.range));
+    }
+
+    let check = Arc::new(StateCheck {
+        engine,
+        jurisdiction: Jurisdiction::new(state),
+        as_of,
+        citations: statutes.iter().map(|(_, c, _)| c.clone()).collect(),
+        last: Mutex::new(None),
+    });
+
+    let mut rules: Vec<Box<dyn ComplianceRule>> = Vec::with_capacity(statutes.len() + 1);
+    for (name, citation, range) in statutes {
+        rules.push(Box::new(StatuteRule {
+            name,
+            citation,
+            range,
+            check: check.clone(),
+        }));
+    }
+    rules.push(Box::new(OtherRequirementsRule {
+        name: format!("Other {} Requirements", state.name()),
+        check,
+    }));
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verifier::parser::PropertyInfo;
+
+    fn lease(text: &str, year_built: Option<u32>) -> ParsedLease {
+        ParsedLease {
+            raw_text: text.to_string(),
+            sections: vec![],
+            parties: Default::default(),
+            property: PropertyInfo {
+                year_built,
+                ..Default::default()
+            },
+            financial: Default::default(),
+            dates: Default::default(),
+            addenda: vec![],
+            unknown_sections: vec![],
+        }
+    }
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()
+    }
+
+    fn result(state: State, citation: &str, lease: &ParsedLease) -> RuleResult {
+        get_state_rules_as_of(state, as_of())
+            .iter()
+            .find(|r| r.statute_reference() == citation)
+            .unwrap_or_else(|| panic!("no rule for {citation}"))
+            .check(lease)
+    }
+
+    #[test]
+    fn test_every_implemented_state_has_rules() {
+        for state in State::implemented_states() {
+            let rules = get_state_rules_as_of(state, as_of());
+            assert!(
+                rules.len() > FEDERAL_RULES.len() + 1,
+                "{:?} has no state rules",
+                state
+            );
+            let lease = lease("Residential lease between Landlord and Tenant.", None);
+            for rule in &rules {
+                rule.check(&lease);
+            }
+        }
+    }
+
+    #[test]
+    fn test_state_statute_fails_on_engine_violation() {
+        let text = "Security deposit will be returned within 45 days of move-out. \
+                    Late fee of 15% of monthly rent.";
+        let texas = lease(text, None);
+        assert!(result(State::TX, "Tex. Prop. Code § 92.104", &texas).is_fail());
+
+        let text = "Security deposit will be returned within 30 days of move-out.";
+        assert_eq!(
+            result(State::TX, "Tex. Prop. Code § 92.104", &lease(text, None)),
+            RuleResult::Pass
+        );
+    }
+
+    #[test]
+    fn test_federal_rules_apply_in_every_state() {
+        let old = lease(
+            "Residential lease. The building was built in 1965.",
+            Some(1965),
+        );
+        for state in [State::FL, State::OH, State::AZ] {
+            match result(state, "42 U.S.C. § 4852d", &old) {
+                RuleResult::Fail { severity, .. } => assert_eq!(severity, Severity::Critical),
+                other => panic!("{:?}: expected failure, got {:?}", state, other),
+            }
+        }
+
+        let new = lease("Residential lease.", Some(2005));
+        assert!(result(State::FL, "42 U.S.C. § 4852d", &new).is_pass());
+    }
+
+    #[test]
+    fn test_radon_disclosure_pass() {
+        let lease = lease("Radon is a naturally occurring radioactive gas.", None);
+        assert!(result(State::FL, "F.S. § 404.056", &lease).is_pass());
+    }
+
+    #[test]
+    fn test_radon_disclosure_fail() {
+        let lease = lease("This is a lease agreement.", None);
+        match result(State::FL, "F.S. § 404.056", &lease) {
+            RuleResult::Fail { severity, .. } => assert_eq!(severity, Severity::Critical),
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_security_deposit_limit() {
+        let text = "Monthly rent: $1,000. Security deposit: $3,500.";
+        assert!(result(State::NY, "NY RPL § 7-108", &lease(text, None)).is_fail());
+
+        let text = "Monthly rent: $1,000. Security deposit: $1,000.";
+        assert!(result(State::NY, "NY RPL § 7-108", &lease(text, None)).is_pass());
+    }
+
+    #[test]
+    fn test_late_fee_excessive() {
+        let text = "Monthly rent: $1,000. Late fee: $150.";
+        assert!(result(State::NY, "NY RPL § 238-a", &lease(text, None)).is_fail());
+    }
+
+    #[test]
+    fn test_prohibited_terms_jury_waiver() {
+        let lease = lease("Tenant hereby waives all rights to jury trial.", None);
+        match result(State::FL, "F.S. § 83.47", &lease) {
+            RuleResult::Fail { reason, severity } => {
+                assert_eq!(severity, Severity::Critical);
+                assert!(reason.contains("jury trial"), "{reason}");
+            }
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lead_paint_not_required_after_1978() {
+        let lease = lease("Modern building.", Some(2000));
+        for citation in ["42 U.S.C. § 4852d", "24 CFR 35.92"] {
+            assert!(result(State::FL, citation, &lease).is_pass(), "{citation}");
+        }
+    }
+
+    #[test]
+    fn test_violation_belongs_to_its_most_specific_rule() {
+        let lease = lease("Notices may be sent to the Tenant by email.", None);
+        assert!(result(State::FL, "F.S. § 83.56 (HB 615)", &lease).is_fail());
+        assert!(result(State::FL, "F.S. § 83.56", &lease).is_pass());
+    }
+
+    #[test]
+    fn test_rules_take_the_date_from_the_clock() {
+        // HB 615 took effect on 2025-07-01
+        let electronic_notice = |date| {
+            let clock = Clock::Fixed(date);
+            get_state_rules_with_clock(State::FL, clock)
+                .into_iter()
+                .find(|r| r.statute_reference() == "F.S. § 83.56 (HB 615)")
+                .unwrap()
+                .check(&lease("Notices may be sent to the Tenant by email.", None))
+        };
+        assert_eq!(
+            electronic_notice(NaiveDate::from_ymd_opt(2025, 6, 30).unwrap()),
+            RuleResult::NotApplicable
+        );
+        assert!(electronic_notice(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()).is_fail());
+    }
+
+    #[test]
+    fn test_statutes_not_in_force_are_not_applicable() {
+        let rules = get_state_rules_as_of(State::FL, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        let flood = rules
+            .iter()
+            .find(|r| r.statute_reference() == "F.S. § 83.512 (SB 948)")
+            .unwrap();
+        assert_eq!(
+            flood.check(&lease("Residential lease.", None)),
+            RuleResult::NotApplicable
+        );
+    }
+
+    #[test]
+    fn test_unclaimed_violations_fall_to_other_requirements() {
+        let rules = get_state_rules_as_of(State::TX, as_of());
+        let other = rules.last().unwrap();
+        assert_eq!(other.name(), "Other Texas Requirements");
+
+        // § 92.019 is checked but not in the covered list
+        let text = "Late fee of 15% of monthly rent.";
+        let result = other.check(&lease(text, None));
+        match result {
+            RuleResult::Fail { reason, .. } => assert!(reason.contains("§ 92.019"), "{reason}"),
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_registered_rule_pack_replaces_state_rules() {
+        let pack = RulePack::from_toml(
+            r#"
+[pack]
+id = "test-wy"
+state = "WY"
+version = "1"
+
+[[rules]]
+id = "entry-waiver"
+statute = "Wyo. Stat. § 1-21-1204"
+severity = "Critical"
+message = "Tenant cannot waive the landlord's duties"
+when = { all = ["waive", "habitable"] }
+"#,
+        )
+        .unwrap();
+        let info = register_rule_pack(pack);
+        assert_eq!(info.id, "test-wy");
+        assert!(registered_rule_packs().iter().any(|p| p.id == "test-wy"));
+
+        let text = "Tenant waives the right to a habitable dwelling.";
+        assert!(result(State::WY, "Wyo. Stat. § 1-21-1204", &lease(text, None)).is_fail());
+    }
+}
@@ -1,11 +1,18 @@
 //! Compliance rules for lease verification
 //!
 //! This module contains state-specific and federal compliance rules
-//! for verifying lease documents.
+//! for verifying lease documents. The rules themselves live in
+//! compliance-engine; see [`engine`].
 
-pub mod florida;
+pub mod engine;
+
+pub use engine::{
+    compliance_engine, get_state_rules, get_state_rules_as_of, get_state_rules_with_clock,
+    load_rule_packs_from_dir, register_rule_pack, registered_rule_packs,
+};
 
 use crate::verifier::parser::ParsedLease;
+use compliance_engine::State;
 use serde::{Deserialize, Serialize};
 
 /// Trait for compliance rules
@@ -75,5 +82,5 @@ pub enum Severity {
 
 /// Get all Florida-specific compliance rules
 pub fn get_florida_rules() -> Vec<Box<dyn ComplianceRule>> {
-    florida::get_all_rules()
+    get_state_rules(State::FL)
 }
@@ -0,0 +1,420 @@
+//! Compliance check of rendered templates
+//!
+//! Lease templates are filled from arbitrary `sys.inputs`, so a template that
+//! is compliant as written can still render a lease that is not (a 45-day
+//! deposit return, a 15% late fee). [`render_and_verify`] renders a template,
+//! reads the text back out of the PDF and runs the compliance engine for the
+//! template's state over it.
+
+use base64::Engine;
+use compliance_engine::{Jurisdiction, NaiveDate, State};
+use serde::{Deserialize, Serialize};
+use shared_types::{ComplianceReport, LeaseDocument, Severity, Violation};
+
+use super::rules::compliance_engine;
+use super::{PdfExtractor, VerifierError};
+use crate::compiler::{
+    compile_document_sync, CompileError, OutputFormat, RenderRequest, RenderResponse, RenderStatus,
+    ServerError,
+};
+use crate::templates::registry::parse_template_uri;
+
+/// Templates the compliance engine can check, with the state whose law
+/// they follow
+const TEMPLATE_STATES: &[(&str, State)] = &[
+    ("florida_lease", State::FL),
+    ("florida_commercial_lease", State::FL),
+    ("texas_lease", State::TX),
+];
+
+/// State whose compliance rules a template is verified against
+pub fn template_state(name: &str) -> Option<State> {
+    TEMPLATE_STATES
+        .iter()
+        .find(|(template, _)| *template == name)
+        .map(|(_, state)| *state)
+}
+
+/// What to do with violations found in a rendered document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViolationPolicy {
+    /// Return the document and report every violation as a warning
+    #[default]
+    Warn,
+    /// Fail on critical violations; report the rest as warnings
+    FailOnCritical,
+    /// Fail on any violation
+    FailOnAny,
+}
+
+impl ViolationPolicy {
+    fn fails_on(self, violation: &Violation) -> bool {
+        match self {
+            ViolationPolicy::Warn => false,
+            ViolationPolicy::FailOnCritical => violation.severity == Severity::Critical,
+            ViolationPolicy::FailOnAny => true,
+        }
+    }
+}
+
+/// A rendered template and the compliance report of its text
+#[derive(Debug, Clone)]
+pub struct VerifiedRender {
+    /// The render in the requested format; violations the policy lets
+    /// through are added to its warnings
+    pub response: RenderResponse,
+    pub report: ComplianceReport,
+}
+
+/// Render a registered template and check the result against its state's law
+///
+/// The template is rendered to PDF via [`compile_document_sync`] and its text
+/// extracted; a second render is made when another format was requested.
+/// `year_built` is read from the template inputs. Rules not yet in force on
+/// `as_of` are skipped.
+///
+/// # Errors
+/// - `VerifierError::UnsupportedTemplate` - the source is not a template
+///   listed in [`template_state`]
+/// - `VerifierError::RenderError` - the template did not compile
+/// - `VerifierError::NonCompliant` - the policy fails on a violation found
+pub fn render_and_verify(
+    request: RenderRequest,
+    policy: ViolationPolicy,
+    as_of: NaiveDate,
+) -> Result<VerifiedRender, VerifierError> {
+    let (name, state) = parse_template_uri(&request.source)
+        .and_then(|name| Some((name.to_string(), template_state(name)?)))
+        .ok_or_else(|| VerifierError::UnsupportedTemplate(request.source.clone()))?;
+
+    let pdf_response = render(RenderRequest {
+        format: OutputFormat::Pdf,
+        ..request.clone()
+    })?;
+    let document = rendered_document(&name, &pdf_response)?;
+
+    let year_built = request
+        .inputs
+        .get("year_built")
+        .and_then(|value| match value {
+            serde_json::Value::Number(n) => n.as_u64().map(|n| n as u32),
+            serde_json::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        });
+    let report = compliance_engine().check_compliance(
+        &Jurisdiction::new(state),
+        &document,
+        year_built,
+        as_of,
+    );
+
+    let failing: Vec<Violation> = report
+        .violations
+        .iter()
+        .filter(|v| policy.fails_on(v))
+        .cloned()
+        .collect();
+    if !failing.is_empty() {
+        return Err(VerifierError::NonCompliant {
+            template: name,
+            violations: failing,
+        });
+    }
+
+    let mut response = if request.format == OutputFormat::Pdf {
+        pdf_response
+    } else {
+        render(request)?
+    };
+    response
+        .warnings
+        .extend(report.violations.iter().map(violation_warning));
+
+    Ok(VerifiedRender { response, report })
+}
+
+/// Render, treating compile errors as a failure
+fn render(request: RenderRequest) -> Result<RenderResponse, VerifierError> {
+    let response = compile_document_sync(request)?;
+    if response.status == RenderStatus::Error {
+        return Err(ServerError::CompileError(response.errors).into());
+    }
+    Ok(response)
+}
+
+/// The rendered PDF's text as the compliance engine reads a lease
+///
+/// Line wraps are joined so a clause broken across lines reads as one.
+fn rendered_document(
+    name: &str,
+    response: &RenderResponse,
+) -> Result<LeaseDocument, VerifierError> {
+    let artifact = response
+        .artifact
+        .as_ref()
+        .ok_or_else(|| VerifierError::ExtractionError("render produced no PDF".to_string()))?;
+    let pdf_bytes = base64::engine::general_purpose::STANDARD
+        .decode(&artifact.data_base64)
+        .map_err(|e| VerifierError::ExtractionError(e.to_string()))?;
+    let extracted = PdfExtractor::extract_text(&pdf_bytes)?;
+
+    let text_content: Vec<String> = extracted
+        .pages
+        .iter()
+        .map(|page| page.text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+    Ok(LeaseDocument {
+        id: name.to_string(),
+        filename: format!("{}.pdf", name),
+        pages: text_content.len() as u32,
+        text_content,
+        created_at: 0,
+        layout: Vec::new(),
+    })
+}
+
+fn violation_warning(violation: &Violation) -> CompileError {
+    let mut warning = CompileError::new(format!("{}: {}", violation.statute, violation.message));
+    if let Some(remediation) = &violation.remediation {
+        warning = warning.with_hint(remediation.explanation.clone());
+    }
+    warning.as_warning()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::list_templates;
+    use std::collections::HashMap;
+
+    /// Values for the inputs every lease template requires
+    const SAMPLE_INPUTS: &[(&str, &str)] = &[
+        ("landlord_name", "Sunshine Properties LLC"),
+        ("tenant_name", "Jordan Rivera"),
+        ("property_address", "100 Main Street"),
+        ("property_city", "Springfield"),
+        ("property_zip", "33101"),
+        ("monthly_rent", "1500"),
+        ("security_deposit", "1500"),
+        ("lease_start", "January 1, 2026"),
+        ("lease_end", "December 31, 2026"),
+    ];
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()
+    }
+
+    fn request(template: &str, inputs: &[(&str, serde_json::Value)]) -> RenderRequest {
+        let mut values: HashMap<String, serde_json::Value> = SAMPLE_INPUTS
+            .iter()
+            .map(|(k, v)| (k.to_string(), serde_json::json!(v)))
+            .collect();
+        for (key, value) in inputs {
+            values.insert(key.to_string(), value.clone());
+        }
+        RenderRequest {
+            source: format!("typst://templates/{}", template),
+            inputs: values,
+            assets: HashMap::new(),
+            format: OutputFormat::Pdf,
+            ppi: None,
+            ..Default::default()
+        }
+    }
+
+    fn statutes(template: &str, inputs: &[(&str, serde_json::Value)]) -> Vec<String> {
+        let verified = render_and_verify(request(template, inputs), ViolationPolicy::Warn, as_of())
+            .unwrap_or_else(|e| panic!("{template} with {inputs:?}: {e}"));
+        verified
+            .report
+            .violations
+            .into_iter()
+            .map(|v| v.statute)
+            .collect()
+    }
+
+    #[test]
+    fn test_every_checked_template_renders_and_verifies() {
+        let checked: Vec<_> = list_templates()
+            .into_iter()
+            .filter(|t| template_state(&t.name).is_some())
+            .collect();
+        assert_eq!(checked.len(), TEMPLATE_STATES.len());
+
+        for template in checked {
+            let verified =
+                render_and_verify(request(&template.name, &[]), ViolationPolicy::Warn, as_of())
+                    .unwrap_or_else(|e| panic!("{}: {}", template.name, e));
+            assert_eq!(verified.response.status, RenderStatus::Success);
+            assert_eq!(verified.report.document_id, template.name);
+            assert!(verified.response.warnings.len() >= verified.report.violations.len());
+        }
+    }
+
+    /// Template, inputs, statute, and whether it is expected to be violated
+    type BoundaryCase<'a> = (&'a str, Vec<(&'a str, serde_json::Value)>, &'a str, bool);
+
+    #[test]
+    fn test_boundary_inputs_matrix() {
+        use serde_json::json;
+
+        let cases: &[BoundaryCase] = &[
+            (
+                "texas_lease",
+                vec![("deposit_return_days", json!("30"))],
+                "Tex. Prop. Code § 92.104",
+                false,
+            ),
+            (
+                "texas_lease",
+                vec![("deposit_return_days", json!("45"))],
+                "Tex. Prop. Code § 92.104",
+                true,
+            ),
+            (
+                "texas_lease",
+                vec![("late_fee_percent", json!("12"))],
+                "Tex. Prop. Code § 92.019",
+                false,
+            ),
+            (
+                "texas_lease",
+                vec![("late_fee_percent", json!("13"))],
+                "Tex. Prop. Code § 92.019",
+                true,
+            ),
+            (
+                "texas_lease",
+                vec![("year_built", json!("1965"))],
+                "24 CFR 35.92",
+                false,
+            ),
+            (
+                "florida_lease",
+                vec![("deposit_return_days", json!("30"))],
+                "F.S. § 83.49(3)(b)",
+                false,
+            ),
+            (
+                "florida_lease",
+                vec![("deposit_return_days", json!("31"))],
+                "F.S. § 83.49(3)(b)",
+                true,
+            ),
+            (
+                "florida_commercial_lease",
+                vec![("deposit_return_days", json!("31"))],
+                "F.S. § 83.49(3)(b)",
+                false,
+            ),
+            (
+                "florida_commercial_lease",
+                vec![("deposit_return_days", json!("45"))],
+                "Commercial Lease - CAM Reconciliation",
+                true,
+            ),
+            (
+                "florida_lease",
+                vec![("year_built", json!("1965"))],
+                "24 CFR 35.92",
+                true,
+            ),
+            (
+                "florida_lease",
+                vec![
+                    ("year_built", json!(1965)),
+                    ("property_built_before_1978", json!(true)),
+                ],
+                "24 CFR 35.92",
+                false,
+            ),
+        ];
+
+        for (template, inputs, statute, violated) in cases {
+            let found = statutes(template, inputs);
+            assert_eq!(
+                found.iter().any(|s| s == statute),
+                *violated,
+                "{template} with {inputs:?}: {statute} expected violated={violated}, found {found:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_commercial_template_stays_on_commercial_track() {
+        // Titled "COMMERCIAL LEASE AGREEMENT" and governed by Chapter 83,
+        // Part I, which the residential deposit rules do not reach
+        let verified = render_and_verify(
+            request(
+                "florida_commercial_lease",
+                &[("deposit_return_days", serde_json::json!("45"))],
+            ),
+            ViolationPolicy::FailOnCritical,
+            as_of(),
+        )
+        .unwrap();
+        assert_eq!(verified.report.track, shared_types::Track::Commercial);
+        assert!(verified
+            .report
+            .violations
+            .iter()
+            .all(|v| !v.statute.starts_with("F.S. § 83.49")));
+    }
+
+    #[test]
+    fn test_policy_fails_on_critical_violation() {
+        let result = render_and_verify(
+            request(
+                "texas_lease",
+                &[("deposit_return_days", serde_json::json!("45"))],
+            ),
+            ViolationPolicy::FailOnCritical,
+            as_of(),
+        );
+        match result {
+            Err(VerifierError::NonCompliant {
+                template,
+                violations,
+            }) => {
+                assert_eq!(template, "texas_lease");
+                assert!(violations.iter().all(|v| v.severity == Severity::Critical));
+                assert!(violations
+                    .iter()
+                    .any(|v| v.statute == "Tex. Prop. Code § 92.104"));
+            }
+            other => panic!("expected NonCompliant, got {:?}", other.map(|v| v.report)),
+        }
+    }
+
+    #[test]
+    fn test_warn_policy_adds_render_warnings() {
+        let verified = render_and_verify(
+            request(
+                "texas_lease",
+                &[("late_fee_percent", serde_json::json!("20"))],
+            ),
+            ViolationPolicy::Warn,
+            as_of(),
+        )
+        .unwrap();
+        assert!(verified
+            .response
+            .warnings
+            .iter()
+            .any(|w| w.message.starts_with("Tex. Prop. Code § 92.019")));
+    }
+
+    #[test]
+    fn test_rejects_templates_without_rules() {
+        let result = render_and_verify(request("invoice", &[]), ViolationPolicy::Warn, as_of());
+        assert!(matches!(result, Err(VerifierError::UnsupportedTemplate(_))));
+
+        let raw = RenderRequest {
+            source: "Hello".to_string(),
+            ..request("texas_lease", &[])
+        };
+        let result = render_and_verify(raw, ViolationPolicy::Warn, as_of());
+        assert!(matches!(result, Err(VerifierError::UnsupportedTemplate(_))));
+    }
+}
@@ -32,6 +32,8 @@ pub struct VirtualFilesystem {
     path_to_id: HashMap<String, FileId>,
     /// The main entry point file ID
     main_id: Option<FileId>,
+    /// The parsed main source, kept so edits reparse incrementally
+    main_source: Option<Source>,
 }
 
 impl VirtualFilesystem {
@@ -41,6 +43,7 @@ impl VirtualFilesystem {
             files: HashMap::new(),
             path_to_id: HashMap::new(),
             main_id: None,
+            main_source: None,
         }
     }
 
@@ -60,10 +63,31 @@ impl VirtualFilesystem {
         self.files.insert(id, file);
         self.path_to_id.insert("/main.typ".to_string(), id);
         self.main_id = Some(id);
+        self.main_source = Some(Source::new(id, content));
 
         id
     }
 
+    /// Replace the text of the main source file, reparsing only the edited
+    /// part
+    ///
+    /// Returns whether the text changed. Mounts the main file if there is
+    /// none yet.
+    pub fn replace_main(&mut self, content: String) -> bool {
+        let Some(source) = self.main_source.as_mut() else {
+            self.mount_main(content);
+            return true;
+        };
+        if source.text() == content {
+            return false;
+        }
+        source.replace(&content);
+        if let Some(file) = self.files.get_mut(&source.id()) {
+            file.content = content.as_bytes().into();
+        }
+        true
+    }
+
     /// Mount an additional file (e.g., an image or included template)
     pub fn mount_file(&mut self, path: &str, content: Bytes) -> Result<FileId, ServerError> {
         // Security check: reject path traversal attempts
@@ -92,6 +116,9 @@ impl VirtualFilesystem {
 
     /// Get a source file by ID (for .typ files)
     pub fn get_source(&self, id: FileId) -> Option<Source> {
+        if let Some(source) = self.main_source.as_ref().filter(|s| s.id() == id) {
+            return Some(source.clone());
+        }
         self.files.get(&id).and_then(|file| {
             // Convert bytes to string for source files
             let text = std::str::from_utf8(&file.content).ok()?;
@@ -185,6 +212,17 @@ mod tests {
         assert!(source.text().contains("Hello"));
     }
 
+    #[test]
+    fn test_replace_main() {
+        let mut fs = VirtualFilesystem::new();
+        let id = fs.mount_main("Hello, World!".to_string());
+
+        assert!(!fs.replace_main("Hello, World!".to_string()));
+        assert!(fs.replace_main("Hello, Typst!".to_string()));
+        assert_eq!(fs.get_source(id).unwrap().text(), "Hello, Typst!");
+        assert_eq!(&fs.get_file(id).unwrap()[..], b"Hello, Typst!");
+    }
+
     #[test]
     fn test_path_traversal_blocked() {
         let mut fs = VirtualFilesystem::new();
@@ -15,6 +15,7 @@ use typst::{Library, World};
 
 use super::fonts::{global_font_cache, FontCache};
 use super::virtual_fs::VirtualFilesystem;
+use crate::accessibility::structure_markers;
 use crate::compiler::errors::ServerError;
 
 /// A virtual world for in-memory Typst compilation
@@ -29,6 +30,12 @@ pub struct VirtualWorld {
     time: chrono::DateTime<Utc>,
     /// Pre-hashed standard library
     library: LazyHash<Library>,
+    /// Fonts added for this world only, indexed after the global fonts
+    extra_fonts: Vec<Font>,
+    /// Font book covering the global and extra fonts, when there are any
+    extra_book: Option<LazyHash<FontBook>>,
+    /// Whether the library marks tables for a tagged PDF
+    tagged: bool,
 }
 
 impl VirtualWorld {
@@ -52,7 +59,7 @@ impl VirtualWorld {
         let inputs_dict = Self::convert_inputs(inputs)?;
 
         // Build the library with inputs
-        let library = Self::build_library(inputs_dict.clone());
+        let library = Self::build_library(inputs_dict.clone(), false);
 
         Ok(Self {
             filesystem,
@@ -60,12 +67,70 @@ impl VirtualWorld {
             inputs: inputs_dict,
             time: Utc::now(),
             library: LazyHash::new(library),
+            extra_fonts: vec![],
+            extra_book: None,
+            tagged: false,
         })
     }
 
-    /// Build the Typst standard library with sys.inputs configured
-    fn build_library(inputs: Dict) -> Library {
-        Library::builder().with_inputs(inputs).build()
+    /// Compile for a tagged PDF: tables and their cells get
+    /// [structure markers](crate::accessibility::structure_markers) that
+    /// the tagger reads back from the frames
+    pub fn with_structure_markers(mut self, tagged: bool) -> Self {
+        if tagged != self.tagged {
+            self.tagged = tagged;
+            self.library = LazyHash::new(Self::build_library(self.inputs.clone(), tagged));
+        }
+        self
+    }
+
+    /// Add fonts for this world only, such as those of a template package
+    pub fn with_fonts(mut self, fonts: Vec<Bytes>) -> Self {
+        for data in fonts {
+            self.extra_fonts.extend(Font::iter(data));
+        }
+        if !self.extra_fonts.is_empty() {
+            let mut book = self.font_cache.book().clone();
+            for font in &self.extra_fonts {
+                book.push(font.info().clone());
+            }
+            self.extra_book = Some(LazyHash::new(book));
+        }
+        self
+    }
+
+    /// Replace the main source text, for another render of the same world
+    ///
+    /// Only the edited part is reparsed. Returns whether the text changed.
+    pub fn set_source(&mut self, source: String) -> bool {
+        self.filesystem.replace_main(source)
+    }
+
+    /// Replace sys.inputs, for another render of the same world
+    ///
+    /// The library is rebuilt only when the inputs differ from the current
+    /// ones. Returns whether they did.
+    pub fn set_inputs(
+        &mut self,
+        inputs: HashMap<String, serde_json::Value>,
+    ) -> Result<bool, ServerError> {
+        let inputs = Self::convert_inputs(inputs)?;
+        if inputs == self.inputs {
+            return Ok(false);
+        }
+        self.library = LazyHash::new(Self::build_library(inputs.clone(), self.tagged));
+        self.inputs = inputs;
+        Ok(true)
+    }
+
+    /// Build the Typst standard library with sys.inputs configured, and
+    /// with the structure markers for a tagged PDF
+    fn build_library(inputs: Dict, tagged: bool) -> Library {
+        let mut library = Library::builder().with_inputs(inputs).build();
+        if tagged {
+            library.styles.apply(structure_markers());
+        }
+        library
     }
 
     /// Convert JSON values to Typst Dict
@@ -135,6 +200,9 @@ impl World for VirtualWorld {
 
     /// Get the font book
     fn book(&self) -> &LazyHash<FontBook> {
+        if let Some(book) = &self.extra_book {
+            return book;
+        }
         // FontBook is not LazyHash in our cache, so we need to create one
         // This is a workaround - ideally the font cache would store LazyHash<FontBook>
         static BOOK: std::sync::OnceLock<LazyHash<FontBook>> = std::sync::OnceLock::new();
@@ -163,7 +231,11 @@ impl World for VirtualWorld {
 
     /// Get a font by index
     fn font(&self, index: usize) -> Option<Font> {
-        self.font_cache.font(index)
+        self.font_cache.font(index).or_else(|| {
+            self.extra_fonts
+                .get(index.checked_sub(self.font_cache.len())?)
+                .cloned()
+        })
     }
 
     /// Get the current date/time
@@ -214,6 +286,32 @@ mod tests {
         assert!(!inputs_dict.is_empty());
     }
 
+    #[test]
+    fn test_set_source_and_inputs() {
+        let mut inputs = HashMap::new();
+        inputs.insert("name".to_string(), serde_json::json!("Alice"));
+        let mut world = VirtualWorld::new(
+            "#sys.inputs.name".to_string(),
+            inputs.clone(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(!world.set_inputs(inputs.clone()).unwrap());
+        inputs.insert("name".to_string(), serde_json::json!("Bob"));
+        assert!(world.set_inputs(inputs).unwrap());
+        assert_eq!(
+            world.inputs().get("name").unwrap(),
+            &Value::Str("Bob".into())
+        );
+
+        assert!(world.set_source("Hi #sys.inputs.name".to_string()));
+        assert_eq!(
+            world.source(world.main()).unwrap().text(),
+            "Hi #sys.inputs.name"
+        );
+    }
+
     #[test]
     fn test_nested_input_conversion() {
         let mut inputs = HashMap::new();
@@ -238,4 +336,18 @@ mod tests {
         let datetime = world.today(None);
         assert!(datetime.is_some());
     }
+
+    #[test]
+    fn test_with_fonts() {
+        let world = VirtualWorld::new("test".to_string(), HashMap::new(), HashMap::new()).unwrap();
+        let global = world.book().families().count();
+
+        let data = Bytes::from_static(typst_assets::fonts().next().unwrap());
+        let world = world.with_fonts(vec![data]);
+
+        let cache_len = global_font_cache().len();
+        assert!(world.font(cache_len).is_some());
+        assert!(world.book().info(cache_len).is_some());
+        assert!(world.book().families().count() >= global);
+    }
 }
@@ -0,0 +1,145 @@
+// Compliance Report Template - Branded lease compliance findings
+// =============================================================================
+// Built from compliance_engine::report::typst_inputs(&ReportView).
+// Required inputs: title, document_id, summary, entries (array)
+// Optional inputs: brand_name, brand_color, document_name, checked_at, coverage,
+//   local_coverage
+//
+// Each entry: rule_id, fingerprint, statute, statute_url, severity, message,
+//             page, excerpt, suggested_clause, no_model_clause
+
+#let data = sys.inputs
+
+#let brand_color = rgb(data.at("brand_color", default: "#1e40af"))
+#let brand_name = data.at("brand_name", default: "agentPDF")
+#let title = data.at("title", default: "Lease Compliance Report")
+#let summary = data.at("summary", default: (critical: 0, warning: 0, info: 0, total: 0))
+#let entries = data.at("entries", default: ())
+
+#let severity_color(severity) = {
+  if severity == "Critical" { rgb("#dc2626") }
+  else if severity == "Warning" { rgb("#d97706") }
+  else { rgb("#2563eb") }
+}
+
+#let present(value) = value != none and value != ""
+
+#set document(title: title, author: brand_name)
+#set page(
+  paper: "us-letter",
+  margin: (x: 0.9in, top: 1in, bottom: 0.9in),
+  header: context {
+    if counter(page).get().first() > 1 [
+      #set text(size: 8pt, fill: luma(120))
+      #brand_name #h(1fr) #title
+    ]
+  },
+  footer: context [
+    #set text(size: 8pt, fill: luma(120))
+    #data.at("document_name", default: data.at("document_id", default: ""))
+    #h(1fr)
+    Page #counter(page).display() of #counter(page).final().first()
+  ],
+)
+#set text(font: "Liberation Sans", size: 10pt)
+#show link: set text(fill: brand_color)
+
+// =============================================================================
+// Cover and summary
+// =============================================================================
+
+#block(width: 100%, inset: (bottom: 8pt), stroke: (bottom: 3pt + brand_color))[
+  #text(size: 9pt, weight: "bold", fill: brand_color, tracking: 0.05em)[#upper(brand_name)]
+  #v(2pt)
+  #text(size: 20pt, weight: "bold")[#title]
+]
+
+#grid(
+  columns: (auto, 1fr),
+  row-gutter: 6pt,
+  column-gutter: 12pt,
+  [*Document*], [#data.at("document_name", default: data.at("document_id", default: "---"))],
+  [*Checked*], [#data.at("checked_at", default: "---")],
+  [*Coverage*], [#data.at("coverage", default: "---")],
+  [*Local ordinances*], [#data.at("local_coverage", default: "---")],
+)
+
+#v(12pt)
+
+#table(
+  columns: (1fr, 1fr, 1fr, 1fr),
+  align: center,
+  stroke: 0.5pt + luma(200),
+  fill: (_, row) => if row == 0 { brand_color.lighten(85%) },
+  [*Critical*], [*Warning*], [*Info*], [*Total*],
+  text(fill: severity_color("Critical"))[#summary.critical],
+  text(fill: severity_color("Warning"))[#summary.warning],
+  text(fill: severity_color("Info"))[#summary.info],
+  [#summary.total],
+)
+
+#if entries.len() == 0 [
+  #v(12pt)
+  No violations found.
+] else [
+  #v(12pt)
+  #table(
+    columns: (auto, auto, 1fr, auto),
+    stroke: 0.5pt + luma(200),
+    fill: (_, row) => if row == 0 { luma(240) },
+    [*\#*], [*Severity*], [*Statute*], [*Page*],
+    ..entries.enumerate().map(((i, entry)) => (
+      [#(i + 1)],
+      text(fill: severity_color(entry.severity))[#entry.severity],
+      [#entry.statute],
+      [#if present(entry.at("page", default: none)) [#entry.page] else [---]],
+    )).flatten()
+  )
+]
+
+// =============================================================================
+// Findings
+// =============================================================================
+
+#for (i, entry) in entries.enumerate() {
+  pagebreak(weak: true)
+  block(
+    width: 100%,
+    inset: 10pt,
+    stroke: (left: 4pt + severity_color(entry.severity)),
+    breakable: true,
+  )[
+    #text(size: 9pt, weight: "bold", fill: severity_color(entry.severity))[
+      #upper(entry.severity) · Finding #(i + 1)
+    ]
+    #v(2pt)
+    #text(size: 12pt, weight: "bold")[
+      #if present(entry.at("statute_url", default: none)) {
+        link(entry.statute_url)[#entry.statute]
+      } else {
+        entry.statute
+      }
+    ]
+    #if present(entry.at("page", default: none)) [ #h(6pt) #text(fill: luma(110))[page #entry.page] ]
+
+    #entry.message
+
+    #if present(entry.at("excerpt", default: none)) [
+      #text(size: 9pt, weight: "bold")[Excerpt]
+      #block(width: 100%, fill: luma(246), inset: 8pt, radius: 2pt)[
+        #text(size: 9pt)[#entry.excerpt]
+      ]
+    ]
+
+    #if present(entry.at("suggested_clause", default: none)) [
+      #text(size: 9pt, weight: "bold")[Suggested clause]
+      #block(width: 100%, fill: brand_color.lighten(92%), inset: 8pt, radius: 2pt)[
+        #text(size: 9pt)[#entry.suggested_clause]
+      ]
+    ] else if entry.at("no_model_clause", default: false) [
+      #text(size: 9pt, style: "italic")[No model clause covers this statute; the fix must be drafted by hand.]
+    ]
+
+    #text(size: 7pt, fill: luma(130), font: "DejaVu Sans Mono")[#entry.rule_id · #entry.fingerprint]
+  ]
+}
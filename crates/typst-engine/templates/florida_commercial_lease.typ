@@ -30,9 +30,18 @@
   numbering: "1",
   number-align: center,
 )
-#set text(font: "New Computer Modern", size: 10pt)
+#set document(title: "Florida Commercial Lease Agreement")
+#set text(font: "New Computer Modern", size: 10pt, lang: "en")
 #set par(justify: true, leading: 0.65em)
 
+// Section titles are headings, so tagged PDFs get a structure outline,
+// but keep the look of plain bold titles
+#show heading: it => block(above: 12pt, below: 12pt, text(
+  size: (24pt, 14pt, 12pt).at(calc.min(it.level, 3) - 1),
+  weight: "bold",
+  it.body,
+))
+
 // ============================================================================
 // COVER PAGE
 // ============================================================================
@@ -40,7 +49,7 @@
 #align(center)[
   #v(1.5in)
 
-  #text(size: 24pt, weight: "bold")[COMMERCIAL LEASE AGREEMENT]
+  #heading(level: 1)[COMMERCIAL LEASE AGREEMENT]
 
   #v(0.5em)
 
@@ -126,7 +135,7 @@
 // TABLE OF CONTENTS
 // ============================================================================
 
-#text(size: 16pt, weight: "bold")[TABLE OF CONTENTS]
+#heading(level: 2)[#text(size: 16pt)[TABLE OF CONTENTS]]
 #v(1em)
 
 #let toc_item(number, title) = [
@@ -169,10 +178,10 @@
 // SECTION 1: PARTIES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[1. PARTIES]
+#heading(level: 2)[1. PARTIES]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[1.1 LANDLORD]
+#heading(level: 3)[1.1 LANDLORD]
 #v(0.5em)
 
 #table(
@@ -187,7 +196,7 @@
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[1.2 TENANT]
+#heading(level: 3)[1.2 TENANT]
 #v(0.5em)
 
 #table(
@@ -208,10 +217,10 @@
 // SECTION 2: PREMISES AND PERMITTED USE
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[2. PREMISES AND PERMITTED USE]
+#heading(level: 2)[2. PREMISES AND PERMITTED USE]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[2.1 PREMISES]
+#heading(level: 3)[2.1 PREMISES]
 #v(0.5em)
 
 Landlord hereby leases to Tenant, and Tenant hereby leases from Landlord, the following described premises ("Premises"):
@@ -228,7 +237,7 @@ Landlord hereby leases to Tenant, and Tenant hereby leases from Landlord, the fo
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[2.2 PERMITTED USE]
+#heading(level: 3)[2.2 PERMITTED USE]
 #v(0.5em)
 
 #rect(
@@ -255,7 +264,7 @@ Landlord hereby leases to Tenant, and Tenant hereby leases from Landlord, the fo
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[2.3 PROHIBITED USES]
+#heading(level: 3)[2.3 PROHIBITED USES]
 #v(0.5em)
 
 Tenant shall not use the Premises for any unlawful purpose or in any manner that:
@@ -270,10 +279,10 @@ Tenant shall not use the Premises for any unlawful purpose or in any manner that
 // SECTION 3: TERM
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[3. TERM]
+#heading(level: 2)[3. TERM]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3.1 LEASE TERM]
+#heading(level: 3)[3.1 LEASE TERM]
 #v(0.5em)
 
 #table(
@@ -287,7 +296,7 @@ Tenant shall not use the Premises for any unlawful purpose or in any manner that
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3.2 RENEWAL OPTIONS]
+#heading(level: 3)[3.2 RENEWAL OPTIONS]
 #v(0.5em)
 
 #if get_bool("has_renewal_option") [
@@ -302,7 +311,7 @@ Tenant shall not use the Premises for any unlawful purpose or in any manner that
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3.3 EARLY POSSESSION]
+#heading(level: 3)[3.3 EARLY POSSESSION]
 #v(0.5em)
 
 #if get_bool("early_possession") [
@@ -317,10 +326,10 @@ Tenant shall not use the Premises for any unlawful purpose or in any manner that
 // SECTION 4: RENT
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[4. RENT]
+#heading(level: 2)[4. RENT]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[4.1 BASE RENT]
+#heading(level: 3)[4.1 BASE RENT]
 #v(0.5em)
 
 #table(
@@ -334,7 +343,7 @@ Tenant shall not use the Premises for any unlawful purpose or in any manner that
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[4.2 RENT PAYMENT]
+#heading(level: 3)[4.2 RENT PAYMENT]
 #v(0.5em)
 
 Base Rent shall be due and payable in advance on the *first (1st) day* of each calendar month during the Lease Term, without demand, deduction, or offset.
@@ -353,7 +362,7 @@ Rent shall be paid to:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[4.3 RENT ESCALATION]
+#heading(level: 3)[4.3 RENT ESCALATION]
 #v(0.5em)
 
 #if get_bool("has_rent_escalation") [
@@ -370,7 +379,7 @@ Rent shall be paid to:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[4.4 LATE CHARGES]
+#heading(level: 3)[4.4 LATE CHARGES]
 #v(0.5em)
 
 If any installment of Rent is not received within #get("late_fee_grace_period", default: "5") days after its due date, Tenant shall pay a late charge of #get("late_fee_percent", default: "5")% of the overdue amount. This late charge is in addition to any other remedies available to Landlord.
@@ -381,7 +390,7 @@ If any installment of Rent is not received within #get("late_fee_grace_period",
 // SECTION 5: SALES TAX ON RENT
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[5. SALES TAX ON RENT]
+#heading(level: 2)[5. SALES TAX ON RENT]
 #v(1em)
 
 #rect(
@@ -428,12 +437,12 @@ If any installment of Rent is not received within #get("late_fee_grace_period",
 // SECTION 6: ADDITIONAL RENT / CAM CHARGES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[6. ADDITIONAL RENT / CAM CHARGES]
+#heading(level: 2)[6. ADDITIONAL RENT / CAM CHARGES]
 #v(1em)
 
 #let lease_type = get("lease_type", default: "gross")
 
-#text(size: 12pt, weight: "bold")[6.1 LEASE TYPE]
+#heading(level: 3)[6.1 LEASE TYPE]
 #v(0.5em)
 
 #if lease_type == "nnn" [
@@ -461,7 +470,7 @@ If any installment of Rent is not received within #get("late_fee_grace_period",
 #v(1em)
 
 #if lease_type == "nnn" or lease_type == "modified_gross" [
-  #text(size: 12pt, weight: "bold")[6.2 COMMON AREA MAINTENANCE (CAM) CHARGES]
+  #heading(level: 3)[6.2 COMMON AREA MAINTENANCE (CAM) CHARGES]
   #v(0.5em)
 
   #rect(
@@ -497,7 +506,7 @@ If any installment of Rent is not received within #get("late_fee_grace_period",
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[6.3 CAM PAYMENT AND RECONCILIATION]
+  #heading(level: 3)[6.3 CAM PAYMENT AND RECONCILIATION]
   #v(0.5em)
 
   - Tenant shall pay *estimated* monthly CAM charges of #format_money(get_num("estimated_monthly_cam", default: 0)) per month.
@@ -507,7 +516,7 @@ If any installment of Rent is not received within #get("late_fee_grace_period",
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[6.4 CAM EXCLUSIONS]
+  #heading(level: 3)[6.4 CAM EXCLUSIONS]
   #v(0.5em)
 
   Operating Expenses shall NOT include:
@@ -524,7 +533,7 @@ If any installment of Rent is not received within #get("late_fee_grace_period",
 // SECTION 7: SECURITY DEPOSIT
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[7. SECURITY DEPOSIT]
+#heading(level: 2)[7. SECURITY DEPOSIT]
 #v(1em)
 
 #table(
@@ -553,7 +562,7 @@ The deposit shall be returned within #get("deposit_return_days", default: "30")
 // SECTION 8: UTILITIES AND SERVICES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[8. UTILITIES AND SERVICES]
+#heading(level: 2)[8. UTILITIES AND SERVICES]
 #v(1em)
 
 Tenant shall be responsible for the following utilities and services at Tenant's sole cost:
@@ -589,7 +598,7 @@ Tenant shall establish utility accounts in Tenant's name prior to the Commenceme
 // SECTION 9: MAINTENANCE AND REPAIRS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[9. MAINTENANCE AND REPAIRS]
+#heading(level: 2)[9. MAINTENANCE AND REPAIRS]
 #v(1em)
 
 #rect(
@@ -612,7 +621,7 @@ Tenant shall establish utility accounts in Tenant's name prior to the Commenceme
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[9.1 TENANT'S MAINTENANCE OBLIGATIONS]
+#heading(level: 3)[9.1 TENANT'S MAINTENANCE OBLIGATIONS]
 #v(0.5em)
 
 Tenant shall, at Tenant's sole cost, maintain in good condition:
@@ -625,7 +634,7 @@ Tenant shall, at Tenant's sole cost, maintain in good condition:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[9.2 LANDLORD'S MAINTENANCE OBLIGATIONS]
+#heading(level: 3)[9.2 LANDLORD'S MAINTENANCE OBLIGATIONS]
 #v(0.5em)
 
 Landlord shall maintain:
@@ -645,24 +654,24 @@ Landlord shall maintain:
 // SECTION 10: ALTERATIONS AND IMPROVEMENTS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[10. ALTERATIONS AND IMPROVEMENTS]
+#heading(level: 2)[10. ALTERATIONS AND IMPROVEMENTS]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[10.1 TENANT IMPROVEMENTS]
+#heading(level: 3)[10.1 TENANT IMPROVEMENTS]
 #v(0.5em)
 
 Tenant shall not make any alterations, additions, or improvements to the Premises without Landlord's prior written consent, which consent shall not be unreasonably withheld for non-structural improvements.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[10.2 OWNERSHIP OF IMPROVEMENTS]
+#heading(level: 3)[10.2 OWNERSHIP OF IMPROVEMENTS]
 #v(0.5em)
 
 All alterations, additions, and improvements made by Tenant shall become the property of Landlord upon installation and shall remain upon the Premises at the expiration or termination of this Lease, unless Landlord requires removal in writing.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[10.3 TRADE FIXTURES]
+#heading(level: 3)[10.3 TRADE FIXTURES]
 #v(0.5em)
 
 Tenant may remove trade fixtures, equipment, and personal property installed by Tenant, provided:
@@ -676,10 +685,10 @@ Tenant may remove trade fixtures, equipment, and personal property installed by
 // SECTION 11: INSURANCE
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[11. INSURANCE]
+#heading(level: 2)[11. INSURANCE]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[11.1 TENANT'S INSURANCE]
+#heading(level: 3)[11.1 TENANT'S INSURANCE]
 #v(0.5em)
 
 Tenant shall maintain, at Tenant's expense:
@@ -702,14 +711,14 @@ Tenant's insurance shall:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[11.2 LANDLORD'S INSURANCE]
+#heading(level: 3)[11.2 LANDLORD'S INSURANCE]
 #v(0.5em)
 
 Landlord shall maintain property insurance covering the Building and common areas. Cost may be passed through to Tenant as Operating Expenses under NNN or Modified Gross leases.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[11.3 WAIVER OF SUBROGATION]
+#heading(level: 3)[11.3 WAIVER OF SUBROGATION]
 #v(0.5em)
 
 Each party waives any right of recovery against the other party for any loss covered by the waiving party's insurance policies.
@@ -720,7 +729,7 @@ Each party waives any right of recovery against the other party for any loss cov
 // SECTION 12: INDEMNIFICATION
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[12. INDEMNIFICATION]
+#heading(level: 2)[12. INDEMNIFICATION]
 #v(1em)
 
 Tenant shall indemnify, defend, and hold harmless Landlord from and against all claims, damages, losses, and expenses arising from:
@@ -737,10 +746,10 @@ Landlord shall indemnify, defend, and hold harmless Tenant from and against all
 // SECTION 13: DEFAULT AND REMEDIES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[13. DEFAULT AND REMEDIES]
+#heading(level: 2)[13. DEFAULT AND REMEDIES]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[13.1 EVENTS OF DEFAULT]
+#heading(level: 3)[13.1 EVENTS OF DEFAULT]
 #v(0.5em)
 
 The following shall constitute an Event of Default:
@@ -753,7 +762,7 @@ The following shall constitute an Event of Default:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[13.2 LANDLORD'S REMEDIES]
+#heading(level: 3)[13.2 LANDLORD'S REMEDIES]
 #v(0.5em)
 
 Upon an Event of Default, Landlord may:
@@ -766,7 +775,7 @@ Upon an Event of Default, Landlord may:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[13.3 ACCELERATION]
+#heading(level: 3)[13.3 ACCELERATION]
 #v(0.5em)
 
 Upon default, Landlord may declare all remaining Rent for the Lease Term immediately due and payable.
@@ -777,10 +786,10 @@ Upon default, Landlord may declare all remaining Rent for the Lease Term immedia
 // SECTION 14: TERMINATION
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[14. TERMINATION]
+#heading(level: 2)[14. TERMINATION]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.1 TERMINATION NOTICE]
+#heading(level: 3)[14.1 TERMINATION NOTICE]
 #v(0.5em)
 
 #rect(
@@ -807,7 +816,7 @@ Upon default, Landlord may declare all remaining Rent for the Lease Term immedia
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.2 EARLY TERMINATION]
+#heading(level: 3)[14.2 EARLY TERMINATION]
 #v(0.5em)
 
 #if get_bool("has_early_termination") [
@@ -824,7 +833,7 @@ Upon default, Landlord may declare all remaining Rent for the Lease Term immedia
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.3 SURRENDER OF PREMISES]
+#heading(level: 3)[14.3 SURRENDER OF PREMISES]
 #v(0.5em)
 
 Upon termination, Tenant shall:
@@ -839,7 +848,7 @@ Upon termination, Tenant shall:
 // SECTION 15: ASSIGNMENT AND SUBLETTING
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[15. ASSIGNMENT AND SUBLETTING]
+#heading(level: 2)[15. ASSIGNMENT AND SUBLETTING]
 #v(1em)
 
 Tenant shall not assign this Lease or sublet all or any portion of the Premises without Landlord's prior written consent, which:
@@ -860,7 +869,7 @@ Any assignment or sublease shall not release Tenant from liability under this Le
 // SECTION 16: COMPLIANCE WITH LAWS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[16. COMPLIANCE WITH LAWS]
+#heading(level: 2)[16. COMPLIANCE WITH LAWS]
 #v(1em)
 
 Tenant shall comply with all federal, state, and local laws, ordinances, and regulations applicable to Tenant's use of the Premises, including but not limited to:
@@ -879,7 +888,7 @@ Tenant shall be responsible for obtaining all permits and licenses required for
 // SECTION 17: SIGNAGE
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[17. SIGNAGE]
+#heading(level: 2)[17. SIGNAGE]
 #v(1em)
 
 #if get_bool("signage_allowed") [
@@ -901,31 +910,31 @@ Tenant shall be responsible for obtaining all permits and licenses required for
 // SECTION 18: ADDITIONAL PROVISIONS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[18. ADDITIONAL PROVISIONS]
+#heading(level: 2)[18. ADDITIONAL PROVISIONS]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[18.1 ENTIRE AGREEMENT]
+#heading(level: 3)[18.1 ENTIRE AGREEMENT]
 #v(0.5em)
 
 This Lease, together with all exhibits and addenda, constitutes the entire agreement between the parties. No prior negotiations, representations, or agreements shall be binding unless incorporated herein.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[18.2 GOVERNING LAW]
+#heading(level: 3)[18.2 GOVERNING LAW]
 #v(0.5em)
 
 This Lease shall be governed by the laws of the State of Florida, specifically Chapter 83, Part I (Non-Residential Tenancies).
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[18.3 ATTORNEY'S FEES]
+#heading(level: 3)[18.3 ATTORNEY'S FEES]
 #v(0.5em)
 
 In any action to enforce this Lease, the prevailing party shall be entitled to recover reasonable attorney's fees and costs.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[18.4 NOTICES]
+#heading(level: 3)[18.4 NOTICES]
 #v(0.5em)
 
 All notices shall be in writing and delivered by:
@@ -937,7 +946,7 @@ Notices shall be sent to the addresses set forth in Section 1.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[18.5 ADDITIONAL TERMS]
+#heading(level: 3)[18.5 ADDITIONAL TERMS]
 #v(0.5em)
 
 #if get("additional_terms", default: "") != "" [
@@ -952,7 +961,7 @@ Notices shall be sent to the addresses set forth in Section 1.
 // SECTION 19: SIGNATURES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[19. SIGNATURES]
+#heading(level: 2)[19. SIGNATURES]
 #v(1em)
 
 BY SIGNING BELOW, THE PARTIES ACKNOWLEDGE THAT THEY HAVE READ THIS COMMERCIAL LEASE IN ITS ENTIRETY, UNDERSTAND ITS TERMS, AND AGREE TO BE BOUND THEREBY.
@@ -997,7 +1006,7 @@ BY SIGNING BELOW, THE PARTIES ACKNOWLEDGE THAT THEY HAVE READ THIS COMMERCIAL LE
 // ============================================================================
 
 #if get_bool("is_agricultural") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM A: AGRICULTURAL LIEN]
+  #heading(level: 2)[ADDENDUM A: AGRICULTURAL LIEN]
   #v(0.5em)
   #text(size: 10pt, style: "italic")[Pursuant to Florida Statutes § 83.08]
   #v(1em)
@@ -1057,7 +1066,7 @@ BY SIGNING BELOW, THE PARTIES ACKNOWLEDGE THAT THEY HAVE READ THIS COMMERCIAL LE
 // ============================================================================
 
 #if get_bool("requires_guaranty") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM B: PERSONAL GUARANTY]
+  #heading(level: 2)[ADDENDUM B: PERSONAL GUARANTY]
   #v(1em)
 
   #rect(
@@ -1110,7 +1119,7 @@ BY SIGNING BELOW, THE PARTIES ACKNOWLEDGE THAT THEY HAVE READ THIS COMMERCIAL LE
 // ============================================================================
 
 #if get_bool("has_exclusivity") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM C: EXCLUSIVITY CLAUSE]
+  #heading(level: 2)[ADDENDUM C: EXCLUSIVITY CLAUSE]
   #v(1em)
 
   #rect(
@@ -26,9 +26,18 @@
   numbering: "1",
   number-align: center,
 )
-#set text(font: "Liberation Sans", size: 10pt)
+#set document(title: "Florida Residential Lease Agreement")
+#set text(font: "Liberation Sans", size: 10pt, lang: "en")
 #set par(justify: true, leading: 0.65em)
 
+// Section titles are headings, so tagged PDFs get a structure outline,
+// but keep the look of plain bold titles
+#show heading: it => block(above: 12pt, below: 12pt, text(
+  size: (24pt, 14pt, 12pt).at(calc.min(it.level, 3) - 1),
+  weight: "bold",
+  it.body,
+))
+
 // ============================================================================
 // COVER PAGE
 // ============================================================================
@@ -36,7 +45,7 @@
 #align(center)[
   #v(2in)
 
-  #text(size: 24pt, weight: "bold")[RESIDENTIAL LEASE AGREEMENT]
+  #heading(level: 1)[RESIDENTIAL LEASE AGREEMENT]
 
   #v(0.5em)
 
@@ -93,7 +102,7 @@
 // TABLE OF CONTENTS
 // ============================================================================
 
-#text(size: 16pt, weight: "bold")[TABLE OF CONTENTS]
+#heading(level: 2)[#text(size: 16pt)[TABLE OF CONTENTS]]
 #v(1em)
 
 #let toc_item(number, title) = [
@@ -166,11 +175,11 @@
 // SECTION 1: BASIC TERMS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[1. BASIC TERMS]
+#heading(level: 2)[1. BASIC TERMS]
 #v(1em)
 
 // 1.1 Amounts Due at Signing
-#text(size: 12pt, weight: "bold")[1.1 AMOUNTS DUE AT SIGNING]
+#heading(level: 3)[1.1 AMOUNTS DUE AT SIGNING]
 #v(0.5em)
 
 #let rent = get_num("monthly_rent")
@@ -192,7 +201,7 @@
 #v(1em)
 
 // 1.2 Property
-#text(size: 12pt, weight: "bold")[1.2 PROPERTY]
+#heading(level: 3)[1.2 PROPERTY]
 #v(0.5em)
 
 The Landlord agrees to lease to Tenant(s) the property located at:
@@ -206,7 +215,7 @@ Bedrooms: #get("bedrooms", default: "N/A") #h(2em) Bathrooms: #get("bathrooms",
 #v(1em)
 
 // 1.3 Lease Term
-#text(size: 12pt, weight: "bold")[1.3 LEASE TERM]
+#heading(level: 3)[1.3 LEASE TERM]
 #v(0.5em)
 
 The lease term begins on *#get("lease_start", default: "[Start Date]")* and ends on *#get("lease_end", default: "[End Date]")*.
@@ -218,7 +227,7 @@ This is a fixed-term lease. Upon expiration, the lease will convert to a month-t
 #v(1em)
 
 // 1.4 Rent
-#text(size: 12pt, weight: "bold")[1.4 RENT]
+#heading(level: 3)[1.4 RENT]
 #v(0.5em)
 
 Monthly rent is *#format_money(get_num("monthly_rent"))*, due on the *#get("rent_due_day", default: "1st")* day of each month.
@@ -236,7 +245,7 @@ Monthly rent is *#format_money(get_num("monthly_rent"))*, due on the *#get("rent
 #v(1em)
 
 // 1.5 Late Fees
-#text(size: 12pt, weight: "bold")[1.5 LATE FEES]
+#heading(level: 3)[1.5 LATE FEES]
 #v(0.5em)
 
 If rent is not received by the *#get("grace_period_days", default: "5th")* day of the month, a late fee of *#format_money(get_num("late_fee", default: 50))* will be charged. Additional late fees of *#format_money(get_num("daily_late_fee", default: 0))* per day may apply thereafter, up to a maximum of #format_money(get_num("max_late_fee", default: 100)).
@@ -246,7 +255,7 @@ A fee of #format_money(get_num("nsf_fee", default: 35)) will be charged for any
 #v(1em)
 
 // 1.6 Utilities
-#text(size: 12pt, weight: "bold")[1.6 UTILITIES]
+#heading(level: 3)[1.6 UTILITIES]
 #v(0.5em)
 
 #let check_landlord(util) = if get(util, default: "tenant") == "landlord" { sym.checkmark } else { "" }
@@ -268,7 +277,7 @@ A fee of #format_money(get_num("nsf_fee", default: 35)) will be charged for any
 #v(1em)
 
 // 1.7 Parking
-#text(size: 12pt, weight: "bold")[1.7 PARKING]
+#heading(level: 3)[1.7 PARKING]
 #v(0.5em)
 
 #if get_bool("parking_included") [
@@ -281,7 +290,7 @@ A fee of #format_money(get_num("nsf_fee", default: 35)) will be charged for any
 #v(1em)
 
 // 1.8 Pets
-#text(size: 12pt, weight: "bold")[1.8 PETS]
+#heading(level: 3)[1.8 PETS]
 #v(0.5em)
 
 #if get_bool("pets_allowed") [
@@ -300,7 +309,7 @@ A fee of #format_money(get_num("nsf_fee", default: 35)) will be charged for any
 #v(1em)
 
 // 1.9 Smoking
-#text(size: 12pt, weight: "bold")[1.9 SMOKING]
+#heading(level: 3)[1.9 SMOKING]
 #v(0.5em)
 
 #if get_bool("smoking_allowed") [
@@ -312,7 +321,7 @@ A fee of #format_money(get_num("nsf_fee", default: 35)) will be charged for any
 #v(1em)
 
 // 1.10 Occupants
-#text(size: 12pt, weight: "bold")[1.10 OCCUPANTS]
+#heading(level: 3)[1.10 OCCUPANTS]
 #v(0.5em)
 
 The following persons are authorized to occupy the premises:
@@ -330,7 +339,7 @@ No other persons may occupy the premises without prior written consent from the
 #v(1em)
 
 // 1.11 Emergency Contact
-#text(size: 12pt, weight: "bold")[1.11 EMERGENCY CONTACT]
+#heading(level: 3)[1.11 EMERGENCY CONTACT]
 #v(0.5em)
 
 Tenant's emergency contact:
@@ -344,7 +353,7 @@ Relationship: #get("emergency_contact_relationship", default: "[Relationship]")
 #v(1em)
 
 // 1.12 Appliances & Furnishings
-#text(size: 12pt, weight: "bold")[1.12 APPLIANCES & FURNISHINGS]
+#heading(level: 3)[1.12 APPLIANCES & FURNISHINGS]
 #v(0.5em)
 
 The following appliances and furnishings are included with the rental:
@@ -361,11 +370,11 @@ The following appliances and furnishings are included with the rental:
 // SECTION 2: ADDITIONAL TERMS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[2. ADDITIONAL TERMS]
+#heading(level: 2)[2. ADDITIONAL TERMS]
 #v(1em)
 
 // 2.1 Property Condition
-#text(size: 12pt, weight: "bold")[2.1 PROPERTY CONDITION]
+#heading(level: 3)[2.1 PROPERTY CONDITION]
 #v(0.5em)
 
 Tenant acknowledges receiving the premises in good condition except as noted in the move-in inspection checklist. Tenant agrees to maintain the premises in good condition throughout the lease term and return it in the same condition, normal wear and tear excepted.
@@ -373,7 +382,7 @@ Tenant acknowledges receiving the premises in good condition except as noted in
 #v(1em)
 
 // 2.2 Possession
-#text(size: 12pt, weight: "bold")[2.2 POSSESSION]
+#heading(level: 3)[2.2 POSSESSION]
 #v(0.5em)
 
 If Landlord cannot deliver possession on the lease start date due to circumstances beyond Landlord's reasonable control, rent shall be abated on a daily basis until possession is delivered. If possession is not delivered within #get("possession_delay_days", default: "7") days of the start date, Tenant may terminate this lease and receive a full refund of all deposits and prepaid rent.
@@ -381,7 +390,7 @@ If Landlord cannot deliver possession on the lease start date due to circumstanc
 #v(1em)
 
 // 2.3 Rent Payment
-#text(size: 12pt, weight: "bold")[2.3 RENT PAYMENT]
+#heading(level: 3)[2.3 RENT PAYMENT]
 #v(0.5em)
 
 Rent shall be paid to:
@@ -395,7 +404,7 @@ Acceptable payment methods: #get("payment_method", default: "Check, money order,
 #v(1em)
 
 // 2.4 Security Deposit
-#text(size: 12pt, weight: "bold")[2.4 SECURITY DEPOSIT]
+#heading(level: 3)[2.4 SECURITY DEPOSIT]
 #v(0.5em)
 
 Tenant has deposited *#format_money(get_num("security_deposit"))* as a security deposit. This deposit shall be held in accordance with Florida Statutes § 83.49 and returned as provided therein. See Security Deposit Disclosure Addendum for full details.
@@ -409,7 +418,7 @@ The security deposit may be used for:
 #v(1em)
 
 // 2.5 Tenant Obligations
-#text(size: 12pt, weight: "bold")[2.5 TENANT OBLIGATIONS]
+#heading(level: 3)[2.5 TENANT OBLIGATIONS]
 #v(0.5em)
 
 Tenant agrees to:
@@ -427,7 +436,7 @@ Tenant agrees to:
 #v(1em)
 
 // 2.6 Landlord Obligations
-#text(size: 12pt, weight: "bold")[2.6 LANDLORD OBLIGATIONS]
+#heading(level: 3)[2.6 LANDLORD OBLIGATIONS]
 #v(0.5em)
 
 Landlord agrees to:
@@ -443,7 +452,7 @@ Landlord agrees to:
 #v(1em)
 
 // 2.7 Access
-#text(size: 12pt, weight: "bold")[2.7 ACCESS]
+#heading(level: 3)[2.7 ACCESS]
 #v(0.5em)
 
 Landlord may enter the premises for inspection, repairs, or to show the property to prospective tenants or buyers, with at least #get("access_notice_hours", default: "12") hours advance notice, except in case of emergency. Entry shall be at reasonable times.
@@ -451,7 +460,7 @@ Landlord may enter the premises for inspection, repairs, or to show the property
 #v(1em)
 
 // 2.8 Alterations
-#text(size: 12pt, weight: "bold")[2.8 ALTERATIONS]
+#heading(level: 3)[2.8 ALTERATIONS]
 #v(0.5em)
 
 Tenant shall not make any alterations, additions, or improvements to the premises without prior written consent from Landlord. Any approved alterations become the property of Landlord unless otherwise agreed in writing.
@@ -459,7 +468,7 @@ Tenant shall not make any alterations, additions, or improvements to the premise
 #v(1em)
 
 // 2.9 Subletting
-#text(size: 12pt, weight: "bold")[2.9 SUBLETTING]
+#heading(level: 3)[2.9 SUBLETTING]
 #v(0.5em)
 
 Subletting or assignment of this lease is not permitted without prior written consent from Landlord.
@@ -467,7 +476,7 @@ Subletting or assignment of this lease is not permitted without prior written co
 #v(1em)
 
 // 2.10 Insurance
-#text(size: 12pt, weight: "bold")[2.10 INSURANCE]
+#heading(level: 3)[2.10 INSURANCE]
 #v(0.5em)
 
 #if get_bool("renters_insurance_required") [
@@ -479,7 +488,7 @@ Subletting or assignment of this lease is not permitted without prior written co
 #v(1em)
 
 // 2.11 Surrender of Premises
-#text(size: 12pt, weight: "bold")[2.11 SURRENDER OF PREMISES]
+#heading(level: 3)[2.11 SURRENDER OF PREMISES]
 #v(0.5em)
 
 Upon termination of this lease, Tenant shall:
@@ -492,7 +501,7 @@ Upon termination of this lease, Tenant shall:
 #v(1em)
 
 // 2.12 Default
-#text(size: 12pt, weight: "bold")[2.12 DEFAULT]
+#heading(level: 3)[2.12 DEFAULT]
 #v(0.5em)
 
 *Tenant Default:* If Tenant fails to pay rent or violates any other term of this lease, Landlord may, after providing notice as required by Florida law, pursue any remedies available under Florida Statutes Chapter 83, including termination of tenancy and eviction proceedings.
@@ -502,7 +511,7 @@ Upon termination of this lease, Tenant shall:
 #v(1em)
 
 // 2.13 Notices
-#text(size: 12pt, weight: "bold")[2.13 NOTICES]
+#heading(level: 3)[2.13 NOTICES]
 #v(0.5em)
 
 All notices shall be in writing and delivered by:
@@ -517,7 +526,7 @@ Notices to Tenant: #get("property_address", default: "[Property Address]")
 #v(1em)
 
 // 2.14 Governing Law
-#text(size: 12pt, weight: "bold")[2.14 GOVERNING LAW]
+#heading(level: 3)[2.14 GOVERNING LAW]
 #v(0.5em)
 
 This lease shall be governed by the laws of the State of Florida, specifically Florida Statutes Chapter 83 (Florida Residential Landlord and Tenant Act).
@@ -525,7 +534,7 @@ This lease shall be governed by the laws of the State of Florida, specifically F
 #v(1em)
 
 // 2.15 Additional Provisions
-#text(size: 12pt, weight: "bold")[2.15 ADDITIONAL PROVISIONS]
+#heading(level: 3)[2.15 ADDITIONAL PROVISIONS]
 #v(0.5em)
 
 #get("additional_provisions", default: "None.")
@@ -533,7 +542,7 @@ This lease shall be governed by the laws of the State of Florida, specifically F
 #v(1em)
 
 // 2.16 Jury Trial Waiver
-#text(size: 12pt, weight: "bold")[2.16 JURY TRIAL WAIVER]
+#heading(level: 3)[2.16 JURY TRIAL WAIVER]
 #v(0.5em)
 
 #rect(
@@ -557,7 +566,7 @@ This lease shall be governed by the laws of the State of Florida, specifically F
 #v(1em)
 
 // 2.17 Unauthorized Occupants (HB 621)
-#text(size: 12pt, weight: "bold")[2.17 UNAUTHORIZED OCCUPANTS (HB 621)]
+#heading(level: 3)[2.17 UNAUTHORIZED OCCUPANTS (HB 621)]
 #v(0.5em)
 
 Only the individuals named in this lease as Tenant(s) are authorized to occupy the premises. No other person may reside at the property without prior written consent from Landlord.
@@ -585,7 +594,7 @@ Only the individuals named in this lease as Tenant(s) are authorized to occupy t
 #v(1em)
 
 // 2.18 Service Member Rights (§ 83.682)
-#text(size: 12pt, weight: "bold")[2.18 SERVICE MEMBER RIGHTS (§ 83.682)]
+#heading(level: 3)[2.18 SERVICE MEMBER RIGHTS (§ 83.682)]
 #v(0.5em)
 
 #rect(
@@ -621,7 +630,7 @@ Only the individuals named in this lease as Tenant(s) are authorized to occupy t
 #v(1em)
 
 // 2.19 Emotional Support Animal / Assistance Animal Policy (SB 1084)
-#text(size: 12pt, weight: "bold")[2.19 EMOTIONAL SUPPORT ANIMAL (ESA) POLICY (SB 1084)]
+#heading(level: 3)[2.19 EMOTIONAL SUPPORT ANIMAL (ESA) POLICY (SB 1084)]
 #v(0.5em)
 
 Florida law (SB 1084, codified at Florida Statute § 817.265) provides protections for both housing providers and individuals with disabilities regarding emotional support animals and assistance animals.
@@ -696,10 +705,10 @@ Florida law (SB 1084, codified at Florida Statute § 817.265) provides protectio
 // SECTION 3: CONTACT INFORMATION
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[3. CONTACT INFORMATION]
+#heading(level: 2)[3. CONTACT INFORMATION]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[LANDLORD / PROPERTY MANAGER]
+#heading(level: 3)[LANDLORD / PROPERTY MANAGER]
 #v(0.5em)
 
 #table(
@@ -715,7 +724,7 @@ Florida law (SB 1084, codified at Florida Statute § 817.265) provides protectio
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[TENANT(S)]
+#heading(level: 3)[TENANT(S)]
 #v(0.5em)
 
 #table(
@@ -734,7 +743,7 @@ Florida law (SB 1084, codified at Florida Statute § 817.265) provides protectio
 // SECTION 4: SIGNATURES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[4. SIGNATURES]
+#heading(level: 2)[4. SIGNATURES]
 #v(1em)
 
 By signing below, the parties agree to all terms and conditions of this Residential Lease Agreement.
@@ -783,14 +792,14 @@ By signing below, the parties agree to all terms and conditions of this Resident
 // ============================================================================
 
 #if get_bool("include_pet_addendum") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM A: PET ADDENDUM]
+  #heading(level: 2)[ADDENDUM A: PET ADDENDUM]
   #v(1em)
 
   This Pet Addendum is attached to and made part of the Residential Lease Agreement dated #get("lease_start", default: "[Start Date]").
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[PET INFORMATION]
+  #heading(level: 3)[PET INFORMATION]
   #v(0.5em)
 
   #table(
@@ -809,7 +818,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[PET FEES]
+  #heading(level: 3)[PET FEES]
   #v(0.5em)
 
   #table(
@@ -823,7 +832,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[PET RULES]
+  #heading(level: 3)[PET RULES]
   #v(0.5em)
 
   + Tenant is responsible for all damage caused by the pet(s)
@@ -860,14 +869,14 @@ By signing below, the parties agree to all terms and conditions of this Resident
 // ============================================================================
 
 #if get_bool("include_parking_addendum") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM B: PARKING ADDENDUM]
+  #heading(level: 2)[ADDENDUM B: PARKING ADDENDUM]
   #v(1em)
 
   This Parking Addendum is attached to and made part of the Residential Lease Agreement dated #get("lease_start", default: "[Start Date]").
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[ASSIGNED PARKING]
+  #heading(level: 3)[ASSIGNED PARKING]
   #v(0.5em)
 
   #table(
@@ -882,7 +891,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[VEHICLE INFORMATION]
+  #heading(level: 3)[VEHICLE INFORMATION]
   #v(0.5em)
 
   #table(
@@ -897,7 +906,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[PARKING RULES]
+  #heading(level: 3)[PARKING RULES]
   #v(0.5em)
 
   + Only vehicles registered above may use the assigned parking space(s)
@@ -932,14 +941,14 @@ By signing below, the parties agree to all terms and conditions of this Resident
 // ============================================================================
 
 #if get_bool("include_rules_addendum") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM C: RULES AND REGULATIONS]
+  #heading(level: 2)[ADDENDUM C: RULES AND REGULATIONS]
   #v(1em)
 
   This Rules and Regulations Addendum is attached to and made part of the Residential Lease Agreement dated #get("lease_start", default: "[Start Date]").
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[GENERAL RULES]
+  #heading(level: 3)[GENERAL RULES]
   #v(0.5em)
 
   + *Quiet Hours:* 10:00 PM to 8:00 AM daily
@@ -957,7 +966,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[MOVE-IN/MOVE-OUT]
+  #heading(level: 3)[MOVE-IN/MOVE-OUT]
   #v(0.5em)
 
   + Reserve the elevator/moving areas 48 hours in advance
@@ -990,7 +999,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 // ============================================================================
 
 #if get_bool("property_built_before_1978") [
-  #text(size: 14pt, weight: "bold")[ADDENDUM D: LEAD-BASED PAINT DISCLOSURE]
+  #heading(level: 2)[ADDENDUM D: LEAD-BASED PAINT DISCLOSURE]
   #v(0.5em)
   #text(size: 10pt, style: "italic")[Required for housing built before 1978 (24 CFR Part 35)]
   #v(1em)
@@ -1009,7 +1018,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[LANDLORD'S DISCLOSURE]
+  #heading(level: 3)[LANDLORD'S DISCLOSURE]
   #v(0.5em)
 
   (a) Presence of lead-based paint and/or lead-based paint hazards:
@@ -1026,7 +1035,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[TENANT'S ACKNOWLEDGMENT]
+  #heading(level: 3)[TENANT'S ACKNOWLEDGMENT]
   #v(0.5em)
 
   (c) Tenant has received the following:
@@ -1045,7 +1054,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
   #v(1em)
 
-  #text(size: 12pt, weight: "bold")[CERTIFICATION]
+  #heading(level: 3)[CERTIFICATION]
   #v(0.5em)
 
   The parties certify that the information provided is true and accurate to the best of their knowledge.
@@ -1082,7 +1091,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 // ADDENDUM E: RADON GAS NOTIFICATION (MANDATORY - F.S. § 404.056)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[ADDENDUM E: RADON GAS NOTIFICATION]
+#heading(level: 2)[ADDENDUM E: RADON GAS NOTIFICATION]
 #v(0.5em)
 #text(size: 10pt, style: "italic")[Required by Florida Statutes § 404.056]
 #v(1em)
@@ -1094,7 +1103,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
   fill: rgb("#f0f8ff"),
   radius: 4pt,
 )[
-  #text(weight: "bold", size: 12pt)[RADON GAS NOTIFICATION]
+  #heading(level: 3)[RADON GAS NOTIFICATION]
 
   #v(1em)
 
@@ -1144,12 +1153,12 @@ By signing below, Tenant acknowledges receipt of the above Radon Gas Notificatio
 // ADDENDUM F: SECURITY DEPOSIT DISCLOSURE (MANDATORY - F.S. § 83.49)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[ADDENDUM F: SECURITY DEPOSIT DISCLOSURE]
+#heading(level: 2)[ADDENDUM F: SECURITY DEPOSIT DISCLOSURE]
 #v(0.5em)
 #text(size: 10pt, style: "italic")[Required by Florida Statutes § 83.49]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[SECURITY DEPOSIT AMOUNT]
+#heading(level: 3)[SECURITY DEPOSIT AMOUNT]
 #v(0.5em)
 
 #table(
@@ -1162,7 +1171,7 @@ By signing below, Tenant acknowledges receipt of the above Radon Gas Notificatio
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[METHOD OF HOLDING DEPOSIT]
+#heading(level: 3)[METHOD OF HOLDING DEPOSIT]
 
 #v(0.5em)
 
@@ -1200,7 +1209,7 @@ Pursuant to Florida Statutes § 83.49(1), the Landlord holds the security deposi
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[STATUTORY RIGHTS AND OBLIGATIONS]
+#heading(level: 3)[STATUTORY RIGHTS AND OBLIGATIONS]
 #v(0.5em)
 
 #rect(
@@ -1216,8 +1225,8 @@ Pursuant to Florida Statutes § 83.49(1), the Landlord holds the security deposi
 
   *Return of Deposit:* Upon termination of the lease and vacation of the premises, the Landlord shall return the security deposit together with interest if required, or shall provide written notice of intention to impose a claim on the deposit:
 
-  - *Within 15 days* if no claim is made against the deposit
-  - *Within 30 days* if a claim is made, with itemized written notice sent by certified mail
+  - Return of the full deposit *within #get("deposit_return_days", default: "15") days* if no claim is made against the deposit
+  - Written notice of a claim *within 30 days*, itemized and sent by certified mail
 
   #v(0.5em)
 
@@ -1230,7 +1239,7 @@ Pursuant to Florida Statutes § 83.49(1), the Landlord holds the security deposi
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[FORWARDING ADDRESS]
+#heading(level: 3)[FORWARDING ADDRESS]
 #v(0.5em)
 
 Tenant must provide a forwarding address in writing within 10 days after vacating the premises to receive return of the security deposit.
@@ -1272,7 +1281,7 @@ By signing below, Tenant acknowledges receipt of this Security Deposit Disclosur
 // ADDENDUM G: ELECTRONIC NOTICE CONSENT (HB 615 - § 83.56)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[ADDENDUM G: ELECTRONIC NOTICE CONSENT]
+#heading(level: 2)[ADDENDUM G: ELECTRONIC NOTICE CONSENT]
 #v(0.5em)
 #text(size: 10pt, style: "italic")[Pursuant to Florida Statutes § 83.56 as amended by HB 615]
 #v(1em)
@@ -1284,7 +1293,7 @@ By signing below, Tenant acknowledges receipt of this Security Deposit Disclosur
   fill: rgb("#f0f8ff"),
   radius: 4pt,
 )[
-  #text(weight: "bold", size: 12pt)[CONSENT TO ELECTRONIC DELIVERY OF NOTICES]
+  #heading(level: 3)[CONSENT TO ELECTRONIC DELIVERY OF NOTICES]
 
   #v(1em)
 
@@ -1309,7 +1318,7 @@ By signing below, Tenant acknowledges receipt of this Security Deposit Disclosur
 // not pre-filled by landlord. Per HB 615, tenant must actively consent.
 // Both checkboxes left unchecked - tenant checks one during signing.
 
-#text(size: 12pt, weight: "bold")[TENANT'S ELECTION]
+#heading(level: 3)[TENANT'S ELECTION]
 #v(0.5em)
 
 #text(style: "italic", size: 10pt)[
@@ -1325,7 +1334,7 @@ By signing below, Tenant acknowledges receipt of this Security Deposit Disclosur
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[TENANT EMAIL ADDRESS FOR NOTICES]
+#heading(level: 3)[TENANT EMAIL ADDRESS FOR NOTICES]
 #v(0.5em)
 
 Email: #get("tenant_email", default: "[Tenant Email Address]")
@@ -1338,7 +1347,7 @@ Email: #get("tenant_email", default: "[Tenant Email Address]")
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[LANDLORD EMAIL ADDRESS FOR NOTICES]
+#heading(level: 3)[LANDLORD EMAIL ADDRESS FOR NOTICES]
 #v(0.5em)
 
 Email: #get("landlord_email", default: "[Landlord Email Address]")
@@ -1380,7 +1389,7 @@ By signing below, both parties acknowledge and agree to the electronic notice pr
 // ADDENDUM H: FLOOD DISCLOSURE (SB 948 - § 83.512)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[ADDENDUM H: FLOOD DISCLOSURE]
+#heading(level: 2)[ADDENDUM H: FLOOD DISCLOSURE]
 #v(0.5em)
 #text(size: 10pt, style: "italic")[Required by Florida Statutes § 83.512 (SB 948)]
 #v(1em)
@@ -1392,7 +1401,7 @@ By signing below, both parties acknowledge and agree to the electronic notice pr
   fill: rgb("#fef2f2"),
   radius: 4pt,
 )[
-  #text(weight: "bold", size: 12pt)[MANDATORY FLOOD DISCLOSURE]
+  #heading(level: 3)[MANDATORY FLOOD DISCLOSURE]
 
   #v(1em)
 
@@ -1405,7 +1414,7 @@ By signing below, both parties acknowledge and agree to the electronic notice pr
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[LANDLORD'S DISCLOSURE]
+#heading(level: 3)[LANDLORD'S DISCLOSURE]
 #v(0.5em)
 
 // Flood History Status - Tristate: "yes", "no", "unknown"
@@ -1580,7 +1589,7 @@ By signing below, both parties certify that this Flood Disclosure has been provi
 #if get_bool("has_hoa") or get_bool("has_association") [
   #pagebreak()
 
-  #text(size: 14pt, weight: "bold")[ADDENDUM I: HOA/CONDO ASSOCIATION ADDENDUM]
+  #heading(level: 2)[ADDENDUM I: HOA/CONDO ASSOCIATION ADDENDUM]
 
   #v(0.5em)
 
@@ -1743,7 +1752,7 @@ By signing below, both parties certify that this Flood Disclosure has been provi
 #if get_bool("in_cdd") [
   #pagebreak()
 
-  #text(size: 14pt, weight: "bold")[ADDENDUM J: COMMUNITY DEVELOPMENT DISTRICT (CDD) DISCLOSURE]
+  #heading(level: 2)[ADDENDUM J: COMMUNITY DEVELOPMENT DISTRICT (CDD) DISCLOSURE]
 
   #v(0.5em)
 
@@ -1887,7 +1896,7 @@ By signing below, both parties certify that this Flood Disclosure has been provi
 #if get_bool("early_termination_fee") [
   #pagebreak()
 
-  #text(size: 14pt, weight: "bold")[ADDENDUM K: LIQUIDATED DAMAGES / EARLY TERMINATION FEE]
+  #heading(level: 2)[ADDENDUM K: LIQUIDATED DAMAGES / EARLY TERMINATION FEE]
 
   #v(0.5em)
 
@@ -2117,7 +2126,7 @@ By signing below, both parties certify that this Flood Disclosure has been provi
 #if get_bool("mold_addendum") [
   #pagebreak()
 
-  #text(size: 14pt, weight: "bold")[ADDENDUM L: MOLD PREVENTION ADDENDUM]
+  #heading(level: 2)[ADDENDUM L: MOLD PREVENTION ADDENDUM]
 
   #v(0.5em)
 
@@ -26,9 +26,18 @@
   numbering: "1",
   number-align: center,
 )
-#set text(font: "Liberation Sans", size: 10pt)
+#set document(title: "Texas Residential Lease Agreement")
+#set text(font: "Liberation Sans", size: 10pt, lang: "en")
 #set par(justify: true, leading: 0.65em)
 
+// Section titles are headings, so tagged PDFs get a structure outline,
+// but keep the look of plain bold titles
+#show heading: it => block(above: 12pt, below: 12pt, text(
+  size: (24pt, 14pt, 12pt).at(calc.min(it.level, 3) - 1),
+  weight: "bold",
+  it.body,
+))
+
 // ============================================================================
 // COVER PAGE
 // ============================================================================
@@ -36,7 +45,7 @@
 #align(center)[
   #v(2in)
 
-  #text(size: 24pt, weight: "bold")[RESIDENTIAL LEASE AGREEMENT]
+  #heading(level: 1)[RESIDENTIAL LEASE AGREEMENT]
 
   #v(0.5em)
 
@@ -95,7 +104,7 @@
 // TABLE OF CONTENTS
 // ============================================================================
 
-#text(size: 16pt, weight: "bold")[TABLE OF CONTENTS]
+#heading(level: 2)[#text(size: 16pt)[TABLE OF CONTENTS]]
 #v(1em)
 
 #let toc_item(number, title) = [
@@ -127,11 +136,11 @@
 // SECTION 1: BASIC LEASE TERMS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[1. BASIC LEASE TERMS]
+#heading(level: 2)[1. BASIC LEASE TERMS]
 #v(1em)
 
 // 1.1 Parties
-#text(size: 12pt, weight: "bold")[1.1 PARTIES TO THIS AGREEMENT]
+#heading(level: 3)[1.1 PARTIES TO THIS AGREEMENT]
 #v(0.5em)
 
 This Residential Lease Agreement ("Lease") is entered into between:
@@ -158,7 +167,7 @@ This Residential Lease Agreement ("Lease") is entered into between:
 #v(1em)
 
 // 1.2 Property Description
-#text(size: 12pt, weight: "bold")[1.2 PROPERTY DESCRIPTION]
+#heading(level: 3)[1.2 PROPERTY DESCRIPTION]
 #v(0.5em)
 
 The Landlord agrees to lease to the Tenant the following residential property ("Premises"):
@@ -177,7 +186,7 @@ The Landlord agrees to lease to the Tenant the following residential property ("
 #v(1em)
 
 // 1.3 Lease Term
-#text(size: 12pt, weight: "bold")[1.3 LEASE TERM]
+#heading(level: 3)[1.3 LEASE TERM]
 #v(0.5em)
 
 The lease term begins on *#get("lease_start", default: "[Start Date]")* and ends on *#get("lease_end", default: "[End Date]")*.
@@ -192,10 +201,10 @@ This is a fixed-term lease. Upon expiration, the lease will convert to a month-t
 // SECTION 2: RENT AND PAYMENTS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[2. RENT AND PAYMENTS]
+#heading(level: 2)[2. RENT AND PAYMENTS]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[2.1 RENT]
+#heading(level: 3)[2.1 RENT]
 #v(0.5em)
 
 Monthly rent is *#format_money(get_num("monthly_rent"))*, due on the *#get("rent_due_day", default: "1st")* day of each month.
@@ -212,7 +221,7 @@ Monthly rent is *#format_money(get_num("monthly_rent"))*, due on the *#get("rent
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[2.2 LATE FEES]
+#heading(level: 3)[2.2 LATE FEES]
 #v(0.5em)
 
 #let late_fee_pct = get("late_fee_percent", default: "10")
@@ -226,12 +235,12 @@ If rent is not received by the *#grace_days* day of the month, a late fee of *#l
 // SECTION 3: SECURITY DEPOSIT (§ 92.103-109)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[3. SECURITY DEPOSIT]
+#heading(level: 2)[3. SECURITY DEPOSIT]
 #v(0.5em)
 #text(size: 10pt, fill: rgb("#444"))[Texas Property Code §§ 92.103-92.109]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3.1 DEPOSIT AMOUNT]
+#heading(level: 3)[3.1 DEPOSIT AMOUNT]
 #v(0.5em)
 
 Tenant shall pay a security deposit of *#format_money(get_num("security_deposit"))* upon execution of this Lease.
@@ -247,10 +256,12 @@ Tenant shall pay a security deposit of *#format_money(get_num("security_deposit"
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3.2 DEPOSIT RETURN (§ 92.104)]
+#heading(level: 3)[3.2 DEPOSIT RETURN (§ 92.104)]
 #v(0.5em)
 
-*The security deposit, less any lawful deductions, will be returned within 30 days of lease termination and Tenant vacating the Premises.*
+#let deposit_return_days = get("deposit_return_days", default: "30")
+
+*The security deposit, less any lawful deductions, will be returned within #deposit_return_days days of lease termination and Tenant vacating the Premises.*
 
 Lawful deductions may include:
 - Unpaid rent
@@ -262,7 +273,7 @@ Lawful deductions may include:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3.3 FORWARDING ADDRESS (§ 92.107)]
+#heading(level: 3)[3.3 FORWARDING ADDRESS (§ 92.107)]
 #v(0.5em)
 
 *Tenant must provide a forwarding address in writing upon move-out.* Failure to provide a forwarding address may affect the timing and method of deposit return.
@@ -273,7 +284,7 @@ Lawful deductions may include:
 // SECTION 4: LANDLORD DISCLOSURE (§ 92.201)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[4. LANDLORD DISCLOSURE]
+#heading(level: 2)[4. LANDLORD DISCLOSURE]
 #v(0.5em)
 #text(size: 10pt, fill: rgb("#444"))[Texas Property Code § 92.201 - Disclosure of Ownership and Management]
 #v(1em)
@@ -297,12 +308,12 @@ As required by Texas law, the following information is provided:
 // SECTION 5: MAINTENANCE AND REPAIRS (§ 92.056)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[5. MAINTENANCE AND REPAIRS]
+#heading(level: 2)[5. MAINTENANCE AND REPAIRS]
 #v(0.5em)
 #text(size: 10pt, fill: rgb("#444"))[Texas Property Code § 92.056]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[5.1 REPAIR REQUEST PROCEDURE]
+#heading(level: 3)[5.1 REPAIR REQUEST PROCEDURE]
 #v(0.5em)
 
 *All repair requests must be submitted in writing* (mail, email, or written notice delivered to Landlord) to:
@@ -321,7 +332,7 @@ Landlord will respond within a *reasonable time*, typically:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[5.2 LANDLORD'S DUTY TO REPAIR (§ 92.006)]
+#heading(level: 3)[5.2 LANDLORD'S DUTY TO REPAIR (§ 92.006)]
 #v(0.5em)
 
 Landlord is obligated to:
@@ -333,7 +344,7 @@ Landlord is obligated to:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[5.3 TENANT'S MAINTENANCE DUTIES]
+#heading(level: 3)[5.3 TENANT'S MAINTENANCE DUTIES]
 #v(0.5em)
 
 Tenant shall:
@@ -349,7 +360,7 @@ Tenant shall:
 // SECTION 6: LOCKOUT POLICY (§ 92.0081)
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[6. LOCKOUT POLICY]
+#heading(level: 2)[6. LOCKOUT POLICY]
 #v(0.5em)
 #text(size: 10pt, fill: rgb("#444"))[Texas Property Code § 92.0081]
 #v(1em)
@@ -375,7 +386,7 @@ Tenant shall:
 // SECTION 7: PARKING AND VEHICLES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[7. PARKING AND VEHICLES]
+#heading(level: 2)[7. PARKING AND VEHICLES]
 #v(1em)
 
 #if get_bool("parking_included") [
@@ -396,7 +407,7 @@ Tenant shall:
 // SECTION 8: TENANT OBLIGATIONS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[8. TENANT OBLIGATIONS]
+#heading(level: 2)[8. TENANT OBLIGATIONS]
 #v(1em)
 
 Tenant agrees to:
@@ -417,7 +428,7 @@ Tenant agrees to:
 // SECTION 9: LANDLORD OBLIGATIONS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[9. LANDLORD OBLIGATIONS]
+#heading(level: 2)[9. LANDLORD OBLIGATIONS]
 #v(1em)
 
 Landlord agrees to:
@@ -427,7 +438,7 @@ Landlord agrees to:
 3. *Provide essential services* (if applicable)
 4. *Respect Tenant's privacy* and provide reasonable notice before entry
 5. *Comply with all building codes* and health and safety laws
-6. *Return security deposit* within 30 days of lease termination (§ 92.104)
+6. *Return security deposit* within #deposit_return_days days of lease termination (§ 92.104)
 7. *Provide required disclosures* (ownership, lead paint, flood history)
 
 #v(1em)
@@ -436,10 +447,10 @@ Landlord agrees to:
 // SECTION 10: NOTICES AND TERMINATION
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[10. NOTICES AND TERMINATION]
+#heading(level: 2)[10. NOTICES AND TERMINATION]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[10.1 NOTICE REQUIREMENTS]
+#heading(level: 3)[10.1 NOTICE REQUIREMENTS]
 #v(0.5em)
 
 All formal notices shall be in writing and delivered to:
@@ -455,7 +466,7 @@ At the Premises address, or forwarding address if provided
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[10.2 TERMINATION]
+#heading(level: 3)[10.2 TERMINATION]
 #v(0.5em)
 
 Either party may terminate this Lease:
@@ -469,10 +480,10 @@ Either party may terminate this Lease:
 // SECTION 11: DEFAULT AND REMEDIES
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[11. DEFAULT AND REMEDIES]
+#heading(level: 2)[11. DEFAULT AND REMEDIES]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[11.1 TENANT DEFAULT]
+#heading(level: 3)[11.1 TENANT DEFAULT]
 #v(0.5em)
 
 Tenant shall be in default if:
@@ -484,7 +495,7 @@ Tenant shall be in default if:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[11.2 LANDLORD REMEDIES]
+#heading(level: 3)[11.2 LANDLORD REMEDIES]
 #v(0.5em)
 
 Upon Tenant default, Landlord may:
@@ -499,7 +510,7 @@ Upon Tenant default, Landlord may:
 // SECTION 12: LEAD-BASED PAINT DISCLOSURE
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[12. LEAD-BASED PAINT DISCLOSURE]
+#heading(level: 2)[12. LEAD-BASED PAINT DISCLOSURE]
 #v(0.5em)
 #text(size: 10pt, fill: rgb("#444"))[42 U.S.C. § 4852d - Required for properties built before 1978]
 #v(1em)
@@ -549,10 +560,10 @@ Upon Tenant default, Landlord may:
 // SECTION 13: FLOOD DISCLOSURE
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[13. FLOOD DISCLOSURE]
+#heading(level: 2)[13. FLOOD DISCLOSURE]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[13.1 FLOOD HISTORY]
+#heading(level: 3)[13.1 FLOOD HISTORY]
 #v(0.5em)
 
 #let has_flooding = get("flood_history_status", default: "unknown")
@@ -569,7 +580,7 @@ Upon Tenant default, Landlord may:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[13.2 FLOOD INSURANCE CLAIMS]
+#heading(level: 3)[13.2 FLOOD INSURANCE CLAIMS]
 #v(0.5em)
 
 #let has_claims = get("flood_claims_status", default: "unknown")
@@ -584,7 +595,7 @@ Upon Tenant default, Landlord may:
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[13.3 FLOOD ZONE INFORMATION]
+#heading(level: 3)[13.3 FLOOD ZONE INFORMATION]
 #v(0.5em)
 
 #if get("flood_zone") != "" [
@@ -603,10 +614,10 @@ Tenant is encouraged to verify flood zone status at FEMA's Flood Map Service Cen
 // SECTION 14: GENERAL PROVISIONS
 // ============================================================================
 
-#text(size: 14pt, weight: "bold")[14. GENERAL PROVISIONS]
+#heading(level: 2)[14. GENERAL PROVISIONS]
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.1 UTILITIES]
+#heading(level: 3)[14.1 UTILITIES]
 #v(0.5em)
 
 #table(
@@ -623,7 +634,7 @@ Tenant is encouraged to verify flood zone status at FEMA's Flood Map Service Cen
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.2 PETS]
+#heading(level: 3)[14.2 PETS]
 #v(0.5em)
 
 #if get_bool("pets_allowed") [
@@ -637,7 +648,7 @@ Tenant is encouraged to verify flood zone status at FEMA's Flood Map Service Cen
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.3 SMOKING]
+#heading(level: 3)[14.3 SMOKING]
 #v(0.5em)
 
 #if get_bool("smoking_allowed") [
@@ -648,28 +659,28 @@ Tenant is encouraged to verify flood zone status at FEMA's Flood Map Service Cen
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.4 GOVERNING LAW]
+#heading(level: 3)[14.4 GOVERNING LAW]
 #v(0.5em)
 
 This Lease shall be governed by Texas Property Code Chapter 92 and other applicable Texas laws.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.5 ENTIRE AGREEMENT]
+#heading(level: 3)[14.5 ENTIRE AGREEMENT]
 #v(0.5em)
 
 This Lease, including all attached addenda, constitutes the entire agreement between the parties. Any modifications must be in writing and signed by both parties.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.6 SEVERABILITY]
+#heading(level: 3)[14.6 SEVERABILITY]
 #v(0.5em)
 
 If any provision of this Lease is found to be invalid or unenforceable, the remaining provisions shall continue in full force and effect.
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[14.7 VOID CLAUSES NOTICE]
+#heading(level: 3)[14.7 VOID CLAUSES NOTICE]
 #v(0.5em)
 
 *The following provisions are void under Texas law and cannot be enforced:*
@@ -684,7 +695,7 @@ If any provision of this Lease is found to be invalid or unenforceable, the rema
 // ============================================================================
 
 #align(center)[
-  #text(size: 16pt, weight: "bold")[SIGNATURE PAGE]
+  #heading(level: 2)[#text(size: 16pt)[SIGNATURE PAGE]]
 ]
 #v(1em)
 
@@ -692,7 +703,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
 #v(2em)
 
-#text(size: 12pt, weight: "bold")[LANDLORD]
+#heading(level: 3)[LANDLORD]
 #v(1em)
 
 #grid(
@@ -710,7 +721,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 
 #v(2em)
 
-#text(size: 12pt, weight: "bold")[TENANT(S)]
+#heading(level: 3)[TENANT(S)]
 #v(1em)
 
 #grid(
@@ -749,7 +760,7 @@ By signing below, the parties agree to all terms and conditions of this Resident
 // ============================================================================
 
 #align(center)[
-  #text(size: 16pt, weight: "bold")[ADDENDUM A: PARKING RULES]
+  #heading(level: 2)[#text(size: 16pt)[ADDENDUM A: PARKING RULES]]
   #v(0.5em)
   #text(size: 10pt, fill: rgb("#444"))[Texas Property Code § 92.0131]
 ]
@@ -761,7 +772,7 @@ This Parking Rules Addendum is attached to and made a part of the Residential Le
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[1. PARKING SPACES]
+#heading(level: 3)[1. PARKING SPACES]
 #v(0.5em)
 
 #if get_bool("parking_included") [
@@ -772,7 +783,7 @@ This Parking Rules Addendum is attached to and made a part of the Residential Le
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[2. VEHICLE REQUIREMENTS]
+#heading(level: 3)[2. VEHICLE REQUIREMENTS]
 #v(0.5em)
 
 - Vehicles must be properly registered and insured
@@ -782,7 +793,7 @@ This Parking Rules Addendum is attached to and made a part of the Residential Le
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[3. TOWING POLICY]
+#heading(level: 3)[3. TOWING POLICY]
 #v(0.5em)
 
 #rect(
@@ -806,14 +817,14 @@ This Parking Rules Addendum is attached to and made a part of the Residential Le
 
 #v(1em)
 
-#text(size: 12pt, weight: "bold")[4. GUEST PARKING]
+#heading(level: 3)[4. GUEST PARKING]
 #v(0.5em)
 
 Guests may park in designated guest parking areas only. Guest vehicles parked for more than 72 consecutive hours may be towed.
 
 #v(2em)
 
-#text(size: 12pt, weight: "bold")[ACKNOWLEDGMENT]
+#heading(level: 3)[ACKNOWLEDGMENT]
 #v(1em)
 
 By signing below, Tenant acknowledges receipt of this Parking Rules Addendum and agrees to comply with all parking rules.